@@ -0,0 +1,704 @@
+//! The Unicode Bidirectional Algorithm, as described in
+//! [Unicode Standard Annex #9](http://www.unicode.org/reports/tr9/).
+//!
+//! Levels are resolved up to and including rule L1, and `reorder` implements rule L2. Mirrored
+//! glyphs (rule L4) are applied to the text of right-to-left runs with `mirror`.
+
+use {
+    makepad_ucd::{BidiClass, BidiPairedBracketType, Ucd},
+    std::{borrow::Cow, ops::Range},
+};
+
+/// The deepest embedding level explicit formatting characters can reach.
+const MAX_DEPTH: u8 = 125;
+
+/// The most opening brackets that can be waiting for their closing bracket (BD16).
+const MAX_OPEN_BRACKETS: usize = 63;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Direction {
+    fn level(self) -> u8 {
+        match self {
+            Self::LeftToRight => 0,
+            Self::RightToLeft => 1,
+        }
+    }
+}
+
+/// A piece of text with the same embedding level.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Run {
+    /// The byte range of the run in the text.
+    pub range: Range<usize>,
+    /// The char range of the run in the text.
+    pub chars: Range<usize>,
+    pub level: u8,
+}
+
+impl Run {
+    pub fn is_rtl(&self) -> bool {
+        self.level % 2 == 1
+    }
+}
+
+/// A paragraph of text with the embedding level of each of its chars resolved.
+///
+/// The whole paragraph is laid out as a single line. Paragraph separators in the text reset the
+/// explicit embeddings, but don't start a new paragraph with its own base direction.
+#[derive(Clone, Debug)]
+pub struct Paragraph {
+    base_level: u8,
+    positions: Vec<usize>,
+    levels: Vec<u8>,
+    len: usize,
+}
+
+impl Paragraph {
+    /// Resolves the embedding levels of the given text. Without a direction, the direction is
+    /// taken from the first strong char in the text, and is left-to-right if there is none.
+    pub fn new(string: &str, direction: Option<Direction>) -> Self {
+        let positions = string.char_indices().map(|(position, _)| position).collect();
+        let chars: Vec<char> = string.chars().collect();
+        let classes: Vec<BidiClass> = chars.iter().map(|ch| ch.bidi_class()).collect();
+        let matching_pdis = matching_pdis(&classes);
+        let base_level = match direction {
+            Some(direction) => direction.level(),
+            None => first_strong_level(&classes, &matching_pdis, 0..classes.len()).unwrap_or(0),
+        };
+        let levels = resolve_levels(&chars, &classes, &matching_pdis, base_level);
+        Self {
+            base_level,
+            positions,
+            levels,
+            len: string.len(),
+        }
+    }
+
+    pub fn base_level(&self) -> u8 {
+        self.base_level
+    }
+
+    pub fn direction(&self) -> Direction {
+        if self.base_level % 2 == 1 {
+            Direction::RightToLeft
+        } else {
+            Direction::LeftToRight
+        }
+    }
+
+    /// Returns the embedding level of each char.
+    pub fn levels(&self) -> &[u8] {
+        &self.levels
+    }
+
+    /// Returns the runs of the paragraph from left to right.
+    pub fn visual_runs(&self) -> Vec<Run> {
+        let mut runs: Vec<Run> = Vec::new();
+        for (index, level) in self.levels.iter().cloned().enumerate() {
+            match runs.last_mut() {
+                Some(run) if run.level == level => run.chars.end = index + 1,
+                _ => runs.push(Run {
+                    range: 0..0,
+                    chars: index..index + 1,
+                    level,
+                }),
+            }
+        }
+        for run in &mut runs {
+            run.range = self.position(run.chars.start)..self.position(run.chars.end);
+        }
+        let levels: Vec<u8> = runs.iter().map(|run| run.level).collect();
+        reorder(&levels)
+            .into_iter()
+            .map(|index| runs[index].clone())
+            .collect()
+    }
+
+    /// Returns the indices of the chars from left to right.
+    pub fn visual_order(&self) -> Vec<usize> {
+        reorder(&self.levels)
+    }
+
+    /// Returns the cursor offset, in chars, that is visually next to the given one on the left or
+    /// the right, or `None` if the given one is already at that end of the line.
+    pub fn move_visually(&self, offset: usize, direction: Direction) -> Option<usize> {
+        let order = self.visual_order();
+        let edge_count = order.len() + 1;
+        let mut slots = vec![0; order.len()];
+        for (slot, index) in order.iter().cloned().enumerate() {
+            slots[index] = slot;
+        }
+        let is_rtl = |index: usize| self.levels[index] % 2 == 1;
+        // the edge between two visual slots that a cursor offset is drawn at
+        let edge = |offset: usize| {
+            if offset < order.len() {
+                slots[offset] + is_rtl(offset) as usize
+            } else if offset > 0 {
+                slots[offset - 1] + !is_rtl(offset - 1) as usize
+            } else {
+                0
+            }
+        };
+        let mut current = edge(offset);
+        loop {
+            current = match direction {
+                Direction::LeftToRight if current + 1 < edge_count => current + 1,
+                Direction::RightToLeft if current > 0 => current - 1,
+                _ => return None,
+            };
+            // the char to the right of the edge gives the offset of its left side, and the char
+            // to the left of it that of its right side
+            let candidates = [
+                order
+                    .get(current)
+                    .map(|&index| if is_rtl(index) { index + 1 } else { index }),
+                current
+                    .checked_sub(1)
+                    .map(|slot| order[slot])
+                    .map(|index| if is_rtl(index) { index } else { index + 1 }),
+            ];
+            let mut candidates = candidates.iter().flatten().cloned();
+            let candidate = candidates
+                .clone()
+                .find(|&candidate| edge(candidate) == current)
+                .or_else(|| candidates.next())
+                .unwrap();
+            if candidate != offset {
+                return Some(candidate);
+            }
+        }
+    }
+
+    fn position(&self, index: usize) -> usize {
+        self.positions.get(index).cloned().unwrap_or(self.len)
+    }
+}
+
+/// Returns the indices of the items with the given levels in visual order, by reversing every
+/// sequence at or above each odd level, from the highest level down (rule L2).
+pub fn reorder(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let max_level = levels.iter().cloned().max().unwrap_or(0);
+    let min_odd_level = match levels.iter().cloned().filter(|level| level % 2 == 1).min() {
+        Some(level) => level,
+        None => return order,
+    };
+    for level in (min_odd_level..=max_level).rev() {
+        let mut index = 0;
+        while index < order.len() {
+            if levels[order[index]] < level {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while index < order.len() && levels[order[index]] >= level {
+                index += 1;
+            }
+            order[start..index].reverse();
+        }
+    }
+    order
+}
+
+/// Replaces every char that has a mirrored glyph by that glyph, for the text of a right-to-left
+/// run (L4). A mirrored glyph is as long in bytes as its char, so byte offsets stay the same.
+pub fn mirror(string: &str) -> Cow<'_, str> {
+    if string.chars().all(|ch| ch.bidi_mirroring_glyph().is_none()) {
+        return Cow::Borrowed(string);
+    }
+    Cow::Owned(
+        string
+            .chars()
+            .map(|ch| ch.bidi_mirroring_glyph().unwrap_or(ch))
+            .collect(),
+    )
+}
+
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(class, BidiClass::LRI | BidiClass::RLI | BidiClass::FSI)
+}
+
+fn is_removed(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::LRE
+            | BidiClass::RLE
+            | BidiClass::LRO
+            | BidiClass::RLO
+            | BidiClass::PDF
+            | BidiClass::BN
+    )
+}
+
+fn is_neutral_or_isolate(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::B
+            | BidiClass::S
+            | BidiClass::WS
+            | BidiClass::ON
+            | BidiClass::FSI
+            | BidiClass::LRI
+            | BidiClass::RLI
+            | BidiClass::PDI
+    )
+}
+
+/// Returns the index of the matching PDI for every isolate initiator that has one (BD9).
+fn matching_pdis(classes: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut matching_pdis = vec![None; classes.len()];
+    let mut open_isolates = Vec::new();
+    for (index, class) in classes.iter().cloned().enumerate() {
+        match class {
+            class if is_isolate_initiator(class) => open_isolates.push(index),
+            BidiClass::PDI => {
+                if let Some(initiator) = open_isolates.pop() {
+                    matching_pdis[initiator] = Some(index);
+                }
+            }
+            BidiClass::B => open_isolates.clear(),
+            _ => {}
+        }
+    }
+    matching_pdis
+}
+
+/// Returns the level of the first strong char in the given range, skipping over isolates (P2, P3).
+fn first_strong_level(
+    classes: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    range: Range<usize>,
+) -> Option<u8> {
+    let mut index = range.start;
+    while index < range.end {
+        match classes[index] {
+            BidiClass::L => return Some(0),
+            BidiClass::R | BidiClass::AL => return Some(1),
+            BidiClass::B => return None,
+            class if is_isolate_initiator(class) => match matching_pdis[index] {
+                Some(pdi) => index = pdi,
+                None => return None,
+            },
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+#[derive(Clone, Copy)]
+struct Status {
+    level: u8,
+    override_class: Option<BidiClass>,
+    is_isolate: bool,
+}
+
+fn resolve_levels(
+    chars: &[char],
+    classes: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    base_level: u8,
+) -> Vec<u8> {
+    let mut levels = vec![base_level; classes.len()];
+    let mut resolved_classes = classes.to_vec();
+    resolve_explicit_levels(classes, matching_pdis, base_level, &mut levels, &mut resolved_classes);
+
+    for sequence in isolating_run_sequences(classes, matching_pdis, &levels) {
+        resolve_sequence(&sequence, chars, classes, base_level, &levels, &mut resolved_classes);
+        for index in sequence {
+            let level = levels[index];
+            levels[index] = match (level % 2 == 1, resolved_classes[index]) {
+                (false, BidiClass::R) => level + 1,
+                (false, BidiClass::AN) | (false, BidiClass::EN) => level + 2,
+                (true, BidiClass::L) | (true, BidiClass::AN) | (true, BidiClass::EN) => level + 1,
+                _ => level,
+            };
+        }
+    }
+
+    // removed chars get the level of the char before them, so they don't split runs
+    for index in 0..classes.len() {
+        if is_removed(classes[index]) {
+            levels[index] = if index == 0 { base_level } else { levels[index - 1] };
+        }
+    }
+
+    // separators and the whitespace before them or at the end of the line go back to the
+    // paragraph level (L1)
+    let mut trailing_whitespace = true;
+    for index in (0..classes.len()).rev() {
+        match classes[index] {
+            BidiClass::S | BidiClass::B => {
+                levels[index] = base_level;
+                trailing_whitespace = true;
+            }
+            class
+                if class == BidiClass::WS
+                    || is_isolate_initiator(class)
+                    || class == BidiClass::PDI
+                    || is_removed(class) =>
+            {
+                if trailing_whitespace {
+                    levels[index] = base_level;
+                }
+            }
+            _ => trailing_whitespace = false,
+        }
+    }
+    levels
+}
+
+/// Applies the explicit embeddings, overrides and isolates (X1-X8).
+fn resolve_explicit_levels(
+    classes: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    base_level: u8,
+    levels: &mut [u8],
+    resolved_classes: &mut [BidiClass],
+) {
+    let base_status = Status {
+        level: base_level,
+        override_class: None,
+        is_isolate: false,
+    };
+    let mut stack = vec![base_status];
+    let mut overflow_isolate_count = 0;
+    let mut overflow_embedding_count = 0;
+    let mut valid_isolate_count = 0;
+    for (index, class) in classes.iter().cloned().enumerate() {
+        let last = *stack.last().unwrap();
+        let next_level = |is_rtl: bool| {
+            if is_rtl {
+                (last.level + 1) | 1
+            } else {
+                (last.level + 2) & !1
+            }
+        };
+        match class {
+            BidiClass::RLE | BidiClass::LRE | BidiClass::RLO | BidiClass::LRO => {
+                levels[index] = last.level;
+                let level = next_level(matches!(class, BidiClass::RLE | BidiClass::RLO));
+                if level <= MAX_DEPTH && overflow_isolate_count == 0 && overflow_embedding_count == 0 {
+                    stack.push(Status {
+                        level,
+                        override_class: match class {
+                            BidiClass::RLO => Some(BidiClass::R),
+                            BidiClass::LRO => Some(BidiClass::L),
+                            _ => None,
+                        },
+                        is_isolate: false,
+                    });
+                } else if overflow_isolate_count == 0 {
+                    overflow_embedding_count += 1;
+                }
+            }
+            BidiClass::RLI | BidiClass::LRI | BidiClass::FSI => {
+                levels[index] = last.level;
+                if let Some(override_class) = last.override_class {
+                    resolved_classes[index] = override_class;
+                }
+                let is_rtl = match class {
+                    BidiClass::RLI => true,
+                    BidiClass::LRI => false,
+                    _ => {
+                        let end = matching_pdis[index].unwrap_or(classes.len());
+                        first_strong_level(classes, matching_pdis, index + 1..end) == Some(1)
+                    }
+                };
+                let level = next_level(is_rtl);
+                if level <= MAX_DEPTH && overflow_isolate_count == 0 && overflow_embedding_count == 0 {
+                    valid_isolate_count += 1;
+                    stack.push(Status {
+                        level,
+                        override_class: None,
+                        is_isolate: true,
+                    });
+                } else {
+                    overflow_isolate_count += 1;
+                }
+            }
+            BidiClass::PDI => {
+                if overflow_isolate_count > 0 {
+                    overflow_isolate_count -= 1;
+                } else if valid_isolate_count > 0 {
+                    overflow_embedding_count = 0;
+                    while !stack.last().unwrap().is_isolate {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolate_count -= 1;
+                }
+                let last = *stack.last().unwrap();
+                levels[index] = last.level;
+                if let Some(override_class) = last.override_class {
+                    resolved_classes[index] = override_class;
+                }
+            }
+            BidiClass::PDF => {
+                levels[index] = last.level;
+                if overflow_isolate_count > 0 {
+                } else if overflow_embedding_count > 0 {
+                    overflow_embedding_count -= 1;
+                } else if !last.is_isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            BidiClass::B => {
+                levels[index] = base_level;
+                stack.truncate(1);
+                overflow_isolate_count = 0;
+                overflow_embedding_count = 0;
+                valid_isolate_count = 0;
+            }
+            BidiClass::BN => levels[index] = last.level,
+            _ => {
+                levels[index] = last.level;
+                if let Some(override_class) = last.override_class {
+                    resolved_classes[index] = override_class;
+                }
+            }
+        }
+    }
+}
+
+/// Splits the chars that weren't removed into level runs, and chains the level runs that are
+/// connected by isolates into sequences (X10).
+fn isolating_run_sequences(
+    classes: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    levels: &[u8],
+) -> Vec<Vec<usize>> {
+    let mut level_runs: Vec<Vec<usize>> = Vec::new();
+    let mut last_level = None;
+    for index in (0..classes.len()).filter(|&index| !is_removed(classes[index])) {
+        if last_level == Some(levels[index]) {
+            level_runs.last_mut().unwrap().push(index);
+        } else {
+            level_runs.push(vec![index]);
+        }
+        last_level = Some(levels[index]);
+    }
+
+    let mut is_matched_pdi = vec![false; classes.len()];
+    for pdi in matching_pdis.iter().flatten() {
+        is_matched_pdi[*pdi] = true;
+    }
+    let mut run_starting_at = vec![None; classes.len()];
+    for (run_index, run) in level_runs.iter().enumerate() {
+        run_starting_at[run[0]] = Some(run_index);
+    }
+
+    let mut sequences = Vec::new();
+    for run in &level_runs {
+        if is_matched_pdi[run[0]] {
+            continue;
+        }
+        let mut sequence = run.clone();
+        while let Some(pdi) = matching_pdis[*sequence.last().unwrap()] {
+            match run_starting_at[pdi] {
+                Some(run_index) => sequence.extend_from_slice(&level_runs[run_index]),
+                None => break,
+            }
+        }
+        sequences.push(sequence);
+    }
+    sequences
+}
+
+/// Returns the bracket pairs of an isolating run sequence, as indices into the sequence sorted by
+/// their opening bracket (BD16).
+fn bracket_pairs(chars: &[char], types: &[BidiClass]) -> Vec<(usize, usize)> {
+    // U+2329 and U+232A are canonically equivalent to U+3008 and U+3009, so they pair with them
+    let canonical = |ch: char| match ch {
+        '\u{2329}' => '\u{3008}',
+        '\u{232A}' => '\u{3009}',
+        _ => ch,
+    };
+    let mut pairs = Vec::new();
+    let mut open_brackets: Vec<(char, usize)> = Vec::new();
+    for (index, (&ch, &class)) in chars.iter().zip(types).enumerate() {
+        if class != BidiClass::ON {
+            continue;
+        }
+        match ch.bidi_paired_bracket_type() {
+            BidiPairedBracketType::Open => {
+                if open_brackets.len() == MAX_OPEN_BRACKETS {
+                    break;
+                }
+                open_brackets.push((canonical(ch.bidi_paired_bracket().unwrap()), index));
+            }
+            BidiPairedBracketType::Close => {
+                let ch = canonical(ch);
+                if let Some(depth) = open_brackets.iter().rposition(|&(close, _)| close == ch) {
+                    pairs.push((open_brackets[depth].1, index));
+                    open_brackets.truncate(depth);
+                }
+            }
+            BidiPairedBracketType::None => {}
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Resolves the weak types, bracket pairs and neutrals of an isolating run sequence (W1-W7,
+/// N0-N2).
+fn resolve_sequence(
+    sequence: &[usize],
+    chars: &[char],
+    classes: &[BidiClass],
+    base_level: u8,
+    levels: &[u8],
+    resolved_classes: &mut [BidiClass],
+) {
+    use BidiClass::*;
+
+    let level = levels[sequence[0]];
+    let first = sequence[0];
+    let last = *sequence.last().unwrap();
+    let preceding_level = (0..first)
+        .rev()
+        .find(|&index| !is_removed(classes[index]))
+        .map_or(base_level, |index| levels[index]);
+    let following_level = if is_isolate_initiator(classes[last]) {
+        base_level
+    } else {
+        (last + 1..classes.len())
+            .find(|&index| !is_removed(classes[index]))
+            .map_or(base_level, |index| levels[index])
+    };
+    let class_of_level = |level: u8| if level % 2 == 1 { R } else { L };
+    let sos = class_of_level(level.max(preceding_level));
+    let eos = class_of_level(level.max(following_level));
+
+    let mut types: Vec<BidiClass> = sequence.iter().map(|&index| resolved_classes[index]).collect();
+
+    // W1
+    for index in 0..types.len() {
+        if types[index] == NSM {
+            types[index] = match index.checked_sub(1).map(|index| types[index]) {
+                None => sos,
+                Some(previous) if is_isolate_initiator(previous) || previous == PDI => ON,
+                Some(previous) => previous,
+            };
+        }
+    }
+    // W2, W3
+    let mut last_strong = sos;
+    for class in &mut types {
+        match *class {
+            L | R | AL => last_strong = *class,
+            EN if last_strong == AL => *class = AN,
+            _ => {}
+        }
+    }
+    for class in &mut types {
+        if *class == AL {
+            *class = R;
+        }
+    }
+    // W4
+    for index in 1..types.len().saturating_sub(1) {
+        match (types[index - 1], types[index], types[index + 1]) {
+            (EN, ES, EN) | (EN, CS, EN) => types[index] = EN,
+            (AN, CS, AN) => types[index] = AN,
+            _ => {}
+        }
+    }
+    // W5
+    let mut index = 0;
+    while index < types.len() {
+        if types[index] != ET {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < types.len() && types[index] == ET {
+            index += 1;
+        }
+        let is_next_to_en = (start > 0 && types[start - 1] == EN) || types.get(index) == Some(&EN);
+        if is_next_to_en {
+            types[start..index].fill(EN);
+        }
+    }
+    // W6
+    for class in &mut types {
+        if matches!(*class, ES | ET | CS) {
+            *class = ON;
+        }
+    }
+    // W7
+    let mut last_strong = sos;
+    for class in &mut types {
+        match *class {
+            L | R => last_strong = *class,
+            EN if last_strong == L => *class = L,
+            _ => {}
+        }
+    }
+    // numbers count as right-to-left for the neutrals
+    let strong_direction = |class: BidiClass| match class {
+        L => Some(L),
+        R | EN | AN => Some(R),
+        _ => None,
+    };
+    // N0
+    let sequence_chars: Vec<char> = sequence.iter().map(|&index| chars[index]).collect();
+    let embedding_direction = class_of_level(level);
+    for (open, close) in bracket_pairs(&sequence_chars, &types) {
+        let mut inside = types[open + 1..close]
+            .iter()
+            .filter_map(|&class| strong_direction(class));
+        let class = if inside.clone().any(|class| class == embedding_direction) {
+            embedding_direction
+        } else if inside.next().is_some() {
+            // only the opposite direction is inside, which the brackets take if the text before
+            // them has it as well
+            types[..open]
+                .iter()
+                .rev()
+                .find_map(|&class| strong_direction(class))
+                .unwrap_or(sos)
+        } else {
+            continue;
+        };
+        for bracket in [open, close] {
+            types[bracket] = class;
+            // the marks on a bracket were made ON by W1, and follow it instead
+            for index in bracket + 1..types.len() {
+                if classes[sequence[index]] != NSM {
+                    break;
+                }
+                types[index] = class;
+            }
+        }
+    }
+    // N1, N2
+    let mut index = 0;
+    while index < types.len() {
+        if !is_neutral_or_isolate(types[index]) {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < types.len() && is_neutral_or_isolate(types[index]) {
+            index += 1;
+        }
+        let before = if start == 0 { Some(sos) } else { strong_direction(types[start - 1]) };
+        let after = if index == types.len() { Some(eos) } else { strong_direction(types[index]) };
+        let class = match (before, after) {
+            (Some(before), Some(after)) if before == after => before,
+            _ => class_of_level(level),
+        };
+        types[start..index].fill(class);
+    }
+
+    for (&index, class) in sequence.iter().zip(types) {
+        resolved_classes[index] = class;
+    }
+}
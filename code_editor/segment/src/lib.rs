@@ -1,2 +1,3 @@
+pub mod bidi;
 pub mod cursor;
 pub mod str;
@@ -0,0 +1,73 @@
+use crate::str::{StrExt, WordIndices};
+
+/// An iterator over the positions in a `str` where a line can be wrapped.
+///
+/// This is a simplification of [Unicode Standard Annex #14](http://www.unicode.org/reports/tr14/)
+/// on top of the word boundaries: lines can be wrapped after whitespace and hyphens, and around
+/// ideographs, but never before whitespace or closing punctuation. The start and end of the
+/// `str` are not included.
+///
+/// This struct is created by the `line_breaks` method on `StrExt`.
+pub struct LineBreaks<'a> {
+    word_indices: WordIndices<'a>,
+    prev_word: Option<&'a str>,
+}
+
+impl<'a> LineBreaks<'a> {
+    pub(super) fn new(string: &'a str) -> Self {
+        Self {
+            word_indices: string.word_indices(),
+            prev_word: None,
+        }
+    }
+}
+
+impl<'a> Iterator for LineBreaks<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (position, word) = self.word_indices.next()?;
+            let prev_word = self.prev_word.replace(word);
+            let prev_char = match prev_word.and_then(|prev_word| prev_word.chars().next_back()) {
+                Some(prev_char) => prev_char,
+                None => continue,
+            };
+            let next_char = word.chars().next().unwrap();
+            if next_char.is_whitespace() || is_closing_punctuation(next_char) {
+                continue;
+            }
+            if prev_char.is_whitespace()
+                || (is_hyphen(prev_char) && next_char.is_alphanumeric())
+                || is_ideograph(prev_char)
+                || is_ideograph(next_char)
+            {
+                return Some(position);
+            }
+        }
+    }
+}
+
+fn is_closing_punctuation(ch: char) -> bool {
+    matches!(
+        ch,
+        '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '%' | '\'' | '"' | '’' | '”' | '»'
+            | '、' | '。' | '，' | '！' | '？' | '：' | '；' | '）' | '】' | '」' | '』' | '〉' | '》'
+    )
+}
+
+fn is_hyphen(ch: char) -> bool {
+    matches!(ch, '-' | '\u{2010}' | '\u{2013}' | '\u{2014}')
+}
+
+fn is_ideograph(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{2E80}'..='\u{2FFF}'
+            | '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
@@ -1,10 +1,11 @@
 mod cursor;
 mod graphemes;
 mod grapheme_indices;
+mod line_breaks;
 mod words;
 mod word_indices;
 
-pub use self::{cursor::Cursor, graphemes::Graphemes, grapheme_indices::GraphemeIndices, words::Words, word_indices::WordIndices};
+pub use self::{cursor::Cursor, graphemes::Graphemes, grapheme_indices::GraphemeIndices, line_breaks::LineBreaks, words::Words, word_indices::WordIndices};
 
 /// Extends `str` with methods to segment strings according to
 /// [Unicode Standard Annex #29](http://www.unicode.org/reports/tr29/).
@@ -18,6 +19,9 @@ pub trait StrExt {
     /// Returns an iterator over the graphemes of this `str`, and their positions.
     fn grapheme_indices(&self) -> GraphemeIndices<'_>;
 
+    /// Returns an iterator over the positions where a line can be wrapped in this `str`.
+    fn line_breaks(&self) -> LineBreaks<'_>;
+
     /// Returns an iterator over the words of this `str`.
     fn words(&self) -> Words<'_>;
 
@@ -38,6 +42,10 @@ impl StrExt for str {
         GraphemeIndices::new(self)
     }
 
+    fn line_breaks(&self) -> LineBreaks<'_> {
+        LineBreaks::new(self)
+    }
+
     fn words(&self) -> Words<'_> {
         Words::new(self)
     }
@@ -0,0 +1,85 @@
+use makepad_segment::bidi::{mirror, reorder, Direction, Paragraph, Run};
+
+#[test]
+fn test_levels() {
+    let tests: &[(&str, Option<Direction>, u8, &[u8])] = &[
+        ("abc", None, 0, &[0, 0, 0]),
+        ("אבג", None, 1, &[1, 1, 1]),
+        ("abc אבג def", None, 0, &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0]),
+        // numbers in right-to-left text stay left-to-right
+        ("אבג 123", None, 1, &[1, 1, 1, 1, 2, 2, 2]),
+        // numbers after Arabic letters are Arabic numbers, even with a separator in them
+        ("\u{0627}1.5", None, 1, &[1, 2, 2, 2]),
+        // trailing whitespace goes back to the paragraph level
+        ("אב ", Some(Direction::LeftToRight), 0, &[1, 1, 0]),
+        // the direction of the paragraph doesn't come from inside an isolate
+        ("\u{2067}ב\u{2069}c", None, 0, &[0, 1, 0, 0]),
+        ("a\u{2067}ב\u{2069}c", None, 0, &[0, 0, 1, 0, 0]),
+        ("\u{202E}abc\u{202C}", None, 0, &[0, 1, 1, 1, 0]),
+        // brackets around the opposite direction take it when the text before them has it too
+        ("א(ב)c", Some(Direction::LeftToRight), 0, &[1, 1, 1, 1, 0]),
+        ("אa(b)ג", Some(Direction::RightToLeft), 1, &[1, 2, 2, 2, 2, 1]),
+        // but not when it doesn't
+        ("a(ב)c", None, 0, &[0, 0, 1, 0, 0]),
+        // brackets pair with their canonical equivalents, and marks on a bracket follow it
+        ("א\u{2329}ב\u{3009}c", Some(Direction::LeftToRight), 0, &[1, 1, 1, 1, 0]),
+        ("א(ב)\u{0301}c", Some(Direction::LeftToRight), 0, &[1, 1, 1, 1, 1, 0]),
+    ];
+    for (string, direction, base_level, levels) in tests {
+        let paragraph = Paragraph::new(string, *direction);
+        assert_eq!(paragraph.base_level(), *base_level, "{}", string);
+        assert_eq!(paragraph.levels(), *levels, "{}", string);
+    }
+}
+
+#[test]
+fn test_mirror() {
+    assert_eq!(mirror("(a[b]<c>)"), ")a]b[>c<(");
+    assert_eq!(mirror("\u{2264}"), "\u{2265}");
+    assert!(matches!(mirror("abc"), std::borrow::Cow::Borrowed("abc")));
+}
+
+#[test]
+fn test_reorder() {
+    assert_eq!(reorder(&[0, 0, 1, 1, 0]), [0, 1, 3, 2, 4]);
+    assert_eq!(reorder(&[1, 1, 1, 1, 2, 2, 2]), [4, 5, 6, 3, 2, 1, 0]);
+    assert_eq!(reorder(&[]), Vec::<usize>::new());
+}
+
+#[test]
+fn test_visual_runs() {
+    let paragraph = Paragraph::new("abc אבג def", None);
+    assert_eq!(
+        paragraph.visual_runs(),
+        [
+            Run { range: 0..4, chars: 0..4, level: 0 },
+            Run { range: 4..10, chars: 4..7, level: 1 },
+            Run { range: 10..14, chars: 7..11, level: 0 },
+        ]
+    );
+    let paragraph = Paragraph::new("אבג abc", None);
+    assert_eq!(paragraph.direction(), Direction::RightToLeft);
+    assert_eq!(
+        paragraph.visual_runs(),
+        [
+            Run { range: 7..10, chars: 4..7, level: 2 },
+            Run { range: 0..7, chars: 0..4, level: 1 },
+        ]
+    );
+}
+
+#[test]
+fn test_move_visually() {
+    // the cursor walks from left to right over the screen, through the Hebrew word backwards
+    let paragraph = Paragraph::new("abc אבג", None);
+    let mut offsets = vec![0];
+    while let Some(offset) = paragraph.move_visually(*offsets.last().unwrap(), Direction::LeftToRight) {
+        offsets.push(offset);
+    }
+    assert_eq!(offsets, [0, 1, 2, 3, 7, 6, 5, 4]);
+    let mut offsets = vec![4];
+    while let Some(offset) = paragraph.move_visually(*offsets.last().unwrap(), Direction::RightToLeft) {
+        offsets.push(offset);
+    }
+    assert_eq!(offsets, [4, 5, 6, 7, 3, 2, 1, 0]);
+}
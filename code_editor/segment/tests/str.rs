@@ -1,5 +1,7 @@
 mod test_data;
 
+use makepad_segment::str::StrExt;
+
 #[test]
 fn test_graphemes() {
     for test in test_data::grapheme::TEST_DATA {
@@ -154,4 +156,22 @@ fn test_word_indices_rev() {
                 .collect::<Vec<_>>(),
         );
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_line_breaks() {
+    let tests: &[(&str, &[usize])] = &[
+        ("", &[]),
+        ("hello", &[]),
+        ("hello world", &[6]),
+        // whitespace and closing punctuation stay on the line before the break
+        ("hello,  world!", &[8]),
+        ("(a) b", &[4]),
+        ("well-known fact", &[5, 11]),
+        ("日本語です。", &[3, 6, 9, 12]),
+        ("one\ntwo", &[4]),
+    ];
+    for (string, line_breaks) in tests {
+        assert_eq!(string.line_breaks().collect::<Vec<_>>(), *line_breaks, "{:?}", string);
+    }
+}
@@ -166,6 +166,89 @@ def load_property_data(filename):
     return property
 
 
+def load_mapping_data(filename, expected_field_count):
+    """Loads data for a mapping from a Unicode data file.
+
+    Returns a dict mapping code points to the list of the remaining fields on
+    the line for that code point.
+    """
+    mapping = {}
+    for [code_points, *values] in read_unicode_data(filename, expected_field_count):
+        for code_point in code_points:
+            mapping[code_point] = values
+    return mapping
+
+
+def print_mapping_table(name, mapping, value_type, format_value):
+    """Prints the table for a mapping.
+
+    The mapping is specified by a dict mapping code points to the list of the
+    remaining fields on their line, which format_value turns into a Rust
+    expression of type value_type. Code points not in the mapping have no
+    value.
+    """
+
+    entries = sorted(mapping.items())
+    print("pub(crate) static %s: [([u8; 3], [u8; 3], %s); %d] = [" %
+          (name.upper(), value_type, len(entries)))
+    for (code_point, values) in entries:
+        print(
+            "    ([0x%02X, 0x%02X, 0x%02X], [0x%02X, 0x%02X, 0x%02X], %s)," %
+            (*code_point.to_bytes(3, byteorder="big"),
+             *code_point.to_bytes(3, byteorder="big"),
+             format_value(values)))
+    print("];")
+
+
+def format_char(string):
+    """Formats a code point as a Rust char literal."""
+    return "'\\u{%04X}'" % parse_code_point(string)
+
+
+def print_bidi_mirroring_glyph_table(mapping):
+    """Prints the table for the Bidi_Mirroring_Glyph property."""
+
+    print("//! This file was generated by:")
+    print("//! generate_table.py Bidi_Mirroring_Glyph <ucd_dir>")
+    print("")
+    print_mapping_table("Bidi_Mirroring_Glyph", mapping, "char",
+                        lambda values: format_char(values[0]))
+
+
+def print_bidi_paired_bracket_table(mapping):
+    """Prints the table for the Bidi_Paired_Bracket and
+    Bidi_Paired_Bracket_Type properties.
+
+    Code points that are not brackets have the bracket type None.
+    """
+
+    bracket_types = {"o": "Open", "c": "Close"}
+    print("//! This file was generated by:")
+    print("//! generate_table.py Bidi_Paired_Bracket <ucd_dir>")
+    print("")
+    print("use BidiPairedBracketType::*;")
+    print("")
+    print("#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]")
+    print("#[repr(u8)]")
+    print("pub enum BidiPairedBracketType {")
+    print("    None,")
+    print("    Open,")
+    print("    Close,")
+    print("}")
+    print("")
+    print("impl Default for BidiPairedBracketType {")
+    print("    fn default() -> Self {")
+    print("        Self::None")
+    print("    }")
+    print("}")
+    print("")
+    print("pub(crate) type BidiPairedBracket = (char, BidiPairedBracketType);")
+    print("")
+    print_mapping_table("Bidi_Paired_Bracket", mapping, "BidiPairedBracket",
+                        lambda values: "(%s, %s)" % (format_char(values[0]),
+                                                     bracket_types[values[1]]))
+
+
 def print_property_table(name, property, default_value):
     """Prints the table for a property.
 
@@ -184,7 +267,8 @@ def print_property_table(name, property, default_value):
     print("pub enum %s {" % name.replace("_", ""))
     print("    %s," % default_value.replace("_", ""))
     for variant in sorted(property):
-        print("    %s," % variant.replace("_", ""))
+        if variant != default_value:
+            print("    %s," % variant.replace("_", ""))
     print("}")
     print("")
     print("impl Default for %s {" % name.replace("_", ""))
@@ -196,6 +280,8 @@ def print_property_table(name, property, default_value):
 
     entries = []
     for value in property:
+        if value == default_value:
+            continue
         entries.extend([(code_points[0], code_points[-1], value)
                        for code_points in property[value]])
     entries.sort()
@@ -243,6 +329,15 @@ def main():
     elif args.name == "Grapheme_Cluster_Break":
         print_property_table(args.name, load_property_data(
             args.ucd_dir + "/auxiliary/GraphemeBreakProperty.txt"), "Other")
+    elif args.name == "Bidi_Class":
+        print_property_table(args.name, load_property_data(
+            args.ucd_dir + "/extracted/DerivedBidiClass.txt"), "L")
+    elif args.name == "Bidi_Mirroring_Glyph":
+        print_bidi_mirroring_glyph_table(load_mapping_data(
+            args.ucd_dir + "/BidiMirroring.txt", 2))
+    elif args.name == "Bidi_Paired_Bracket":
+        print_bidi_paired_bracket_table(load_mapping_data(
+            args.ucd_dir + "/BidiBrackets.txt", 3))
     elif args.name == "Word_Break":
         print_property_table(args.name, load_property_data(
             args.ucd_dir + "/auxiliary/WordBreakProperty.txt"), "Other")
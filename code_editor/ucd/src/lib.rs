@@ -1,11 +1,28 @@
 mod tables;
 
-pub use {grapheme_cluster_break::GraphemeClusterBreak, word_break::WordBreak};
+pub use {
+    bidi_class::BidiClass, bidi_paired_bracket::BidiPairedBracketType,
+    grapheme_cluster_break::GraphemeClusterBreak, word_break::WordBreak,
+};
 
 use tables::*;
 
 /// Extends `char` with methods to access the properties in the Unicode Character Database (UCD).
 pub trait Ucd {
+    /// Returns the value of the `Bidi_Class` property for this `char`.
+    fn bidi_class(self) -> BidiClass;
+
+    /// Returns the value of the `Bidi_Mirroring_Glyph` property for this `char`, or `None` if it
+    /// has no mirrored glyph.
+    fn bidi_mirroring_glyph(self) -> Option<char>;
+
+    /// Returns the value of the `Bidi_Paired_Bracket` property for this `char`, or `None` if it is
+    /// not a bracket.
+    fn bidi_paired_bracket(self) -> Option<char>;
+
+    /// Returns the value of the `Bidi_Paired_Bracket_Type` property for this `char`.
+    fn bidi_paired_bracket_type(self) -> BidiPairedBracketType;
+
     /// Returns the value of the `Extended_Pictographic` property for this `char`.
     fn extended_pictographic(self) -> bool;
 
@@ -17,6 +34,27 @@ pub trait Ucd {
 }
 
 impl Ucd for char {
+    fn bidi_class(self) -> BidiClass {
+        bidi_class::BIDI_CLASS.search(self).unwrap_or_default()
+    }
+
+    fn bidi_mirroring_glyph(self) -> Option<char> {
+        bidi_mirroring_glyph::BIDI_MIRRORING_GLYPH.search(self)
+    }
+
+    fn bidi_paired_bracket(self) -> Option<char> {
+        bidi_paired_bracket::BIDI_PAIRED_BRACKET
+            .search(self)
+            .map(|(bracket, _)| bracket)
+    }
+
+    fn bidi_paired_bracket_type(self) -> BidiPairedBracketType {
+        bidi_paired_bracket::BIDI_PAIRED_BRACKET
+            .search(self)
+            .map(|(_, bracket_type)| bracket_type)
+            .unwrap_or_default()
+    }
+
     fn extended_pictographic(self) -> bool {
         extended_pictographic::EXTENDED_PICTOGRAPHIC
             .search(self)
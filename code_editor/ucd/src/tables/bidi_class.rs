@@ -0,0 +1,754 @@
+//! This file was generated by:
+//! generate_table.py Bidi_Class <ucd_dir>
+
+use BidiClass::*;
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum BidiClass {
+    L,
+    AL,
+    AN,
+    B,
+    BN,
+    CS,
+    EN,
+    ES,
+    ET,
+    FSI,
+    LRE,
+    LRI,
+    LRO,
+    NSM,
+    ON,
+    PDF,
+    PDI,
+    R,
+    RLE,
+    RLI,
+    RLO,
+    S,
+    WS,
+}
+
+impl Default for BidiClass {
+    fn default() -> Self {
+        Self::L
+    }
+}
+
+pub(crate) static BIDI_CLASS: [([u8; 3], [u8; 3], BidiClass); 713] = [
+    ([0x00, 0x00, 0x00], [0x00, 0x00, 0x08], BN),
+    ([0x00, 0x00, 0x09], [0x00, 0x00, 0x09], S),
+    ([0x00, 0x00, 0x0A], [0x00, 0x00, 0x0A], B),
+    ([0x00, 0x00, 0x0B], [0x00, 0x00, 0x0B], S),
+    ([0x00, 0x00, 0x0C], [0x00, 0x00, 0x0C], WS),
+    ([0x00, 0x00, 0x0D], [0x00, 0x00, 0x0D], B),
+    ([0x00, 0x00, 0x0E], [0x00, 0x00, 0x1B], BN),
+    ([0x00, 0x00, 0x1C], [0x00, 0x00, 0x1E], B),
+    ([0x00, 0x00, 0x1F], [0x00, 0x00, 0x1F], S),
+    ([0x00, 0x00, 0x20], [0x00, 0x00, 0x20], WS),
+    ([0x00, 0x00, 0x21], [0x00, 0x00, 0x22], ON),
+    ([0x00, 0x00, 0x23], [0x00, 0x00, 0x25], ET),
+    ([0x00, 0x00, 0x26], [0x00, 0x00, 0x2A], ON),
+    ([0x00, 0x00, 0x2B], [0x00, 0x00, 0x2B], ES),
+    ([0x00, 0x00, 0x2C], [0x00, 0x00, 0x2C], CS),
+    ([0x00, 0x00, 0x2D], [0x00, 0x00, 0x2D], ES),
+    ([0x00, 0x00, 0x2E], [0x00, 0x00, 0x2F], CS),
+    ([0x00, 0x00, 0x30], [0x00, 0x00, 0x39], EN),
+    ([0x00, 0x00, 0x3A], [0x00, 0x00, 0x3A], CS),
+    ([0x00, 0x00, 0x3B], [0x00, 0x00, 0x40], ON),
+    ([0x00, 0x00, 0x5B], [0x00, 0x00, 0x60], ON),
+    ([0x00, 0x00, 0x7B], [0x00, 0x00, 0x7E], ON),
+    ([0x00, 0x00, 0x7F], [0x00, 0x00, 0x84], BN),
+    ([0x00, 0x00, 0x85], [0x00, 0x00, 0x85], B),
+    ([0x00, 0x00, 0x86], [0x00, 0x00, 0x9F], BN),
+    ([0x00, 0x00, 0xA0], [0x00, 0x00, 0xA0], CS),
+    ([0x00, 0x00, 0xA1], [0x00, 0x00, 0xA1], ON),
+    ([0x00, 0x00, 0xA2], [0x00, 0x00, 0xA5], ET),
+    ([0x00, 0x00, 0xA6], [0x00, 0x00, 0xA9], ON),
+    ([0x00, 0x00, 0xAB], [0x00, 0x00, 0xAC], ON),
+    ([0x00, 0x00, 0xAD], [0x00, 0x00, 0xAD], BN),
+    ([0x00, 0x00, 0xAE], [0x00, 0x00, 0xAF], ON),
+    ([0x00, 0x00, 0xB0], [0x00, 0x00, 0xB1], ET),
+    ([0x00, 0x00, 0xB2], [0x00, 0x00, 0xB3], EN),
+    ([0x00, 0x00, 0xB4], [0x00, 0x00, 0xB4], ON),
+    ([0x00, 0x00, 0xB6], [0x00, 0x00, 0xB8], ON),
+    ([0x00, 0x00, 0xB9], [0x00, 0x00, 0xB9], EN),
+    ([0x00, 0x00, 0xBB], [0x00, 0x00, 0xBF], ON),
+    ([0x00, 0x00, 0xD7], [0x00, 0x00, 0xD7], ON),
+    ([0x00, 0x00, 0xF7], [0x00, 0x00, 0xF7], ON),
+    ([0x00, 0x02, 0xB9], [0x00, 0x02, 0xBA], ON),
+    ([0x00, 0x02, 0xC2], [0x00, 0x02, 0xCF], ON),
+    ([0x00, 0x02, 0xD2], [0x00, 0x02, 0xDF], ON),
+    ([0x00, 0x02, 0xE5], [0x00, 0x02, 0xED], ON),
+    ([0x00, 0x02, 0xEF], [0x00, 0x02, 0xFF], ON),
+    ([0x00, 0x03, 0x00], [0x00, 0x03, 0x6F], NSM),
+    ([0x00, 0x03, 0x74], [0x00, 0x03, 0x75], ON),
+    ([0x00, 0x03, 0x7E], [0x00, 0x03, 0x7E], ON),
+    ([0x00, 0x03, 0x84], [0x00, 0x03, 0x85], ON),
+    ([0x00, 0x03, 0x87], [0x00, 0x03, 0x87], ON),
+    ([0x00, 0x03, 0xF6], [0x00, 0x03, 0xF6], ON),
+    ([0x00, 0x04, 0x83], [0x00, 0x04, 0x89], NSM),
+    ([0x00, 0x05, 0x8A], [0x00, 0x05, 0x8A], ON),
+    ([0x00, 0x05, 0x8D], [0x00, 0x05, 0x8E], ON),
+    ([0x00, 0x05, 0x8F], [0x00, 0x05, 0x8F], ET),
+    ([0x00, 0x05, 0x90], [0x00, 0x05, 0x90], R),
+    ([0x00, 0x05, 0x91], [0x00, 0x05, 0xBD], NSM),
+    ([0x00, 0x05, 0xBE], [0x00, 0x05, 0xBE], R),
+    ([0x00, 0x05, 0xBF], [0x00, 0x05, 0xBF], NSM),
+    ([0x00, 0x05, 0xC0], [0x00, 0x05, 0xC0], R),
+    ([0x00, 0x05, 0xC1], [0x00, 0x05, 0xC2], NSM),
+    ([0x00, 0x05, 0xC3], [0x00, 0x05, 0xC3], R),
+    ([0x00, 0x05, 0xC4], [0x00, 0x05, 0xC5], NSM),
+    ([0x00, 0x05, 0xC6], [0x00, 0x05, 0xC6], R),
+    ([0x00, 0x05, 0xC7], [0x00, 0x05, 0xC7], NSM),
+    ([0x00, 0x05, 0xC8], [0x00, 0x05, 0xFF], R),
+    ([0x00, 0x06, 0x00], [0x00, 0x06, 0x05], AN),
+    ([0x00, 0x06, 0x06], [0x00, 0x06, 0x07], ON),
+    ([0x00, 0x06, 0x08], [0x00, 0x06, 0x08], AL),
+    ([0x00, 0x06, 0x09], [0x00, 0x06, 0x0A], ET),
+    ([0x00, 0x06, 0x0B], [0x00, 0x06, 0x0B], AL),
+    ([0x00, 0x06, 0x0C], [0x00, 0x06, 0x0C], CS),
+    ([0x00, 0x06, 0x0D], [0x00, 0x06, 0x0D], AL),
+    ([0x00, 0x06, 0x0E], [0x00, 0x06, 0x0F], ON),
+    ([0x00, 0x06, 0x10], [0x00, 0x06, 0x1A], NSM),
+    ([0x00, 0x06, 0x1B], [0x00, 0x06, 0x4A], AL),
+    ([0x00, 0x06, 0x4B], [0x00, 0x06, 0x5F], NSM),
+    ([0x00, 0x06, 0x60], [0x00, 0x06, 0x69], AN),
+    ([0x00, 0x06, 0x6A], [0x00, 0x06, 0x6A], ET),
+    ([0x00, 0x06, 0x6B], [0x00, 0x06, 0x6C], AN),
+    ([0x00, 0x06, 0x6D], [0x00, 0x06, 0x6F], AL),
+    ([0x00, 0x06, 0x70], [0x00, 0x06, 0x70], NSM),
+    ([0x00, 0x06, 0x71], [0x00, 0x06, 0xD5], AL),
+    ([0x00, 0x06, 0xD6], [0x00, 0x06, 0xDC], NSM),
+    ([0x00, 0x06, 0xDD], [0x00, 0x06, 0xDD], AN),
+    ([0x00, 0x06, 0xDE], [0x00, 0x06, 0xDE], ON),
+    ([0x00, 0x06, 0xDF], [0x00, 0x06, 0xE4], NSM),
+    ([0x00, 0x06, 0xE5], [0x00, 0x06, 0xE6], AL),
+    ([0x00, 0x06, 0xE7], [0x00, 0x06, 0xE8], NSM),
+    ([0x00, 0x06, 0xE9], [0x00, 0x06, 0xE9], ON),
+    ([0x00, 0x06, 0xEA], [0x00, 0x06, 0xED], NSM),
+    ([0x00, 0x06, 0xEE], [0x00, 0x06, 0xEF], AL),
+    ([0x00, 0x06, 0xF0], [0x00, 0x06, 0xF9], EN),
+    ([0x00, 0x06, 0xFA], [0x00, 0x07, 0x10], AL),
+    ([0x00, 0x07, 0x11], [0x00, 0x07, 0x11], NSM),
+    ([0x00, 0x07, 0x12], [0x00, 0x07, 0x2F], AL),
+    ([0x00, 0x07, 0x30], [0x00, 0x07, 0x4A], NSM),
+    ([0x00, 0x07, 0x4B], [0x00, 0x07, 0xA5], AL),
+    ([0x00, 0x07, 0xA6], [0x00, 0x07, 0xB0], NSM),
+    ([0x00, 0x07, 0xB1], [0x00, 0x07, 0xBF], AL),
+    ([0x00, 0x07, 0xC0], [0x00, 0x07, 0xEA], R),
+    ([0x00, 0x07, 0xEB], [0x00, 0x07, 0xF3], NSM),
+    ([0x00, 0x07, 0xF4], [0x00, 0x07, 0xF5], R),
+    ([0x00, 0x07, 0xF6], [0x00, 0x07, 0xF9], ON),
+    ([0x00, 0x07, 0xFA], [0x00, 0x07, 0xFC], R),
+    ([0x00, 0x07, 0xFD], [0x00, 0x07, 0xFD], NSM),
+    ([0x00, 0x07, 0xFE], [0x00, 0x08, 0x15], R),
+    ([0x00, 0x08, 0x16], [0x00, 0x08, 0x19], NSM),
+    ([0x00, 0x08, 0x1A], [0x00, 0x08, 0x1A], R),
+    ([0x00, 0x08, 0x1B], [0x00, 0x08, 0x23], NSM),
+    ([0x00, 0x08, 0x24], [0x00, 0x08, 0x24], R),
+    ([0x00, 0x08, 0x25], [0x00, 0x08, 0x27], NSM),
+    ([0x00, 0x08, 0x28], [0x00, 0x08, 0x28], R),
+    ([0x00, 0x08, 0x29], [0x00, 0x08, 0x2D], NSM),
+    ([0x00, 0x08, 0x2E], [0x00, 0x08, 0x58], R),
+    ([0x00, 0x08, 0x59], [0x00, 0x08, 0x5B], NSM),
+    ([0x00, 0x08, 0x5C], [0x00, 0x08, 0x5F], R),
+    ([0x00, 0x08, 0x60], [0x00, 0x08, 0x8F], AL),
+    ([0x00, 0x08, 0x90], [0x00, 0x08, 0x91], AN),
+    ([0x00, 0x08, 0x92], [0x00, 0x08, 0x97], AL),
+    ([0x00, 0x08, 0x98], [0x00, 0x08, 0x9F], NSM),
+    ([0x00, 0x08, 0xA0], [0x00, 0x08, 0xC9], AL),
+    ([0x00, 0x08, 0xCA], [0x00, 0x08, 0xE1], NSM),
+    ([0x00, 0x08, 0xE2], [0x00, 0x08, 0xE2], AN),
+    ([0x00, 0x08, 0xE3], [0x00, 0x09, 0x02], NSM),
+    ([0x00, 0x09, 0x3A], [0x00, 0x09, 0x3A], NSM),
+    ([0x00, 0x09, 0x3C], [0x00, 0x09, 0x3C], NSM),
+    ([0x00, 0x09, 0x41], [0x00, 0x09, 0x48], NSM),
+    ([0x00, 0x09, 0x4D], [0x00, 0x09, 0x4D], NSM),
+    ([0x00, 0x09, 0x51], [0x00, 0x09, 0x57], NSM),
+    ([0x00, 0x09, 0x62], [0x00, 0x09, 0x63], NSM),
+    ([0x00, 0x09, 0x81], [0x00, 0x09, 0x81], NSM),
+    ([0x00, 0x09, 0xBC], [0x00, 0x09, 0xBC], NSM),
+    ([0x00, 0x09, 0xC1], [0x00, 0x09, 0xC4], NSM),
+    ([0x00, 0x09, 0xCD], [0x00, 0x09, 0xCD], NSM),
+    ([0x00, 0x09, 0xE2], [0x00, 0x09, 0xE3], NSM),
+    ([0x00, 0x09, 0xF2], [0x00, 0x09, 0xF3], ET),
+    ([0x00, 0x09, 0xFB], [0x00, 0x09, 0xFB], ET),
+    ([0x00, 0x09, 0xFE], [0x00, 0x09, 0xFE], NSM),
+    ([0x00, 0x0A, 0x01], [0x00, 0x0A, 0x02], NSM),
+    ([0x00, 0x0A, 0x3C], [0x00, 0x0A, 0x3C], NSM),
+    ([0x00, 0x0A, 0x41], [0x00, 0x0A, 0x42], NSM),
+    ([0x00, 0x0A, 0x47], [0x00, 0x0A, 0x48], NSM),
+    ([0x00, 0x0A, 0x4B], [0x00, 0x0A, 0x4D], NSM),
+    ([0x00, 0x0A, 0x51], [0x00, 0x0A, 0x51], NSM),
+    ([0x00, 0x0A, 0x70], [0x00, 0x0A, 0x71], NSM),
+    ([0x00, 0x0A, 0x75], [0x00, 0x0A, 0x75], NSM),
+    ([0x00, 0x0A, 0x81], [0x00, 0x0A, 0x82], NSM),
+    ([0x00, 0x0A, 0xBC], [0x00, 0x0A, 0xBC], NSM),
+    ([0x00, 0x0A, 0xC1], [0x00, 0x0A, 0xC5], NSM),
+    ([0x00, 0x0A, 0xC7], [0x00, 0x0A, 0xC8], NSM),
+    ([0x00, 0x0A, 0xCD], [0x00, 0x0A, 0xCD], NSM),
+    ([0x00, 0x0A, 0xE2], [0x00, 0x0A, 0xE3], NSM),
+    ([0x00, 0x0A, 0xF1], [0x00, 0x0A, 0xF1], ET),
+    ([0x00, 0x0A, 0xFA], [0x00, 0x0A, 0xFF], NSM),
+    ([0x00, 0x0B, 0x01], [0x00, 0x0B, 0x01], NSM),
+    ([0x00, 0x0B, 0x3C], [0x00, 0x0B, 0x3C], NSM),
+    ([0x00, 0x0B, 0x3F], [0x00, 0x0B, 0x3F], NSM),
+    ([0x00, 0x0B, 0x41], [0x00, 0x0B, 0x44], NSM),
+    ([0x00, 0x0B, 0x4D], [0x00, 0x0B, 0x4D], NSM),
+    ([0x00, 0x0B, 0x55], [0x00, 0x0B, 0x56], NSM),
+    ([0x00, 0x0B, 0x62], [0x00, 0x0B, 0x63], NSM),
+    ([0x00, 0x0B, 0x82], [0x00, 0x0B, 0x82], NSM),
+    ([0x00, 0x0B, 0xC0], [0x00, 0x0B, 0xC0], NSM),
+    ([0x00, 0x0B, 0xCD], [0x00, 0x0B, 0xCD], NSM),
+    ([0x00, 0x0B, 0xF3], [0x00, 0x0B, 0xF8], ON),
+    ([0x00, 0x0B, 0xF9], [0x00, 0x0B, 0xF9], ET),
+    ([0x00, 0x0B, 0xFA], [0x00, 0x0B, 0xFA], ON),
+    ([0x00, 0x0C, 0x00], [0x00, 0x0C, 0x00], NSM),
+    ([0x00, 0x0C, 0x04], [0x00, 0x0C, 0x04], NSM),
+    ([0x00, 0x0C, 0x3C], [0x00, 0x0C, 0x3C], NSM),
+    ([0x00, 0x0C, 0x3E], [0x00, 0x0C, 0x40], NSM),
+    ([0x00, 0x0C, 0x46], [0x00, 0x0C, 0x48], NSM),
+    ([0x00, 0x0C, 0x4A], [0x00, 0x0C, 0x4D], NSM),
+    ([0x00, 0x0C, 0x55], [0x00, 0x0C, 0x56], NSM),
+    ([0x00, 0x0C, 0x62], [0x00, 0x0C, 0x63], NSM),
+    ([0x00, 0x0C, 0x78], [0x00, 0x0C, 0x7E], ON),
+    ([0x00, 0x0C, 0x81], [0x00, 0x0C, 0x81], NSM),
+    ([0x00, 0x0C, 0xBC], [0x00, 0x0C, 0xBC], NSM),
+    ([0x00, 0x0C, 0xCC], [0x00, 0x0C, 0xCD], NSM),
+    ([0x00, 0x0C, 0xE2], [0x00, 0x0C, 0xE3], NSM),
+    ([0x00, 0x0D, 0x00], [0x00, 0x0D, 0x01], NSM),
+    ([0x00, 0x0D, 0x3B], [0x00, 0x0D, 0x3C], NSM),
+    ([0x00, 0x0D, 0x41], [0x00, 0x0D, 0x44], NSM),
+    ([0x00, 0x0D, 0x4D], [0x00, 0x0D, 0x4D], NSM),
+    ([0x00, 0x0D, 0x62], [0x00, 0x0D, 0x63], NSM),
+    ([0x00, 0x0D, 0x81], [0x00, 0x0D, 0x81], NSM),
+    ([0x00, 0x0D, 0xCA], [0x00, 0x0D, 0xCA], NSM),
+    ([0x00, 0x0D, 0xD2], [0x00, 0x0D, 0xD4], NSM),
+    ([0x00, 0x0D, 0xD6], [0x00, 0x0D, 0xD6], NSM),
+    ([0x00, 0x0E, 0x31], [0x00, 0x0E, 0x31], NSM),
+    ([0x00, 0x0E, 0x34], [0x00, 0x0E, 0x3A], NSM),
+    ([0x00, 0x0E, 0x3F], [0x00, 0x0E, 0x3F], ET),
+    ([0x00, 0x0E, 0x47], [0x00, 0x0E, 0x4E], NSM),
+    ([0x00, 0x0E, 0xB1], [0x00, 0x0E, 0xB1], NSM),
+    ([0x00, 0x0E, 0xB4], [0x00, 0x0E, 0xBC], NSM),
+    ([0x00, 0x0E, 0xC8], [0x00, 0x0E, 0xCD], NSM),
+    ([0x00, 0x0F, 0x18], [0x00, 0x0F, 0x19], NSM),
+    ([0x00, 0x0F, 0x35], [0x00, 0x0F, 0x35], NSM),
+    ([0x00, 0x0F, 0x37], [0x00, 0x0F, 0x37], NSM),
+    ([0x00, 0x0F, 0x39], [0x00, 0x0F, 0x39], NSM),
+    ([0x00, 0x0F, 0x3A], [0x00, 0x0F, 0x3D], ON),
+    ([0x00, 0x0F, 0x71], [0x00, 0x0F, 0x7E], NSM),
+    ([0x00, 0x0F, 0x80], [0x00, 0x0F, 0x84], NSM),
+    ([0x00, 0x0F, 0x86], [0x00, 0x0F, 0x87], NSM),
+    ([0x00, 0x0F, 0x8D], [0x00, 0x0F, 0x97], NSM),
+    ([0x00, 0x0F, 0x99], [0x00, 0x0F, 0xBC], NSM),
+    ([0x00, 0x0F, 0xC6], [0x00, 0x0F, 0xC6], NSM),
+    ([0x00, 0x10, 0x2D], [0x00, 0x10, 0x30], NSM),
+    ([0x00, 0x10, 0x32], [0x00, 0x10, 0x37], NSM),
+    ([0x00, 0x10, 0x39], [0x00, 0x10, 0x3A], NSM),
+    ([0x00, 0x10, 0x3D], [0x00, 0x10, 0x3E], NSM),
+    ([0x00, 0x10, 0x58], [0x00, 0x10, 0x59], NSM),
+    ([0x00, 0x10, 0x5E], [0x00, 0x10, 0x60], NSM),
+    ([0x00, 0x10, 0x71], [0x00, 0x10, 0x74], NSM),
+    ([0x00, 0x10, 0x82], [0x00, 0x10, 0x82], NSM),
+    ([0x00, 0x10, 0x85], [0x00, 0x10, 0x86], NSM),
+    ([0x00, 0x10, 0x8D], [0x00, 0x10, 0x8D], NSM),
+    ([0x00, 0x10, 0x9D], [0x00, 0x10, 0x9D], NSM),
+    ([0x00, 0x13, 0x5D], [0x00, 0x13, 0x5F], NSM),
+    ([0x00, 0x13, 0x90], [0x00, 0x13, 0x99], ON),
+    ([0x00, 0x14, 0x00], [0x00, 0x14, 0x00], ON),
+    ([0x00, 0x16, 0x80], [0x00, 0x16, 0x80], WS),
+    ([0x00, 0x16, 0x9B], [0x00, 0x16, 0x9C], ON),
+    ([0x00, 0x17, 0x12], [0x00, 0x17, 0x14], NSM),
+    ([0x00, 0x17, 0x32], [0x00, 0x17, 0x33], NSM),
+    ([0x00, 0x17, 0x52], [0x00, 0x17, 0x53], NSM),
+    ([0x00, 0x17, 0x72], [0x00, 0x17, 0x73], NSM),
+    ([0x00, 0x17, 0xB4], [0x00, 0x17, 0xB5], NSM),
+    ([0x00, 0x17, 0xB7], [0x00, 0x17, 0xBD], NSM),
+    ([0x00, 0x17, 0xC6], [0x00, 0x17, 0xC6], NSM),
+    ([0x00, 0x17, 0xC9], [0x00, 0x17, 0xD3], NSM),
+    ([0x00, 0x17, 0xDB], [0x00, 0x17, 0xDB], ET),
+    ([0x00, 0x17, 0xDD], [0x00, 0x17, 0xDD], NSM),
+    ([0x00, 0x17, 0xF0], [0x00, 0x17, 0xF9], ON),
+    ([0x00, 0x18, 0x00], [0x00, 0x18, 0x0A], ON),
+    ([0x00, 0x18, 0x0B], [0x00, 0x18, 0x0D], NSM),
+    ([0x00, 0x18, 0x0E], [0x00, 0x18, 0x0E], BN),
+    ([0x00, 0x18, 0x0F], [0x00, 0x18, 0x0F], NSM),
+    ([0x00, 0x18, 0x85], [0x00, 0x18, 0x86], NSM),
+    ([0x00, 0x18, 0xA9], [0x00, 0x18, 0xA9], NSM),
+    ([0x00, 0x19, 0x20], [0x00, 0x19, 0x22], NSM),
+    ([0x00, 0x19, 0x27], [0x00, 0x19, 0x28], NSM),
+    ([0x00, 0x19, 0x32], [0x00, 0x19, 0x32], NSM),
+    ([0x00, 0x19, 0x39], [0x00, 0x19, 0x3B], NSM),
+    ([0x00, 0x19, 0x40], [0x00, 0x19, 0x40], ON),
+    ([0x00, 0x19, 0x44], [0x00, 0x19, 0x45], ON),
+    ([0x00, 0x19, 0xDE], [0x00, 0x19, 0xFF], ON),
+    ([0x00, 0x1A, 0x17], [0x00, 0x1A, 0x18], NSM),
+    ([0x00, 0x1A, 0x1B], [0x00, 0x1A, 0x1B], NSM),
+    ([0x00, 0x1A, 0x56], [0x00, 0x1A, 0x56], NSM),
+    ([0x00, 0x1A, 0x58], [0x00, 0x1A, 0x5E], NSM),
+    ([0x00, 0x1A, 0x60], [0x00, 0x1A, 0x60], NSM),
+    ([0x00, 0x1A, 0x62], [0x00, 0x1A, 0x62], NSM),
+    ([0x00, 0x1A, 0x65], [0x00, 0x1A, 0x6C], NSM),
+    ([0x00, 0x1A, 0x73], [0x00, 0x1A, 0x7C], NSM),
+    ([0x00, 0x1A, 0x7F], [0x00, 0x1A, 0x7F], NSM),
+    ([0x00, 0x1A, 0xB0], [0x00, 0x1A, 0xCE], NSM),
+    ([0x00, 0x1B, 0x00], [0x00, 0x1B, 0x03], NSM),
+    ([0x00, 0x1B, 0x34], [0x00, 0x1B, 0x34], NSM),
+    ([0x00, 0x1B, 0x36], [0x00, 0x1B, 0x3A], NSM),
+    ([0x00, 0x1B, 0x3C], [0x00, 0x1B, 0x3C], NSM),
+    ([0x00, 0x1B, 0x42], [0x00, 0x1B, 0x42], NSM),
+    ([0x00, 0x1B, 0x6B], [0x00, 0x1B, 0x73], NSM),
+    ([0x00, 0x1B, 0x80], [0x00, 0x1B, 0x81], NSM),
+    ([0x00, 0x1B, 0xA2], [0x00, 0x1B, 0xA5], NSM),
+    ([0x00, 0x1B, 0xA8], [0x00, 0x1B, 0xA9], NSM),
+    ([0x00, 0x1B, 0xAB], [0x00, 0x1B, 0xAD], NSM),
+    ([0x00, 0x1B, 0xE6], [0x00, 0x1B, 0xE6], NSM),
+    ([0x00, 0x1B, 0xE8], [0x00, 0x1B, 0xE9], NSM),
+    ([0x00, 0x1B, 0xED], [0x00, 0x1B, 0xED], NSM),
+    ([0x00, 0x1B, 0xEF], [0x00, 0x1B, 0xF1], NSM),
+    ([0x00, 0x1C, 0x2C], [0x00, 0x1C, 0x33], NSM),
+    ([0x00, 0x1C, 0x36], [0x00, 0x1C, 0x37], NSM),
+    ([0x00, 0x1C, 0xD0], [0x00, 0x1C, 0xD2], NSM),
+    ([0x00, 0x1C, 0xD4], [0x00, 0x1C, 0xE0], NSM),
+    ([0x00, 0x1C, 0xE2], [0x00, 0x1C, 0xE8], NSM),
+    ([0x00, 0x1C, 0xED], [0x00, 0x1C, 0xED], NSM),
+    ([0x00, 0x1C, 0xF4], [0x00, 0x1C, 0xF4], NSM),
+    ([0x00, 0x1C, 0xF8], [0x00, 0x1C, 0xF9], NSM),
+    ([0x00, 0x1D, 0xC0], [0x00, 0x1D, 0xFF], NSM),
+    ([0x00, 0x1F, 0xBD], [0x00, 0x1F, 0xBD], ON),
+    ([0x00, 0x1F, 0xBF], [0x00, 0x1F, 0xC1], ON),
+    ([0x00, 0x1F, 0xCD], [0x00, 0x1F, 0xCF], ON),
+    ([0x00, 0x1F, 0xDD], [0x00, 0x1F, 0xDF], ON),
+    ([0x00, 0x1F, 0xED], [0x00, 0x1F, 0xEF], ON),
+    ([0x00, 0x1F, 0xFD], [0x00, 0x1F, 0xFE], ON),
+    ([0x00, 0x20, 0x00], [0x00, 0x20, 0x0A], WS),
+    ([0x00, 0x20, 0x0B], [0x00, 0x20, 0x0D], BN),
+    ([0x00, 0x20, 0x0F], [0x00, 0x20, 0x0F], R),
+    ([0x00, 0x20, 0x10], [0x00, 0x20, 0x27], ON),
+    ([0x00, 0x20, 0x28], [0x00, 0x20, 0x28], WS),
+    ([0x00, 0x20, 0x29], [0x00, 0x20, 0x29], B),
+    ([0x00, 0x20, 0x2A], [0x00, 0x20, 0x2A], LRE),
+    ([0x00, 0x20, 0x2B], [0x00, 0x20, 0x2B], RLE),
+    ([0x00, 0x20, 0x2C], [0x00, 0x20, 0x2C], PDF),
+    ([0x00, 0x20, 0x2D], [0x00, 0x20, 0x2D], LRO),
+    ([0x00, 0x20, 0x2E], [0x00, 0x20, 0x2E], RLO),
+    ([0x00, 0x20, 0x2F], [0x00, 0x20, 0x2F], CS),
+    ([0x00, 0x20, 0x30], [0x00, 0x20, 0x34], ET),
+    ([0x00, 0x20, 0x35], [0x00, 0x20, 0x43], ON),
+    ([0x00, 0x20, 0x44], [0x00, 0x20, 0x44], CS),
+    ([0x00, 0x20, 0x45], [0x00, 0x20, 0x5E], ON),
+    ([0x00, 0x20, 0x5F], [0x00, 0x20, 0x5F], WS),
+    ([0x00, 0x20, 0x60], [0x00, 0x20, 0x64], BN),
+    ([0x00, 0x20, 0x66], [0x00, 0x20, 0x66], LRI),
+    ([0x00, 0x20, 0x67], [0x00, 0x20, 0x67], RLI),
+    ([0x00, 0x20, 0x68], [0x00, 0x20, 0x68], FSI),
+    ([0x00, 0x20, 0x69], [0x00, 0x20, 0x69], PDI),
+    ([0x00, 0x20, 0x6A], [0x00, 0x20, 0x6F], BN),
+    ([0x00, 0x20, 0x70], [0x00, 0x20, 0x70], EN),
+    ([0x00, 0x20, 0x74], [0x00, 0x20, 0x79], EN),
+    ([0x00, 0x20, 0x7A], [0x00, 0x20, 0x7B], ES),
+    ([0x00, 0x20, 0x7C], [0x00, 0x20, 0x7E], ON),
+    ([0x00, 0x20, 0x80], [0x00, 0x20, 0x89], EN),
+    ([0x00, 0x20, 0x8A], [0x00, 0x20, 0x8B], ES),
+    ([0x00, 0x20, 0x8C], [0x00, 0x20, 0x8E], ON),
+    ([0x00, 0x20, 0xA0], [0x00, 0x20, 0xCF], ET),
+    ([0x00, 0x20, 0xD0], [0x00, 0x20, 0xF0], NSM),
+    ([0x00, 0x21, 0x00], [0x00, 0x21, 0x01], ON),
+    ([0x00, 0x21, 0x03], [0x00, 0x21, 0x06], ON),
+    ([0x00, 0x21, 0x08], [0x00, 0x21, 0x09], ON),
+    ([0x00, 0x21, 0x14], [0x00, 0x21, 0x14], ON),
+    ([0x00, 0x21, 0x16], [0x00, 0x21, 0x18], ON),
+    ([0x00, 0x21, 0x1E], [0x00, 0x21, 0x23], ON),
+    ([0x00, 0x21, 0x25], [0x00, 0x21, 0x25], ON),
+    ([0x00, 0x21, 0x27], [0x00, 0x21, 0x27], ON),
+    ([0x00, 0x21, 0x29], [0x00, 0x21, 0x29], ON),
+    ([0x00, 0x21, 0x2E], [0x00, 0x21, 0x2E], ET),
+    ([0x00, 0x21, 0x3A], [0x00, 0x21, 0x3B], ON),
+    ([0x00, 0x21, 0x40], [0x00, 0x21, 0x44], ON),
+    ([0x00, 0x21, 0x4A], [0x00, 0x21, 0x4D], ON),
+    ([0x00, 0x21, 0x50], [0x00, 0x21, 0x5F], ON),
+    ([0x00, 0x21, 0x89], [0x00, 0x21, 0x8B], ON),
+    ([0x00, 0x21, 0x90], [0x00, 0x22, 0x11], ON),
+    ([0x00, 0x22, 0x12], [0x00, 0x22, 0x12], ES),
+    ([0x00, 0x22, 0x13], [0x00, 0x22, 0x13], ET),
+    ([0x00, 0x22, 0x14], [0x00, 0x23, 0x35], ON),
+    ([0x00, 0x23, 0x7B], [0x00, 0x23, 0x94], ON),
+    ([0x00, 0x23, 0x96], [0x00, 0x24, 0x26], ON),
+    ([0x00, 0x24, 0x40], [0x00, 0x24, 0x4A], ON),
+    ([0x00, 0x24, 0x60], [0x00, 0x24, 0x87], ON),
+    ([0x00, 0x24, 0x88], [0x00, 0x24, 0x9B], EN),
+    ([0x00, 0x24, 0xEA], [0x00, 0x26, 0xAB], ON),
+    ([0x00, 0x26, 0xAD], [0x00, 0x27, 0xFF], ON),
+    ([0x00, 0x29, 0x00], [0x00, 0x2B, 0x73], ON),
+    ([0x00, 0x2B, 0x76], [0x00, 0x2B, 0x95], ON),
+    ([0x00, 0x2B, 0x97], [0x00, 0x2B, 0xFF], ON),
+    ([0x00, 0x2C, 0xE5], [0x00, 0x2C, 0xEA], ON),
+    ([0x00, 0x2C, 0xEF], [0x00, 0x2C, 0xF1], NSM),
+    ([0x00, 0x2C, 0xF9], [0x00, 0x2C, 0xFF], ON),
+    ([0x00, 0x2D, 0x7F], [0x00, 0x2D, 0x7F], NSM),
+    ([0x00, 0x2D, 0xE0], [0x00, 0x2D, 0xFF], NSM),
+    ([0x00, 0x2E, 0x00], [0x00, 0x2E, 0x5D], ON),
+    ([0x00, 0x2E, 0x80], [0x00, 0x2E, 0x99], ON),
+    ([0x00, 0x2E, 0x9B], [0x00, 0x2E, 0xF3], ON),
+    ([0x00, 0x2F, 0x00], [0x00, 0x2F, 0xD5], ON),
+    ([0x00, 0x2F, 0xF0], [0x00, 0x2F, 0xFB], ON),
+    ([0x00, 0x30, 0x00], [0x00, 0x30, 0x00], WS),
+    ([0x00, 0x30, 0x01], [0x00, 0x30, 0x04], ON),
+    ([0x00, 0x30, 0x08], [0x00, 0x30, 0x20], ON),
+    ([0x00, 0x30, 0x2A], [0x00, 0x30, 0x2D], NSM),
+    ([0x00, 0x30, 0x30], [0x00, 0x30, 0x30], ON),
+    ([0x00, 0x30, 0x36], [0x00, 0x30, 0x37], ON),
+    ([0x00, 0x30, 0x3D], [0x00, 0x30, 0x3F], ON),
+    ([0x00, 0x30, 0x99], [0x00, 0x30, 0x9A], NSM),
+    ([0x00, 0x30, 0x9B], [0x00, 0x30, 0x9C], ON),
+    ([0x00, 0x30, 0xA0], [0x00, 0x30, 0xA0], ON),
+    ([0x00, 0x30, 0xFB], [0x00, 0x30, 0xFB], ON),
+    ([0x00, 0x31, 0xC0], [0x00, 0x31, 0xE3], ON),
+    ([0x00, 0x32, 0x1D], [0x00, 0x32, 0x1E], ON),
+    ([0x00, 0x32, 0x50], [0x00, 0x32, 0x5F], ON),
+    ([0x00, 0x32, 0x7C], [0x00, 0x32, 0x7E], ON),
+    ([0x00, 0x32, 0xB1], [0x00, 0x32, 0xBF], ON),
+    ([0x00, 0x32, 0xCC], [0x00, 0x32, 0xCF], ON),
+    ([0x00, 0x33, 0x77], [0x00, 0x33, 0x7A], ON),
+    ([0x00, 0x33, 0xDE], [0x00, 0x33, 0xDF], ON),
+    ([0x00, 0x33, 0xFF], [0x00, 0x33, 0xFF], ON),
+    ([0x00, 0x4D, 0xC0], [0x00, 0x4D, 0xFF], ON),
+    ([0x00, 0xA4, 0x90], [0x00, 0xA4, 0xC6], ON),
+    ([0x00, 0xA6, 0x0D], [0x00, 0xA6, 0x0F], ON),
+    ([0x00, 0xA6, 0x6F], [0x00, 0xA6, 0x72], NSM),
+    ([0x00, 0xA6, 0x73], [0x00, 0xA6, 0x73], ON),
+    ([0x00, 0xA6, 0x74], [0x00, 0xA6, 0x7D], NSM),
+    ([0x00, 0xA6, 0x7E], [0x00, 0xA6, 0x7F], ON),
+    ([0x00, 0xA6, 0x9E], [0x00, 0xA6, 0x9F], NSM),
+    ([0x00, 0xA6, 0xF0], [0x00, 0xA6, 0xF1], NSM),
+    ([0x00, 0xA7, 0x00], [0x00, 0xA7, 0x21], ON),
+    ([0x00, 0xA7, 0x88], [0x00, 0xA7, 0x88], ON),
+    ([0x00, 0xA8, 0x02], [0x00, 0xA8, 0x02], NSM),
+    ([0x00, 0xA8, 0x06], [0x00, 0xA8, 0x06], NSM),
+    ([0x00, 0xA8, 0x0B], [0x00, 0xA8, 0x0B], NSM),
+    ([0x00, 0xA8, 0x25], [0x00, 0xA8, 0x26], NSM),
+    ([0x00, 0xA8, 0x28], [0x00, 0xA8, 0x2B], ON),
+    ([0x00, 0xA8, 0x2C], [0x00, 0xA8, 0x2C], NSM),
+    ([0x00, 0xA8, 0x38], [0x00, 0xA8, 0x39], ET),
+    ([0x00, 0xA8, 0x74], [0x00, 0xA8, 0x77], ON),
+    ([0x00, 0xA8, 0xC4], [0x00, 0xA8, 0xC5], NSM),
+    ([0x00, 0xA8, 0xE0], [0x00, 0xA8, 0xF1], NSM),
+    ([0x00, 0xA8, 0xFF], [0x00, 0xA8, 0xFF], NSM),
+    ([0x00, 0xA9, 0x26], [0x00, 0xA9, 0x2D], NSM),
+    ([0x00, 0xA9, 0x47], [0x00, 0xA9, 0x51], NSM),
+    ([0x00, 0xA9, 0x80], [0x00, 0xA9, 0x82], NSM),
+    ([0x00, 0xA9, 0xB3], [0x00, 0xA9, 0xB3], NSM),
+    ([0x00, 0xA9, 0xB6], [0x00, 0xA9, 0xB9], NSM),
+    ([0x00, 0xA9, 0xBC], [0x00, 0xA9, 0xBD], NSM),
+    ([0x00, 0xA9, 0xE5], [0x00, 0xA9, 0xE5], NSM),
+    ([0x00, 0xAA, 0x29], [0x00, 0xAA, 0x2E], NSM),
+    ([0x00, 0xAA, 0x31], [0x00, 0xAA, 0x32], NSM),
+    ([0x00, 0xAA, 0x35], [0x00, 0xAA, 0x36], NSM),
+    ([0x00, 0xAA, 0x43], [0x00, 0xAA, 0x43], NSM),
+    ([0x00, 0xAA, 0x4C], [0x00, 0xAA, 0x4C], NSM),
+    ([0x00, 0xAA, 0x7C], [0x00, 0xAA, 0x7C], NSM),
+    ([0x00, 0xAA, 0xB0], [0x00, 0xAA, 0xB0], NSM),
+    ([0x00, 0xAA, 0xB2], [0x00, 0xAA, 0xB4], NSM),
+    ([0x00, 0xAA, 0xB7], [0x00, 0xAA, 0xB8], NSM),
+    ([0x00, 0xAA, 0xBE], [0x00, 0xAA, 0xBF], NSM),
+    ([0x00, 0xAA, 0xC1], [0x00, 0xAA, 0xC1], NSM),
+    ([0x00, 0xAA, 0xEC], [0x00, 0xAA, 0xED], NSM),
+    ([0x00, 0xAA, 0xF6], [0x00, 0xAA, 0xF6], NSM),
+    ([0x00, 0xAB, 0x6A], [0x00, 0xAB, 0x6B], ON),
+    ([0x00, 0xAB, 0xE5], [0x00, 0xAB, 0xE5], NSM),
+    ([0x00, 0xAB, 0xE8], [0x00, 0xAB, 0xE8], NSM),
+    ([0x00, 0xAB, 0xED], [0x00, 0xAB, 0xED], NSM),
+    ([0x00, 0xFB, 0x1D], [0x00, 0xFB, 0x1D], R),
+    ([0x00, 0xFB, 0x1E], [0x00, 0xFB, 0x1E], NSM),
+    ([0x00, 0xFB, 0x1F], [0x00, 0xFB, 0x28], R),
+    ([0x00, 0xFB, 0x29], [0x00, 0xFB, 0x29], ES),
+    ([0x00, 0xFB, 0x2A], [0x00, 0xFB, 0x4F], R),
+    ([0x00, 0xFB, 0x50], [0x00, 0xFD, 0x3D], AL),
+    ([0x00, 0xFD, 0x3E], [0x00, 0xFD, 0x4F], ON),
+    ([0x00, 0xFD, 0x50], [0x00, 0xFD, 0xCE], AL),
+    ([0x00, 0xFD, 0xCF], [0x00, 0xFD, 0xCF], ON),
+    ([0x00, 0xFD, 0xD0], [0x00, 0xFD, 0xEF], BN),
+    ([0x00, 0xFD, 0xF0], [0x00, 0xFD, 0xFC], AL),
+    ([0x00, 0xFD, 0xFD], [0x00, 0xFD, 0xFF], ON),
+    ([0x00, 0xFE, 0x00], [0x00, 0xFE, 0x0F], NSM),
+    ([0x00, 0xFE, 0x10], [0x00, 0xFE, 0x19], ON),
+    ([0x00, 0xFE, 0x20], [0x00, 0xFE, 0x2F], NSM),
+    ([0x00, 0xFE, 0x30], [0x00, 0xFE, 0x4F], ON),
+    ([0x00, 0xFE, 0x50], [0x00, 0xFE, 0x50], CS),
+    ([0x00, 0xFE, 0x51], [0x00, 0xFE, 0x51], ON),
+    ([0x00, 0xFE, 0x52], [0x00, 0xFE, 0x52], CS),
+    ([0x00, 0xFE, 0x54], [0x00, 0xFE, 0x54], ON),
+    ([0x00, 0xFE, 0x55], [0x00, 0xFE, 0x55], CS),
+    ([0x00, 0xFE, 0x56], [0x00, 0xFE, 0x5E], ON),
+    ([0x00, 0xFE, 0x5F], [0x00, 0xFE, 0x5F], ET),
+    ([0x00, 0xFE, 0x60], [0x00, 0xFE, 0x61], ON),
+    ([0x00, 0xFE, 0x62], [0x00, 0xFE, 0x63], ES),
+    ([0x00, 0xFE, 0x64], [0x00, 0xFE, 0x66], ON),
+    ([0x00, 0xFE, 0x68], [0x00, 0xFE, 0x68], ON),
+    ([0x00, 0xFE, 0x69], [0x00, 0xFE, 0x6A], ET),
+    ([0x00, 0xFE, 0x6B], [0x00, 0xFE, 0x6B], ON),
+    ([0x00, 0xFE, 0x70], [0x00, 0xFE, 0xFE], AL),
+    ([0x00, 0xFE, 0xFF], [0x00, 0xFE, 0xFF], BN),
+    ([0x00, 0xFF, 0x01], [0x00, 0xFF, 0x02], ON),
+    ([0x00, 0xFF, 0x03], [0x00, 0xFF, 0x05], ET),
+    ([0x00, 0xFF, 0x06], [0x00, 0xFF, 0x0A], ON),
+    ([0x00, 0xFF, 0x0B], [0x00, 0xFF, 0x0B], ES),
+    ([0x00, 0xFF, 0x0C], [0x00, 0xFF, 0x0C], CS),
+    ([0x00, 0xFF, 0x0D], [0x00, 0xFF, 0x0D], ES),
+    ([0x00, 0xFF, 0x0E], [0x00, 0xFF, 0x0F], CS),
+    ([0x00, 0xFF, 0x10], [0x00, 0xFF, 0x19], EN),
+    ([0x00, 0xFF, 0x1A], [0x00, 0xFF, 0x1A], CS),
+    ([0x00, 0xFF, 0x1B], [0x00, 0xFF, 0x20], ON),
+    ([0x00, 0xFF, 0x3B], [0x00, 0xFF, 0x40], ON),
+    ([0x00, 0xFF, 0x5B], [0x00, 0xFF, 0x65], ON),
+    ([0x00, 0xFF, 0xE0], [0x00, 0xFF, 0xE1], ET),
+    ([0x00, 0xFF, 0xE2], [0x00, 0xFF, 0xE4], ON),
+    ([0x00, 0xFF, 0xE5], [0x00, 0xFF, 0xE6], ET),
+    ([0x00, 0xFF, 0xE8], [0x00, 0xFF, 0xEE], ON),
+    ([0x00, 0xFF, 0xF9], [0x00, 0xFF, 0xFD], ON),
+    ([0x00, 0xFF, 0xFE], [0x00, 0xFF, 0xFF], BN),
+    ([0x01, 0x01, 0x01], [0x01, 0x01, 0x01], ON),
+    ([0x01, 0x01, 0x40], [0x01, 0x01, 0x8C], ON),
+    ([0x01, 0x01, 0x90], [0x01, 0x01, 0x9C], ON),
+    ([0x01, 0x01, 0xA0], [0x01, 0x01, 0xA0], ON),
+    ([0x01, 0x01, 0xFD], [0x01, 0x01, 0xFD], NSM),
+    ([0x01, 0x02, 0xE0], [0x01, 0x02, 0xE0], NSM),
+    ([0x01, 0x02, 0xE1], [0x01, 0x02, 0xFB], EN),
+    ([0x01, 0x03, 0x76], [0x01, 0x03, 0x7A], NSM),
+    ([0x01, 0x08, 0x00], [0x01, 0x09, 0x1E], R),
+    ([0x01, 0x09, 0x1F], [0x01, 0x09, 0x1F], ON),
+    ([0x01, 0x09, 0x20], [0x01, 0x0A, 0x00], R),
+    ([0x01, 0x0A, 0x01], [0x01, 0x0A, 0x03], NSM),
+    ([0x01, 0x0A, 0x04], [0x01, 0x0A, 0x04], R),
+    ([0x01, 0x0A, 0x05], [0x01, 0x0A, 0x06], NSM),
+    ([0x01, 0x0A, 0x07], [0x01, 0x0A, 0x0B], R),
+    ([0x01, 0x0A, 0x0C], [0x01, 0x0A, 0x0F], NSM),
+    ([0x01, 0x0A, 0x10], [0x01, 0x0A, 0x37], R),
+    ([0x01, 0x0A, 0x38], [0x01, 0x0A, 0x3A], NSM),
+    ([0x01, 0x0A, 0x3B], [0x01, 0x0A, 0x3E], R),
+    ([0x01, 0x0A, 0x3F], [0x01, 0x0A, 0x3F], NSM),
+    ([0x01, 0x0A, 0x40], [0x01, 0x0A, 0xE4], R),
+    ([0x01, 0x0A, 0xE5], [0x01, 0x0A, 0xE6], NSM),
+    ([0x01, 0x0A, 0xE7], [0x01, 0x0B, 0x38], R),
+    ([0x01, 0x0B, 0x39], [0x01, 0x0B, 0x3F], ON),
+    ([0x01, 0x0B, 0x40], [0x01, 0x0C, 0xFF], R),
+    ([0x01, 0x0D, 0x00], [0x01, 0x0D, 0x23], AL),
+    ([0x01, 0x0D, 0x24], [0x01, 0x0D, 0x27], NSM),
+    ([0x01, 0x0D, 0x28], [0x01, 0x0D, 0x2F], AL),
+    ([0x01, 0x0D, 0x30], [0x01, 0x0D, 0x39], AN),
+    ([0x01, 0x0D, 0x3A], [0x01, 0x0D, 0x3F], AL),
+    ([0x01, 0x0D, 0x40], [0x01, 0x0E, 0x5F], R),
+    ([0x01, 0x0E, 0x60], [0x01, 0x0E, 0x7E], AN),
+    ([0x01, 0x0E, 0x7F], [0x01, 0x0E, 0xAA], R),
+    ([0x01, 0x0E, 0xAB], [0x01, 0x0E, 0xAC], NSM),
+    ([0x01, 0x0E, 0xAD], [0x01, 0x0E, 0xBF], R),
+    ([0x01, 0x0E, 0xC0], [0x01, 0x0E, 0xFF], AL),
+    ([0x01, 0x0F, 0x00], [0x01, 0x0F, 0x2F], R),
+    ([0x01, 0x0F, 0x30], [0x01, 0x0F, 0x45], AL),
+    ([0x01, 0x0F, 0x46], [0x01, 0x0F, 0x50], NSM),
+    ([0x01, 0x0F, 0x51], [0x01, 0x0F, 0x6F], AL),
+    ([0x01, 0x0F, 0x70], [0x01, 0x0F, 0x81], R),
+    ([0x01, 0x0F, 0x82], [0x01, 0x0F, 0x85], NSM),
+    ([0x01, 0x0F, 0x86], [0x01, 0x0F, 0xFF], R),
+    ([0x01, 0x10, 0x01], [0x01, 0x10, 0x01], NSM),
+    ([0x01, 0x10, 0x38], [0x01, 0x10, 0x46], NSM),
+    ([0x01, 0x10, 0x52], [0x01, 0x10, 0x65], ON),
+    ([0x01, 0x10, 0x70], [0x01, 0x10, 0x70], NSM),
+    ([0x01, 0x10, 0x73], [0x01, 0x10, 0x74], NSM),
+    ([0x01, 0x10, 0x7F], [0x01, 0x10, 0x81], NSM),
+    ([0x01, 0x10, 0xB3], [0x01, 0x10, 0xB6], NSM),
+    ([0x01, 0x10, 0xB9], [0x01, 0x10, 0xBA], NSM),
+    ([0x01, 0x10, 0xC2], [0x01, 0x10, 0xC2], NSM),
+    ([0x01, 0x11, 0x00], [0x01, 0x11, 0x02], NSM),
+    ([0x01, 0x11, 0x27], [0x01, 0x11, 0x2B], NSM),
+    ([0x01, 0x11, 0x2D], [0x01, 0x11, 0x34], NSM),
+    ([0x01, 0x11, 0x73], [0x01, 0x11, 0x73], NSM),
+    ([0x01, 0x11, 0x80], [0x01, 0x11, 0x81], NSM),
+    ([0x01, 0x11, 0xB6], [0x01, 0x11, 0xBE], NSM),
+    ([0x01, 0x11, 0xC9], [0x01, 0x11, 0xCC], NSM),
+    ([0x01, 0x11, 0xCF], [0x01, 0x11, 0xCF], NSM),
+    ([0x01, 0x12, 0x2F], [0x01, 0x12, 0x31], NSM),
+    ([0x01, 0x12, 0x34], [0x01, 0x12, 0x34], NSM),
+    ([0x01, 0x12, 0x36], [0x01, 0x12, 0x37], NSM),
+    ([0x01, 0x12, 0x3E], [0x01, 0x12, 0x3E], NSM),
+    ([0x01, 0x12, 0xDF], [0x01, 0x12, 0xDF], NSM),
+    ([0x01, 0x12, 0xE3], [0x01, 0x12, 0xEA], NSM),
+    ([0x01, 0x13, 0x00], [0x01, 0x13, 0x01], NSM),
+    ([0x01, 0x13, 0x3B], [0x01, 0x13, 0x3C], NSM),
+    ([0x01, 0x13, 0x40], [0x01, 0x13, 0x40], NSM),
+    ([0x01, 0x13, 0x66], [0x01, 0x13, 0x6C], NSM),
+    ([0x01, 0x13, 0x70], [0x01, 0x13, 0x74], NSM),
+    ([0x01, 0x14, 0x38], [0x01, 0x14, 0x3F], NSM),
+    ([0x01, 0x14, 0x42], [0x01, 0x14, 0x44], NSM),
+    ([0x01, 0x14, 0x46], [0x01, 0x14, 0x46], NSM),
+    ([0x01, 0x14, 0x5E], [0x01, 0x14, 0x5E], NSM),
+    ([0x01, 0x14, 0xB3], [0x01, 0x14, 0xB8], NSM),
+    ([0x01, 0x14, 0xBA], [0x01, 0x14, 0xBA], NSM),
+    ([0x01, 0x14, 0xBF], [0x01, 0x14, 0xC0], NSM),
+    ([0x01, 0x14, 0xC2], [0x01, 0x14, 0xC3], NSM),
+    ([0x01, 0x15, 0xB2], [0x01, 0x15, 0xB5], NSM),
+    ([0x01, 0x15, 0xBC], [0x01, 0x15, 0xBD], NSM),
+    ([0x01, 0x15, 0xBF], [0x01, 0x15, 0xC0], NSM),
+    ([0x01, 0x15, 0xDC], [0x01, 0x15, 0xDD], NSM),
+    ([0x01, 0x16, 0x33], [0x01, 0x16, 0x3A], NSM),
+    ([0x01, 0x16, 0x3D], [0x01, 0x16, 0x3D], NSM),
+    ([0x01, 0x16, 0x3F], [0x01, 0x16, 0x40], NSM),
+    ([0x01, 0x16, 0x60], [0x01, 0x16, 0x6C], ON),
+    ([0x01, 0x16, 0xAB], [0x01, 0x16, 0xAB], NSM),
+    ([0x01, 0x16, 0xAD], [0x01, 0x16, 0xAD], NSM),
+    ([0x01, 0x16, 0xB0], [0x01, 0x16, 0xB5], NSM),
+    ([0x01, 0x16, 0xB7], [0x01, 0x16, 0xB7], NSM),
+    ([0x01, 0x17, 0x1D], [0x01, 0x17, 0x1F], NSM),
+    ([0x01, 0x17, 0x22], [0x01, 0x17, 0x25], NSM),
+    ([0x01, 0x17, 0x27], [0x01, 0x17, 0x2B], NSM),
+    ([0x01, 0x18, 0x2F], [0x01, 0x18, 0x37], NSM),
+    ([0x01, 0x18, 0x39], [0x01, 0x18, 0x3A], NSM),
+    ([0x01, 0x19, 0x3B], [0x01, 0x19, 0x3C], NSM),
+    ([0x01, 0x19, 0x3E], [0x01, 0x19, 0x3E], NSM),
+    ([0x01, 0x19, 0x43], [0x01, 0x19, 0x43], NSM),
+    ([0x01, 0x19, 0xD4], [0x01, 0x19, 0xD7], NSM),
+    ([0x01, 0x19, 0xDA], [0x01, 0x19, 0xDB], NSM),
+    ([0x01, 0x19, 0xE0], [0x01, 0x19, 0xE0], NSM),
+    ([0x01, 0x1A, 0x01], [0x01, 0x1A, 0x06], NSM),
+    ([0x01, 0x1A, 0x09], [0x01, 0x1A, 0x0A], NSM),
+    ([0x01, 0x1A, 0x33], [0x01, 0x1A, 0x38], NSM),
+    ([0x01, 0x1A, 0x3B], [0x01, 0x1A, 0x3E], NSM),
+    ([0x01, 0x1A, 0x47], [0x01, 0x1A, 0x47], NSM),
+    ([0x01, 0x1A, 0x51], [0x01, 0x1A, 0x56], NSM),
+    ([0x01, 0x1A, 0x59], [0x01, 0x1A, 0x5B], NSM),
+    ([0x01, 0x1A, 0x8A], [0x01, 0x1A, 0x96], NSM),
+    ([0x01, 0x1A, 0x98], [0x01, 0x1A, 0x99], NSM),
+    ([0x01, 0x1C, 0x30], [0x01, 0x1C, 0x36], NSM),
+    ([0x01, 0x1C, 0x38], [0x01, 0x1C, 0x3D], NSM),
+    ([0x01, 0x1C, 0x92], [0x01, 0x1C, 0xA7], NSM),
+    ([0x01, 0x1C, 0xAA], [0x01, 0x1C, 0xB0], NSM),
+    ([0x01, 0x1C, 0xB2], [0x01, 0x1C, 0xB3], NSM),
+    ([0x01, 0x1C, 0xB5], [0x01, 0x1C, 0xB6], NSM),
+    ([0x01, 0x1D, 0x31], [0x01, 0x1D, 0x36], NSM),
+    ([0x01, 0x1D, 0x3A], [0x01, 0x1D, 0x3A], NSM),
+    ([0x01, 0x1D, 0x3C], [0x01, 0x1D, 0x3D], NSM),
+    ([0x01, 0x1D, 0x3F], [0x01, 0x1D, 0x45], NSM),
+    ([0x01, 0x1D, 0x47], [0x01, 0x1D, 0x47], NSM),
+    ([0x01, 0x1D, 0x90], [0x01, 0x1D, 0x91], NSM),
+    ([0x01, 0x1D, 0x95], [0x01, 0x1D, 0x95], NSM),
+    ([0x01, 0x1D, 0x97], [0x01, 0x1D, 0x97], NSM),
+    ([0x01, 0x1E, 0xF3], [0x01, 0x1E, 0xF4], NSM),
+    ([0x01, 0x1F, 0xD5], [0x01, 0x1F, 0xDC], ON),
+    ([0x01, 0x1F, 0xDD], [0x01, 0x1F, 0xE0], ET),
+    ([0x01, 0x1F, 0xE1], [0x01, 0x1F, 0xF1], ON),
+    ([0x01, 0x6A, 0xF0], [0x01, 0x6A, 0xF4], NSM),
+    ([0x01, 0x6B, 0x30], [0x01, 0x6B, 0x36], NSM),
+    ([0x01, 0x6F, 0x4F], [0x01, 0x6F, 0x4F], NSM),
+    ([0x01, 0x6F, 0x8F], [0x01, 0x6F, 0x92], NSM),
+    ([0x01, 0x6F, 0xE2], [0x01, 0x6F, 0xE2], ON),
+    ([0x01, 0x6F, 0xE4], [0x01, 0x6F, 0xE4], NSM),
+    ([0x01, 0xBC, 0x9D], [0x01, 0xBC, 0x9E], NSM),
+    ([0x01, 0xBC, 0xA0], [0x01, 0xBC, 0xA3], BN),
+    ([0x01, 0xCF, 0x00], [0x01, 0xCF, 0x2D], NSM),
+    ([0x01, 0xCF, 0x30], [0x01, 0xCF, 0x46], NSM),
+    ([0x01, 0xD1, 0x67], [0x01, 0xD1, 0x69], NSM),
+    ([0x01, 0xD1, 0x73], [0x01, 0xD1, 0x7A], BN),
+    ([0x01, 0xD1, 0x7B], [0x01, 0xD1, 0x82], NSM),
+    ([0x01, 0xD1, 0x85], [0x01, 0xD1, 0x8B], NSM),
+    ([0x01, 0xD1, 0xAA], [0x01, 0xD1, 0xAD], NSM),
+    ([0x01, 0xD1, 0xE9], [0x01, 0xD1, 0xEA], ON),
+    ([0x01, 0xD2, 0x00], [0x01, 0xD2, 0x41], ON),
+    ([0x01, 0xD2, 0x42], [0x01, 0xD2, 0x44], NSM),
+    ([0x01, 0xD2, 0x45], [0x01, 0xD2, 0x45], ON),
+    ([0x01, 0xD3, 0x00], [0x01, 0xD3, 0x56], ON),
+    ([0x01, 0xD6, 0xDB], [0x01, 0xD6, 0xDB], ON),
+    ([0x01, 0xD7, 0x15], [0x01, 0xD7, 0x15], ON),
+    ([0x01, 0xD7, 0x4F], [0x01, 0xD7, 0x4F], ON),
+    ([0x01, 0xD7, 0x89], [0x01, 0xD7, 0x89], ON),
+    ([0x01, 0xD7, 0xC3], [0x01, 0xD7, 0xC3], ON),
+    ([0x01, 0xD7, 0xCE], [0x01, 0xD7, 0xFF], EN),
+    ([0x01, 0xDA, 0x00], [0x01, 0xDA, 0x36], NSM),
+    ([0x01, 0xDA, 0x3B], [0x01, 0xDA, 0x6C], NSM),
+    ([0x01, 0xDA, 0x75], [0x01, 0xDA, 0x75], NSM),
+    ([0x01, 0xDA, 0x84], [0x01, 0xDA, 0x84], NSM),
+    ([0x01, 0xDA, 0x9B], [0x01, 0xDA, 0x9F], NSM),
+    ([0x01, 0xDA, 0xA1], [0x01, 0xDA, 0xAF], NSM),
+    ([0x01, 0xE0, 0x00], [0x01, 0xE0, 0x06], NSM),
+    ([0x01, 0xE0, 0x08], [0x01, 0xE0, 0x18], NSM),
+    ([0x01, 0xE0, 0x1B], [0x01, 0xE0, 0x21], NSM),
+    ([0x01, 0xE0, 0x23], [0x01, 0xE0, 0x24], NSM),
+    ([0x01, 0xE0, 0x26], [0x01, 0xE0, 0x2A], NSM),
+    ([0x01, 0xE1, 0x30], [0x01, 0xE1, 0x36], NSM),
+    ([0x01, 0xE2, 0xAE], [0x01, 0xE2, 0xAE], NSM),
+    ([0x01, 0xE2, 0xEC], [0x01, 0xE2, 0xEF], NSM),
+    ([0x01, 0xE2, 0xFF], [0x01, 0xE2, 0xFF], ET),
+    ([0x01, 0xE8, 0x00], [0x01, 0xE8, 0xCF], R),
+    ([0x01, 0xE8, 0xD0], [0x01, 0xE8, 0xD6], NSM),
+    ([0x01, 0xE8, 0xD7], [0x01, 0xE9, 0x43], R),
+    ([0x01, 0xE9, 0x44], [0x01, 0xE9, 0x4A], NSM),
+    ([0x01, 0xE9, 0x4B], [0x01, 0xEC, 0x6F], R),
+    ([0x01, 0xEC, 0x70], [0x01, 0xEC, 0xBF], AL),
+    ([0x01, 0xEC, 0xC0], [0x01, 0xEC, 0xFF], R),
+    ([0x01, 0xED, 0x00], [0x01, 0xED, 0x4F], AL),
+    ([0x01, 0xED, 0x50], [0x01, 0xED, 0xFF], R),
+    ([0x01, 0xEE, 0x00], [0x01, 0xEE, 0xEF], AL),
+    ([0x01, 0xEE, 0xF0], [0x01, 0xEE, 0xF1], ON),
+    ([0x01, 0xEE, 0xF2], [0x01, 0xEE, 0xFF], AL),
+    ([0x01, 0xEF, 0x00], [0x01, 0xEF, 0xFF], R),
+    ([0x01, 0xF0, 0x00], [0x01, 0xF0, 0x2B], ON),
+    ([0x01, 0xF0, 0x30], [0x01, 0xF0, 0x93], ON),
+    ([0x01, 0xF0, 0xA0], [0x01, 0xF0, 0xAE], ON),
+    ([0x01, 0xF0, 0xB1], [0x01, 0xF0, 0xBF], ON),
+    ([0x01, 0xF0, 0xC1], [0x01, 0xF0, 0xCF], ON),
+    ([0x01, 0xF0, 0xD1], [0x01, 0xF0, 0xF5], ON),
+    ([0x01, 0xF1, 0x00], [0x01, 0xF1, 0x0A], EN),
+    ([0x01, 0xF1, 0x0B], [0x01, 0xF1, 0x0F], ON),
+    ([0x01, 0xF1, 0x2F], [0x01, 0xF1, 0x2F], ON),
+    ([0x01, 0xF1, 0x6A], [0x01, 0xF1, 0x6F], ON),
+    ([0x01, 0xF1, 0xAD], [0x01, 0xF1, 0xAD], ON),
+    ([0x01, 0xF2, 0x60], [0x01, 0xF2, 0x65], ON),
+    ([0x01, 0xF3, 0x00], [0x01, 0xF6, 0xD7], ON),
+    ([0x01, 0xF6, 0xDD], [0x01, 0xF6, 0xEC], ON),
+    ([0x01, 0xF6, 0xF0], [0x01, 0xF6, 0xFC], ON),
+    ([0x01, 0xF7, 0x00], [0x01, 0xF7, 0x73], ON),
+    ([0x01, 0xF7, 0x80], [0x01, 0xF7, 0xD8], ON),
+    ([0x01, 0xF7, 0xE0], [0x01, 0xF7, 0xEB], ON),
+    ([0x01, 0xF7, 0xF0], [0x01, 0xF7, 0xF0], ON),
+    ([0x01, 0xF8, 0x00], [0x01, 0xF8, 0x0B], ON),
+    ([0x01, 0xF8, 0x10], [0x01, 0xF8, 0x47], ON),
+    ([0x01, 0xF8, 0x50], [0x01, 0xF8, 0x59], ON),
+    ([0x01, 0xF8, 0x60], [0x01, 0xF8, 0x87], ON),
+    ([0x01, 0xF8, 0x90], [0x01, 0xF8, 0xAD], ON),
+    ([0x01, 0xF8, 0xB0], [0x01, 0xF8, 0xB1], ON),
+    ([0x01, 0xF9, 0x00], [0x01, 0xFA, 0x53], ON),
+    ([0x01, 0xFA, 0x60], [0x01, 0xFA, 0x6D], ON),
+    ([0x01, 0xFA, 0x70], [0x01, 0xFA, 0x74], ON),
+    ([0x01, 0xFA, 0x78], [0x01, 0xFA, 0x7C], ON),
+    ([0x01, 0xFA, 0x80], [0x01, 0xFA, 0x86], ON),
+    ([0x01, 0xFA, 0x90], [0x01, 0xFA, 0xAC], ON),
+    ([0x01, 0xFA, 0xB0], [0x01, 0xFA, 0xBA], ON),
+    ([0x01, 0xFA, 0xC0], [0x01, 0xFA, 0xC5], ON),
+    ([0x01, 0xFA, 0xD0], [0x01, 0xFA, 0xD9], ON),
+    ([0x01, 0xFA, 0xE0], [0x01, 0xFA, 0xE7], ON),
+    ([0x01, 0xFA, 0xF0], [0x01, 0xFA, 0xF6], ON),
+    ([0x01, 0xFB, 0x00], [0x01, 0xFB, 0x92], ON),
+    ([0x01, 0xFB, 0x94], [0x01, 0xFB, 0xCA], ON),
+    ([0x01, 0xFB, 0xF0], [0x01, 0xFB, 0xF9], EN),
+    ([0x01, 0xFF, 0xFE], [0x01, 0xFF, 0xFF], BN),
+    ([0x02, 0xFF, 0xFE], [0x02, 0xFF, 0xFF], BN),
+    ([0x03, 0xFF, 0xFE], [0x03, 0xFF, 0xFF], BN),
+    ([0x04, 0xFF, 0xFE], [0x04, 0xFF, 0xFF], BN),
+    ([0x05, 0xFF, 0xFE], [0x05, 0xFF, 0xFF], BN),
+    ([0x06, 0xFF, 0xFE], [0x06, 0xFF, 0xFF], BN),
+    ([0x07, 0xFF, 0xFE], [0x07, 0xFF, 0xFF], BN),
+    ([0x08, 0xFF, 0xFE], [0x08, 0xFF, 0xFF], BN),
+    ([0x09, 0xFF, 0xFE], [0x09, 0xFF, 0xFF], BN),
+    ([0x0A, 0xFF, 0xFE], [0x0A, 0xFF, 0xFF], BN),
+    ([0x0B, 0xFF, 0xFE], [0x0B, 0xFF, 0xFF], BN),
+    ([0x0C, 0xFF, 0xFE], [0x0C, 0xFF, 0xFF], BN),
+    ([0x0D, 0xFF, 0xFE], [0x0E, 0x00, 0xFF], BN),
+    ([0x0E, 0x01, 0x00], [0x0E, 0x01, 0xEF], NSM),
+    ([0x0E, 0x01, 0xF0], [0x0E, 0x0F, 0xFF], BN),
+    ([0x0E, 0xFF, 0xFE], [0x0E, 0xFF, 0xFF], BN),
+    ([0x0F, 0xFF, 0xFE], [0x0F, 0xFF, 0xFF], BN),
+    ([0x10, 0xFF, 0xFE], [0x10, 0xFF, 0xFF], BN),
+];
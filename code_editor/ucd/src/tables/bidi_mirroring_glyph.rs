@@ -0,0 +1,433 @@
+//! This file was generated by:
+//! generate_table.py Bidi_Mirroring_Glyph <ucd_dir>
+
+pub(crate) static BIDI_MIRRORING_GLYPH: [([u8; 3], [u8; 3], char); 428] = [
+    ([0x00, 0x00, 0x28], [0x00, 0x00, 0x28], '\u{0029}'),
+    ([0x00, 0x00, 0x29], [0x00, 0x00, 0x29], '\u{0028}'),
+    ([0x00, 0x00, 0x3C], [0x00, 0x00, 0x3C], '\u{003E}'),
+    ([0x00, 0x00, 0x3E], [0x00, 0x00, 0x3E], '\u{003C}'),
+    ([0x00, 0x00, 0x5B], [0x00, 0x00, 0x5B], '\u{005D}'),
+    ([0x00, 0x00, 0x5D], [0x00, 0x00, 0x5D], '\u{005B}'),
+    ([0x00, 0x00, 0x7B], [0x00, 0x00, 0x7B], '\u{007D}'),
+    ([0x00, 0x00, 0x7D], [0x00, 0x00, 0x7D], '\u{007B}'),
+    ([0x00, 0x00, 0xAB], [0x00, 0x00, 0xAB], '\u{00BB}'),
+    ([0x00, 0x00, 0xBB], [0x00, 0x00, 0xBB], '\u{00AB}'),
+    ([0x00, 0x0F, 0x3A], [0x00, 0x0F, 0x3A], '\u{0F3B}'),
+    ([0x00, 0x0F, 0x3B], [0x00, 0x0F, 0x3B], '\u{0F3A}'),
+    ([0x00, 0x0F, 0x3C], [0x00, 0x0F, 0x3C], '\u{0F3D}'),
+    ([0x00, 0x0F, 0x3D], [0x00, 0x0F, 0x3D], '\u{0F3C}'),
+    ([0x00, 0x16, 0x9B], [0x00, 0x16, 0x9B], '\u{169C}'),
+    ([0x00, 0x16, 0x9C], [0x00, 0x16, 0x9C], '\u{169B}'),
+    ([0x00, 0x20, 0x39], [0x00, 0x20, 0x39], '\u{203A}'),
+    ([0x00, 0x20, 0x3A], [0x00, 0x20, 0x3A], '\u{2039}'),
+    ([0x00, 0x20, 0x45], [0x00, 0x20, 0x45], '\u{2046}'),
+    ([0x00, 0x20, 0x46], [0x00, 0x20, 0x46], '\u{2045}'),
+    ([0x00, 0x20, 0x7D], [0x00, 0x20, 0x7D], '\u{207E}'),
+    ([0x00, 0x20, 0x7E], [0x00, 0x20, 0x7E], '\u{207D}'),
+    ([0x00, 0x20, 0x8D], [0x00, 0x20, 0x8D], '\u{208E}'),
+    ([0x00, 0x20, 0x8E], [0x00, 0x20, 0x8E], '\u{208D}'),
+    ([0x00, 0x22, 0x08], [0x00, 0x22, 0x08], '\u{220B}'),
+    ([0x00, 0x22, 0x09], [0x00, 0x22, 0x09], '\u{220C}'),
+    ([0x00, 0x22, 0x0A], [0x00, 0x22, 0x0A], '\u{220D}'),
+    ([0x00, 0x22, 0x0B], [0x00, 0x22, 0x0B], '\u{2208}'),
+    ([0x00, 0x22, 0x0C], [0x00, 0x22, 0x0C], '\u{2209}'),
+    ([0x00, 0x22, 0x0D], [0x00, 0x22, 0x0D], '\u{220A}'),
+    ([0x00, 0x22, 0x15], [0x00, 0x22, 0x15], '\u{29F5}'),
+    ([0x00, 0x22, 0x1F], [0x00, 0x22, 0x1F], '\u{2BFE}'),
+    ([0x00, 0x22, 0x20], [0x00, 0x22, 0x20], '\u{29A3}'),
+    ([0x00, 0x22, 0x21], [0x00, 0x22, 0x21], '\u{299B}'),
+    ([0x00, 0x22, 0x22], [0x00, 0x22, 0x22], '\u{29A0}'),
+    ([0x00, 0x22, 0x24], [0x00, 0x22, 0x24], '\u{2AEE}'),
+    ([0x00, 0x22, 0x3C], [0x00, 0x22, 0x3C], '\u{223D}'),
+    ([0x00, 0x22, 0x3D], [0x00, 0x22, 0x3D], '\u{223C}'),
+    ([0x00, 0x22, 0x43], [0x00, 0x22, 0x43], '\u{22CD}'),
+    ([0x00, 0x22, 0x45], [0x00, 0x22, 0x45], '\u{224C}'),
+    ([0x00, 0x22, 0x4C], [0x00, 0x22, 0x4C], '\u{2245}'),
+    ([0x00, 0x22, 0x52], [0x00, 0x22, 0x52], '\u{2253}'),
+    ([0x00, 0x22, 0x53], [0x00, 0x22, 0x53], '\u{2252}'),
+    ([0x00, 0x22, 0x54], [0x00, 0x22, 0x54], '\u{2255}'),
+    ([0x00, 0x22, 0x55], [0x00, 0x22, 0x55], '\u{2254}'),
+    ([0x00, 0x22, 0x64], [0x00, 0x22, 0x64], '\u{2265}'),
+    ([0x00, 0x22, 0x65], [0x00, 0x22, 0x65], '\u{2264}'),
+    ([0x00, 0x22, 0x66], [0x00, 0x22, 0x66], '\u{2267}'),
+    ([0x00, 0x22, 0x67], [0x00, 0x22, 0x67], '\u{2266}'),
+    ([0x00, 0x22, 0x68], [0x00, 0x22, 0x68], '\u{2269}'),
+    ([0x00, 0x22, 0x69], [0x00, 0x22, 0x69], '\u{2268}'),
+    ([0x00, 0x22, 0x6A], [0x00, 0x22, 0x6A], '\u{226B}'),
+    ([0x00, 0x22, 0x6B], [0x00, 0x22, 0x6B], '\u{226A}'),
+    ([0x00, 0x22, 0x6E], [0x00, 0x22, 0x6E], '\u{226F}'),
+    ([0x00, 0x22, 0x6F], [0x00, 0x22, 0x6F], '\u{226E}'),
+    ([0x00, 0x22, 0x70], [0x00, 0x22, 0x70], '\u{2271}'),
+    ([0x00, 0x22, 0x71], [0x00, 0x22, 0x71], '\u{2270}'),
+    ([0x00, 0x22, 0x72], [0x00, 0x22, 0x72], '\u{2273}'),
+    ([0x00, 0x22, 0x73], [0x00, 0x22, 0x73], '\u{2272}'),
+    ([0x00, 0x22, 0x74], [0x00, 0x22, 0x74], '\u{2275}'),
+    ([0x00, 0x22, 0x75], [0x00, 0x22, 0x75], '\u{2274}'),
+    ([0x00, 0x22, 0x76], [0x00, 0x22, 0x76], '\u{2277}'),
+    ([0x00, 0x22, 0x77], [0x00, 0x22, 0x77], '\u{2276}'),
+    ([0x00, 0x22, 0x78], [0x00, 0x22, 0x78], '\u{2279}'),
+    ([0x00, 0x22, 0x79], [0x00, 0x22, 0x79], '\u{2278}'),
+    ([0x00, 0x22, 0x7A], [0x00, 0x22, 0x7A], '\u{227B}'),
+    ([0x00, 0x22, 0x7B], [0x00, 0x22, 0x7B], '\u{227A}'),
+    ([0x00, 0x22, 0x7C], [0x00, 0x22, 0x7C], '\u{227D}'),
+    ([0x00, 0x22, 0x7D], [0x00, 0x22, 0x7D], '\u{227C}'),
+    ([0x00, 0x22, 0x7E], [0x00, 0x22, 0x7E], '\u{227F}'),
+    ([0x00, 0x22, 0x7F], [0x00, 0x22, 0x7F], '\u{227E}'),
+    ([0x00, 0x22, 0x80], [0x00, 0x22, 0x80], '\u{2281}'),
+    ([0x00, 0x22, 0x81], [0x00, 0x22, 0x81], '\u{2280}'),
+    ([0x00, 0x22, 0x82], [0x00, 0x22, 0x82], '\u{2283}'),
+    ([0x00, 0x22, 0x83], [0x00, 0x22, 0x83], '\u{2282}'),
+    ([0x00, 0x22, 0x84], [0x00, 0x22, 0x84], '\u{2285}'),
+    ([0x00, 0x22, 0x85], [0x00, 0x22, 0x85], '\u{2284}'),
+    ([0x00, 0x22, 0x86], [0x00, 0x22, 0x86], '\u{2287}'),
+    ([0x00, 0x22, 0x87], [0x00, 0x22, 0x87], '\u{2286}'),
+    ([0x00, 0x22, 0x88], [0x00, 0x22, 0x88], '\u{2289}'),
+    ([0x00, 0x22, 0x89], [0x00, 0x22, 0x89], '\u{2288}'),
+    ([0x00, 0x22, 0x8A], [0x00, 0x22, 0x8A], '\u{228B}'),
+    ([0x00, 0x22, 0x8B], [0x00, 0x22, 0x8B], '\u{228A}'),
+    ([0x00, 0x22, 0x8F], [0x00, 0x22, 0x8F], '\u{2290}'),
+    ([0x00, 0x22, 0x90], [0x00, 0x22, 0x90], '\u{228F}'),
+    ([0x00, 0x22, 0x91], [0x00, 0x22, 0x91], '\u{2292}'),
+    ([0x00, 0x22, 0x92], [0x00, 0x22, 0x92], '\u{2291}'),
+    ([0x00, 0x22, 0x98], [0x00, 0x22, 0x98], '\u{29B8}'),
+    ([0x00, 0x22, 0xA2], [0x00, 0x22, 0xA2], '\u{22A3}'),
+    ([0x00, 0x22, 0xA3], [0x00, 0x22, 0xA3], '\u{22A2}'),
+    ([0x00, 0x22, 0xA6], [0x00, 0x22, 0xA6], '\u{2ADE}'),
+    ([0x00, 0x22, 0xA8], [0x00, 0x22, 0xA8], '\u{2AE4}'),
+    ([0x00, 0x22, 0xA9], [0x00, 0x22, 0xA9], '\u{2AE3}'),
+    ([0x00, 0x22, 0xAB], [0x00, 0x22, 0xAB], '\u{2AE5}'),
+    ([0x00, 0x22, 0xB0], [0x00, 0x22, 0xB0], '\u{22B1}'),
+    ([0x00, 0x22, 0xB1], [0x00, 0x22, 0xB1], '\u{22B0}'),
+    ([0x00, 0x22, 0xB2], [0x00, 0x22, 0xB2], '\u{22B3}'),
+    ([0x00, 0x22, 0xB3], [0x00, 0x22, 0xB3], '\u{22B2}'),
+    ([0x00, 0x22, 0xB4], [0x00, 0x22, 0xB4], '\u{22B5}'),
+    ([0x00, 0x22, 0xB5], [0x00, 0x22, 0xB5], '\u{22B4}'),
+    ([0x00, 0x22, 0xB6], [0x00, 0x22, 0xB6], '\u{22B7}'),
+    ([0x00, 0x22, 0xB7], [0x00, 0x22, 0xB7], '\u{22B6}'),
+    ([0x00, 0x22, 0xB8], [0x00, 0x22, 0xB8], '\u{27DC}'),
+    ([0x00, 0x22, 0xC9], [0x00, 0x22, 0xC9], '\u{22CA}'),
+    ([0x00, 0x22, 0xCA], [0x00, 0x22, 0xCA], '\u{22C9}'),
+    ([0x00, 0x22, 0xCB], [0x00, 0x22, 0xCB], '\u{22CC}'),
+    ([0x00, 0x22, 0xCC], [0x00, 0x22, 0xCC], '\u{22CB}'),
+    ([0x00, 0x22, 0xCD], [0x00, 0x22, 0xCD], '\u{2243}'),
+    ([0x00, 0x22, 0xD0], [0x00, 0x22, 0xD0], '\u{22D1}'),
+    ([0x00, 0x22, 0xD1], [0x00, 0x22, 0xD1], '\u{22D0}'),
+    ([0x00, 0x22, 0xD6], [0x00, 0x22, 0xD6], '\u{22D7}'),
+    ([0x00, 0x22, 0xD7], [0x00, 0x22, 0xD7], '\u{22D6}'),
+    ([0x00, 0x22, 0xD8], [0x00, 0x22, 0xD8], '\u{22D9}'),
+    ([0x00, 0x22, 0xD9], [0x00, 0x22, 0xD9], '\u{22D8}'),
+    ([0x00, 0x22, 0xDA], [0x00, 0x22, 0xDA], '\u{22DB}'),
+    ([0x00, 0x22, 0xDB], [0x00, 0x22, 0xDB], '\u{22DA}'),
+    ([0x00, 0x22, 0xDC], [0x00, 0x22, 0xDC], '\u{22DD}'),
+    ([0x00, 0x22, 0xDD], [0x00, 0x22, 0xDD], '\u{22DC}'),
+    ([0x00, 0x22, 0xDE], [0x00, 0x22, 0xDE], '\u{22DF}'),
+    ([0x00, 0x22, 0xDF], [0x00, 0x22, 0xDF], '\u{22DE}'),
+    ([0x00, 0x22, 0xE0], [0x00, 0x22, 0xE0], '\u{22E1}'),
+    ([0x00, 0x22, 0xE1], [0x00, 0x22, 0xE1], '\u{22E0}'),
+    ([0x00, 0x22, 0xE2], [0x00, 0x22, 0xE2], '\u{22E3}'),
+    ([0x00, 0x22, 0xE3], [0x00, 0x22, 0xE3], '\u{22E2}'),
+    ([0x00, 0x22, 0xE4], [0x00, 0x22, 0xE4], '\u{22E5}'),
+    ([0x00, 0x22, 0xE5], [0x00, 0x22, 0xE5], '\u{22E4}'),
+    ([0x00, 0x22, 0xE6], [0x00, 0x22, 0xE6], '\u{22E7}'),
+    ([0x00, 0x22, 0xE7], [0x00, 0x22, 0xE7], '\u{22E6}'),
+    ([0x00, 0x22, 0xE8], [0x00, 0x22, 0xE8], '\u{22E9}'),
+    ([0x00, 0x22, 0xE9], [0x00, 0x22, 0xE9], '\u{22E8}'),
+    ([0x00, 0x22, 0xEA], [0x00, 0x22, 0xEA], '\u{22EB}'),
+    ([0x00, 0x22, 0xEB], [0x00, 0x22, 0xEB], '\u{22EA}'),
+    ([0x00, 0x22, 0xEC], [0x00, 0x22, 0xEC], '\u{22ED}'),
+    ([0x00, 0x22, 0xED], [0x00, 0x22, 0xED], '\u{22EC}'),
+    ([0x00, 0x22, 0xF0], [0x00, 0x22, 0xF0], '\u{22F1}'),
+    ([0x00, 0x22, 0xF1], [0x00, 0x22, 0xF1], '\u{22F0}'),
+    ([0x00, 0x22, 0xF2], [0x00, 0x22, 0xF2], '\u{22FA}'),
+    ([0x00, 0x22, 0xF3], [0x00, 0x22, 0xF3], '\u{22FB}'),
+    ([0x00, 0x22, 0xF4], [0x00, 0x22, 0xF4], '\u{22FC}'),
+    ([0x00, 0x22, 0xF6], [0x00, 0x22, 0xF6], '\u{22FD}'),
+    ([0x00, 0x22, 0xF7], [0x00, 0x22, 0xF7], '\u{22FE}'),
+    ([0x00, 0x22, 0xFA], [0x00, 0x22, 0xFA], '\u{22F2}'),
+    ([0x00, 0x22, 0xFB], [0x00, 0x22, 0xFB], '\u{22F3}'),
+    ([0x00, 0x22, 0xFC], [0x00, 0x22, 0xFC], '\u{22F4}'),
+    ([0x00, 0x22, 0xFD], [0x00, 0x22, 0xFD], '\u{22F6}'),
+    ([0x00, 0x22, 0xFE], [0x00, 0x22, 0xFE], '\u{22F7}'),
+    ([0x00, 0x23, 0x08], [0x00, 0x23, 0x08], '\u{2309}'),
+    ([0x00, 0x23, 0x09], [0x00, 0x23, 0x09], '\u{2308}'),
+    ([0x00, 0x23, 0x0A], [0x00, 0x23, 0x0A], '\u{230B}'),
+    ([0x00, 0x23, 0x0B], [0x00, 0x23, 0x0B], '\u{230A}'),
+    ([0x00, 0x23, 0x29], [0x00, 0x23, 0x29], '\u{232A}'),
+    ([0x00, 0x23, 0x2A], [0x00, 0x23, 0x2A], '\u{2329}'),
+    ([0x00, 0x27, 0x68], [0x00, 0x27, 0x68], '\u{2769}'),
+    ([0x00, 0x27, 0x69], [0x00, 0x27, 0x69], '\u{2768}'),
+    ([0x00, 0x27, 0x6A], [0x00, 0x27, 0x6A], '\u{276B}'),
+    ([0x00, 0x27, 0x6B], [0x00, 0x27, 0x6B], '\u{276A}'),
+    ([0x00, 0x27, 0x6C], [0x00, 0x27, 0x6C], '\u{276D}'),
+    ([0x00, 0x27, 0x6D], [0x00, 0x27, 0x6D], '\u{276C}'),
+    ([0x00, 0x27, 0x6E], [0x00, 0x27, 0x6E], '\u{276F}'),
+    ([0x00, 0x27, 0x6F], [0x00, 0x27, 0x6F], '\u{276E}'),
+    ([0x00, 0x27, 0x70], [0x00, 0x27, 0x70], '\u{2771}'),
+    ([0x00, 0x27, 0x71], [0x00, 0x27, 0x71], '\u{2770}'),
+    ([0x00, 0x27, 0x72], [0x00, 0x27, 0x72], '\u{2773}'),
+    ([0x00, 0x27, 0x73], [0x00, 0x27, 0x73], '\u{2772}'),
+    ([0x00, 0x27, 0x74], [0x00, 0x27, 0x74], '\u{2775}'),
+    ([0x00, 0x27, 0x75], [0x00, 0x27, 0x75], '\u{2774}'),
+    ([0x00, 0x27, 0xC3], [0x00, 0x27, 0xC3], '\u{27C4}'),
+    ([0x00, 0x27, 0xC4], [0x00, 0x27, 0xC4], '\u{27C3}'),
+    ([0x00, 0x27, 0xC5], [0x00, 0x27, 0xC5], '\u{27C6}'),
+    ([0x00, 0x27, 0xC6], [0x00, 0x27, 0xC6], '\u{27C5}'),
+    ([0x00, 0x27, 0xC8], [0x00, 0x27, 0xC8], '\u{27C9}'),
+    ([0x00, 0x27, 0xC9], [0x00, 0x27, 0xC9], '\u{27C8}'),
+    ([0x00, 0x27, 0xCB], [0x00, 0x27, 0xCB], '\u{27CD}'),
+    ([0x00, 0x27, 0xCD], [0x00, 0x27, 0xCD], '\u{27CB}'),
+    ([0x00, 0x27, 0xD5], [0x00, 0x27, 0xD5], '\u{27D6}'),
+    ([0x00, 0x27, 0xD6], [0x00, 0x27, 0xD6], '\u{27D5}'),
+    ([0x00, 0x27, 0xDC], [0x00, 0x27, 0xDC], '\u{22B8}'),
+    ([0x00, 0x27, 0xDD], [0x00, 0x27, 0xDD], '\u{27DE}'),
+    ([0x00, 0x27, 0xDE], [0x00, 0x27, 0xDE], '\u{27DD}'),
+    ([0x00, 0x27, 0xE2], [0x00, 0x27, 0xE2], '\u{27E3}'),
+    ([0x00, 0x27, 0xE3], [0x00, 0x27, 0xE3], '\u{27E2}'),
+    ([0x00, 0x27, 0xE4], [0x00, 0x27, 0xE4], '\u{27E5}'),
+    ([0x00, 0x27, 0xE5], [0x00, 0x27, 0xE5], '\u{27E4}'),
+    ([0x00, 0x27, 0xE6], [0x00, 0x27, 0xE6], '\u{27E7}'),
+    ([0x00, 0x27, 0xE7], [0x00, 0x27, 0xE7], '\u{27E6}'),
+    ([0x00, 0x27, 0xE8], [0x00, 0x27, 0xE8], '\u{27E9}'),
+    ([0x00, 0x27, 0xE9], [0x00, 0x27, 0xE9], '\u{27E8}'),
+    ([0x00, 0x27, 0xEA], [0x00, 0x27, 0xEA], '\u{27EB}'),
+    ([0x00, 0x27, 0xEB], [0x00, 0x27, 0xEB], '\u{27EA}'),
+    ([0x00, 0x27, 0xEC], [0x00, 0x27, 0xEC], '\u{27ED}'),
+    ([0x00, 0x27, 0xED], [0x00, 0x27, 0xED], '\u{27EC}'),
+    ([0x00, 0x27, 0xEE], [0x00, 0x27, 0xEE], '\u{27EF}'),
+    ([0x00, 0x27, 0xEF], [0x00, 0x27, 0xEF], '\u{27EE}'),
+    ([0x00, 0x29, 0x83], [0x00, 0x29, 0x83], '\u{2984}'),
+    ([0x00, 0x29, 0x84], [0x00, 0x29, 0x84], '\u{2983}'),
+    ([0x00, 0x29, 0x85], [0x00, 0x29, 0x85], '\u{2986}'),
+    ([0x00, 0x29, 0x86], [0x00, 0x29, 0x86], '\u{2985}'),
+    ([0x00, 0x29, 0x87], [0x00, 0x29, 0x87], '\u{2988}'),
+    ([0x00, 0x29, 0x88], [0x00, 0x29, 0x88], '\u{2987}'),
+    ([0x00, 0x29, 0x89], [0x00, 0x29, 0x89], '\u{298A}'),
+    ([0x00, 0x29, 0x8A], [0x00, 0x29, 0x8A], '\u{2989}'),
+    ([0x00, 0x29, 0x8B], [0x00, 0x29, 0x8B], '\u{298C}'),
+    ([0x00, 0x29, 0x8C], [0x00, 0x29, 0x8C], '\u{298B}'),
+    ([0x00, 0x29, 0x8D], [0x00, 0x29, 0x8D], '\u{2990}'),
+    ([0x00, 0x29, 0x8E], [0x00, 0x29, 0x8E], '\u{298F}'),
+    ([0x00, 0x29, 0x8F], [0x00, 0x29, 0x8F], '\u{298E}'),
+    ([0x00, 0x29, 0x90], [0x00, 0x29, 0x90], '\u{298D}'),
+    ([0x00, 0x29, 0x91], [0x00, 0x29, 0x91], '\u{2992}'),
+    ([0x00, 0x29, 0x92], [0x00, 0x29, 0x92], '\u{2991}'),
+    ([0x00, 0x29, 0x93], [0x00, 0x29, 0x93], '\u{2994}'),
+    ([0x00, 0x29, 0x94], [0x00, 0x29, 0x94], '\u{2993}'),
+    ([0x00, 0x29, 0x95], [0x00, 0x29, 0x95], '\u{2996}'),
+    ([0x00, 0x29, 0x96], [0x00, 0x29, 0x96], '\u{2995}'),
+    ([0x00, 0x29, 0x97], [0x00, 0x29, 0x97], '\u{2998}'),
+    ([0x00, 0x29, 0x98], [0x00, 0x29, 0x98], '\u{2997}'),
+    ([0x00, 0x29, 0x9B], [0x00, 0x29, 0x9B], '\u{2221}'),
+    ([0x00, 0x29, 0xA0], [0x00, 0x29, 0xA0], '\u{2222}'),
+    ([0x00, 0x29, 0xA3], [0x00, 0x29, 0xA3], '\u{2220}'),
+    ([0x00, 0x29, 0xA4], [0x00, 0x29, 0xA4], '\u{29A5}'),
+    ([0x00, 0x29, 0xA5], [0x00, 0x29, 0xA5], '\u{29A4}'),
+    ([0x00, 0x29, 0xA8], [0x00, 0x29, 0xA8], '\u{29A9}'),
+    ([0x00, 0x29, 0xA9], [0x00, 0x29, 0xA9], '\u{29A8}'),
+    ([0x00, 0x29, 0xAA], [0x00, 0x29, 0xAA], '\u{29AB}'),
+    ([0x00, 0x29, 0xAB], [0x00, 0x29, 0xAB], '\u{29AA}'),
+    ([0x00, 0x29, 0xAC], [0x00, 0x29, 0xAC], '\u{29AD}'),
+    ([0x00, 0x29, 0xAD], [0x00, 0x29, 0xAD], '\u{29AC}'),
+    ([0x00, 0x29, 0xAE], [0x00, 0x29, 0xAE], '\u{29AF}'),
+    ([0x00, 0x29, 0xAF], [0x00, 0x29, 0xAF], '\u{29AE}'),
+    ([0x00, 0x29, 0xB8], [0x00, 0x29, 0xB8], '\u{2298}'),
+    ([0x00, 0x29, 0xC0], [0x00, 0x29, 0xC0], '\u{29C1}'),
+    ([0x00, 0x29, 0xC1], [0x00, 0x29, 0xC1], '\u{29C0}'),
+    ([0x00, 0x29, 0xC4], [0x00, 0x29, 0xC4], '\u{29C5}'),
+    ([0x00, 0x29, 0xC5], [0x00, 0x29, 0xC5], '\u{29C4}'),
+    ([0x00, 0x29, 0xCF], [0x00, 0x29, 0xCF], '\u{29D0}'),
+    ([0x00, 0x29, 0xD0], [0x00, 0x29, 0xD0], '\u{29CF}'),
+    ([0x00, 0x29, 0xD1], [0x00, 0x29, 0xD1], '\u{29D2}'),
+    ([0x00, 0x29, 0xD2], [0x00, 0x29, 0xD2], '\u{29D1}'),
+    ([0x00, 0x29, 0xD4], [0x00, 0x29, 0xD4], '\u{29D5}'),
+    ([0x00, 0x29, 0xD5], [0x00, 0x29, 0xD5], '\u{29D4}'),
+    ([0x00, 0x29, 0xD8], [0x00, 0x29, 0xD8], '\u{29D9}'),
+    ([0x00, 0x29, 0xD9], [0x00, 0x29, 0xD9], '\u{29D8}'),
+    ([0x00, 0x29, 0xDA], [0x00, 0x29, 0xDA], '\u{29DB}'),
+    ([0x00, 0x29, 0xDB], [0x00, 0x29, 0xDB], '\u{29DA}'),
+    ([0x00, 0x29, 0xE8], [0x00, 0x29, 0xE8], '\u{29E9}'),
+    ([0x00, 0x29, 0xE9], [0x00, 0x29, 0xE9], '\u{29E8}'),
+    ([0x00, 0x29, 0xF5], [0x00, 0x29, 0xF5], '\u{2215}'),
+    ([0x00, 0x29, 0xF8], [0x00, 0x29, 0xF8], '\u{29F9}'),
+    ([0x00, 0x29, 0xF9], [0x00, 0x29, 0xF9], '\u{29F8}'),
+    ([0x00, 0x29, 0xFC], [0x00, 0x29, 0xFC], '\u{29FD}'),
+    ([0x00, 0x29, 0xFD], [0x00, 0x29, 0xFD], '\u{29FC}'),
+    ([0x00, 0x2A, 0x2B], [0x00, 0x2A, 0x2B], '\u{2A2C}'),
+    ([0x00, 0x2A, 0x2C], [0x00, 0x2A, 0x2C], '\u{2A2B}'),
+    ([0x00, 0x2A, 0x2D], [0x00, 0x2A, 0x2D], '\u{2A2E}'),
+    ([0x00, 0x2A, 0x2E], [0x00, 0x2A, 0x2E], '\u{2A2D}'),
+    ([0x00, 0x2A, 0x34], [0x00, 0x2A, 0x34], '\u{2A35}'),
+    ([0x00, 0x2A, 0x35], [0x00, 0x2A, 0x35], '\u{2A34}'),
+    ([0x00, 0x2A, 0x3C], [0x00, 0x2A, 0x3C], '\u{2A3D}'),
+    ([0x00, 0x2A, 0x3D], [0x00, 0x2A, 0x3D], '\u{2A3C}'),
+    ([0x00, 0x2A, 0x64], [0x00, 0x2A, 0x64], '\u{2A65}'),
+    ([0x00, 0x2A, 0x65], [0x00, 0x2A, 0x65], '\u{2A64}'),
+    ([0x00, 0x2A, 0x79], [0x00, 0x2A, 0x79], '\u{2A7A}'),
+    ([0x00, 0x2A, 0x7A], [0x00, 0x2A, 0x7A], '\u{2A79}'),
+    ([0x00, 0x2A, 0x7B], [0x00, 0x2A, 0x7B], '\u{2A7C}'),
+    ([0x00, 0x2A, 0x7C], [0x00, 0x2A, 0x7C], '\u{2A7B}'),
+    ([0x00, 0x2A, 0x7D], [0x00, 0x2A, 0x7D], '\u{2A7E}'),
+    ([0x00, 0x2A, 0x7E], [0x00, 0x2A, 0x7E], '\u{2A7D}'),
+    ([0x00, 0x2A, 0x7F], [0x00, 0x2A, 0x7F], '\u{2A80}'),
+    ([0x00, 0x2A, 0x80], [0x00, 0x2A, 0x80], '\u{2A7F}'),
+    ([0x00, 0x2A, 0x81], [0x00, 0x2A, 0x81], '\u{2A82}'),
+    ([0x00, 0x2A, 0x82], [0x00, 0x2A, 0x82], '\u{2A81}'),
+    ([0x00, 0x2A, 0x83], [0x00, 0x2A, 0x83], '\u{2A84}'),
+    ([0x00, 0x2A, 0x84], [0x00, 0x2A, 0x84], '\u{2A83}'),
+    ([0x00, 0x2A, 0x85], [0x00, 0x2A, 0x85], '\u{2A86}'),
+    ([0x00, 0x2A, 0x86], [0x00, 0x2A, 0x86], '\u{2A85}'),
+    ([0x00, 0x2A, 0x87], [0x00, 0x2A, 0x87], '\u{2A88}'),
+    ([0x00, 0x2A, 0x88], [0x00, 0x2A, 0x88], '\u{2A87}'),
+    ([0x00, 0x2A, 0x89], [0x00, 0x2A, 0x89], '\u{2A8A}'),
+    ([0x00, 0x2A, 0x8A], [0x00, 0x2A, 0x8A], '\u{2A89}'),
+    ([0x00, 0x2A, 0x8B], [0x00, 0x2A, 0x8B], '\u{2A8C}'),
+    ([0x00, 0x2A, 0x8C], [0x00, 0x2A, 0x8C], '\u{2A8B}'),
+    ([0x00, 0x2A, 0x8D], [0x00, 0x2A, 0x8D], '\u{2A8E}'),
+    ([0x00, 0x2A, 0x8E], [0x00, 0x2A, 0x8E], '\u{2A8D}'),
+    ([0x00, 0x2A, 0x8F], [0x00, 0x2A, 0x8F], '\u{2A90}'),
+    ([0x00, 0x2A, 0x90], [0x00, 0x2A, 0x90], '\u{2A8F}'),
+    ([0x00, 0x2A, 0x91], [0x00, 0x2A, 0x91], '\u{2A92}'),
+    ([0x00, 0x2A, 0x92], [0x00, 0x2A, 0x92], '\u{2A91}'),
+    ([0x00, 0x2A, 0x93], [0x00, 0x2A, 0x93], '\u{2A94}'),
+    ([0x00, 0x2A, 0x94], [0x00, 0x2A, 0x94], '\u{2A93}'),
+    ([0x00, 0x2A, 0x95], [0x00, 0x2A, 0x95], '\u{2A96}'),
+    ([0x00, 0x2A, 0x96], [0x00, 0x2A, 0x96], '\u{2A95}'),
+    ([0x00, 0x2A, 0x97], [0x00, 0x2A, 0x97], '\u{2A98}'),
+    ([0x00, 0x2A, 0x98], [0x00, 0x2A, 0x98], '\u{2A97}'),
+    ([0x00, 0x2A, 0x99], [0x00, 0x2A, 0x99], '\u{2A9A}'),
+    ([0x00, 0x2A, 0x9A], [0x00, 0x2A, 0x9A], '\u{2A99}'),
+    ([0x00, 0x2A, 0x9B], [0x00, 0x2A, 0x9B], '\u{2A9C}'),
+    ([0x00, 0x2A, 0x9C], [0x00, 0x2A, 0x9C], '\u{2A9B}'),
+    ([0x00, 0x2A, 0x9D], [0x00, 0x2A, 0x9D], '\u{2A9E}'),
+    ([0x00, 0x2A, 0x9E], [0x00, 0x2A, 0x9E], '\u{2A9D}'),
+    ([0x00, 0x2A, 0x9F], [0x00, 0x2A, 0x9F], '\u{2AA0}'),
+    ([0x00, 0x2A, 0xA0], [0x00, 0x2A, 0xA0], '\u{2A9F}'),
+    ([0x00, 0x2A, 0xA1], [0x00, 0x2A, 0xA1], '\u{2AA2}'),
+    ([0x00, 0x2A, 0xA2], [0x00, 0x2A, 0xA2], '\u{2AA1}'),
+    ([0x00, 0x2A, 0xA6], [0x00, 0x2A, 0xA6], '\u{2AA7}'),
+    ([0x00, 0x2A, 0xA7], [0x00, 0x2A, 0xA7], '\u{2AA6}'),
+    ([0x00, 0x2A, 0xA8], [0x00, 0x2A, 0xA8], '\u{2AA9}'),
+    ([0x00, 0x2A, 0xA9], [0x00, 0x2A, 0xA9], '\u{2AA8}'),
+    ([0x00, 0x2A, 0xAA], [0x00, 0x2A, 0xAA], '\u{2AAB}'),
+    ([0x00, 0x2A, 0xAB], [0x00, 0x2A, 0xAB], '\u{2AAA}'),
+    ([0x00, 0x2A, 0xAC], [0x00, 0x2A, 0xAC], '\u{2AAD}'),
+    ([0x00, 0x2A, 0xAD], [0x00, 0x2A, 0xAD], '\u{2AAC}'),
+    ([0x00, 0x2A, 0xAF], [0x00, 0x2A, 0xAF], '\u{2AB0}'),
+    ([0x00, 0x2A, 0xB0], [0x00, 0x2A, 0xB0], '\u{2AAF}'),
+    ([0x00, 0x2A, 0xB1], [0x00, 0x2A, 0xB1], '\u{2AB2}'),
+    ([0x00, 0x2A, 0xB2], [0x00, 0x2A, 0xB2], '\u{2AB1}'),
+    ([0x00, 0x2A, 0xB3], [0x00, 0x2A, 0xB3], '\u{2AB4}'),
+    ([0x00, 0x2A, 0xB4], [0x00, 0x2A, 0xB4], '\u{2AB3}'),
+    ([0x00, 0x2A, 0xB5], [0x00, 0x2A, 0xB5], '\u{2AB6}'),
+    ([0x00, 0x2A, 0xB6], [0x00, 0x2A, 0xB6], '\u{2AB5}'),
+    ([0x00, 0x2A, 0xB7], [0x00, 0x2A, 0xB7], '\u{2AB8}'),
+    ([0x00, 0x2A, 0xB8], [0x00, 0x2A, 0xB8], '\u{2AB7}'),
+    ([0x00, 0x2A, 0xB9], [0x00, 0x2A, 0xB9], '\u{2ABA}'),
+    ([0x00, 0x2A, 0xBA], [0x00, 0x2A, 0xBA], '\u{2AB9}'),
+    ([0x00, 0x2A, 0xBB], [0x00, 0x2A, 0xBB], '\u{2ABC}'),
+    ([0x00, 0x2A, 0xBC], [0x00, 0x2A, 0xBC], '\u{2ABB}'),
+    ([0x00, 0x2A, 0xBD], [0x00, 0x2A, 0xBD], '\u{2ABE}'),
+    ([0x00, 0x2A, 0xBE], [0x00, 0x2A, 0xBE], '\u{2ABD}'),
+    ([0x00, 0x2A, 0xBF], [0x00, 0x2A, 0xBF], '\u{2AC0}'),
+    ([0x00, 0x2A, 0xC0], [0x00, 0x2A, 0xC0], '\u{2ABF}'),
+    ([0x00, 0x2A, 0xC1], [0x00, 0x2A, 0xC1], '\u{2AC2}'),
+    ([0x00, 0x2A, 0xC2], [0x00, 0x2A, 0xC2], '\u{2AC1}'),
+    ([0x00, 0x2A, 0xC3], [0x00, 0x2A, 0xC3], '\u{2AC4}'),
+    ([0x00, 0x2A, 0xC4], [0x00, 0x2A, 0xC4], '\u{2AC3}'),
+    ([0x00, 0x2A, 0xC5], [0x00, 0x2A, 0xC5], '\u{2AC6}'),
+    ([0x00, 0x2A, 0xC6], [0x00, 0x2A, 0xC6], '\u{2AC5}'),
+    ([0x00, 0x2A, 0xC7], [0x00, 0x2A, 0xC7], '\u{2AC8}'),
+    ([0x00, 0x2A, 0xC8], [0x00, 0x2A, 0xC8], '\u{2AC7}'),
+    ([0x00, 0x2A, 0xC9], [0x00, 0x2A, 0xC9], '\u{2ACA}'),
+    ([0x00, 0x2A, 0xCA], [0x00, 0x2A, 0xCA], '\u{2AC9}'),
+    ([0x00, 0x2A, 0xCB], [0x00, 0x2A, 0xCB], '\u{2ACC}'),
+    ([0x00, 0x2A, 0xCC], [0x00, 0x2A, 0xCC], '\u{2ACB}'),
+    ([0x00, 0x2A, 0xCD], [0x00, 0x2A, 0xCD], '\u{2ACE}'),
+    ([0x00, 0x2A, 0xCE], [0x00, 0x2A, 0xCE], '\u{2ACD}'),
+    ([0x00, 0x2A, 0xCF], [0x00, 0x2A, 0xCF], '\u{2AD0}'),
+    ([0x00, 0x2A, 0xD0], [0x00, 0x2A, 0xD0], '\u{2ACF}'),
+    ([0x00, 0x2A, 0xD1], [0x00, 0x2A, 0xD1], '\u{2AD2}'),
+    ([0x00, 0x2A, 0xD2], [0x00, 0x2A, 0xD2], '\u{2AD1}'),
+    ([0x00, 0x2A, 0xD3], [0x00, 0x2A, 0xD3], '\u{2AD4}'),
+    ([0x00, 0x2A, 0xD4], [0x00, 0x2A, 0xD4], '\u{2AD3}'),
+    ([0x00, 0x2A, 0xD5], [0x00, 0x2A, 0xD5], '\u{2AD6}'),
+    ([0x00, 0x2A, 0xD6], [0x00, 0x2A, 0xD6], '\u{2AD5}'),
+    ([0x00, 0x2A, 0xDE], [0x00, 0x2A, 0xDE], '\u{22A6}'),
+    ([0x00, 0x2A, 0xE3], [0x00, 0x2A, 0xE3], '\u{22A9}'),
+    ([0x00, 0x2A, 0xE4], [0x00, 0x2A, 0xE4], '\u{22A8}'),
+    ([0x00, 0x2A, 0xE5], [0x00, 0x2A, 0xE5], '\u{22AB}'),
+    ([0x00, 0x2A, 0xEC], [0x00, 0x2A, 0xEC], '\u{2AED}'),
+    ([0x00, 0x2A, 0xED], [0x00, 0x2A, 0xED], '\u{2AEC}'),
+    ([0x00, 0x2A, 0xEE], [0x00, 0x2A, 0xEE], '\u{2224}'),
+    ([0x00, 0x2A, 0xF7], [0x00, 0x2A, 0xF7], '\u{2AF8}'),
+    ([0x00, 0x2A, 0xF8], [0x00, 0x2A, 0xF8], '\u{2AF7}'),
+    ([0x00, 0x2A, 0xF9], [0x00, 0x2A, 0xF9], '\u{2AFA}'),
+    ([0x00, 0x2A, 0xFA], [0x00, 0x2A, 0xFA], '\u{2AF9}'),
+    ([0x00, 0x2B, 0xFE], [0x00, 0x2B, 0xFE], '\u{221F}'),
+    ([0x00, 0x2E, 0x02], [0x00, 0x2E, 0x02], '\u{2E03}'),
+    ([0x00, 0x2E, 0x03], [0x00, 0x2E, 0x03], '\u{2E02}'),
+    ([0x00, 0x2E, 0x04], [0x00, 0x2E, 0x04], '\u{2E05}'),
+    ([0x00, 0x2E, 0x05], [0x00, 0x2E, 0x05], '\u{2E04}'),
+    ([0x00, 0x2E, 0x09], [0x00, 0x2E, 0x09], '\u{2E0A}'),
+    ([0x00, 0x2E, 0x0A], [0x00, 0x2E, 0x0A], '\u{2E09}'),
+    ([0x00, 0x2E, 0x0C], [0x00, 0x2E, 0x0C], '\u{2E0D}'),
+    ([0x00, 0x2E, 0x0D], [0x00, 0x2E, 0x0D], '\u{2E0C}'),
+    ([0x00, 0x2E, 0x1C], [0x00, 0x2E, 0x1C], '\u{2E1D}'),
+    ([0x00, 0x2E, 0x1D], [0x00, 0x2E, 0x1D], '\u{2E1C}'),
+    ([0x00, 0x2E, 0x20], [0x00, 0x2E, 0x20], '\u{2E21}'),
+    ([0x00, 0x2E, 0x21], [0x00, 0x2E, 0x21], '\u{2E20}'),
+    ([0x00, 0x2E, 0x22], [0x00, 0x2E, 0x22], '\u{2E23}'),
+    ([0x00, 0x2E, 0x23], [0x00, 0x2E, 0x23], '\u{2E22}'),
+    ([0x00, 0x2E, 0x24], [0x00, 0x2E, 0x24], '\u{2E25}'),
+    ([0x00, 0x2E, 0x25], [0x00, 0x2E, 0x25], '\u{2E24}'),
+    ([0x00, 0x2E, 0x26], [0x00, 0x2E, 0x26], '\u{2E27}'),
+    ([0x00, 0x2E, 0x27], [0x00, 0x2E, 0x27], '\u{2E26}'),
+    ([0x00, 0x2E, 0x28], [0x00, 0x2E, 0x28], '\u{2E29}'),
+    ([0x00, 0x2E, 0x29], [0x00, 0x2E, 0x29], '\u{2E28}'),
+    ([0x00, 0x2E, 0x55], [0x00, 0x2E, 0x55], '\u{2E56}'),
+    ([0x00, 0x2E, 0x56], [0x00, 0x2E, 0x56], '\u{2E55}'),
+    ([0x00, 0x2E, 0x57], [0x00, 0x2E, 0x57], '\u{2E58}'),
+    ([0x00, 0x2E, 0x58], [0x00, 0x2E, 0x58], '\u{2E57}'),
+    ([0x00, 0x2E, 0x59], [0x00, 0x2E, 0x59], '\u{2E5A}'),
+    ([0x00, 0x2E, 0x5A], [0x00, 0x2E, 0x5A], '\u{2E59}'),
+    ([0x00, 0x2E, 0x5B], [0x00, 0x2E, 0x5B], '\u{2E5C}'),
+    ([0x00, 0x2E, 0x5C], [0x00, 0x2E, 0x5C], '\u{2E5B}'),
+    ([0x00, 0x30, 0x08], [0x00, 0x30, 0x08], '\u{3009}'),
+    ([0x00, 0x30, 0x09], [0x00, 0x30, 0x09], '\u{3008}'),
+    ([0x00, 0x30, 0x0A], [0x00, 0x30, 0x0A], '\u{300B}'),
+    ([0x00, 0x30, 0x0B], [0x00, 0x30, 0x0B], '\u{300A}'),
+    ([0x00, 0x30, 0x0C], [0x00, 0x30, 0x0C], '\u{300D}'),
+    ([0x00, 0x30, 0x0D], [0x00, 0x30, 0x0D], '\u{300C}'),
+    ([0x00, 0x30, 0x0E], [0x00, 0x30, 0x0E], '\u{300F}'),
+    ([0x00, 0x30, 0x0F], [0x00, 0x30, 0x0F], '\u{300E}'),
+    ([0x00, 0x30, 0x10], [0x00, 0x30, 0x10], '\u{3011}'),
+    ([0x00, 0x30, 0x11], [0x00, 0x30, 0x11], '\u{3010}'),
+    ([0x00, 0x30, 0x14], [0x00, 0x30, 0x14], '\u{3015}'),
+    ([0x00, 0x30, 0x15], [0x00, 0x30, 0x15], '\u{3014}'),
+    ([0x00, 0x30, 0x16], [0x00, 0x30, 0x16], '\u{3017}'),
+    ([0x00, 0x30, 0x17], [0x00, 0x30, 0x17], '\u{3016}'),
+    ([0x00, 0x30, 0x18], [0x00, 0x30, 0x18], '\u{3019}'),
+    ([0x00, 0x30, 0x19], [0x00, 0x30, 0x19], '\u{3018}'),
+    ([0x00, 0x30, 0x1A], [0x00, 0x30, 0x1A], '\u{301B}'),
+    ([0x00, 0x30, 0x1B], [0x00, 0x30, 0x1B], '\u{301A}'),
+    ([0x00, 0xFE, 0x59], [0x00, 0xFE, 0x59], '\u{FE5A}'),
+    ([0x00, 0xFE, 0x5A], [0x00, 0xFE, 0x5A], '\u{FE59}'),
+    ([0x00, 0xFE, 0x5B], [0x00, 0xFE, 0x5B], '\u{FE5C}'),
+    ([0x00, 0xFE, 0x5C], [0x00, 0xFE, 0x5C], '\u{FE5B}'),
+    ([0x00, 0xFE, 0x5D], [0x00, 0xFE, 0x5D], '\u{FE5E}'),
+    ([0x00, 0xFE, 0x5E], [0x00, 0xFE, 0x5E], '\u{FE5D}'),
+    ([0x00, 0xFE, 0x64], [0x00, 0xFE, 0x64], '\u{FE65}'),
+    ([0x00, 0xFE, 0x65], [0x00, 0xFE, 0x65], '\u{FE64}'),
+    ([0x00, 0xFF, 0x08], [0x00, 0xFF, 0x08], '\u{FF09}'),
+    ([0x00, 0xFF, 0x09], [0x00, 0xFF, 0x09], '\u{FF08}'),
+    ([0x00, 0xFF, 0x1C], [0x00, 0xFF, 0x1C], '\u{FF1E}'),
+    ([0x00, 0xFF, 0x1E], [0x00, 0xFF, 0x1E], '\u{FF1C}'),
+    ([0x00, 0xFF, 0x3B], [0x00, 0xFF, 0x3B], '\u{FF3D}'),
+    ([0x00, 0xFF, 0x3D], [0x00, 0xFF, 0x3D], '\u{FF3B}'),
+    ([0x00, 0xFF, 0x5B], [0x00, 0xFF, 0x5B], '\u{FF5D}'),
+    ([0x00, 0xFF, 0x5D], [0x00, 0xFF, 0x5D], '\u{FF5B}'),
+    ([0x00, 0xFF, 0x5F], [0x00, 0xFF, 0x5F], '\u{FF60}'),
+    ([0x00, 0xFF, 0x60], [0x00, 0xFF, 0x60], '\u{FF5F}'),
+    ([0x00, 0xFF, 0x62], [0x00, 0xFF, 0x62], '\u{FF63}'),
+    ([0x00, 0xFF, 0x63], [0x00, 0xFF, 0x63], '\u{FF62}'),
+];
@@ -0,0 +1,151 @@
+//! This file was generated by:
+//! generate_table.py Bidi_Paired_Bracket <ucd_dir>
+
+use BidiPairedBracketType::*;
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum BidiPairedBracketType {
+    None,
+    Open,
+    Close,
+}
+
+impl Default for BidiPairedBracketType {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+pub(crate) type BidiPairedBracket = (char, BidiPairedBracketType);
+
+pub(crate) static BIDI_PAIRED_BRACKET: [([u8; 3], [u8; 3], BidiPairedBracket); 128] = [
+    ([0x00, 0x00, 0x28], [0x00, 0x00, 0x28], ('\u{0029}', Open)),
+    ([0x00, 0x00, 0x29], [0x00, 0x00, 0x29], ('\u{0028}', Close)),
+    ([0x00, 0x00, 0x5B], [0x00, 0x00, 0x5B], ('\u{005D}', Open)),
+    ([0x00, 0x00, 0x5D], [0x00, 0x00, 0x5D], ('\u{005B}', Close)),
+    ([0x00, 0x00, 0x7B], [0x00, 0x00, 0x7B], ('\u{007D}', Open)),
+    ([0x00, 0x00, 0x7D], [0x00, 0x00, 0x7D], ('\u{007B}', Close)),
+    ([0x00, 0x0F, 0x3A], [0x00, 0x0F, 0x3A], ('\u{0F3B}', Open)),
+    ([0x00, 0x0F, 0x3B], [0x00, 0x0F, 0x3B], ('\u{0F3A}', Close)),
+    ([0x00, 0x0F, 0x3C], [0x00, 0x0F, 0x3C], ('\u{0F3D}', Open)),
+    ([0x00, 0x0F, 0x3D], [0x00, 0x0F, 0x3D], ('\u{0F3C}', Close)),
+    ([0x00, 0x16, 0x9B], [0x00, 0x16, 0x9B], ('\u{169C}', Open)),
+    ([0x00, 0x16, 0x9C], [0x00, 0x16, 0x9C], ('\u{169B}', Close)),
+    ([0x00, 0x20, 0x45], [0x00, 0x20, 0x45], ('\u{2046}', Open)),
+    ([0x00, 0x20, 0x46], [0x00, 0x20, 0x46], ('\u{2045}', Close)),
+    ([0x00, 0x20, 0x7D], [0x00, 0x20, 0x7D], ('\u{207E}', Open)),
+    ([0x00, 0x20, 0x7E], [0x00, 0x20, 0x7E], ('\u{207D}', Close)),
+    ([0x00, 0x20, 0x8D], [0x00, 0x20, 0x8D], ('\u{208E}', Open)),
+    ([0x00, 0x20, 0x8E], [0x00, 0x20, 0x8E], ('\u{208D}', Close)),
+    ([0x00, 0x23, 0x08], [0x00, 0x23, 0x08], ('\u{2309}', Open)),
+    ([0x00, 0x23, 0x09], [0x00, 0x23, 0x09], ('\u{2308}', Close)),
+    ([0x00, 0x23, 0x0A], [0x00, 0x23, 0x0A], ('\u{230B}', Open)),
+    ([0x00, 0x23, 0x0B], [0x00, 0x23, 0x0B], ('\u{230A}', Close)),
+    ([0x00, 0x23, 0x29], [0x00, 0x23, 0x29], ('\u{232A}', Open)),
+    ([0x00, 0x23, 0x2A], [0x00, 0x23, 0x2A], ('\u{2329}', Close)),
+    ([0x00, 0x27, 0x68], [0x00, 0x27, 0x68], ('\u{2769}', Open)),
+    ([0x00, 0x27, 0x69], [0x00, 0x27, 0x69], ('\u{2768}', Close)),
+    ([0x00, 0x27, 0x6A], [0x00, 0x27, 0x6A], ('\u{276B}', Open)),
+    ([0x00, 0x27, 0x6B], [0x00, 0x27, 0x6B], ('\u{276A}', Close)),
+    ([0x00, 0x27, 0x6C], [0x00, 0x27, 0x6C], ('\u{276D}', Open)),
+    ([0x00, 0x27, 0x6D], [0x00, 0x27, 0x6D], ('\u{276C}', Close)),
+    ([0x00, 0x27, 0x6E], [0x00, 0x27, 0x6E], ('\u{276F}', Open)),
+    ([0x00, 0x27, 0x6F], [0x00, 0x27, 0x6F], ('\u{276E}', Close)),
+    ([0x00, 0x27, 0x70], [0x00, 0x27, 0x70], ('\u{2771}', Open)),
+    ([0x00, 0x27, 0x71], [0x00, 0x27, 0x71], ('\u{2770}', Close)),
+    ([0x00, 0x27, 0x72], [0x00, 0x27, 0x72], ('\u{2773}', Open)),
+    ([0x00, 0x27, 0x73], [0x00, 0x27, 0x73], ('\u{2772}', Close)),
+    ([0x00, 0x27, 0x74], [0x00, 0x27, 0x74], ('\u{2775}', Open)),
+    ([0x00, 0x27, 0x75], [0x00, 0x27, 0x75], ('\u{2774}', Close)),
+    ([0x00, 0x27, 0xC5], [0x00, 0x27, 0xC5], ('\u{27C6}', Open)),
+    ([0x00, 0x27, 0xC6], [0x00, 0x27, 0xC6], ('\u{27C5}', Close)),
+    ([0x00, 0x27, 0xE6], [0x00, 0x27, 0xE6], ('\u{27E7}', Open)),
+    ([0x00, 0x27, 0xE7], [0x00, 0x27, 0xE7], ('\u{27E6}', Close)),
+    ([0x00, 0x27, 0xE8], [0x00, 0x27, 0xE8], ('\u{27E9}', Open)),
+    ([0x00, 0x27, 0xE9], [0x00, 0x27, 0xE9], ('\u{27E8}', Close)),
+    ([0x00, 0x27, 0xEA], [0x00, 0x27, 0xEA], ('\u{27EB}', Open)),
+    ([0x00, 0x27, 0xEB], [0x00, 0x27, 0xEB], ('\u{27EA}', Close)),
+    ([0x00, 0x27, 0xEC], [0x00, 0x27, 0xEC], ('\u{27ED}', Open)),
+    ([0x00, 0x27, 0xED], [0x00, 0x27, 0xED], ('\u{27EC}', Close)),
+    ([0x00, 0x27, 0xEE], [0x00, 0x27, 0xEE], ('\u{27EF}', Open)),
+    ([0x00, 0x27, 0xEF], [0x00, 0x27, 0xEF], ('\u{27EE}', Close)),
+    ([0x00, 0x29, 0x83], [0x00, 0x29, 0x83], ('\u{2984}', Open)),
+    ([0x00, 0x29, 0x84], [0x00, 0x29, 0x84], ('\u{2983}', Close)),
+    ([0x00, 0x29, 0x85], [0x00, 0x29, 0x85], ('\u{2986}', Open)),
+    ([0x00, 0x29, 0x86], [0x00, 0x29, 0x86], ('\u{2985}', Close)),
+    ([0x00, 0x29, 0x87], [0x00, 0x29, 0x87], ('\u{2988}', Open)),
+    ([0x00, 0x29, 0x88], [0x00, 0x29, 0x88], ('\u{2987}', Close)),
+    ([0x00, 0x29, 0x89], [0x00, 0x29, 0x89], ('\u{298A}', Open)),
+    ([0x00, 0x29, 0x8A], [0x00, 0x29, 0x8A], ('\u{2989}', Close)),
+    ([0x00, 0x29, 0x8B], [0x00, 0x29, 0x8B], ('\u{298C}', Open)),
+    ([0x00, 0x29, 0x8C], [0x00, 0x29, 0x8C], ('\u{298B}', Close)),
+    ([0x00, 0x29, 0x8D], [0x00, 0x29, 0x8D], ('\u{2990}', Open)),
+    ([0x00, 0x29, 0x8E], [0x00, 0x29, 0x8E], ('\u{298F}', Close)),
+    ([0x00, 0x29, 0x8F], [0x00, 0x29, 0x8F], ('\u{298E}', Open)),
+    ([0x00, 0x29, 0x90], [0x00, 0x29, 0x90], ('\u{298D}', Close)),
+    ([0x00, 0x29, 0x91], [0x00, 0x29, 0x91], ('\u{2992}', Open)),
+    ([0x00, 0x29, 0x92], [0x00, 0x29, 0x92], ('\u{2991}', Close)),
+    ([0x00, 0x29, 0x93], [0x00, 0x29, 0x93], ('\u{2994}', Open)),
+    ([0x00, 0x29, 0x94], [0x00, 0x29, 0x94], ('\u{2993}', Close)),
+    ([0x00, 0x29, 0x95], [0x00, 0x29, 0x95], ('\u{2996}', Open)),
+    ([0x00, 0x29, 0x96], [0x00, 0x29, 0x96], ('\u{2995}', Close)),
+    ([0x00, 0x29, 0x97], [0x00, 0x29, 0x97], ('\u{2998}', Open)),
+    ([0x00, 0x29, 0x98], [0x00, 0x29, 0x98], ('\u{2997}', Close)),
+    ([0x00, 0x29, 0xD8], [0x00, 0x29, 0xD8], ('\u{29D9}', Open)),
+    ([0x00, 0x29, 0xD9], [0x00, 0x29, 0xD9], ('\u{29D8}', Close)),
+    ([0x00, 0x29, 0xDA], [0x00, 0x29, 0xDA], ('\u{29DB}', Open)),
+    ([0x00, 0x29, 0xDB], [0x00, 0x29, 0xDB], ('\u{29DA}', Close)),
+    ([0x00, 0x29, 0xFC], [0x00, 0x29, 0xFC], ('\u{29FD}', Open)),
+    ([0x00, 0x29, 0xFD], [0x00, 0x29, 0xFD], ('\u{29FC}', Close)),
+    ([0x00, 0x2E, 0x22], [0x00, 0x2E, 0x22], ('\u{2E23}', Open)),
+    ([0x00, 0x2E, 0x23], [0x00, 0x2E, 0x23], ('\u{2E22}', Close)),
+    ([0x00, 0x2E, 0x24], [0x00, 0x2E, 0x24], ('\u{2E25}', Open)),
+    ([0x00, 0x2E, 0x25], [0x00, 0x2E, 0x25], ('\u{2E24}', Close)),
+    ([0x00, 0x2E, 0x26], [0x00, 0x2E, 0x26], ('\u{2E27}', Open)),
+    ([0x00, 0x2E, 0x27], [0x00, 0x2E, 0x27], ('\u{2E26}', Close)),
+    ([0x00, 0x2E, 0x28], [0x00, 0x2E, 0x28], ('\u{2E29}', Open)),
+    ([0x00, 0x2E, 0x29], [0x00, 0x2E, 0x29], ('\u{2E28}', Close)),
+    ([0x00, 0x2E, 0x55], [0x00, 0x2E, 0x55], ('\u{2E56}', Open)),
+    ([0x00, 0x2E, 0x56], [0x00, 0x2E, 0x56], ('\u{2E55}', Close)),
+    ([0x00, 0x2E, 0x57], [0x00, 0x2E, 0x57], ('\u{2E58}', Open)),
+    ([0x00, 0x2E, 0x58], [0x00, 0x2E, 0x58], ('\u{2E57}', Close)),
+    ([0x00, 0x2E, 0x59], [0x00, 0x2E, 0x59], ('\u{2E5A}', Open)),
+    ([0x00, 0x2E, 0x5A], [0x00, 0x2E, 0x5A], ('\u{2E59}', Close)),
+    ([0x00, 0x2E, 0x5B], [0x00, 0x2E, 0x5B], ('\u{2E5C}', Open)),
+    ([0x00, 0x2E, 0x5C], [0x00, 0x2E, 0x5C], ('\u{2E5B}', Close)),
+    ([0x00, 0x30, 0x08], [0x00, 0x30, 0x08], ('\u{3009}', Open)),
+    ([0x00, 0x30, 0x09], [0x00, 0x30, 0x09], ('\u{3008}', Close)),
+    ([0x00, 0x30, 0x0A], [0x00, 0x30, 0x0A], ('\u{300B}', Open)),
+    ([0x00, 0x30, 0x0B], [0x00, 0x30, 0x0B], ('\u{300A}', Close)),
+    ([0x00, 0x30, 0x0C], [0x00, 0x30, 0x0C], ('\u{300D}', Open)),
+    ([0x00, 0x30, 0x0D], [0x00, 0x30, 0x0D], ('\u{300C}', Close)),
+    ([0x00, 0x30, 0x0E], [0x00, 0x30, 0x0E], ('\u{300F}', Open)),
+    ([0x00, 0x30, 0x0F], [0x00, 0x30, 0x0F], ('\u{300E}', Close)),
+    ([0x00, 0x30, 0x10], [0x00, 0x30, 0x10], ('\u{3011}', Open)),
+    ([0x00, 0x30, 0x11], [0x00, 0x30, 0x11], ('\u{3010}', Close)),
+    ([0x00, 0x30, 0x14], [0x00, 0x30, 0x14], ('\u{3015}', Open)),
+    ([0x00, 0x30, 0x15], [0x00, 0x30, 0x15], ('\u{3014}', Close)),
+    ([0x00, 0x30, 0x16], [0x00, 0x30, 0x16], ('\u{3017}', Open)),
+    ([0x00, 0x30, 0x17], [0x00, 0x30, 0x17], ('\u{3016}', Close)),
+    ([0x00, 0x30, 0x18], [0x00, 0x30, 0x18], ('\u{3019}', Open)),
+    ([0x00, 0x30, 0x19], [0x00, 0x30, 0x19], ('\u{3018}', Close)),
+    ([0x00, 0x30, 0x1A], [0x00, 0x30, 0x1A], ('\u{301B}', Open)),
+    ([0x00, 0x30, 0x1B], [0x00, 0x30, 0x1B], ('\u{301A}', Close)),
+    ([0x00, 0xFE, 0x59], [0x00, 0xFE, 0x59], ('\u{FE5A}', Open)),
+    ([0x00, 0xFE, 0x5A], [0x00, 0xFE, 0x5A], ('\u{FE59}', Close)),
+    ([0x00, 0xFE, 0x5B], [0x00, 0xFE, 0x5B], ('\u{FE5C}', Open)),
+    ([0x00, 0xFE, 0x5C], [0x00, 0xFE, 0x5C], ('\u{FE5B}', Close)),
+    ([0x00, 0xFE, 0x5D], [0x00, 0xFE, 0x5D], ('\u{FE5E}', Open)),
+    ([0x00, 0xFE, 0x5E], [0x00, 0xFE, 0x5E], ('\u{FE5D}', Close)),
+    ([0x00, 0xFF, 0x08], [0x00, 0xFF, 0x08], ('\u{FF09}', Open)),
+    ([0x00, 0xFF, 0x09], [0x00, 0xFF, 0x09], ('\u{FF08}', Close)),
+    ([0x00, 0xFF, 0x3B], [0x00, 0xFF, 0x3B], ('\u{FF3D}', Open)),
+    ([0x00, 0xFF, 0x3D], [0x00, 0xFF, 0x3D], ('\u{FF3B}', Close)),
+    ([0x00, 0xFF, 0x5B], [0x00, 0xFF, 0x5B], ('\u{FF5D}', Open)),
+    ([0x00, 0xFF, 0x5D], [0x00, 0xFF, 0x5D], ('\u{FF5B}', Close)),
+    ([0x00, 0xFF, 0x5F], [0x00, 0xFF, 0x5F], ('\u{FF60}', Open)),
+    ([0x00, 0xFF, 0x60], [0x00, 0xFF, 0x60], ('\u{FF5F}', Close)),
+    ([0x00, 0xFF, 0x62], [0x00, 0xFF, 0x62], ('\u{FF63}', Open)),
+    ([0x00, 0xFF, 0x63], [0x00, 0xFF, 0x63], ('\u{FF62}', Close)),
+];
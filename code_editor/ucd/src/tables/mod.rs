@@ -1,3 +1,6 @@
+pub(crate) mod bidi_class;
+pub(crate) mod bidi_mirroring_glyph;
+pub(crate) mod bidi_paired_bracket;
 pub(crate) mod extended_pictographic;
 pub(crate) mod grapheme_cluster_break;
 pub(crate) mod word_break;
@@ -0,0 +1,308 @@
+// image_formats::gif
+
+use crate::{AnimatedImage, AnimationFrame, ImageBuffer};
+
+const MAX_CODES: usize = 4096;
+const MAX_PIXELS: usize = 400_000_000;
+
+fn from_le16(src: &[u8]) -> u16 {
+    (src[0] as u16) | ((src[1] as u16) << 8)
+}
+
+fn check_length(src: &[u8], sp: usize, length: usize) -> Result<(), String> {
+    if sp + length > src.len() {
+        return Err("Invalid GIF".to_string());
+    }
+    Ok(())
+}
+
+fn read_palette(src: &[u8], sp: &mut usize, size: usize) -> Result<Vec<u32>, String> {
+    check_length(src, *sp, size * 3)?;
+    let mut palette = Vec::with_capacity(size);
+    for _ in 0..size {
+        let r = src[*sp] as u32;
+        let g = src[*sp + 1] as u32;
+        let b = src[*sp + 2] as u32;
+        *sp += 3;
+        palette.push(0xFF000000 | (r << 16) | (g << 8) | b);
+    }
+    Ok(palette)
+}
+
+// the data of an extension or image is split into blocks of at most 255 bytes
+fn read_sub_blocks(src: &[u8], sp: &mut usize) -> Result<Vec<u8>, String> {
+    let mut data = Vec::new();
+    loop {
+        check_length(src, *sp, 1)?;
+        let length = src[*sp] as usize;
+        *sp += 1;
+        if length == 0 {
+            return Ok(data);
+        }
+        check_length(src, *sp, length)?;
+        data.extend_from_slice(&src[*sp..*sp + length]);
+        *sp += length;
+    }
+}
+
+fn skip_sub_blocks(src: &[u8], sp: &mut usize) -> Result<(), String> {
+    loop {
+        check_length(src, *sp, 1)?;
+        let length = src[*sp] as usize;
+        *sp += 1 + length;
+        if length == 0 {
+            return Ok(());
+        }
+    }
+}
+
+fn decode_lzw(src: &[u8], min_code_size: u8, pixel_count: usize) -> Result<Vec<u8>, String> {
+    if !(1..=11).contains(&min_code_size) {
+        return Err("Invalid GIF".to_string());
+    }
+    let clear = 1usize << min_code_size;
+    let end = clear + 1;
+    let mut prefix = [0u16; MAX_CODES];
+    let mut suffix = [0u8; MAX_CODES];
+    let mut first = [0u8; MAX_CODES];
+    for code in 0..clear {
+        suffix[code] = code as u8;
+        first[code] = code as u8;
+    }
+    let mut next = end + 1;
+    let mut code_size = min_code_size as u32 + 1;
+    let mut prev: Option<usize> = None;
+    let mut stack: Vec<u8> = Vec::with_capacity(MAX_CODES);
+    let mut dst: Vec<u8> = Vec::with_capacity(pixel_count);
+    let mut bits: u32 = 0;
+    let mut count: u32 = 0;
+    let mut sp = 0;
+    while dst.len() < pixel_count {
+        while count < code_size && sp < src.len() {
+            bits |= (src[sp] as u32) << count;
+            count += 8;
+            sp += 1;
+        }
+        if count < code_size {
+            break;
+        }
+        let code = (bits & ((1 << code_size) - 1)) as usize;
+        bits >>= code_size;
+        count -= code_size;
+        if code == clear {
+            next = end + 1;
+            code_size = min_code_size as u32 + 1;
+            prev = None;
+            continue;
+        }
+        if code == end {
+            break;
+        }
+        let prev_code = match prev {
+            Some(prev_code) => prev_code,
+            None => {
+                if code >= clear {
+                    return Err("Invalid GIF".to_string());
+                }
+                dst.push(code as u8);
+                prev = Some(code);
+                continue;
+            }
+        };
+        // a code that is not in the table yet stands for the previous string plus its own first byte
+        let (string_code, extra) = if code < next {
+            (code, None)
+        }
+        else if code == next {
+            (prev_code, Some(first[prev_code]))
+        }
+        else {
+            return Err("Invalid GIF".to_string());
+        };
+        stack.clear();
+        let mut c = string_code;
+        while c > end {
+            stack.push(suffix[c]);
+            c = prefix[c] as usize;
+        }
+        stack.push(suffix[c]);
+        dst.extend(stack.iter().rev());
+        if let Some(byte) = extra {
+            dst.push(byte);
+        }
+        if next < MAX_CODES {
+            prefix[next] = prev_code as u16;
+            suffix[next] = first[string_code];
+            first[next] = first[prev_code];
+            next += 1;
+            if (next == (1 << code_size)) && (code_size < 12) {
+                code_size += 1;
+            }
+        }
+        prev = Some(code);
+    }
+    // truncated data leaves the rest of the frame at the first palette entry, like browsers do
+    dst.resize(pixel_count, 0);
+    Ok(dst)
+}
+
+pub fn test(src: &[u8]) -> Option<(usize, usize)> {
+    if (src.len() >= 10) && ((&src[0..6] == b"GIF87a") || (&src[0..6] == b"GIF89a")) {
+        return Some((from_le16(&src[6..8]) as usize, from_le16(&src[8..10]) as usize));
+    }
+    None
+}
+
+pub fn decode(src: &[u8]) -> Result<ImageBuffer, String> {
+    let mut animation = decode_animation(src)?;
+    Ok(animation.frames.swap_remove(0).image)
+}
+
+pub fn decode_animation(src: &[u8]) -> Result<AnimatedImage, String> {
+    let (width, height) = match test(src) {
+        Some(size) => size,
+        None => return Err("Invalid GIF".to_string()),
+    };
+    if (width == 0) || (height == 0) || (width * height > MAX_PIXELS) {
+        return Err("Invalid GIF".to_string());
+    }
+    check_length(src, 0, 13)?;
+    let flags = src[10];
+    let mut sp = 13;
+    let global_palette = if (flags & 0x80) != 0 {
+        read_palette(src, &mut sp, 2 << (flags & 7))?
+    }
+    else {
+        Vec::new()
+    };
+
+    let mut animation = AnimatedImage {
+        width,
+        height,
+        frames: Vec::new(),
+        loop_count: 1,
+    };
+    let mut canvas = vec![0u32; width * height];
+    // the graphic control extension applies to the next image only
+    let mut delay = 0;
+    let mut disposal = 0;
+    let mut transparent: Option<u8> = None;
+    while sp < src.len() {
+        let block = src[sp];
+        sp += 1;
+        match block {
+            0x21 => { // extension
+                check_length(src, sp, 1)?;
+                let label = src[sp];
+                sp += 1;
+                match label {
+                    0xF9 => { // graphic control
+                        let data = read_sub_blocks(src, &mut sp)?;
+                        if data.len() >= 4 {
+                            disposal = (data[0] >> 2) & 7;
+                            delay = from_le16(&data[1..3]);
+                            transparent = if (data[0] & 1) != 0 {Some(data[3])} else {None};
+                        }
+                    },
+                    0xFF => { // application
+                        let data = read_sub_blocks(src, &mut sp)?;
+                        if (data.len() >= 14) && ((&data[0..11] == b"NETSCAPE2.0") || (&data[0..11] == b"ANIMEXTS1.0")) && (data[11] == 1) {
+                            // the count is the number of repeats after the first time
+                            let repeats = from_le16(&data[12..14]) as usize;
+                            animation.loop_count = if repeats == 0 {0} else {repeats + 1};
+                        }
+                    },
+                    _ => {
+                        skip_sub_blocks(src, &mut sp)?;
+                    },
+                }
+            },
+            0x2C => { // image
+                check_length(src, sp, 9)?;
+                let x0 = from_le16(&src[sp..]) as usize;
+                let y0 = from_le16(&src[sp + 2..]) as usize;
+                let frame_width = from_le16(&src[sp + 4..]) as usize;
+                let frame_height = from_le16(&src[sp + 6..]) as usize;
+                let frame_flags = src[sp + 8];
+                sp += 9;
+                let local_palette = if (frame_flags & 0x80) != 0 {
+                    Some(read_palette(src, &mut sp, 2 << (frame_flags & 7))?)
+                }
+                else {
+                    None
+                };
+                let palette = local_palette.as_ref().unwrap_or(&global_palette);
+                let interlaced = (frame_flags & 0x40) != 0;
+                check_length(src, sp, 1)?;
+                let min_code_size = src[sp];
+                sp += 1;
+                let data = read_sub_blocks(src, &mut sp)?;
+                let indices = decode_lzw(&data, min_code_size, frame_width * frame_height)?;
+
+                let previous = if disposal == 3 {Some(canvas.clone())} else {None};
+                let mut rows: Vec<usize> = Vec::with_capacity(frame_height);
+                if interlaced {
+                    for (start, step) in [(0, 8), (4, 8), (2, 4), (1, 2)] {
+                        rows.extend((start..frame_height).step_by(step));
+                    }
+                }
+                else {
+                    rows.extend(0..frame_height);
+                }
+                for (i, row) in rows.iter().enumerate() {
+                    let y = y0 + row;
+                    if y >= height {
+                        continue;
+                    }
+                    for x in 0..frame_width {
+                        if x0 + x >= width {
+                            break;
+                        }
+                        let index = indices[i * frame_width + x];
+                        if transparent == Some(index) {
+                            continue;
+                        }
+                        // indices past the end of the palette are black
+                        canvas[y * width + x0 + x] = palette.get(index as usize).cloned().unwrap_or(0xFF000000);
+                    }
+                }
+
+                // browsers show frames without a delay, or with the shortest one, for 100 ms
+                let centiseconds = if delay <= 1 {10} else {delay};
+                animation.frames.push(AnimationFrame {
+                    image: ImageBuffer {width, height, data: canvas.clone()},
+                    delay: centiseconds as f64 / 100.0,
+                });
+
+                match disposal {
+                    2 => { // restore to background, which browsers treat as transparent
+                        for y in y0..(y0 + frame_height).min(height) {
+                            for x in x0..(x0 + frame_width).min(width) {
+                                canvas[y * width + x] = 0;
+                            }
+                        }
+                    },
+                    3 => { // restore to previous
+                        if let Some(previous) = previous {
+                            canvas = previous;
+                        }
+                    },
+                    _ => {},
+                }
+                delay = 0;
+                disposal = 0;
+                transparent = None;
+            },
+            0x3B => { // trailer
+                break;
+            },
+            _ => {
+                return Err("Invalid GIF".to_string());
+            },
+        }
+    }
+    if animation.frames.is_empty() {
+        return Err("Invalid GIF".to_string());
+    }
+    Ok(animation)
+}
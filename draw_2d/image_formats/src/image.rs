@@ -1,7 +1,7 @@
 // image_formats::image
 // by Desmond Germans, 2019
 
-#[derive(Default)] 
+#[derive(Clone, Default)] 
 pub struct ImageBuffer {
     pub width: usize,
     pub height: usize,
@@ -17,3 +17,55 @@ impl ImageBuffer {
         }
     }
 }
+
+/// One frame of an animation, already composited onto the full canvas.
+#[derive(Clone, Default)]
+pub struct AnimationFrame {
+    pub image: ImageBuffer,
+    /// How long the frame is shown, in seconds.
+    pub delay: f64,
+}
+
+#[derive(Clone, Default)]
+pub struct AnimatedImage {
+    pub width: usize,
+    pub height: usize,
+    pub frames: Vec<AnimationFrame>,
+    /// How many times the animation plays, or 0 to repeat it forever.
+    pub loop_count: usize,
+}
+
+impl AnimatedImage {
+    pub fn from_image(image: ImageBuffer) -> AnimatedImage {
+        AnimatedImage {
+            width: image.width,
+            height: image.height,
+            frames: vec![AnimationFrame {image, delay: 0.0}],
+            loop_count: 1,
+        }
+    }
+    
+    pub fn duration(&self) -> f64 {
+        self.frames.iter().map( | frame | frame.delay).sum()
+    }
+    
+    /// Returns the index of the frame that is shown `time` seconds after the animation started.
+    /// Once the last loop is over, the last frame stays on.
+    pub fn frame_at_time(&self, time: f64) -> usize {
+        let duration = self.duration();
+        if self.frames.len() <= 1 || duration <= 0.0 {
+            return 0;
+        }
+        if self.loop_count != 0 && time >= duration * self.loop_count as f64 {
+            return self.frames.len() - 1;
+        }
+        let mut time = time.max(0.0) % duration;
+        for (index, frame) in self.frames.iter().enumerate() {
+            if time < frame.delay {
+                return index;
+            }
+            time -= frame.delay;
+        }
+        self.frames.len() - 1
+    }
+}
@@ -922,8 +922,8 @@ pub fn test(src: &[u8]) -> Option<(usize, usize)> {
         let length = from_be16(&src[sp + 2..sp + 4]) as usize;
         match marker {
             0xFFC0 | 0xFFC1 | 0xFFC2 => {
-                let width = from_be16(&src[sp + 5..sp + 7]) as usize;
-                let height = from_be16(&src[sp + 7..sp + 9]) as usize;
+                let height = from_be16(&src[sp + 5..sp + 7]) as usize;
+                let width = from_be16(&src[sp + 7..sp + 9]) as usize;
                 let components = src[sp + 9];
                 if (components == 1) || (components == 3) { // does not support RGBA or CMYK JPEGs
                     return Some((width, height));
@@ -1238,6 +1238,329 @@ pub fn decode(src: &[u8]) -> Result<ImageBuffer, String> {
     Err("Invalid JPEG 11".to_string())
 }
 
-pub fn encode(_image: &ImageBuffer) -> Result<Vec<u8>, String> {
-    Err("not implemented yet".to_string())
+// natural (row major) index of each coefficient in zigzag order
+const ZIGZAG: [u8; 64] = [0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20, 13, 6, 7, 14, 21, 28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59, 52, 45, 38, 31, 39, 46, 53, 60, 61, 54, 47, 55, 62, 63,];
+
+// the example tables from Annex K of the JPEG standard, in natural order
+const LUMINANCE_QTABLE: [u8; 64] = [
+    16, 11, 10, 16, 24, 40, 51, 61,
+    12, 12, 14, 19, 26, 58, 60, 55,
+    14, 13, 16, 24, 40, 57, 69, 56,
+    14, 17, 22, 29, 51, 87, 80, 62,
+    18, 22, 37, 56, 68, 109, 103, 77,
+    24, 35, 55, 64, 81, 104, 113, 92,
+    49, 64, 78, 87, 103, 121, 120, 101,
+    72, 92, 95, 98, 112, 100, 103, 99,
+];
+const CHROMINANCE_QTABLE: [u8; 64] = [
+    17, 18, 24, 47, 99, 99, 99, 99,
+    18, 21, 26, 66, 99, 99, 99, 99,
+    24, 26, 56, 99, 99, 99, 99, 99,
+    47, 66, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99,
+];
+
+const LUMINANCE_DC_BITS: [u8; 16] = [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+const LUMINANCE_DC_VALUES: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+const CHROMINANCE_DC_BITS: [u8; 16] = [0, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0];
+const CHROMINANCE_DC_VALUES: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+const LUMINANCE_AC_BITS: [u8; 16] = [0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 1, 0x7D];
+const LUMINANCE_AC_VALUES: [u8; 162] = [
+    0x01, 0x02, 0x03, 0x00, 0x04, 0x11, 0x05, 0x12, 0x21, 0x31, 0x41, 0x06, 0x13, 0x51, 0x61, 0x07,
+    0x22, 0x71, 0x14, 0x32, 0x81, 0x91, 0xA1, 0x08, 0x23, 0x42, 0xB1, 0xC1, 0x15, 0x52, 0xD1, 0xF0,
+    0x24, 0x33, 0x62, 0x72, 0x82, 0x09, 0x0A, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x25, 0x26, 0x27, 0x28,
+    0x29, 0x2A, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4A, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89,
+    0x8A, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0xA2, 0xA3, 0xA4, 0xA5, 0xA6, 0xA7,
+    0xA8, 0xA9, 0xAA, 0xB2, 0xB3, 0xB4, 0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xC2, 0xC3, 0xC4, 0xC5,
+    0xC6, 0xC7, 0xC8, 0xC9, 0xCA, 0xD2, 0xD3, 0xD4, 0xD5, 0xD6, 0xD7, 0xD8, 0xD9, 0xDA, 0xE1, 0xE2,
+    0xE3, 0xE4, 0xE5, 0xE6, 0xE7, 0xE8, 0xE9, 0xEA, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8,
+    0xF9, 0xFA,
+];
+const CHROMINANCE_AC_BITS: [u8; 16] = [0, 2, 1, 2, 4, 4, 3, 4, 7, 5, 4, 4, 0, 1, 2, 0x77];
+const CHROMINANCE_AC_VALUES: [u8; 162] = [
+    0x00, 0x01, 0x02, 0x03, 0x11, 0x04, 0x05, 0x21, 0x31, 0x06, 0x12, 0x41, 0x51, 0x07, 0x61, 0x71,
+    0x13, 0x22, 0x32, 0x81, 0x08, 0x14, 0x42, 0x91, 0xA1, 0xB1, 0xC1, 0x09, 0x23, 0x33, 0x52, 0xF0,
+    0x15, 0x62, 0x72, 0xD1, 0x0A, 0x16, 0x24, 0x34, 0xE1, 0x25, 0xF1, 0x17, 0x18, 0x19, 0x1A, 0x26,
+    0x27, 0x28, 0x29, 0x2A, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4A, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6A, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87,
+    0x88, 0x89, 0x8A, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0xA2, 0xA3, 0xA4, 0xA5,
+    0xA6, 0xA7, 0xA8, 0xA9, 0xAA, 0xB2, 0xB3, 0xB4, 0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xC2, 0xC3,
+    0xC4, 0xC5, 0xC6, 0xC7, 0xC8, 0xC9, 0xCA, 0xD2, 0xD3, 0xD4, 0xD5, 0xD6, 0xD7, 0xD8, 0xD9, 0xDA,
+    0xE2, 0xE3, 0xE4, 0xE5, 0xE6, 0xE7, 0xE8, 0xE9, 0xEA, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8,
+    0xF9, 0xFA,
+];
+
+// chroma subsampling used by the encoder
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JpegSubsampling {
+    // half resolution chroma in both directions
+    Yuv420,
+    // full resolution chroma
+    Yuv444,
+}
+
+// the code and length of each symbol of a huffman table
+struct EncodeTable {
+    codes: [u16; 256],
+    lengths: [u8; 256],
+}
+
+impl EncodeTable {
+    fn new(bits: &[u8; 16], values: &[u8]) -> EncodeTable {
+        let mut table = EncodeTable {codes: [0; 256], lengths: [0; 256]};
+        let mut code: u16 = 0;
+        let mut k = 0;
+        for i in 0..16 {
+            for _ in 0..bits[i] {
+                table.codes[values[k] as usize] = code;
+                table.lengths[values[k] as usize] = (i + 1) as u8;
+                code += 1;
+                k += 1;
+            }
+            code <<= 1;
+        }
+        table
+    }
+}
+
+struct Writer {
+    dst: Vec<u8>,
+    bits: u32,
+    count: u32,
+}
+
+impl Writer {
+    fn put_bits(&mut self, value: u32, length: u8) {
+        self.bits = (self.bits << length) | (value & ((1 << length) - 1));
+        self.count += length as u32;
+        while self.count >= 8 {
+            self.count -= 8;
+            let byte = ((self.bits >> self.count) & 255) as u8;
+            self.dst.push(byte);
+            if byte == 0xFF { // byte stuffing
+                self.dst.push(0);
+            }
+        }
+    }
+    
+    fn put_code(&mut self, table: &EncodeTable, symbol: u8) {
+        self.put_bits(table.codes[symbol as usize] as u32, table.lengths[symbol as usize]);
+    }
+    
+    fn flush(&mut self) {
+        // pad the last byte with ones
+        if self.count > 0 {
+            self.put_bits(0x7F, (8 - self.count) as u8);
+        }
+    }
+}
+
+fn scale_qtable(qtable: &[u8; 64], quality: u8) -> [u8; 64] {
+    // the scaling of the IJG reference encoder
+    let quality = clamp(quality as i32, 1, 100);
+    let scale = if quality < 50 {5000 / quality} else {200 - quality * 2};
+    let mut result = [0u8; 64];
+    for i in 0..64 {
+        result[i] = clamp((qtable[i] as i32 * scale + 50) / 100, 1, 255) as u8;
+    }
+    result
+}
+
+fn forward_dct(block: &[f32; 64], qtable: &[u8; 64], out: &mut [i32; 64]) {
+    let mut cos = [[0f32; 8]; 8];
+    for x in 0..8 {
+        for u in 0..8 {
+            cos[x][u] = (((2 * x + 1) * u) as f32 * std::f32::consts::PI / 16.0).cos();
+        }
+    }
+    // rows, then columns
+    let mut rows = [0f32; 64];
+    for y in 0..8 {
+        for u in 0..8 {
+            let mut sum = 0.0;
+            for x in 0..8 {
+                sum += block[y * 8 + x] * cos[x][u];
+            }
+            rows[y * 8 + u] = sum;
+        }
+    }
+    for v in 0..8 {
+        for u in 0..8 {
+            let mut sum = 0.0;
+            for y in 0..8 {
+                sum += rows[y * 8 + u] * cos[y][v];
+            }
+            let cu = if u == 0 {std::f32::consts::FRAC_1_SQRT_2} else {1.0};
+            let cv = if v == 0 {std::f32::consts::FRAC_1_SQRT_2} else {1.0};
+            let coeff = 0.25 * cu * cv * sum;
+            out[v * 8 + u] = (coeff / qtable[v * 8 + u] as f32).round() as i32;
+        }
+    }
+}
+
+fn category(value: i32) -> u8 {
+    (32 - value.unsigned_abs().leading_zeros()) as u8
+}
+
+fn encode_block(writer: &mut Writer, block: &[f32; 64], qtable: &[u8; 64], dcht: &EncodeTable, acht: &EncodeTable, dc: &mut i32) {
+    let mut coeffs = [0i32; 64];
+    forward_dct(block, qtable, &mut coeffs);
+    
+    let diff = coeffs[0] - *dc;
+    *dc = coeffs[0];
+    let cat = category(diff);
+    writer.put_code(dcht, cat);
+    if cat > 0 {
+        writer.put_bits(if diff < 0 {(diff - 1) as u32} else {diff as u32}, cat);
+    }
+    
+    let mut run = 0;
+    for k in 1..64 {
+        let coeff = coeffs[ZIGZAG[k] as usize];
+        if coeff == 0 {
+            run += 1;
+            continue;
+        }
+        while run >= 16 {
+            writer.put_code(acht, 0xF0);
+            run -= 16;
+        }
+        let cat = category(coeff);
+        writer.put_code(acht, (run << 4) | cat);
+        writer.put_bits(if coeff < 0 {(coeff - 1) as u32} else {coeff as u32}, cat);
+        run = 0;
+    }
+    if run > 0 {
+        writer.put_code(acht, 0x00); // end of block
+    }
+}
+
+fn push_be16(dst: &mut Vec<u8>, value: u16) {
+    dst.extend_from_slice(&value.to_be_bytes());
+}
+
+pub fn encode(image: &ImageBuffer) -> Result<Vec<u8>, String> {
+    encode_with_options(image, 90, JpegSubsampling::Yuv420)
+}
+
+// baseline JPEG, quality 1..100 scales the quantization tables like the IJG encoder, alpha is dropped
+pub fn encode_with_options(image: &ImageBuffer, quality: u8, subsampling: JpegSubsampling) -> Result<Vec<u8>, String> {
+    if (image.width == 0) || (image.height == 0) || (image.width >= 65536) || (image.height >= 65536) {
+        return Err("invalid image size".to_string());
+    }
+    if image.data.len() < image.width * image.height {
+        return Err("image data too small".to_string());
+    }
+    let qtables = [scale_qtable(&LUMINANCE_QTABLE, quality), scale_qtable(&CHROMINANCE_QTABLE, quality)];
+    let (samp, mbsize) = match subsampling {
+        JpegSubsampling::Yuv420 => (0x22u8, 16),
+        JpegSubsampling::Yuv444 => (0x11u8, 8),
+    };
+    
+    let mut dst: Vec<u8> = Vec::new();
+    push_be16(&mut dst, 0xFFD8); // image start
+    push_be16(&mut dst, 0xFFE0); // JFIF header
+    push_be16(&mut dst, 16);
+    dst.extend_from_slice(b"JFIF\0");
+    dst.extend_from_slice(&[1, 1, 0, 0, 1, 0, 1, 0, 0]);
+    push_be16(&mut dst, 0xFFDB); // quantization tables
+    push_be16(&mut dst, 2 + 2 * 65);
+    for (n, qtable) in qtables.iter().enumerate() {
+        dst.push(n as u8);
+        for k in 0..64 {
+            dst.push(qtable[ZIGZAG[k] as usize]);
+        }
+    }
+    push_be16(&mut dst, 0xFFC0); // baseline sequential
+    push_be16(&mut dst, 17);
+    dst.push(8);
+    push_be16(&mut dst, image.height as u16);
+    push_be16(&mut dst, image.width as u16);
+    dst.push(3);
+    dst.extend_from_slice(&[1, samp, 0, 2, 0x11, 1, 3, 0x11, 1]);
+    push_be16(&mut dst, 0xFFC4); // huffman tables
+    let huffman_tables: [(u8, &[u8; 16], &[u8]); 4] = [
+        (0x00, &LUMINANCE_DC_BITS, &LUMINANCE_DC_VALUES),
+        (0x10, &LUMINANCE_AC_BITS, &LUMINANCE_AC_VALUES),
+        (0x01, &CHROMINANCE_DC_BITS, &CHROMINANCE_DC_VALUES),
+        (0x11, &CHROMINANCE_AC_BITS, &CHROMINANCE_AC_VALUES),
+    ];
+    let length: usize = huffman_tables.iter().map( | (_, _, values) | 17 + values.len()).sum();
+    push_be16(&mut dst, 2 + length as u16);
+    for (d, bits, values) in huffman_tables.iter() {
+        dst.push(*d);
+        dst.extend_from_slice(*bits);
+        dst.extend_from_slice(values);
+    }
+    push_be16(&mut dst, 0xFFDA); // scan start
+    push_be16(&mut dst, 12);
+    dst.extend_from_slice(&[3, 1, 0x00, 2, 0x11, 3, 0x11, 0, 63, 0]);
+    
+    let dcht = [EncodeTable::new(&LUMINANCE_DC_BITS, &LUMINANCE_DC_VALUES), EncodeTable::new(&CHROMINANCE_DC_BITS, &CHROMINANCE_DC_VALUES)];
+    let acht = [EncodeTable::new(&LUMINANCE_AC_BITS, &LUMINANCE_AC_VALUES), EncodeTable::new(&CHROMINANCE_AC_BITS, &CHROMINANCE_AC_VALUES)];
+    let mut writer = Writer {dst, bits: 0, count: 0};
+    let mut dc = [0i32; 3];
+    let mut y_mb = [0f32; 256];
+    let mut u_mb = [0f32; 256];
+    let mut v_mb = [0f32; 256];
+    let mut block = [0f32; 64];
+    let mbwidth = image.width.div_ceil(mbsize);
+    let mbheight = image.height.div_ceil(mbsize);
+    for mby in 0..mbheight {
+        for mbx in 0..mbwidth {
+            // convert the macroblock to YCbCr, repeating the edge pixels past the image
+            for i in 0..mbsize {
+                let py = std::cmp::min(mby * mbsize + i, image.height - 1);
+                for k in 0..mbsize {
+                    let px = std::cmp::min(mbx * mbsize + k, image.width - 1);
+                    let pixel = image.data[py * image.width + px];
+                    let r = ((pixel >> 16) & 255) as f32;
+                    let g = ((pixel >> 8) & 255) as f32;
+                    let b = (pixel & 255) as f32;
+                    y_mb[i * mbsize + k] = 0.299 * r + 0.587 * g + 0.114 * b - 128.0;
+                    u_mb[i * mbsize + k] = -0.168736 * r - 0.331264 * g + 0.5 * b;
+                    v_mb[i * mbsize + k] = 0.5 * r - 0.418688 * g - 0.081312 * b;
+                }
+            }
+            if mbsize == 16 {
+                for by in 0..2 {
+                    for bx in 0..2 {
+                        for i in 0..8 {
+                            for k in 0..8 {
+                                block[i * 8 + k] = y_mb[(by * 8 + i) * 16 + bx * 8 + k];
+                            }
+                        }
+                        encode_block(&mut writer, &block, &qtables[0], &dcht[0], &acht[0], &mut dc[0]);
+                    }
+                }
+                for (c, mb) in [&u_mb, &v_mb].iter().enumerate() {
+                    for i in 0..8 {
+                        for k in 0..8 {
+                            let p = i * 32 + k * 2;
+                            block[i * 8 + k] = 0.25 * (mb[p] + mb[p + 1] + mb[p + 16] + mb[p + 17]);
+                        }
+                    }
+                    encode_block(&mut writer, &block, &qtables[1], &dcht[1], &acht[1], &mut dc[c + 1]);
+                }
+            }
+            else {
+                block.copy_from_slice(&y_mb[0..64]);
+                encode_block(&mut writer, &block, &qtables[0], &dcht[0], &acht[0], &mut dc[0]);
+                block.copy_from_slice(&u_mb[0..64]);
+                encode_block(&mut writer, &block, &qtables[1], &dcht[1], &acht[1], &mut dc[1]);
+                block.copy_from_slice(&v_mb[0..64]);
+                encode_block(&mut writer, &block, &qtables[1], &dcht[1], &acht[1], &mut dc[2]);
+            }
+        }
+    }
+    writer.flush();
+    let mut dst = writer.dst;
+    push_be16(&mut dst, 0xFFD9); // image end
+    Ok(dst)
 }
@@ -8,4 +8,7 @@ pub use image::*;
 pub mod bmp;
 pub mod png;
 pub mod jpeg;
+pub mod gif;
+pub mod webp;
+pub mod qoi;
 
@@ -1037,6 +1037,139 @@ pub fn decode(src: &[u8]) -> Result<ImageBuffer, String> {
     }
 }
 
-pub fn encode(_image: &ImageBuffer) -> Result<Vec<u8>, String> {
-    Err("not implemented".to_string())
+// row filter used by the encoder
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PngFilter {
+    None,
+    Sub,
+    Up,
+    Average,
+    Paeth,
+    // per row, the filter with the smallest sum of absolute differences
+    Adaptive,
+}
+
+fn crc32(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if (crc & 1) != 0 {(crc >> 1) ^ 0xEDB88320} else {crc >> 1};
+        }
+    }
+    !crc
+}
+
+fn push_be32(dst: &mut Vec<u8>, value: u32) {
+    dst.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_chunk(dst: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    push_be32(dst, data.len() as u32);
+    let start = dst.len();
+    dst.extend_from_slice(chunk_type);
+    dst.extend_from_slice(data);
+    let crc = crc32(0, &dst[start..]);
+    push_be32(dst, crc);
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let d = a as i32 + b as i32 - c as i32;
+    let pa = (d - a as i32).abs();
+    let pb = (d - b as i32).abs();
+    let pc = (d - c as i32).abs();
+    if (pa <= pb) && (pa <= pc) {a} else if pb <= pc {b} else {c}
+}
+
+fn filter_row(dst: &mut Vec<u8>, row: &[u8], prev: &[u8], bpp: usize, ftype: u8) {
+    dst.push(ftype);
+    for x in 0..row.len() {
+        let a = if x >= bpp {row[x - bpp]} else {0};
+        let b = prev[x];
+        let c = if x >= bpp {prev[x - bpp]} else {0};
+        let predicted = match ftype {
+            1 => a,
+            2 => b,
+            3 => ((a as u16 + b as u16) >> 1) as u8,
+            4 => paeth(a, b, c),
+            _ => 0,
+        };
+        dst.push(row[x].wrapping_sub(predicted));
+    }
+}
+
+fn filter(raw_data: &[u8], height: usize, stride: usize, bpp: usize, filter: PngFilter) -> Vec<u8> {
+    let mut dst: Vec<u8> = Vec::with_capacity((stride + 1) * height);
+    let zero_row = vec![0u8; stride];
+    let mut candidate: Vec<u8> = Vec::with_capacity(stride + 1);
+    for y in 0..height {
+        let row = &raw_data[y * stride..(y + 1) * stride];
+        let prev = if y >= 1 {&raw_data[(y - 1) * stride..y * stride]} else {&zero_row[..]};
+        let ftype = match filter {
+            PngFilter::None => 0,
+            PngFilter::Sub => 1,
+            PngFilter::Up => 2,
+            PngFilter::Average => 3,
+            PngFilter::Paeth => 4,
+            PngFilter::Adaptive => {
+                // bytes are scored as signed values, so small differences either way score low
+                let mut best_ftype = 0;
+                let mut best_score = u64::MAX;
+                for ftype in 0..5 {
+                    candidate.clear();
+                    filter_row(&mut candidate, row, prev, bpp, ftype);
+                    let score = candidate[1..].iter().map( | byte | (*byte as i8).unsigned_abs() as u64).sum();
+                    if score < best_score {
+                        best_score = score;
+                        best_ftype = ftype;
+                    }
+                }
+                best_ftype
+            },
+        };
+        filter_row(&mut dst, row, prev, bpp, ftype);
+    }
+    dst
+}
+
+pub fn encode(image: &ImageBuffer) -> Result<Vec<u8>, String> {
+    encode_with_filter(image, PngFilter::Adaptive)
+}
+
+// 8-bit PNG, with alpha only if the image has transparent pixels
+pub fn encode_with_filter(image: &ImageBuffer, filter_type: PngFilter) -> Result<Vec<u8>, String> {
+    if (image.width == 0) || (image.height == 0) || (image.width >= 65536) || (image.height >= 65536) {
+        return Err("invalid image size".to_string());
+    }
+    if image.data.len() < image.width * image.height {
+        return Err("image data too small".to_string());
+    }
+    let pixels = &image.data[0..image.width * image.height];
+    let has_alpha = pixels.iter().any( | pixel | (pixel >> 24) != 255);
+    let (itype, bpp) = if has_alpha {(TYPE_RGBA8, 4)} else {(TYPE_RGB8, 3)};
+    let stride = image.width * bpp;
+    let mut raw_data: Vec<u8> = Vec::with_capacity(stride * image.height);
+    for pixel in pixels {
+        raw_data.push(((pixel >> 16) & 255) as u8);
+        raw_data.push(((pixel >> 8) & 255) as u8);
+        raw_data.push((pixel & 255) as u8);
+        if has_alpha {
+            raw_data.push((pixel >> 24) as u8);
+        }
+    }
+    let filtered_data = filter(&raw_data, image.height, stride, bpp, filter_type);
+    let zipped_data = makepad_miniz::compress_to_vec_zlib(&filtered_data, 6);
+    
+    let mut dst: Vec<u8> = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+    let mut ihdr: Vec<u8> = Vec::new();
+    push_be32(&mut ihdr, image.width as u32);
+    push_be32(&mut ihdr, image.height as u32);
+    ihdr.extend_from_slice(&itype.to_be_bytes()); // bit depth and color type
+    ihdr.push(0); // compression
+    ihdr.push(0); // filter
+    ihdr.push(0); // interlace
+    push_chunk(&mut dst, b"IHDR", &ihdr);
+    push_chunk(&mut dst, b"IDAT", &zipped_data);
+    push_chunk(&mut dst, b"IEND", &[]);
+    Ok(dst)
 }
\ No newline at end of file
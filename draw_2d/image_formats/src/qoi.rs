@@ -0,0 +1,108 @@
+// image_formats::qoi
+
+use crate::ImageBuffer;
+
+const QOI_OP_INDEX: u8 = 0x00;
+const QOI_OP_DIFF: u8 = 0x40;
+const QOI_OP_LUMA: u8 = 0x80;
+const QOI_OP_RGB: u8 = 0xFE;
+const QOI_OP_RGBA: u8 = 0xFF;
+const QOI_MASK: u8 = 0xC0;
+
+const HEADER_SIZE: usize = 14;
+const MAX_PIXELS: usize = 400_000_000;
+
+fn from_be32(src: &[u8]) -> u32 {
+    ((src[0] as u32) << 24) | ((src[1] as u32) << 16) | ((src[2] as u32) << 8) | (src[3] as u32)
+}
+
+fn hash(r: u8, g: u8, b: u8, a: u8) -> usize {
+    (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64
+}
+
+pub fn test(src: &[u8]) -> Option<(usize, usize)> {
+    if (src.len() >= HEADER_SIZE) && (&src[0..4] == b"qoif") {
+        return Some((from_be32(&src[4..8]) as usize, from_be32(&src[8..12]) as usize));
+    }
+    None
+}
+
+pub fn decode(src: &[u8]) -> Result<ImageBuffer, String> {
+    let (width, height) = match test(src) {
+        Some(size) => size,
+        None => return Err("Invalid QOI".to_string()),
+    };
+    let channels = src[12];
+    if (width == 0) || (height == 0) || (width * height > MAX_PIXELS) || ((channels != 3) && (channels != 4)) {
+        return Err("Invalid QOI".to_string());
+    }
+    let mut image = ImageBuffer::new(width, height);
+    let mut index = [[0u8; 4]; 64];
+    let (mut r, mut g, mut b, mut a) = (0u8, 0u8, 0u8, 255u8);
+    let mut run = 0;
+    let mut sp = HEADER_SIZE;
+    for pixel in image.data.iter_mut() {
+        if run > 0 {
+            run -= 1;
+        }
+        else {
+            if sp >= src.len() {
+                return Err("Invalid QOI".to_string());
+            }
+            let op = src[sp];
+            sp += 1;
+            if op == QOI_OP_RGB {
+                if sp + 3 > src.len() {
+                    return Err("Invalid QOI".to_string());
+                }
+                r = src[sp];
+                g = src[sp + 1];
+                b = src[sp + 2];
+                sp += 3;
+            }
+            else if op == QOI_OP_RGBA {
+                if sp + 4 > src.len() {
+                    return Err("Invalid QOI".to_string());
+                }
+                r = src[sp];
+                g = src[sp + 1];
+                b = src[sp + 2];
+                a = src[sp + 3];
+                sp += 4;
+            }
+            else {
+                match op & QOI_MASK {
+                    QOI_OP_INDEX => {
+                        let color = index[op as usize];
+                        r = color[0];
+                        g = color[1];
+                        b = color[2];
+                        a = color[3];
+                    },
+                    QOI_OP_DIFF => {
+                        r = r.wrapping_add((op >> 4) & 3).wrapping_sub(2);
+                        g = g.wrapping_add((op >> 2) & 3).wrapping_sub(2);
+                        b = b.wrapping_add(op & 3).wrapping_sub(2);
+                    },
+                    QOI_OP_LUMA => {
+                        if sp >= src.len() {
+                            return Err("Invalid QOI".to_string());
+                        }
+                        let dg = (op & 0x3F).wrapping_sub(32);
+                        let d = src[sp];
+                        sp += 1;
+                        r = r.wrapping_add(dg).wrapping_add(d >> 4).wrapping_sub(8);
+                        g = g.wrapping_add(dg);
+                        b = b.wrapping_add(dg).wrapping_add(d & 15).wrapping_sub(8);
+                    },
+                    _ => { // run
+                        run = (op & 0x3F) as usize;
+                    },
+                }
+            }
+            index[hash(r, g, b, a)] = [r, g, b, a];
+        }
+        *pixel = ((a as u32) << 24) | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32);
+    }
+    Ok(image)
+}
@@ -0,0 +1,526 @@
+// image_formats::webp::lossless
+// VP8L, as described in RFC 9649
+
+use crate::ImageBuffer;
+
+const CODE_LENGTH_ORDER: [usize; 19] = [17, 18, 0, 1, 2, 3, 4, 5, 16, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+// the first 120 distance codes are offsets to nearby pixels, as (dx, dy)
+const DISTANCE_MAP: [(i8, i8); 120] = [
+    (0, 1), (1, 0), (1, 1), (-1, 1), (0, 2), (2, 0), (1, 2), (-1, 2),
+    (2, 1), (-2, 1), (2, 2), (-2, 2), (0, 3), (3, 0), (1, 3), (-1, 3),
+    (3, 1), (-3, 1), (2, 3), (-2, 3), (3, 2), (-3, 2), (0, 4), (4, 0),
+    (1, 4), (-1, 4), (4, 1), (-4, 1), (3, 3), (-3, 3), (2, 4), (-2, 4),
+    (4, 2), (-4, 2), (0, 5), (3, 4), (-3, 4), (4, 3), (-4, 3), (5, 0),
+    (1, 5), (-1, 5), (5, 1), (-5, 1), (2, 5), (-2, 5), (5, 2), (-5, 2),
+    (4, 4), (-4, 4), (3, 5), (-3, 5), (5, 3), (-5, 3), (0, 6), (6, 0),
+    (1, 6), (-1, 6), (6, 1), (-6, 1), (2, 6), (-2, 6), (6, 2), (-6, 2),
+    (4, 5), (-4, 5), (5, 4), (-5, 4), (3, 6), (-3, 6), (6, 3), (-6, 3),
+    (0, 7), (7, 0), (1, 7), (-1, 7), (5, 5), (-5, 5), (7, 1), (-7, 1),
+    (4, 6), (-4, 6), (6, 4), (-6, 4), (2, 7), (-2, 7), (7, 2), (-7, 2),
+    (3, 7), (-3, 7), (7, 3), (-7, 3), (5, 6), (-5, 6), (6, 5), (-6, 5),
+    (8, 0), (4, 7), (-4, 7), (7, 4), (-7, 4), (8, 1), (8, 2), (6, 6),
+    (-6, 6), (8, 3), (5, 7), (-5, 7), (7, 5), (-7, 5), (8, 4), (6, 7),
+    (-6, 7), (7, 6), (-7, 6), (8, 5), (7, 7), (-7, 7), (8, 6), (8, 7),
+];
+
+const GREEN: usize = 0;
+const RED: usize = 1;
+const BLUE: usize = 2;
+const ALPHA: usize = 3;
+const DISTANCE: usize = 4;
+
+const MAX_PIXELS: usize = 400_000_000;
+
+struct BitReader<'a> {
+    src: &'a [u8],
+    sp: usize,
+    bits: u64,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(src: &'a [u8]) -> BitReader<'a> {
+        BitReader {
+            src,
+            sp: 0,
+            bits: 0,
+            count: 0,
+        }
+    }
+
+    fn read(&mut self, n: u32) -> Result<u32, String> {
+        while self.count < n {
+            if self.sp >= self.src.len() {
+                return Err("Invalid WebP".to_string());
+            }
+            self.bits |= (self.src[self.sp] as u64) << self.count;
+            self.sp += 1;
+            self.count += 8;
+        }
+        let value = (self.bits & ((1 << n) - 1)) as u32;
+        self.bits >>= n;
+        self.count -= n;
+        Ok(value)
+    }
+
+    fn read_flag(&mut self) -> Result<bool, String> {
+        Ok(self.read(1)? != 0)
+    }
+}
+
+// canonical prefix code, decoded one bit at a time
+struct PrefixCode {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+    single: Option<u16>,
+}
+
+impl PrefixCode {
+    fn from_lengths(lengths: &[u8]) -> Result<PrefixCode, String> {
+        let mut counts = [0u16; 16];
+        for length in lengths {
+            counts[*length as usize] += 1;
+        }
+        counts[0] = 0;
+        let used: Vec<usize> = (0..lengths.len()).filter( | i | lengths[*i] != 0).collect();
+        // a code with one symbol takes no bits at all
+        if used.len() <= 1 {
+            return Ok(PrefixCode {
+                counts,
+                symbols: Vec::new(),
+                single: Some(used.first().cloned().unwrap_or(0) as u16),
+            });
+        }
+        let mut left: i32 = 1;
+        for count in &counts[1..] {
+            left = (left << 1) - *count as i32;
+            if left < 0 {
+                return Err("Invalid WebP".to_string());
+            }
+        }
+        let mut offsets = [0usize; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length] as usize;
+        }
+        let mut symbols = vec![0u16; used.len()];
+        for symbol in used {
+            let length = lengths[symbol] as usize;
+            symbols[offsets[length]] = symbol as u16;
+            offsets[length] += 1;
+        }
+        Ok(PrefixCode {
+            counts,
+            symbols,
+            single: None,
+        })
+    }
+
+    fn read_symbol(&self, br: &mut BitReader) -> Result<usize, String> {
+        if let Some(symbol) = self.single {
+            return Ok(symbol as usize);
+        }
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+        for length in 1..16 {
+            code |= br.read(1)? as i32;
+            let count = self.counts[length] as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize] as usize);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("Invalid WebP".to_string())
+    }
+}
+
+fn read_prefix_code(br: &mut BitReader, alphabet_size: usize) -> Result<PrefixCode, String> {
+    let mut lengths = vec![0u8; alphabet_size];
+    if br.read_flag()? {
+        // simple code of one or two symbols
+        let two_symbols = br.read_flag()?;
+        let first_bits = if br.read_flag()? {8} else {1};
+        let first = br.read(first_bits)? as usize;
+        if first >= alphabet_size {
+            return Err("Invalid WebP".to_string());
+        }
+        lengths[first] = 1;
+        if two_symbols {
+            let second = br.read(8)? as usize;
+            if second >= alphabet_size {
+                return Err("Invalid WebP".to_string());
+            }
+            lengths[second] = 1;
+        }
+        return PrefixCode::from_lengths(&lengths);
+    }
+
+    // the code lengths are themselves prefix coded
+    let mut length_lengths = [0u8; 19];
+    let count = 4 + br.read(4)? as usize;
+    for index in CODE_LENGTH_ORDER.iter().take(count) {
+        length_lengths[*index] = br.read(3)? as u8;
+    }
+    let length_code = PrefixCode::from_lengths(&length_lengths)?;
+    let mut max_symbol = if br.read_flag()? {
+        let bits = 2 + 2 * br.read(3)?;
+        2 + br.read(bits)? as usize
+    }
+    else {
+        alphabet_size
+    };
+    if max_symbol > alphabet_size {
+        return Err("Invalid WebP".to_string());
+    }
+    let mut previous = 8;
+    let mut symbol = 0;
+    while (symbol < alphabet_size) && (max_symbol > 0) {
+        max_symbol -= 1;
+        let length = length_code.read_symbol(br)?;
+        if length < 16 {
+            lengths[symbol] = length as u8;
+            symbol += 1;
+            if length != 0 {
+                previous = length as u8;
+            }
+        }
+        else {
+            let (bits, offset, value) = match length {
+                16 => (2, 3, previous),
+                17 => (3, 3, 0),
+                _ => (7, 11, 0),
+            };
+            let repeat = offset + br.read(bits)? as usize;
+            if symbol + repeat > alphabet_size {
+                return Err("Invalid WebP".to_string());
+            }
+            lengths[symbol..symbol + repeat].fill(value);
+            symbol += repeat;
+        }
+    }
+    PrefixCode::from_lengths(&lengths)
+}
+
+// lengths and distances are coded as a prefix symbol with extra bits
+fn read_prefix_value(br: &mut BitReader, prefix: usize) -> Result<usize, String> {
+    if prefix < 4 {
+        return Ok(prefix + 1);
+    }
+    let extra_bits = (prefix as u32 - 2) >> 1;
+    let offset = (2 + (prefix & 1)) << extra_bits;
+    Ok(offset + br.read(extra_bits)? as usize + 1)
+}
+
+fn subsample_size(size: usize, bits: u32) -> usize {
+    (size + (1 << bits) - 1) >> bits
+}
+
+enum Transform {
+    Predictor {bits: u32, data: Vec<u32>},
+    Color {bits: u32, data: Vec<u32>},
+    SubtractGreen,
+    ColorIndexing {bits: u32, palette: Vec<u32>, width: usize},
+}
+
+fn add_pixels(a: u32, b: u32) -> u32 {
+    let alpha_green = (a & 0xFF00FF00).wrapping_add(b & 0xFF00FF00) & 0xFF00FF00;
+    let red_blue = (a & 0x00FF00FF).wrapping_add(b & 0x00FF00FF) & 0x00FF00FF;
+    alpha_green | red_blue
+}
+
+fn average2(a: u32, b: u32) -> u32 {
+    (((a ^ b) & 0xFEFEFEFE) >> 1) + (a & b)
+}
+
+fn channel(pixel: u32, shift: u32) -> i32 {
+    ((pixel >> shift) & 0xFF) as i32
+}
+
+fn select(left: u32, top: u32, top_left: u32) -> u32 {
+    let mut left_distance = 0;
+    let mut top_distance = 0;
+    for shift in [0, 8, 16, 24] {
+        let estimate = channel(left, shift) + channel(top, shift) - channel(top_left, shift);
+        left_distance += (estimate - channel(left, shift)).abs();
+        top_distance += (estimate - channel(top, shift)).abs();
+    }
+    // the neighbour closest to the estimate wins
+    if left_distance < top_distance {left} else {top}
+}
+
+fn clamp_add_subtract_full(a: u32, b: u32, c: u32) -> u32 {
+    let mut result = 0;
+    for shift in [0, 8, 16, 24] {
+        let value = (channel(a, shift) + channel(b, shift) - channel(c, shift)).clamp(0, 255) as u32;
+        result |= value << shift;
+    }
+    result
+}
+
+fn clamp_add_subtract_half(a: u32, b: u32) -> u32 {
+    let mut result = 0;
+    for shift in [0, 8, 16, 24] {
+        let value = (channel(a, shift) + (channel(a, shift) - channel(b, shift)) / 2).clamp(0, 255) as u32;
+        result |= value << shift;
+    }
+    result
+}
+
+fn predict(mode: u32, left: u32, top: u32, top_left: u32, top_right: u32) -> u32 {
+    match mode {
+        1 => left,
+        2 => top,
+        3 => top_right,
+        4 => top_left,
+        5 => average2(average2(left, top_right), top),
+        6 => average2(left, top_left),
+        7 => average2(left, top),
+        8 => average2(top_left, top),
+        9 => average2(top, top_right),
+        10 => average2(average2(left, top_left), average2(top, top_right)),
+        11 => select(left, top, top_left),
+        12 => clamp_add_subtract_full(left, top, top_left),
+        13 => clamp_add_subtract_half(average2(left, top), top_left),
+        _ => 0xFF000000,
+    }
+}
+
+fn color_transform_delta(transform: u32, color: u32) -> u32 {
+    (((transform as u8 as i8 as i32) * (color as u8 as i8 as i32)) >> 5) as u32
+}
+
+impl Transform {
+    fn apply(&self, data: Vec<u32>, width: usize, height: usize) -> Vec<u32> {
+        let mut data = data;
+        match self {
+            Transform::Predictor {bits, data: modes} => {
+                let blocks_width = subsample_size(width, *bits);
+                for y in 0..height {
+                    for x in 0..width {
+                        let i = y * width + x;
+                        let prediction = if y == 0 {
+                            if x == 0 {0xFF000000} else {data[i - 1]}
+                        }
+                        else if x == 0 {
+                            data[i - width]
+                        }
+                        else {
+                            let mode = (modes[(y >> bits) * blocks_width + (x >> bits)] >> 8) & 0xF;
+                            // the pixel above and to the right of the last column is the first
+                            // pixel of the current row
+                            predict(mode, data[i - 1], data[i - width], data[i - width - 1], data[i - width + 1])
+                        };
+                        data[i] = add_pixels(data[i], prediction);
+                    }
+                }
+            },
+            Transform::Color {bits, data: elements} => {
+                let blocks_width = subsample_size(width, *bits);
+                for y in 0..height {
+                    for x in 0..width {
+                        let element = elements[(y >> bits) * blocks_width + (x >> bits)];
+                        let pixel = data[y * width + x];
+                        let green = (pixel >> 8) & 0xFF;
+                        let red = ((pixel >> 16).wrapping_add(color_transform_delta(element, green))) & 0xFF;
+                        let mut blue = pixel.wrapping_add(color_transform_delta(element >> 8, green));
+                        blue = blue.wrapping_add(color_transform_delta(element >> 16, red)) & 0xFF;
+                        data[y * width + x] = (pixel & 0xFF00FF00) | (red << 16) | blue;
+                    }
+                }
+            },
+            Transform::SubtractGreen => {
+                for pixel in data.iter_mut() {
+                    let green = (*pixel >> 8) & 0xFF;
+                    *pixel = add_pixels(*pixel, (green << 16) | green);
+                }
+            },
+            Transform::ColorIndexing {bits, palette, width: full_width} => {
+                // several indices can be packed into the green channel of one pixel
+                let packed_width = width;
+                let index_bits = 8 >> bits;
+                let mask = (1 << index_bits) - 1;
+                let mut unpacked = vec![0u32; full_width * height];
+                for y in 0..height {
+                    for x in 0..*full_width {
+                        let packed = data[y * packed_width + (x >> bits)];
+                        let shift = index_bits * (x & ((1 << bits) - 1)) as u32;
+                        let index = ((packed >> 8) >> shift) & mask;
+                        unpacked[y * full_width + x] = palette.get(index as usize).cloned().unwrap_or(0);
+                    }
+                }
+                data = unpacked;
+            },
+        }
+        data
+    }
+}
+
+// decodes the pixels of an image without transforms, which is also how the transform data
+// and the meta prefix codes are stored
+fn decode_entropy_image(br: &mut BitReader, width: usize, height: usize, allow_meta: bool) -> Result<Vec<u32>, String> {
+    let cache_bits = if br.read_flag()? {
+        let bits = br.read(4)?;
+        if !(1..=11).contains(&bits) {
+            return Err("Invalid WebP".to_string());
+        }
+        bits
+    }
+    else {
+        0
+    };
+    let cache_size = if cache_bits > 0 {1 << cache_bits} else {0};
+
+    let mut meta_bits = 0;
+    let mut meta_width = 0;
+    let mut meta_codes: Vec<u32> = Vec::new();
+    let mut group_count = 1;
+    if allow_meta && br.read_flag()? {
+        meta_bits = br.read(3)? + 2;
+        meta_width = subsample_size(width, meta_bits);
+        meta_codes = decode_entropy_image(br, meta_width, subsample_size(height, meta_bits), false)?;
+        for code in meta_codes.iter_mut() {
+            *code = (*code >> 8) & 0xFFFF;
+            group_count = group_count.max(*code as usize + 1);
+        }
+    }
+
+    let alphabet_sizes = [256 + 24 + cache_size, 256, 256, 256, 40];
+    let mut groups: Vec<Vec<PrefixCode>> = Vec::with_capacity(group_count);
+    for _ in 0..group_count {
+        let mut group = Vec::with_capacity(5);
+        for size in alphabet_sizes {
+            group.push(read_prefix_code(br, size)?);
+        }
+        groups.push(group);
+    }
+
+    let total = width * height;
+    let mut data = vec![0u32; total];
+    let mut cache = vec![0u32; cache_size];
+    let mut cached = 0;
+    let mut pos = 0;
+    while pos < total {
+        let group = if meta_bits > 0 {
+            let (x, y) = (pos % width, pos / width);
+            &groups[meta_codes[(y >> meta_bits) * meta_width + (x >> meta_bits)] as usize]
+        }
+        else {
+            &groups[0]
+        };
+        let symbol = group[GREEN].read_symbol(br)?;
+        if symbol < 256 {
+            let red = group[RED].read_symbol(br)? as u32;
+            let blue = group[BLUE].read_symbol(br)? as u32;
+            let alpha = group[ALPHA].read_symbol(br)? as u32;
+            data[pos] = (alpha << 24) | (red << 16) | ((symbol as u32) << 8) | blue;
+            pos += 1;
+        }
+        else if symbol < 256 + 24 {
+            let length = read_prefix_value(br, symbol - 256)?;
+            let distance_symbol = group[DISTANCE].read_symbol(br)?;
+            let code = read_prefix_value(br, distance_symbol)?;
+            let distance = if code > 120 {
+                code - 120
+            }
+            else {
+                let (dx, dy) = DISTANCE_MAP[code - 1];
+                (dx as isize + dy as isize * width as isize).max(1) as usize
+            };
+            if (distance > pos) || (pos + length > total) {
+                return Err("Invalid WebP".to_string());
+            }
+            for _ in 0..length {
+                data[pos] = data[pos - distance];
+                pos += 1;
+            }
+        }
+        else {
+            let index = symbol - 256 - 24;
+            // the cache only has the colors up to the previous pixel
+            while cached < pos {
+                let color = data[cached];
+                cache[(0x1E35A7BD_u32.wrapping_mul(color) >> (32 - cache_bits)) as usize] = color;
+                cached += 1;
+            }
+            data[pos] = cache[index];
+            pos += 1;
+        }
+    }
+    Ok(data)
+}
+
+/// Decodes a VP8L bitstream without its header, as it appears in an ALPH chunk.
+pub fn decode_image_stream(src: &[u8], width: usize, height: usize) -> Result<Vec<u32>, String> {
+    if (width == 0) || (height == 0) || (width * height > MAX_PIXELS) {
+        return Err("Invalid WebP".to_string());
+    }
+    let mut br = BitReader::new(src);
+    let mut transforms: Vec<(Transform, usize)> = Vec::new();
+    let mut seen = [false; 4];
+    let mut coded_width = width;
+    while br.read_flag()? {
+        let kind = br.read(2)? as usize;
+        if seen[kind] {
+            return Err("Invalid WebP".to_string());
+        }
+        seen[kind] = true;
+        let transform = match kind {
+            0 | 1 => {
+                let bits = br.read(3)? + 2;
+                let data = decode_entropy_image(&mut br, subsample_size(coded_width, bits), subsample_size(height, bits), false)?;
+                if kind == 0 {Transform::Predictor {bits, data}} else {Transform::Color {bits, data}}
+            },
+            2 => Transform::SubtractGreen,
+            _ => {
+                let size = br.read(8)? as usize + 1;
+                let mut palette = decode_entropy_image(&mut br, size, 1, false)?;
+                for i in 1..size {
+                    palette[i] = add_pixels(palette[i], palette[i - 1]);
+                }
+                let bits = match size {
+                    0..=2 => 3,
+                    3..=4 => 2,
+                    5..=16 => 1,
+                    _ => 0,
+                };
+                let transform = Transform::ColorIndexing {bits, palette, width: coded_width};
+                coded_width = subsample_size(coded_width, bits);
+                transform
+            },
+        };
+        // each transform is undone at the width the image had when it was read
+        transforms.push((transform, coded_width));
+    }
+    let mut data = decode_entropy_image(&mut br, coded_width, height, true)?;
+    for (transform, width) in transforms.iter().rev() {
+        data = transform.apply(data, *width, height);
+    }
+    Ok(data)
+}
+
+pub fn test(src: &[u8]) -> Option<(usize, usize)> {
+    if (src.len() >= 5) && (src[0] == 0x2F) {
+        let bits = (src[1] as usize) | ((src[2] as usize) << 8) | ((src[3] as usize) << 16) | ((src[4] as usize) << 24);
+        return Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1));
+    }
+    None
+}
+
+pub fn decode(src: &[u8]) -> Result<ImageBuffer, String> {
+    let (width, height) = match test(src) {
+        Some(size) => size,
+        None => return Err("Invalid WebP".to_string()),
+    };
+    // the version has to be 0
+    if (src[4] >> 5) != 0 {
+        return Err("Invalid WebP".to_string());
+    }
+    Ok(ImageBuffer {
+        width,
+        height,
+        data: decode_image_stream(&src[5..], width, height)?,
+    })
+}
@@ -0,0 +1,1432 @@
+// image_formats::webp::lossy
+// VP8 key frames, as described in RFC 6386
+
+use crate::ImageBuffer;
+
+// intra prediction modes of a macroblock
+const DC_PRED: i8 = 0;
+const V_PRED: i8 = 1;
+const H_PRED: i8 = 2;
+const TM_PRED: i8 = 3;
+const B_PRED: i8 = 4;
+
+// intra prediction modes of a 4x4 subblock
+const B_DC_PRED: i8 = 0;
+const B_TM_PRED: i8 = 1;
+const B_VE_PRED: i8 = 2;
+const B_HE_PRED: i8 = 3;
+const B_LD_PRED: i8 = 4;
+const B_RD_PRED: i8 = 5;
+const B_VR_PRED: i8 = 6;
+const B_VL_PRED: i8 = 7;
+const B_HD_PRED: i8 = 8;
+const B_HU_PRED: i8 = 9;
+
+// DCT tokens
+const DCT_0: i8 = 0;
+const DCT_1: i8 = 1;
+const DCT_2: i8 = 2;
+const DCT_3: i8 = 3;
+const DCT_4: i8 = 4;
+const DCT_CAT1: i8 = 5;
+const DCT_CAT2: i8 = 6;
+const DCT_CAT3: i8 = 7;
+const DCT_CAT4: i8 = 8;
+const DCT_CAT5: i8 = 9;
+const DCT_CAT6: i8 = 10;
+const DCT_EOB: i8 = 11;
+
+// trees: positive entries index the next pair, others are negated leaves
+const SEGMENT_TREE: [i8; 6] = [2, 4, -0, -1, -2, -3];
+const YMODE_TREE: [i8; 8] = [-B_PRED, 2, 4, 6, -DC_PRED, -V_PRED, -H_PRED, -TM_PRED];
+const UV_MODE_TREE: [i8; 6] = [-DC_PRED, 2, -V_PRED, 4, -H_PRED, -TM_PRED];
+const BMODE_TREE: [i8; 18] = [
+    -B_DC_PRED, 2, -B_TM_PRED, 4, -B_VE_PRED, 6, 8, 12, -B_HE_PRED, 10, -B_RD_PRED, -B_VR_PRED,
+    -B_LD_PRED, 14, -B_VL_PRED, 16, -B_HD_PRED, -B_HU_PRED,
+];
+const DCT_TOKEN_TREE: [i8; 22] = [
+    -DCT_EOB, 2, -DCT_0, 4, -DCT_1, 6, 8, 12, -DCT_2, 10, -DCT_3, -DCT_4, 14, 16, -DCT_CAT1,
+    -DCT_CAT2, 18, 20, -DCT_CAT3, -DCT_CAT4, -DCT_CAT5, -DCT_CAT6,
+];
+
+const YMODE_PROBS: [u8; 4] = [145, 156, 163, 128];
+const UV_MODE_PROBS: [u8; 3] = [142, 114, 183];
+
+const DCT_CAT_PROBS: [&[u8]; 6] = [
+    &[159],
+    &[165, 145],
+    &[173, 148, 140],
+    &[176, 155, 140, 135],
+    &[180, 157, 141, 134, 130],
+    &[254, 254, 243, 230, 196, 177, 153, 140, 133, 130, 129],
+];
+const DCT_CAT_BASE: [i32; 6] = [5, 7, 11, 19, 35, 67];
+const COEFF_BANDS: [usize; 16] = [0, 1, 2, 3, 6, 4, 5, 6, 6, 6, 6, 6, 6, 6, 6, 7];
+const ZIGZAG: [usize; 16] = [0, 1, 4, 8, 5, 2, 3, 6, 9, 12, 13, 10, 7, 11, 14, 15];
+
+const DC_QUANT: [i32; 128] = [
+    4, 5, 6, 7, 8, 9, 10, 10, 11, 12, 13, 14, 15, 16, 17, 17,
+    18, 19, 20, 20, 21, 21, 22, 22, 23, 23, 24, 25, 25, 26, 27, 28,
+    29, 30, 31, 32, 33, 34, 35, 36, 37, 37, 38, 39, 40, 41, 42, 43,
+    44, 45, 46, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58,
+    59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74,
+    75, 76, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89,
+    91, 93, 95, 96, 98, 100, 101, 102, 104, 106, 108, 110, 112, 114, 116, 118,
+    122, 124, 126, 128, 130, 132, 134, 136, 138, 140, 143, 145, 148, 151, 154, 157,
+];
+
+const AC_QUANT: [i32; 128] = [
+    4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+    20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
+    36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+    52, 53, 54, 55, 56, 57, 58, 60, 62, 64, 66, 68, 70, 72, 74, 76,
+    78, 80, 82, 84, 86, 88, 90, 92, 94, 96, 98, 100, 102, 104, 106, 108,
+    110, 112, 114, 116, 119, 122, 125, 128, 131, 134, 137, 140, 143, 146, 149, 152,
+    155, 158, 161, 164, 167, 170, 173, 177, 181, 185, 189, 193, 197, 201, 205, 209,
+    213, 217, 221, 225, 229, 234, 239, 245, 249, 254, 259, 264, 269, 274, 279, 284,
+];
+
+const BMODE_PROBS: [[[u8; 9]; 10]; 10] = [
+    [
+        [231, 120, 48, 89, 115, 113, 120, 152, 112],
+        [152, 179, 64, 126, 170, 118, 46, 70, 95],
+        [175, 69, 143, 80, 85, 82, 72, 155, 103],
+        [56, 58, 10, 171, 218, 189, 17, 13, 152],
+        [144, 71, 10, 38, 171, 213, 144, 34, 26],
+        [114, 26, 17, 163, 44, 195, 21, 10, 173],
+        [121, 24, 80, 195, 26, 62, 44, 64, 85],
+        [170, 46, 55, 19, 136, 160, 33, 206, 71],
+        [63, 20, 8, 114, 114, 208, 12, 9, 226],
+        [81, 40, 11, 96, 182, 84, 29, 16, 36],
+    ],
+    [
+        [134, 183, 89, 137, 98, 101, 106, 165, 148],
+        [72, 187, 100, 130, 157, 111, 32, 75, 80],
+        [66, 102, 167, 99, 74, 62, 40, 234, 128],
+        [41, 53, 9, 178, 241, 141, 26, 8, 107],
+        [104, 79, 12, 27, 217, 255, 87, 17, 7],
+        [74, 43, 26, 146, 73, 166, 49, 23, 157],
+        [65, 38, 105, 160, 51, 52, 31, 115, 128],
+        [87, 68, 71, 44, 114, 51, 15, 186, 23],
+        [47, 41, 14, 110, 182, 183, 21, 17, 194],
+        [66, 45, 25, 102, 197, 189, 23, 18, 22],
+    ],
+    [
+        [88, 88, 147, 150, 42, 46, 45, 196, 205],
+        [43, 97, 183, 117, 85, 38, 35, 179, 61],
+        [39, 53, 200, 87, 26, 21, 43, 232, 171],
+        [56, 34, 51, 104, 114, 102, 29, 93, 77],
+        [107, 54, 32, 26, 51, 1, 81, 43, 31],
+        [39, 28, 85, 171, 58, 165, 90, 98, 64],
+        [34, 22, 116, 206, 23, 34, 43, 166, 73],
+        [68, 25, 106, 22, 64, 171, 36, 225, 114],
+        [34, 19, 21, 102, 132, 188, 16, 76, 124],
+        [62, 18, 78, 95, 85, 57, 50, 48, 51],
+    ],
+    [
+        [193, 101, 35, 159, 215, 111, 89, 46, 111],
+        [60, 148, 31, 172, 219, 228, 21, 18, 111],
+        [112, 113, 77, 85, 179, 255, 38, 120, 114],
+        [40, 42, 1, 196, 245, 209, 10, 25, 109],
+        [100, 80, 8, 43, 154, 1, 51, 26, 71],
+        [88, 43, 29, 140, 166, 213, 37, 43, 154],
+        [61, 63, 30, 155, 67, 45, 68, 1, 209],
+        [142, 78, 78, 16, 255, 128, 34, 197, 171],
+        [41, 40, 5, 102, 211, 183, 4, 1, 221],
+        [51, 50, 17, 168, 209, 192, 23, 25, 82],
+    ],
+    [
+        [125, 98, 42, 88, 104, 85, 117, 175, 82],
+        [95, 84, 53, 89, 128, 100, 113, 101, 45],
+        [75, 79, 123, 47, 51, 128, 81, 171, 1],
+        [57, 17, 5, 71, 102, 57, 53, 41, 49],
+        [115, 21, 2, 10, 102, 255, 166, 23, 6],
+        [38, 33, 13, 121, 57, 73, 26, 1, 85],
+        [41, 10, 67, 138, 77, 110, 90, 47, 114],
+        [101, 29, 16, 10, 85, 128, 101, 196, 26],
+        [57, 18, 10, 102, 102, 213, 34, 20, 43],
+        [117, 20, 15, 36, 163, 128, 68, 1, 26],
+    ],
+    [
+        [138, 31, 36, 171, 27, 166, 38, 44, 229],
+        [67, 87, 58, 169, 82, 115, 26, 59, 179],
+        [63, 59, 90, 180, 59, 166, 93, 73, 154],
+        [40, 40, 21, 116, 143, 209, 34, 39, 175],
+        [57, 46, 22, 24, 128, 1, 54, 17, 37],
+        [47, 15, 16, 183, 34, 223, 49, 45, 183],
+        [46, 17, 33, 183, 6, 98, 15, 32, 183],
+        [65, 32, 73, 115, 28, 128, 23, 128, 205],
+        [40, 3, 9, 115, 51, 192, 18, 6, 223],
+        [87, 37, 9, 115, 59, 77, 64, 21, 47],
+    ],
+    [
+        [104, 55, 44, 218, 9, 54, 53, 130, 226],
+        [64, 90, 70, 205, 40, 41, 23, 26, 57],
+        [54, 57, 112, 184, 5, 41, 38, 166, 213],
+        [30, 34, 26, 133, 152, 116, 10, 32, 134],
+        [75, 32, 12, 51, 192, 255, 160, 43, 51],
+        [39, 19, 53, 221, 26, 114, 32, 73, 255],
+        [31, 9, 65, 234, 2, 15, 1, 118, 73],
+        [88, 31, 35, 67, 102, 85, 55, 186, 85],
+        [56, 21, 23, 111, 59, 205, 45, 37, 192],
+        [55, 38, 70, 124, 73, 102, 1, 34, 98],
+    ],
+    [
+        [102, 61, 71, 37, 34, 53, 31, 243, 192],
+        [69, 60, 71, 38, 73, 119, 28, 222, 37],
+        [68, 45, 128, 34, 1, 47, 11, 245, 171],
+        [62, 17, 19, 70, 146, 85, 55, 62, 70],
+        [75, 15, 9, 9, 64, 255, 184, 119, 16],
+        [37, 43, 37, 154, 100, 163, 85, 160, 1],
+        [63, 9, 92, 136, 28, 64, 32, 201, 85],
+        [86, 6, 28, 5, 64, 255, 25, 248, 1],
+        [56, 8, 17, 132, 137, 255, 55, 116, 128],
+        [58, 15, 20, 82, 135, 57, 26, 121, 40],
+    ],
+    [
+        [164, 50, 31, 137, 154, 133, 25, 35, 218],
+        [51, 103, 44, 131, 131, 123, 31, 6, 158],
+        [86, 40, 64, 135, 148, 224, 45, 183, 128],
+        [22, 26, 17, 131, 240, 154, 14, 1, 209],
+        [83, 12, 13, 54, 192, 255, 68, 47, 28],
+        [45, 16, 21, 91, 64, 222, 7, 1, 197],
+        [56, 21, 39, 155, 60, 138, 23, 102, 213],
+        [85, 26, 85, 85, 128, 128, 32, 146, 171],
+        [18, 11, 7, 63, 144, 171, 4, 4, 246],
+        [35, 27, 10, 146, 174, 171, 12, 26, 128],
+    ],
+    [
+        [190, 80, 35, 99, 180, 80, 126, 54, 45],
+        [85, 126, 47, 87, 176, 51, 41, 20, 32],
+        [101, 75, 128, 139, 118, 146, 116, 128, 85],
+        [56, 41, 15, 176, 236, 85, 37, 9, 62],
+        [146, 36, 19, 30, 171, 255, 97, 27, 20],
+        [71, 30, 17, 119, 118, 255, 17, 18, 138],
+        [101, 38, 60, 138, 55, 70, 43, 26, 142],
+        [138, 45, 61, 62, 219, 1, 81, 188, 64],
+        [32, 41, 20, 117, 151, 142, 20, 21, 163],
+        [112, 19, 12, 61, 195, 128, 48, 4, 24],
+    ],
+];
+
+
+const COEFF_UPDATE_PROBS: [[[[u8; 11]; 3]; 8]; 4] = [
+    [
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [176, 246, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [223, 241, 252, 255, 255, 255, 255, 255, 255, 255, 255],
+            [249, 253, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 244, 252, 255, 255, 255, 255, 255, 255, 255, 255],
+            [234, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 246, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [239, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 248, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [251, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [251, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 254, 253, 255, 254, 255, 255, 255, 255, 255, 255],
+            [250, 255, 254, 255, 254, 255, 255, 255, 255, 255, 255],
+            [254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+    ],
+    [
+        [
+            [217, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [225, 252, 241, 253, 255, 255, 254, 255, 255, 255, 255],
+            [234, 250, 241, 250, 253, 255, 253, 254, 255, 255, 255],
+        ],
+        [
+            [255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [223, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [238, 253, 254, 254, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 248, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [249, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 253, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [247, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 254, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+            [250, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+    ],
+    [
+        [
+            [186, 251, 250, 255, 255, 255, 255, 255, 255, 255, 255],
+            [234, 251, 244, 254, 255, 255, 255, 255, 255, 255, 255],
+            [251, 251, 243, 253, 254, 255, 254, 255, 255, 255, 255],
+        ],
+        [
+            [255, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [236, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [251, 253, 253, 254, 254, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+    ],
+    [
+        [
+            [248, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [250, 254, 252, 254, 255, 255, 255, 255, 255, 255, 255],
+            [248, 254, 249, 253, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 253, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+            [246, 253, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+            [252, 254, 251, 254, 254, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 254, 252, 255, 255, 255, 255, 255, 255, 255, 255],
+            [248, 254, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+            [253, 255, 254, 254, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 251, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [245, 251, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [253, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 251, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+            [252, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [249, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 255],
+            [250, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+        [
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+            [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+        ],
+    ],
+];
+
+
+const COEFF_PROBS: [[[[u8; 11]; 3]; 8]; 4] = [
+    [
+        [
+            [128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+            [128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+            [128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+        [
+            [253, 136, 254, 255, 228, 219, 128, 128, 128, 128, 128],
+            [189, 129, 242, 255, 227, 213, 255, 219, 128, 128, 128],
+            [106, 126, 227, 252, 214, 209, 255, 255, 128, 128, 128],
+        ],
+        [
+            [1, 98, 248, 255, 236, 226, 255, 255, 128, 128, 128],
+            [181, 133, 238, 254, 221, 234, 255, 154, 128, 128, 128],
+            [78, 134, 202, 247, 198, 180, 255, 219, 128, 128, 128],
+        ],
+        [
+            [1, 185, 249, 255, 243, 255, 128, 128, 128, 128, 128],
+            [184, 150, 247, 255, 236, 224, 128, 128, 128, 128, 128],
+            [77, 110, 216, 255, 236, 230, 128, 128, 128, 128, 128],
+        ],
+        [
+            [1, 101, 251, 255, 241, 255, 128, 128, 128, 128, 128],
+            [170, 139, 241, 252, 236, 209, 255, 255, 128, 128, 128],
+            [37, 116, 196, 243, 228, 255, 255, 255, 128, 128, 128],
+        ],
+        [
+            [1, 204, 254, 255, 245, 255, 128, 128, 128, 128, 128],
+            [207, 160, 250, 255, 238, 128, 128, 128, 128, 128, 128],
+            [102, 103, 231, 255, 211, 171, 128, 128, 128, 128, 128],
+        ],
+        [
+            [1, 152, 252, 255, 240, 255, 128, 128, 128, 128, 128],
+            [177, 135, 243, 255, 234, 225, 128, 128, 128, 128, 128],
+            [80, 129, 211, 255, 194, 224, 128, 128, 128, 128, 128],
+        ],
+        [
+            [1, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+            [246, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+            [255, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+    ],
+    [
+        [
+            [198, 35, 237, 223, 193, 187, 162, 160, 145, 155, 62],
+            [131, 45, 198, 221, 172, 176, 220, 157, 252, 221, 1],
+            [68, 47, 146, 208, 149, 167, 221, 162, 255, 223, 128],
+        ],
+        [
+            [1, 149, 241, 255, 221, 224, 255, 255, 128, 128, 128],
+            [184, 141, 234, 253, 222, 220, 255, 199, 128, 128, 128],
+            [81, 99, 181, 242, 176, 190, 249, 202, 255, 255, 128],
+        ],
+        [
+            [1, 129, 232, 253, 214, 197, 242, 196, 255, 255, 128],
+            [99, 121, 210, 250, 201, 198, 255, 202, 128, 128, 128],
+            [23, 91, 163, 242, 170, 187, 247, 210, 255, 255, 128],
+        ],
+        [
+            [1, 200, 246, 255, 234, 255, 128, 128, 128, 128, 128],
+            [109, 178, 241, 255, 231, 245, 255, 255, 128, 128, 128],
+            [44, 130, 201, 253, 205, 192, 255, 255, 128, 128, 128],
+        ],
+        [
+            [1, 132, 239, 251, 219, 209, 255, 165, 128, 128, 128],
+            [94, 136, 225, 251, 218, 190, 255, 255, 128, 128, 128],
+            [22, 100, 174, 245, 186, 161, 255, 199, 128, 128, 128],
+        ],
+        [
+            [1, 182, 249, 255, 232, 235, 128, 128, 128, 128, 128],
+            [124, 143, 241, 255, 227, 234, 128, 128, 128, 128, 128],
+            [35, 77, 181, 251, 193, 211, 255, 205, 128, 128, 128],
+        ],
+        [
+            [1, 157, 247, 255, 236, 231, 255, 255, 128, 128, 128],
+            [121, 141, 235, 255, 225, 227, 255, 255, 128, 128, 128],
+            [45, 99, 188, 251, 195, 217, 255, 224, 128, 128, 128],
+        ],
+        [
+            [1, 1, 251, 255, 213, 255, 128, 128, 128, 128, 128],
+            [203, 1, 248, 255, 255, 128, 128, 128, 128, 128, 128],
+            [137, 1, 177, 255, 224, 255, 128, 128, 128, 128, 128],
+        ],
+    ],
+    [
+        [
+            [253, 9, 248, 251, 207, 208, 255, 192, 128, 128, 128],
+            [175, 13, 224, 243, 193, 185, 249, 198, 255, 255, 128],
+            [73, 17, 171, 221, 161, 179, 236, 167, 255, 234, 128],
+        ],
+        [
+            [1, 95, 247, 253, 212, 183, 255, 255, 128, 128, 128],
+            [239, 90, 244, 250, 211, 209, 255, 255, 128, 128, 128],
+            [155, 77, 195, 248, 188, 195, 255, 255, 128, 128, 128],
+        ],
+        [
+            [1, 24, 239, 251, 218, 219, 255, 205, 128, 128, 128],
+            [201, 51, 219, 255, 196, 186, 128, 128, 128, 128, 128],
+            [69, 46, 190, 239, 201, 218, 255, 228, 128, 128, 128],
+        ],
+        [
+            [1, 191, 251, 255, 255, 128, 128, 128, 128, 128, 128],
+            [223, 165, 249, 255, 213, 255, 128, 128, 128, 128, 128],
+            [141, 124, 248, 255, 255, 128, 128, 128, 128, 128, 128],
+        ],
+        [
+            [1, 16, 248, 255, 255, 128, 128, 128, 128, 128, 128],
+            [190, 36, 230, 255, 236, 255, 128, 128, 128, 128, 128],
+            [149, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+        [
+            [1, 226, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+            [247, 192, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+            [240, 128, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+        [
+            [1, 134, 252, 255, 255, 128, 128, 128, 128, 128, 128],
+            [213, 62, 250, 255, 255, 128, 128, 128, 128, 128, 128],
+            [55, 93, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+        [
+            [128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+            [128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+            [128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+    ],
+    [
+        [
+            [202, 24, 213, 235, 186, 191, 220, 160, 240, 175, 255],
+            [126, 38, 182, 232, 169, 184, 228, 174, 255, 187, 128],
+            [61, 46, 138, 219, 151, 178, 240, 170, 255, 216, 128],
+        ],
+        [
+            [1, 112, 230, 250, 199, 191, 247, 159, 255, 255, 128],
+            [166, 109, 228, 252, 211, 215, 255, 174, 128, 128, 128],
+            [39, 77, 162, 232, 172, 180, 245, 178, 255, 255, 128],
+        ],
+        [
+            [1, 52, 220, 246, 198, 199, 249, 220, 255, 255, 128],
+            [124, 74, 191, 243, 183, 193, 250, 221, 255, 255, 128],
+            [24, 71, 130, 219, 154, 170, 243, 182, 255, 255, 128],
+        ],
+        [
+            [1, 182, 225, 249, 219, 240, 255, 224, 128, 128, 128],
+            [149, 150, 226, 252, 216, 205, 255, 171, 128, 128, 128],
+            [28, 108, 170, 242, 183, 194, 254, 223, 255, 255, 128],
+        ],
+        [
+            [1, 81, 230, 252, 204, 203, 255, 192, 128, 128, 128],
+            [123, 102, 209, 247, 188, 196, 255, 233, 128, 128, 128],
+            [20, 95, 153, 243, 164, 173, 255, 203, 128, 128, 128],
+        ],
+        [
+            [1, 222, 248, 255, 216, 213, 128, 128, 128, 128, 128],
+            [168, 175, 246, 252, 235, 205, 255, 255, 128, 128, 128],
+            [47, 116, 215, 255, 211, 212, 255, 255, 128, 128, 128],
+        ],
+        [
+            [1, 121, 236, 253, 212, 214, 255, 255, 128, 128, 128],
+            [141, 84, 213, 252, 201, 202, 255, 219, 128, 128, 128],
+            [42, 80, 160, 240, 162, 185, 255, 205, 128, 128, 128],
+        ],
+        [
+            [1, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+            [244, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+            [238, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128],
+        ],
+    ],
+];
+
+struct BoolDecoder<'a> {
+    src: &'a [u8],
+    sp: usize,
+    value: u32,
+    range: u32,
+    bit_count: u32,
+}
+
+impl<'a> BoolDecoder<'a> {
+    fn new(src: &'a [u8]) -> BoolDecoder<'a> {
+        let mut decoder = BoolDecoder {
+            src,
+            sp: 0,
+            value: 0,
+            range: 255,
+            bit_count: 0,
+        };
+        decoder.value = (decoder.next_byte() << 8) | decoder.next_byte();
+        decoder
+    }
+
+    // a truncated partition reads as zeros, like libvpx does
+    fn next_byte(&mut self) -> u32 {
+        let byte = self.src.get(self.sp).cloned().unwrap_or(0);
+        self.sp += 1;
+        byte as u32
+    }
+
+    fn read_bool(&mut self, prob: u8) -> bool {
+        let split = 1 + (((self.range - 1) * prob as u32) >> 8);
+        let big_split = split << 8;
+        let bit = if self.value >= big_split {
+            self.range -= split;
+            self.value -= big_split;
+            true
+        }
+        else {
+            self.range = split;
+            false
+        };
+        while self.range < 128 {
+            self.value <<= 1;
+            self.range <<= 1;
+            self.bit_count += 1;
+            if self.bit_count == 8 {
+                self.bit_count = 0;
+                self.value |= self.next_byte();
+            }
+        }
+        bit
+    }
+
+    fn read_flag(&mut self) -> bool {
+        self.read_bool(128)
+    }
+
+    fn read_literal(&mut self, bits: u32) -> i32 {
+        let mut value = 0;
+        for _ in 0..bits {
+            value = (value << 1) | (self.read_flag() as i32);
+        }
+        value
+    }
+
+    // a flag, followed by the magnitude and the sign if it is set
+    fn read_optional_signed(&mut self, bits: u32) -> i32 {
+        if !self.read_flag() {
+            return 0;
+        }
+        let value = self.read_literal(bits);
+        if self.read_flag() {-value} else {value}
+    }
+
+    fn read_tree(&mut self, tree: &[i8], probs: &[u8], start: usize) -> i8 {
+        let mut i = start;
+        loop {
+            let node = tree[i + self.read_bool(probs[i >> 1]) as usize];
+            if node <= 0 {
+                return -node;
+            }
+            i = node as usize;
+        }
+    }
+}
+
+type CoeffProbs = [[[[u8; 11]; 3]; 8]; 4];
+
+#[derive(Clone, Copy, Default)]
+struct Segment {
+    quantizer: i32,
+    filter_level: i32,
+}
+
+// dequantization factors for the DC and AC coefficients
+#[derive(Clone, Copy, Default)]
+struct Quantizer {
+    y: [i32; 2],
+    y2: [i32; 2],
+    uv: [i32; 2],
+}
+
+#[derive(Clone, Copy, Default)]
+struct MacroBlockInfo {
+    segment: usize,
+    y_mode: i8,
+    non_zero: bool,
+}
+
+/// A decoded key frame, in YUV 4:2:0 planes that cover whole macroblocks.
+pub struct Vp8Frame {
+    pub width: usize,
+    pub height: usize,
+    y_stride: usize,
+    uv_stride: usize,
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+fn from_le16(src: &[u8]) -> usize {
+    (src[0] as usize) | ((src[1] as usize) << 8)
+}
+
+fn from_le24(src: &[u8]) -> usize {
+    (src[0] as usize) | ((src[1] as usize) << 8) | ((src[2] as usize) << 16)
+}
+
+pub fn test(src: &[u8]) -> Option<(usize, usize)> {
+    if (src.len() >= 10) && ((src[0] & 1) == 0) && (src[3..6] == [0x9D, 0x01, 0x2A]) {
+        return Some((from_le16(&src[6..8]) & 0x3FFF, from_le16(&src[8..10]) & 0x3FFF));
+    }
+    None
+}
+
+pub fn decode_frame(src: &[u8]) -> Result<Vp8Frame, String> {
+    let (width, height) = match test(src) {
+        Some(size) => size,
+        None => return Err("Invalid WebP".to_string()),
+    };
+    let first_size = from_le24(src) >> 5;
+    if (width == 0) || (height == 0) || (10 + first_size > src.len()) {
+        return Err("Invalid WebP".to_string());
+    }
+    let mut bd = BoolDecoder::new(&src[10..10 + first_size]);
+    bd.read_literal(2); // color space and clamping type
+
+    let mut segments = [Segment::default(); 4];
+    // like libwebp, segments without feature data have absolute values of 0
+    let mut segments_absolute = true;
+    let mut update_segment_map = false;
+    let mut segment_probs = [255u8; 3];
+    let segmentation = bd.read_flag();
+    if segmentation {
+        update_segment_map = bd.read_flag();
+        if bd.read_flag() {
+            segments_absolute = bd.read_flag();
+            for segment in segments.iter_mut() {
+                segment.quantizer = bd.read_optional_signed(7);
+            }
+            for segment in segments.iter_mut() {
+                segment.filter_level = bd.read_optional_signed(6);
+            }
+        }
+        if update_segment_map {
+            for prob in segment_probs.iter_mut() {
+                *prob = if bd.read_flag() {bd.read_literal(8) as u8} else {255};
+            }
+        }
+    }
+
+    let simple_filter = bd.read_flag();
+    let filter_level = bd.read_literal(6);
+    let sharpness = bd.read_literal(3);
+    let mut ref_delta = 0;
+    let mut mode_delta = 0;
+    let filter_adjustments = bd.read_flag();
+    if filter_adjustments && bd.read_flag() {
+        // a key frame only uses the intra frame delta and the B_PRED mode delta
+        let ref_deltas = [0; 4].map( | _: i32 | bd.read_optional_signed(6));
+        let mode_deltas = [0; 4].map( | _: i32 | bd.read_optional_signed(6));
+        ref_delta = ref_deltas[0];
+        mode_delta = mode_deltas[0];
+    }
+
+    let partition_count = 1 << bd.read_literal(2);
+    let sizes = 10 + first_size;
+    let mut sp = sizes + 3 * (partition_count - 1);
+    if sp > src.len() {
+        return Err("Invalid WebP".to_string());
+    }
+    let mut partitions = Vec::with_capacity(partition_count);
+    for i in 0..partition_count {
+        let size = if i + 1 < partition_count {from_le24(&src[sizes + 3 * i..])} else {src.len() - sp};
+        let end = (sp + size).min(src.len());
+        partitions.push(BoolDecoder::new(&src[sp..end]));
+        sp = end;
+    }
+
+    let base_quantizer = bd.read_literal(7);
+    let y_dc_delta = bd.read_optional_signed(4);
+    let y2_dc_delta = bd.read_optional_signed(4);
+    let y2_ac_delta = bd.read_optional_signed(4);
+    let uv_dc_delta = bd.read_optional_signed(4);
+    let uv_ac_delta = bd.read_optional_signed(4);
+    let mut quantizers = [Quantizer::default(); 4];
+    for (quantizer, segment) in quantizers.iter_mut().zip(segments.iter()) {
+        let q = if !segmentation {
+            base_quantizer
+        }
+        else if segments_absolute {
+            segment.quantizer
+        }
+        else {
+            base_quantizer + segment.quantizer
+        };
+        let index = | delta: i32 | (q + delta).clamp(0, 127) as usize;
+        quantizer.y = [DC_QUANT[index(y_dc_delta)], AC_QUANT[index(0)]];
+        quantizer.y2 = [DC_QUANT[index(y2_dc_delta)] * 2, (AC_QUANT[index(y2_ac_delta)] * 155 / 100).max(8)];
+        quantizer.uv = [DC_QUANT[index(uv_dc_delta)].min(132), AC_QUANT[index(uv_ac_delta)]];
+    }
+
+    bd.read_literal(1); // refresh entropy probabilities
+    let mut coeff_probs: CoeffProbs = COEFF_PROBS;
+    for i in 0..4 {
+        for j in 0..8 {
+            for k in 0..3 {
+                for l in 0..11 {
+                    if bd.read_bool(COEFF_UPDATE_PROBS[i][j][k][l]) {
+                        coeff_probs[i][j][k][l] = bd.read_literal(8) as u8;
+                    }
+                }
+            }
+        }
+    }
+    let skip_enabled = bd.read_flag();
+    let skip_prob = if skip_enabled {bd.read_literal(8) as u8} else {0};
+
+    let mbw = width.div_ceil(16);
+    let mbh = height.div_ceil(16);
+    let mut frame = Vp8Frame {
+        width,
+        height,
+        y_stride: mbw * 16,
+        uv_stride: mbw * 8,
+        y: vec![0; mbw * mbh * 256],
+        u: vec![0; mbw * mbh * 64],
+        v: vec![0; mbw * mbh * 64],
+    };
+    let mut infos = vec![MacroBlockInfo::default(); mbw * mbh];
+    // prediction contexts: subblock modes along the edges, and whether the neighbouring blocks
+    // had coefficients, as 4 Y, 2 U, 2 V and 1 Y2 flags
+    let mut above_modes = vec![B_DC_PRED; mbw * 4];
+    let mut above_non_zero = vec![[false; 9]; mbw];
+    for mby in 0..mbh {
+        let mut left_modes = [B_DC_PRED; 4];
+        let mut left_non_zero = [false; 9];
+        let partition = &mut partitions[mby % partition_count];
+        for mbx in 0..mbw {
+            let segment = if update_segment_map {bd.read_tree(&SEGMENT_TREE, &segment_probs, 0) as usize} else {0};
+            let skip = skip_enabled && bd.read_bool(skip_prob);
+            let y_mode = bd.read_tree(&YMODE_TREE, &YMODE_PROBS, 0);
+            let mut b_modes = [B_DC_PRED; 16];
+            if y_mode == B_PRED {
+                for sy in 0..4 {
+                    for sx in 0..4 {
+                        let above = above_modes[mbx * 4 + sx] as usize;
+                        let left = left_modes[sy] as usize;
+                        let mode = bd.read_tree(&BMODE_TREE, &BMODE_PROBS[above][left], 0);
+                        b_modes[sy * 4 + sx] = mode;
+                        above_modes[mbx * 4 + sx] = mode;
+                        left_modes[sy] = mode;
+                    }
+                }
+            }
+            else {
+                // the neighbours of a whole macroblock see the subblock mode that matches its mode
+                let mode = match y_mode {
+                    V_PRED => B_VE_PRED,
+                    H_PRED => B_HE_PRED,
+                    TM_PRED => B_TM_PRED,
+                    _ => B_DC_PRED,
+                };
+                above_modes[mbx * 4..mbx * 4 + 4].fill(mode);
+                left_modes.fill(mode);
+            }
+            let uv_mode = bd.read_tree(&UV_MODE_TREE, &UV_MODE_PROBS, 0);
+
+            let mut coeffs = [0i32; 384];
+            let mut non_zero = false;
+            if skip {
+                above_non_zero[mbx][0..8].fill(false);
+                left_non_zero[0..8].fill(false);
+                if y_mode != B_PRED {
+                    above_non_zero[mbx][8] = false;
+                    left_non_zero[8] = false;
+                }
+            }
+            else {
+                non_zero = read_residuals(partition, &coeff_probs, &quantizers[segment], y_mode != B_PRED, &mut above_non_zero[mbx], &mut left_non_zero, &mut coeffs);
+            }
+            infos[mby * mbw + mbx] = MacroBlockInfo {
+                segment,
+                y_mode,
+                non_zero,
+            };
+            frame.reconstruct(mbx, mby, mbw, y_mode, &b_modes, uv_mode, &coeffs);
+        }
+    }
+
+    if filter_level != 0 {
+        for mby in 0..mbh {
+            for mbx in 0..mbw {
+                let info = infos[mby * mbw + mbx];
+                let mut level = filter_level;
+                if segmentation {
+                    level = if segments_absolute {0} else {level} + segments[info.segment].filter_level;
+                    level = level.clamp(0, 63);
+                }
+                if filter_adjustments {
+                    level += ref_delta;
+                    if info.y_mode == B_PRED {
+                        level += mode_delta;
+                    }
+                    level = level.clamp(0, 63);
+                }
+                if level == 0 {
+                    continue;
+                }
+                let mut interior = level;
+                if sharpness > 0 {
+                    interior >>= if sharpness > 4 {2} else {1};
+                    interior = interior.min(9 - sharpness);
+                }
+                let filter = LoopFilter {
+                    interior: interior.max(1),
+                    hev_threshold: if level >= 40 {2} else if level >= 15 {1} else {0},
+                    mb_edge: (level + 2) * 2 + interior.max(1),
+                    sub_edge: level * 2 + interior.max(1),
+                    simple: simple_filter,
+                };
+                let inner = (info.y_mode == B_PRED) || info.non_zero;
+                filter.filter_macroblock(&mut frame.y, frame.y_stride, mbx, mby, 16, inner);
+                if !simple_filter {
+                    filter.filter_macroblock(&mut frame.u, frame.uv_stride, mbx, mby, 8, inner);
+                    filter.filter_macroblock(&mut frame.v, frame.uv_stride, mbx, mby, 8, inner);
+                }
+            }
+        }
+    }
+    Ok(frame)
+}
+
+// returns whether any block has coefficients, which decides if the edges between the
+// subblocks get filtered
+fn read_residuals(bd: &mut BoolDecoder, probs: &CoeffProbs, quantizer: &Quantizer, has_y2: bool, above: &mut [bool; 9], left: &mut [bool; 9], coeffs: &mut [i32; 384]) -> bool {
+    let mut any_non_zero = false;
+    let mut first = 0;
+    let mut y_probs = &probs[3];
+    if has_y2 {
+        // the DC coefficients of the luma blocks are coded separately
+        let mut y2 = [0i32; 16];
+        let context = above[8] as usize + left[8] as usize;
+        let non_zero = read_coefficients(bd, &probs[1], context, 0, quantizer.y2, &mut y2);
+        above[8] = non_zero;
+        left[8] = non_zero;
+        for (i, dc) in inverse_wht(&y2).iter().enumerate() {
+            coeffs[i * 16] = *dc as i16 as i32;
+            any_non_zero |= coeffs[i * 16] != 0;
+        }
+        first = 1;
+        y_probs = &probs[0];
+    }
+    for sy in 0..4 {
+        for sx in 0..4 {
+            let context = above[sx] as usize + left[sy] as usize;
+            let block = &mut coeffs[(sy * 4 + sx) * 16..][..16];
+            let non_zero = read_coefficients(bd, y_probs, context, first, quantizer.y, block);
+            above[sx] = non_zero;
+            left[sy] = non_zero;
+            any_non_zero |= non_zero;
+        }
+    }
+    for plane in 0..2 {
+        for sy in 0..2 {
+            for sx in 0..2 {
+                let (a, l) = (4 + plane * 2 + sx, 4 + plane * 2 + sy);
+                let context = above[a] as usize + left[l] as usize;
+                let block = &mut coeffs[256 + plane * 64 + (sy * 2 + sx) * 16..][..16];
+                let non_zero = read_coefficients(bd, &probs[2], context, 0, quantizer.uv, block);
+                above[a] = non_zero;
+                left[l] = non_zero;
+                any_non_zero |= non_zero;
+            }
+        }
+    }
+    any_non_zero
+}
+
+// returns whether anything but an immediate end of block was coded
+fn read_coefficients(bd: &mut BoolDecoder, probs: &[[[u8; 11]; 3]; 8], mut context: usize, first: usize, quantizer: [i32; 2], block: &mut [i32]) -> bool {
+    let mut start = 0;
+    let mut non_zero = false;
+    for i in first..16 {
+        let token = bd.read_tree(&DCT_TOKEN_TREE, &probs[COEFF_BANDS[i]][context], start);
+        if token == DCT_EOB {
+            break;
+        }
+        non_zero = true;
+        let value = match token {
+            DCT_0 => 0,
+            DCT_1..=DCT_4 => token as i32,
+            _ => {
+                let category = (token - DCT_CAT1) as usize;
+                let mut extra = 0;
+                for prob in DCT_CAT_PROBS[category] {
+                    extra = (extra << 1) | (bd.read_bool(*prob) as i32);
+                }
+                DCT_CAT_BASE[category] + extra
+            },
+        };
+        if value == 0 {
+            // an end of block can't follow a zero
+            context = 0;
+            start = 2;
+        }
+        else {
+            context = if value > 1 {2} else {1};
+            start = 0;
+            let value = if bd.read_flag() {-value} else {value};
+            // coefficients are 16 bit, like in libvpx and libwebp
+            block[ZIGZAG[i]] = (value * quantizer[(i > 0) as usize]) as i16 as i32;
+        }
+    }
+    non_zero
+}
+
+fn inverse_wht(src: &[i32; 16]) -> [i32; 16] {
+    let mut tmp = [0i32; 16];
+    for i in 0..4 {
+        let a1 = src[i] + src[12 + i];
+        let b1 = src[4 + i] + src[8 + i];
+        let c1 = src[4 + i] - src[8 + i];
+        let d1 = src[i] - src[12 + i];
+        tmp[i] = a1 + b1;
+        tmp[4 + i] = c1 + d1;
+        tmp[8 + i] = a1 - b1;
+        tmp[12 + i] = d1 - c1;
+    }
+    let mut dst = [0i32; 16];
+    for i in 0..4 {
+        let a1 = tmp[4 * i] + tmp[4 * i + 3];
+        let b1 = tmp[4 * i + 1] + tmp[4 * i + 2];
+        let c1 = tmp[4 * i + 1] - tmp[4 * i + 2];
+        let d1 = tmp[4 * i] - tmp[4 * i + 3];
+        dst[4 * i] = (a1 + b1 + 3) >> 3;
+        dst[4 * i + 1] = (c1 + d1 + 3) >> 3;
+        dst[4 * i + 2] = (a1 - b1 + 3) >> 3;
+        dst[4 * i + 3] = (d1 - c1 + 3) >> 3;
+    }
+    dst
+}
+
+// the row pass works on sums of 16 bit coefficients, which can overflow an i32 once multiplied
+fn mul_16(a: i32, b: i32) -> i32 {
+    ((a as i64 * b as i64) >> 16) as i32
+}
+
+fn inverse_dct(src: &[i32]) -> [i32; 16] {
+    const C1: i32 = 20091; // cos(pi / 8) * sqrt(2) - 1, in 16.16
+    const C2: i32 = 35468; // sin(pi / 8) * sqrt(2), in 16.16
+    let mut tmp = [0i32; 16];
+    for i in 0..4 {
+        let a1 = src[i] + src[8 + i];
+        let b1 = src[i] - src[8 + i];
+        let c1 = mul_16(src[4 + i], C2) - (src[12 + i] + mul_16(src[12 + i], C1));
+        let d1 = (src[4 + i] + mul_16(src[4 + i], C1)) + mul_16(src[12 + i], C2);
+        tmp[i] = a1 + d1;
+        tmp[4 + i] = b1 + c1;
+        tmp[8 + i] = b1 - c1;
+        tmp[12 + i] = a1 - d1;
+    }
+    let mut dst = [0i32; 16];
+    for i in 0..4 {
+        let row = &tmp[4 * i..4 * i + 4];
+        let a1 = row[0] + row[2];
+        let b1 = row[0] - row[2];
+        let c1 = mul_16(row[1], C2) - (row[3] + mul_16(row[3], C1));
+        let d1 = (row[1] + mul_16(row[1], C1)) + mul_16(row[3], C2);
+        dst[4 * i] = (a1 + d1 + 4) >> 3;
+        dst[4 * i + 1] = (b1 + c1 + 4) >> 3;
+        dst[4 * i + 2] = (b1 - c1 + 4) >> 3;
+        dst[4 * i + 3] = (a1 - d1 + 4) >> 3;
+    }
+    dst
+}
+
+fn avg2(a: i32, b: i32) -> i32 {
+    (a + b + 1) >> 1
+}
+
+fn avg3(a: i32, b: i32, c: i32) -> i32 {
+    (a + 2 * b + c + 2) >> 2
+}
+
+// predicts a size x size block at (1, 1) in a workspace that has the pixels above it in row 0
+// and the pixels left of it in column 0
+fn predict_block(ws: &mut [u8], stride: usize, size: usize, mode: i8, has_left: bool, has_above: bool) {
+    let above: Vec<i32> = ws[1..=size].iter().map( | p | *p as i32).collect();
+    let left: Vec<i32> = (1..=size).map( | y | ws[y * stride] as i32).collect();
+    let corner = ws[0] as i32;
+    let shift = if size == 16 {3} else {2};
+    let dc = match (has_above, has_left) {
+        (true, true) => (above.iter().sum::<i32>() + left.iter().sum::<i32>() + size as i32) >> (shift + 2),
+        (true, false) => (above.iter().sum::<i32>() + (size as i32 >> 1)) >> (shift + 1),
+        (false, true) => (left.iter().sum::<i32>() + (size as i32 >> 1)) >> (shift + 1),
+        (false, false) => 128,
+    };
+    for y in 0..size {
+        for x in 0..size {
+            let value = match mode {
+                V_PRED => above[x],
+                H_PRED => left[y],
+                TM_PRED => (left[y] + above[x] - corner).clamp(0, 255),
+                _ => dc,
+            };
+            ws[(y + 1) * stride + x + 1] = value as u8;
+        }
+    }
+}
+
+fn predict_subblock(ws: &mut [u8], stride: usize, x0: usize, y0: usize, mode: i8) {
+    let top = (y0 - 1) * stride + x0;
+    let a: [i32; 8] = std::array::from_fn( | i | ws[top + i] as i32);
+    let l: [i32; 4] = std::array::from_fn( | i | ws[(y0 + i) * stride + x0 - 1] as i32);
+    let p = ws[top - 1] as i32;
+    // the edge from the bottom left, through the corner, to the top right
+    let e = [l[3], l[2], l[1], l[0], p, a[0], a[1], a[2], a[3]];
+    let mut b = [[0i32; 4]; 4];
+    match mode {
+        B_TM_PRED => {
+            for (r, row) in b.iter_mut().enumerate() {
+                for (c, value) in row.iter_mut().enumerate() {
+                    *value = (l[r] + a[c] - p).clamp(0, 255);
+                }
+            }
+        },
+        B_VE_PRED => {
+            for row in b.iter_mut() {
+                for (c, value) in row.iter_mut().enumerate() {
+                    *value = avg3(if c == 0 {p} else {a[c - 1]}, a[c], a[c + 1]);
+                }
+            }
+        },
+        B_HE_PRED => {
+            let column = [avg3(p, l[0], l[1]), avg3(l[0], l[1], l[2]), avg3(l[1], l[2], l[3]), avg3(l[2], l[3], l[3])];
+            for (row, value) in b.iter_mut().zip(column) {
+                row.fill(value);
+            }
+        },
+        B_LD_PRED => {
+            for (r, row) in b.iter_mut().enumerate() {
+                for (c, value) in row.iter_mut().enumerate() {
+                    let i = r + c;
+                    *value = if i < 6 {avg3(a[i], a[i + 1], a[i + 2])} else {avg3(a[6], a[7], a[7])};
+                }
+            }
+        },
+        B_RD_PRED => {
+            for (r, row) in b.iter_mut().enumerate() {
+                for (c, value) in row.iter_mut().enumerate() {
+                    let i = 3 + c - r;
+                    *value = avg3(e[i], e[i + 1], e[i + 2]);
+                }
+            }
+        },
+        B_VR_PRED => {
+            b[3][0] = avg3(e[1], e[2], e[3]);
+            b[2][0] = avg3(e[2], e[3], e[4]);
+            b[3][1] = avg3(e[3], e[4], e[5]);
+            b[1][0] = b[3][1];
+            b[2][1] = avg2(e[4], e[5]);
+            b[0][0] = b[2][1];
+            b[3][2] = avg3(e[4], e[5], e[6]);
+            b[1][1] = b[3][2];
+            b[2][2] = avg2(e[5], e[6]);
+            b[0][1] = b[2][2];
+            b[3][3] = avg3(e[5], e[6], e[7]);
+            b[1][2] = b[3][3];
+            b[2][3] = avg2(e[6], e[7]);
+            b[0][2] = b[2][3];
+            b[1][3] = avg3(e[6], e[7], e[8]);
+            b[0][3] = avg2(e[7], e[8]);
+        },
+        B_VL_PRED => {
+            b[0][0] = avg2(a[0], a[1]);
+            b[1][0] = avg3(a[0], a[1], a[2]);
+            b[2][0] = avg2(a[1], a[2]);
+            b[0][1] = b[2][0];
+            b[1][1] = avg3(a[1], a[2], a[3]);
+            b[3][0] = b[1][1];
+            b[2][1] = avg2(a[2], a[3]);
+            b[0][2] = b[2][1];
+            b[3][1] = avg3(a[2], a[3], a[4]);
+            b[1][2] = b[3][1];
+            b[2][2] = avg2(a[3], a[4]);
+            b[0][3] = b[2][2];
+            b[3][2] = avg3(a[3], a[4], a[5]);
+            b[1][3] = b[3][2];
+            b[2][3] = avg3(a[4], a[5], a[6]);
+            b[3][3] = avg3(a[5], a[6], a[7]);
+        },
+        B_HD_PRED => {
+            b[3][0] = avg2(e[0], e[1]);
+            b[3][1] = avg3(e[0], e[1], e[2]);
+            b[2][0] = avg2(e[1], e[2]);
+            b[3][2] = b[2][0];
+            b[2][1] = avg3(e[1], e[2], e[3]);
+            b[3][3] = b[2][1];
+            b[2][2] = avg2(e[2], e[3]);
+            b[1][0] = b[2][2];
+            b[2][3] = avg3(e[2], e[3], e[4]);
+            b[1][1] = b[2][3];
+            b[1][2] = avg2(e[3], e[4]);
+            b[0][0] = b[1][2];
+            b[1][3] = avg3(e[3], e[4], e[5]);
+            b[0][1] = b[1][3];
+            b[0][2] = avg3(e[4], e[5], e[6]);
+            b[0][3] = avg3(e[5], e[6], e[7]);
+        },
+        B_HU_PRED => {
+            b[0][0] = avg2(l[0], l[1]);
+            b[0][1] = avg3(l[0], l[1], l[2]);
+            b[0][2] = avg2(l[1], l[2]);
+            b[1][0] = b[0][2];
+            b[0][3] = avg3(l[1], l[2], l[3]);
+            b[1][1] = b[0][3];
+            b[1][2] = avg2(l[2], l[3]);
+            b[2][0] = b[1][2];
+            b[1][3] = avg3(l[2], l[3], l[3]);
+            b[2][1] = b[1][3];
+            b[2][2] = l[3];
+            b[2][3] = l[3];
+            b[3] = [l[3]; 4];
+        },
+        _ => { // B_DC_PRED
+            let dc = (a[0..4].iter().sum::<i32>() + l.iter().sum::<i32>() + 4) >> 3;
+            b = [[dc; 4]; 4];
+        },
+    }
+    for (r, row) in b.iter().enumerate() {
+        for (c, value) in row.iter().enumerate() {
+            ws[(y0 + r) * stride + x0 + c] = *value as u8;
+        }
+    }
+}
+
+fn add_residual(ws: &mut [u8], stride: usize, x0: usize, y0: usize, block: &[i32]) {
+    if block.iter().all( | coeff | *coeff == 0) {
+        return;
+    }
+    let residual = inverse_dct(block);
+    for y in 0..4 {
+        for x in 0..4 {
+            let pixel = &mut ws[(y0 + y) * stride + x0 + x];
+            *pixel = (*pixel as i32 + residual[y * 4 + x]).clamp(0, 255) as u8;
+        }
+    }
+}
+
+// Copies the edges of a macroblock into a workspace, with 127 above the frame and 129 left of
+// it. Luma also gets the 4 pixels above and to the right, which the subblocks in the right
+// column all use.
+fn load_workspace(ws: &mut [u8], plane: &[u8], stride: usize, size: usize, mbx: usize, mby: usize, mbw: usize) {
+    let extra = if size == 16 {4} else {0};
+    let ws_stride = 1 + size + extra;
+    let x0 = mbx * size;
+    let y0 = mby * size;
+    if mby == 0 {
+        ws[0..ws_stride].fill(127);
+    }
+    else {
+        let above = &plane[(y0 - 1) * stride..];
+        ws[0] = if mbx == 0 {129} else {above[x0 - 1]};
+        ws[1..=size].copy_from_slice(&above[x0..x0 + size]);
+        for i in 0..extra {
+            ws[1 + size + i] = if mbx + 1 < mbw {above[x0 + size + i]} else {above[x0 + size - 1]};
+        }
+    }
+    for y in 0..size {
+        ws[(y + 1) * ws_stride] = if mbx == 0 {129} else {plane[(y0 + y) * stride + x0 - 1]};
+    }
+    if extra > 0 {
+        for y in [4, 8, 12] {
+            ws.copy_within(1 + size..ws_stride, y * ws_stride + 1 + size);
+        }
+    }
+}
+
+fn store_workspace(ws: &[u8], plane: &mut [u8], stride: usize, size: usize, mbx: usize, mby: usize) {
+    let ws_stride = if size == 16 {21} else {9};
+    for y in 0..size {
+        let dst = (mby * size + y) * stride + mbx * size;
+        plane[dst..dst + size].copy_from_slice(&ws[(y + 1) * ws_stride + 1..][..size]);
+    }
+}
+
+impl Vp8Frame {
+    #[allow(clippy::too_many_arguments)]
+    fn reconstruct(&mut self, mbx: usize, mby: usize, mbw: usize, y_mode: i8, b_modes: &[i8; 16], uv_mode: i8, coeffs: &[i32; 384]) {
+        let mut ws = [0u8; 21 * 17];
+        load_workspace(&mut ws, &self.y, self.y_stride, 16, mbx, mby, mbw);
+        if y_mode != B_PRED {
+            predict_block(&mut ws, 21, 16, y_mode, mbx > 0, mby > 0);
+        }
+        for (i, block) in coeffs[0..256].chunks(16).enumerate() {
+            let (x0, y0) = (1 + (i & 3) * 4, 1 + (i >> 2) * 4);
+            if y_mode == B_PRED {
+                predict_subblock(&mut ws, 21, x0, y0, b_modes[i]);
+            }
+            add_residual(&mut ws, 21, x0, y0, block);
+        }
+        store_workspace(&ws, &mut self.y, self.y_stride, 16, mbx, mby);
+
+        for (plane, coeffs) in [&mut self.u, &mut self.v].iter_mut().zip(coeffs[256..].chunks(64)) {
+            let mut ws = [0u8; 9 * 9];
+            load_workspace(&mut ws, &plane[..], self.uv_stride, 8, mbx, mby, mbw);
+            predict_block(&mut ws, 9, 8, uv_mode, mbx > 0, mby > 0);
+            for (i, block) in coeffs.chunks(16).enumerate() {
+                add_residual(&mut ws, 9, 1 + (i & 1) * 4, 1 + (i >> 1) * 4, block);
+            }
+            store_workspace(&ws, &mut plane[..], self.uv_stride, 8, mbx, mby);
+        }
+    }
+
+    /// Converts the frame to ARGB, interpolating the chroma between the samples like libwebp
+    /// does, with the alpha plane if there is one.
+    pub fn to_image(&self, alpha: Option<&[u8]>) -> ImageBuffer {
+        let mut image = ImageBuffer::new(self.width, self.height);
+        let last_x = self.width.div_ceil(2) - 1;
+        let last_y = self.height.div_ceil(2) - 1;
+        for y in 0..self.height {
+            let near_y = y >> 1;
+            let far_y = if (y & 1) == 0 {near_y.saturating_sub(1)} else {(near_y + 1).min(last_y)};
+            for x in 0..self.width {
+                let near_x = x >> 1;
+                let far_x = if (x & 1) == 0 {near_x.saturating_sub(1)} else {(near_x + 1).min(last_x)};
+                let sample = | plane: &[u8] | {
+                    let near = near_y * self.uv_stride;
+                    let far = far_y * self.uv_stride;
+                    (9 * plane[near + near_x] as i32 + 3 * plane[near + far_x] as i32 + 3 * plane[far + near_x] as i32 + plane[far + far_x] as i32 + 8) >> 4
+                };
+                let (r, g, b) = yuv_to_rgb(self.y[y * self.y_stride + x] as i32, sample(&self.u), sample(&self.v));
+                let a = alpha.map( | alpha | alpha[y * self.width + x] as u32).unwrap_or(255);
+                image.data[y * self.width + x] = (a << 24) | (r << 16) | (g << 8) | b;
+            }
+        }
+        image
+    }
+}
+
+// BT.601 with limited range, in the fixed point of libwebp
+fn yuv_to_rgb(y: i32, u: i32, v: i32) -> (u32, u32, u32) {
+    fn mul_hi(value: i32, coeff: i32) -> i32 {
+        (value * coeff) >> 8
+    }
+    fn clip(value: i32) -> u32 {
+        (value >> 6).clamp(0, 255) as u32
+    }
+    let y = mul_hi(y, 19077);
+    (
+        clip(y + mul_hi(v, 26149) - 14234),
+        clip(y - mul_hi(u, 6419) - mul_hi(v, 13320) + 8708),
+        clip(y + mul_hi(u, 33050) - 17685),
+    )
+}
+
+struct LoopFilter {
+    interior: i32,
+    hev_threshold: i32,
+    mb_edge: i32,
+    sub_edge: i32,
+    simple: bool,
+}
+
+fn to_signed(value: u8) -> i32 {
+    value as i32 - 128
+}
+
+fn to_unsigned(value: i32) -> u8 {
+    (value.clamp(-128, 127) + 128) as u8
+}
+
+impl LoopFilter {
+    // filters the left and top edges of a macroblock, and the edges between its subblocks
+    fn filter_macroblock(&self, plane: &mut [u8], stride: usize, mbx: usize, mby: usize, size: usize, inner: bool) {
+        let origin = mby * size * stride + mbx * size;
+        let inner_edges = if inner {(4..size).step_by(4)} else {(0..0).step_by(4)};
+        if mbx > 0 {
+            for y in 0..size {
+                self.filter_edge(plane, origin + y * stride, 1, true);
+            }
+        }
+        for x in inner_edges.clone() {
+            for y in 0..size {
+                self.filter_edge(plane, origin + y * stride + x, 1, false);
+            }
+        }
+        if mby > 0 {
+            for x in 0..size {
+                self.filter_edge(plane, origin + x, stride, true);
+            }
+        }
+        for y in inner_edges {
+            for x in 0..size {
+                self.filter_edge(plane, origin + y * stride + x, stride, false);
+            }
+        }
+    }
+
+    // filters across the edge in front of the pixel at pos, with step going across it
+    fn filter_edge(&self, plane: &mut [u8], pos: usize, step: usize, macroblock_edge: bool) {
+        let pixel = | i: isize | plane[(pos as isize + i * step as isize) as usize] as i32;
+        let (p1, p0, q0, q1) = (pixel(-2), pixel(-1), pixel(0), pixel(1));
+        let edge_limit = if macroblock_edge {self.mb_edge} else {self.sub_edge};
+        if (p0 - q0).abs() * 2 + (p1 - q1).abs() / 2 > edge_limit {
+            return;
+        }
+        if self.simple {
+            self.common_adjust(plane, pos, step, true);
+            return;
+        }
+        let (p3, p2, q2, q3) = (pixel(-4), pixel(-3), pixel(2), pixel(3));
+        if [p3 - p2, p2 - p1, p1 - p0, q3 - q2, q2 - q1, q1 - q0].iter().any( | d | d.abs() > self.interior) {
+            return;
+        }
+        let hev = ((p1 - p0).abs() > self.hev_threshold) || ((q1 - q0).abs() > self.hev_threshold);
+        if macroblock_edge {
+            if hev {
+                self.common_adjust(plane, pos, step, true);
+                return;
+            }
+            let (p2, p1, p0) = (p2 - 128, p1 - 128, p0 - 128);
+            let (q0, q1, q2) = (q0 - 128, q1 - 128, q2 - 128);
+            let w = ((p1 - q1).clamp(-128, 127) + 3 * (q0 - p0)).clamp(-128, 127);
+            let a = ((27 * w + 63) >> 7).clamp(-128, 127);
+            plane[pos] = to_unsigned(q0 - a);
+            plane[pos - step] = to_unsigned(p0 + a);
+            let a = ((18 * w + 63) >> 7).clamp(-128, 127);
+            plane[pos + step] = to_unsigned(q1 - a);
+            plane[pos - 2 * step] = to_unsigned(p1 + a);
+            let a = ((9 * w + 63) >> 7).clamp(-128, 127);
+            plane[pos + 2 * step] = to_unsigned(q2 - a);
+            plane[pos - 3 * step] = to_unsigned(p2 + a);
+        }
+        else {
+            let a = (self.common_adjust(plane, pos, step, hev) + 1) >> 1;
+            if !hev {
+                plane[pos + step] = to_unsigned(to_signed(plane[pos + step]) - a);
+                plane[pos - 2 * step] = to_unsigned(to_signed(plane[pos - 2 * step]) + a);
+            }
+        }
+    }
+
+    fn common_adjust(&self, plane: &mut [u8], pos: usize, step: usize, use_outer_taps: bool) -> i32 {
+        let p1 = to_signed(plane[pos - 2 * step]);
+        let p0 = to_signed(plane[pos - step]);
+        let q0 = to_signed(plane[pos]);
+        let q1 = to_signed(plane[pos + step]);
+        let outer = if use_outer_taps {(p1 - q1).clamp(-128, 127)} else {0};
+        let a = (outer + 3 * (q0 - p0)).clamp(-128, 127);
+        let b = (a + 3).clamp(-128, 127) >> 3;
+        let a = (a + 4).clamp(-128, 127) >> 3;
+        plane[pos] = to_unsigned(q0 - a);
+        plane[pos - step] = to_unsigned(p0 + b);
+        a
+    }
+}
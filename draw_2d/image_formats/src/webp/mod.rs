@@ -0,0 +1,228 @@
+// image_formats::webp
+
+use crate::{AnimatedImage, AnimationFrame, ImageBuffer};
+
+mod lossless;
+mod lossy;
+
+const MAX_PIXELS: usize = 400_000_000;
+
+struct Chunk<'a> {
+    id: [u8; 4],
+    data: &'a [u8],
+}
+
+fn from_le16(src: &[u8]) -> usize {
+    (src[0] as usize) | ((src[1] as usize) << 8)
+}
+
+fn from_le24(src: &[u8]) -> usize {
+    (src[0] as usize) | ((src[1] as usize) << 8) | ((src[2] as usize) << 16)
+}
+
+fn from_le32(src: &[u8]) -> usize {
+    from_le24(src) | ((src[3] as usize) << 24)
+}
+
+fn read_chunks(src: &[u8]) -> Result<Vec<Chunk<'_>>, String> {
+    let mut chunks = Vec::new();
+    let mut sp = 0;
+    while sp + 8 <= src.len() {
+        let id = [src[sp], src[sp + 1], src[sp + 2], src[sp + 3]];
+        let size = from_le32(&src[sp + 4..]);
+        sp += 8;
+        if sp + size > src.len() {
+            return Err("Invalid WebP".to_string());
+        }
+        chunks.push(Chunk {id, data: &src[sp..sp + size]});
+        // chunks are padded to an even size
+        sp += size + (size & 1);
+    }
+    Ok(chunks)
+}
+
+fn read_file(src: &[u8]) -> Result<Vec<Chunk<'_>>, String> {
+    if (src.len() < 12) || (&src[0..4] != b"RIFF") || (&src[8..12] != b"WEBP") {
+        return Err("Invalid WebP".to_string());
+    }
+    let end = (8 + from_le32(&src[4..8])).min(src.len());
+    read_chunks(&src[12..end])
+}
+
+// undoes the filtering and compression of an ALPH chunk
+fn decode_alpha(src: &[u8], width: usize, height: usize) -> Result<Vec<u8>, String> {
+    if src.is_empty() {
+        return Err("Invalid WebP".to_string());
+    }
+    let compression = src[0] & 3;
+    let filtering = (src[0] >> 2) & 3;
+    let mut alpha: Vec<u8> = match compression {
+        0 => {
+            if src.len() < 1 + width * height {
+                return Err("Invalid WebP".to_string());
+            }
+            src[1..1 + width * height].to_vec()
+        },
+        1 => lossless::decode_image_stream(&src[1..], width, height)?.iter().map( | pixel | (pixel >> 8) as u8).collect(),
+        _ => return Err("Invalid WebP".to_string()),
+    };
+    if filtering != 0 {
+        for y in 0..height {
+            for x in 0..width {
+                let i = y * width + x;
+                let prediction = if (x == 0) && (y == 0) {
+                    0
+                }
+                else if y == 0 {
+                    alpha[i - 1]
+                }
+                else if x == 0 {
+                    alpha[i - width]
+                }
+                else {
+                    match filtering {
+                        1 => alpha[i - 1],
+                        2 => alpha[i - width],
+                        _ => (alpha[i - 1] as i32 + alpha[i - width] as i32 - alpha[i - width - 1] as i32).clamp(0, 255) as u8,
+                    }
+                };
+                alpha[i] = alpha[i].wrapping_add(prediction);
+            }
+        }
+    }
+    Ok(alpha)
+}
+
+// decodes the image in a list of chunks, which is either a VP8L chunk, or a VP8 chunk that
+// can come after an ALPH chunk
+fn decode_image(chunks: &[Chunk]) -> Result<ImageBuffer, String> {
+    let mut alpha = None;
+    for chunk in chunks {
+        match &chunk.id {
+            b"ALPH" => {
+                alpha = Some(chunk.data);
+            },
+            b"VP8L" => {
+                return lossless::decode(chunk.data);
+            },
+            b"VP8 " => {
+                let frame = lossy::decode_frame(chunk.data)?;
+                let alpha = match alpha {
+                    Some(alpha) => Some(decode_alpha(alpha, frame.width, frame.height)?),
+                    None => None,
+                };
+                return Ok(frame.to_image(alpha.as_deref()));
+            },
+            _ => {},
+        }
+    }
+    Err("Invalid WebP".to_string())
+}
+
+// composites a pixel over another one, both without premultiplied alpha
+fn blend(src: u32, dst: u32) -> u32 {
+    let src_alpha = src >> 24;
+    if src_alpha == 255 {
+        return src;
+    }
+    if src_alpha == 0 {
+        return dst;
+    }
+    let dst_alpha = (dst >> 24) * (255 - src_alpha) / 255;
+    let alpha = src_alpha + dst_alpha;
+    let mut result = alpha << 24;
+    for shift in [0, 8, 16] {
+        let value = (((src >> shift) & 0xFF) * src_alpha + ((dst >> shift) & 0xFF) * dst_alpha) / alpha;
+        result |= value << shift;
+    }
+    result
+}
+
+pub fn test(src: &[u8]) -> Option<(usize, usize)> {
+    if (src.len() < 30) || (&src[0..4] != b"RIFF") || (&src[8..12] != b"WEBP") {
+        return None;
+    }
+    match &src[12..16] {
+        b"VP8X" => Some((from_le24(&src[24..27]) + 1, from_le24(&src[27..30]) + 1)),
+        b"VP8L" => lossless::test(&src[20..]),
+        b"VP8 " => lossy::test(&src[20..]),
+        _ => None,
+    }
+}
+
+pub fn decode(src: &[u8]) -> Result<ImageBuffer, String> {
+    let chunks = read_file(src)?;
+    if chunks.iter().any( | chunk | &chunk.id == b"ANMF") {
+        let mut animation = decode_animation(src)?;
+        return Ok(animation.frames.swap_remove(0).image);
+    }
+    decode_image(&chunks)
+}
+
+pub fn decode_animation(src: &[u8]) -> Result<AnimatedImage, String> {
+    let chunks = read_file(src)?;
+    if !chunks.iter().any( | chunk | &chunk.id == b"ANMF") {
+        return Ok(AnimatedImage::from_image(decode_image(&chunks)?));
+    }
+    let (width, height) = match test(src) {
+        Some(size) => size,
+        None => return Err("Invalid WebP".to_string()),
+    };
+    if width * height > MAX_PIXELS {
+        return Err("Invalid WebP".to_string());
+    }
+    let mut animation = AnimatedImage {
+        width,
+        height,
+        frames: Vec::new(),
+        loop_count: 0,
+    };
+    // browsers ignore the background color and start from a transparent canvas
+    let mut canvas = vec![0u32; width * height];
+    for chunk in chunks {
+        match &chunk.id {
+            b"ANIM" => {
+                if chunk.data.len() < 6 {
+                    return Err("Invalid WebP".to_string());
+                }
+                animation.loop_count = from_le16(&chunk.data[4..6]);
+            },
+            b"ANMF" => {
+                if chunk.data.len() < 16 {
+                    return Err("Invalid WebP".to_string());
+                }
+                let x0 = (from_le24(&chunk.data[0..3]) * 2).min(width);
+                let y0 = (from_le24(&chunk.data[3..6]) * 2).min(height);
+                let duration = from_le24(&chunk.data[12..15]);
+                let flags = chunk.data[15];
+                let image = decode_image(&read_chunks(&chunk.data[16..])?)?;
+                let blending = (flags & 2) == 0;
+                let dispose = (flags & 1) != 0;
+                let x1 = (x0 + image.width).min(width);
+                let y1 = (y0 + image.height).min(height);
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        let pixel = image.data[(y - y0) * image.width + x - x0];
+                        let dst = &mut canvas[y * width + x];
+                        *dst = if blending {blend(pixel, *dst)} else {pixel};
+                    }
+                }
+
+                // browsers show frames with a very short duration for 100 ms, like in GIF
+                let milliseconds = if duration <= 10 {100} else {duration};
+                animation.frames.push(AnimationFrame {
+                    image: ImageBuffer {width, height, data: canvas.clone()},
+                    delay: milliseconds as f64 / 1000.0,
+                });
+
+                if dispose {
+                    for y in y0..y1 {
+                        canvas[y * width + x0..y * width + x1].fill(0);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    Ok(animation)
+}
@@ -0,0 +1,164 @@
+use makepad_image_formats::{gif, png, qoi, webp, ImageBuffer};
+
+const RED: u32 = 0xFFFF0000;
+const GREEN: u32 = 0xFF00FF00;
+const BLUE: u32 = 0xFF0000FF;
+const WHITE: u32 = 0xFFFFFFFF;
+const YELLOW: u32 = 0xFFFFFF00;
+
+// the pixels of pattern.qoi and pattern.webp, which cover every alpha and color operation
+fn pattern_image() -> ImageBuffer {
+    let mut image = ImageBuffer::new(13, 11);
+    for y in 0..11 {
+        for x in 0..13 {
+            let r = (x * 255 / 12) as u32;
+            let g = (y * 255 / 10) as u32;
+            let b = ((x + y) * 37 % 256) as u32;
+            let a = if (x + y) % 3 == 0 {255} else {(x * 20 + y * 3) as u8 as u32};
+            image.data[y * 13 + x] = (a << 24) | (r << 16) | (g << 8) | b;
+        }
+    }
+    image
+}
+
+fn pixel(image: &ImageBuffer, x: usize, y: usize) -> u32 {
+    image.data[y * image.width + x]
+}
+
+#[test]
+fn test_qoi_decode() {
+    let data = include_bytes!("data/pattern.qoi");
+    assert_eq!(qoi::test(data), Some((13, 11)));
+    let image = qoi::decode(data).unwrap();
+    assert_eq!(image.data, pattern_image().data);
+
+    assert!(qoi::decode(&data[..data.len() / 2]).is_err());
+    assert!(qoi::decode(b"qoif").is_err());
+}
+
+#[test]
+fn test_webp_lossless_decode() {
+    let data = include_bytes!("data/pattern.webp");
+    assert_eq!(webp::test(data), Some((13, 11)));
+    let image = webp::decode(data).unwrap();
+    assert_eq!(image.data, pattern_image().data);
+
+    assert!(webp::decode(&data[..data.len() / 2]).is_err());
+}
+
+#[test]
+fn test_webp_lossy_decode() {
+    // a lossy image with a compressed and filtered alpha channel; the expected pixels come
+    // from the libwebp decoder
+    let data = include_bytes!("data/python.webp");
+    assert_eq!(webp::test(data), Some((16, 16)));
+    let image = webp::decode(data).unwrap();
+    let expected = png::decode(include_bytes!("data/python_webp.png")).unwrap();
+    assert_eq!((image.width, image.height), (16, 16));
+    assert_eq!(image.data, expected.data);
+}
+
+#[test]
+fn test_webp_lossy_corrupt_coefficients() {
+    // lossy images with damaged coefficient data, which overflowed the inverse dct. like
+    // libwebp they still decode, to a broken picture
+    let files: [&[u8]; 3] = [
+        include_bytes!("data/corrupt_coefficients_1.webp"),
+        include_bytes!("data/corrupt_coefficients_2.webp"),
+        include_bytes!("data/corrupt_coefficients_3.webp"),
+    ];
+    for data in files {
+        let (width, height) = webp::test(data).unwrap();
+        if let Ok(image) = webp::decode(data) {
+            assert_eq!((image.width, image.height), (width, height));
+        }
+        if let Ok(animation) = webp::decode_animation(data) {
+            assert!(animation.frames.iter().all(|frame| frame.image.data.len() == width * height));
+        }
+    }
+}
+
+#[test]
+fn test_gif_animation() {
+    let data = include_bytes!("data/animated.gif");
+    assert_eq!(gif::test(data), Some((8, 8)));
+    let animation = gif::decode_animation(data).unwrap();
+    assert_eq!((animation.width, animation.height), (8, 8));
+    assert_eq!(animation.loop_count, 3);
+    let delays: Vec<f64> = animation.frames.iter().map( | frame | frame.delay).collect();
+    assert_eq!(delays, [0.05, 0.1, 0.2, 0.1]);
+
+    let frames: Vec<&ImageBuffer> = animation.frames.iter().map( | frame | &frame.image).collect();
+    assert!(frames[0].data.iter().all( | pixel | *pixel == RED));
+
+    // transparent pixels of the second frame show the first one
+    assert_eq!(pixel(frames[1], 2, 2), RED);
+    assert_eq!(pixel(frames[1], 3, 2), BLUE);
+    assert_eq!(pixel(frames[1], 2, 3), BLUE);
+    assert_eq!(pixel(frames[1], 1, 1), RED);
+
+    // the second frame is cleared to transparent, and the third one is interlaced
+    assert_eq!(pixel(frames[2], 3, 3), 0);
+    assert_eq!(pixel(frames[2], 0, 3), RED);
+    for (y, color) in [(4, WHITE), (5, YELLOW), (6, WHITE), (7, YELLOW)] {
+        assert_eq!(pixel(frames[2], 0, y), color);
+        assert_eq!(pixel(frames[2], 7, y), color);
+    }
+
+    // the third frame is restored to the canvas it was drawn on
+    assert_eq!(pixel(frames[3], 0, 0), GREEN);
+    assert_eq!(pixel(frames[3], 1, 0), RED);
+    assert_eq!(pixel(frames[3], 3, 4), 0);
+    assert_eq!(pixel(frames[3], 0, 4), RED);
+    assert_eq!(pixel(frames[3], 0, 7), RED);
+
+    // decoding a single image gives the first frame
+    assert_eq!(gif::decode(data).unwrap().data, frames[0].data);
+}
+
+#[test]
+fn test_webp_animation() {
+    let data = include_bytes!("data/animated.webp");
+    assert_eq!(webp::test(data), Some((8, 8)));
+    let animation = webp::decode_animation(data).unwrap();
+    assert_eq!((animation.width, animation.height), (8, 8));
+    assert_eq!(animation.loop_count, 3);
+    let delays: Vec<f64> = animation.frames.iter().map( | frame | frame.delay).collect();
+    assert_eq!(delays, [0.05, 0.1, 0.2]);
+
+    let frames: Vec<&ImageBuffer> = animation.frames.iter().map( | frame | &frame.image).collect();
+    assert!(frames[0].data.iter().all( | pixel | *pixel == RED));
+
+    // half transparent blue blended over red
+    assert_eq!(pixel(frames[1], 2, 2), 0xFF7F0080);
+    assert_eq!(pixel(frames[1], 5, 5), 0xFF7F0080);
+    assert_eq!(pixel(frames[1], 1, 1), RED);
+
+    // the second frame is disposed to transparent before the third one is drawn
+    assert_eq!(pixel(frames[2], 2, 2), 0);
+    assert_eq!(pixel(frames[2], 5, 5), 0);
+    assert_eq!(pixel(frames[2], 4, 2), GREEN);
+    assert_eq!(pixel(frames[2], 7, 0), GREEN);
+    assert_eq!(pixel(frames[2], 7, 7), RED);
+
+    assert_eq!(webp::decode(data).unwrap().data, frames[0].data);
+}
+
+#[test]
+fn test_frame_at_time() {
+    let animation = gif::decode_animation(include_bytes!("data/animated.gif")).unwrap();
+    assert!((animation.duration() - 0.45).abs() < 1e-9);
+    assert_eq!(animation.frame_at_time(0.0), 0);
+    assert_eq!(animation.frame_at_time(0.06), 1);
+    assert_eq!(animation.frame_at_time(0.2), 2);
+    assert_eq!(animation.frame_at_time(0.44), 3);
+    assert_eq!(animation.frame_at_time(0.46), 0);
+
+    // after three loops the last frame stays on
+    assert_eq!(animation.frame_at_time(1.4), 3);
+    assert_eq!(animation.frame_at_time(100.0), 3);
+
+    let still = qoi::decode(include_bytes!("data/pattern.qoi")).unwrap();
+    let animation = makepad_image_formats::AnimatedImage::from_image(still);
+    assert_eq!(animation.frame_at_time(5.0), 0);
+}
@@ -0,0 +1,87 @@
+use makepad_image_formats::{jpeg, png, ImageBuffer};
+
+fn test_image(width: usize, height: usize, alpha: bool) -> ImageBuffer {
+    let mut image = ImageBuffer::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            // smooth gradients with a sharp edge between two chroma samples, so every filter
+            // gets used and 4:2:0 subsampling does not smear the edge
+            let r = (x * 255 / width) as u32;
+            let g = (y * 255 / height) as u32;
+            let b = if x >= 24 {200} else {40};
+            let a = if alpha {((x + y) * 255 / (width + height)) as u32} else {255};
+            image.data[y * width + x] = (a << 24) | (r << 16) | (g << 8) | b;
+        }
+    }
+    image
+}
+
+fn max_channel_error(a: &ImageBuffer, b: &ImageBuffer) -> u32 {
+    a.data.iter().zip(b.data.iter()).map( | (a, b) | {
+        (0..3).map( | i | {
+            let ca = (a >> (i * 8)) & 255;
+            let cb = (b >> (i * 8)) & 255;
+            if ca > cb {ca - cb} else {cb - ca}
+        }).max().unwrap()
+    }).max().unwrap()
+}
+
+fn mean_channel_error(a: &ImageBuffer, b: &ImageBuffer) -> f64 {
+    let mut total = 0.0;
+    for (a, b) in a.data.iter().zip(b.data.iter()) {
+        for i in 0..3 {
+            let ca = ((a >> (i * 8)) & 255) as f64;
+            let cb = ((b >> (i * 8)) & 255) as f64;
+            total += (ca - cb).abs();
+        }
+    }
+    total / (a.data.len() * 3) as f64
+}
+
+#[test]
+fn test_png_round_trip() {
+    for alpha in [false, true] {
+        let image = test_image(37, 21, alpha);
+        for filter in [png::PngFilter::None, png::PngFilter::Sub, png::PngFilter::Up, png::PngFilter::Average, png::PngFilter::Paeth, png::PngFilter::Adaptive] {
+            let data = png::encode_with_filter(&image, filter).unwrap();
+            assert_eq!(png::test(&data), Some((37, 21)));
+            let decoded = png::decode(&data).unwrap();
+            assert_eq!((decoded.width, decoded.height), (37, 21));
+            assert_eq!(decoded.data, image.data, "{:?}", filter);
+        }
+    }
+    
+    // an opaque image has no alpha channel
+    let data = png::encode(&test_image(4, 4, false)).unwrap();
+    assert_eq!(data[25], 2);
+    let data = png::encode(&test_image(4, 4, true)).unwrap();
+    assert_eq!(data[25], 6);
+    
+    assert!(png::encode(&ImageBuffer::new(0, 4)).is_err());
+}
+
+#[test]
+fn test_jpeg_round_trip() {
+    let image = test_image(45, 29, false);
+    for subsampling in [jpeg::JpegSubsampling::Yuv420, jpeg::JpegSubsampling::Yuv444] {
+        let data = jpeg::encode_with_options(&image, 95, subsampling).unwrap();
+        assert_eq!(jpeg::test(&data), Some((45, 29)));
+        let decoded = jpeg::decode(&data).unwrap();
+        assert_eq!((decoded.width, decoded.height), (45, 29));
+        assert!(mean_channel_error(&image, &decoded) < 3.0, "{:?}", subsampling);
+        assert!(decoded.data.iter().all( | pixel | pixel >> 24 == 255));
+    }
+    
+    // a flat color survives a medium quality almost exactly
+    let mut flat = ImageBuffer::new(20, 20);
+    flat.data.iter_mut().for_each( | pixel | *pixel = 0xFF3080C0);
+    let decoded = jpeg::decode(&jpeg::encode_with_options(&flat, 50, jpeg::JpegSubsampling::Yuv420).unwrap()).unwrap();
+    assert!(max_channel_error(&flat, &decoded) <= 3);
+    
+    // lower quality gives smaller files
+    let high = jpeg::encode_with_options(&image, 95, jpeg::JpegSubsampling::Yuv444).unwrap();
+    let low = jpeg::encode_with_options(&image, 20, jpeg::JpegSubsampling::Yuv444).unwrap();
+    assert!(low.len() < high.len());
+    let low_420 = jpeg::encode_with_options(&image, 20, jpeg::JpegSubsampling::Yuv420).unwrap();
+    assert!(low_420.len() < low.len());
+}
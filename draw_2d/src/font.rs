@@ -5,6 +5,7 @@ pub use {
         io::prelude::*,
         fs::File,
         collections::HashMap,
+        ops::Range,
     },
     crate::{
         makepad_platform::*,
@@ -17,6 +18,8 @@ pub use {
         makepad_vector::geometry::{AffineTransformation, Transform, Vector},
         makepad_vector::internal_iter::*,
         makepad_vector::path::PathIterator,
+        makepad_segment::str::StrExt,
+        makepad_image_formats::{png, ImageBuffer},
     }
 };
 
@@ -117,7 +120,14 @@ pub struct CxFontsAtlas {
     pub path_to_font_id: HashMap<String, usize>,
     pub texture_id: TextureId,
     pub clear_buffer: bool,
-    pub alloc: CxFontsAtlasAlloc
+    pub alloc: CxFontsAtlasAlloc,
+    // bitmap glyphs are decoded on the cpu and copied into their own texture by draw_font_atlas
+    pub color_texture_id: TextureId,
+    pub color_alloc: CxFontsAtlasAlloc,
+    pub color_todo: Vec<CxFontsAtlasBitmapTodo>,
+    pub color_clear: bool,
+    // set when glyphs already drawn have moved in the colour atlas or were evicted from it
+    pub color_redraw: bool,
 }
 
 #[derive(Default)]
@@ -130,7 +140,7 @@ pub struct CxFontsAtlasAlloc {
 }
 
 impl CxFontsAtlas {
-    pub fn new(texture_id: TextureId) -> Self {
+    pub fn new(texture_id: TextureId, color_texture_id: TextureId) -> Self {
         Self {
             fonts: Vec::new(),
             path_to_font_id: HashMap::new(),
@@ -142,11 +152,24 @@ impl CxFontsAtlas {
                 ypos: 0.0,
                 hmax: 0.0,
                 todo: Vec::new(),
-            }
+            },
+            color_texture_id,
+            color_alloc: CxFontsAtlasAlloc {
+                texture_size: DVec2 {x: COLOR_ATLAS_SIZE as f64, y: COLOR_ATLAS_SIZE as f64},
+                ..Default::default()
+            },
+            color_todo: Vec::new(),
+            color_clear: false,
+            color_redraw: false,
         }
     }
 }
 impl CxFontsAtlasAlloc {
+    pub fn has_room(&self, w: f64, h: f64) -> bool {
+        let ypos = if w + self.xpos >= self.texture_size.x {self.ypos + self.hmax + 1.0} else {self.ypos};
+        w < self.texture_size.x && h + ypos < self.texture_size.y
+    }
+    
     pub fn alloc_atlas_glyph(&mut self, w: f64, h: f64) -> CxFontAtlasGlyph {
         if w + self.xpos >= self.texture_size.x {
             self.xpos = 0.0;
@@ -221,15 +244,159 @@ impl CxFontsAtlas {
         font_id
     }
     
-    pub fn reset_font_atlas_and_redraw(&mut self) {
-        for cxfont in &mut self.fonts {
-            if let Some(cxfont) = cxfont {
-                cxfont.atlas_pages.clear();
+    // runs of graphemes drawn with the same font, each grapheme uses the first font with all of its
+    // chars or the first font if none of them have them
+    pub fn font_runs(&self, font_ids: &[usize], text: &str) -> Vec<(usize, Range<usize>)> {
+        let mut runs: Vec<(usize, Range<usize>)> = Vec::new();
+        if font_ids.is_empty() {
+            return runs
+        }
+        for (start, grapheme) in text.grapheme_indices() {
+            let font_id = font_ids.iter().cloned().find( | font_id | {
+                self.fonts[*font_id].as_ref().is_some_and( | cxfont | cxfont.ttf_font.has_glyphs_for(grapheme))
+            }).unwrap_or(font_ids[0]);
+            let end = start + grapheme.len();
+            match runs.last_mut() {
+                Some((last_font_id, range)) if *last_font_id == font_id => range.end = end,
+                _ => runs.push((font_id, start..end))
+            }
+        }
+        runs
+    }
+    
+    // a bitmap glyph in the colour atlas, decoded and added the first time it is used at this size
+    pub fn get_bitmap_glyph(&mut self, font_id: usize, glyph_id: usize, ppem: f64) -> Option<CxFontAtlasBitmapGlyph> {
+        let cxfont = self.fonts[font_id].as_mut()?;
+        let color_glyphs = &cxfont.ttf_font.color_glyphs;
+        let strike_index = color_glyphs.bitmap_strike_index(ppem)?;
+        let strike = &color_glyphs.bitmap_strikes[strike_index];
+        let atlas_ppem = bitmap_atlas_ppem(ppem, strike.ppem);
+        let key = (strike_index, glyph_id, atlas_ppem as usize);
+        if let Some(bitmap_glyph) = cxfont.bitmap_glyphs.get(&key) {
+            return *bitmap_glyph
+        }
+        let strike_ppem = strike.ppem;
+        let decoded = match strike.glyphs.get(&glyph_id) {
+            Some(glyph) => match png::decode(&glyph.png) {
+                Ok(image) => Some((glyph.left, glyph.bottom, image)),
+                Err(err) => {
+                    error!("Error decoding bitmap glyph {} {}", glyph_id, err);
+                    None
+                }
             }
+            None => None
+        };
+        let bitmap_glyph = decoded.and_then( | (left, bottom, image) | {
+            let width = image.width as f64;
+            let height = image.height as f64;
+            let tc = self.alloc_color_glyph(downscale_image(image, atlas_ppem / strike_ppem))?;
+            Some(CxFontAtlasBitmapGlyph {
+                tc,
+                strike_ppem,
+                left,
+                bottom,
+                width,
+                height,
+            })
+        });
+        if let Some(cxfont) = self.fonts[font_id].as_mut() {
+            cxfont.bitmap_glyphs.insert(key, bitmap_glyph);
+        }
+        bitmap_glyph
+    }
+    
+    // places a bitmap in the colour atlas, doubling the atlas when it is full and emptying it
+    // once it can't grow any further
+    fn alloc_color_glyph(&mut self, image: ImageBuffer) -> Option<CxFontAtlasGlyph> {
+        let w = image.width as f64;
+        let h = image.height as f64;
+        while !self.color_alloc.has_room(w, h) {
+            let size = self.color_alloc.texture_size.x;
+            if size < COLOR_ATLAS_MAX_SIZE as f64 {
+                // the glyphs keep their pixel positions, so only their texture coordinates change
+                self.color_alloc.texture_size = dvec2(size * 2.0, size * 2.0);
+                for cxfont in self.fonts.iter_mut().flatten() {
+                    for bitmap_glyph in cxfont.bitmap_glyphs.values_mut().flatten() {
+                        bitmap_glyph.tc.tx1 *= 0.5;
+                        bitmap_glyph.tc.ty1 *= 0.5;
+                        bitmap_glyph.tc.tx2 *= 0.5;
+                        bitmap_glyph.tc.ty2 *= 0.5;
+                    }
+                }
+            }
+            else if self.color_alloc.xpos > 0.0 || self.color_alloc.ypos > 0.0 {
+                self.reset_color_atlas();
+            }
+            else {
+                error!("Bitmap glyph of {}x{} doesn't fit in the colour atlas", w, h);
+                return None
+            }
+            self.color_redraw = true;
+        }
+        let tc = self.color_alloc.alloc_atlas_glyph(w, h);
+        self.color_todo.push(CxFontsAtlasBitmapTodo {
+            x: (tc.tx1 * self.color_alloc.texture_size.x) as usize,
+            y: (tc.ty1 * self.color_alloc.texture_size.y) as usize,
+            image,
+        });
+        Some(tc)
+    }
+    
+    fn reset_color_atlas(&mut self) {
+        for cxfont in self.fonts.iter_mut().flatten() {
+            cxfont.bitmap_glyphs.clear();
+        }
+        self.color_alloc.xpos = 0.;
+        self.color_alloc.ypos = 0.;
+        self.color_alloc.hmax = 0.;
+        self.color_todo.clear();
+        self.color_clear = true;
+    }
+    
+    // copies the new bitmap glyphs into the colour atlas texture, resizing it when the atlas has grown
+    pub fn update_color_texture(&mut self, cx: &mut Cx, texture: &Texture) {
+        let size = self.color_alloc.texture_size.x as usize;
+        let mut desc = texture.get_desc(cx);
+        let old_size = desc.width.unwrap_or(0);
+        if !self.color_clear && self.color_todo.is_empty() && old_size == size {
+            return
+        }
+        // the pixels are owned by the texture, and taken out of it to be updated in place
+        let mut image = Vec::new();
+        texture.swap_image_u32(cx, &mut image);
+        if self.color_clear || image.len() != old_size * old_size {
+            self.color_clear = false;
+            image.clear();
+            image.resize(size * size, 0);
+        }
+        else if old_size != size {
+            let mut grown = vec![0; size * size];
+            for (row, pixels) in image.chunks_exact(old_size).enumerate() {
+                grown[row * size..][..old_size].copy_from_slice(pixels);
+            }
+            image = grown;
+        }
+        for todo in self.color_todo.drain(..) {
+            let width = todo.image.width.min(size.saturating_sub(todo.x));
+            for row in 0..todo.image.height.min(size.saturating_sub(todo.y)) {
+                let start = (todo.y + row) * size + todo.x;
+                image[start..start + width].copy_from_slice(&todo.image.data[row * todo.image.width..][..width]);
+            }
+        }
+        desc.width = Some(size);
+        desc.height = Some(size);
+        texture.set_desc(cx, desc);
+        texture.swap_image_u32(cx, &mut image);
+    }
+    
+    pub fn reset_font_atlas_and_redraw(&mut self) {
+        for cxfont in self.fonts.iter_mut().flatten() {
+            cxfont.atlas_pages.clear();
         }
         self.alloc.xpos = 0.;
         self.alloc.ypos = 0.;
         self.alloc.hmax = 0.;
+        self.reset_color_atlas();
         self.clear_buffer = true;
         todo!();
         //self.redraw_all();
@@ -332,9 +499,9 @@ impl DrawTrapezoidText {
                 let atlas_page = &cxfont.atlas_pages[todo.atlas_page_id];
                 let glyph = &font.glyphs[todo.glyph_id];
                 
-                if todo.glyph_id == font.char_code_to_glyph_index_map[10] ||
-                todo.glyph_id == font.char_code_to_glyph_index_map[9] ||
-                todo.glyph_id == font.char_code_to_glyph_index_map[13] {
+                if todo.glyph_id == font.glyph_index('\n') ||
+                todo.glyph_id == font.glyph_index('\t') ||
+                todo.glyph_id == font.glyph_index('\r') {
                     return
                 }
                 
@@ -386,6 +553,7 @@ pub struct CxDrawFontsAtlas {
     pub atlas_pass: Pass,
     pub atlas_view: View,
     pub atlas_texture: Texture,
+    pub color_atlas_texture: Texture,
     pub counter: usize
 }
 
@@ -394,6 +562,14 @@ impl CxDrawFontsAtlas {
         
         let atlas_texture = Texture::new(cx);
         
+        let color_atlas_texture = Texture::new(cx);
+        color_atlas_texture.set_desc(cx, TextureDesc {
+            format: TextureFormat::ImageBGRA,
+            width: Some(COLOR_ATLAS_SIZE),
+            height: Some(COLOR_ATLAS_SIZE),
+            multisample: None
+        });
+        
         //cx.fonts_atlas.texture_id = Some(atlas_texture.texture_id());
         
         let draw_trapezoid_text = DrawTrapezoidText::new_local(cx);
@@ -404,7 +580,8 @@ impl CxDrawFontsAtlas {
             draw_trapezoid_text,
             atlas_pass: Pass::new(cx),
             atlas_view: View::new(cx),
-            atlas_texture: atlas_texture
+            atlas_texture: atlas_texture,
+            color_atlas_texture,
         }
     }
 }
@@ -416,9 +593,10 @@ impl<'a> Cx2d<'a> {
             
             let draw_fonts_atlas = CxDrawFontsAtlas::new(cx);
             let texture_id = draw_fonts_atlas.atlas_texture.texture_id();
+            let color_texture_id = draw_fonts_atlas.color_atlas_texture.texture_id();
             cx.set_global(CxDrawFontsAtlasRc(Rc::new(RefCell::new(draw_fonts_atlas))));
             
-            let fonts_atlas = CxFontsAtlas::new(texture_id);
+            let fonts_atlas = CxFontsAtlas::new(texture_id, color_texture_id);
             cx.set_global(CxFontsAtlasRc(Rc::new(RefCell::new(fonts_atlas))));
         }
     }
@@ -430,6 +608,11 @@ impl<'a> Cx2d<'a> {
         let mut fonts_atlas = fonts_atlas_rc.0.borrow_mut();
         let fonts_atlas = &mut*fonts_atlas;
         //let start = Cx::profile_time_ns();
+        fonts_atlas.update_color_texture(self.cx, &draw_fonts_atlas.color_atlas_texture);
+        if fonts_atlas.color_redraw {
+            fonts_atlas.color_redraw = false;
+            self.cx.redraw_all();
+        }
         // we need to start a pass that just uses the texture
         if fonts_atlas.alloc.todo.len()>0 {
             self.begin_pass(&draw_fonts_atlas.atlas_pass);
@@ -473,10 +656,16 @@ impl<'a> Cx2d<'a> {
 pub struct CxFont {
     pub ttf_font: makepad_vector::font::TTFFont,
     pub atlas_pages: Vec<CxFontAtlasPage>,
+    // by strike index, glyph id and the size it is kept at in the atlas, None when the bitmap didn't decode
+    pub bitmap_glyphs: HashMap<(usize, usize, usize), Option<CxFontAtlasBitmapGlyph>>,
 }
 
 pub const ATLAS_SUBPIXEL_SLOTS: usize = 64;
 
+pub const COLOR_ATLAS_SIZE: usize = 1024;
+
+pub const COLOR_ATLAS_MAX_SIZE: usize = 4096;
+
 #[derive(Clone)]
 pub struct CxFontAtlasPage {
     pub dpi_factor: f64,
@@ -492,6 +681,24 @@ pub struct CxFontAtlasGlyph {
     pub ty2: f64,
 }
 
+// placement in pixels of the strike
+#[derive(Clone, Copy)]
+pub struct CxFontAtlasBitmapGlyph {
+    pub tc: CxFontAtlasGlyph,
+    pub strike_ppem: f64,
+    pub left: f64,
+    pub bottom: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+// a decoded bitmap glyph waiting to be copied into the colour atlas texture
+pub struct CxFontsAtlasBitmapTodo {
+    pub x: usize,
+    pub y: usize,
+    pub image: ImageBuffer,
+}
+
 #[derive(Default, Debug)]
 pub struct CxFontsAtlasTodo {
     pub subpixel_x_fract: f64,
@@ -509,7 +716,8 @@ impl CxFont {
         let ttf_font = makepad_vector::ttf_parser::parse_ttf(bytes) ?;
         Ok(Self {
             ttf_font,
-            atlas_pages: Vec::new()
+            atlas_pages: Vec::new(),
+            bitmap_glyphs: HashMap::new(),
         })
     }
     
@@ -532,3 +740,46 @@ impl CxFont {
         self.atlas_pages.len() - 1
     }
 }
+
+// the size a bitmap glyph is kept at in the colour atlas: the power of two at or above the size
+// it is drawn at, so nearby sizes share one copy, but never larger than the strike itself
+fn bitmap_atlas_ppem(ppem: f64, strike_ppem: f64) -> f64 {
+    let ppem = ppem.max(1.0).ceil() as usize;
+    (ppem.next_power_of_two() as f64).min(strike_ppem)
+}
+
+// box filters an image down by scale, weighting the colours by alpha so the edges don't darken
+fn downscale_image(image: ImageBuffer, scale: f64) -> ImageBuffer {
+    if scale >= 1.0 {
+        return image
+    }
+    let width = ((image.width as f64 * scale).ceil() as usize).max(1);
+    let height = ((image.height as f64 * scale).ceil() as usize).max(1);
+    let mut data = vec![0; width * height];
+    for y in 0..height {
+        let src_y = (y as f64 / scale) as usize..(((y + 1) as f64 / scale).ceil() as usize).min(image.height);
+        for x in 0..width {
+            let src_x = (x as f64 / scale) as usize..(((x + 1) as f64 / scale).ceil() as usize).min(image.width);
+            let mut count = 0u64;
+            let mut sums = [0u64; 4];
+            for sy in src_y.clone() {
+                for &pixel in &image.data[sy * image.width..][src_x.clone()] {
+                    let alpha = (pixel >> 24) as u64;
+                    sums[0] += alpha;
+                    for (channel, sum) in sums[1..].iter_mut().enumerate() {
+                        *sum += ((pixel >> (channel * 8)) & 0xff) as u64 * alpha;
+                    }
+                    count += 1;
+                }
+            }
+            if count > 0 && sums[0] > 0 {
+                let mut pixel = ((sums[0] / count) as u32) << 24;
+                for (channel, sum) in sums[1..].iter().enumerate() {
+                    pixel |= ((sum / sums[0]) as u32) << (channel * 8);
+                }
+                data[y * width + x] = pixel;
+            }
+        }
+    }
+    ImageBuffer {width, height, data}
+}
@@ -2,6 +2,7 @@ pub use makepad_image_formats;
 pub use makepad_platform;
 pub use makepad_platform::*;
 pub use makepad_vector;
+pub use makepad_segment;
 
 pub mod overlay;
 pub mod cx_2d;
@@ -22,13 +23,19 @@ pub use crate::{
         Padding,
         Flow,
         Size,
-        DeferWalk
+        DeferWalk,
+        GridLayout,
+        GridTrack,
+        GridCell
     },
     overlay::{
         Overlay
     },
     nav::{
         NavRole,
+        NavInfo,
+        NavState,
+        AccessNode,
         NavOrder,
         NavStop,
         NavItem,
@@ -46,8 +53,9 @@ pub use crate::{
     shader::{
         draw_shape::{DrawShape, Shape, Fill},
         draw_quad::DrawQuad,
-        draw_text::DrawText,
+        draw_text::{DrawText, TextSpan, TextWrap, TextLayoutLine},
         draw_color::DrawColor,
+        draw_image::DrawImage,
     },
     geometry::{
         GeometryGen,
@@ -58,6 +66,7 @@ pub use crate::{
 pub fn live_design(cx: &mut Cx) {
     crate::shader::draw_quad::live_design(cx);
     crate::shader::draw_color::live_design(cx);
+    crate::shader::draw_image::live_design(cx);
     crate::shader::draw_shape::live_design(cx);
     crate::shader::draw_text::live_design(cx);
     crate::geometry::geometry_gen::live_design(cx);
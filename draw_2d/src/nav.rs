@@ -7,6 +7,8 @@ use {
         makepad_platform::Area,
         makepad_platform::DrawListId,
         makepad_platform::Margin,
+        makepad_platform::Rect,
+        makepad_platform::dvec2,
         makepad_platform::Cx,
     }
 };
@@ -50,7 +52,26 @@ pub struct NavStop {
     pub role: NavRole,
     pub order: NavOrder,
     pub margin: Margin,
-    pub area: Area
+    pub area: Area,
+    pub info: NavInfo,
+}
+
+// what a nav stop tells the accessibility tree about itself
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NavInfo {
+    pub name: String,
+    pub value: Option<String>,
+    pub state: NavState,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NavState {
+    pub checked: Option<bool>,
+    pub selected: Option<bool>,
+    pub expanded: Option<bool>,
+    pub disabled: bool,
+    // filled in when the tree is made, from the key focus
+    pub focused: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -61,11 +82,65 @@ pub enum NavItem {
     EndScroll(Area)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NavRole {
     TextInput,
     DropDown,
     Slider,
+    Button,
+    CheckBox,
+    RadioButton,
+    Tab,
+    ListItem,
+    // the window, or a scrolled area, in the accessibility tree
+    Group,
+}
+
+// A node of the accessibility tree, which is made from the nav lists of the last draw. Tests can
+// query it and screen reader bridges can hand it on to the platform.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessNode {
+    pub role: NavRole,
+    pub name: String,
+    pub value: Option<String>,
+    pub state: NavState,
+    pub bounds: Rect,
+    pub area: Area,
+    pub children: Vec<AccessNode>,
+}
+
+impl AccessNode {
+    // the first node, depth first, for which the callback returns true
+    pub fn find<F>(&self, mut callback: F) -> Option<&AccessNode> where F: FnMut(&AccessNode) -> bool {
+        fn find<'a, F>(node: &'a AccessNode, callback: &mut F) -> Option<&'a AccessNode> where F: FnMut(&AccessNode) -> bool {
+            if callback(node) {
+                return Some(node)
+            }
+            node.children.iter().find_map( | child | find(child, callback))
+        }
+        find(self, &mut callback)
+    }
+    
+    pub fn find_by_name(&self, role: NavRole, name: &str) -> Option<&AccessNode> {
+        self.find( | node | node.role == role && node.name == name)
+    }
+    
+    pub fn focused(&self) -> Option<&AccessNode> {
+        self.find( | node | node.state.focused)
+    }
+    
+    // all the nodes in depth first order, which is the order tab moves the focus in
+    pub fn flatten(&self) -> Vec<&AccessNode> {
+        fn flatten<'a>(node: &'a AccessNode, out: &mut Vec<&'a AccessNode>) {
+            out.push(node);
+            for child in &node.children {
+                flatten(child, out);
+            }
+        }
+        let mut out = Vec::new();
+        flatten(self, &mut out);
+        out
+    }
 }
 
 impl<'a> Cx2d<'a> {
@@ -114,6 +189,78 @@ impl<'a> Cx2d<'a> {
         }
     }
     
+    pub fn accessibility_tree(cx: &mut Cx, root: DrawListId) -> AccessNode {
+        let nav_tree_rc = cx.get_global::<CxNavTreeRc>().clone();
+        let nav_tree = &*nav_tree_rc.0.borrow();
+        
+        fn group(area: Area, bounds: Rect) -> AccessNode {
+            AccessNode {
+                role: NavRole::Group,
+                name: String::new(),
+                value: None,
+                state: NavState::default(),
+                bounds,
+                area,
+                children: Vec::new()
+            }
+        }
+        // the scrolled areas nest, so the open groups are kept on a stack with the window at the bottom
+        fn collect(cx: &Cx, stack: &mut Vec<AccessNode>, nav_tree: &CxNavTree, draw_list_id: DrawListId) {
+            if draw_list_id.index() >= nav_tree.nav_lists.len() {
+                return
+            }
+            for nav_item in &nav_tree[draw_list_id].nav_list {
+                match nav_item {
+                    NavItem::Child(draw_list_id) => {
+                        collect(cx, stack, nav_tree, *draw_list_id);
+                    }
+                    NavItem::Stop(stop) => {
+                        let rect = stop.area.get_clipped_rect(cx);
+                        let mut state = stop.info.state;
+                        state.focused = cx.has_key_focus(stop.area);
+                        stack.last_mut().unwrap().children.push(AccessNode {
+                            role: stop.role,
+                            name: stop.info.name.clone(),
+                            value: stop.info.value.clone(),
+                            state,
+                            bounds: Rect {
+                                pos: rect.pos - stop.margin.left_top(),
+                                size: rect.size + stop.margin.size()
+                            },
+                            area: stop.area,
+                            children: Vec::new()
+                        });
+                    }
+                    NavItem::BeginScroll(area) => {
+                        stack.push(group(*area, area.get_clipped_rect(cx)));
+                    }
+                    NavItem::EndScroll(_) => if stack.len() > 1 {
+                        let node = stack.pop().unwrap();
+                        stack.last_mut().unwrap().children.push(node);
+                    }
+                }
+            }
+        }
+        let mut stack = vec![group(Area::Empty, Rect::default())];
+        collect(cx, &mut stack, nav_tree, root);
+        while stack.len() > 1 {
+            let node = stack.pop().unwrap();
+            stack.last_mut().unwrap().children.push(node);
+        }
+        let mut root = stack.pop().unwrap();
+        // the window is as large as what is in it
+        if let Some(first) = root.children.first().map( | child | child.bounds) {
+            let (mut min, mut max) = (first.pos, first.pos + first.size);
+            for child in &root.children {
+                let (lo, hi) = (child.bounds.pos, child.bounds.pos + child.bounds.size);
+                min = dvec2(min.x.min(lo.x), min.y.min(lo.y));
+                max = dvec2(max.x.max(hi.x), max.y.max(hi.y));
+            }
+            root.bounds = Rect {pos: min, size: max - min};
+        }
+        root
+    }
+    
     pub fn nav_list_clear(&mut self, draw_list_id: DrawListId) {
         let mut nav_tree = self.nav_tree_rc.0.borrow_mut();
         if draw_list_id.index() >= nav_tree.nav_lists.len() {
@@ -128,12 +275,17 @@ impl<'a> Cx2d<'a> {
     }
     
     pub fn add_nav_stop(&mut self, area: Area, role: NavRole, margin: Margin) {
+        self.add_nav_stop_with_info(area, role, margin, NavInfo::default());
+    }
+    
+    pub fn add_nav_stop_with_info(&mut self, area: Area, role: NavRole, margin: Margin, info: NavInfo) {
         let draw_list_id = *self.draw_list_stack.last().unwrap();
         self.nav_list_item_push(draw_list_id, NavItem::Stop(NavStop {
             role,
             area,
             order: NavOrder::Default,
-            margin
+            margin,
+            info
         }));
     }
     
@@ -1,61 +1,32 @@
-use crate::makepad_platform::*;
-use crate::shader::draw_quad::DrawQuad;
+use {
+    crate::{
+        makepad_platform::*,
+        shader::draw_quad::DrawQuad,
+    },
+};
 
-#[derive(Clone, DrawQuad)]
-#[repr(C)]
-pub struct DrawImage {
-    #[default_shader(self::shader)]
-    #[custom_new()]
-    pub texture: Texture2D,
-    pub base: DrawQuad,
-    pub pt1: Vec2,
-    pub pt2: Vec2,
-    pub alpha: f32
-}
+live_design!{
+    DrawImage= {{DrawImage}} {
+        texture image: texture2d
 
-impl DrawImage {
-    
-    pub fn new(cx: &mut Cx, shader:Shader)->Self{
-        Self{
-            pt1: vec2(0.,0.),
-            pt2: vec2(1.,1.),
-            alpha: 1.0,
-            ..Self::custom_new(cx, shader)
+        fn pixel(self) -> vec4 {
+            let color = sample2d(self.image, mix(self.pt1, self.pt2, self.pos));
+            return vec4(color.rgb * self.alpha, color.a * self.alpha);
         }
     }
-    
-    pub fn style(cx: &mut Cx) {
-        Self::register_draw_input(cx);
-        live_body!(cx, {
-            self::shader: Shader {
-                use crate::drawquad::shader::*;
+}
 
-                draw_input: self::DrawImage;
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawImage {
+    #[live] pub draw_super: DrawQuad,
+    #[live(vec2(0.0, 0.0))] pub pt1: Vec2,
+    #[live(vec2(1.0, 1.0))] pub pt2: Vec2,
+    #[live(1.0)] pub alpha: f32,
+}
 
-                varying tc: vec2;
-                varying v_pixel: vec2;
-                //let dpi_dilate: float<Uniform>;
-                
-                fn vertex() -> vec4 {
-                    // return vec4(geom.x-0.5, geom.y, 0., 1.);
-                    let shift: vec2 = -draw_scroll.xy;
-                    let clipped: vec2 = clamp(
-                        geom * rect_size + rect_pos + shift,
-                        draw_clip.xy,
-                        draw_clip.zw
-                    );
-                    let pos = (clipped - shift - rect_pos) / rect_size;
-                    tc = mix(pt1, pt2, pos);
-                    v_pixel = clipped;
-                    // only pass the clipped position forward
-                    return camera_projection * vec4(clipped.x, clipped.y, draw_depth, 1.);
-                }
-                
-                fn pixel() -> vec4 {
-                    return vec4(sample2d(texture, tc.xy).rgb * alpha, alpha);
-                }
-            }
-        });
+impl DrawImage {
+    pub fn set_texture(&mut self, texture: &Texture) {
+        self.draw_vars.set_texture(0, texture);
     }
 }
-
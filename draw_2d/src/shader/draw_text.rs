@@ -2,7 +2,9 @@ use {
     crate::{
         makepad_platform::*,
         turtle::{Walk, Size, Flow, Align},
-        font::{CxFontsAtlasTodo, CxFontsAtlas, Font},
+        font::{CxFontsAtlasTodo, CxFontsAtlas, CxFontAtlasBitmapGlyph, Font},
+        makepad_vector::font::ShapedGlyph,
+        makepad_segment::{bidi::{self, Paragraph}, str::StrExt},
         view::ManyInstances,
         geometry::GeometryQuad2D,
         cx_2d::Cx2d
@@ -14,7 +16,7 @@ live_design!{
     
     DrawText= {{DrawText}} {
         //debug: true;
-        wrapping: None
+        wrap: Ellipsis
         text_style: {
             font: {
                 path: d"crate://makepad-widgets/resources/IBMPlexSans-Text.ttf"
@@ -27,6 +29,7 @@ live_design!{
         uniform curve: float
         
         texture tex: texture2d
+        texture color_tex: texture2d
         
         varying tex_coord1: vec2
         varying tex_coord2: vec2
@@ -78,6 +81,17 @@ live_design!{
         
         fn pixel(self) -> vec4 {
             
+            if self.is_underline > 0.5 {
+                let col = self.get_color();
+                return vec4(col.rgb * self.brightness * col.a, col.a);
+            }
+            
+            // bitmap glyphs come from the colour atlas as they are
+            if self.is_color > 0.5 {
+                let c = sample2d(self.color_tex, self.tex_coord1.xy);
+                return vec4(c.rgb * c.a, c.a) * self.get_color().a;
+            }
+            
             let dx = dFdx(vec2(self.tex_coord1.x * 2048.0, 0.)).x;
             let dp = 1.0 / 2048.0;
             
@@ -117,6 +131,8 @@ live_design!{
 #[live_ignore]
 pub struct TextStyle {
     #[live()] pub font: Font,
+    // fonts tried in order for graphemes the font has no glyphs for, like CJK or emoji
+    #[live] pub fallback_fonts: Vec<Font>,
     #[live(9.0)] pub font_size: f64,
     #[live(1.0)] pub brightness: f32,
     #[live(0.6)] pub curve: f32,
@@ -124,6 +140,71 @@ pub struct TextStyle {
     #[live(1.1)] pub top_drop: f64,
     #[live(1.3)] pub height_factor: f64,
 }
+
+#[derive(Clone, Copy, Debug, Live, LiveHook, PartialEq)]
+#[live_ignore]
+pub enum TextWrap {
+    // text that doesn't fit is cut off with an ellipsis
+    #[pick] Ellipsis,
+    // text that doesn't fit goes on to the next line, at the places a line can be wrapped
+    Word
+}
+
+// a piece of rich text for draw_spans_walk, the style and colour override the ones of the DrawText
+#[derive(Clone, Default)]
+pub struct TextSpan<'a> {
+    pub text: &'a str,
+    pub text_style: Option<TextStyle>,
+    pub color: Option<Vec4>,
+    pub underline: bool,
+}
+
+impl<'a> TextSpan<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {text, ..Default::default()}
+    }
+}
+
+// a piece of a span between two places where a line can be wrapped or the style changes
+struct TextFragment {
+    span: usize,
+    range: std::ops::Range<usize>,
+    // the offset of the first char in the text of all spans
+    char_offset: usize,
+    width: f64,
+    // the width of the whitespace at the end, which can hang over the end of a line
+    trailing_width: f64,
+    ascent: f64,
+    ends_word: bool,
+    ends_paragraph: bool,
+    level: u8,
+}
+
+// a line of text as draw_spans_walk wraps it
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLayoutLine {
+    // char offsets, the end is before the newline that ends the line
+    pub start: usize,
+    pub end: usize,
+    // ends at a newline or the end of the text rather than being wrapped
+    pub ends_paragraph: bool,
+    // from the top of the text
+    pub y: f64,
+    pub height: f64,
+}
+
+// the position, advance, char offset, char count and direction of a glyph that was drawn
+type HitTestGlyph = (DVec2, f64, usize, usize, bool);
+
+struct TextLine {
+    fragments: Vec<TextFragment>,
+    width: f64,
+    ascent: f64,
+    height: f64,
+    advance: f64,
+    ends_paragraph: bool,
+}
+
 /*
 #[derive(Debug, Clone, Copy, Live, LiveHook)]
 pub enum Overflow {
@@ -150,6 +231,9 @@ pub struct DrawText {
     
     #[live(1.0)] pub font_scale: f64,
     #[live(1.0)] pub draw_depth: f32,
+    #[live] pub wrap: TextWrap,
+    // stretches the space between words so wrapped lines fill the width
+    #[live] pub justify: bool,
     
     #[calc] pub draw_vars: DrawVars,
     // these values are all generated
@@ -163,6 +247,11 @@ pub struct DrawText {
     #[calc] pub delta: Vec2,
     #[calc] pub font_size: f32,
     #[calc] pub advance: f32,
+    #[calc] pub char_offset: f32,
+    #[calc] pub char_count: f32,
+    #[calc] pub is_color: f32,
+    #[calc] pub is_rtl: f32,
+    #[calc] pub is_underline: f32,
 }
 
 impl DrawText {
@@ -202,8 +291,62 @@ impl DrawText {
         cx.new_draw_call(&self.draw_vars);
     }
     
+    // the text style font followed by its fallback fonts, leaving out the ones that didn't load
+    fn font_ids(&self, fonts_atlas: &CxFontsAtlas) -> Vec<usize> {
+        self.text_style.font.font_id.iter()
+            .chain(self.text_style.fallback_fonts.iter().filter_map( | font | font.font_id.as_ref()))
+            .cloned()
+            .filter( | font_id | fonts_atlas.fonts[*font_id].is_some())
+            .collect()
+    }
+    
+    // shapes the text per font run, with clusters relative to the whole text
+    fn shape_runs(&self, fonts_atlas: &CxFontsAtlas, text: &str) -> Vec<(usize, Vec<ShapedGlyph>)> {
+        let font_ids = self.font_ids(fonts_atlas);
+        fonts_atlas.font_runs(&font_ids, text).into_iter().map( | (font_id, range) | {
+            let mut shaped_glyphs = fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.shape(&text[range.clone()]);
+            for shaped_glyph in &mut shaped_glyphs {
+                shaped_glyph.cluster += range.start;
+            }
+            (font_id, shaped_glyphs)
+        }).collect()
+    }
+    
+    // shapes the text per bidi run from left to right, with the clusters of right-to-left runs
+    // reversed so the glyphs come out in visual order as well, and their brackets mirrored
+    fn shape_visual_runs(&self, fonts_atlas: &CxFontsAtlas, text: &str) -> Vec<(usize, bool, Vec<ShapedGlyph>)> {
+        let mut visual_runs = Vec::new();
+        for run in Paragraph::new(text, None).visual_runs() {
+            let run_text = &text[run.range.clone()];
+            let run_text = if run.is_rtl() {bidi::mirror(run_text)} else {run_text.into()};
+            let mut font_runs = self.shape_runs(fonts_atlas, &run_text);
+            for (_, shaped_glyphs) in &mut font_runs {
+                for shaped_glyph in shaped_glyphs.iter_mut() {
+                    shaped_glyph.cluster += run.range.start;
+                }
+            }
+            if run.is_rtl() {
+                font_runs.reverse();
+                for (_, shaped_glyphs) in &mut font_runs {
+                    // marks stay after their base glyph, since they are offset from its pen position
+                    let mut clusters: Vec<Vec<ShapedGlyph>> = Vec::new();
+                    for shaped_glyph in shaped_glyphs.drain(..) {
+                        match clusters.last_mut() {
+                            Some(cluster) if shaped_glyph.char_count == 0 => cluster.push(shaped_glyph),
+                            _ => clusters.push(vec![shaped_glyph])
+                        }
+                    }
+                    shaped_glyphs.extend(clusters.into_iter().rev().flatten());
+                }
+            }
+            visual_runs.extend(font_runs.into_iter().map( | (font_id, shaped_glyphs) | (font_id, run.is_rtl(), shaped_glyphs)));
+        }
+        visual_runs
+    }
+    
     pub fn update_draw_call_vars(&mut self, font_atlas: &CxFontsAtlas) {
         self.draw_vars.texture_slots[0] = Some(font_atlas.texture_id);
+        self.draw_vars.texture_slots[1] = Some(font_atlas.color_texture_id);
         self.draw_vars.user_uniforms[0] = self.text_style.brightness;
         self.draw_vars.user_uniforms[1] = self.text_style.curve;
     }
@@ -234,6 +377,9 @@ impl DrawText {
         }
         
         let mut walk_x = pos.x;
+        self.is_color = 0.0;
+        self.is_rtl = 0.0;
+        self.is_underline = 0.0;
         
         let cxfont = fonts_atlas.fonts[font_id].as_mut().unwrap();
         let dpi_factor = cx.current_dpi_factor;
@@ -251,10 +397,9 @@ impl DrawText {
         let zbias_step = 0.00001;
         let mut char_depth = self.draw_depth;
         let font_top_drop = self.text_style.font_size * self.text_style.top_drop;
-        for wc in chunk {
+        for (char_offset, wc) in chunk.iter().enumerate() {
             
-            let unicode = *wc as usize;
-            let glyph_id = font.char_code_to_glyph_index_map[unicode];
+            let glyph_id = font.glyph_index(*wc);
             
             let glyph = &font.glyphs[glyph_id];
             
@@ -321,7 +466,9 @@ impl DrawText {
             self.delta.x = delta_x as f32;
             self.delta.y = delta_y as f32;
             self.font_size = self.text_style.font_size as f32;
-            self.advance = advance as f32; //char_offset as f32;
+            self.advance = advance as f32;
+            self.char_offset = char_offset as f32;
+            self.char_count = 1.0;
             char_depth += zbias_step;
             mi.instances.extend_from_slice(self.draw_vars.as_slice());
             walk_x += advance;
@@ -333,6 +480,11 @@ impl DrawText {
     }
     
     pub fn draw_inner(&mut self, cx: &mut Cx2d, pos: DVec2, chunk: &str) {
+        self.draw_chunk(cx, pos, chunk, 0);
+    }
+    
+    // draws text as a single line, with char offsets for hit testing starting at the given one
+    fn draw_chunk(&mut self, cx: &mut Cx2d, pos: DVec2, chunk: &str, char_offset_base: usize) {
         if !self.draw_vars.can_instance()
             || pos.x.is_nan()
             || pos.y.is_nan()
@@ -355,101 +507,77 @@ impl DrawText {
             self.begin_many_instances_internal(cx, fonts_atlas);
         }
        
+        let runs = self.shape_visual_runs(fonts_atlas, chunk);
+        // the char offset of every byte that starts a char, to turn clusters into char offsets
+        let mut char_offsets = vec![0; chunk.len()];
+        for (char_offset, (byte_offset, _)) in chunk.char_indices().enumerate() {
+            char_offsets[byte_offset] = char_offset_base + char_offset;
+        }
+        
         //cx.debug.rect_r(Rect{pos:dvec2(1.0,2.0), size:dvec2(200.0,300.0)});
         let mut walk_x = pos.x;
-        //let mut char_offset = char_offset;
-        
-        let cxfont = fonts_atlas.fonts[font_id].as_mut().unwrap();
         let dpi_factor = cx.current_dpi_factor;
         
-        let atlas_page_id = cxfont.get_atlas_page_id(dpi_factor, self.text_style.font_size);
-        
-        let font = &mut cxfont.ttf_font;
-        
-        let font_size_logical = self.text_style.font_size * 96.0 / (72.0 * font.units_per_em);
-        let font_size_pixels = font_size_logical * dpi_factor;
-        
-        let atlas_page = &mut cxfont.atlas_pages[atlas_page_id];
-        
-        let mi = if let Some(mi) = &mut self.many_instances {mi} else {return};
+        if self.many_instances.is_none() {
+            return
+        }
         let zbias_step = 0.00001;
-        let mut char_depth = self.draw_depth;
-        for wc in chunk.chars() {
-            
-            let unicode = wc as usize;
-            let glyph_id = font.char_code_to_glyph_index_map[unicode];
-            
-            let glyph = &font.glyphs[glyph_id];
-            
-            let advance = glyph.horizontal_metrics.advance_width * font_size_logical * self.font_scale;
-            
-            // snap width/height to pixel granularity
-            let w = ((glyph.bounds.p_max.x - glyph.bounds.p_min.x) * font_size_pixels).ceil() + 1.0;
-            let h = ((glyph.bounds.p_max.y - glyph.bounds.p_min.y) * font_size_pixels).ceil() + 1.0;
+        self.char_depth = self.draw_depth;
+        self.is_underline = 0.0;
+        for (font_id, is_rtl, shaped_glyphs) in runs {
+            let cxfont = fonts_atlas.fonts[font_id].as_mut().unwrap();
             
-            // this one needs pixel snapping
-            let min_pos_x = walk_x + font_size_logical * glyph.bounds.p_min.x;
-            let min_pos_y = pos.y - font_size_logical * glyph.bounds.p_min.y + self.text_style.font_size * self.text_style.top_drop;
-            
-            // compute subpixel shift
-            let subpixel_x_fract = min_pos_x - (min_pos_x * dpi_factor).floor() / dpi_factor;
-            let subpixel_y_fract = min_pos_y - (min_pos_y * dpi_factor).floor() / dpi_factor;
+            let atlas_page_id = cxfont.get_atlas_page_id(dpi_factor, self.text_style.font_size);
             
-            // scale and snap it
-            //let scaled_min_pos_x = walk_x + font_size_logical * self.font_scale * glyph.bounds.p_min.x - subpixel_x_fract;
-            //let scaled_min_pos_y = pos.y - font_size_logical * self.font_scale * glyph.bounds.p_min.y + self.text_style.font_size * self.font_scale * self.text_style.top_drop - subpixel_y_fract;
+            let font_size_logical = self.text_style.font_size * 96.0 / (72.0 * cxfont.ttf_font.units_per_em);
             
-            // only use a subpixel id for small fonts
-            let subpixel_id = if self.text_style.font_size>32.0 {
-                0
-            }
-            else { // subtle 64 index subpixel id
-                ((subpixel_y_fract * 7.0) as usize) << 3 |
-                (subpixel_x_fract * 7.0) as usize
-            };
+            // the size bitmap glyphs end up on screen, to pick a strike
+            let ppem = self.text_style.font_size * 96.0 / 72.0 * self.font_scale * dpi_factor;
             
-            let tc = if let Some(tc) = &atlas_page.atlas_glyphs[glyph_id][subpixel_id] {
-                //println!("{} {} {} {}", tc.tx1,tc.tx2,tc.ty1,tc.ty2);
-                tc
-            }
-            else {
-                // see if we can fit it
-                // allocate slot
-                fonts_atlas.alloc.todo.push(CxFontsAtlasTodo {
-                    subpixel_x_fract,
-                    subpixel_y_fract,
-                    font_id,
-                    atlas_page_id,
-                    glyph_id,
-                    subpixel_id
-                });
+            for shaped_glyph in shaped_glyphs {
                 
-                atlas_page.atlas_glyphs[glyph_id][subpixel_id] = Some(
-                    fonts_atlas.alloc.alloc_atlas_glyph(w, h)
+                let glyph_id = shaped_glyph.glyph_id;
+                
+                let advance = shaped_glyph.x_advance * font_size_logical * self.font_scale;
+                
+                // marks are offset from the pen position to sit on their base glyph
+                let glyph_pos = dvec2(
+                    walk_x + shaped_glyph.x_offset * font_size_logical * self.font_scale,
+                    pos.y - shaped_glyph.y_offset * font_size_logical * self.font_scale
                 );
                 
-                atlas_page.atlas_glyphs[glyph_id][subpixel_id].as_ref().unwrap()
-            };
-            
-            let delta_x = font_size_logical * self.font_scale * glyph.bounds.p_min.x - subpixel_x_fract;
-            let delta_y = -font_size_logical * self.font_scale * glyph.bounds.p_min.y + self.text_style.font_size * self.font_scale * self.text_style.top_drop - subpixel_y_fract;
-            // give the callback a chance to do things
-            //et scaled_min_pos_x = walk_x + delta_x;
-            //let scaled_min_pos_y = pos.y - delta_y;
-            self.font_t1.x = tc.tx1 as f32;
-            self.font_t1.y = tc.ty1 as f32;
-            self.font_t2.x = tc.tx2 as f32;
-            self.font_t2.y = tc.ty2 as f32;
-            self.rect_pos = dvec2(walk_x + delta_x, pos.y + delta_y).into();
-            self.rect_size = dvec2(w * self.font_scale / dpi_factor, h * self.font_scale / dpi_factor).into();
-            self.char_depth = char_depth;
-            self.delta.x = delta_x as f32;
-            self.delta.y = delta_y as f32;
-            self.font_size = self.text_style.font_size as f32;
-            self.advance = advance as f32; //char_offset as f32;
-            char_depth += zbias_step;
-            mi.instances.extend_from_slice(self.draw_vars.as_slice());
-            walk_x += advance;
+                self.font_size = self.text_style.font_size as f32;
+                self.advance = advance as f32;
+                self.char_offset = char_offsets[shaped_glyph.cluster] as f32;
+                self.char_count = shaped_glyph.char_count as f32;
+                self.is_rtl = if is_rtl {1.0} else {0.0};
+                
+                let layers = fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.color_glyphs.layers.get(&glyph_id).cloned();
+                if let Some(bitmap_glyph) = fonts_atlas.get_bitmap_glyph(font_id, glyph_id, ppem) {
+                    self.draw_bitmap_glyph(bitmap_glyph, glyph_pos);
+                }
+                else if let Some(layers) = layers {
+                    // colour glyphs are drawn as a stack of glyphs in their own colours, with
+                    // only the last one hit tested so the char spans still add up
+                    let color = self.color;
+                    let char_count = self.char_count;
+                    for (index, layer) in layers.iter().enumerate() {
+                        self.color = match layer.color {
+                            Some([r, g, b, a]) => vec4(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0 * color.w),
+                            None => color
+                        };
+                        self.char_count = if index + 1 == layers.len() {char_count} else {0.0};
+                        self.draw_outline_glyph(fonts_atlas, font_id, atlas_page_id, layer.glyph_id, glyph_pos, dpi_factor);
+                        self.char_depth += zbias_step;
+                    }
+                    self.color = color;
+                }
+                else {
+                    self.draw_outline_glyph(fonts_atlas, font_id, atlas_page_id, glyph_id, glyph_pos, dpi_factor);
+                }
+                self.char_depth += zbias_step;
+                walk_x += advance;
+            }
         }
         
         if !in_many {
@@ -457,6 +585,97 @@ impl DrawText {
         }
     }
     
+    // draws a glyph from the coverage atlas, adding it to the atlas the first time
+    fn draw_outline_glyph(&mut self, fonts_atlas: &mut CxFontsAtlas, font_id: usize, atlas_page_id: usize, glyph_id: usize, glyph_pos: DVec2, dpi_factor: f64) {
+        let cxfont = fonts_atlas.fonts[font_id].as_mut().unwrap();
+        let font = &cxfont.ttf_font;
+        let glyph = &font.glyphs[glyph_id];
+        
+        let font_size_logical = self.text_style.font_size * 96.0 / (72.0 * font.units_per_em);
+        let font_size_pixels = font_size_logical * dpi_factor;
+        
+        let atlas_page = &mut cxfont.atlas_pages[atlas_page_id];
+        
+        // snap width/height to pixel granularity
+        let w = ((glyph.bounds.p_max.x - glyph.bounds.p_min.x) * font_size_pixels).ceil() + 1.0;
+        let h = ((glyph.bounds.p_max.y - glyph.bounds.p_min.y) * font_size_pixels).ceil() + 1.0;
+        
+        // this one needs pixel snapping
+        let min_pos_x = glyph_pos.x + font_size_logical * glyph.bounds.p_min.x;
+        let min_pos_y = glyph_pos.y - font_size_logical * glyph.bounds.p_min.y + self.text_style.font_size * self.text_style.top_drop;
+        
+        // compute subpixel shift
+        let subpixel_x_fract = min_pos_x - (min_pos_x * dpi_factor).floor() / dpi_factor;
+        let subpixel_y_fract = min_pos_y - (min_pos_y * dpi_factor).floor() / dpi_factor;
+        
+        // only use a subpixel id for small fonts
+        let subpixel_id = if self.text_style.font_size>32.0 {
+            0
+        }
+        else { // subtle 64 index subpixel id
+            ((subpixel_y_fract * 7.0) as usize) << 3 |
+            (subpixel_x_fract * 7.0) as usize
+        };
+        
+        let tc = if let Some(tc) = &atlas_page.atlas_glyphs[glyph_id][subpixel_id] {
+            tc
+        }
+        else {
+            // see if we can fit it
+            // allocate slot
+            fonts_atlas.alloc.todo.push(CxFontsAtlasTodo {
+                subpixel_x_fract,
+                subpixel_y_fract,
+                font_id,
+                atlas_page_id,
+                glyph_id,
+                subpixel_id
+            });
+            
+            atlas_page.atlas_glyphs[glyph_id][subpixel_id] = Some(
+                fonts_atlas.alloc.alloc_atlas_glyph(w, h)
+            );
+            
+            atlas_page.atlas_glyphs[glyph_id][subpixel_id].as_ref().unwrap()
+        };
+        
+        let delta_x = font_size_logical * self.font_scale * glyph.bounds.p_min.x - subpixel_x_fract;
+        let delta_y = -font_size_logical * self.font_scale * glyph.bounds.p_min.y + self.text_style.font_size * self.font_scale * self.text_style.top_drop - subpixel_y_fract;
+        self.font_t1.x = tc.tx1 as f32;
+        self.font_t1.y = tc.ty1 as f32;
+        self.font_t2.x = tc.tx2 as f32;
+        self.font_t2.y = tc.ty2 as f32;
+        self.rect_pos = dvec2(glyph_pos.x + delta_x, glyph_pos.y + delta_y).into();
+        self.rect_size = dvec2(w * self.font_scale / dpi_factor, h * self.font_scale / dpi_factor).into();
+        self.delta.x = delta_x as f32;
+        self.delta.y = delta_y as f32;
+        self.is_color = 0.0;
+        if let Some(mi) = &mut self.many_instances {
+            mi.instances.extend_from_slice(self.draw_vars.as_slice());
+        }
+    }
+    
+    // draws a glyph from the colour atlas, scaled from the size of its strike
+    fn draw_bitmap_glyph(&mut self, bitmap_glyph: CxFontAtlasBitmapGlyph, glyph_pos: DVec2) {
+        let scale = self.text_style.font_size * 96.0 / 72.0 * self.font_scale / bitmap_glyph.strike_ppem;
+        let delta_x = bitmap_glyph.left * scale;
+        let delta_y = -bitmap_glyph.bottom * scale + self.text_style.font_size * self.font_scale * self.text_style.top_drop;
+        let tc = bitmap_glyph.tc;
+        // images are stored top row first, unlike the rendered coverage atlas
+        self.font_t1.x = tc.tx1 as f32;
+        self.font_t1.y = tc.ty2 as f32;
+        self.font_t2.x = tc.tx2 as f32;
+        self.font_t2.y = tc.ty1 as f32;
+        self.rect_pos = dvec2(glyph_pos.x + delta_x, glyph_pos.y + delta_y).into();
+        self.rect_size = dvec2(bitmap_glyph.width * scale, bitmap_glyph.height * scale).into();
+        self.delta.x = delta_x as f32;
+        self.delta.y = delta_y as f32;
+        self.is_color = 1.0;
+        if let Some(mi) = &mut self.many_instances {
+            mi.instances.extend_from_slice(self.draw_vars.as_slice());
+        }
+    }
+    
     pub fn compute_geom(&self, cx: &Cx2d, walk: Walk, text: &str) -> Option<TextGeom> {
         // we include the align factor and the width/height
         let font_id = self.text_style.font.font_id.unwrap();
@@ -472,15 +691,23 @@ impl DrawText {
         let eval_width = cx.turtle().eval_width(walk.width, walk.margin, Flow::Right);
         let eval_height = cx.turtle().eval_height(walk.height, walk.margin, Flow::Right);
         
+        let font = &fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font;
+        
+        // every glyph with its advance, the fallback fonts can have other units per em
+        let mut glyphs = Vec::new();
+        for (font_id, shaped_glyphs) in self.shape_runs(&fonts_atlas, text) {
+            let font_size_logical = self.text_style.font_size * 96.0 / (72.0 * fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.units_per_em);
+            glyphs.extend(shaped_glyphs.into_iter().map( | shaped_glyph | {
+                (shaped_glyph, shaped_glyph.x_advance * font_size_logical * self.font_scale)
+            }));
+        }
+        
         // if we have a fit width, we simply fit
         // if we have a fixed width, we can apply align + ellipsis
         if walk.width.is_fit() {
             let mut measured_width = 0.0;
-            for c in text.chars() {
-                if let Some(glyph) = fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.get_glyph(c) {
-                    let adv = glyph.horizontal_metrics.advance_width * font_size_logical * self.font_scale;
-                    measured_width += adv;
-                }
+            for (_, adv) in &glyphs {
+                measured_width += adv;
             }
             Some(TextGeom {
                 eval_width,
@@ -492,7 +719,7 @@ impl DrawText {
         }
         else {
             
-            let ellip_width = if let Some(glyph) = fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.get_glyph('.') {
+            let ellip_width = if let Some(glyph) = font.get_glyph('.') {
                 glyph.horizontal_metrics.advance_width * font_size_logical * self.font_scale
             }
             else {
@@ -501,33 +728,30 @@ impl DrawText {
             
             let mut measured_width = 0.0;
             let mut ellip_pt = None;
-            for (i, c) in text.chars().enumerate() {
-                
-                if measured_width + ellip_width * 3.0 < eval_width {
-                    ellip_pt = Some((i, measured_width, 3));
+            for (shaped_glyph, adv) in glyphs {
+                // the ellipsis can only go between clusters, not between a mark and its base
+                if shaped_glyph.char_count > 0 && measured_width + ellip_width * 3.0 < eval_width {
+                    ellip_pt = Some((shaped_glyph.cluster, measured_width, 3));
                 }
-                if let Some(glyph) = fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.get_glyph(c) {
-                    let adv = glyph.horizontal_metrics.advance_width * font_size_logical * self.font_scale;
-                    // ok so now what.
-                    if measured_width + adv >= eval_width { // we have to drop back to ellip_pt
-                        // if we don't have an ellip_pt, set it to 0
-                        if ellip_pt.is_none() {
-                            let dots = if ellip_width * 3.0 < eval_width {3}
-                            else if ellip_width * 2.0 < eval_width {2}
-                            else if ellip_width < eval_width {1}
-                            else {0};
-                            ellip_pt = Some((0, 0.0, dots));
-                        }
-                        return Some(TextGeom {
-                            eval_width,
-                            eval_height,
-                            measured_width: ellip_pt.unwrap().1 + ellip_width,
-                            measured_height,
-                            ellip_pt
-                        })
+                // ok so now what.
+                if measured_width + adv >= eval_width { // we have to drop back to ellip_pt
+                    // if we don't have an ellip_pt, set it to 0
+                    if ellip_pt.is_none() {
+                        let dots = if ellip_width * 3.0 < eval_width {3}
+                        else if ellip_width * 2.0 < eval_width {2}
+                        else if ellip_width < eval_width {1}
+                        else {0};
+                        ellip_pt = Some((0, 0.0, dots));
                     }
-                    measured_width += adv;
+                    return Some(TextGeom {
+                        eval_width,
+                        eval_height,
+                        measured_width: ellip_pt.unwrap().1 + ellip_width,
+                        measured_height,
+                        ellip_pt
+                    })
                 }
+                measured_width += adv;
             }
             
             Some(TextGeom {
@@ -547,6 +771,9 @@ impl DrawText {
         if text.len() == 0 {
             return
         }
+        if self.wrap == TextWrap::Word {
+            return self.draw_spans_walk(cx, walk, align, &[TextSpan::new(text)])
+        }
         if let Some(geom) = self.compute_geom(cx, walk, text) {
             let height = if walk.height.is_fit() {
                 geom.measured_height
@@ -558,10 +785,9 @@ impl DrawText {
             if walk.width.is_fit() {
                 // lets just output it and walk it
                 let rect = cx.walk_turtle(Walk {
-                    abs_pos: walk.abs_pos,
-                    margin: walk.margin,
                     width: Size::Fixed(geom.measured_width),
-                    height: Size::Fixed(height)
+                    height: Size::Fixed(height),
+                    ..walk
                 });
                 // lets do our y alignment
                 self.draw_inner(cx, rect.pos + dvec2(0.0, y_align), text);
@@ -571,10 +797,9 @@ impl DrawText {
                 if let Some((ellip, at_x, dots)) = geom.ellip_pt {
                     // ok so how do we draw this
                     let rect = cx.walk_turtle(Walk {
-                        abs_pos: walk.abs_pos,
-                        margin: walk.margin,
                         width: Size::Fixed(geom.eval_width),
-                        height: Size::Fixed(height)
+                        height: Size::Fixed(height),
+                        ..walk
                     });
                     
                     self.draw_inner(cx, rect.pos + dvec2(0.0, y_align), &text[0..ellip]);
@@ -582,8 +807,6 @@ impl DrawText {
                 }
                 else { // we might have space to h-align
                     let rect = cx.walk_turtle(Walk {
-                        abs_pos: walk.abs_pos,
-                        margin: walk.margin,
                         width: Size::Fixed(geom.eval_width),
                         height: Size::Fixed(
                             if walk.height.is_fit() {
@@ -591,7 +814,8 @@ impl DrawText {
                             } else {
                                 geom.eval_height
                             }
-                        )
+                        ),
+                        ..walk
                     });
                     let x_align = (geom.eval_width - geom.measured_width) * align.x;
                     self.draw_inner(cx, rect.pos + dvec2(x_align, y_align), text);
@@ -600,76 +824,371 @@ impl DrawText {
         }
     }
     
-    // looks up text with the behavior of a text selection mouse cursor
-    pub fn closest_offset(&self, cx: &Cx, pos: DVec2) -> Option<usize> {
-        let area = &self.draw_vars.area;
-        
-        if !area.is_valid(cx) {
-            return None
+    // draws text that can have several styles, wrapped into lines that fit the width of the walk
+    // unless it is fit. align.x aligns each line, and align.y the text as a whole
+    pub fn draw_spans_walk(&mut self, cx: &mut Cx2d, walk: Walk, align: Align, spans: &[TextSpan]) {
+        if !self.draw_vars.can_instance() || self.text_style.font.font_id.is_none() {
+            return
         }
-        //let debug = cx.debug.clone();
-        //let scroll_pos = area.get_scroll_pos(cx);
-        //let pos = Vec2 {x: pos.x + scroll_pos.x, y: pos.y + scroll_pos.y};
-        
-        let rect_pos = area.get_read_ref(cx, live_id!(rect_pos), ShaderTy::Vec2).unwrap();
-        let delta = area.get_read_ref(cx, live_id!(delta), ShaderTy::Vec2).unwrap();
-        let advance = area.get_read_ref(cx, live_id!(advance), ShaderTy::Float).unwrap();
-        //let font_size = area.get_read_ref(cx, live_id!(font_size), ShaderTy::Float).unwrap();
+        let eval_width = cx.turtle().eval_width(walk.width, walk.margin, Flow::Right);
+        let eval_height = cx.turtle().eval_height(walk.height, walk.margin, Flow::Right);
+        let max_width = if walk.width.is_fit() {f64::INFINITY} else {eval_width};
+        let lines = self.layout_spans(cx, spans, max_width);
         
-        //let line_spacing = self.text_style.line_spacing;
+        let measured_width = lines.iter().map( | line | line.width).fold(0.0, f64::max);
+        let measured_height = match lines.split_last() {
+            Some((last, lines)) => lines.iter().map( | line | line.advance).sum::<f64>() + last.height,
+            None => 0.0
+        };
+        let rect = cx.walk_turtle(Walk {
+            width: Size::Fixed(if walk.width.is_fit() {measured_width} else {eval_width}),
+            height: Size::Fixed(if walk.height.is_fit() {measured_height} else {eval_height}),
+            ..walk
+        });
         
-        // TODO add multiline support
-        for i in 0..rect_pos.repeat {
-            //let index = rect_pos.stride * i;
-            //let fs = font_size.buffer[index];
-            let index = rect_pos.stride * i;
-            let x = rect_pos.buffer[index + 0] as f64 - delta.buffer[index + 0] as f64;
-            //let y = rect_pos.buffer[index + 1] - delta.buffer[index + 1];
-            let advance = advance.buffer[index + 0] as f64;
-            if pos.x < x + advance * 0.5 {
-                return Some(i)
+        let in_many = self.many_instances.is_some();
+        if !in_many {
+            self.begin_many_instances(cx);
+        }
+        let text_style = self.text_style.clone();
+        let color = self.color;
+        let mut y = rect.pos.y + (rect.size.y - measured_height) * align.y;
+        for line in lines {
+            let extra_width = rect.size.x - line.width;
+            let word_count = line.fragments.iter().filter( | fragment | fragment.ends_word).count();
+            let (mut x, word_spacing) = if self.justify && !line.ends_paragraph && word_count > 1 && extra_width > 0.0 {
+                (rect.pos.x, extra_width / (word_count - 1) as f64)
             }
+            else {
+                (rect.pos.x + extra_width * align.x, 0.0)
+            };
+            let levels: Vec<u8> = line.fragments.iter().map( | fragment | fragment.level).collect();
+            let order = bidi::reorder(&levels);
+            for (visual_index, index) in order.iter().cloned().enumerate() {
+                let fragment = &line.fragments[index];
+                let span = &spans[fragment.span];
+                self.text_style = span.text_style.clone().unwrap_or_else( || text_style.clone());
+                self.color = span.color.unwrap_or(color);
+                let text = span.text[fragment.range.clone()].trim_end_matches(['\n', '\r']);
+                self.draw_chunk(cx, dvec2(x, y + line.ascent - fragment.ascent), text, fragment.char_offset);
+                if span.underline {
+                    // whitespace hanging over the end of the line isn't underlined
+                    let width = if index + 1 == line.fragments.len() {fragment.width - fragment.trailing_width} else {fragment.width};
+                    self.draw_underline(cx, dvec2(x, y + line.ascent), width);
+                }
+                x += fragment.width;
+                if fragment.ends_word && visual_index + 1 < order.len() {
+                    x += word_spacing;
+                }
+            }
+            y += line.advance;
+        }
+        self.text_style = text_style;
+        self.color = color;
+        if !in_many {
+            self.end_many_instances(cx);
         }
-        return Some(rect_pos.repeat);
     }
     
-    pub fn get_char_count(&self, cx: &Cx) -> usize {
-        let area = &self.draw_vars.area;
-        if !area.is_valid(cx) {
-            return 0
+    // the lines that draw_spans_walk breaks the spans into for the same walk. text without
+    // any lines has one empty line, so that a text cursor can go on it
+    pub fn layout_lines_walk(&mut self, cx: &Cx2d, walk: Walk, spans: &[TextSpan]) -> Vec<TextLayoutLine> {
+        if self.text_style.font.font_id.is_none() {
+            return Vec::new()
         }
-        let rect_pos = area.get_read_ref(cx, live_id!(rect_pos), ShaderTy::Vec2).unwrap();
-        rect_pos.repeat
+        let eval_width = cx.turtle().eval_width(walk.width, walk.margin, Flow::Right);
+        let max_width = if walk.width.is_fit() {f64::INFINITY} else {eval_width};
+        let mut lines = Vec::new();
+        let mut y = 0.0;
+        let mut char_pos = 0;
+        for line in self.layout_spans(cx, spans, max_width) {
+            let start = line.fragments.first().map( | fragment | fragment.char_offset).unwrap_or(char_pos);
+            let mut end = start;
+            for fragment in &line.fragments {
+                let text = &spans[fragment.span].text[fragment.range.clone()];
+                end = fragment.char_offset + text.trim_end_matches(['\n', '\r']).chars().count();
+                char_pos = fragment.char_offset + text.chars().count();
+            }
+            lines.push(TextLayoutLine {start, end, ends_paragraph: line.ends_paragraph, y, height: line.height});
+            y += line.advance;
+        }
+        if lines.is_empty() {
+            let font_size = self.text_style.font_size * self.font_scale;
+            lines.push(TextLayoutLine {start: 0, end: 0, ends_paragraph: true, y: 0.0, height: font_size * self.text_style.height_factor});
+        }
+        lines
     }
     
-    pub fn get_cursor_pos(&self, cx: &Cx, pos: f32, index: usize) -> Option<DVec2> {
+    // draws a line under text, from the baseline at the given position
+    fn draw_underline(&mut self, cx: &mut Cx2d, baseline: DVec2, width: f64) {
+        let font_size = self.text_style.font_size * self.font_scale;
+        let thickness = (font_size * 0.06).max(1.0 / cx.current_dpi_factor);
+        let mi = if let Some(mi) = &mut self.many_instances {mi} else {return};
+        self.draw_clip = cx.turtle().draw_clip().into();
+        self.font_t1 = Vec2::default();
+        self.font_t2 = Vec2::default();
+        self.rect_pos = dvec2(baseline.x, baseline.y + font_size * 0.1 + thickness).into();
+        self.rect_size = dvec2(width, thickness).into();
+        self.delta = Vec2::default();
+        self.advance = 0.0;
+        self.char_count = 0.0;
+        self.is_color = 0.0;
+        self.is_rtl = 0.0;
+        self.is_underline = 1.0;
+        mi.instances.extend_from_slice(self.draw_vars.as_slice());
+        self.is_underline = 0.0;
+    }
+    
+    // the width of text in the current text style
+    fn measure_width(&self, fonts_atlas: &CxFontsAtlas, text: &str) -> f64 {
+        let mut width = 0.0;
+        for (font_id, shaped_glyphs) in self.shape_runs(fonts_atlas, text) {
+            let font_size_logical = self.text_style.font_size * 96.0 / (72.0 * fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font.units_per_em);
+            for shaped_glyph in shaped_glyphs {
+                width += shaped_glyph.x_advance * font_size_logical * self.font_scale;
+            }
+        }
+        width
+    }
+    
+    // breaks the spans into lines no wider than the given width, except for words that don't fit
+    // on a line by themselves. newlines always end a line
+    fn layout_spans(&mut self, cx: &Cx2d, spans: &[TextSpan], max_width: f64) -> Vec<TextLine> {
+        let text: String = spans.iter().map( | span | span.text).collect();
+        let line_breaks: Vec<usize> = text.line_breaks().collect();
+        let levels = Paragraph::new(&text, None).levels().to_vec();
+        
+        // fragments end where a line can be wrapped, and where a span ends
+        let mut ends = line_breaks.clone();
+        let mut span_start = 0;
+        let mut span_starts = Vec::new();
+        for span in spans {
+            span_starts.push(span_start);
+            span_start += span.text.len();
+            ends.push(span_start);
+        }
+        ends.sort_unstable();
+        ends.dedup();
+        
+        let fonts_atlas_rc = cx.fonts_atlas_rc.clone();
+        let fonts_atlas = fonts_atlas_rc.0.borrow();
+        let text_style = self.text_style.clone();
+        let metrics = | text_style: &TextStyle, font_scale: f64 | (
+            text_style.font_size * text_style.top_drop * font_scale,
+            text_style.font_size * text_style.height_factor * font_scale,
+            text_style.font_size * text_style.line_spacing * font_scale
+        );
+        
+        let mut fragments = Vec::new();
+        let mut start = 0;
+        let mut char_offset = 0;
+        for end in ends {
+            if end == start {
+                continue
+            }
+            let span = span_starts.iter().rposition( | span_start | *span_start <= start).unwrap();
+            self.text_style = spans[span].text_style.clone().unwrap_or_else( || text_style.clone());
+            let fragment_text = &text[start..end];
+            let trimmed_text = fragment_text.trim_end_matches(['\n', '\r']);
+            let width = self.measure_width(&fonts_atlas, trimmed_text);
+            let trailing_width = width - self.measure_width(&fonts_atlas, trimmed_text.trim_end());
+            fragments.push(TextFragment {
+                span,
+                range: start - span_starts[span]..end - span_starts[span],
+                char_offset,
+                width,
+                trailing_width,
+                ascent: metrics(&self.text_style, self.font_scale).0,
+                ends_word: end == text.len() || line_breaks.binary_search(&end).is_ok(),
+                ends_paragraph: trimmed_text.len() != fragment_text.len(),
+                level: levels.get(char_offset).cloned().unwrap_or(0),
+            });
+            char_offset += fragment_text.chars().count();
+            start = end;
+        }
+        self.text_style = text_style;
+        
+        let new_line = | fragments: Vec<TextFragment>, ends_paragraph: bool | {
+            let mut line = TextLine {
+                width: 0.0,
+                ascent: 0.0,
+                height: 0.0,
+                advance: 0.0,
+                ends_paragraph,
+                fragments: Vec::new()
+            };
+            for fragment in &fragments {
+                let span_style = spans[fragment.span].text_style.as_ref().unwrap_or(&self.text_style);
+                let (ascent, height, advance) = metrics(span_style, self.font_scale);
+                line.ascent = line.ascent.max(ascent);
+                line.height = line.height.max(height);
+                line.advance = line.advance.max(advance);
+                line.width += fragment.width;
+            }
+            if let Some(fragment) = fragments.last() {
+                line.width -= fragment.trailing_width;
+            }
+            else {
+                // an empty line after a newline still takes up space
+                let (ascent, height, advance) = metrics(&self.text_style, self.font_scale);
+                line.ascent = ascent;
+                line.height = height;
+                line.advance = advance;
+            }
+            line.fragments = fragments;
+            line
+        };
+        
+        let mut lines = Vec::new();
+        let mut line_fragments: Vec<TextFragment> = Vec::new();
+        let mut word: Vec<TextFragment> = Vec::new();
+        let mut line_width = 0.0;
+        for fragment in fragments {
+            let ends_word = fragment.ends_word;
+            word.push(fragment);
+            if !ends_word {
+                continue
+            }
+            let word_width: f64 = word.iter().map( | fragment | fragment.width).sum();
+            let trailing_width = word.last().unwrap().trailing_width;
+            if !line_fragments.is_empty() && line_width + word_width - trailing_width > max_width {
+                lines.push(new_line(std::mem::take(&mut line_fragments), false));
+                line_width = 0.0;
+            }
+            line_width += word_width;
+            let ends_paragraph = word.last().unwrap().ends_paragraph;
+            line_fragments.append(&mut word);
+            if ends_paragraph {
+                lines.push(new_line(std::mem::take(&mut line_fragments), true));
+                line_width = 0.0;
+            }
+        }
+        line_fragments.append(&mut word);
+        if !line_fragments.is_empty() || lines.last().is_some_and( | line: &TextLine | line.ends_paragraph) {
+            lines.push(new_line(line_fragments, true));
+        }
+        lines
+    }
+    
+    // the glyphs that can be hit tested from left to right, as their position, advance, char
+    // offset, char count and direction. marks are hit tested with their base glyph
+    fn hit_test_glyphs(&self, cx: &Cx) -> Vec<HitTestGlyph> {
         let area = &self.draw_vars.area;
         
         if !area.is_valid(cx) {
-            return None
+            return Vec::new()
         }
         
         let rect_pos = area.get_read_ref(cx, live_id!(rect_pos), ShaderTy::Vec2).unwrap();
         let delta = area.get_read_ref(cx, live_id!(delta), ShaderTy::Vec2).unwrap();
         let advance = area.get_read_ref(cx, live_id!(advance), ShaderTy::Float).unwrap();
+        let char_offset = area.get_read_ref(cx, live_id!(char_offset), ShaderTy::Float).unwrap();
+        let char_count = area.get_read_ref(cx, live_id!(char_count), ShaderTy::Float).unwrap();
+        let is_rtl = area.get_read_ref(cx, live_id!(is_rtl), ShaderTy::Float).unwrap();
         
-        if rect_pos.repeat == 0 {
+        let mut glyphs = Vec::new();
+        for i in 0..rect_pos.repeat {
+            let index = rect_pos.stride * i;
+            let count = char_count.buffer[index] as usize;
+            if count == 0 {
+                continue
+            }
+            let pos = dvec2(
+                rect_pos.buffer[index] as f64 - delta.buffer[index] as f64,
+                rect_pos.buffer[index + 1] as f64 - delta.buffer[index + 1] as f64
+            );
+            glyphs.push((pos, advance.buffer[index] as f64, char_offset.buffer[index] as usize, count, is_rtl.buffer[index] > 0.5));
+        }
+        glyphs
+    }
+    
+    // looks up text with the behavior of a text selection mouse cursor
+    pub fn closest_offset(&self, cx: &Cx, pos: DVec2) -> Option<usize> {
+        if !self.draw_vars.area.is_valid(cx) {
             return None
         }
         
-        if index >= rect_pos.repeat {
-            // lets get the last one and advance
-            let index = (rect_pos.repeat - 1) * rect_pos.stride;
-            let x = rect_pos.buffer[index + 0] - delta.buffer[index + 0] + advance.buffer[index + 0];
-            let y = rect_pos.buffer[index + 1] - delta.buffer[index + 1];
-            Some(dvec2(x as f64, y as f64))
+        // glyphs go from left to right, so a glyph left of the one before it starts a new line
+        let mut lines: Vec<Vec<HitTestGlyph>> = Vec::new();
+        for glyph in self.hit_test_glyphs(cx) {
+            match lines.last_mut() {
+                Some(line) if line.last().is_some_and( | last | glyph.0.x >= last.0.x + last.1 - 0.001) => line.push(glyph),
+                _ => lines.push(vec![glyph])
+            }
         }
-        else {
-            let index = index * rect_pos.stride;
-            let x = rect_pos.buffer[index + 0] - delta.buffer[index + 0] + advance.buffer[index + 0] * pos;
-            let y = rect_pos.buffer[index + 1] - delta.buffer[index + 1];
-            Some(dvec2(x as f64, y as f64))
+        // the last line that starts above the position
+        let line = lines.iter().rposition( | line | {
+            line.iter().map( | glyph | glyph.0.y).fold(f64::INFINITY, f64::min) <= pos.y
+        }).unwrap_or(0);
+        
+        let mut end = 0;
+        for (glyph_pos, advance, offset, count, is_rtl) in lines.into_iter().nth(line).unwrap_or_default() {
+            // a ligature is split evenly between the chars it was made from
+            let char_advance = advance / count as f64;
+            for c in 0..count {
+                // the offsets on the left and right side of the char, which are the other way
+                // around in right-to-left text
+                let (left, right) = if is_rtl {(offset + count - c, offset + count - c - 1)} else {(offset + c, offset + c + 1)};
+                let x = glyph_pos.x + char_advance * c as f64;
+                if pos.x < x + char_advance * 0.5 {
+                    return Some(left)
+                }
+                if pos.x < x + char_advance {
+                    return Some(right)
+                }
+            }
+            end = if is_rtl {offset} else {offset + count};
+        }
+        Some(end)
+    }
+    
+    pub fn get_char_count(&self, cx: &Cx) -> usize {
+        self.hit_test_glyphs(cx).into_iter().map( | (_, _, offset, count, _) | offset + count).max().unwrap_or(0)
+    }
+    
+    pub fn get_cursor_pos(&self, cx: &Cx, pos: f32, index: usize) -> Option<DVec2> {
+        let glyphs = self.hit_test_glyphs(cx);
+        
+        // the position at the given fraction into a char, from its leading side
+        let char_pos = | index: usize, pos: f64 | {
+            glyphs.iter().find( | (_, _, offset, count, _) | index >= *offset && index < offset + count).map( | (glyph_pos, advance, offset, count, is_rtl) | {
+                let char_advance = advance / *count as f64;
+                let c = (index - offset) as f64;
+                let x = if *is_rtl {
+                    glyph_pos.x + char_advance * (*count as f64 - c - pos)
+                }
+                else {
+                    glyph_pos.x + char_advance * (c + pos)
+                };
+                dvec2(x, glyph_pos.y)
+            })
+        };
+        
+        // past the end, put it on the trailing side of the last char
+        char_pos(index, pos as f64)
+            .or_else( || index.checked_sub(1).and_then( | index | char_pos(index, pos as f64 + 1.0)))
+            .or_else( || glyphs.last().map( | (glyph_pos, advance, ..) | dvec2(glyph_pos.x + advance, glyph_pos.y)))
+    }
+    
+    // the horizontal spans covered by the chars in the given range, from left to right. in
+    // bidirectional text a range of chars can be split over several places
+    pub fn get_selection_spans(&self, cx: &Cx, start: usize, end: usize) -> Vec<(f64, f64)> {
+        let mut spans: Vec<(f64, f64)> = Vec::new();
+        for (glyph_pos, advance, offset, count, is_rtl) in self.hit_test_glyphs(cx) {
+            let char_advance = advance / count as f64;
+            for c in 0..count {
+                let index = if is_rtl {offset + count - 1 - c} else {offset + c};
+                if index < start || index >= end {
+                    continue
+                }
+                let x = glyph_pos.x + char_advance * c as f64;
+                match spans.last_mut() {
+                    Some(span) if (span.1 - x).abs() < 0.001 => span.1 = x + char_advance,
+                    _ => spans.push((x, x + char_advance))
+                }
+            }
         }
+        spans
     }
     
     pub fn get_monospace_base(&self, cx: &Cx2d) -> DVec2 {
@@ -682,7 +1201,7 @@ impl DrawText {
             return DVec2::default();
         }
         let font = &fonts_atlas.fonts[font_id].as_ref().unwrap().ttf_font;
-        let slot = font.char_code_to_glyph_index_map[33];
+        let slot = font.glyph_index('!');
         let glyph = &font.glyphs[slot];
         
         //let font_size = if let Some(font_size) = font_size{font_size}else{self.font_size};
@@ -1,4 +1,5 @@
 pub mod draw_color;
+pub mod draw_image;
 pub mod draw_quad;
 pub mod draw_shape;
 pub mod draw_text;
@@ -5,6 +5,9 @@ use {
     }
 };
 
+// how far a walk can stick out of a wrapping line because of rounding
+const WRAP_EPSILON: f64 = 0.001;
+
 #[derive(Copy, Clone, Debug, Live, LiveHook)]
 #[live_ignore]
 pub struct Layout {
@@ -38,6 +41,41 @@ pub struct Walk {
     pub margin: Margin,
     pub width: Size,
     pub height: Size,
+    pub min_width: Option<f64>,
+    pub max_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_height: Option<f64>,
+    // width divided by height. a size that is not known follows from the other one, and when
+    // both are known the walk shrinks to keep the ratio
+    pub aspect_ratio: Option<f64>,
+    pub cell: GridCell,
+}
+
+// where a walk goes in a grid, without a column or row it takes the next free cell, a span of 0 counts as 1
+#[derive(Copy, Clone, Default, Debug, Live, LiveHook)]
+#[live_ignore]
+pub struct GridCell {
+    pub column: Option<usize>,
+    pub row: Option<usize>,
+    pub column_span: usize,
+    pub row_span: usize,
+}
+
+// the tracks of a grid turtle, missing columns are a single Fill column and rows past the end are Fit
+#[derive(Clone, Default, Debug, Live, LiveHook)]
+#[live_ignore]
+pub struct GridLayout {
+    pub columns: Vec<GridTrack>,
+    pub rows: Vec<GridTrack>,
+}
+
+#[derive(Copy, Clone, Debug, Live, LiveHook)]
+#[live_ignore]
+pub enum GridTrack {
+    #[pick] Fill,
+    #[live(100.0)] Fixed(f64),
+    Fit,
+    #[live(1.0)] Fraction(f64),
 }
 
 #[derive(Clone, Copy, Default, Debug, Live, LiveHook)]
@@ -56,7 +94,7 @@ pub struct Padding {
     pub bottom: f64
 }
 
-#[derive(Copy, Clone, Debug, Live, LiveHook)]
+#[derive(Copy, Clone, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub enum Axis {
     #[pick] Horizontal,
@@ -74,13 +112,17 @@ impl Default for Axis {
 pub enum Flow {
     #[pick] Right,
     Down,
-    Overlay
+    Overlay,
+    RightWrap,
+    DownWrap,
+    Grid
 }
 
+// fill walks share the space that is left by their weight, a bare Fill weighs 1
 #[derive(Copy, Clone, Debug, Live)]
 #[live_ignore]
 pub enum Size {
-    #[pick] Fill,
+    #[pick(1.0)] Fill(f64),
     #[live(200.0)] Fixed(f64),
     Fit,
 }
@@ -88,8 +130,7 @@ pub enum Size {
 #[derive(Clone, Default, Debug)]
 pub struct DeferWalk {
     defer_index: usize,
-    margin: Margin,
-    other_axis: Size,
+    walk: Walk,
     pos: DVec2
 }
 
@@ -106,7 +147,7 @@ pub struct Turtle {
     layout: Layout,
     align_start: usize,
     turtle_walks_start: usize,
-    defer_count: usize,
+    deferred: Vec<Walk>,
     shift: Option<DVec2>,
     pos: DVec2,
     origin: DVec2,
@@ -115,7 +156,37 @@ pub struct Turtle {
     width_used: f64,
     height_used: f64,
     draw_clip: (DVec2, DVec2),
-    guard_area: Area
+    guard_area: Area,
+    wrap_start: usize,
+    wrap_items: usize,
+    wrap_line_size: f64,
+    grid: Option<GridState>,
+}
+
+#[derive(Clone, Default, Debug)]
+struct GridItem {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+    // the turtle walk of the item, deferred items have none
+    walk_index: Option<usize>,
+    // the cell position the item was drawn at and its size with margins
+    pos: DVec2,
+    size: DVec2,
+}
+
+#[derive(Clone, Default, Debug)]
+struct GridState {
+    columns: Vec<GridTrack>,
+    rows: Vec<GridTrack>,
+    column_fit: Vec<f64>,
+    row_fit: Vec<f64>,
+    items: Vec<GridItem>,
+    // the next cell to auto place in, counted row by row
+    cursor: usize,
+    // the item of a child turtle that has begun but not ended yet
+    pending: Option<usize>,
 }
 
 impl<'a> Cx2d<'a> {
@@ -131,14 +202,19 @@ impl<'a> Cx2d<'a> {
         self.begin_turtle_with_guard(walk, layout, Area::Empty)
     }
     
+    pub fn eval_width(&self, walk: &Walk) -> f64 {
+        self.turtle().eval_walk(walk).x
+    }
+    
+    pub fn eval_height(&self, walk: &Walk) -> f64 {
+        self.turtle().eval_walk(walk).y
+    }
+    
     pub fn defer_walk(&mut self, walk: Walk) -> Option<DeferWalk> {
         let turtle = self.turtles.last_mut().unwrap();
-        let defer_index = turtle.defer_count;
+        let defer_index = turtle.deferred.len();
         let pos = turtle.pos;
-        let size = dvec2(
-            turtle.eval_width(walk.width, walk.margin, turtle.layout.flow),
-            turtle.eval_height(walk.height, walk.margin, turtle.layout.flow)
-        );
+        let size = turtle.eval_walk(&walk);
         let margin_size = walk.margin.size();
         match turtle.layout.flow {
             Flow::Right if walk.width.is_fill() => {
@@ -146,11 +222,10 @@ impl<'a> Cx2d<'a> {
                 turtle.pos.x += margin_size.x + spacing.x;
                 turtle.update_width_max(turtle.pos.x, 0.0);
                 turtle.update_height_max(turtle.pos.y, size.y + margin_size.y);
-                turtle.defer_count += 1;
+                turtle.deferred.push(walk);
                 Some(DeferWalk {
                     defer_index,
-                    margin: walk.margin,
-                    other_axis: walk.height,
+                    walk,
                     pos: pos + spacing
                 })
             },
@@ -159,14 +234,23 @@ impl<'a> Cx2d<'a> {
                 turtle.pos.y += margin_size.y + spacing.y;
                 turtle.update_width_max(turtle.pos.x, size.x + margin_size.x);
                 turtle.update_height_max(turtle.pos.y, 0.0);
-                turtle.defer_count += 1;
+                turtle.deferred.push(walk);
                 Some(DeferWalk {
                     defer_index,
-                    margin: walk.margin,
-                    other_axis: walk.width,
+                    walk,
                     pos: pos + spacing
                 })
             },
+            Flow::Grid if walk.abs_pos.is_none() && (walk.width.is_fill() || walk.height.is_fill()) => {
+                // fill walks wait until the fit tracks are measured, but take their cell now
+                let grid = turtle.grid.as_mut().unwrap();
+                let index = grid.place(walk.cell);
+                Some(DeferWalk {
+                    defer_index: index,
+                    walk,
+                    pos
+                })
+            },
             _ => {
                 None
             }
@@ -181,7 +265,7 @@ impl<'a> Cx2d<'a> {
             draw_clip: (dvec2(0.0,0.0),pass_size),
             align_start: self.align_list.len(),
             turtle_walks_start: self.turtle_walks.len(),
-            defer_count: 0,
+            deferred: Vec::new(),
             pos: DVec2 {
                 x: layout.padding.left,
                 y: layout.padding.top
@@ -193,6 +277,10 @@ impl<'a> Cx2d<'a> {
             width_used: layout.padding.left,
             height_used: layout.padding.top,
             guard_area: Area::Empty,
+            wrap_start: self.turtle_walks.len(),
+            wrap_items: 0,
+            wrap_line_size: 0.0,
+            grid: GridState::new_for_flow(layout.flow),
         };
         self.turtles.push(turtle);
     }
@@ -203,14 +291,20 @@ impl<'a> Cx2d<'a> {
     }
     
     pub fn begin_turtle_with_guard(&mut self, walk: Walk, layout: Layout, guard_area: Area) {
+        if walk.abs_pos.is_none() {
+            if let Some(grid) = self.turtles.last_mut().and_then( | parent | parent.grid.as_mut()) {
+                let index = grid.place(walk.cell);
+                grid.pending = Some(index);
+            }
+        }
         let (origin, width, height, draw_clip) = if let Some(parent) = self.turtles.last() {
             
             let o = walk.margin.left_top() + if let Some(pos) = walk.abs_pos {pos} else {
-                parent.pos + parent.child_spacing(self.turtle_walks.len())
+                parent.next_pos(self.turtle_walks.len())
             };
             
-            let w = parent.eval_width(walk.width, walk.margin, parent.layout.flow);
-            let h = parent.eval_height(walk.height, walk.margin, parent.layout.flow);
+            let size = parent.eval_walk(&walk);
+            let (w, h) = (size.x, size.y);
             
             // figure out new clipping rect
             let (x0, x1) = if layout.clip_x {
@@ -235,8 +329,8 @@ impl<'a> Cx2d<'a> {
         }
         else {
             let o = DVec2 {x: walk.margin.left, y: walk.margin.top};
-            let w = walk.width.fixed_or_nan();
-            let h = walk.height.fixed_or_nan();
+            let size = constrain_walk_size(&walk, dvec2(walk.width.fixed_or_nan(), walk.height.fixed_or_nan()));
+            let (w, h) = (size.x, size.y);
             
             (o, w, h, (dvec2(o.x, o.y), dvec2(o.x + w, o.y + h)))
         };
@@ -247,7 +341,7 @@ impl<'a> Cx2d<'a> {
             draw_clip,
             align_start: self.align_list.len(),
             turtle_walks_start: self.turtle_walks.len(),
-            defer_count: 0,
+            deferred: Vec::new(),
             pos: DVec2 {
                 x: origin.x + layout.padding.left,
                 y: origin.y + layout.padding.top
@@ -259,6 +353,10 @@ impl<'a> Cx2d<'a> {
             width_used: layout.padding.left,
             height_used: layout.padding.top,
             guard_area,
+            wrap_start: self.turtle_walks.len(),
+            wrap_items: 0,
+            wrap_line_size: 0.0,
+            grid: GridState::new_for_flow(layout.flow),
         };
         
         self.turtles.push(turtle);
@@ -274,12 +372,39 @@ impl<'a> Cx2d<'a> {
     }
     
     pub fn end_turtle_with_guard(&mut self, guard_area: Area) -> Rect {
-        let turtle = self.turtles.pop().unwrap();
+        let mut turtle = self.turtles.pop().unwrap();
         if guard_area != turtle.guard_area {
             panic!("End turtle guard area misaligned!, begin/end pair not matched begin {:?} end {:?}", turtle.guard_area, guard_area)
         }
         
-        // computed height
+        // a grid uses the space of all its tracks
+        let grid_tracks = if let Some(grid) = &turtle.grid {
+            let (columns, rows) = turtle.grid_track_sizes(grid);
+            turtle.width_used = turtle.layout.padding.left + tracks_length(&columns, turtle.layout.spacing);
+            turtle.height_used = turtle.layout.padding.top + tracks_length(&rows, turtle.layout.spacing);
+            Some((columns, rows))
+        }
+        else {
+            None
+        };
+        
+        // computed height, a fit size still has to stay within the min and max size of the walk,
+        // and the items are aligned in that
+        if turtle.width.is_nan() {
+            let used = turtle.width_used + turtle.layout.padding.right - turtle.layout.scroll.x;
+            let width = clamp_size(used, turtle.walk.min_width, turtle.walk.max_width);
+            if width != used {
+                turtle.width = width;
+            }
+        }
+        if turtle.height.is_nan() {
+            let used = turtle.height_used + turtle.layout.padding.bottom - turtle.layout.scroll.y;
+            let height = clamp_size(used, turtle.walk.min_height, turtle.walk.max_height);
+            if height != used {
+                turtle.height = height;
+            }
+        }
+        
         let w = if turtle.width.is_nan() {
             Size::Fixed(turtle.width_used + turtle.layout.padding.right - turtle.layout.scroll.x)
         }
@@ -296,12 +421,11 @@ impl<'a> Cx2d<'a> {
         
         match turtle.layout.flow {
             Flow::Right => {
-                if turtle.defer_count > 0 {
-                    let left = turtle.width_left();
-                    let part = left / turtle.defer_count as f64;
+                if !turtle.deferred.is_empty() {
+                    let sizes = turtle.deferred_sizes();
                     for i in turtle.turtle_walks_start..self.turtle_walks.len() {
                         let walk = &self.turtle_walks[i];
-                        let shift_x = walk.defer_index as f64 * part;
+                        let shift_x = sizes[..walk.defer_index].iter().sum::<f64>();
                         let shift_y = turtle.layout.align.y * (turtle.padded_height_or_used() - walk.rect.size.y);
                         let align_start = walk.align_start;
                         let align_end = self.get_turtle_walk_align_end(i);
@@ -320,13 +444,12 @@ impl<'a> Cx2d<'a> {
                 }
             },
             Flow::Down => {
-                if turtle.defer_count > 0 {
-                    let left = turtle.height_left();
-                    let part = left / turtle.defer_count as f64;
+                if !turtle.deferred.is_empty() {
+                    let sizes = turtle.deferred_sizes();
                     for i in turtle.turtle_walks_start..self.turtle_walks.len() {
                         let walk = &self.turtle_walks[i];
                         let shift_x = turtle.layout.align.x * (turtle.padded_width_or_used() - walk.rect.size.x);
-                        let shift_y = walk.defer_index as f64 * part;
+                        let shift_y = sizes[..walk.defer_index].iter().sum::<f64>();
                         let align_start = walk.align_start;
                         let align_end = self.get_turtle_walk_align_end(i);
                         self.move_align_list(shift_x, shift_y, align_start, align_end);
@@ -353,6 +476,26 @@ impl<'a> Cx2d<'a> {
                     self.move_align_list(shift_x, shift_y, align_start, align_end);
                 }
             }
+            Flow::RightWrap | Flow::DownWrap => {
+                let left = turtle.wrap_line_left();
+                self.align_wrap_line(turtle.wrap_start, turtle.layout.flow, turtle.layout.align, left, turtle.wrap_line_size);
+            }
+            Flow::Grid => {
+                // items were drawn with the track sizes known at the time, move them to their
+                // final cells and align them in there
+                let (columns, rows) = grid_tracks.unwrap();
+                for item in &turtle.grid.as_ref().unwrap().items {
+                    if let Some(i) = item.walk_index {
+                        let rect = turtle.grid_item_rect(item, &columns, &rows);
+                        let shift = rect.pos - item.pos;
+                        let shift_x = shift.x + turtle.layout.align.x * (rect.size.x - item.size.x);
+                        let shift_y = shift.y + turtle.layout.align.y * (rect.size.y - item.size.y);
+                        let align_start = self.turtle_walks[i].align_start;
+                        let align_end = self.get_turtle_walk_align_end(i);
+                        self.move_align_list(shift_x, shift_y, align_start, align_end);
+                    }
+                }
+            }
         }
         if let Some(shift) = turtle.shift {
             for i in turtle.turtle_walks_start..self.turtle_walks.len() {
@@ -414,10 +557,14 @@ impl<'a> Cx2d<'a> {
     fn walk_turtle_internal(&mut self, walk: Walk, align_start: usize, actually_move: bool) -> Rect {
         
         let turtle = self.turtles.last_mut().unwrap();
-        let size = dvec2(
-            turtle.eval_width(walk.width, walk.margin, turtle.layout.flow),
-            turtle.eval_height(walk.height, walk.margin, turtle.layout.flow)
-        );
+        if walk.abs_pos.is_none() {
+            match turtle.layout.flow {
+                Flow::RightWrap | Flow::DownWrap => return self.walk_wrap_turtle(walk, align_start, actually_move),
+                Flow::Grid => return self.walk_grid_turtle(walk, align_start, actually_move),
+                _ => ()
+            }
+        }
+        let size = turtle.eval_walk(&walk);
         
         if let Some(pos) = walk.abs_pos {
             if actually_move {
@@ -428,8 +575,8 @@ impl<'a> Cx2d<'a> {
                 });
                 
                 match turtle.layout.flow {
-                    Flow::Right=>turtle.update_height_max(pos.y, size.y + walk.margin.size().y),
-                    Flow::Down=>turtle.update_width_max(pos.x, size.x + walk.margin.size().x),
+                    Flow::Right | Flow::RightWrap=>turtle.update_height_max(pos.y, size.y + walk.margin.size().y),
+                    Flow::Down | Flow::DownWrap=>turtle.update_width_max(pos.x, size.x + walk.margin.size().x),
                     _=>()
                 }
             }
@@ -467,11 +614,12 @@ impl<'a> Cx2d<'a> {
                         turtle.update_width_max(turtle.pos.x, size.x);
                         turtle.update_height_max(turtle.pos.y,size.y);
                     }
+                    Flow::RightWrap | Flow::DownWrap | Flow::Grid => unreachable!()
                 };
                 
                 self.turtle_walks.push(TurtleWalk {
                     align_start,
-                    defer_index: turtle.defer_count,
+                    defer_index: turtle.deferred.len(),
                     rect: Rect {pos, size: size + margin_size}
                 });
             }
@@ -479,6 +627,112 @@ impl<'a> Cx2d<'a> {
         }
     }
     
+    fn walk_wrap_turtle(&mut self, walk: Walk, align_start: usize, actually_move: bool) -> Rect {
+        let turtle = self.turtles.last_mut().unwrap();
+        let size = turtle.eval_walk(&walk);
+        let margin_size = walk.margin.size();
+        let old_pos = turtle.pos + turtle.wrap_spacing();
+        let wrap = turtle.wrap_needed(size + margin_size);
+        if !actually_move {
+            let pos = if wrap {turtle.next_line_pos()} else {old_pos};
+            return Rect {pos: pos + walk.margin.left_top(), size}
+        }
+        
+        if wrap {
+            let (start, flow, align) = (turtle.wrap_start, turtle.layout.flow, turtle.layout.align);
+            let (left, line_size) = (turtle.wrap_line_left(), turtle.wrap_line_size);
+            self.align_wrap_line(start, flow, align, left, line_size);
+            
+            let turtle = self.turtles.last_mut().unwrap();
+            turtle.pos = turtle.next_line_pos();
+            turtle.wrap_start = self.turtle_walks.len();
+            turtle.wrap_items = 0;
+            turtle.wrap_line_size = 0.0;
+            
+            // a child turtle was already drawn at the end of the previous line
+            let shift = turtle.pos - old_pos;
+            self.move_align_list(shift.x, shift.y, align_start, self.align_list.len());
+        }
+        
+        let turtle = self.turtles.last_mut().unwrap();
+        let pos = turtle.pos + turtle.wrap_spacing();
+        match turtle.layout.flow {
+            Flow::RightWrap => {
+                turtle.pos.x = pos.x + size.x + margin_size.x;
+                turtle.wrap_line_size = turtle.wrap_line_size.max(size.y + margin_size.y);
+                turtle.update_width_max(turtle.pos.x, 0.0);
+                turtle.update_height_max(turtle.pos.y, size.y + margin_size.y);
+            }
+            _ => {
+                turtle.pos.y = pos.y + size.y + margin_size.y;
+                turtle.wrap_line_size = turtle.wrap_line_size.max(size.x + margin_size.x);
+                turtle.update_width_max(turtle.pos.x, size.x + margin_size.x);
+                turtle.update_height_max(turtle.pos.y, 0.0);
+            }
+        }
+        turtle.wrap_items += 1;
+        
+        self.turtle_walks.push(TurtleWalk {
+            align_start,
+            defer_index: 0,
+            rect: Rect {pos, size: size + margin_size}
+        });
+        Rect {pos: pos + walk.margin.left_top(), size}
+    }
+    
+    // aligns the walks of a finished line of a wrapping turtle, along the flow with the space
+    // left on the line and across it within the line
+    fn align_wrap_line(&mut self, start: usize, flow: Flow, align: Align, left: f64, line_size: f64) {
+        for i in start..self.turtle_walks.len() {
+            let walk = &self.turtle_walks[i];
+            let (shift_x, shift_y) = match flow {
+                Flow::RightWrap => (align.x * left, align.y * (line_size - walk.rect.size.y)),
+                _ => (align.x * (line_size - walk.rect.size.x), align.y * left),
+            };
+            let align_start = walk.align_start;
+            let align_end = self.get_turtle_walk_align_end(i);
+            self.move_align_list(shift_x, shift_y, align_start, align_end);
+        }
+    }
+    
+    fn walk_grid_turtle(&mut self, walk: Walk, align_start: usize, actually_move: bool) -> Rect {
+        let walks_len = self.turtle_walks.len();
+        let turtle = self.turtles.last_mut().unwrap();
+        let margin_size = walk.margin.size();
+        let grid = turtle.grid.as_mut().unwrap();
+        let index = if actually_move {
+            Some(grid.pending.take().unwrap_or_else( || grid.place(walk.cell)))
+        }
+        else {
+            grid.pending
+        };
+        let cell = match index {
+            Some(index) => turtle.grid_cell_rect(index),
+            None => turtle.grid_next_rect(walk.cell)
+        };
+        let size = constrain_walk_size(&walk, dvec2(
+            eval_cell_size(walk.width, cell.size.x, margin_size.x),
+            eval_cell_size(walk.height, cell.size.y, margin_size.y)
+        ));
+        if actually_move {
+            let spacing = turtle.layout.spacing;
+            let grid = turtle.grid.as_mut().unwrap();
+            let index = index.unwrap();
+            let item = &mut grid.items[index];
+            item.walk_index = Some(walks_len);
+            item.pos = cell.pos;
+            item.size = size + margin_size;
+            grid.measure(index, spacing);
+            
+            self.turtle_walks.push(TurtleWalk {
+                align_start,
+                defer_index: 0,
+                rect: Rect {pos: cell.pos, size: size + margin_size}
+            });
+        }
+        Rect {pos: cell.pos + walk.margin.left_top(), size}
+    }
+    
     fn move_align_list(&mut self, dx: f64, dy: f64, align_start: usize, align_end: usize) {
         let dx = if dx.is_nan() {0.0}else {dx};
         let dy = if dy.is_nan() {0.0}else {dy};
@@ -583,8 +837,15 @@ impl Turtle {
         self.pos = pos
     }
     
+    pub fn set_grid(&mut self, grid: &GridLayout) {
+        if let Some(state) = &mut self.grid {
+            state.columns = grid.columns.clone();
+            state.rows = grid.rows.clone();
+        }
+    }
+    
     fn child_spacing(&self, walks_len: usize) -> DVec2 {
-        if self.turtle_walks_start < walks_len || self.defer_count > 0 {
+        if self.turtle_walks_start < walks_len || !self.deferred.is_empty() {
             match self.layout.flow {
                 Flow::Right => {
                     dvec2(self.layout.spacing, 0.0)
@@ -592,7 +853,10 @@ impl Turtle {
                 Flow::Down => {
                     dvec2(0.0, self.layout.spacing)
                 }
-                Flow::Overlay => {
+                Flow::RightWrap | Flow::DownWrap => {
+                    self.wrap_spacing()
+                }
+                Flow::Overlay | Flow::Grid => {
                     dvec2(0.0, 0.0)
                 }
             }
@@ -602,6 +866,119 @@ impl Turtle {
         }
     }
     
+    // where the next child turtle begins
+    fn next_pos(&self, walks_len: usize) -> DVec2 {
+        if let Some(index) = self.grid.as_ref().and_then( | grid | grid.pending) {
+            return self.grid_cell_rect(index).pos
+        }
+        self.pos + self.child_spacing(walks_len)
+    }
+    
+    fn wrap_spacing(&self) -> DVec2 {
+        if self.wrap_items == 0 {
+            return dvec2(0.0, 0.0)
+        }
+        match self.layout.flow {
+            Flow::RightWrap => dvec2(self.layout.spacing, 0.0),
+            _ => dvec2(0.0, self.layout.spacing)
+        }
+    }
+    
+    // the space left after the last walk of the current line of a wrapping turtle
+    fn wrap_line_left(&self) -> f64 {
+        match self.layout.flow {
+            Flow::RightWrap => max_zero_keep_nan(self.origin.x + self.width - self.layout.padding.right - self.pos.x),
+            _ => max_zero_keep_nan(self.origin.y + self.height - self.layout.padding.bottom - self.pos.y)
+        }
+    }
+    
+    // a walk moves to the next line when it does not fit, unless it is the first one on the line
+    fn wrap_needed(&self, size: DVec2) -> bool {
+        if self.wrap_items == 0 {
+            return false
+        }
+        let spacing = self.wrap_spacing();
+        match self.layout.flow {
+            Flow::RightWrap => spacing.x + size.x > self.wrap_line_left() + WRAP_EPSILON,
+            _ => spacing.y + size.y > self.wrap_line_left() + WRAP_EPSILON
+        }
+    }
+    
+    fn next_line_pos(&self) -> DVec2 {
+        match self.layout.flow {
+            Flow::RightWrap => dvec2(
+                self.origin.x + self.layout.padding.left,
+                self.pos.y + self.wrap_line_size + self.layout.spacing
+            ),
+            _ => dvec2(
+                self.pos.x + self.wrap_line_size + self.layout.spacing,
+                self.origin.y + self.layout.padding.top
+            )
+        }
+    }
+    
+    fn grid_track_sizes(&self, grid: &GridState) -> (Vec<f64>, Vec<f64>) {
+        let spacing = self.layout.spacing;
+        let columns = resolve_tracks(
+            &grid.column_fit,
+            grid.column_count(),
+            | i | grid.column_track(i),
+            self.width - self.layout.padding.width(),
+            spacing
+        );
+        let rows = resolve_tracks(
+            &grid.row_fit,
+            grid.row_count(),
+            | i | grid.row_track(i),
+            self.height - self.layout.padding.height(),
+            spacing
+        );
+        (columns, rows)
+    }
+    
+    fn grid_item_rect(&self, item: &GridItem, columns: &[f64], rows: &[f64]) -> Rect {
+        let spacing = self.layout.spacing;
+        let track_pos = | sizes: &[f64], index: usize | sizes[..index].iter().map( | size | size + spacing).sum::<f64>();
+        let track_size = | sizes: &[f64], index: usize, span: usize | {
+            let end = (index + span).min(sizes.len());
+            tracks_length(&sizes[index.min(end)..end], spacing)
+        };
+        Rect {
+            pos: self.origin + self.layout.padding.left_top() + dvec2(
+                track_pos(columns, item.column),
+                track_pos(rows, item.row)
+            ),
+            size: dvec2(
+                track_size(columns, item.column, item.column_span),
+                track_size(rows, item.row, item.row_span)
+            )
+        }
+    }
+    
+    fn grid_cell_rect(&self, index: usize) -> Rect {
+        self.grid_rect(self.grid.as_ref().unwrap(), index)
+    }
+    
+    fn grid_rect(&self, grid: &GridState, index: usize) -> Rect {
+        let (columns, rows) = self.grid_track_sizes(grid);
+        self.grid_item_rect(&grid.items[index], &columns, &rows)
+    }
+    
+    // the cell a walk would get, without taking it
+    fn grid_next_rect(&self, cell: GridCell) -> Rect {
+        let mut grid = self.grid.clone().unwrap();
+        let index = grid.place(cell);
+        self.grid_rect(&grid, index)
+    }
+    
+    // the cell size a fill walk evaluates to
+    fn grid_cell_size(&self) -> DVec2 {
+        match self.grid.as_ref().and_then( | grid | grid.pending) {
+            Some(index) => self.grid_cell_rect(index).size,
+            None => self.grid_next_rect(GridCell::default()).size
+        }
+    }
+    
     pub fn rect_is_visible(&self, geom: Rect) -> bool {
         let view = Rect {pos: self.draw_clip.0, size: self.draw_clip.1 - self.draw_clip.0};
         return view.intersects(geom)
@@ -626,16 +1003,65 @@ impl Turtle {
         self.layout.scroll
     }
     
+    // the size of a walk with its min and max sizes and aspect ratio applied
+    pub fn eval_walk(&self, walk: &Walk) -> DVec2 {
+        constrain_walk_size(walk, dvec2(
+            self.eval_width(walk.width, walk.margin, self.layout.flow),
+            self.eval_height(walk.height, walk.margin, self.layout.flow)
+        ))
+    }
+    
+    // shares the space left along the flow between the deferred fill walks by their weight.
+    // walks that would pass their min or max size get that instead, and the others share the rest
+    fn deferred_sizes(&self) -> Vec<f64> {
+        let left = match self.layout.flow {
+            Flow::Down => self.height_left(),
+            _ => self.width_left()
+        };
+        let limits: Vec<_> = self.deferred.iter().map( | walk | match self.layout.flow {
+            Flow::Down => (walk.height.fill_weight(), walk.min_height, walk.max_height),
+            _ => (walk.width.fill_weight(), walk.min_width, walk.max_width)
+        }).collect();
+        let mut sizes = vec![0.0; limits.len()];
+        let mut fixed = vec![false; limits.len()];
+        loop {
+            let weight: f64 = limits.iter().zip(&fixed).filter( | (_, fixed) | !**fixed).map( | (limit, _) | limit.0).sum();
+            let space = left - sizes.iter().zip(&fixed).filter( | (_, fixed) | **fixed).map( | (size, _) | size).sum::<f64>();
+            let mut changed = false;
+            for (i, (walk_weight, min, max)) in limits.iter().enumerate() {
+                if fixed[i] {
+                    continue
+                }
+                let size = if weight > 0.0 {max_zero_keep_nan(space) * walk_weight / weight} else {0.0};
+                let clamped = clamp_size(size, *min, *max);
+                sizes[i] = clamped;
+                if clamped != size && !size.is_nan() {
+                    fixed[i] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return sizes
+            }
+        }
+    }
+    
     pub fn eval_width(&self, width: Size, margin: Margin, flow: Flow) -> f64 {
         return match width {
             Size::Fit => std::f64::NAN,
             Size::Fixed(v) => max_zero_keep_nan(v),
-            Size::Fill => {
+            Size::Fill(_) => {
                 match flow {
                     Flow::Right => {
                         max_zero_keep_nan(self.width_left() - margin.width())
                     },
-                    Flow::Down | Flow::Overlay => {
+                    Flow::RightWrap if self.wrap_line_left() - self.wrap_spacing().x > 0.0 => {
+                        max_zero_keep_nan(self.wrap_line_left() - self.wrap_spacing().x - margin.width())
+                    },
+                    Flow::Grid => {
+                        max_zero_keep_nan(self.grid_cell_size().x - margin.width())
+                    },
+                    Flow::Down | Flow::Overlay | Flow::RightWrap | Flow::DownWrap => {
                         let r = max_zero_keep_nan(self.width - self.layout.padding.width() - margin.width());
                         if r.is_nan() {
                             return self.width_used - margin.width() - self.layout.padding.right
@@ -651,18 +1077,24 @@ impl Turtle {
         return match height {
             Size::Fit => std::f64::NAN,
             Size::Fixed(v) => max_zero_keep_nan(v),
-            Size::Fill => {
+            Size::Fill(_) => {
                 match flow {
-                    Flow::Right | Flow::Overlay => {
+                    Flow::Down => {
+                        max_zero_keep_nan(self.height_left() - margin.height())
+                    }
+                    Flow::DownWrap if self.wrap_line_left() - self.wrap_spacing().y > 0.0 => {
+                        max_zero_keep_nan(self.wrap_line_left() - self.wrap_spacing().y - margin.height())
+                    },
+                    Flow::Grid => {
+                        max_zero_keep_nan(self.grid_cell_size().y - margin.height())
+                    },
+                    Flow::Right | Flow::Overlay | Flow::RightWrap | Flow::DownWrap => {
                         let r = max_zero_keep_nan(self.height - self.layout.padding.height() - margin.height());
                         if r.is_nan() {
                             return self.height_used - margin.height() - self.layout.padding.bottom
                         }
                         return r
                     }
-                    Flow::Down => {
-                        max_zero_keep_nan(self.height_left() - margin.height())
-                    }
                 }
             }
         }
@@ -732,26 +1164,39 @@ impl DeferWalk {
         let turtle = cx.turtles.last().unwrap();
         match turtle.layout.flow {
             Flow::Right => {
-                let left = turtle.width_left();
-                let part = left / turtle.defer_count as f64;
+                let sizes = turtle.deferred_sizes();
+                let offset = sizes[..self.defer_index].iter().sum::<f64>();
                 Walk {
-                    abs_pos: Some(self.pos + dvec2(part * self.defer_index as f64, 0.)),
-                    margin: self.margin,
-                    width: Size::Fixed(part),
-                    height: self.other_axis
+                    abs_pos: Some(self.pos + dvec2(offset, 0.)),
+                    width: Size::Fixed(sizes[self.defer_index]),
+                    ..self.walk
                 }
             },
             Flow::Down => {
-                let left = turtle.height_left();
-                let part = left / turtle.defer_count as f64;
+                let sizes = turtle.deferred_sizes();
+                let offset = sizes[..self.defer_index].iter().sum::<f64>();
                 Walk {
-                    abs_pos: Some(self.pos + dvec2(0., part * self.defer_index as f64)),
-                    margin: self.margin,
-                    height: Size::Fixed(part),
-                    width: self.other_axis
+                    abs_pos: Some(self.pos + dvec2(0., offset)),
+                    height: Size::Fixed(sizes[self.defer_index]),
+                    ..self.walk
                 }
             }
-            Flow::Overlay => panic!()
+            Flow::Grid => {
+                let cell = turtle.grid_cell_rect(self.defer_index);
+                let margin_size = self.walk.margin.size();
+                let fill = | size: Size, cell_size: f64, margin: f64 | if size.is_fill() {
+                    Size::Fixed(max_zero_keep_nan(cell_size - margin))
+                } else {
+                    size
+                };
+                Walk {
+                    abs_pos: Some(cell.pos),
+                    width: fill(self.walk.width, cell.size.x, margin_size.x),
+                    height: fill(self.walk.height, cell.size.y, margin_size.y),
+                    ..self.walk
+                }
+            }
+            Flow::Overlay | Flow::RightWrap | Flow::DownWrap => panic!()
         }
     }
 }
@@ -830,6 +1275,7 @@ impl Walk {
             margin: Margin::default(),
             width: Size::Fixed(0.0),
             height: Size::Fixed(0.0),
+            ..Self::default()
         }
     }
     
@@ -839,6 +1285,7 @@ impl Walk {
             margin: Margin::default(),
             width: w,
             height: h,
+            ..Self::default()
         }
     }
     
@@ -848,6 +1295,7 @@ impl Walk {
             margin: Margin::default(),
             width: Size::Fixed(size.x),
             height: Size::Fixed(size.y),
+            ..Self::default()
         }
     }
     
@@ -857,6 +1305,7 @@ impl Walk {
             margin: Margin::default(),
             width: Size::Fit,
             height: Size::Fit,
+            ..Self::default()
         }
     }
     
@@ -864,8 +1313,9 @@ impl Walk {
         Self {
             abs_pos: None,
             margin: Margin::default(),
-            width: Size::Fill,
-            height: Size::Fill,
+            width: Size::fill(),
+            height: Size::fill(),
+            ..Self::default()
         }
     }
     
@@ -873,8 +1323,9 @@ impl Walk {
         Self {
             abs_pos: None,
             margin: Margin::default(),
-            width: Size::Fill,
+            width: Size::fill(),
             height: Size::Fit,
+            ..Self::default()
         }
     }
     
@@ -980,6 +1431,10 @@ impl LiveHook for Size {
                 *self = Self::Fixed(*v as f64);
                 Some(index + 1)
             }
+            LiveValue::BareEnum(variant) if *variant == live_id!(Fill) => {
+                *self = Self::Fill(1.0);
+                Some(index + 1)
+            }
             _ => None
         }
     }
@@ -987,11 +1442,22 @@ impl LiveHook for Size {
 
 impl Default for Size {
     fn default() -> Self {
-        Size::Fill
+        Size::Fill(1.0)
     }
 }
 
 impl Size {
+    pub fn fill() -> Self {
+        Self::Fill(1.0)
+    }
+    
+    pub fn fill_weight(&self) -> f64 {
+        match self {
+            Self::Fill(weight) => weight.max(0.0),
+            _ => 0.0
+        }
+    }
+    
     pub fn fixed_or_zero(&self) -> f64 {
         match self {
             Self::Fixed(v) => *v,
@@ -1022,12 +1488,202 @@ impl Size {
     
     pub fn is_fill(&self) -> bool {
         match self {
-            Self::Fill => true,
+            Self::Fill(_) => true,
             _ => false
         }
     }
 }
 
+impl GridState {
+    fn new_for_flow(flow: Flow) -> Option<Self> {
+        match flow {
+            Flow::Grid => Some(Self::default()),
+            _ => None
+        }
+    }
+    
+    fn column_count(&self) -> usize {
+        self.columns.len().max(1)
+    }
+    
+    fn row_count(&self) -> usize {
+        self.items.iter().map( | item | item.row + item.row_span).fold(self.rows.len(), usize::max)
+    }
+    
+    fn column_track(&self, index: usize) -> GridTrack {
+        self.columns.get(index).copied().unwrap_or(GridTrack::Fill)
+    }
+    
+    fn row_track(&self, index: usize) -> GridTrack {
+        self.rows.get(index).copied().unwrap_or(GridTrack::Fit)
+    }
+    
+    fn is_free(&self, column: usize, row: usize, column_span: usize, row_span: usize) -> bool {
+        !self.items.iter().any( | item | {
+            column < item.column + item.column_span && item.column < column + column_span
+                && row < item.row + item.row_span && item.row < row + row_span
+        })
+    }
+    
+    // takes the cell of a walk, either the one it asks for or the next free one
+    fn place(&mut self, cell: GridCell) -> usize {
+        let columns = self.column_count();
+        let column_span = cell.column_span.max(1).min(columns);
+        let row_span = cell.row_span.max(1);
+        let (column, row) = match (cell.column, cell.row) {
+            (Some(column), Some(row)) => (column.min(columns - column_span), row),
+            (Some(column), None) => {
+                let column = column.min(columns - column_span);
+                let row = (0..).find( | row | self.is_free(column, *row, column_span, row_span)).unwrap();
+                (column, row)
+            }
+            (None, Some(row)) => {
+                match (0..=columns - column_span).find( | column | self.is_free(*column, row, column_span, row_span)) {
+                    Some(column) => (column, row),
+                    None => (0, row)
+                }
+            }
+            (None, None) => {
+                let cursor = (self.cursor..).find( | cursor | {
+                    let column = cursor % columns;
+                    column + column_span <= columns && self.is_free(column, cursor / columns, column_span, row_span)
+                }).unwrap();
+                self.cursor = cursor + column_span;
+                (cursor % columns, cursor / columns)
+            }
+        };
+        self.items.push(GridItem {
+            column,
+            row,
+            column_span,
+            row_span,
+            ..GridItem::default()
+        });
+        self.items.len() - 1
+    }
+    
+    // grows the fit tracks of an item to its size. an item spanning several tracks only counts
+    // when all of them are fixed or fit, and puts what the others do not cover in the last one
+    fn measure(&mut self, index: usize, spacing: f64) {
+        let item = &self.items[index];
+        let (column, column_span, row, row_span, size) = (item.column, item.column_span, item.row, item.row_span, item.size);
+        let column_tracks: Vec<GridTrack> = (column..column + column_span).map( | i | self.column_track(i)).collect();
+        let row_tracks: Vec<GridTrack> = (row..row + row_span).map( | i | self.row_track(i)).collect();
+        measure_tracks(&mut self.column_fit, column, &column_tracks, size.x, spacing);
+        measure_tracks(&mut self.row_fit, row, &row_tracks, size.y, spacing);
+    }
+}
+
+fn measure_tracks(fit: &mut Vec<f64>, start: usize, tracks: &[GridTrack], size: f64, spacing: f64) {
+    if size.is_nan() {
+        return
+    }
+    if fit.len() < start + tracks.len() {
+        fit.resize(start + tracks.len(), 0.0);
+    }
+    if tracks.len() == 1 {
+        fit[start] = fit[start].max(size);
+        return
+    }
+    let mut last_fit = None;
+    let mut covered = spacing * (tracks.len() - 1) as f64;
+    for (i, track) in tracks.iter().enumerate() {
+        match track {
+            GridTrack::Fixed(v) => covered += v,
+            GridTrack::Fit => {
+                if let Some(last) = last_fit {
+                    covered += fit[start + last];
+                }
+                last_fit = Some(i);
+            }
+            GridTrack::Fill | GridTrack::Fraction(_) => return
+        }
+    }
+    if let Some(last) = last_fit {
+        fit[start + last] = fit[start + last].max(size - covered);
+    }
+}
+
+// sizes the tracks of one axis of a grid. fill and fraction tracks share the space the fixed
+// and fit tracks leave, and act like fit tracks when the grid has no size along the axis
+fn resolve_tracks(fit: &[f64], count: usize, track: impl Fn(usize) -> GridTrack, available: f64, spacing: f64) -> Vec<f64> {
+    let fit_size = | i: usize | fit.get(i).copied().unwrap_or(0.0);
+    let mut sizes = vec![0.0; count];
+    let mut used = spacing * count.saturating_sub(1) as f64;
+    let mut fractions = 0.0;
+    for (i, size) in sizes.iter_mut().enumerate() {
+        match track(i) {
+            GridTrack::Fixed(v) => *size = v,
+            GridTrack::Fit => *size = fit_size(i),
+            GridTrack::Fill => fractions += 1.0,
+            GridTrack::Fraction(v) => fractions += v.max(0.0),
+        }
+        used += *size;
+    }
+    for (i, size) in sizes.iter_mut().enumerate() {
+        let fraction = match track(i) {
+            GridTrack::Fill => 1.0,
+            GridTrack::Fraction(v) => v.max(0.0),
+            _ => continue
+        };
+        *size = if available.is_nan() {
+            fit_size(i)
+        }
+        else if fractions > 0.0 {
+            (available - used).max(0.0) * fraction / fractions
+        }
+        else {
+            0.0
+        };
+    }
+    sizes
+}
+
+fn tracks_length(sizes: &[f64], spacing: f64) -> f64 {
+    sizes.iter().sum::<f64>() + spacing * sizes.len().saturating_sub(1) as f64
+}
+
+fn eval_cell_size(size: Size, cell_size: f64, margin: f64) -> f64 {
+    match size {
+        Size::Fit => f64::NAN,
+        Size::Fixed(v) => max_zero_keep_nan(v),
+        Size::Fill(_) => max_zero_keep_nan(cell_size - margin),
+    }
+}
+
+fn clamp_size(size: f64, min: Option<f64>, max: Option<f64>) -> f64 {
+    if size.is_nan() {
+        return size
+    }
+    // like css the min size wins over the max size
+    let size = match max {Some(max) => size.min(max), None => size};
+    match min {Some(min) => size.max(min), None => size}
+}
+
+fn constrain_walk_size(walk: &Walk, size: DVec2) -> DVec2 {
+    let mut size = dvec2(
+        clamp_size(size.x, walk.min_width, walk.max_width),
+        clamp_size(size.y, walk.min_height, walk.max_height)
+    );
+    if let Some(ratio) = walk.aspect_ratio.filter( | ratio | *ratio > 0.0) {
+        if size.x.is_nan() && !size.y.is_nan() {
+            size.x = clamp_size(size.y * ratio, walk.min_width, walk.max_width);
+        }
+        else if size.y.is_nan() && !size.x.is_nan() {
+            size.y = clamp_size(size.x / ratio, walk.min_height, walk.max_height);
+        }
+        else if !size.x.is_nan() {
+            if size.x > size.y * ratio {
+                size.x = size.y * ratio;
+            }
+            else {
+                size.y = size.x / ratio;
+            }
+        }
+    }
+    size
+}
+
 fn max_zero_keep_nan(v: f64) -> f64 {
     if v.is_nan() {
         v
@@ -347,6 +347,7 @@ impl<'a> Cx2d<'a> {
             rect_id
         });
         self.update_area_refs(*area, new_area);
+        self.align_list.push(new_area);
         *area = new_area;
     }
 }
@@ -0,0 +1,68 @@
+use makepad_draw_2d::*;
+
+// lays out nav stops without a window or gpu, and returns the accessibility tree made of them
+fn tree(f: impl FnOnce(&mut Cx2d)) -> AccessNode {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    makepad_draw_2d::live_design(&mut cx);
+    cx.live_expand();
+    let draw_list = cx.draw_lists.alloc();
+    let draw_event = DrawEvent::default();
+    let mut cx = Cx2d::new(&mut cx, &draw_event);
+    cx.nav_list_clear(draw_list.id());
+    cx.draw_list_stack.push(draw_list.id());
+    cx.begin_turtle(Walk::fixed_size(dvec2(100.0, 100.0)), Layout::flow_down());
+    f(&mut cx);
+    cx.end_turtle();
+    cx.draw_list_stack.pop();
+    Cx2d::accessibility_tree(&mut cx, draw_list.id())
+}
+
+fn stop(cx: &mut Cx2d, role: NavRole, name: &str, state: NavState) {
+    let mut area = Area::Empty;
+    cx.walk_turtle_with_area(&mut area, Walk::fixed_size(dvec2(50.0, 10.0)));
+    cx.add_nav_stop_with_info(area, role, Margin::default(), NavInfo {
+        name: name.to_string(),
+        value: None,
+        state
+    });
+}
+
+#[test]
+fn test_tree_of_stops() {
+    let root = tree( | cx | {
+        stop(cx, NavRole::Button, "Save", NavState::default());
+        stop(cx, NavRole::CheckBox, "Wrap", NavState {checked: Some(true), ..NavState::default()});
+    });
+    assert_eq!(root.role, NavRole::Group);
+    assert_eq!(root.children.len(), 2);
+
+    let check = root.find_by_name(NavRole::CheckBox, "Wrap").unwrap();
+    assert_eq!(check.state.checked, Some(true));
+    assert_eq!(check.bounds, Rect {pos: dvec2(0.0, 10.0), size: dvec2(50.0, 10.0)});
+    assert!(root.find_by_name(NavRole::Button, "Wrap").is_none());
+    // the window is as large as what is in it
+    assert_eq!(root.bounds, Rect {pos: dvec2(0.0, 0.0), size: dvec2(50.0, 20.0)});
+    assert!(root.focused().is_none());
+}
+
+#[test]
+fn test_scrolled_groups_nest() {
+    let root = tree( | cx | {
+        stop(cx, NavRole::Tab, "One", NavState {selected: Some(true), ..NavState::default()});
+        let scroll = cx.add_begin_scroll();
+        stop(cx, NavRole::ListItem, "First", NavState::default());
+        stop(cx, NavRole::ListItem, "Second", NavState::default());
+        let mut area = Area::Empty;
+        cx.walk_turtle_with_area(&mut area, Walk::fixed_size(dvec2(0.0, 0.0)));
+        cx.add_end_scroll(scroll, area);
+        stop(cx, NavRole::Button, "Close", NavState::default());
+    });
+    let roles: Vec<NavRole> = root.children.iter().map( | node | node.role).collect();
+    assert_eq!(roles, vec![NavRole::Tab, NavRole::Group, NavRole::Button]);
+    let names: Vec<&str> = root.children[1].children.iter().map( | node | node.name.as_str()).collect();
+    assert_eq!(names, vec!["First", "Second"]);
+
+    // depth first is the order tab goes through the stops
+    let order: Vec<&str> = root.flatten().iter().filter( | node | node.role != NavRole::Group).map( | node | node.name.as_str()).collect();
+    assert_eq!(order, vec!["One", "First", "Second", "Close"]);
+}
@@ -0,0 +1,287 @@
+use makepad_draw_2d::*;
+
+live_design!{
+    import makepad_draw_2d::turtle::*;
+    
+    ConstrainedWalk = {{Walk}} {width: Fill, height: Fill(2.0), min_width: 30.0, aspect_ratio: 1.5}
+}
+
+// runs a turtle without a window or gpu, and returns the final rects of the walks that were
+// given an area, after all alignment
+fn layout(walk: Walk, layout: Layout, grid: Option<GridLayout>, f: impl FnOnce(&mut Cx2d, &mut Vec<Area>)) -> (Rect, Vec<Rect>) {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    makepad_draw_2d::live_design(&mut cx);
+    cx.live_expand();
+    let draw_list = cx.draw_lists.alloc();
+    let draw_event = DrawEvent::default();
+    let mut cx = Cx2d::new(&mut cx, &draw_event);
+    cx.draw_list_stack.push(draw_list.id());
+
+    let mut areas = Vec::new();
+    cx.begin_turtle(walk, layout);
+    if let Some(grid) = &grid {
+        cx.turtle_mut().set_grid(grid);
+    }
+    f(&mut cx, &mut areas);
+    let rect = cx.end_turtle();
+
+    let rects = areas.iter().map( | area | area.get_rect(&cx)).collect();
+    cx.draw_list_stack.pop();
+    (rect, rects)
+}
+
+fn walk(cx: &mut Cx2d, areas: &mut Vec<Area>, walk: Walk) {
+    let mut area = Area::Empty;
+    cx.walk_turtle_with_area(&mut area, walk);
+    areas.push(area);
+}
+
+fn rect(x: f64, y: f64, w: f64, h: f64) -> Rect {
+    Rect {pos: dvec2(x, y), size: dvec2(w, h)}
+}
+
+fn size(w: f64, h: f64) -> Walk {
+    Walk::fixed_size(dvec2(w, h))
+}
+
+#[test]
+fn test_right_wrap() {
+    let turtle = Layout {flow: Flow::RightWrap, spacing: 10.0, ..Layout::default()};
+    let (outer, rects) = layout(Walk::size(Size::Fixed(100.0), Size::Fit), turtle, None, | cx, areas | {
+        walk(cx, areas, size(30.0, 10.0));
+        walk(cx, areas, size(30.0, 20.0));
+        walk(cx, areas, size(30.0, 10.0));
+        walk(cx, areas, size(40.0, 10.0));
+        // a fill walk takes the rest of the line
+        walk(cx, areas, Walk::size(Size::fill(), Size::Fixed(10.0)));
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 30.0, 10.0),
+        rect(40.0, 0.0, 30.0, 20.0),
+        rect(0.0, 30.0, 30.0, 10.0),
+        rect(40.0, 30.0, 40.0, 10.0),
+        rect(90.0, 30.0, 10.0, 10.0),
+    ]);
+    assert_eq!(outer, rect(0.0, 0.0, 100.0, 40.0));
+}
+
+#[test]
+fn test_right_wrap_align() {
+    // every line is aligned on its own
+    let turtle = Layout {flow: Flow::RightWrap, spacing: 10.0, align: Align {x: 0.5, y: 1.0}, ..Layout::default()};
+    let (_, rects) = layout(size(100.0, 100.0), turtle, None, | cx, areas | {
+        walk(cx, areas, size(30.0, 10.0));
+        walk(cx, areas, size(30.0, 20.0));
+        walk(cx, areas, size(60.0, 10.0));
+    });
+    assert_eq!(rects, vec![
+        rect(15.0, 10.0, 30.0, 10.0),
+        rect(55.0, 0.0, 30.0, 20.0),
+        rect(20.0, 30.0, 60.0, 10.0),
+    ]);
+}
+
+#[test]
+fn test_down_wrap() {
+    let turtle = Layout {flow: Flow::DownWrap, spacing: 5.0, padding: Padding {left: 2.0, top: 2.0, right: 2.0, bottom: 2.0}, ..Layout::default()};
+    let (outer, rects) = layout(Walk::size(Size::Fit, Size::Fixed(54.0)), turtle, None, | cx, areas | {
+        walk(cx, areas, size(10.0, 20.0));
+        walk(cx, areas, size(20.0, 20.0));
+        walk(cx, areas, size(10.0, 20.0));
+    });
+    assert_eq!(rects, vec![
+        rect(2.0, 2.0, 10.0, 20.0),
+        rect(2.0, 27.0, 20.0, 20.0),
+        rect(27.0, 2.0, 10.0, 20.0),
+    ]);
+    assert_eq!(outer, rect(0.0, 0.0, 39.0, 54.0));
+}
+
+#[test]
+fn test_wrap_moves_child_turtle() {
+    // a child turtle only knows its size when it ends, so what it drew moves to the next line
+    let turtle = Layout {flow: Flow::RightWrap, ..Layout::default()};
+    let (_, rects) = layout(size(100.0, 100.0), turtle, None, | cx, areas | {
+        walk(cx, areas, size(60.0, 10.0));
+        cx.begin_turtle(Walk::fit(), Layout::flow_right());
+        walk(cx, areas, size(50.0, 15.0));
+        cx.end_turtle();
+        walk(cx, areas, size(20.0, 10.0));
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 60.0, 10.0),
+        rect(0.0, 10.0, 50.0, 15.0),
+        rect(50.0, 10.0, 20.0, 10.0),
+    ]);
+}
+
+#[test]
+fn test_grid() {
+    let grid = GridLayout {
+        columns: vec![GridTrack::Fixed(50.0), GridTrack::Fit, GridTrack::Fill],
+        rows: vec![],
+    };
+    let turtle = Layout {flow: Flow::Grid, spacing: 10.0, ..Layout::default()};
+    let (outer, rects) = layout(Walk::size(Size::Fixed(300.0), Size::Fit), turtle, Some(grid), | cx, areas | {
+        walk(cx, areas, size(20.0, 10.0));
+        cx.begin_turtle(Walk::fit(), Layout::flow_right());
+        walk(cx, areas, size(40.0, 20.0));
+        cx.end_turtle();
+        // drawn before the fit column has its final size
+        walk(cx, areas, size(30.0, 10.0));
+        walk(cx, areas, Walk {cell: GridCell {column: Some(1), row: Some(1), ..GridCell::default()}, ..size(70.0, 10.0)});
+        // fill walks are deferred until the fit tracks are known
+        let deferred = cx.defer_walk(Walk {
+            cell: GridCell {row: Some(2), column_span: 3, ..GridCell::default()},
+            ..Walk::size(Size::fill(), Size::Fixed(15.0))
+        }).unwrap();
+        walk(cx, areas, size(10.0, 10.0));
+        let resolved = deferred.resolve(cx);
+        walk(cx, areas, resolved);
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 20.0, 10.0),
+        rect(60.0, 0.0, 40.0, 20.0),
+        rect(140.0, 0.0, 30.0, 10.0),
+        rect(60.0, 30.0, 70.0, 10.0),
+        rect(0.0, 30.0, 10.0, 10.0),
+        rect(0.0, 50.0, 300.0, 15.0),
+    ]);
+    assert_eq!(outer, rect(0.0, 0.0, 300.0, 50.0));
+}
+
+#[test]
+fn test_grid_fractions_and_align() {
+    let grid = GridLayout {
+        columns: vec![GridTrack::Fraction(1.0), GridTrack::Fraction(3.0)],
+        rows: vec![GridTrack::Fixed(40.0), GridTrack::Fit],
+    };
+    let turtle = Layout {flow: Flow::Grid, align: Align {x: 0.5, y: 1.0}, ..Layout::default()};
+    let (outer, rects) = layout(size(200.0, 100.0), turtle, Some(grid.clone()), | cx, areas | {
+        let first = cx.defer_walk(Walk::fill()).unwrap();
+        walk(cx, areas, size(30.0, 10.0));
+        walk(cx, areas, Walk {cell: GridCell {column_span: 2, ..GridCell::default()}, ..size(20.0, 20.0)});
+        let resolved = first.resolve(cx);
+        walk(cx, areas, resolved);
+    });
+    assert_eq!(rects, vec![
+        rect(110.0, 30.0, 30.0, 10.0),
+        rect(90.0, 40.0, 20.0, 20.0),
+        rect(0.0, 0.0, 50.0, 40.0),
+    ]);
+    assert_eq!(outer, rect(0.0, 0.0, 200.0, 100.0));
+
+    // without a width fractions fit their contents
+    let (outer, rects) = layout(Walk::fit(), Layout {flow: Flow::Grid, ..Layout::default()}, Some(grid), | cx, areas | {
+        walk(cx, areas, size(30.0, 10.0));
+        walk(cx, areas, size(50.0, 10.0));
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 30.0, 10.0),
+        rect(30.0, 0.0, 50.0, 10.0),
+    ]);
+    assert_eq!(outer, rect(0.0, 0.0, 80.0, 40.0));
+}
+
+#[test]
+fn test_weighted_fills() {
+    let (_, rects) = layout(size(300.0, 100.0), Layout::flow_right(), None, | cx, areas | {
+        let first = cx.defer_walk(Walk::size(Size::Fill(2.0), Size::Fixed(10.0))).unwrap();
+        let second = cx.defer_walk(Walk::size(Size::fill(), Size::Fixed(10.0))).unwrap();
+        walk(cx, areas, size(60.0, 10.0));
+        let first = first.resolve(cx);
+        walk(cx, areas, first);
+        let second = second.resolve(cx);
+        walk(cx, areas, second);
+    });
+    assert_eq!(rects, vec![
+        rect(240.0, 0.0, 60.0, 10.0),
+        rect(0.0, 0.0, 160.0, 10.0),
+        rect(160.0, 0.0, 80.0, 10.0),
+    ]);
+}
+
+#[test]
+fn test_fill_max_width() {
+    // a fill that reaches its max width gives the rest to the other fills
+    let (_, rects) = layout(size(300.0, 100.0), Layout::flow_down(), None, | cx, areas | {
+        let first = cx.defer_walk(Walk {max_height: Some(20.0), ..Walk::fill()}).unwrap();
+        let second = cx.defer_walk(Walk {min_height: Some(250.0), ..Walk::fill()}).unwrap();
+        let first = first.resolve(cx);
+        walk(cx, areas, first);
+        let second = second.resolve(cx);
+        walk(cx, areas, second);
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 300.0, 20.0),
+        rect(0.0, 20.0, 300.0, 250.0),
+    ]);
+
+    let (_, rects) = layout(size(300.0, 100.0), Layout::flow_right(), None, | cx, areas | {
+        let first = cx.defer_walk(Walk {max_width: Some(50.0), ..Walk::fill()}).unwrap();
+        let second = cx.defer_walk(Walk::fill()).unwrap();
+        let third = cx.defer_walk(Walk::fill()).unwrap();
+        for deferred in [first, second, third] {
+            let resolved = deferred.resolve(cx);
+            walk(cx, areas, resolved);
+        }
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 50.0, 100.0),
+        rect(50.0, 0.0, 125.0, 100.0),
+        rect(175.0, 0.0, 125.0, 100.0),
+    ]);
+}
+
+#[test]
+fn test_min_max_size() {
+    // a fit turtle grows to its min size, and a fixed size is clamped
+    let walk_min = Walk {min_width: Some(80.0), max_height: Some(15.0), ..Walk::size(Size::Fit, Size::Fixed(40.0))};
+    let (outer, _) = layout(walk_min, Layout::flow_right(), None, | cx, areas | {
+        walk(cx, areas, size(30.0, 10.0));
+    });
+    assert_eq!(outer, rect(0.0, 0.0, 80.0, 15.0));
+
+    let (_, rects) = layout(size(300.0, 100.0), Layout::flow_right(), None, | cx, areas | {
+        walk(cx, areas, Walk {max_width: Some(120.0), ..Walk::fill()});
+        assert_eq!(cx.eval_width(&Walk {min_width: Some(200.0), ..size(10.0, 10.0)}), 200.0);
+        assert_eq!(cx.eval_height(&Walk {max_height: Some(40.0), ..Walk::fill()}), 40.0);
+    });
+    assert_eq!(rects, vec![rect(0.0, 0.0, 120.0, 100.0)]);
+}
+
+#[test]
+fn test_aspect_ratio() {
+    let (outer, rects) = layout(size(300.0, 100.0), Layout::flow_right(), None, | cx, areas | {
+        // the height follows from the width
+        walk(cx, areas, Walk {aspect_ratio: Some(2.0), ..Walk::size(Size::Fixed(40.0), Size::Fit)});
+        // both are known, so the walk shrinks to keep the ratio
+        walk(cx, areas, Walk {aspect_ratio: Some(0.5), ..Walk::size(Size::Fixed(40.0), Size::fill())});
+        walk(cx, areas, Walk {aspect_ratio: Some(4.0), ..Walk::size(Size::Fixed(40.0), Size::Fixed(100.0))});
+    });
+    assert_eq!(rects, vec![
+        rect(0.0, 0.0, 40.0, 20.0),
+        rect(40.0, 0.0, 40.0, 80.0),
+        rect(80.0, 0.0, 40.0, 10.0),
+    ]);
+    assert_eq!(outer, rect(0.0, 0.0, 300.0, 100.0));
+
+    // a fit turtle with a ratio gets its width from its fixed height
+    let (outer, _) = layout(Walk {aspect_ratio: Some(1.5), ..Walk::size(Size::Fit, Size::Fixed(40.0))}, Layout::flow_right(), None, | _, _ | {});
+    assert_eq!(outer, rect(0.0, 0.0, 60.0, 40.0));
+}
+
+#[test]
+fn test_walk_from_dsl() {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    makepad_draw_2d::live_design(&mut cx);
+    crate::live_design(&mut cx);
+    cx.live_expand();
+    let module_id = LiveModuleId::from_str(module_path!()).unwrap();
+    let walk = Walk::new_from_module(&mut cx, module_id, live_id!(ConstrainedWalk)).unwrap();
+    assert!(matches!(walk.width, Size::Fill(weight) if weight == 1.0));
+    assert!(matches!(walk.height, Size::Fill(weight) if weight == 2.0));
+    assert_eq!(walk.min_width, Some(30.0));
+    assert_eq!(walk.max_width, None);
+    assert_eq!(walk.aspect_ratio, Some(1.5));
+}
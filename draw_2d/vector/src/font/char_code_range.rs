@@ -0,0 +1,19 @@
+/// A range of char codes mapped by a cmap format 12 or 13 subtable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CharCodeRange {
+    pub start: u32,
+    pub end: u32,
+    pub start_glyph_index: usize,
+    /// Format 13 maps every char code in the range to the same glyph.
+    pub is_many_to_one: bool,
+}
+
+impl CharCodeRange {
+    pub fn glyph_index(&self, char_code: u32) -> usize {
+        if self.is_many_to_one {
+            self.start_glyph_index
+        } else {
+            self.start_glyph_index + (char_code - self.start) as usize
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+/// The colour glyphs of a font, from the `COLR`/`CPAL`, `CBDT`/`CBLC` and `sbix` tables.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColorGlyphs {
+    /// `COLR` glyphs, drawn as a stack of other glyphs from bottom to top.
+    pub layers: HashMap<usize, Vec<ColorLayer>>,
+    /// Bitmap strikes, sorted by size.
+    pub bitmap_strikes: Vec<BitmapStrike>,
+}
+
+/// A layer of a `COLR` glyph.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorLayer {
+    pub glyph_id: usize,
+    /// The colour from the first `CPAL` palette as RGBA, or `None` to use the text colour.
+    pub color: Option<[u8; 4]>,
+}
+
+/// The bitmaps of a font at one size.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BitmapStrike {
+    /// The size the bitmaps were made for, in pixels per em.
+    pub ppem: f64,
+    pub glyphs: HashMap<usize, BitmapGlyph>,
+}
+
+/// A glyph drawn as a PNG image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitmapGlyph {
+    /// The position of the bottom left corner of the image relative to the glyph origin, in
+    /// pixels of the strike with y pointing up.
+    pub left: f64,
+    pub bottom: f64,
+    pub png: Vec<u8>,
+}
+
+impl ColorGlyphs {
+    /// Returns the index of the strike to draw bitmaps at the given size with: the smallest one
+    /// that is at least as big, so it only has to be scaled down, or else the biggest one.
+    pub fn bitmap_strike_index(&self, ppem: f64) -> Option<usize> {
+        let index = self.bitmap_strikes.partition_point(|strike| strike.ppem < ppem);
+        if index < self.bitmap_strikes.len() {
+            Some(index)
+        } else {
+            self.bitmap_strikes.len().checked_sub(1)
+        }
+    }
+}
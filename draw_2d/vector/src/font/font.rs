@@ -1,4 +1,4 @@
-use crate::font::Glyph;
+use crate::font::{CharCodeRange, ColorGlyphs, Glyph, ShapedGlyph, ShapingTables};
 use crate::geometry::Rectangle;
 
 /// A font.
@@ -10,17 +10,74 @@ pub struct TTFFont {
     pub line_gap: f64,
     pub bounds: Rectangle,
     pub char_code_to_glyph_index_map: Vec<usize>,
+    /// The char codes above U+FFFF, sorted by char code.
+    pub supplementary_char_code_ranges: Vec<CharCodeRange>,
     pub glyphs: Vec<Glyph>,
+    pub shaping: ShapingTables,
+    pub color_glyphs: ColorGlyphs,
 }
 
 
 impl TTFFont{
     pub fn get_glyph(&self, c:char)->Option<&Glyph>{
-        if c < '\u{10000}' {
-            Some(&self.glyphs[self.char_code_to_glyph_index_map[c as usize]])
+        self.get_glyph_by_id(self.glyph_index(c))
+    }
+    
+    pub fn get_glyph_by_id(&self, glyph_id: usize) -> Option<&Glyph> {
+        self.glyphs.get(glyph_id)
+    }
+    
+    /// Returns the glyph index for a char, or 0 (the missing glyph) if the font doesn't have it.
+    pub fn glyph_index(&self, c: char) -> usize {
+        let char_code = c as u32;
+        let glyph_index = if char_code < 0x10000 {
+            self.char_code_to_glyph_index_map.get(char_code as usize).cloned().unwrap_or(0)
         } else {
-            None
+            let ranges = &self.supplementary_char_code_ranges;
+            match ranges.get(ranges.partition_point(|range| range.end < char_code)) {
+                Some(range) if range.start <= char_code => range.glyph_index(char_code),
+                _ => 0
+            }
+        };
+        if glyph_index < self.glyphs.len() {glyph_index} else {0}
+    }
+    
+    /// Returns whether the font has a glyph for every char in the text, not counting
+    /// invisible chars like joiners and variation selectors.
+    pub fn has_glyphs_for(&self, text: &str) -> bool {
+        text.chars().all(|c| is_default_ignorable(c) || self.glyph_index(c) != 0)
+    }
+    
+    /// Turns a string into positioned glyphs, applying ligatures, kerning and mark attachment.
+    pub fn shape(&self, text: &str) -> Vec<ShapedGlyph> {
+        let mut glyphs: Vec<ShapedGlyph> = text.char_indices().map(|(cluster, c)| ShapedGlyph {
+            glyph_id: self.glyph_index(c),
+            cluster,
+            char_count: 1,
+            x_advance: 0.0,
+            x_offset: 0.0,
+            y_offset: 0.0,
+        }).collect();
+        self.shaping.substitute_ligatures(&mut glyphs);
+        for glyph in &mut glyphs {
+            // a substitution to a glyph the font doesn't have shows the missing glyph
+            if glyph.glyph_id >= self.glyphs.len() {
+                glyph.glyph_id = 0;
+            }
+            glyph.x_advance = self.get_glyph_by_id(glyph.glyph_id)
+                .map_or(0.0, |glyph| glyph.horizontal_metrics.advance_width);
         }
+        self.shaping.position(&mut glyphs);
+        glyphs
     }
 }
 
+fn is_default_ignorable(c: char) -> bool {
+    matches!(c,
+        '\u{200B}'..='\u{200F}' |
+        '\u{2060}'..='\u{2064}' |
+        '\u{FE00}'..='\u{FE0F}' |
+        '\u{FEFF}' |
+        '\u{E0000}'..='\u{E0FFF}'
+    )
+}
@@ -1,12 +1,18 @@
 pub mod outline;
 
+mod char_code_range;
+mod color_glyphs;
 mod font;
 mod glyph;
 mod horizontal_metrics;
 mod outline_point;
+mod shaping;
 
+pub use self::char_code_range::CharCodeRange;
+pub use self::color_glyphs::{BitmapGlyph, BitmapStrike, ColorGlyphs, ColorLayer};
 pub use self::font::TTFFont;
 pub use self::glyph::Glyph;
 pub use self::horizontal_metrics::HorizontalMetrics;
 pub use self::outline::Outline;
 pub use self::outline_point::OutlinePoint;
+pub use self::shaping::{Anchor, Ligature, MarkAttachment, PairAdjustment, ShapedGlyph, ShapingTables};
@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+
+/// The parts of the `kern`, `GPOS` and `GSUB` tables used for shaping.
+///
+/// Lookups are only read for the `kern`, `mark`, `mkmk` and `liga` features of the default, Latin,
+/// Cyrillic and Greek scripts. All values are in font units.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShapingTables {
+    /// Pairs from a format 0 `kern` table, only used when there are no `GPOS` pair lookups.
+    pub kern_pairs: HashMap<(usize, usize), f64>,
+    /// `GPOS` pair adjustment lookups, each with its subtables in order.
+    pub pair_lookups: Vec<Vec<PairAdjustment>>,
+    /// `GSUB` ligatures, by the glyph they start with. Longer ligatures come first.
+    pub ligatures: HashMap<usize, Vec<Ligature>>,
+    /// `GPOS` mark to base attachment subtables.
+    pub mark_to_base: Vec<MarkAttachment>,
+    /// `GPOS` mark to mark attachment subtables.
+    pub mark_to_mark: Vec<MarkAttachment>,
+}
+
+/// A pair adjustment subtable, giving the advance adjustment for the first glyph of a pair.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PairAdjustment {
+    Pairs(HashMap<(usize, usize), f64>),
+    Classes {
+        coverage: HashSet<usize>,
+        first_classes: HashMap<usize, usize>,
+        second_classes: HashMap<usize, usize>,
+        second_class_count: usize,
+        x_advances: Vec<f64>,
+    },
+}
+
+impl PairAdjustment {
+    fn get(&self, first: usize, second: usize) -> Option<f64> {
+        match self {
+            PairAdjustment::Pairs(pairs) => pairs.get(&(first, second)).cloned(),
+            PairAdjustment::Classes {
+                coverage,
+                first_classes,
+                second_classes,
+                second_class_count,
+                x_advances,
+            } => {
+                if !coverage.contains(&first) {
+                    return None;
+                }
+                let first_class = first_classes.get(&first).cloned().unwrap_or(0);
+                let second_class = second_classes.get(&second).cloned().unwrap_or(0);
+                x_advances
+                    .get(first_class * second_class_count + second_class)
+                    .cloned()
+            }
+        }
+    }
+}
+
+/// A ligature, replacing its first glyph and `components` with `glyph`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ligature {
+    pub components: Vec<usize>,
+    pub glyph: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Anchor {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A mark attachment subtable. Each mark has a class, and each base glyph has an anchor per class.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarkAttachment {
+    pub marks: HashMap<usize, (usize, Anchor)>,
+    pub bases: HashMap<usize, Vec<Option<Anchor>>>,
+}
+
+impl MarkAttachment {
+    fn get(&self, base: usize, mark: usize) -> Option<(Anchor, Anchor)> {
+        let (class, mark_anchor) = self.marks.get(&mark)?;
+        let base_anchor = (*self.bases.get(&base)?.get(*class)?)?;
+        Some((base_anchor, *mark_anchor))
+    }
+}
+
+/// A glyph produced by shaping a string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapedGlyph {
+    pub glyph_id: usize,
+    /// The byte offset in the string of the first char this glyph was made from.
+    pub cluster: usize,
+    /// The number of chars this glyph stands for. Ligatures cover several chars, and attached
+    /// marks are counted with their base glyph, leaving them at zero.
+    pub char_count: usize,
+    pub x_advance: f64,
+    pub x_offset: f64,
+    pub y_offset: f64,
+}
+
+impl ShapingTables {
+    pub fn is_mark(&self, glyph_id: usize) -> bool {
+        self.mark_to_base
+            .iter()
+            .chain(self.mark_to_mark.iter())
+            .any(|attachment| attachment.marks.contains_key(&glyph_id))
+    }
+
+    /// Replaces runs of glyphs with their ligatures.
+    pub fn substitute_ligatures(&self, glyphs: &mut Vec<ShapedGlyph>) {
+        let mut index = 0;
+        while index < glyphs.len() {
+            if let Some(ligatures) = self.ligatures.get(&glyphs[index].glyph_id) {
+                let rest = &glyphs[index + 1..];
+                let ligature = ligatures.iter().find(|ligature| {
+                    ligature.components.len() <= rest.len()
+                        && ligature
+                            .components
+                            .iter()
+                            .zip(rest)
+                            .all(|(component, glyph)| *component == glyph.glyph_id)
+                });
+                if let Some(ligature) = ligature {
+                    let end = index + 1 + ligature.components.len();
+                    let char_count: usize = glyphs[index + 1..end].iter().map(|glyph| glyph.char_count).sum();
+                    glyphs[index].glyph_id = ligature.glyph;
+                    glyphs[index].char_count += char_count;
+                    glyphs.drain(index + 1..end);
+                }
+            }
+            index += 1;
+        }
+    }
+
+    /// Returns the kerning between two glyphs.
+    pub fn pair_adjustment(&self, first: usize, second: usize) -> f64 {
+        if self.pair_lookups.is_empty() {
+            return self.kern_pairs.get(&(first, second)).cloned().unwrap_or(0.0);
+        }
+        // within a lookup the first subtable that has the pair applies
+        self.pair_lookups
+            .iter()
+            .filter_map(|subtables| {
+                subtables
+                    .iter()
+                    .find_map(|subtable| subtable.get(first, second))
+            })
+            .sum()
+    }
+
+    /// Applies kerning and mark attachment to glyphs that have their default advances.
+    pub fn position(&self, glyphs: &mut [ShapedGlyph]) {
+        let is_mark: Vec<bool> = glyphs.iter().map(|glyph| self.is_mark(glyph.glyph_id)).collect();
+
+        // kerning skips over marks
+        let mut previous_base: Option<usize> = None;
+        for index in 0..glyphs.len() {
+            if is_mark[index] {
+                continue;
+            }
+            if let Some(previous_base) = previous_base {
+                glyphs[previous_base].x_advance +=
+                    self.pair_adjustment(glyphs[previous_base].glyph_id, glyphs[index].glyph_id);
+            }
+            previous_base = Some(index);
+        }
+
+        let mut pen_x = 0.0;
+        let mut pens = Vec::with_capacity(glyphs.len());
+        let mut base: Option<usize> = None;
+        for index in 0..glyphs.len() {
+            if !is_mark[index] {
+                base = Some(index);
+                pens.push(pen_x);
+                pen_x += glyphs[index].x_advance;
+                continue;
+            }
+            // a mark directly after another mark attaches to it if it can,
+            // otherwise to the base glyph before them
+            let attachment = if index > 0 && is_mark[index - 1] {
+                self.mark_to_mark
+                    .iter()
+                    .find_map(|attachment| attachment.get(glyphs[index - 1].glyph_id, glyphs[index].glyph_id))
+                    .map(|anchors| (index - 1, anchors))
+            } else {
+                None
+            }
+            .or_else(|| {
+                let base = base?;
+                self.mark_to_base
+                    .iter()
+                    .find_map(|attachment| attachment.get(glyphs[base].glyph_id, glyphs[index].glyph_id))
+                    .map(|anchors| (base, anchors))
+            });
+            match attachment {
+                Some((target, (target_anchor, mark_anchor))) => {
+                    glyphs[index].x_advance = 0.0;
+                    glyphs[index].x_offset =
+                        pens[target] + glyphs[target].x_offset + target_anchor.x - mark_anchor.x - pen_x;
+                    glyphs[index].y_offset = glyphs[target].y_offset + target_anchor.y - mark_anchor.y;
+                    pens.push(pen_x);
+                    // the mark moves with its base when hit testing
+                    if let Some(base) = base {
+                        glyphs[base].char_count += glyphs[index].char_count;
+                        glyphs[index].char_count = 0;
+                    }
+                }
+                None => {
+                    pens.push(pen_x);
+                    pen_x += glyphs[index].x_advance;
+                }
+            }
+        }
+    }
+}
@@ -39,6 +39,15 @@ impl AffineTransformation {
     pub fn translate(self, v: Vector) -> AffineTransformation {
         AffineTransformation::new(self.xy, self.z + v)
     }
+
+    pub fn compose(self, other: AffineTransformation) -> AffineTransformation {
+        AffineTransformation::new(self.xy.compose(other.xy), other.z.transform(&self.xy) + self.z)
+    }
+
+    pub fn invert(self) -> Option<AffineTransformation> {
+        let xy = self.xy.invert()?;
+        Some(AffineTransformation::new(xy, -self.z.transform(&xy)))
+    }
 }
 
 impl Transformation for AffineTransformation {
@@ -0,0 +1,131 @@
+use crate::geometry::{Point, QuadraticSegment, Transform, Transformation};
+use crate::internal_iter::InternalIterator;
+
+/// A cubic bezier curve segment in 2-dimensional Euclidian space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct CubicSegment {
+    pub p0: Point,
+    pub p1: Point,
+    pub p2: Point,
+    pub p3: Point,
+}
+
+impl CubicSegment {
+    /// Creates a new cubic bezier curve segment with the given control points.
+    pub fn new(p0: Point, p1: Point, p2: Point, p3: Point) -> CubicSegment {
+        CubicSegment { p0, p1, p2, p3 }
+    }
+
+    /// Returns true if `self` is approximately linear with tolerance `epsilon`.
+    pub fn is_approximately_linear(self, epsilon: f64) -> bool {
+        let distance = |p: Point| {
+            let v = p - self.p0;
+            if let Some(vx) = (self.p3 - self.p0).normalize() {
+                v.cross(vx).abs()
+            } else {
+                v.length()
+            }
+        };
+        distance(self.p1) < epsilon && distance(self.p2) < epsilon
+    }
+
+    /// Splits `self` into two cubic Bezier curve segments, at parameter `t`.
+    pub fn split(self, t: f64) -> (CubicSegment, CubicSegment) {
+        let p01 = self.p0.lerp(self.p1, t);
+        let p12 = self.p1.lerp(self.p2, t);
+        let p23 = self.p2.lerp(self.p3, t);
+        let p012 = p01.lerp(p12, t);
+        let p123 = p12.lerp(p23, t);
+        let p0123 = p012.lerp(p123, t);
+        (
+            CubicSegment::new(self.p0, p01, p012, p0123),
+            CubicSegment::new(p0123, p123, p23, self.p3),
+        )
+    }
+
+    /// Returns a sequence of quadratic Bezier curve segments that approximates `self` with
+    /// tolerance `epsilon`.
+    pub fn to_quadratics(self, epsilon: f64) -> Vec<QuadraticSegment> {
+        // The distance between a cubic and the quadratic through its midpoint is at most
+        // sqrt(3) / 36 times the length of its third difference, which shrinks with the cube of
+        // the number of pieces the cubic is split into.
+        let third_difference = (self.p3 - self.p0) - (self.p2 - self.p1) * 3.0;
+        let error = 3.0f64.sqrt() / 36.0 * third_difference.length();
+        let count = (error / epsilon).cbrt().ceil().max(1.0) as usize;
+        let mut quadratics = Vec::with_capacity(count);
+        let mut rest = self;
+        for index in 0..count {
+            let (piece, next_rest) = if index + 1 == count {
+                (rest, rest)
+            } else {
+                rest.split(1.0 / (count - index) as f64)
+            };
+            quadratics.push(piece.approximate_quadratic());
+            rest = next_rest;
+        }
+        quadratics
+    }
+
+    /// Returns an iterator over the points of a polyline that approximates `self` with tolerance
+    /// `epsilon`, *excluding* the first point.
+    pub fn linearize(self, epsilon: f64) -> Linearize {
+        Linearize {
+            segment: self,
+            epsilon,
+        }
+    }
+
+    fn approximate_quadratic(self) -> QuadraticSegment {
+        let control = self.p0
+            + ((self.p1 - self.p0) * 3.0 + (self.p2 - self.p0) * 3.0 - (self.p3 - self.p0)) / 4.0;
+        QuadraticSegment::new(self.p0, control, self.p3)
+    }
+}
+
+impl Transform for CubicSegment {
+    fn transform<T>(self, t: &T) -> CubicSegment
+    where
+        T: Transformation,
+    {
+        CubicSegment::new(
+            self.p0.transform(t),
+            self.p1.transform(t),
+            self.p2.transform(t),
+            self.p3.transform(t),
+        )
+    }
+
+    fn transform_mut<T>(&mut self, t: &T)
+    where
+        T: Transformation,
+    {
+        *self = self.transform(t);
+    }
+}
+
+/// An iterator over the points of a polyline that approximates `self` with tolerance `epsilon`,
+/// *excluding* the first point.
+#[derive(Clone, Copy)]
+pub struct Linearize {
+    segment: CubicSegment,
+    epsilon: f64,
+}
+
+impl InternalIterator for Linearize {
+    type Item = Point;
+
+    fn for_each<F>(self, f: &mut F) -> bool
+    where
+        F: FnMut(Point) -> bool,
+    {
+        if self.segment.is_approximately_linear(self.epsilon) {
+            return f(self.segment.p3);
+        }
+        let (segment_0, segment_1) = self.segment.split(0.5);
+        if !segment_0.linearize(self.epsilon).for_each(f) {
+            return false;
+        }
+        segment_1.linearize(self.epsilon).for_each(f)
+    }
+}
@@ -42,6 +42,24 @@ impl LineSegment {
             },
         })
     }
+
+    /// Returns the point where `self` and `other` cross, or None if they don't cross. Segments
+    /// that only touch at an endpoint, or that are parallel, don't cross.
+    pub fn intersect_with_line_segment(self, other: LineSegment) -> Option<Point> {
+        let d0 = self.p1 - self.p0;
+        let d1 = other.p1 - other.p0;
+        let denominator = d0.cross(d1);
+        if denominator == 0.0 {
+            return None;
+        }
+        let d = other.p0 - self.p0;
+        let t0 = d.cross(d1) / denominator;
+        let t1 = d.cross(d0) / denominator;
+        if t0 <= 0.0 || t0 >= 1.0 || t1 <= 0.0 || t1 >= 1.0 {
+            return None;
+        }
+        Some(self.p0.lerp(self.p1, t0))
+    }
 }
 
 impl Transform for LineSegment {
@@ -38,6 +38,21 @@ impl LinearTransformation {
             self.transform_vector(other.y),
         )
     }
+
+    pub fn determinant(self) -> f64 {
+        self.x.cross(self.y)
+    }
+
+    pub fn invert(self) -> Option<LinearTransformation> {
+        let determinant = self.determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+        Some(LinearTransformation::new(
+            Vector::new(self.y.y, -self.x.y) / determinant,
+            Vector::new(-self.y.x, self.x.x) / determinant,
+        ))
+    }
 }
 
 impl Transformation for LinearTransformation {
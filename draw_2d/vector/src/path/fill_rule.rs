@@ -0,0 +1,21 @@
+/// A rule that decides which regions enclosed by a path are inside it, based on the winding
+/// number of the region. The winding number counts how many times the contours of the path wind
+/// around the region, with contours that go the other way counting negatively.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum FillRule {
+    /// A region is inside if its winding number is not zero.
+    #[default]
+    NonZero,
+    /// A region is inside if its winding number is odd.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Returns `true` if a region with the given winding number is inside.
+    pub fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
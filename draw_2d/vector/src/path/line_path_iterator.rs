@@ -1,7 +1,16 @@
-use crate::path::LinePathCommand;
+use crate::path::{stroke, LinePath, LinePathCommand, StrokeStyle};
 use crate::internal_iter::InternalIterator;
 
 /// An extension trait for iterators over line path commands.
-pub trait LinePathIterator: InternalIterator<Item = LinePathCommand> {}
+pub trait LinePathIterator: InternalIterator<Item = LinePathCommand> {
+    /// Returns a line path that outlines the stroke of `self` with the given style, approximating
+    /// round joins and caps with tolerance `epsilon`.
+    fn stroke(self, style: &StrokeStyle, epsilon: f64) -> LinePath
+    where
+        Self: Sized,
+    {
+        stroke(self, style, epsilon)
+    }
+}
 
 impl<I> LinePathIterator for I where I: InternalIterator<Item = LinePathCommand> {}
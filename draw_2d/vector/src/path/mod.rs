@@ -1,14 +1,20 @@
 pub mod line_path;
 pub mod path;
 
+mod fill_rule;
 mod line_path_command;
 mod line_path_iterator;
 mod path_command;
 mod path_iterator;
+mod stroker;
+mod svg_path;
 
+pub use self::fill_rule::FillRule;
 pub use self::line_path::LinePath;
 pub use self::line_path_command::LinePathCommand;
 pub use self::line_path_iterator::LinePathIterator;
 pub use self::path::Path;
 pub use self::path_command::PathCommand;
 pub use self::path_iterator::PathIterator;
+pub use self::stroker::{stroke, LineCap, LineJoin, StrokeStyle};
+pub use self::svg_path::{parse_svg_path, SvgPathError};
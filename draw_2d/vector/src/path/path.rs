@@ -1,5 +1,5 @@
 use crate::path::PathCommand;
-use crate::geometry::{Point, Transform, Transformation};
+use crate::geometry::{Point, Transform, Transformation, Vector};
 use crate::internal_iter::{
     ExtendFromInternalIterator, FromInternalIterator, InternalIterator, IntoInternalIterator,
 };
@@ -12,6 +12,7 @@ use std::slice::Iter;
 pub struct Path {
     verbs: Vec<Verb>,
     points: Vec<Point>,
+    contour_start: usize,
 }
 
 impl Path {
@@ -40,6 +41,7 @@ impl Path {
 
     /// Adds a new contour, starting at the given point.
     pub fn move_to(&mut self, p: Point) {
+        self.contour_start = self.points.len();
         self.verbs.push(Verb::MoveTo);
         self.points.push(p);
     }
@@ -57,6 +59,87 @@ impl Path {
         self.points.push(p);
     }
 
+    /// Adds a cubic Bezier curve segment to the current contour, starting at the current point.
+    pub fn cubic_to(&mut self, p1: Point, p2: Point, p: Point) {
+        self.verbs.push(Verb::CubicTo);
+        self.points.push(p1);
+        self.points.push(p2);
+        self.points.push(p);
+    }
+
+    /// Adds an elliptical arc to the current contour, from the current point to `p`, as cubic
+    /// Bezier curve segments.
+    ///
+    /// The arc is specified like in SVG: the ellipse has radii `radii` and is rotated by
+    /// `x_axis_rotation` radians. Of the four arcs that fit, `large_arc` picks one that spans more
+    /// than 180 degrees, and `sweep` one that goes in the direction of increasing angles. Radii
+    /// that are too small are scaled up until the ellipse fits.
+    pub fn arc_to(&mut self, radii: Vector, x_axis_rotation: f64, large_arc: bool, sweep: bool, p: Point) {
+        let p0 = self.current_point().unwrap_or(p);
+        let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+        if p0 == p {
+            return;
+        }
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(p);
+            return;
+        }
+        let (sin, cos) = x_axis_rotation.sin_cos();
+        // The endpoints in the coordinate system of the ellipse, relative to the midpoint.
+        let half_chord = (p0 - p) / 2.0;
+        let x1 = cos * half_chord.x + sin * half_chord.y;
+        let y1 = -sin * half_chord.x + cos * half_chord.y;
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut factor = (numerator / denominator).max(0.0).sqrt();
+        if large_arc == sweep {
+            factor = -factor;
+        }
+        let cx1 = factor * rx * y1 / ry;
+        let cy1 = -factor * ry * x1 / rx;
+        let midpoint = p0.lerp(p, 0.5);
+        let center = midpoint + Vector::new(cos * cx1 - sin * cy1, sin * cx1 + cos * cy1);
+        let angle = |x: f64, y: f64| y.atan2(x);
+        let start_angle = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let end_angle = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        let mut sweep_angle = end_angle - start_angle;
+        if sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * std::f64::consts::PI;
+        } else if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * std::f64::consts::PI;
+        }
+
+        // Each piece of at most 90 degrees is approximated by a cubic.
+        let point_at = |angle: f64| {
+            let (sin_angle, cos_angle) = angle.sin_cos();
+            let (x, y) = (rx * cos_angle, ry * sin_angle);
+            center + Vector::new(cos * x - sin * y, sin * x + cos * y)
+        };
+        let tangent_at = |angle: f64| {
+            let (sin_angle, cos_angle) = angle.sin_cos();
+            let (x, y) = (-rx * sin_angle, ry * cos_angle);
+            Vector::new(cos * x - sin * y, sin * x + cos * y)
+        };
+        let count = (sweep_angle.abs() / std::f64::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+        let piece_angle = sweep_angle / count as f64;
+        let k = 4.0 / 3.0 * (piece_angle / 4.0).tan();
+        for index in 0..count {
+            let angle_0 = start_angle + piece_angle * index as f64;
+            let angle_1 = angle_0 + piece_angle;
+            let end = if index + 1 == count { p } else { point_at(angle_1) };
+            self.cubic_to(
+                point_at(angle_0) + tangent_at(angle_0) * k,
+                point_at(angle_1) - tangent_at(angle_1) * k,
+                end,
+            );
+        }
+    }
+
     /// Closes the current contour.
     pub fn close(&mut self) {
         self.verbs.push(Verb::Close);
@@ -66,6 +149,16 @@ impl Path {
     pub fn clear(&mut self) {
         self.verbs.clear();
         self.points.clear();
+        self.contour_start = 0;
+    }
+
+    /// Returns the point the next segment starts at, which is the start of the current contour
+    /// if it was just closed.
+    pub fn current_point(&self) -> Option<Point> {
+        match self.verbs.last() {
+            Some(Verb::Close) => self.points.get(self.contour_start).cloned(),
+            _ => self.points.last().cloned(),
+        }
     }
 }
 
@@ -79,6 +172,7 @@ impl ExtendFromInternalIterator<PathCommand> for Path {
                 PathCommand::MoveTo(p) => self.move_to(p),
                 PathCommand::LineTo(p) => self.line_to(p),
                 PathCommand::QuadraticTo(p1, p) => self.quadratic_to(p1, p),
+                PathCommand::CubicTo(p1, p2, p) => self.cubic_to(p1, p2, p),
                 PathCommand::Close => self.close(),
             }
             true
@@ -133,6 +227,11 @@ impl<'a> Iterator for Commands<'a> {
             Verb::QuadraticTo => {
                 PathCommand::QuadraticTo(self.points.next().unwrap(), self.points.next().unwrap())
             }
+            Verb::CubicTo => PathCommand::CubicTo(
+                self.points.next().unwrap(),
+                self.points.next().unwrap(),
+                self.points.next().unwrap(),
+            ),
             Verb::Close => PathCommand::Close,
         })
     }
@@ -143,5 +242,6 @@ enum Verb {
     MoveTo,
     LineTo,
     QuadraticTo,
+    CubicTo,
     Close,
 }
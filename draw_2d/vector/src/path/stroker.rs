@@ -0,0 +1,359 @@
+use crate::geometry::{Point, Vector};
+use crate::path::{LinePath, LinePathCommand, LinePathIterator};
+use std::f64::consts::PI;
+
+/// The shape at the ends of open contours.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end point.
+    #[default]
+    Butt,
+    /// The stroke ends with a half circle around the end point.
+    Round,
+    /// The stroke ends with a half square around the end point.
+    Square,
+}
+
+/// The shape at the corners between two segments.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet, unless that exceeds the miter limit, in
+    /// which case the corner is beveled.
+    #[default]
+    Miter,
+    /// The corner is rounded off with a circle around the corner point.
+    Round,
+    /// The corner is cut off with a straight line between the outer edges.
+    Bevel,
+}
+
+/// The parameters of a stroke.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrokeStyle {
+    /// The width of the stroke.
+    pub width: f64,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    /// The maximum ratio of the length of a miter to the width of the stroke.
+    pub miter_limit: f64,
+    /// The lengths of the alternating dashes and gaps, starting with a dash. The pattern is
+    /// repeated twice if it has an odd number of entries. An empty pattern draws a solid stroke.
+    pub dashes: Vec<f64>,
+    /// The distance into the dash pattern at which each contour starts.
+    pub dash_offset: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> StrokeStyle {
+        StrokeStyle {
+            width: 1.0,
+            cap: LineCap::default(),
+            join: LineJoin::default(),
+            miter_limit: 4.0,
+            dashes: Vec::new(),
+            dash_offset: 0.0,
+        }
+    }
+}
+
+/// Returns a line path whose interior, under the non-zero fill rule, is the outline of the stroke
+/// of `path` with the given style. Round joins and caps are approximated with tolerance
+/// `epsilon`.
+///
+/// The line path is a union of overlapping convex polygons that all wind the same way, so it
+/// can be passed to the `Trapezoidator` as is.
+pub fn stroke<P: LinePathIterator>(path: P, style: &StrokeStyle, epsilon: f64) -> LinePath {
+    let mut polylines = Vec::new();
+    let mut points: Vec<Point> = Vec::new();
+    path.for_each(&mut |command| {
+        match command {
+            LinePathCommand::MoveTo(p) => {
+                // A lone move doesn't draw anything, unlike a segment without length.
+                if points.len() > 1 {
+                    polylines.push(Polyline::new(points.split_off(0), false));
+                }
+                points.clear();
+                points.push(p);
+            }
+            LinePathCommand::LineTo(p) => points.push(p),
+            LinePathCommand::Close => {
+                if let Some(&p) = points.first() {
+                    polylines.push(Polyline::new(points.split_off(0), true));
+                    // A contour that is continued after closing it starts at the same point.
+                    points.push(p);
+                }
+            }
+        }
+        true
+    });
+    if points.len() > 1 {
+        polylines.push(Polyline::new(points, false));
+    }
+
+    let mut stroker = Stroker {
+        style,
+        half_width: style.width / 2.0,
+        epsilon,
+        output: LinePath::new(),
+    };
+    if stroker.half_width <= 0.0 {
+        return stroker.output;
+    }
+    match DashPattern::new(style) {
+        Some(pattern) => {
+            for polyline in &polylines {
+                for dash in pattern.apply(polyline) {
+                    stroker.stroke_polyline(&dash);
+                }
+            }
+        }
+        None => {
+            for polyline in &polylines {
+                stroker.stroke_polyline(polyline);
+            }
+        }
+    }
+    stroker.output
+}
+
+#[derive(Clone, Debug)]
+struct Polyline {
+    points: Vec<Point>,
+    is_closed: bool,
+}
+
+impl Polyline {
+    fn new(mut points: Vec<Point>, is_closed: bool) -> Polyline {
+        points.dedup();
+        if is_closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        Polyline { points, is_closed }
+    }
+}
+
+struct DashPattern {
+    dashes: Vec<f64>,
+    start_index: usize,
+    start_remaining: f64,
+}
+
+impl DashPattern {
+    fn new(style: &StrokeStyle) -> Option<DashPattern> {
+        let mut dashes = style.dashes.clone();
+        if dashes.iter().any(|&dash| dash < 0.0 || !dash.is_finite()) {
+            return None;
+        }
+        if !dashes.len().is_multiple_of(2) {
+            dashes.extend_from_within(..);
+        }
+        let total: f64 = dashes.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut offset = style.dash_offset.rem_euclid(total);
+        let mut start_index = 0;
+        while offset >= dashes[start_index] && start_index + 1 < dashes.len() {
+            offset -= dashes[start_index];
+            start_index += 1;
+        }
+        Some(DashPattern {
+            start_remaining: dashes[start_index] - offset,
+            dashes,
+            start_index,
+        })
+    }
+
+    fn apply(&self, polyline: &Polyline) -> Vec<Polyline> {
+        let mut points = polyline.points.clone();
+        if polyline.is_closed && points.len() > 1 {
+            points.push(points[0]);
+        }
+        let mut dashes = Vec::new();
+        let mut index = self.start_index;
+        let mut remaining = self.start_remaining;
+        let mut current: Vec<Point> = Vec::new();
+        if index.is_multiple_of(2) {
+            current.push(points[0]);
+        }
+        for segment in points.windows(2) {
+            let (p0, p1) = (segment[0], segment[1]);
+            let length = (p1 - p0).length();
+            let mut distance = 0.0;
+            while length - distance > remaining {
+                distance += remaining;
+                let p = p0.lerp(p1, distance / length);
+                current.push(p);
+                if index.is_multiple_of(2) {
+                    dashes.push(Polyline::new(current.split_off(0), false));
+                }
+                index = (index + 1) % self.dashes.len();
+                remaining = self.dashes[index];
+            }
+            remaining -= length - distance;
+            if index.is_multiple_of(2) {
+                current.push(p1);
+            }
+        }
+        if index.is_multiple_of(2) && !current.is_empty() {
+            dashes.push(Polyline::new(current, false));
+        }
+        dashes
+    }
+}
+
+struct Stroker<'a> {
+    style: &'a StrokeStyle,
+    half_width: f64,
+    epsilon: f64,
+    output: LinePath,
+}
+
+impl<'a> Stroker<'a> {
+    fn stroke_polyline(&mut self, polyline: &Polyline) {
+        let points = &polyline.points;
+        if points.len() == 1 {
+            self.push_dot(points[0]);
+            return;
+        }
+        let segment_count = if polyline.is_closed {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+        for index in 0..segment_count {
+            let p0 = points[index];
+            let p1 = points[(index + 1) % points.len()];
+            let normal = normal(p1 - p0) * self.half_width;
+            self.push_polygon(&[p0 + normal, p1 + normal, p1 - normal, p0 - normal]);
+        }
+        if polyline.is_closed {
+            for index in 0..points.len() {
+                let p0 = points[(index + points.len() - 1) % points.len()];
+                let p1 = points[(index + 1) % points.len()];
+                self.push_join(p0, points[index], p1);
+            }
+        } else {
+            for segment in points.windows(3) {
+                self.push_join(segment[0], segment[1], segment[2]);
+            }
+            self.push_cap(points[1], points[0]);
+            self.push_cap(points[points.len() - 2], points[points.len() - 1]);
+        }
+    }
+
+    fn push_join(&mut self, p0: Point, p: Point, p1: Point) {
+        let d0 = (p - p0).normalize().unwrap();
+        let d1 = (p1 - p).normalize().unwrap();
+        let cross = d0.cross(d1);
+        let cos = d0.dot(d1);
+        if cross == 0.0 && cos > 0.0 {
+            return;
+        }
+        if self.style.join == LineJoin::Round {
+            self.push_circle(p);
+            return;
+        }
+        // The outer side of the corner is opposite to the direction of the turn.
+        let sign = if cross > 0.0 { -1.0 } else { 1.0 };
+        let a = p + normal(d0) * (sign * self.half_width);
+        let b = p + normal(d1) * (sign * self.half_width);
+        if self.style.join == LineJoin::Miter {
+            // The ratio of the length of the miter to the width of the stroke is 1 / cos(φ / 2),
+            // where φ is the angle the path turns by.
+            let half_cos = ((1.0 + cos) / 2.0).sqrt();
+            if half_cos > 0.0 && 1.0 / half_cos <= self.style.miter_limit {
+                if let Some(direction) = ((a - p) + (b - p)).normalize() {
+                    let m = p + direction * (self.half_width / half_cos);
+                    self.push_polygon(&[p, a, m, b]);
+                    return;
+                }
+            }
+        }
+        self.push_polygon(&[p, a, b]);
+    }
+
+    fn push_cap(&mut self, p0: Point, p: Point) {
+        let direction = (p - p0).normalize().unwrap();
+        let normal = normal(direction) * self.half_width;
+        match self.style.cap {
+            LineCap::Butt => {}
+            LineCap::Round => self.push_circle(p),
+            LineCap::Square => {
+                let extension = direction * self.half_width;
+                self.push_polygon(&[
+                    p + normal,
+                    p + normal + extension,
+                    p - normal + extension,
+                    p - normal,
+                ]);
+            }
+        }
+    }
+
+    // A contour without length only shows up with round or square caps.
+    fn push_dot(&mut self, p: Point) {
+        match self.style.cap {
+            LineCap::Butt => {}
+            LineCap::Round => self.push_circle(p),
+            LineCap::Square => {
+                let h = self.half_width;
+                self.push_polygon(&[
+                    p + Vector::new(-h, -h),
+                    p + Vector::new(h, -h),
+                    p + Vector::new(h, h),
+                    p + Vector::new(-h, h),
+                ]);
+            }
+        }
+    }
+
+    fn push_circle(&mut self, p: Point) {
+        let r = self.half_width;
+        // Each chord deviates from the circle by at most `epsilon`.
+        let max_angle = 2.0 * (1.0 - (self.epsilon / r).min(1.0)).acos();
+        // A multiple of 4 keeps the polygon symmetric around both axes, so that round caps are
+        // equally long on both ends of a horizontal or vertical line.
+        let count = ((2.0 * PI / max_angle).ceil() as usize).clamp(8, 1024).div_ceil(4) * 4;
+        let points: Vec<Point> = (0..count)
+            .map(|index| {
+                let (sin, cos) = (2.0 * PI * index as f64 / count as f64).sin_cos();
+                p + Vector::new(cos * r, sin * r)
+            })
+            .collect();
+        self.push_polygon(&points);
+    }
+
+    // All polygons are pushed with the same winding, so that they add up under the non-zero
+    // fill rule instead of cancelling each other out.
+    fn push_polygon(&mut self, points: &[Point]) {
+        let area: f64 = (0..points.len())
+            .map(|index| {
+                let p0 = points[index].to_vector();
+                let p1 = points[(index + 1) % points.len()].to_vector();
+                p0.cross(p1)
+            })
+            .sum();
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+        let (&first, rest) = points.split_first().unwrap();
+        self.output.move_to(first);
+        if area > 0.0 {
+            for &p in rest {
+                self.output.line_to(p);
+            }
+        } else {
+            for &p in rest.iter().rev() {
+                self.output.line_to(p);
+            }
+        }
+        self.output.close();
+    }
+}
+
+// Returns the unit normal to the left of `vector`.
+fn normal(vector: Vector) -> Vector {
+    let direction = vector.normalize().unwrap();
+    Vector::new(-direction.y, direction.x)
+}
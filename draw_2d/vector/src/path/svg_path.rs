@@ -0,0 +1,302 @@
+use crate::geometry::{Point, Vector};
+use crate::path::Path;
+use std::error::Error;
+use std::fmt;
+
+/// Parses the contents of an SVG `d` attribute into a path.
+///
+/// All commands are supported, in both their absolute and relative forms. Elliptical arcs are
+/// converted to cubic Bezier curve segments.
+pub fn parse_svg_path(d: &str) -> Result<Path, SvgPathError> {
+    let mut parser = Parser {
+        bytes: d.as_bytes(),
+        position: 0,
+        path: Path::new(),
+        initial_point: Point::origin(),
+        current_point: Point::origin(),
+        last_control_point: None,
+        needs_move_to: false,
+    };
+    parser.parse()?;
+    Ok(parser.path)
+}
+
+/// An error that occurs while parsing an SVG path.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SvgPathError {
+    /// The byte offset of the first character that could not be parsed.
+    pub position: usize,
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid SVG path data at offset {}", self.position)
+    }
+}
+
+impl Error for SvgPathError {}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ControlPoint {
+    Quadratic,
+    Cubic,
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    path: Path,
+    initial_point: Point,
+    current_point: Point,
+    // The last control point of the previous segment, for the smooth curve commands.
+    last_control_point: Option<(ControlPoint, Point)>,
+    // A closed contour is followed by a new one that starts at the same point, unless the next
+    // command is a move.
+    needs_move_to: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(&mut self) -> Result<(), SvgPathError> {
+        self.skip_whitespace();
+        if self.position < self.bytes.len() && !matches!(self.peek(), Some(b'M' | b'm')) {
+            return Err(self.error());
+        }
+        while let Some(command) = self.peek() {
+            if !command.is_ascii_alphabetic() {
+                return Err(self.error());
+            }
+            self.position += 1;
+            self.parse_command(command)?;
+            self.skip_whitespace();
+        }
+        Ok(())
+    }
+
+    fn parse_command(&mut self, command: u8) -> Result<(), SvgPathError> {
+        let relative = command.is_ascii_lowercase();
+        let command = command.to_ascii_uppercase();
+        if command == b'Z' {
+            self.path.close();
+            self.current_point = self.initial_point;
+            self.last_control_point = None;
+            self.needs_move_to = true;
+            return Ok(());
+        }
+        let mut first = true;
+        loop {
+            if first {
+                self.skip_whitespace();
+            } else {
+                self.skip_separator();
+                if !self.at_number() {
+                    return Ok(());
+                }
+            }
+            let origin = if relative {
+                self.current_point.to_vector()
+            } else {
+                Vector::zero()
+            };
+            match command {
+                b'M' if first => {
+                    let p = self.parse_point()? + origin;
+                    self.path.move_to(p);
+                    self.initial_point = p;
+                    self.current_point = p;
+                    self.last_control_point = None;
+                    self.needs_move_to = false;
+                }
+                // Subsequent pairs of coordinates after a move are implicit line commands.
+                b'M' | b'L' => {
+                    let p = self.parse_point()? + origin;
+                    self.line_to(p);
+                }
+                b'H' => {
+                    let x = self.parse_number()? + origin.x;
+                    self.line_to(Point::new(x, self.current_point.y));
+                }
+                b'V' => {
+                    let y = self.parse_number()? + origin.y;
+                    self.line_to(Point::new(self.current_point.x, y));
+                }
+                b'C' => {
+                    let p1 = self.parse_point()? + origin;
+                    self.skip_separator();
+                    let p2 = self.parse_point()? + origin;
+                    self.skip_separator();
+                    let p = self.parse_point()? + origin;
+                    self.cubic_to(p1, p2, p);
+                }
+                b'S' => {
+                    let p1 = self.reflected_control_point(ControlPoint::Cubic);
+                    let p2 = self.parse_point()? + origin;
+                    self.skip_separator();
+                    let p = self.parse_point()? + origin;
+                    self.cubic_to(p1, p2, p);
+                }
+                b'Q' => {
+                    let p1 = self.parse_point()? + origin;
+                    self.skip_separator();
+                    let p = self.parse_point()? + origin;
+                    self.quadratic_to(p1, p);
+                }
+                b'T' => {
+                    let p1 = self.reflected_control_point(ControlPoint::Quadratic);
+                    let p = self.parse_point()? + origin;
+                    self.quadratic_to(p1, p);
+                }
+                b'A' => {
+                    let rx = self.parse_number()?;
+                    self.skip_separator();
+                    let ry = self.parse_number()?;
+                    self.skip_separator();
+                    let x_axis_rotation = self.parse_number()?;
+                    self.skip_separator();
+                    let large_arc = self.parse_flag()?;
+                    self.skip_separator();
+                    let sweep = self.parse_flag()?;
+                    self.skip_separator();
+                    let p = self.parse_point()? + origin;
+                    self.begin_segment();
+                    self.path.arc_to(
+                        Vector::new(rx, ry),
+                        x_axis_rotation.to_radians(),
+                        large_arc,
+                        sweep,
+                        p,
+                    );
+                    self.current_point = p;
+                    self.last_control_point = None;
+                }
+                _ => return Err(SvgPathError { position: self.position - 1 }),
+            }
+            first = false;
+        }
+    }
+
+    fn begin_segment(&mut self) {
+        if self.needs_move_to {
+            self.path.move_to(self.current_point);
+            self.needs_move_to = false;
+        }
+    }
+
+    fn line_to(&mut self, p: Point) {
+        self.begin_segment();
+        self.path.line_to(p);
+        self.current_point = p;
+        self.last_control_point = None;
+    }
+
+    fn quadratic_to(&mut self, p1: Point, p: Point) {
+        self.begin_segment();
+        self.path.quadratic_to(p1, p);
+        self.current_point = p;
+        self.last_control_point = Some((ControlPoint::Quadratic, p1));
+    }
+
+    fn cubic_to(&mut self, p1: Point, p2: Point, p: Point) {
+        self.begin_segment();
+        self.path.cubic_to(p1, p2, p);
+        self.current_point = p;
+        self.last_control_point = Some((ControlPoint::Cubic, p2));
+    }
+
+    fn reflected_control_point(&self, kind: ControlPoint) -> Point {
+        match self.last_control_point {
+            Some((last_kind, p)) if last_kind == kind => {
+                self.current_point + (self.current_point - p)
+            }
+            _ => self.current_point,
+        }
+    }
+
+    fn parse_point(&mut self) -> Result<Point, SvgPathError> {
+        let x = self.parse_number()?;
+        self.skip_separator();
+        let y = self.parse_number()?;
+        Ok(Point::new(x, y))
+    }
+
+    fn parse_flag(&mut self) -> Result<bool, SvgPathError> {
+        // Flags are a single digit, so they can be written without separators, as in `a1 1 0 00 1 1`.
+        match self.peek() {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                Ok(true)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<f64, SvgPathError> {
+        let start = self.position;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.position += 1;
+        }
+        let integer_digits = self.skip_digits();
+        let mut fraction_digits = 0;
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            fraction_digits = self.skip_digits();
+        }
+        if integer_digits == 0 && fraction_digits == 0 {
+            self.position = start;
+            return Err(self.error());
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let exponent_start = self.position;
+            self.position += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            if self.skip_digits() == 0 {
+                self.position = exponent_start;
+            }
+        }
+        // The slice only contains ASCII digits, signs, dots and exponents.
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .unwrap()
+            .parse()
+            .map_err(|_| SvgPathError { position: start })
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.position;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.position += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    fn at_number(&self) -> bool {
+        self.peek()
+            .is_some_and(|byte| byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.'))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).cloned()
+    }
+
+    fn error(&self) -> SvgPathError {
+        SvgPathError { position: self.position }
+    }
+}
@@ -0,0 +1,167 @@
+//! A parser and rasterizer for the subset of SVG that icons and illustrations use: paths, basic
+//! shapes, groups, `use` references, transforms, solid fills and strokes, linear and radial
+//! gradients, and the `viewBox` of the root element.
+
+mod parser;
+mod rasterizer;
+mod xml;
+
+pub use self::parser::parse_svg;
+
+use crate::geometry::{AffineTransformation, Point, Rectangle, Vector};
+use crate::path::{FillRule, Path, StrokeStyle};
+use std::error::Error;
+use std::fmt;
+
+/// A parsed SVG document, flattened into a list of shapes in painting order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgDocument {
+    /// The intrinsic width of the document, in pixels.
+    pub width: f64,
+    /// The intrinsic height of the document, in pixels.
+    pub height: f64,
+    /// The rectangle in user space that is mapped to the viewport.
+    pub view_box: Rectangle,
+    pub preserve_aspect_ratio: SvgPreserveAspectRatio,
+    pub shapes: Vec<SvgShape>,
+}
+
+impl SvgDocument {
+    /// Returns the transformation from user space to a viewport of the given size.
+    pub fn view_box_transformation(&self, width: f64, height: f64) -> AffineTransformation {
+        let view_box_size = self.view_box.p_max - self.view_box.p_min;
+        if view_box_size.x <= 0.0 || view_box_size.y <= 0.0 {
+            return AffineTransformation::identity();
+        }
+        let mut scale = Vector::new(width / view_box_size.x, height / view_box_size.y);
+        let mut offset = Vector::zero();
+        if let Some(align) = self.preserve_aspect_ratio.align {
+            let uniform_scale = if self.preserve_aspect_ratio.slice {
+                scale.x.max(scale.y)
+            } else {
+                scale.x.min(scale.y)
+            };
+            scale = Vector::new(uniform_scale, uniform_scale);
+            offset = Vector::new(
+                (width - view_box_size.x * uniform_scale) * align.x,
+                (height - view_box_size.y * uniform_scale) * align.y,
+            );
+        }
+        AffineTransformation::translation(-self.view_box.p_min.to_vector())
+            .scale(scale)
+            .translate(offset)
+    }
+}
+
+/// How the view box is fitted into a viewport with a different aspect ratio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgPreserveAspectRatio {
+    /// Where the view box is aligned within the viewport, from 0 to 1 on each axis, or `None`
+    /// if it is stretched to fill the viewport.
+    pub align: Option<Vector>,
+    /// Whether the view box is scaled to cover the viewport instead of fitting inside it.
+    pub slice: bool,
+}
+
+impl Default for SvgPreserveAspectRatio {
+    fn default() -> SvgPreserveAspectRatio {
+        SvgPreserveAspectRatio {
+            align: Some(Vector::new(0.5, 0.5)),
+            slice: false,
+        }
+    }
+}
+
+/// A path with the paint it is filled and stroked with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgShape {
+    /// The path, in the local coordinate system of the shape.
+    pub path: Path,
+    /// The transformation from the local coordinate system to user space.
+    pub transform: AffineTransformation,
+    pub fill: Option<SvgFill>,
+    pub stroke: Option<SvgStroke>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgFill {
+    pub paint: SvgPaint,
+    pub opacity: f64,
+    pub rule: FillRule,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgStroke {
+    pub paint: SvgPaint,
+    pub opacity: f64,
+    pub style: StrokeStyle,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SvgPaint {
+    /// A solid color, as non-premultiplied RGBA.
+    Color([f32; 4]),
+    Gradient(SvgGradient),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgGradient {
+    pub kind: SvgGradientKind,
+    pub units: SvgGradientUnits,
+    /// The transformation from the coordinate system of the gradient to the one given by
+    /// `units`.
+    pub transform: AffineTransformation,
+    pub spread: SvgSpreadMethod,
+    pub stops: Vec<SvgGradientStop>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SvgGradientKind {
+    Linear { start: Point, end: Point },
+    Radial { center: Point, radius: f64, focus: Point },
+}
+
+/// The coordinate system the geometry of a gradient is specified in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SvgGradientUnits {
+    /// The bounding box of the shape maps to the unit square.
+    ObjectBoundingBox,
+    /// The local coordinate system of the shape.
+    UserSpaceOnUse,
+}
+
+/// What a gradient does beyond its start and end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SvgSpreadMethod {
+    Pad,
+    Reflect,
+    Repeat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgGradientStop {
+    pub offset: f64,
+    /// The color of the stop, as non-premultiplied RGBA.
+    pub color: [f32; 4],
+}
+
+/// An error that occurs while parsing an SVG document.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SvgError {
+    /// The document is not well-formed XML. The position is the byte offset where parsing
+    /// failed.
+    Xml { position: usize },
+    /// The root element is not an `svg` element.
+    NotSvg,
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SvgError::Xml { position } => write!(f, "invalid XML at offset {}", position),
+            SvgError::NotSvg => write!(f, "the root element is not an svg element"),
+        }
+    }
+}
+
+impl Error for SvgError {}
@@ -0,0 +1,835 @@
+use crate::geometry::{AffineTransformation, LinearTransformation, Point, Rectangle, Vector};
+use crate::path::{parse_svg_path, FillRule, LineCap, LineJoin, Path, StrokeStyle};
+use crate::svg::xml::{parse_xml, XmlElement};
+use crate::svg::{
+    SvgDocument, SvgError, SvgFill, SvgGradient, SvgGradientKind, SvgGradientStop,
+    SvgGradientUnits, SvgPaint, SvgPreserveAspectRatio, SvgShape, SvgSpreadMethod, SvgStroke,
+};
+use std::collections::HashMap;
+
+// The size CSS gives an SVG without a width or height.
+const DEFAULT_WIDTH: f64 = 300.0;
+const DEFAULT_HEIGHT: f64 = 150.0;
+
+// `use` elements can refer to each other, so their nesting is limited.
+const MAX_USE_DEPTH: usize = 16;
+
+/// Parses an SVG document.
+///
+/// Elements and attributes outside of the supported subset are ignored, as are shapes whose
+/// geometry can't be parsed.
+pub fn parse_svg(source: &str) -> Result<SvgDocument, SvgError> {
+    let root = parse_xml(source)?;
+    if local_name(&root.name) != "svg" {
+        return Err(SvgError::NotSvg);
+    }
+
+    let view_box = root.attribute("viewBox").and_then(|value| {
+        let numbers = parse_numbers(value)?;
+        if numbers.len() != 4 || numbers[2] <= 0.0 || numbers[3] <= 0.0 {
+            return None;
+        }
+        Some(Rectangle::new(
+            Point::new(numbers[0], numbers[1]),
+            Point::new(numbers[0] + numbers[2], numbers[1] + numbers[3]),
+        ))
+    });
+    // Percentages are relative to a viewport we don't know, so they count as unspecified.
+    let length = |name| {
+        root.attribute(name)
+            .filter(|value| !value.trim_end().ends_with('%'))
+            .and_then(|value| parse_length(value, 0.0))
+    };
+    let (width, height) = match (length("width"), length("height"), view_box) {
+        (Some(width), Some(height), _) => (width, height),
+        (Some(width), None, Some(view_box)) => (width, width * aspect_ratio(view_box)),
+        (None, Some(height), Some(view_box)) => (height / aspect_ratio(view_box), height),
+        (None, None, Some(view_box)) => (
+            view_box.p_max.x - view_box.p_min.x,
+            view_box.p_max.y - view_box.p_min.y,
+        ),
+        (width, height, None) => (
+            width.unwrap_or(DEFAULT_WIDTH),
+            height.unwrap_or(DEFAULT_HEIGHT),
+        ),
+    };
+    let view_box =
+        view_box.unwrap_or_else(|| Rectangle::new(Point::origin(), Point::new(width, height)));
+
+    let mut elements_by_id = HashMap::new();
+    collect_elements_by_id(&root, &mut elements_by_id);
+    let mut parser = Parser {
+        elements_by_id,
+        view_box,
+        shapes: Vec::new(),
+    };
+    parser.parse_children(&root, &Style::default(), AffineTransformation::identity(), 0);
+    Ok(SvgDocument {
+        width,
+        height,
+        view_box,
+        preserve_aspect_ratio: root
+            .attribute("preserveAspectRatio")
+            .map(parse_preserve_aspect_ratio)
+            .unwrap_or_default(),
+        shapes: parser.shapes,
+    })
+}
+
+fn aspect_ratio(view_box: Rectangle) -> f64 {
+    (view_box.p_max.y - view_box.p_min.y) / (view_box.p_max.x - view_box.p_min.x)
+}
+
+fn collect_elements_by_id<'a>(element: &'a XmlElement, elements_by_id: &mut HashMap<&'a str, &'a XmlElement>) {
+    if let Some(id) = element.attribute("id") {
+        elements_by_id.entry(id).or_insert(element);
+    }
+    for child in &element.children {
+        collect_elements_by_id(child, elements_by_id);
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap()
+}
+
+struct Parser<'a> {
+    elements_by_id: HashMap<&'a str, &'a XmlElement>,
+    view_box: Rectangle,
+    shapes: Vec<SvgShape>,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_children(
+        &mut self,
+        element: &XmlElement,
+        style: &Style,
+        transform: AffineTransformation,
+        use_depth: usize,
+    ) {
+        for child in &element.children {
+            self.parse_element(child, style, transform, use_depth);
+        }
+    }
+
+    fn parse_element(
+        &mut self,
+        element: &XmlElement,
+        parent_style: &Style,
+        parent_transform: AffineTransformation,
+        use_depth: usize,
+    ) {
+        let name = local_name(&element.name);
+        if !matches!(
+            name,
+            "g" | "a" | "svg" | "switch" | "use" | "path" | "rect" | "circle" | "ellipse" | "line"
+                | "polyline" | "polygon"
+        ) {
+            return;
+        }
+        let properties = Properties::new(element);
+        let style = parent_style.inherit(&properties, self);
+        if !style.is_visible {
+            return;
+        }
+        let mut transform = parent_transform;
+        if let Some(value) = element.attribute("transform") {
+            transform = transform.compose(parse_transform(value));
+        }
+        match name {
+            "g" | "a" | "switch" => self.parse_children(element, &style, transform, use_depth),
+            "svg" => {
+                let offset = Vector::new(self.length(element, "x"), self.length(element, "y"));
+                let transform = transform.compose(AffineTransformation::translation(offset));
+                self.parse_children(element, &style, transform, use_depth);
+            }
+            "use" => {
+                let href = element
+                    .attribute("href")
+                    .or_else(|| element.attribute("xlink:href"))
+                    .and_then(|href| href.strip_prefix('#'));
+                let referenced = href.and_then(|id| self.elements_by_id.get(id).cloned());
+                if let (Some(referenced), true) = (referenced, use_depth < MAX_USE_DEPTH) {
+                    let offset = Vector::new(self.length(element, "x"), self.length(element, "y"));
+                    let transform = transform.compose(AffineTransformation::translation(offset));
+                    if local_name(&referenced.name) == "symbol" {
+                        self.parse_children(referenced, &style, transform, use_depth + 1);
+                    } else {
+                        self.parse_element(referenced, &style, transform, use_depth + 1);
+                    }
+                }
+            }
+            _ => {
+                if let Some(path) = self.parse_shape(element, name) {
+                    self.push_shape(path, transform, &style);
+                }
+            }
+        }
+    }
+
+    fn parse_shape(&self, element: &XmlElement, name: &str) -> Option<Path> {
+        let mut path = Path::new();
+        match name {
+            "path" => return parse_svg_path(element.attribute("d")?).ok(),
+            "rect" => {
+                let x = self.length(element, "x");
+                let y = self.length(element, "y");
+                let width = self.length(element, "width");
+                let height = self.length(element, "height");
+                if width <= 0.0 || height <= 0.0 {
+                    return None;
+                }
+                // A missing radius defaults to the other one.
+                let rx = element.attribute("rx").map(|_| self.length(element, "rx"));
+                let ry = element.attribute("ry").map(|_| self.length(element, "ry"));
+                let (rx, ry) = match (rx, ry) {
+                    (Some(rx), Some(ry)) => (rx, ry),
+                    (Some(r), None) | (None, Some(r)) => (r, r),
+                    (None, None) => (0.0, 0.0),
+                };
+                let rx = rx.max(0.0).min(width / 2.0);
+                let ry = ry.max(0.0).min(height / 2.0);
+                if rx > 0.0 && ry > 0.0 {
+                    let radii = Vector::new(rx, ry);
+                    path.move_to(Point::new(x + rx, y));
+                    path.line_to(Point::new(x + width - rx, y));
+                    path.arc_to(radii, 0.0, false, true, Point::new(x + width, y + ry));
+                    path.line_to(Point::new(x + width, y + height - ry));
+                    path.arc_to(radii, 0.0, false, true, Point::new(x + width - rx, y + height));
+                    path.line_to(Point::new(x + rx, y + height));
+                    path.arc_to(radii, 0.0, false, true, Point::new(x, y + height - ry));
+                    path.line_to(Point::new(x, y + ry));
+                    path.arc_to(radii, 0.0, false, true, Point::new(x + rx, y));
+                } else {
+                    path.move_to(Point::new(x, y));
+                    path.line_to(Point::new(x + width, y));
+                    path.line_to(Point::new(x + width, y + height));
+                    path.line_to(Point::new(x, y + height));
+                }
+                path.close();
+            }
+            "circle" | "ellipse" => {
+                let cx = self.length(element, "cx");
+                let cy = self.length(element, "cy");
+                let (rx, ry) = if name == "circle" {
+                    let r = self.length(element, "r");
+                    (r, r)
+                } else {
+                    (self.length(element, "rx"), self.length(element, "ry"))
+                };
+                if rx <= 0.0 || ry <= 0.0 {
+                    return None;
+                }
+                let radii = Vector::new(rx, ry);
+                path.move_to(Point::new(cx + rx, cy));
+                path.arc_to(radii, 0.0, false, true, Point::new(cx, cy + ry));
+                path.arc_to(radii, 0.0, false, true, Point::new(cx - rx, cy));
+                path.arc_to(radii, 0.0, false, true, Point::new(cx, cy - ry));
+                path.arc_to(radii, 0.0, false, true, Point::new(cx + rx, cy));
+                path.close();
+            }
+            "line" => {
+                path.move_to(Point::new(self.length(element, "x1"), self.length(element, "y1")));
+                path.line_to(Point::new(self.length(element, "x2"), self.length(element, "y2")));
+            }
+            "polyline" | "polygon" => {
+                let numbers = parse_numbers(element.attribute("points")?)?;
+                let mut points = numbers.chunks_exact(2).map(|pair| Point::new(pair[0], pair[1]));
+                path.move_to(points.next()?);
+                for p in points {
+                    path.line_to(p);
+                }
+                if name == "polygon" {
+                    path.close();
+                }
+            }
+            _ => return None,
+        }
+        Some(path)
+    }
+
+    fn push_shape(&mut self, path: Path, transform: AffineTransformation, style: &Style) {
+        let fill = style.fill.clone().map(|paint| SvgFill {
+            paint,
+            opacity: style.fill_opacity * style.opacity,
+            rule: style.fill_rule,
+        });
+        let stroke = style
+            .stroke
+            .clone()
+            .filter(|_| style.stroke_style.width > 0.0)
+            .map(|paint| SvgStroke {
+                paint,
+                opacity: style.stroke_opacity * style.opacity,
+                style: style.stroke_style.clone(),
+            });
+        if fill.is_none() && stroke.is_none() {
+            return;
+        }
+        self.shapes.push(SvgShape {
+            path,
+            transform,
+            fill,
+            stroke,
+        });
+    }
+
+    // Lengths in percentages are relative to the view box, in the direction of the attribute.
+    fn length(&self, element: &XmlElement, name: &str) -> f64 {
+        let size = self.view_box.p_max - self.view_box.p_min;
+        let reference = match name {
+            "x" | "cx" | "x1" | "x2" | "width" | "rx" => size.x,
+            "y" | "cy" | "y1" | "y2" | "height" | "ry" => size.y,
+            _ => size.length() / 2.0f64.sqrt(),
+        };
+        element
+            .attribute(name)
+            .and_then(|value| parse_length(value, reference))
+            .unwrap_or(0.0)
+    }
+
+    fn parse_paint(&self, value: &str, current_color: [f32; 4]) -> Option<Option<SvgPaint>> {
+        let value = value.trim();
+        if value == "none" {
+            return Some(None);
+        }
+        if value == "currentColor" {
+            return Some(Some(SvgPaint::Color(current_color)));
+        }
+        if let Some(rest) = value.strip_prefix("url(") {
+            let end = rest.find(')')?;
+            let id = rest[..end].trim().trim_matches(|char| char == '\'' || char == '"');
+            let gradient = id
+                .strip_prefix('#')
+                .and_then(|id| self.elements_by_id.get(id))
+                .and_then(|element| self.parse_gradient(element));
+            return match gradient {
+                Some(gradient) => Some(Some(gradient)),
+                // An unresolved reference falls back to the paint that comes after it.
+                None => match rest[end + 1..].trim() {
+                    "" => Some(None),
+                    fallback => self.parse_paint(fallback, current_color),
+                },
+            };
+        }
+        parse_color(value).map(|color| Some(SvgPaint::Color(color)))
+    }
+
+    fn parse_gradient(&self, element: &XmlElement) -> Option<SvgPaint> {
+        let is_radial = match local_name(&element.name) {
+            "linearGradient" => false,
+            "radialGradient" => true,
+            _ => return None,
+        };
+        // Gradients inherit attributes and stops from the gradients they refer to.
+        let mut chain = vec![element];
+        while chain.len() < MAX_USE_DEPTH {
+            let href = chain
+                .last()
+                .unwrap()
+                .attribute("href")
+                .or_else(|| chain.last().unwrap().attribute("xlink:href"));
+            match href
+                .and_then(|href| href.strip_prefix('#'))
+                .and_then(|id| self.elements_by_id.get(id))
+            {
+                Some(referenced) if !chain.contains(referenced) => chain.push(referenced),
+                _ => break,
+            }
+        }
+        let attribute = |name: &str| chain.iter().find_map(|element| element.attribute(name));
+
+        let units = match attribute("gradientUnits") {
+            Some("userSpaceOnUse") => SvgGradientUnits::UserSpaceOnUse,
+            _ => SvgGradientUnits::ObjectBoundingBox,
+        };
+        let size = match units {
+            SvgGradientUnits::ObjectBoundingBox => Vector::new(1.0, 1.0),
+            SvgGradientUnits::UserSpaceOnUse => self.view_box.p_max - self.view_box.p_min,
+        };
+        let coordinate = |name: &str, reference: f64, default: f64| {
+            attribute(name)
+                .and_then(|value| parse_length(value, reference))
+                .unwrap_or(default)
+        };
+        let kind = if is_radial {
+            let center = Point::new(
+                coordinate("cx", size.x, size.x * 0.5),
+                coordinate("cy", size.y, size.y * 0.5),
+            );
+            let radius = coordinate("r", size.length() / 2.0f64.sqrt(), size.length() / 2.0f64.sqrt() * 0.5);
+            let focus = Point::new(coordinate("fx", size.x, center.x), coordinate("fy", size.y, center.y));
+            SvgGradientKind::Radial {
+                center,
+                radius,
+                focus,
+            }
+        } else {
+            SvgGradientKind::Linear {
+                start: Point::new(coordinate("x1", size.x, 0.0), coordinate("y1", size.y, 0.0)),
+                end: Point::new(coordinate("x2", size.x, size.x), coordinate("y2", size.y, 0.0)),
+            }
+        };
+
+        let stop_element = chain
+            .iter()
+            .find(|element| element.children.iter().any(|child| local_name(&child.name) == "stop"))?;
+        let mut stops: Vec<SvgGradientStop> = Vec::new();
+        for stop in &stop_element.children {
+            if local_name(&stop.name) != "stop" {
+                continue;
+            }
+            let properties = Properties::new(stop);
+            let offset = stop
+                .attribute("offset")
+                .and_then(|value| parse_length(value, 1.0))
+                .unwrap_or(0.0)
+                .clamp(0.0, 1.0);
+            let mut color = properties
+                .get("stop-color")
+                .and_then(parse_color)
+                .unwrap_or([0.0, 0.0, 0.0, 1.0]);
+            color[3] *= properties
+                .get("stop-opacity")
+                .and_then(parse_opacity)
+                .unwrap_or(1.0) as f32;
+            // Offsets never decrease.
+            let offset = stops.last().map_or(offset, |last| offset.max(last.offset));
+            stops.push(SvgGradientStop { offset, color });
+        }
+        if stops.is_empty() {
+            return None;
+        }
+        if stops.len() == 1 {
+            return Some(SvgPaint::Color(stops[0].color));
+        }
+        Some(SvgPaint::Gradient(SvgGradient {
+            kind,
+            units,
+            transform: attribute("gradientTransform")
+                .map(parse_transform)
+                .unwrap_or_else(AffineTransformation::identity),
+            spread: match attribute("spreadMethod") {
+                Some("reflect") => SvgSpreadMethod::Reflect,
+                Some("repeat") => SvgSpreadMethod::Repeat,
+                _ => SvgSpreadMethod::Pad,
+            },
+            stops,
+        }))
+    }
+}
+
+// The presentation attributes of an element, overridden by the declarations in its `style`
+// attribute.
+struct Properties<'a> {
+    element: &'a XmlElement,
+    declarations: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Properties<'a> {
+    fn new(element: &'a XmlElement) -> Properties<'a> {
+        let declarations = element
+            .attribute("style")
+            .map(|style| {
+                style
+                    .split(';')
+                    .filter_map(|declaration| {
+                        let (name, value) = declaration.split_once(':')?;
+                        let value = value.trim();
+                        let value = value.strip_suffix("!important").unwrap_or(value).trim();
+                        Some((name.trim(), value))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Properties {
+            element,
+            declarations,
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&'a str> {
+        let value = self
+            .declarations
+            .iter()
+            .rev()
+            .find(|(declaration_name, _)| *declaration_name == name)
+            .map(|(_, value)| *value)
+            .or_else(|| self.element.attribute(name))?;
+        // Inheriting explicitly is the same as not specifying a value.
+        if value.trim() == "inherit" {
+            None
+        } else {
+            Some(value)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Style {
+    fill: Option<SvgPaint>,
+    fill_opacity: f64,
+    fill_rule: FillRule,
+    stroke: Option<SvgPaint>,
+    stroke_opacity: f64,
+    stroke_style: StrokeStyle,
+    color: [f32; 4],
+    // Opacity is not inherited, but we have no layers, so the opacity of a group is multiplied
+    // into the opacity of its children.
+    opacity: f64,
+    is_visible: bool,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style {
+            fill: Some(SvgPaint::Color([0.0, 0.0, 0.0, 1.0])),
+            fill_opacity: 1.0,
+            fill_rule: FillRule::NonZero,
+            stroke: None,
+            stroke_opacity: 1.0,
+            stroke_style: StrokeStyle::default(),
+            color: [0.0, 0.0, 0.0, 1.0],
+            opacity: 1.0,
+            is_visible: true,
+        }
+    }
+}
+
+impl Style {
+    fn inherit(&self, properties: &Properties, parser: &Parser) -> Style {
+        let mut style = self.clone();
+        if let Some(color) = properties.get("color").and_then(parse_color) {
+            style.color = color;
+        }
+        if let Some(paint) = properties.get("fill").and_then(|value| parser.parse_paint(value, style.color)) {
+            style.fill = paint;
+        }
+        if let Some(opacity) = properties.get("fill-opacity").and_then(parse_opacity) {
+            style.fill_opacity = opacity;
+        }
+        match properties.get("fill-rule").map(str::trim) {
+            Some("evenodd") => style.fill_rule = FillRule::EvenOdd,
+            Some("nonzero") => style.fill_rule = FillRule::NonZero,
+            _ => {}
+        }
+        if let Some(paint) = properties.get("stroke").and_then(|value| parser.parse_paint(value, style.color)) {
+            style.stroke = paint;
+        }
+        if let Some(opacity) = properties.get("stroke-opacity").and_then(parse_opacity) {
+            style.stroke_opacity = opacity;
+        }
+        let size = parser.view_box.p_max - parser.view_box.p_min;
+        let reference = size.length() / 2.0f64.sqrt();
+        if let Some(width) = properties.get("stroke-width").and_then(|value| parse_length(value, reference)) {
+            style.stroke_style.width = width;
+        }
+        match properties.get("stroke-linecap").map(str::trim) {
+            Some("butt") => style.stroke_style.cap = LineCap::Butt,
+            Some("round") => style.stroke_style.cap = LineCap::Round,
+            Some("square") => style.stroke_style.cap = LineCap::Square,
+            _ => {}
+        }
+        match properties.get("stroke-linejoin").map(str::trim) {
+            Some("miter") | Some("miter-clip") => style.stroke_style.join = LineJoin::Miter,
+            Some("round") => style.stroke_style.join = LineJoin::Round,
+            Some("bevel") => style.stroke_style.join = LineJoin::Bevel,
+            _ => {}
+        }
+        if let Some(limit) = properties.get("stroke-miterlimit").and_then(|value| value.trim().parse::<f64>().ok()) {
+            if limit >= 1.0 {
+                style.stroke_style.miter_limit = limit;
+            }
+        }
+        if let Some(value) = properties.get("stroke-dasharray") {
+            style.stroke_style.dashes = if value.trim() == "none" {
+                Vec::new()
+            } else {
+                value
+                    .split(|char: char| char == ',' || char.is_whitespace())
+                    .filter(|part| !part.is_empty())
+                    .map(|part| parse_length(part, reference))
+                    .collect::<Option<Vec<f64>>>()
+                    .unwrap_or_default()
+            };
+        }
+        if let Some(offset) = properties.get("stroke-dashoffset").and_then(|value| parse_length(value, reference)) {
+            style.stroke_style.dash_offset = offset;
+        }
+        style.opacity = self.opacity
+            * properties
+                .get("opacity")
+                .and_then(parse_opacity)
+                .unwrap_or(1.0);
+        if properties.get("display").map(str::trim) == Some("none") {
+            style.is_visible = false;
+        }
+        match properties.get("visibility").map(str::trim) {
+            Some("hidden") | Some("collapse") => style.is_visible = false,
+            _ => {}
+        }
+        style
+    }
+}
+
+fn parse_preserve_aspect_ratio(value: &str) -> SvgPreserveAspectRatio {
+    let mut parts = value.split_whitespace();
+    let align = match parts.next() {
+        Some("none") => None,
+        Some(align) if align.len() == 8 => {
+            let coordinate = |part: &str| match part {
+                "Min" => Some(0.0),
+                "Mid" => Some(0.5),
+                "Max" => Some(1.0),
+                _ => None,
+            };
+            match (coordinate(&align[1..4]), coordinate(&align[5..8])) {
+                (Some(x), Some(y)) => Some(Vector::new(x, y)),
+                _ => return SvgPreserveAspectRatio::default(),
+            }
+        }
+        _ => return SvgPreserveAspectRatio::default(),
+    };
+    SvgPreserveAspectRatio {
+        align,
+        slice: parts.next() == Some("slice"),
+    }
+}
+
+fn parse_transform(value: &str) -> AffineTransformation {
+    let mut transform = AffineTransformation::identity();
+    let mut rest = value;
+    while let Some(open) = rest.find('(') {
+        let name = rest[..open].trim_matches(|char: char| char == ',' || char.is_whitespace());
+        let close = match rest[open..].find(')') {
+            Some(close) => open + close,
+            None => break,
+        };
+        let arguments = match parse_numbers(&rest[open + 1..close]) {
+            Some(arguments) => arguments,
+            None => break,
+        };
+        let argument = |index: usize, default: f64| arguments.get(index).cloned().unwrap_or(default);
+        let next = match (name, arguments.len()) {
+            ("matrix", 6) => AffineTransformation::new(
+                LinearTransformation::new(
+                    Vector::new(arguments[0], arguments[1]),
+                    Vector::new(arguments[2], arguments[3]),
+                ),
+                Vector::new(arguments[4], arguments[5]),
+            ),
+            ("translate", 1..=2) => {
+                AffineTransformation::translation(Vector::new(argument(0, 0.0), argument(1, 0.0)))
+            }
+            ("scale", 1..=2) => {
+                let x = argument(0, 1.0);
+                AffineTransformation::scaling(Vector::new(x, argument(1, x)))
+            }
+            ("rotate", 1) | ("rotate", 3) => {
+                let (sin, cos) = argument(0, 0.0).to_radians().sin_cos();
+                let rotation = AffineTransformation::new(
+                    LinearTransformation::new(Vector::new(cos, sin), Vector::new(-sin, cos)),
+                    Vector::zero(),
+                );
+                let center = Vector::new(argument(1, 0.0), argument(2, 0.0));
+                AffineTransformation::translation(center)
+                    .compose(rotation)
+                    .compose(AffineTransformation::translation(-center))
+            }
+            ("skewX", 1) => AffineTransformation::new(
+                LinearTransformation::new(
+                    Vector::new(1.0, 0.0),
+                    Vector::new(arguments[0].to_radians().tan(), 1.0),
+                ),
+                Vector::zero(),
+            ),
+            ("skewY", 1) => AffineTransformation::new(
+                LinearTransformation::new(
+                    Vector::new(1.0, arguments[0].to_radians().tan()),
+                    Vector::new(0.0, 1.0),
+                ),
+                Vector::zero(),
+            ),
+            _ => break,
+        };
+        transform = transform.compose(next);
+        rest = &rest[close + 1..];
+    }
+    transform
+}
+
+fn parse_numbers(value: &str) -> Option<Vec<f64>> {
+    value
+        .split(|char: char| char == ',' || char.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .flat_map(split_compact_numbers)
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+// Numbers in lists don't need separators if the next one starts with a sign, as in `1-2`.
+fn split_compact_numbers(part: &str) -> Vec<&str> {
+    let mut numbers = Vec::new();
+    let mut start = 0;
+    let bytes = part.as_bytes();
+    for index in 1..bytes.len() {
+        if matches!(bytes[index], b'-' | b'+') && !matches!(bytes[index - 1], b'e' | b'E') {
+            numbers.push(&part[start..index]);
+            start = index;
+        }
+    }
+    numbers.push(&part[start..]);
+    numbers
+}
+
+fn parse_length(value: &str, reference: f64) -> Option<f64> {
+    let value = value.trim();
+    let split = number_length(value);
+    let number: f64 = value[..split].parse().ok()?;
+    let scale = match value[split..].trim() {
+        "" | "px" => 1.0,
+        "%" => reference / 100.0,
+        "pt" => 4.0 / 3.0,
+        "pc" => 16.0,
+        "mm" => 96.0 / 25.4,
+        "cm" => 96.0 / 2.54,
+        "in" => 96.0,
+        "em" => 16.0,
+        "ex" => 8.0,
+        _ => return None,
+    };
+    Some(number * scale)
+}
+
+// Returns the length of the number at the start of `value`, so that an exponent can be told
+// apart from a unit like `em`.
+fn number_length(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    let digits = |mut index: usize| {
+        while bytes.get(index).is_some_and(|byte| byte.is_ascii_digit()) {
+            index += 1;
+        }
+        index
+    };
+    let mut index = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        index += 1;
+    }
+    index = digits(index);
+    if bytes.get(index) == Some(&b'.') {
+        index = digits(index + 1);
+    }
+    if matches!(bytes.get(index), Some(b'e' | b'E')) {
+        let mut exponent = index + 1;
+        if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+            exponent += 1;
+        }
+        if bytes.get(exponent).is_some_and(|byte| byte.is_ascii_digit()) {
+            index = digits(exponent);
+        }
+    }
+    index
+}
+
+fn parse_opacity(value: &str) -> Option<f64> {
+    parse_length(value, 1.0).map(|opacity| opacity.clamp(0.0, 1.0))
+}
+
+fn parse_color(value: &str) -> Option<[f32; 4]> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        let digit = |index: usize| u8::from_str_radix(hex.get(index..index + 1)?, 16).ok();
+        let byte = |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+        let (r, g, b, a) = match hex.len() {
+            3 | 4 => (
+                digit(0)? * 17,
+                digit(1)? * 17,
+                digit(2)? * 17,
+                if hex.len() == 4 { digit(3)? * 17 } else { 255 },
+            ),
+            6 | 8 => (byte(0)?, byte(2)?, byte(4)?, if hex.len() == 8 { byte(6)? } else { 255 }),
+            _ => return None,
+        };
+        return Some([r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0]);
+    }
+    let lowercase = value.to_ascii_lowercase();
+    if let Some(arguments) = lowercase
+        .strip_prefix("rgba(")
+        .or_else(|| lowercase.strip_prefix("rgb("))
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let components: Vec<&str> = arguments
+            .split(|char: char| char == ',' || char == '/' || char.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .collect();
+        if components.len() != 3 && components.len() != 4 {
+            return None;
+        }
+        let channel = |part: &str| parse_length(part, 255.0).map(|value| (value / 255.0).clamp(0.0, 1.0) as f32);
+        let alpha = match components.get(3) {
+            Some(part) => parse_opacity(part)? as f32,
+            None => 1.0,
+        };
+        return Some([channel(components[0])?, channel(components[1])?, channel(components[2])?, alpha]);
+    }
+    let rgb: u32 = match lowercase.as_str() {
+        "transparent" => return Some([0.0, 0.0, 0.0, 0.0]),
+        "black" => 0x000000,
+        "silver" => 0xc0c0c0,
+        "gray" | "grey" => 0x808080,
+        "white" => 0xffffff,
+        "maroon" => 0x800000,
+        "red" => 0xff0000,
+        "purple" => 0x800080,
+        "fuchsia" | "magenta" => 0xff00ff,
+        "green" => 0x008000,
+        "lime" => 0x00ff00,
+        "olive" => 0x808000,
+        "yellow" => 0xffff00,
+        "navy" => 0x000080,
+        "blue" => 0x0000ff,
+        "teal" => 0x008080,
+        "aqua" | "cyan" => 0x00ffff,
+        "orange" => 0xffa500,
+        "brown" => 0xa52a2a,
+        "pink" => 0xffc0cb,
+        "gold" => 0xffd700,
+        "indigo" => 0x4b0082,
+        "violet" => 0xee82ee,
+        "darkgray" | "darkgrey" => 0xa9a9a9,
+        "lightgray" | "lightgrey" => 0xd3d3d3,
+        "dimgray" | "dimgrey" => 0x696969,
+        "darkred" => 0x8b0000,
+        "darkgreen" => 0x006400,
+        "darkblue" => 0x00008b,
+        "lightblue" => 0xadd8e6,
+        "skyblue" => 0x87ceeb,
+        "steelblue" => 0x4682b4,
+        "royalblue" => 0x4169e1,
+        "dodgerblue" => 0x1e90ff,
+        "tomato" => 0xff6347,
+        "crimson" => 0xdc143c,
+        "coral" => 0xff7f50,
+        "salmon" => 0xfa8072,
+        "khaki" => 0xf0e68c,
+        "beige" => 0xf5f5dc,
+        "ivory" => 0xfffff0,
+        "whitesmoke" => 0xf5f5f5,
+        "chocolate" => 0xd2691e,
+        "tan" => 0xd2b48c,
+        "turquoise" => 0x40e0d0,
+        "orchid" => 0xda70d6,
+        "plum" => 0xdda0dd,
+        "slategray" | "slategrey" => 0x708090,
+        "forestgreen" => 0x228b22,
+        "seagreen" => 0x2e8b57,
+        "limegreen" => 0x32cd32,
+        _ => return None,
+    };
+    Some([
+        (rgb >> 16) as f32 / 255.0,
+        ((rgb >> 8) & 0xff) as f32 / 255.0,
+        (rgb & 0xff) as f32 / 255.0,
+        1.0,
+    ])
+}
@@ -0,0 +1,383 @@
+use crate::geometry::{AffineTransformation, Point, Rectangle, Transform, Trapezoid, Vector};
+use crate::path::{FillRule, LinePath, LinePathCommand, LinePathIterator, PathIterator};
+use crate::svg::{
+    SvgDocument, SvgGradient, SvgGradientKind, SvgGradientUnits, SvgPaint, SvgSpreadMethod,
+};
+use crate::trapezoidator::Trapezoidator;
+
+// The maximum distance, in pixels, between curves and the line segments that approximate them.
+const TOLERANCE: f64 = 0.1;
+
+impl SvgDocument {
+    /// Renders `self` into an image of the given size, with the view box fitted into it.
+    ///
+    /// Pixels are packed as ARGB in a `u32`, with non-premultiplied alpha, in rows from top to
+    /// bottom.
+    pub fn rasterize(&self, width: usize, height: usize) -> Vec<u32> {
+        let mut canvas = Canvas {
+            width,
+            height,
+            colors: vec![[0.0; 4]; width * height],
+            coverage: vec![0.0; width * height],
+        };
+        let view_box_transformation = self.view_box_transformation(width as f64, height as f64);
+        for shape in &self.shapes {
+            let transform = view_box_transformation.compose(shape.transform);
+            // The tolerance in the local coordinate system of the shape.
+            let scale = transform.xy.determinant().abs().sqrt();
+            if scale == 0.0 || !scale.is_finite() {
+                continue;
+            }
+            let local_tolerance = TOLERANCE / scale;
+            let bounds = bounds(shape.path.commands().linearize(local_tolerance));
+            if let Some(fill) = &shape.fill {
+                let line_path = close_contours(
+                    shape
+                        .path
+                        .clone()
+                        .transform(&transform)
+                        .commands()
+                        .linearize(TOLERANCE),
+                );
+                if let Some(area) = canvas.accumulate_coverage(&line_path, fill.rule) {
+                    canvas.composite(area, &fill.paint, fill.opacity, transform, bounds);
+                }
+            }
+            if let Some(stroke) = &shape.stroke {
+                let line_path = shape
+                    .path
+                    .commands()
+                    .linearize(local_tolerance)
+                    .stroke(&stroke.style, local_tolerance)
+                    .transform(&transform);
+                if let Some(area) = canvas.accumulate_coverage(&line_path, FillRule::NonZero) {
+                    canvas.composite(area, &stroke.paint, stroke.opacity, transform, bounds);
+                }
+            }
+        }
+        canvas
+            .colors
+            .iter()
+            .map(|&[r, g, b, a]| {
+                if a <= 0.0 {
+                    return 0;
+                }
+                let channel = |value: f32| ((value / a).clamp(0.0, 1.0) * 255.0).round() as u32;
+                let alpha = (a.min(1.0) * 255.0).round() as u32;
+                (alpha << 24) | (channel(r) << 16) | (channel(g) << 8) | channel(b)
+            })
+            .collect()
+    }
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    // Premultiplied RGBA.
+    colors: Vec<[f32; 4]>,
+    coverage: Vec<f32>,
+}
+
+// A range of pixels, with exclusive maximums.
+#[derive(Clone, Copy, Debug)]
+struct PixelArea {
+    x_min: usize,
+    y_min: usize,
+    x_max: usize,
+    y_max: usize,
+}
+
+impl Canvas {
+    // Adds the coverage of each pixel by the interior of `line_path` to the coverage buffer, and
+    // returns the area that was touched.
+    fn accumulate_coverage(&mut self, line_path: &LinePath, fill_rule: FillRule) -> Option<PixelArea> {
+        use crate::internal_iter::InternalIterator;
+
+        let mut area: Option<PixelArea> = None;
+        let (width, height) = (self.width, self.height);
+        let coverage = &mut self.coverage;
+        let mut trapezoidator = Trapezoidator::with_fill_rule(fill_rule);
+        let trapezoids = trapezoidator.trapezoidate(line_path.commands())?;
+        trapezoids.for_each(&mut |trapezoid| {
+            if let Some(trapezoid_area) = accumulate_trapezoid(coverage, width, height, trapezoid) {
+                area = Some(match area {
+                    Some(area) => PixelArea {
+                        x_min: area.x_min.min(trapezoid_area.x_min),
+                        y_min: area.y_min.min(trapezoid_area.y_min),
+                        x_max: area.x_max.max(trapezoid_area.x_max),
+                        y_max: area.y_max.max(trapezoid_area.y_max),
+                    },
+                    None => trapezoid_area,
+                });
+            }
+            true
+        });
+        area
+    }
+
+    // Blends `paint` over the pixels in `area`, weighted by their coverage, and clears the
+    // coverage buffer again.
+    fn composite(
+        &mut self,
+        area: PixelArea,
+        paint: &SvgPaint,
+        opacity: f64,
+        transform: AffineTransformation,
+        bounds: Option<Rectangle>,
+    ) {
+        let shader = match paint {
+            SvgPaint::Color(color) => Shader::Color(*color),
+            SvgPaint::Gradient(gradient) => match GradientShader::new(gradient, transform, bounds) {
+                Some(shader) => Shader::Gradient(shader),
+                None => Shader::Color(gradient.stops.last().unwrap().color),
+            },
+        };
+        for y in area.y_min..area.y_max {
+            for x in area.x_min..area.x_max {
+                let index = y * self.width + x;
+                let coverage = self.coverage[index].abs().min(1.0);
+                self.coverage[index] = 0.0;
+                if coverage <= 0.0 {
+                    continue;
+                }
+                let [r, g, b, a] = match &shader {
+                    Shader::Color(color) => *color,
+                    Shader::Gradient(gradient) => {
+                        gradient.color_at(Point::new(x as f64 + 0.5, y as f64 + 0.5))
+                    }
+                };
+                let alpha = a * coverage * opacity as f32;
+                let destination = &mut self.colors[index];
+                let inverse_alpha = 1.0 - alpha;
+                destination[0] = r * alpha + destination[0] * inverse_alpha;
+                destination[1] = g * alpha + destination[1] * inverse_alpha;
+                destination[2] = b * alpha + destination[2] * inverse_alpha;
+                destination[3] = alpha + destination[3] * inverse_alpha;
+            }
+        }
+    }
+}
+
+// Adds the area of each pixel that `trapezoid` covers to `coverage`.
+fn accumulate_trapezoid(
+    coverage: &mut [f32],
+    width: usize,
+    height: usize,
+    trapezoid: Trapezoid,
+) -> Option<PixelArea> {
+    let [x0, x1] = trapezoid.xs;
+    let [y0, y1, y2, y3] = trapezoid.ys;
+    let clamp = |value: f32, max: usize| value.max(0.0).min(max as f32) as usize;
+    let area = PixelArea {
+        x_min: clamp(x0.floor(), width),
+        y_min: clamp(y0.min(y1).floor(), height),
+        x_max: clamp(x1.ceil(), width),
+        y_max: clamp(y2.max(y3).ceil(), height),
+    };
+    if area.x_min >= area.x_max || area.y_min >= area.y_max {
+        return None;
+    }
+    let lower = ([x0, y0], [x1, y1]);
+    let upper = ([x0, y2], [x1, y3]);
+    for y in area.y_min..area.y_max {
+        for x in area.x_min..area.x_max {
+            let p_min = [x as f32, y as f32];
+            let p_max = [x as f32 + 1.0, y as f32 + 1.0];
+            coverage[y * width + x] += clamped_right_trapezoid_area(lower.0, lower.1, p_min, p_max)
+                - clamped_right_trapezoid_area(upper.0, upper.1, p_min, p_max);
+        }
+    }
+    Some(area)
+}
+
+// Returns the area of the pixel between `p_min` and `p_max` that lies between the line segment
+// from `p0` to `p1` and the bottom of the pixel. This is the same computation the trapezoid
+// shader for glyphs does.
+fn clamped_right_trapezoid_area(mut p0: [f32; 2], mut p1: [f32; 2], p_min: [f32; 2], p_max: [f32; 2]) -> f32 {
+    let intersect_vertical = |p0: [f32; 2], p1: [f32; 2], x: f32| {
+        [x, p0[1] + (p1[1] - p0[1]) * (x - p0[0]) / (p1[0] - p0[0])]
+    };
+    let intersect_horizontal = |p0: [f32; 2], p1: [f32; 2], y: f32| {
+        [p0[0] + (p1[0] - p0[0]) * (y - p0[1]) / (p1[1] - p0[1]), y]
+    };
+    let x0 = p0[0].max(p_min[0]).min(p_max[0]);
+    let x1 = p1[0].max(p_min[0]).min(p_max[0]);
+    if p0[0] < p_min[0] && p_min[0] < p1[0] {
+        p0 = intersect_vertical(p0, p1, p_min[0]);
+    }
+    if p0[0] < p_max[0] && p_max[0] < p1[0] {
+        p1 = intersect_vertical(p0, p1, p_max[0]);
+    }
+    if p0[1] < p_min[1] && p_min[1] < p1[1] {
+        p0 = intersect_horizontal(p0, p1, p_min[1]);
+    }
+    if p1[1] < p_min[1] && p_min[1] < p0[1] {
+        p1 = intersect_horizontal(p1, p0, p_min[1]);
+    }
+    if p0[1] < p_max[1] && p_max[1] < p1[1] {
+        p1 = intersect_horizontal(p0, p1, p_max[1]);
+    }
+    if p1[1] < p_max[1] && p_max[1] < p0[1] {
+        p0 = intersect_horizontal(p1, p0, p_max[1]);
+    }
+    let clamp = |p: [f32; 2]| {
+        [
+            p[0].max(p_min[0]).min(p_max[0]),
+            p[1].max(p_min[1]).min(p_max[1]),
+        ]
+    };
+    let p0 = clamp(p0);
+    let p1 = clamp(p1);
+    let h0 = p_max[1] - p0[1];
+    let h1 = p_max[1] - p1[1];
+    let a0 = (p0[0] - x0) * h0;
+    let a1 = (p1[0] - p0[0]) * (h0 + h1) * 0.5;
+    let a2 = (x1 - p1[0]) * h1;
+    a0 + a1 + a2
+}
+
+// Filling treats open contours as if they were closed.
+fn close_contours<P: LinePathIterator>(path: P) -> LinePath {
+    let mut line_path = LinePath::new();
+    let mut is_open = false;
+    path.for_each(&mut |command| {
+        match command {
+            LinePathCommand::MoveTo(p) => {
+                if is_open {
+                    line_path.close();
+                }
+                line_path.move_to(p);
+                is_open = true;
+            }
+            LinePathCommand::LineTo(p) => line_path.line_to(p),
+            LinePathCommand::Close => {
+                line_path.close();
+                is_open = false;
+            }
+        }
+        true
+    });
+    if is_open {
+        line_path.close();
+    }
+    line_path
+}
+
+fn bounds<P: LinePathIterator>(path: P) -> Option<Rectangle> {
+    let mut bounds: Option<Rectangle> = None;
+    path.for_each(&mut |command| {
+        if let LinePathCommand::MoveTo(p) | LinePathCommand::LineTo(p) = command {
+            bounds = Some(match bounds {
+                Some(bounds) => Rectangle::new(
+                    Point::new(bounds.p_min.x.min(p.x), bounds.p_min.y.min(p.y)),
+                    Point::new(bounds.p_max.x.max(p.x), bounds.p_max.y.max(p.y)),
+                ),
+                None => Rectangle::new(p, p),
+            });
+        }
+        true
+    });
+    bounds
+}
+
+enum Shader<'a> {
+    Color([f32; 4]),
+    Gradient(GradientShader<'a>),
+}
+
+struct GradientShader<'a> {
+    gradient: &'a SvgGradient,
+    // The transformation from pixels to the coordinate system of the gradient.
+    inverse_transform: AffineTransformation,
+}
+
+impl<'a> GradientShader<'a> {
+    fn new(
+        gradient: &'a SvgGradient,
+        transform: AffineTransformation,
+        bounds: Option<Rectangle>,
+    ) -> Option<GradientShader<'a>> {
+        let units_transform = match gradient.units {
+            SvgGradientUnits::UserSpaceOnUse => AffineTransformation::identity(),
+            SvgGradientUnits::ObjectBoundingBox => {
+                let bounds = bounds?;
+                let size = bounds.p_max - bounds.p_min;
+                if size.x <= 0.0 || size.y <= 0.0 {
+                    return None;
+                }
+                AffineTransformation::scaling(size).translate(bounds.p_min.to_vector())
+            }
+        };
+        Some(GradientShader {
+            gradient,
+            inverse_transform: transform
+                .compose(units_transform)
+                .compose(gradient.transform)
+                .invert()?,
+        })
+    }
+
+    fn color_at(&self, p: Point) -> [f32; 4] {
+        let p = p.transform(&self.inverse_transform);
+        let t = match self.gradient.kind {
+            SvgGradientKind::Linear { start, end } => {
+                let direction = end - start;
+                let length_squared = direction.dot(direction);
+                if length_squared == 0.0 {
+                    1.0
+                } else {
+                    (p - start).dot(direction) / length_squared
+                }
+            }
+            SvgGradientKind::Radial {
+                center,
+                radius,
+                focus,
+            } => radial_gradient_position(p - focus, center - focus, radius),
+        };
+        let t = match self.gradient.spread {
+            SvgSpreadMethod::Pad => t.clamp(0.0, 1.0),
+            SvgSpreadMethod::Repeat => t - t.floor(),
+            SvgSpreadMethod::Reflect => {
+                let t = t.rem_euclid(2.0);
+                if t > 1.0 {
+                    2.0 - t
+                } else {
+                    t
+                }
+            }
+        };
+        let stops = &self.gradient.stops;
+        let index = stops.iter().position(|stop| stop.offset > t).unwrap_or(stops.len());
+        if index == 0 {
+            return stops[0].color;
+        }
+        if index == stops.len() {
+            return stops[stops.len() - 1].color;
+        }
+        let (stop_0, stop_1) = (stops[index - 1], stops[index]);
+        let s = ((t - stop_0.offset) / (stop_1.offset - stop_0.offset)) as f32;
+        let mut color = stop_0.color;
+        for (channel, end) in color.iter_mut().zip(stop_1.color) {
+            *channel += (end - *channel) * s;
+        }
+        color
+    }
+}
+
+// Returns the position on a radial gradient of the point `q`, relative to the focus, where the
+// gradient goes from a point at the focus to a circle with the given radius around the center,
+// which is at `d` relative to the focus. This is the largest `t` for which `q` lies on the
+// circle with radius `t * radius` around `t * d`.
+fn radial_gradient_position(q: Vector, d: Vector, radius: f64) -> f64 {
+    let a = d.dot(d) - radius * radius;
+    let b = q.dot(d);
+    let c = q.dot(q);
+    if a.abs() < 1e-12 {
+        return if b == 0.0 { 0.0 } else { c / (2.0 * b) };
+    }
+    let discriminant = (b * b - a * c).max(0.0).sqrt();
+    let t0 = (b + discriminant) / a;
+    let t1 = (b - discriminant) / a;
+    t0.max(t1)
+}
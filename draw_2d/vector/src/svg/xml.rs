@@ -0,0 +1,219 @@
+// A small XML parser that only keeps what SVG documents need: elements and their attributes.
+// Text, comments, processing instructions, doctypes and CDATA sections are skipped.
+
+use crate::svg::SvgError;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct XmlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute_name, _)| attribute_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub fn parse_xml(source: &str) -> Result<XmlElement, SvgError> {
+    let mut parser = Parser {
+        source,
+        position: 0,
+    };
+    parser.skip_misc()?;
+    let root = parser.parse_element()?;
+    parser.skip_misc()?;
+    if parser.position < source.len() {
+        return Err(parser.error());
+    }
+    Ok(root)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn error(&self) -> SvgError {
+        SvgError::Xml {
+            position: self.position,
+        }
+    }
+
+    // Skips whitespace, comments, processing instructions and doctypes outside the root element.
+    fn skip_misc(&mut self) -> Result<(), SvgError> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_doctype()?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_doctype(&mut self) -> Result<(), SvgError> {
+        // A doctype can have an internal subset in brackets, which can contain `>`.
+        let mut depth = 0;
+        for (index, char) in self.rest().char_indices() {
+            match char {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '>' if depth == 0 => {
+                    self.position += index + 1;
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        Err(self.error())
+    }
+
+    fn skip_past(&mut self, pattern: &str) -> Result<(), SvgError> {
+        match self.rest().find(pattern) {
+            Some(index) => {
+                self.position += index + pattern.len();
+                Ok(())
+            }
+            None => Err(self.error()),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn parse_name(&mut self) -> Result<String, SvgError> {
+        let length = self
+            .rest()
+            .find(|char: char| char.is_whitespace() || matches!(char, '=' | '/' | '>'))
+            .unwrap_or(self.rest().len());
+        if length == 0 {
+            return Err(self.error());
+        }
+        let name = self.rest()[..length].to_string();
+        self.position += length;
+        Ok(name)
+    }
+
+    fn parse_element(&mut self) -> Result<XmlElement, SvgError> {
+        if !self.rest().starts_with('<') {
+            return Err(self.error());
+        }
+        self.position += 1;
+        let mut element = XmlElement {
+            name: self.parse_name()?,
+            ..XmlElement::default()
+        };
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let name = self.parse_name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error());
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error()),
+            };
+            self.position += 1;
+            let length = self.rest().find(quote).ok_or_else(|| self.error())?;
+            let value = decode_entities(&self.rest()[..length]);
+            self.position += length + 1;
+            element.attributes.push((name, value));
+        }
+        loop {
+            let text_length = self.rest().find('<').ok_or_else(|| self.error())?;
+            self.position += text_length;
+            if self.rest().starts_with("</") {
+                self.position += 2;
+                let name_position = self.position;
+                if self.parse_name()? != element.name {
+                    return Err(SvgError::Xml {
+                        position: name_position,
+                    });
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.error());
+                }
+                self.position += 1;
+                return Ok(element);
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<![CDATA[") {
+                self.skip_past("]]>")?;
+            } else if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else {
+                element.children.push(self.parse_element()?);
+            }
+        }
+    }
+}
+
+fn decode_entities(string: &str) -> String {
+    if !string.contains('&') {
+        return string.to_string();
+    }
+    let mut decoded = String::with_capacity(string.len());
+    let mut rest = string;
+    while let Some(index) = rest.find('&') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let char = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (entity, char) {
+            (Some(entity), Some(char)) => {
+                decoded.push(char);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
@@ -1,6 +1,6 @@
 use crate::geometry::{LineSegment, Point, Trapezoid};
 use crate::internal_iter::InternalIterator;
-use crate::path::{LinePathCommand, LinePathIterator};
+use crate::path::{FillRule, LinePathCommand, LinePathIterator};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::mem;
@@ -12,6 +12,7 @@ use std::ops::Range;
 pub struct Trapezoidator {
     event_queue: BinaryHeap<Event>,
     active_segments: Vec<ActiveSegment>,
+    fill_rule: FillRule,
 }
 
 impl Trapezoidator {
@@ -20,6 +21,14 @@ impl Trapezoidator {
         Trapezoidator::default()
     }
 
+    /// Creates a new trapezoidator that uses the given rule to decide which regions are inside.
+    pub fn with_fill_rule(fill_rule: FillRule) -> Trapezoidator {
+        Trapezoidator {
+            fill_rule,
+            ..Trapezoidator::default()
+        }
+    }
+
     /// Returns an iterator over trapezoids corresponding to the given iterator over line path
     /// commands.
     pub fn trapezoidate<P: LinePathIterator>(&mut self, path: P)->Option<Trapezoidate>{
@@ -128,6 +137,7 @@ impl Trapezoidator {
         {
             trapezoid_segments.push(trapezoid_segment);
         }
+        self.push_events_for_intersections(point, incident_segment_range);
         self.generate_trapezoids(trapezoid_segments, f)
     }
 
@@ -225,13 +235,14 @@ impl Trapezoidator {
         } else {
             self.active_segments[incident_segment_range.end - 1].upper_region
         };
+        let fill_rule = self.fill_rule;
         self.active_segments.splice(
             incident_segment_range.end..incident_segment_range.end,
             Iterator::map(right_segments.iter(), |right_segment| {
                 let upper_region = {
                     let winding = lower_region.winding + right_segment.winding;
                     Region {
-                        is_inside: winding != 0,
+                        is_inside: fill_rule.is_inside(winding),
                         winding,
                     }
                 };
@@ -274,6 +285,45 @@ impl Trapezoidator {
         Some(self.active_segments[incident_segment_end])
     }
 
+    // Segments that became adjacent at `point` might cross further on. Both are split at the
+    // crossing, so that active segments never cross each other.
+    fn push_events_for_intersections(&mut self, point: Point, incident_segment_range: Range<usize>) {
+        let mut lower_indices = Vec::with_capacity(2);
+        if incident_segment_range.start > 0 {
+            lower_indices.push(incident_segment_range.start - 1);
+        }
+        if incident_segment_range.end > incident_segment_range.start {
+            lower_indices.push(incident_segment_range.end - 1);
+        }
+        for index in lower_indices {
+            if index + 1 >= self.active_segments.len() {
+                continue;
+            }
+            let segment_0 = self.active_segments[index].segment;
+            let segment_1 = self.active_segments[index + 1].segment;
+            let intersection = match segment_0.intersect_with_line_segment(segment_1) {
+                Some(intersection)
+                    if intersection > point
+                        && intersection < segment_0.p1
+                        && intersection < segment_1.p1 =>
+                {
+                    intersection
+                }
+                _ => continue,
+            };
+            for index in index..index + 2 {
+                if let Some(pending_segment) =
+                    self.active_segments[index].split_back_mut(intersection)
+                {
+                    self.event_queue.push(Event {
+                        point: intersection,
+                        pending_segment: Some(pending_segment),
+                    });
+                }
+            }
+        }
+    }
+
     fn generate_trapezoids<F>(&self, trapezoid_segments: &[ActiveSegment], f: &mut F) -> bool
     where
         F: FnMut(Trapezoid) -> bool,
@@ -0,0 +1,587 @@
+//! Glyph outlines from the `CFF` and `CFF2` tables of OpenType fonts.
+//!
+//! Their charstrings describe outlines with cubic Bezier curves, which are approximated by
+//! quadratic ones so glyphs end up in the same `Outline` form as TrueType glyphs.
+
+use super::{slice_from, Error, Reader, Result};
+use crate::font::{Outline, OutlinePoint};
+use crate::geometry::{CubicSegment, Point, Vector};
+use std::convert::TryFrom;
+
+/// The tolerance used to approximate cubic curves by quadratic ones, in font units.
+const CUBIC_TO_QUADRATIC_EPSILON: f64 = 0.5;
+
+/// Charstrings and subroutines can call each other at most this deep.
+const MAX_CALL_DEPTH: usize = 10;
+
+// top and font dict operators
+const CHAR_STRINGS: u16 = 17;
+const PRIVATE: u16 = 18;
+const SUBRS: u16 = 19;
+const VSINDEX: u16 = 22;
+const VSTORE: u16 = 24;
+const FD_ARRAY: u16 = 12 << 8 | 36;
+const FD_SELECT: u16 = 12 << 8 | 37;
+
+/// Parses the outline of every glyph in a `CFF` or `CFF2` table.
+pub fn parse_outlines(bytes: &[u8], is_cff2: bool) -> Result<Vec<Outline>> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(2)?;
+    let header_size = reader.read_u8()? as usize;
+    let (top_dict, global_subrs) = if is_cff2 {
+        let top_dict_length = reader.read_u16()? as usize;
+        let top_dict_bytes = bytes.get(header_size..header_size + top_dict_length).ok_or(Error)?;
+        let mut reader = Reader::new(slice_from(bytes, header_size + top_dict_length)?);
+        (parse_dict(top_dict_bytes)?, Index::parse(&mut reader, true)?)
+    } else {
+        let mut reader = Reader::new(slice_from(bytes, header_size)?);
+        let _names = Index::parse(&mut reader, false)?;
+        let top_dicts = Index::parse(&mut reader, false)?;
+        let _strings = Index::parse(&mut reader, false)?;
+        let global_subrs = Index::parse(&mut reader, false)?;
+        (parse_dict(top_dicts.get(0)?)?, global_subrs)
+    };
+
+    let char_strings_offset = dict_offset(&top_dict, CHAR_STRINGS).ok_or(Error)?;
+    let char_strings = Index::parse(&mut Reader::new(slice_from(bytes, char_strings_offset)?), is_cff2)?;
+
+    // CID keyed fonts and CFF2 fonts have a private dict per font dict, the others just one
+    let mut private_dicts = Vec::new();
+    let mut fd_select = None;
+    if let Some(fd_array_offset) = dict_offset(&top_dict, FD_ARRAY) {
+        let fd_array = Index::parse(&mut Reader::new(slice_from(bytes, fd_array_offset)?), is_cff2)?;
+        for index in 0..fd_array.len() {
+            private_dicts.push(PrivateDict::parse(bytes, &parse_dict(fd_array.get(index)?)?, is_cff2)?);
+        }
+        if let Some(fd_select_offset) = dict_offset(&top_dict, FD_SELECT) {
+            fd_select = Some(parse_fd_select(slice_from(bytes, fd_select_offset)?, char_strings.len())?);
+        }
+    } else {
+        private_dicts.push(PrivateDict::parse(bytes, &top_dict, is_cff2)?);
+    }
+
+    let region_counts = match dict_offset(&top_dict, VSTORE) {
+        Some(offset) if is_cff2 => parse_region_counts(slice_from(bytes, offset)?)?,
+        _ => Vec::new(),
+    };
+
+    let mut outlines = Vec::with_capacity(char_strings.len());
+    for glyph_index in 0..char_strings.len() {
+        let font_dict_index = fd_select.as_ref().map_or(0, |fd_select: &Vec<usize>| fd_select[glyph_index]);
+        let private_dict = private_dicts.get(font_dict_index).ok_or(Error)?;
+        let mut interpreter = CharStringInterpreter::new(&global_subrs, private_dict, &region_counts, is_cff2);
+        interpreter.execute(char_strings.get(glyph_index)?, 0)?;
+        outlines.push(interpreter.finish());
+    }
+    Ok(outlines)
+}
+
+#[derive(Clone, Debug)]
+struct Index<'a> {
+    offsets: Vec<usize>,
+    data: &'a [u8],
+}
+
+impl<'a> Index<'a> {
+    fn parse(reader: &mut Reader<'a>, is_cff2: bool) -> Result<Index<'a>> {
+        let count = if is_cff2 {
+            reader.read_u32()? as usize
+        } else {
+            reader.read_u16()? as usize
+        };
+        if count == 0 {
+            return Ok(Index {
+                offsets: vec![0],
+                data: &[],
+            });
+        }
+        let offset_size = reader.read_u8()? as usize;
+        if !(1..=4).contains(&offset_size) {
+            return Err(Error);
+        }
+        let mut offsets = Vec::with_capacity(count + 1);
+        for _ in 0..=count {
+            let mut offset = 0;
+            for _ in 0..offset_size {
+                offset = offset << 8 | reader.read_u8()? as usize;
+            }
+            // offsets start at 1
+            offsets.push(offset.checked_sub(1).ok_or(Error)?);
+        }
+        let data_length = *offsets.last().unwrap();
+        let data = reader.bytes.get(..data_length).ok_or(Error)?;
+        reader.skip(data_length)?;
+        Ok(Index { offsets, data })
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn get(&self, index: usize) -> Result<&'a [u8]> {
+        let start = *self.offsets.get(index).ok_or(Error)?;
+        let end = *self.offsets.get(index + 1).ok_or(Error)?;
+        self.data.get(start..end).ok_or(Error)
+    }
+
+    // the bias added to subroutine numbers
+    fn subr_bias(&self) -> usize {
+        match self.len() {
+            count if count < 1240 => 107,
+            count if count < 33900 => 1131,
+            _ => 32768,
+        }
+    }
+}
+
+type Dict = Vec<(u16, Vec<f64>)>;
+
+fn parse_dict(bytes: &[u8]) -> Result<Dict> {
+    let mut reader = Reader::new(bytes);
+    let mut dict = Vec::new();
+    let mut operands = Vec::new();
+    while !reader.bytes.is_empty() {
+        let b0 = reader.read_u8()?;
+        match b0 {
+            12 => {
+                let operator = 12 << 8 | reader.read_u8()? as u16;
+                dict.push((operator, std::mem::take(&mut operands)));
+            }
+            0..=27 => dict.push((b0 as u16, std::mem::take(&mut operands))),
+            28 => operands.push(reader.read_i16()? as f64),
+            29 => operands.push(reader.read_u32()? as i32 as f64),
+            30 => operands.push(read_real(&mut reader)?),
+            32..=254 => operands.push(read_small_int(&mut reader, b0)?),
+            _ => return Err(Error),
+        }
+    }
+    Ok(dict)
+}
+
+fn dict_get(dict: &Dict, operator: u16) -> Option<&[f64]> {
+    dict.iter()
+        .find(|(dict_operator, _)| *dict_operator == operator)
+        .map(|(_, operands)| operands.as_slice())
+}
+
+fn dict_offset(dict: &Dict, operator: u16) -> Option<usize> {
+    dict_get(dict, operator)
+        .and_then(|operands| operands.last())
+        .map(|offset| *offset as usize)
+}
+
+// operands encoded in one or two bytes, shared by dicts and charstrings
+fn read_small_int(reader: &mut Reader, b0: u8) -> Result<f64> {
+    Ok(match b0 {
+        32..=246 => b0 as f64 - 139.0,
+        247..=250 => (b0 as f64 - 247.0) * 256.0 + reader.read_u8()? as f64 + 108.0,
+        251..=254 => -(b0 as f64 - 251.0) * 256.0 - reader.read_u8()? as f64 - 108.0,
+        _ => return Err(Error),
+    })
+}
+
+// a real number in dicts, written as nibbles
+fn read_real(reader: &mut Reader) -> Result<f64> {
+    let mut string = String::new();
+    loop {
+        let byte = reader.read_u8()?;
+        for nibble in [byte >> 4, byte & 0xF] {
+            match nibble {
+                0..=9 => string.push((b'0' + nibble) as char),
+                0xA => string.push('.'),
+                0xB => string.push('E'),
+                0xC => string.push_str("E-"),
+                0xE => string.push('-'),
+                0xF => return string.parse().map_err(|_| Error),
+                _ => return Err(Error),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PrivateDict<'a> {
+    subrs: Index<'a>,
+    vsindex: usize,
+}
+
+impl<'a> PrivateDict<'a> {
+    fn parse(bytes: &'a [u8], font_dict: &Dict, is_cff2: bool) -> Result<PrivateDict<'a>> {
+        let mut private_dict = PrivateDict {
+            subrs: Index {
+                offsets: vec![0],
+                data: &[],
+            },
+            vsindex: 0,
+        };
+        let (size, offset) = match dict_get(font_dict, PRIVATE) {
+            Some([size, offset]) => (*size as usize, *offset as usize),
+            _ => return Ok(private_dict),
+        };
+        let private_bytes = bytes.get(offset..offset + size).ok_or(Error)?;
+        let dict = parse_dict(private_bytes)?;
+        if let Some(subrs_offset) = dict_offset(&dict, SUBRS) {
+            let subrs_bytes = slice_from(bytes, offset + subrs_offset)?;
+            private_dict.subrs = Index::parse(&mut Reader::new(subrs_bytes), is_cff2)?;
+        }
+        if let Some(vsindex) = dict_offset(&dict, VSINDEX) {
+            private_dict.vsindex = vsindex;
+        }
+        Ok(private_dict)
+    }
+}
+
+// Returns the font dict index of every glyph.
+fn parse_fd_select(bytes: &[u8], glyph_count: usize) -> Result<Vec<usize>> {
+    let mut reader = Reader::new(bytes);
+    let format = reader.read_u8()?;
+    let mut fd_select = vec![0; glyph_count];
+    match format {
+        0 => {
+            for font_dict_index in &mut fd_select {
+                *font_dict_index = reader.read_u8()? as usize;
+            }
+        }
+        3 | 4 => {
+            let is_format_4 = format == 4;
+            let read_glyph = |reader: &mut Reader| -> Result<usize> {
+                Ok(if is_format_4 {
+                    reader.read_u32()? as usize
+                } else {
+                    reader.read_u16()? as usize
+                })
+            };
+            let range_count = read_glyph(&mut reader)?;
+            let mut first = read_glyph(&mut reader)?;
+            for _ in 0..range_count {
+                let font_dict_index = if is_format_4 {
+                    reader.read_u16()? as usize
+                } else {
+                    reader.read_u8()? as usize
+                };
+                let next = read_glyph(&mut reader)?;
+                if first < next.min(glyph_count) {
+                    fd_select[first..next.min(glyph_count)].fill(font_dict_index);
+                }
+                first = next;
+            }
+        }
+        _ => return Err(Error),
+    }
+    Ok(fd_select)
+}
+
+// Returns the number of regions of each item variation data in a CFF2 variation store,
+// which is what blend needs to know how many deltas to drop.
+fn parse_region_counts(bytes: &[u8]) -> Result<Vec<usize>> {
+    // the store is preceded by its length
+    let store_bytes = slice_from(bytes, 2)?;
+    let mut reader = Reader::new(store_bytes);
+    reader.skip(6)?;
+    let data_count = reader.read_u16()? as usize;
+    let mut region_counts = Vec::with_capacity(data_count);
+    for _ in 0..data_count {
+        let mut data_reader = Reader::new(slice_from(store_bytes, reader.read_u32()? as usize)?);
+        data_reader.skip(4)?;
+        region_counts.push(data_reader.read_u16()? as usize);
+    }
+    Ok(region_counts)
+}
+
+/// Runs a Type 2 charstring and builds the outline it draws. Hints are skipped and variable
+/// fonts are drawn at their default instance.
+struct CharStringInterpreter<'a> {
+    global_subrs: &'a Index<'a>,
+    local_subrs: &'a Index<'a>,
+    region_counts: &'a [usize],
+    is_cff2: bool,
+    vsindex: usize,
+    stack: Vec<f64>,
+    stem_count: usize,
+    position: Point,
+    contour: Vec<OutlinePoint>,
+    outline: Outline,
+    is_done: bool,
+}
+
+impl<'a> CharStringInterpreter<'a> {
+    fn new(
+        global_subrs: &'a Index<'a>,
+        private_dict: &'a PrivateDict<'a>,
+        region_counts: &'a [usize],
+        is_cff2: bool,
+    ) -> CharStringInterpreter<'a> {
+        CharStringInterpreter {
+            global_subrs,
+            local_subrs: &private_dict.subrs,
+            region_counts,
+            is_cff2,
+            vsindex: private_dict.vsindex,
+            stack: Vec::new(),
+            stem_count: 0,
+            position: Point::origin(),
+            contour: Vec::new(),
+            outline: Outline::new(),
+            is_done: false,
+        }
+    }
+
+    fn finish(mut self) -> Outline {
+        self.close_contour();
+        self.outline
+    }
+
+    fn execute(&mut self, bytes: &[u8], depth: usize) -> Result<()> {
+        if depth > MAX_CALL_DEPTH {
+            return Err(Error);
+        }
+        let mut reader = Reader::new(bytes);
+        while !reader.bytes.is_empty() && !self.is_done {
+            let b0 = reader.read_u8()?;
+            match b0 {
+                // hstem, vstem, hstemhm, vstemhm
+                1 | 3 | 18 | 23 => {
+                    self.stem_count += self.stack.len() / 2;
+                    self.stack.clear();
+                }
+                // hintmask, cntrmask
+                19 | 20 => {
+                    // the arguments left on the stack are an implicit vstem
+                    self.stem_count += self.stack.len() / 2;
+                    self.stack.clear();
+                    reader.skip(self.stem_count.div_ceil(8))?;
+                }
+                // rmoveto
+                21 => {
+                    let [dx, dy] = self.last_args::<2>()?;
+                    self.move_to(dx, dy);
+                }
+                // hmoveto
+                22 => {
+                    let [dx] = self.last_args::<1>()?;
+                    self.move_to(dx, 0.0);
+                }
+                // vmoveto
+                4 => {
+                    let [dy] = self.last_args::<1>()?;
+                    self.move_to(0.0, dy);
+                }
+                // rlineto
+                5 => {
+                    for args in self.stack.chunks_exact(2) {
+                        self.position += Vector::new(args[0], args[1]);
+                        self.contour.push(on_curve(self.position));
+                    }
+                    self.stack.clear();
+                }
+                // hlineto, vlineto
+                6 | 7 => {
+                    let mut is_horizontal = b0 == 6;
+                    for index in 0..self.stack.len() {
+                        let d = self.stack[index];
+                        self.position += if is_horizontal {
+                            Vector::new(d, 0.0)
+                        } else {
+                            Vector::new(0.0, d)
+                        };
+                        self.contour.push(on_curve(self.position));
+                        is_horizontal = !is_horizontal;
+                    }
+                    self.stack.clear();
+                }
+                // rrcurveto
+                8 => {
+                    let stack = std::mem::take(&mut self.stack);
+                    for args in stack.chunks_exact(6) {
+                        self.curve_to(args[0], args[1], args[2], args[3], args[4], args[5]);
+                    }
+                }
+                // rcurveline
+                24 => {
+                    let stack = std::mem::take(&mut self.stack);
+                    if stack.len() < 8 {
+                        return Err(Error);
+                    }
+                    let (curves, line) = stack.split_at(stack.len() - 2);
+                    for args in curves.chunks_exact(6) {
+                        self.curve_to(args[0], args[1], args[2], args[3], args[4], args[5]);
+                    }
+                    self.line_to(line[0], line[1]);
+                }
+                // rlinecurve
+                25 => {
+                    let stack = std::mem::take(&mut self.stack);
+                    if stack.len() < 8 {
+                        return Err(Error);
+                    }
+                    let (lines, curve) = stack.split_at(stack.len() - 6);
+                    for args in lines.chunks_exact(2) {
+                        self.line_to(args[0], args[1]);
+                    }
+                    self.curve_to(curve[0], curve[1], curve[2], curve[3], curve[4], curve[5]);
+                }
+                // vvcurveto, hhcurveto
+                26 | 27 => {
+                    let stack = std::mem::take(&mut self.stack);
+                    let (mut first, args) = if stack.len() % 4 == 1 {
+                        (stack[0], &stack[1..])
+                    } else {
+                        (0.0, &stack[..])
+                    };
+                    for args in args.chunks_exact(4) {
+                        if b0 == 26 {
+                            self.curve_to(first, args[0], args[1], args[2], 0.0, args[3]);
+                        } else {
+                            self.curve_to(args[0], first, args[1], args[2], args[3], 0.0);
+                        }
+                        first = 0.0;
+                    }
+                }
+                // vhcurveto, hvcurveto
+                30 | 31 => {
+                    let stack = std::mem::take(&mut self.stack);
+                    let mut is_horizontal = b0 == 31;
+                    let mut index = 0;
+                    while index + 4 <= stack.len() {
+                        let args = &stack[index..];
+                        let last = if stack.len() - index == 5 { args[4] } else { 0.0 };
+                        if is_horizontal {
+                            self.curve_to(args[0], 0.0, args[1], args[2], last, args[3]);
+                        } else {
+                            self.curve_to(0.0, args[0], args[1], args[2], args[3], last);
+                        }
+                        index += 4;
+                        is_horizontal = !is_horizontal;
+                    }
+                }
+                // callsubr, callgsubr
+                10 | 29 => {
+                    let subrs = if b0 == 10 { self.local_subrs } else { self.global_subrs };
+                    let number = self.stack.pop().ok_or(Error)? as i64 + subrs.subr_bias() as i64;
+                    let subr = subrs.get(usize::try_from(number).map_err(|_| Error)?)?;
+                    self.execute(subr, depth + 1)?;
+                }
+                // return
+                11 => return Ok(()),
+                // endchar
+                14 => {
+                    self.stack.clear();
+                    self.is_done = true;
+                }
+                // vsindex
+                15 if self.is_cff2 => {
+                    self.vsindex = self.stack.pop().ok_or(Error)? as usize;
+                    self.stack.clear();
+                }
+                // blend, keeping the default values and dropping the deltas
+                16 if self.is_cff2 => {
+                    let count = self.stack.pop().ok_or(Error)? as usize;
+                    let region_count = *self.region_counts.get(self.vsindex).ok_or(Error)?;
+                    let start = self.stack.len().checked_sub(count * (region_count + 1)).ok_or(Error)?;
+                    self.stack.truncate(start + count);
+                }
+                12 => {
+                    let b1 = reader.read_u8()?;
+                    self.execute_flex(b1)?;
+                }
+                28 => self.stack.push(reader.read_i16()? as f64),
+                32..=254 => {
+                    let value = read_small_int(&mut reader, b0)?;
+                    self.stack.push(value);
+                }
+                255 => self.stack.push(reader.read_u32()? as i32 as f64 / 65536.0),
+                _ => return Err(Error),
+            }
+        }
+        Ok(())
+    }
+
+    fn execute_flex(&mut self, operator: u8) -> Result<()> {
+        let stack = std::mem::take(&mut self.stack);
+        match (operator, stack.as_slice()) {
+            // hflex
+            (34, &[dx1, dx2, dy2, dx3, dx4, dx5, dx6]) => {
+                self.curve_to(dx1, 0.0, dx2, dy2, dx3, 0.0);
+                self.curve_to(dx4, 0.0, dx5, -dy2, dx6, 0.0);
+            }
+            // flex
+            (35, &[dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, dx6, dy6, _]) => {
+                self.curve_to(dx1, dy1, dx2, dy2, dx3, dy3);
+                self.curve_to(dx4, dy4, dx5, dy5, dx6, dy6);
+            }
+            // hflex1
+            (36, &[dx1, dy1, dx2, dy2, dx3, dx4, dx5, dy5, dx6]) => {
+                self.curve_to(dx1, dy1, dx2, dy2, dx3, 0.0);
+                self.curve_to(dx4, 0.0, dx5, dy5, dx6, -(dy1 + dy2 + dy5));
+            }
+            // flex1
+            (37, &[dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, d6]) => {
+                let dx = dx1 + dx2 + dx3 + dx4 + dx5;
+                let dy = dy1 + dy2 + dy3 + dy4 + dy5;
+                let (dx6, dy6) = if dx.abs() > dy.abs() { (d6, -dy) } else { (-dx, d6) };
+                self.curve_to(dx1, dy1, dx2, dy2, dx3, dy3);
+                self.curve_to(dx4, dy4, dx5, dy5, dx6, dy6);
+            }
+            _ => return Err(Error),
+        }
+        Ok(())
+    }
+
+    // The arguments of an operator that may be preceded by the glyph width, which we don't need
+    // since the advance comes from the `hmtx` table.
+    fn last_args<const N: usize>(&mut self) -> Result<[f64; N]> {
+        let start = self.stack.len().checked_sub(N).ok_or(Error)?;
+        let mut args = [0.0; N];
+        args.copy_from_slice(&self.stack[start..]);
+        self.stack.clear();
+        Ok(args)
+    }
+
+    fn move_to(&mut self, dx: f64, dy: f64) {
+        self.close_contour();
+        self.position += Vector::new(dx, dy);
+        self.contour.push(on_curve(self.position));
+    }
+
+    fn line_to(&mut self, dx: f64, dy: f64) {
+        self.position += Vector::new(dx, dy);
+        self.contour.push(on_curve(self.position));
+    }
+
+    fn curve_to(&mut self, dx1: f64, dy1: f64, dx2: f64, dy2: f64, dx3: f64, dy3: f64) {
+        let p0 = self.position;
+        let p1 = p0 + Vector::new(dx1, dy1);
+        let p2 = p1 + Vector::new(dx2, dy2);
+        let p3 = p2 + Vector::new(dx3, dy3);
+        for quadratic in CubicSegment::new(p0, p1, p2, p3).to_quadratics(CUBIC_TO_QUADRATIC_EPSILON) {
+            self.contour.push(OutlinePoint {
+                is_on_curve: false,
+                point: quadratic.p1,
+            });
+            self.contour.push(on_curve(quadratic.p2));
+        }
+        self.position = p3;
+    }
+
+    fn close_contour(&mut self) {
+        // contours are closed implicitly, so a final point on the start point is redundant
+        if self.contour.len() > 1 && self.contour.last() == self.contour.first() {
+            self.contour.pop();
+        }
+        if self.contour.len() > 1 {
+            let mut contour = self.outline.begin_contour();
+            for point in self.contour.drain(..) {
+                contour.push(point);
+            }
+            contour.end();
+        }
+        self.contour.clear();
+    }
+}
+
+fn on_curve(point: Point) -> OutlinePoint {
+    OutlinePoint {
+        is_on_curve: true,
+        point,
+    }
+}
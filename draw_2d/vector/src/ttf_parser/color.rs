@@ -0,0 +1,389 @@
+//! Colour glyphs from the `COLR`/`CPAL`, `CBDT`/`CBLC` and `sbix` tables.
+//!
+//! `COLR` glyphs are read from the version 0 records, which version 1 tables also have.
+//! Bitmaps are only read when they are PNG images, which is what colour fonts use.
+
+use super::{slice_from, Error, Reader, Result};
+use crate::font::{BitmapGlyph, BitmapStrike, ColorGlyphs, ColorLayer};
+use std::{collections::HashMap, ops::Range};
+
+/// The colour tables of a font, all of them optional.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ColorTables<'a> {
+    pub colr: Option<&'a [u8]>,
+    pub cpal: Option<&'a [u8]>,
+    pub cblc: Option<&'a [u8]>,
+    pub cbdt: Option<&'a [u8]>,
+    pub sbix: Option<&'a [u8]>,
+}
+
+impl<'a> ColorTables<'a> {
+    pub fn has_bitmaps(&self) -> bool {
+        (self.cblc.is_some() && self.cbdt.is_some()) || self.sbix.is_some()
+    }
+}
+
+pub fn parse_color_glyphs(tables: ColorTables, glyph_count: usize) -> Result<ColorGlyphs> {
+    let mut color_glyphs = ColorGlyphs::default();
+    if let Some(colr_table_bytes) = tables.colr {
+        let palette = match tables.cpal {
+            Some(cpal_table_bytes) => parse_palette(cpal_table_bytes)?,
+            None => Vec::new(),
+        };
+        color_glyphs.layers = parse_layers(colr_table_bytes, &palette, glyph_count)?;
+    }
+    if let (Some(cblc_table_bytes), Some(cbdt_table_bytes)) = (tables.cblc, tables.cbdt) {
+        color_glyphs
+            .bitmap_strikes
+            .extend(parse_cbdt_strikes(cblc_table_bytes, cbdt_table_bytes)?);
+    }
+    if let Some(sbix_table_bytes) = tables.sbix {
+        color_glyphs
+            .bitmap_strikes
+            .extend(parse_sbix_strikes(sbix_table_bytes, glyph_count)?);
+    }
+    color_glyphs
+        .bitmap_strikes
+        .retain(|strike| !strike.glyphs.is_empty());
+    color_glyphs
+        .bitmap_strikes
+        .sort_by(|a, b| a.ppem.total_cmp(&b.ppem));
+    Ok(color_glyphs)
+}
+
+// Returns the colours of the first palette as RGBA.
+fn parse_palette(bytes: &[u8]) -> Result<Vec<[u8; 4]>> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(2)?;
+    let entry_count = reader.read_u16()? as usize;
+    let palette_count = reader.read_u16()? as usize;
+    reader.skip(2)?;
+    let color_records_offset = reader.read_u32()? as usize;
+    if palette_count == 0 {
+        return Ok(Vec::new());
+    }
+    let first_color_index = reader.read_u16()? as usize;
+    let mut reader = Reader::new(slice_from(bytes, color_records_offset + first_color_index * 4)?);
+    let mut palette = Vec::with_capacity(entry_count);
+    for _ in 0..entry_count {
+        let [b, g, r, a] = reader.read_u32()?.to_be_bytes();
+        palette.push([r, g, b, a]);
+    }
+    Ok(palette)
+}
+
+fn parse_layers(
+    bytes: &[u8],
+    palette: &[[u8; 4]],
+    glyph_count: usize,
+) -> Result<HashMap<usize, Vec<ColorLayer>>> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(2)?;
+    let base_glyph_count = reader.read_u16()? as usize;
+    let base_glyphs_bytes = slice_from(bytes, reader.read_u32()? as usize)?;
+    let layers_bytes = slice_from(bytes, reader.read_u32()? as usize)?;
+    let layer_count = reader.read_u16()? as usize;
+    let mut reader = Reader::new(base_glyphs_bytes);
+    let mut layers = HashMap::new();
+    for _ in 0..base_glyph_count {
+        let glyph_id = reader.read_u16()? as usize;
+        let first_layer_index = reader.read_u16()? as usize;
+        let glyph_layer_count = reader.read_u16()? as usize;
+        if first_layer_index + glyph_layer_count > layer_count {
+            return Err(Error);
+        }
+        let mut layer_reader = Reader::new(slice_from(layers_bytes, first_layer_index * 4)?);
+        let mut glyph_layers = Vec::with_capacity(glyph_layer_count);
+        for _ in 0..glyph_layer_count {
+            let layer_glyph_id = layer_reader.read_u16()? as usize;
+            // palette index 0xFFFF stands for the text colour
+            let color = palette.get(layer_reader.read_u16()? as usize).cloned();
+            if layer_glyph_id < glyph_count {
+                glyph_layers.push(ColorLayer {
+                    glyph_id: layer_glyph_id,
+                    color,
+                });
+            }
+        }
+        if glyph_id < glyph_count && !glyph_layers.is_empty() {
+            layers.insert(glyph_id, glyph_layers);
+        }
+    }
+    Ok(layers)
+}
+
+fn parse_cbdt_strikes(cblc_bytes: &[u8], cbdt_bytes: &[u8]) -> Result<Vec<BitmapStrike>> {
+    let mut reader = Reader::new(cblc_bytes);
+    reader.skip(4)?;
+    let size_count = reader.read_u32()? as usize;
+    let mut strikes = Vec::with_capacity(size_count);
+    for _ in 0..size_count {
+        let index_subtables_bytes = slice_from(cblc_bytes, reader.read_u32()? as usize)?;
+        reader.skip(4)?;
+        let index_subtable_count = reader.read_u32()? as usize;
+        // the colour ref, line metrics and glyph range
+        reader.skip(32)?;
+        reader.skip(1)?;
+        let ppem = reader.read_u8()? as f64;
+        reader.skip(2)?;
+        let mut strike = BitmapStrike {
+            ppem,
+            glyphs: HashMap::new(),
+        };
+        let mut index_subtables_reader = Reader::new(index_subtables_bytes);
+        for _ in 0..index_subtable_count {
+            let first_glyph_id = index_subtables_reader.read_u16()? as usize;
+            let last_glyph_id = index_subtables_reader.read_u16()? as usize;
+            let subtable_bytes = slice_from(index_subtables_bytes, index_subtables_reader.read_u32()? as usize)?;
+            let (image_format, image_data_offset, images) =
+                parse_index_subtable(subtable_bytes, first_glyph_id, last_glyph_id)?;
+            for (glyph_id, range, metrics) in images {
+                let bytes = slice_from(cbdt_bytes, image_data_offset)?
+                    .get(range)
+                    .ok_or(Error)?;
+                if let Some(glyph) = parse_cbdt_glyph(bytes, image_format, metrics)? {
+                    strike.glyphs.insert(glyph_id, glyph);
+                }
+            }
+        }
+        strikes.push(strike);
+    }
+    Ok(strikes)
+}
+
+// The left and bottom of a bitmap, from the height, width and bearings that start both the small
+// and the big glyph metrics.
+type BitmapMetrics = (f64, f64);
+
+fn read_bitmap_metrics(reader: &mut Reader) -> Result<BitmapMetrics> {
+    let height = reader.read_u8()? as f64;
+    reader.skip(1)?;
+    let bearing_x = reader.read_i8()? as f64;
+    let bearing_y = reader.read_i8()? as f64;
+    Ok((bearing_x, bearing_y - height))
+}
+
+// A glyph with the range of its image data, and its metrics if the index subtable has them.
+type IndexedImage = (usize, Range<usize>, Option<BitmapMetrics>);
+
+// Returns the image format, the offset of the image data in the `CBDT` table and its images.
+fn parse_index_subtable(
+    bytes: &[u8],
+    first_glyph_id: usize,
+    last_glyph_id: usize,
+) -> Result<(u16, usize, Vec<IndexedImage>)> {
+    let mut reader = Reader::new(bytes);
+    let index_format = reader.read_u16()?;
+    let image_format = reader.read_u16()?;
+    let image_data_offset = reader.read_u32()? as usize;
+    let glyph_ids = first_glyph_id..last_glyph_id + 1;
+    let mut images = Vec::new();
+    match index_format {
+        1 | 3 => {
+            let mut start = None;
+            for glyph_id in glyph_ids.start..=glyph_ids.end {
+                let offset = if index_format == 1 {
+                    reader.read_u32()? as usize
+                } else {
+                    reader.read_u16()? as usize
+                };
+                if let Some(start) = start.replace(offset) {
+                    if offset > start {
+                        images.push((glyph_id - 1, start..offset, None));
+                    }
+                }
+            }
+        }
+        2 => {
+            let image_size = reader.read_u32()? as usize;
+            let metrics = read_bitmap_metrics(&mut reader)?;
+            for (index, glyph_id) in glyph_ids.enumerate() {
+                images.push((glyph_id, index * image_size..(index + 1) * image_size, Some(metrics)));
+            }
+        }
+        4 => {
+            let glyph_count = reader.read_u32()? as usize;
+            let mut previous = None;
+            for _ in 0..=glyph_count {
+                let glyph_id = reader.read_u16()? as usize;
+                let offset = reader.read_u16()? as usize;
+                if let Some((previous_glyph_id, start)) = previous.replace((glyph_id, offset)) {
+                    images.push((previous_glyph_id, start..offset, None));
+                }
+            }
+        }
+        5 => {
+            let image_size = reader.read_u32()? as usize;
+            let metrics = read_bitmap_metrics(&mut reader)?;
+            reader.skip(4)?;
+            let glyph_count = reader.read_u32()? as usize;
+            for index in 0..glyph_count {
+                let glyph_id = reader.read_u16()? as usize;
+                images.push((glyph_id, index * image_size..(index + 1) * image_size, Some(metrics)));
+            }
+        }
+        _ => return Err(Error),
+    }
+    Ok((image_format, image_data_offset, images))
+}
+
+fn parse_cbdt_glyph(bytes: &[u8], image_format: u16, metrics: Option<BitmapMetrics>) -> Result<Option<BitmapGlyph>> {
+    let mut reader = Reader::new(bytes);
+    let (left, bottom) = match image_format {
+        // small and big glyph metrics
+        17 | 18 => {
+            let metrics = read_bitmap_metrics(&mut reader)?;
+            reader.skip(if image_format == 17 { 1 } else { 4 })?;
+            metrics
+        }
+        19 => metrics.ok_or(Error)?,
+        // monochrome and uncompressed bitmaps
+        _ => return Ok(None),
+    };
+    let length = reader.read_u32()? as usize;
+    let png = reader.bytes.get(..length).ok_or(Error)?.to_vec();
+    Ok(Some(BitmapGlyph { left, bottom, png }))
+}
+
+fn parse_sbix_strikes(bytes: &[u8], glyph_count: usize) -> Result<Vec<BitmapStrike>> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(4)?;
+    let strike_count = reader.read_u32()? as usize;
+    let mut strikes = Vec::with_capacity(strike_count);
+    for _ in 0..strike_count {
+        let strike_bytes = slice_from(bytes, reader.read_u32()? as usize)?;
+        let mut strike_reader = Reader::new(strike_bytes);
+        let mut strike = BitmapStrike {
+            ppem: strike_reader.read_u16()? as f64,
+            glyphs: HashMap::new(),
+        };
+        strike_reader.skip(2)?;
+        let mut dupes = Vec::new();
+        let mut start = strike_reader.read_u32()? as usize;
+        for glyph_id in 0..glyph_count {
+            let end = strike_reader.read_u32()? as usize;
+            if end > start {
+                let mut reader = Reader::new(strike_bytes.get(start..end).ok_or(Error)?);
+                let left = reader.read_i16()? as f64;
+                let bottom = reader.read_i16()? as f64;
+                match &reader.read_u32()?.to_be_bytes() {
+                    b"png " => {
+                        let png = reader.bytes.to_vec();
+                        strike.glyphs.insert(glyph_id, BitmapGlyph { left, bottom, png });
+                    }
+                    // the same image as another glyph
+                    b"dupe" => dupes.push((glyph_id, reader.read_u16()? as usize)),
+                    _ => {}
+                }
+            }
+            start = end;
+        }
+        for (glyph_id, original_glyph_id) in dupes {
+            if let Some(glyph) = strike.glyphs.get(&original_glyph_id).cloned() {
+                strike.glyphs.insert(glyph_id, glyph);
+            }
+        }
+        strikes.push(strike);
+    }
+    Ok(strikes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_u16s(bytes: &mut Vec<u8>, values: &[u16]) {
+        for value in values {
+            bytes.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+
+    #[test]
+    fn colr_layers() {
+        let mut cpal = Vec::new();
+        push_u16s(&mut cpal, &[0, 2, 1, 2]);
+        cpal.extend_from_slice(&14u32.to_be_bytes());
+        push_u16s(&mut cpal, &[0]);
+        // BGRA
+        cpal.extend_from_slice(&[0, 0, 255, 255, 255, 0, 0, 128]);
+        let mut colr = Vec::new();
+        push_u16s(&mut colr, &[0, 1]);
+        colr.extend_from_slice(&14u32.to_be_bytes());
+        colr.extend_from_slice(&20u32.to_be_bytes());
+        push_u16s(&mut colr, &[3]);
+        push_u16s(&mut colr, &[5, 0, 3]);
+        push_u16s(&mut colr, &[6, 1, 7, 0xFFFF, 8, 0]);
+        let tables = ColorTables {
+            colr: Some(&colr),
+            cpal: Some(&cpal),
+            ..ColorTables::default()
+        };
+        let color_glyphs = parse_color_glyphs(tables, 10).unwrap();
+        assert_eq!(
+            color_glyphs.layers[&5],
+            vec![
+                ColorLayer { glyph_id: 6, color: Some([0, 0, 255, 128]) },
+                ColorLayer { glyph_id: 7, color: None },
+                ColorLayer { glyph_id: 8, color: Some([255, 0, 0, 255]) },
+            ]
+        );
+    }
+
+    #[test]
+    fn cbdt_and_sbix_strikes() {
+        // a format 1 index subtable for glyphs 2 and 3, with images in format 17
+        let mut cblc = Vec::new();
+        cblc.extend_from_slice(&0x00030000u32.to_be_bytes());
+        cblc.extend_from_slice(&1u32.to_be_bytes());
+        cblc.extend_from_slice(&56u32.to_be_bytes());
+        cblc.extend_from_slice(&[0; 4]);
+        cblc.extend_from_slice(&1u32.to_be_bytes());
+        cblc.extend_from_slice(&[0; 32]);
+        cblc.extend_from_slice(&[109, 109, 32, 1]);
+        push_u16s(&mut cblc, &[2, 3]);
+        cblc.extend_from_slice(&8u32.to_be_bytes());
+        push_u16s(&mut cblc, &[1, 17]);
+        cblc.extend_from_slice(&4u32.to_be_bytes());
+        for offset in [0u32, 12, 24] {
+            cblc.extend_from_slice(&offset.to_be_bytes());
+        }
+        let mut cbdt = 0x00030000u32.to_be_bytes().to_vec();
+        for png in [b"png1", b"png2"] {
+            cbdt.extend_from_slice(&[20, 22, 2, 16, 24]);
+            cbdt.extend_from_slice(&3u32.to_be_bytes());
+            cbdt.extend_from_slice(&png[..3]);
+        }
+        // an sbix strike where glyph 1 is a duplicate of glyph 0
+        let mut sbix = Vec::new();
+        push_u16s(&mut sbix, &[1, 0]);
+        sbix.extend_from_slice(&1u32.to_be_bytes());
+        sbix.extend_from_slice(&12u32.to_be_bytes());
+        push_u16s(&mut sbix, &[40, 72]);
+        for offset in [24u32, 39, 49, 49, 49] {
+            sbix.extend_from_slice(&offset.to_be_bytes());
+        }
+        push_u16s(&mut sbix, &[0xFFFE, 3]);
+        sbix.extend_from_slice(b"png abcdefg");
+        push_u16s(&mut sbix, &[0, 0]);
+        sbix.extend_from_slice(b"dupe");
+        push_u16s(&mut sbix, &[0]);
+        let tables = ColorTables {
+            cblc: Some(&cblc),
+            cbdt: Some(&cbdt),
+            sbix: Some(&sbix),
+            ..ColorTables::default()
+        };
+        let color_glyphs = parse_color_glyphs(tables, 4).unwrap();
+        assert_eq!(color_glyphs.bitmap_strikes.len(), 2);
+        let sbix_strike = &color_glyphs.bitmap_strikes[0];
+        assert_eq!(sbix_strike.ppem, 40.0);
+        assert_eq!(sbix_strike.glyphs[&0], BitmapGlyph { left: -2.0, bottom: 3.0, png: b"abcdefg".to_vec() });
+        assert_eq!(sbix_strike.glyphs[&1], sbix_strike.glyphs[&0]);
+        let cbdt_strike = &color_glyphs.bitmap_strikes[1];
+        assert_eq!(cbdt_strike.ppem, 109.0);
+        assert_eq!(cbdt_strike.glyphs[&3], BitmapGlyph { left: 2.0, bottom: -4.0, png: b"png".to_vec() });
+        assert_eq!(color_glyphs.bitmap_strike_index(20.0), Some(0));
+        assert_eq!(color_glyphs.bitmap_strike_index(64.0), Some(1));
+        assert_eq!(color_glyphs.bitmap_strike_index(200.0), Some(1));
+    }
+}
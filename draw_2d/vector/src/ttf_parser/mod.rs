@@ -1,9 +1,15 @@
-use crate::font::{TTFFont, Glyph, HorizontalMetrics, Outline, OutlinePoint};
+use crate::font::{
+    Anchor, CharCodeRange, TTFFont, Glyph, HorizontalMetrics, Ligature, MarkAttachment, Outline, OutlinePoint,
+    PairAdjustment, ShapingTables,
+};
 use crate::geometry::{
     AffineTransformation, LinearTransformation, Point, Rectangle, Transform, Vector,
 };
 use crate::internal_iter::ExtendFromInternalIterator;
-use std::{convert::TryFrom, mem, result};
+use std::{collections::HashMap, convert::TryFrom, mem, result};
+
+mod cff;
+mod color;
 
 #[derive(Clone, Debug)]
 pub struct GlyphsParser<'a> {
@@ -99,23 +105,7 @@ impl<'a> GlyphsParser<'a> {
     }
 
     fn parse_horizontal_metrics(&self, index: usize) -> Result<HorizontalMetrics> {
-        let mut reader = Reader::new(self.hmtx_table_bytes);
-        if index < self.advance_width_count {
-            reader.skip(index * 4)?;
-            Ok(HorizontalMetrics {
-                advance_width: reader.read_u16()? as f64,
-                left_side_bearing: reader.read_i16()? as f64,
-            })
-        } else {
-            reader.skip((self.advance_width_count - 1) * 4)?;
-            let advance_width = reader.read_u16()? as f64;
-            reader.skip(2)?;
-            reader.skip((index - self.advance_width_count) * 2)?;
-            Ok(HorizontalMetrics {
-                advance_width,
-                left_side_bearing: reader.read_i16()? as f64,
-            })
-        }
+        parse_horizontal_metrics(self.hmtx_table_bytes, self.advance_width_count, index)
     }
 
     fn parse_simple_glyph(
@@ -505,6 +495,26 @@ impl<'a> Reader<'a> {
     }
 }
 
+fn parse_horizontal_metrics(hmtx_table_bytes: &[u8], advance_width_count: usize, index: usize) -> Result<HorizontalMetrics> {
+    let mut reader = Reader::new(hmtx_table_bytes);
+    if index < advance_width_count {
+        reader.skip(index * 4)?;
+        Ok(HorizontalMetrics {
+            advance_width: reader.read_u16()? as f64,
+            left_side_bearing: reader.read_i16()? as f64,
+        })
+    } else {
+        reader.skip((advance_width_count - 1) * 4)?;
+        let advance_width = reader.read_u16()? as f64;
+        reader.skip(2)?;
+        reader.skip((index - advance_width_count) * 2)?;
+        Ok(HorizontalMetrics {
+            advance_width,
+            left_side_bearing: reader.read_i16()? as f64,
+        })
+    }
+}
+
 pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -513,18 +523,24 @@ pub struct Error;
 pub fn parse_ttf(bytes: &[u8]) -> Result<TTFFont> {
     let mut reader = Reader::new(&bytes[0..12]);
     let sfnt_version = reader.read_u32()?;
-    if ![0x00010000, u32::from_be_bytes(*b"true")].contains(&sfnt_version) {
+    if ![0x00010000, u32::from_be_bytes(*b"true"), u32::from_be_bytes(*b"OTTO")].contains(&sfnt_version) {
         return Err(Error);
     }
     let table_count = reader.read_u16()? as usize;
     reader.skip(6)?;
+    let mut cff_table_bytes = None;
+    let mut cff2_table_bytes = None;
     let mut cmap_table_bytes = None;
     let mut glyf_table_bytes = None;
     let mut head_table_bytes = None;
     let mut hhea_table_bytes = None;
     let mut hmtx_table_bytes = None;
+    let mut kern_table_bytes = None;
+    let mut gpos_table_bytes = None;
+    let mut gsub_table_bytes = None;
     let mut loca_table_bytes = None;
     let mut maxp_table_bytes = None;
+    let mut color_tables = color::ColorTables::default();
     for index in 0..table_count {
         let mut reader = Reader::new(&bytes[(12 + index * 16)..][..16]);
         let table_tag = reader.read_u32()?;
@@ -533,22 +549,30 @@ pub fn parse_ttf(bytes: &[u8]) -> Result<TTFFont> {
         let length = reader.read_u32()? as usize;
         let table_bytes = &bytes[offset..][..length];
         match &table_tag.to_be_bytes() {
+            b"CFF " => cff_table_bytes = Some(table_bytes),
+            b"CFF2" => cff2_table_bytes = Some(table_bytes),
             b"cmap" => cmap_table_bytes = Some(table_bytes),
             b"glyf" => glyf_table_bytes = Some(table_bytes),
             b"head" => head_table_bytes = Some(table_bytes),
             b"hhea" => hhea_table_bytes = Some(table_bytes),
             b"hmtx" => hmtx_table_bytes = Some(table_bytes),
+            b"kern" => kern_table_bytes = Some(table_bytes),
+            b"GPOS" => gpos_table_bytes = Some(table_bytes),
+            b"GSUB" => gsub_table_bytes = Some(table_bytes),
             b"loca" => loca_table_bytes = Some(table_bytes),
+            b"COLR" => color_tables.colr = Some(table_bytes),
+            b"CPAL" => color_tables.cpal = Some(table_bytes),
+            b"CBLC" => color_tables.cblc = Some(table_bytes),
+            b"CBDT" => color_tables.cbdt = Some(table_bytes),
+            b"sbix" => color_tables.sbix = Some(table_bytes),
             b"maxp" => maxp_table_bytes = Some(table_bytes),
             _ => {}
         }
     }
     let cmap_table_bytes = cmap_table_bytes.ok_or(Error)?;
-    let glyf_table_bytes = glyf_table_bytes.ok_or(Error)?;
     let head_table_bytes = head_table_bytes.ok_or(Error)?;
     let hhea_table_bytes = hhea_table_bytes.ok_or(Error)?;
     let hmtx_table_bytes = hmtx_table_bytes.ok_or(Error)?;
-    let loca_table_bytes = loca_table_bytes.ok_or(Error)?;
     let maxp_table_bytes = maxp_table_bytes.ok_or(Error)?;
     let mut reader = Reader::new(hhea_table_bytes);
     reader.skip(4)?;
@@ -560,7 +584,6 @@ pub fn parse_ttf(bytes: &[u8]) -> Result<TTFFont> {
     let mut reader = Reader::new(maxp_table_bytes);
     reader.skip(4)?;
     let glyph_count = reader.read_u16()? as usize;
-    reader.skip(26)?;
     let mut reader = Reader::new(head_table_bytes);
     reader.skip(18)?;
     let units_per_em = reader.read_u16()? as f64;
@@ -572,14 +595,9 @@ pub fn parse_ttf(bytes: &[u8]) -> Result<TTFFont> {
     reader.skip(6)?;
     let index_to_loc_format = IndexToLocFormat::from_i16(reader.read_i16()?).ok_or(Error)?;
     reader.skip(2)?;
-    Ok(TTFFont {
-        units_per_em,
-        ascender,
-        descender,
-        line_gap,
-        bounds,
-        char_code_to_glyph_index_map: parse_char_code_to_glyph_index_map(cmap_table_bytes)?,
-        glyphs: GlyphsParser::new(
+    let (char_code_to_glyph_index_map, supplementary_char_code_ranges) = parse_cmap_table(cmap_table_bytes)?;
+    let glyphs = match (glyf_table_bytes, loca_table_bytes, cff2_table_bytes, cff_table_bytes) {
+        (Some(glyf_table_bytes), Some(loca_table_bytes), _, _) => GlyphsParser::new(
             glyph_count,
             advance_width_count,
             hmtx_table_bytes,
@@ -588,31 +606,141 @@ pub fn parse_ttf(bytes: &[u8]) -> Result<TTFFont> {
             glyf_table_bytes,
         )
         .parse_glyphs()?,
+        (_, _, Some(cff2_table_bytes), _) => {
+            parse_cff_glyphs(cff2_table_bytes, true, hmtx_table_bytes, advance_width_count)?
+        }
+        (_, _, _, Some(cff_table_bytes)) => {
+            parse_cff_glyphs(cff_table_bytes, false, hmtx_table_bytes, advance_width_count)?
+        }
+        // bitmap fonts don't need outlines
+        _ if color_tables.has_bitmaps() => (0..glyph_count)
+            .map(|index| {
+                Ok(Glyph {
+                    horizontal_metrics: parse_horizontal_metrics(hmtx_table_bytes, advance_width_count, index)?,
+                    bounds: Rectangle::default(),
+                    outline: Outline::new(),
+                })
+            })
+            .collect::<Result<_>>()?,
+        _ => return Err(Error),
+    };
+    // a font with layout tables we can't read still renders, just without shaping. substitutions
+    // and colour layers are checked against the glyphs parsed, as a cff charstrings index can
+    // disagree with maxp
+    let shaping = parse_shaping_tables(kern_table_bytes, gpos_table_bytes, gsub_table_bytes, glyphs.len())
+        .unwrap_or_default();
+    let color_glyphs = color::parse_color_glyphs(color_tables, glyphs.len()).unwrap_or_default();
+    Ok(TTFFont {
+        units_per_em,
+        ascender,
+        descender,
+        line_gap,
+        bounds,
+        char_code_to_glyph_index_map,
+        supplementary_char_code_ranges,
+        glyphs,
+        shaping,
+        color_glyphs,
     })
 }
 
-fn parse_char_code_to_glyph_index_map(bytes: &[u8]) -> Result<Vec<usize>> {
+// CFF glyphs have no bounds of their own, so they are computed from their outlines.
+fn parse_cff_glyphs(
+    bytes: &[u8],
+    is_cff2: bool,
+    hmtx_table_bytes: &[u8],
+    advance_width_count: usize,
+) -> Result<Vec<Glyph>> {
+    cff::parse_outlines(bytes, is_cff2)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, outline)| {
+            let bounds = outline
+                .points()
+                .iter()
+                .map(|point| Rectangle::new(point.point, point.point))
+                .reduce(|bounds, point_bounds| {
+                    Rectangle::new(
+                        Point::new(bounds.p_min.x.min(point_bounds.p_min.x), bounds.p_min.y.min(point_bounds.p_min.y)),
+                        Point::new(bounds.p_max.x.max(point_bounds.p_max.x), bounds.p_max.y.max(point_bounds.p_max.y)),
+                    )
+                })
+                .unwrap_or_default();
+            Ok(Glyph {
+                horizontal_metrics: parse_horizontal_metrics(hmtx_table_bytes, advance_width_count, index)?,
+                bounds,
+                outline,
+            })
+        })
+        .collect()
+}
+
+// Returns the map for char codes up to U+FFFF and the ranges above it. Format 12 and 13
+// subtables cover all of unicode and are used instead of the format 4 one when there is one.
+fn parse_cmap_table(bytes: &[u8]) -> Result<(Vec<usize>, Vec<CharCodeRange>)> {
     let mut reader = Reader::new(bytes);
     reader.skip(2)?;
-    let mut subtable_bytes = None;
+    let mut format_4_bytes = None;
+    let mut format_12_bytes = None;
+    let mut format_13_bytes = None;
     let subtable_count = reader.read_u16()? as usize;
     for _ in 0..subtable_count {
         let platform_id = reader.read_u16()?;
         let encoding_id = reader.read_u16()?;
-        let offset = reader.read_u32()? as usize;
+        let subtable_bytes = slice_from(bytes, reader.read_u32()? as usize)?;
         if let (0, _) | (3, 1) | (3, 10) = (platform_id, encoding_id) {
-            subtable_bytes = Some(&bytes[offset..]);
-            break;
+            let (format, bytes) = (Reader::new(subtable_bytes).read_u16()?, &subtable_bytes[2..]);
+            match format {
+                4 => format_4_bytes = format_4_bytes.or(Some(bytes)),
+                12 => format_12_bytes = format_12_bytes.or(Some(bytes)),
+                13 => format_13_bytes = format_13_bytes.or(Some(bytes)),
+                _ => {}
+            }
         }
     }
-    let subtable_bytes = subtable_bytes.ok_or(Error)?;
-    let mut reader = Reader::new(subtable_bytes);
-    let format = reader.read_u16()?;
-    let bytes = &subtable_bytes[2..];
-    match format {
-        4 => parse_char_code_to_glyph_index_map_format_4(bytes),
-        _ => Err(Error),
+    if let Some(bytes) = format_12_bytes {
+        parse_char_code_ranges(bytes, false)
+    } else if let Some(bytes) = format_4_bytes {
+        Ok((parse_char_code_to_glyph_index_map_format_4(bytes)?, Vec::new()))
+    } else if let Some(bytes) = format_13_bytes {
+        parse_char_code_ranges(bytes, true)
+    } else {
+        Err(Error)
+    }
+}
+
+// Parses a format 12 or 13 subtable, which have the same layout.
+fn parse_char_code_ranges(bytes: &[u8], is_many_to_one: bool) -> Result<(Vec<usize>, Vec<CharCodeRange>)> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(10)?;
+    let group_count = reader.read_u32()? as usize;
+    let mut char_code_to_glyph_index_map = Vec::new();
+    let mut ranges = Vec::new();
+    for _ in 0..group_count {
+        let range = CharCodeRange {
+            start: reader.read_u32()?,
+            end: reader.read_u32()?,
+            start_glyph_index: reader.read_u32()? as usize,
+            is_many_to_one,
+        };
+        if range.start > range.end || range.end > 0x10FFFF {
+            return Err(Error);
+        }
+        for code in range.start..=range.end.min(0xFFFF) {
+            char_code_to_glyph_index_map.resize(char_code_to_glyph_index_map.len().max(code as usize + 1), 0);
+            char_code_to_glyph_index_map[code as usize] = range.glyph_index(code);
+        }
+        if range.end >= 0x10000 {
+            let start = range.start.max(0x10000);
+            ranges.push(CharCodeRange {
+                start,
+                start_glyph_index: range.glyph_index(start),
+                ..range
+            });
+        }
     }
+    ranges.sort_by_key(|range| range.start);
+    Ok((char_code_to_glyph_index_map, ranges))
 }
 
 fn parse_char_code_to_glyph_index_map_format_4(bytes: &[u8]) -> Result<Vec<usize>> {
@@ -657,3 +785,409 @@ fn parse_char_code_to_glyph_index_map_format_4(bytes: &[u8]) -> Result<Vec<usize
     }
     Ok(char_code_to_glyph_index_map)
 }
+
+// the scripts whose default language system we take the shaping features from
+const SHAPING_SCRIPTS: [&[u8; 4]; 4] = [b"DFLT", b"latn", b"cyrl", b"grek"];
+
+fn parse_shaping_tables(
+    kern_table_bytes: Option<&[u8]>,
+    gpos_table_bytes: Option<&[u8]>,
+    gsub_table_bytes: Option<&[u8]>,
+    glyph_count: usize,
+) -> Result<ShapingTables> {
+    let mut tables = ShapingTables::default();
+    if let Some(bytes) = kern_table_bytes {
+        tables.kern_pairs = parse_kern_table(bytes)?;
+    }
+    if let Some(bytes) = gpos_table_bytes {
+        for subtables in parse_layout_lookups(bytes, &[b"kern", b"mark", b"mkmk"], 9)? {
+            let mut pair_subtables = Vec::new();
+            for (lookup_type, subtable_bytes) in subtables {
+                match lookup_type {
+                    2 => pair_subtables.push(parse_pair_adjustment(subtable_bytes)?),
+                    4 => tables.mark_to_base.push(parse_mark_attachment(subtable_bytes)?),
+                    6 => tables.mark_to_mark.push(parse_mark_attachment(subtable_bytes)?),
+                    _ => {}
+                }
+            }
+            if !pair_subtables.is_empty() {
+                tables.pair_lookups.push(pair_subtables);
+            }
+        }
+    }
+    if let Some(bytes) = gsub_table_bytes {
+        for subtables in parse_layout_lookups(bytes, &[b"liga"], 7)? {
+            for (lookup_type, subtable_bytes) in subtables {
+                if lookup_type == 4 {
+                    parse_ligatures(subtable_bytes, glyph_count, &mut tables.ligatures)?;
+                }
+            }
+        }
+        for ligatures in tables.ligatures.values_mut() {
+            ligatures.sort_by_key(|ligature| std::cmp::Reverse(ligature.components.len()));
+        }
+    }
+    Ok(tables)
+}
+
+fn parse_kern_table(bytes: &[u8]) -> Result<HashMap<(usize, usize), f64>> {
+    let mut reader = Reader::new(bytes);
+    let mut pairs = HashMap::new();
+    // only the Microsoft version of the table, Apple's has a 32 bit version number
+    if reader.read_u16()? != 0 {
+        return Ok(pairs);
+    }
+    let subtable_count = reader.read_u16()? as usize;
+    let mut offset = 4;
+    for _ in 0..subtable_count {
+        let mut reader = Reader::new(slice_from(bytes, offset)?);
+        reader.skip(2)?;
+        let length = reader.read_u16()? as usize;
+        let coverage = reader.read_u16()?;
+        // format 0, horizontal kerning values
+        if coverage >> 8 == 0 && coverage & 0b111 == 0b001 {
+            let pair_count = reader.read_u16()? as usize;
+            reader.skip(6)?;
+            for _ in 0..pair_count {
+                let left = reader.read_u16()? as usize;
+                let right = reader.read_u16()? as usize;
+                *pairs.entry((left, right)).or_insert(0.0) += reader.read_i16()? as f64;
+            }
+        }
+        offset += length;
+    }
+    Ok(pairs)
+}
+
+// the subtables of a lookup, each with its lookup type
+type LayoutLookup<'a> = Vec<(u16, &'a [u8])>;
+
+// Returns the lookups used by the given features. Extension subtables are replaced by the
+// subtable they point to.
+fn parse_layout_lookups<'a>(
+    bytes: &'a [u8],
+    feature_tags: &[&[u8; 4]],
+    extension_lookup_type: u16,
+) -> Result<Vec<LayoutLookup<'a>>> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(4)?;
+    let script_list_bytes = slice_from(bytes, reader.read_u16()? as usize)?;
+    let feature_list_bytes = slice_from(bytes, reader.read_u16()? as usize)?;
+    let lookup_list_bytes = slice_from(bytes, reader.read_u16()? as usize)?;
+
+    let mut feature_indices = Vec::new();
+    let mut reader = Reader::new(script_list_bytes);
+    let script_count = reader.read_u16()? as usize;
+    for _ in 0..script_count {
+        let script_tag = reader.read_u32()?.to_be_bytes();
+        let script_bytes = slice_from(script_list_bytes, reader.read_u16()? as usize)?;
+        if !SHAPING_SCRIPTS.contains(&&script_tag) {
+            continue;
+        }
+        let default_lang_sys_offset = Reader::new(script_bytes).read_u16()? as usize;
+        if default_lang_sys_offset == 0 {
+            continue;
+        }
+        let mut reader = Reader::new(slice_from(script_bytes, default_lang_sys_offset)?);
+        reader.skip(2)?;
+        let required_feature_index = reader.read_u16()?;
+        if required_feature_index != 0xFFFF {
+            feature_indices.push(required_feature_index as usize);
+        }
+        let feature_index_count = reader.read_u16()? as usize;
+        for _ in 0..feature_index_count {
+            feature_indices.push(reader.read_u16()? as usize);
+        }
+    }
+
+    let mut lookup_indices = Vec::new();
+    let mut reader = Reader::new(feature_list_bytes);
+    let feature_count = reader.read_u16()? as usize;
+    for feature_index in 0..feature_count {
+        let feature_tag = reader.read_u32()?.to_be_bytes();
+        let feature_offset = reader.read_u16()? as usize;
+        if !feature_indices.contains(&feature_index) || !feature_tags.contains(&&feature_tag) {
+            continue;
+        }
+        let mut reader = Reader::new(slice_from(feature_list_bytes, feature_offset)?);
+        reader.skip(2)?;
+        let lookup_index_count = reader.read_u16()? as usize;
+        for _ in 0..lookup_index_count {
+            lookup_indices.push(reader.read_u16()? as usize);
+        }
+    }
+    // lookups are applied in lookup list order
+    lookup_indices.sort_unstable();
+    lookup_indices.dedup();
+
+    let mut reader = Reader::new(lookup_list_bytes);
+    let lookup_count = reader.read_u16()? as usize;
+    let mut lookup_offsets = Vec::with_capacity(lookup_count);
+    for _ in 0..lookup_count {
+        lookup_offsets.push(reader.read_u16()? as usize);
+    }
+    let mut lookups = Vec::new();
+    for lookup_index in lookup_indices {
+        let lookup_bytes = slice_from(lookup_list_bytes, *lookup_offsets.get(lookup_index).ok_or(Error)?)?;
+        let mut reader = Reader::new(lookup_bytes);
+        let lookup_type = reader.read_u16()?;
+        reader.skip(2)?;
+        let subtable_count = reader.read_u16()? as usize;
+        let mut subtables = Vec::with_capacity(subtable_count);
+        for _ in 0..subtable_count {
+            let subtable_bytes = slice_from(lookup_bytes, reader.read_u16()? as usize)?;
+            subtables.push(if lookup_type == extension_lookup_type {
+                let mut reader = Reader::new(subtable_bytes);
+                reader.skip(2)?;
+                let lookup_type = reader.read_u16()?;
+                (lookup_type, slice_from(subtable_bytes, reader.read_u32()? as usize)?)
+            } else {
+                (lookup_type, subtable_bytes)
+            });
+        }
+        lookups.push(subtables);
+    }
+    Ok(lookups)
+}
+
+// Returns the glyphs in a coverage table, in coverage index order.
+fn parse_coverage(bytes: &[u8]) -> Result<Vec<usize>> {
+    let mut reader = Reader::new(bytes);
+    let format = reader.read_u16()?;
+    let mut glyphs = Vec::new();
+    match format {
+        1 => {
+            let glyph_count = reader.read_u16()? as usize;
+            for _ in 0..glyph_count {
+                glyphs.push(reader.read_u16()? as usize);
+            }
+        }
+        2 => {
+            let range_count = reader.read_u16()? as usize;
+            for _ in 0..range_count {
+                let start = reader.read_u16()? as usize;
+                let end = reader.read_u16()? as usize;
+                reader.skip(2)?;
+                glyphs.extend(start..=end);
+            }
+        }
+        _ => return Err(Error),
+    }
+    Ok(glyphs)
+}
+
+// Returns the glyphs with a non-zero class in a class definition table.
+fn parse_class_def(bytes: &[u8]) -> Result<HashMap<usize, usize>> {
+    let mut reader = Reader::new(bytes);
+    let format = reader.read_u16()?;
+    let mut classes = HashMap::new();
+    match format {
+        1 => {
+            let start = reader.read_u16()? as usize;
+            let glyph_count = reader.read_u16()? as usize;
+            for glyph in start..start + glyph_count {
+                let class = reader.read_u16()? as usize;
+                if class != 0 {
+                    classes.insert(glyph, class);
+                }
+            }
+        }
+        2 => {
+            let range_count = reader.read_u16()? as usize;
+            for _ in 0..range_count {
+                let start = reader.read_u16()? as usize;
+                let end = reader.read_u16()? as usize;
+                let class = reader.read_u16()? as usize;
+                for glyph in start..=end {
+                    classes.insert(glyph, class);
+                }
+            }
+        }
+        _ => return Err(Error),
+    }
+    Ok(classes)
+}
+
+// Reads a value record and returns its x advance, the only value we use.
+fn read_value_record_x_advance(reader: &mut Reader, value_format: u16) -> Result<f64> {
+    let mut x_advance = 0.0;
+    for bit in 0..8 {
+        if value_format & (1 << bit) != 0 {
+            let value = reader.read_i16()?;
+            if bit == 2 {
+                x_advance = value as f64;
+            }
+        }
+    }
+    Ok(x_advance)
+}
+
+fn parse_pair_adjustment(bytes: &[u8]) -> Result<PairAdjustment> {
+    let mut reader = Reader::new(bytes);
+    let format = reader.read_u16()?;
+    let coverage = parse_coverage(slice_from(bytes, reader.read_u16()? as usize)?)?;
+    let value_format_1 = reader.read_u16()?;
+    let value_format_2 = reader.read_u16()?;
+    match format {
+        1 => {
+            let pair_set_count = reader.read_u16()? as usize;
+            let mut pairs = HashMap::new();
+            for first in coverage.into_iter().take(pair_set_count) {
+                let mut pair_set_reader = Reader::new(slice_from(bytes, reader.read_u16()? as usize)?);
+                let pair_count = pair_set_reader.read_u16()? as usize;
+                for _ in 0..pair_count {
+                    let second = pair_set_reader.read_u16()? as usize;
+                    let x_advance = read_value_record_x_advance(&mut pair_set_reader, value_format_1)?;
+                    read_value_record_x_advance(&mut pair_set_reader, value_format_2)?;
+                    pairs.entry((first, second)).or_insert(x_advance);
+                }
+            }
+            Ok(PairAdjustment::Pairs(pairs))
+        }
+        2 => {
+            let first_classes = parse_class_def(slice_from(bytes, reader.read_u16()? as usize)?)?;
+            let second_classes = parse_class_def(slice_from(bytes, reader.read_u16()? as usize)?)?;
+            let first_class_count = reader.read_u16()? as usize;
+            let second_class_count = reader.read_u16()? as usize;
+            let mut x_advances = Vec::with_capacity(first_class_count * second_class_count);
+            for _ in 0..first_class_count * second_class_count {
+                x_advances.push(read_value_record_x_advance(&mut reader, value_format_1)?);
+                read_value_record_x_advance(&mut reader, value_format_2)?;
+            }
+            Ok(PairAdjustment::Classes {
+                coverage: coverage.into_iter().collect(),
+                first_classes,
+                second_classes,
+                second_class_count,
+                x_advances,
+            })
+        }
+        _ => Err(Error),
+    }
+}
+
+fn parse_anchor(bytes: &[u8]) -> Result<Anchor> {
+    let mut reader = Reader::new(bytes);
+    reader.skip(2)?;
+    Ok(Anchor {
+        x: reader.read_i16()? as f64,
+        y: reader.read_i16()? as f64,
+    })
+}
+
+// Mark to base and mark to mark subtables have the same layout.
+fn parse_mark_attachment(bytes: &[u8]) -> Result<MarkAttachment> {
+    let mut reader = Reader::new(bytes);
+    if reader.read_u16()? != 1 {
+        return Err(Error);
+    }
+    let mark_coverage = parse_coverage(slice_from(bytes, reader.read_u16()? as usize)?)?;
+    let base_coverage = parse_coverage(slice_from(bytes, reader.read_u16()? as usize)?)?;
+    let mark_class_count = reader.read_u16()? as usize;
+    let mark_array_bytes = slice_from(bytes, reader.read_u16()? as usize)?;
+    let base_array_bytes = slice_from(bytes, reader.read_u16()? as usize)?;
+
+    let mut attachment = MarkAttachment::default();
+    let mut reader = Reader::new(mark_array_bytes);
+    let mark_count = reader.read_u16()? as usize;
+    for mark in mark_coverage.into_iter().take(mark_count) {
+        let class = reader.read_u16()? as usize;
+        let anchor = parse_anchor(slice_from(mark_array_bytes, reader.read_u16()? as usize)?)?;
+        attachment.marks.insert(mark, (class, anchor));
+    }
+    let mut reader = Reader::new(base_array_bytes);
+    let base_count = reader.read_u16()? as usize;
+    for base in base_coverage.into_iter().take(base_count) {
+        let mut anchors = Vec::with_capacity(mark_class_count);
+        for _ in 0..mark_class_count {
+            let offset = reader.read_u16()? as usize;
+            anchors.push(if offset == 0 {
+                None
+            } else {
+                Some(parse_anchor(slice_from(base_array_bytes, offset)?)?)
+            });
+        }
+        attachment.bases.insert(base, anchors);
+    }
+    Ok(attachment)
+}
+
+fn parse_ligatures(
+    bytes: &[u8],
+    glyph_count: usize,
+    ligatures: &mut HashMap<usize, Vec<Ligature>>,
+) -> Result<()> {
+    let mut reader = Reader::new(bytes);
+    if reader.read_u16()? != 1 {
+        return Err(Error);
+    }
+    let coverage = parse_coverage(slice_from(bytes, reader.read_u16()? as usize)?)?;
+    let ligature_set_count = reader.read_u16()? as usize;
+    for first in coverage.into_iter().take(ligature_set_count) {
+        let ligature_set_bytes = slice_from(bytes, reader.read_u16()? as usize)?;
+        let mut ligature_set_reader = Reader::new(ligature_set_bytes);
+        let ligature_count = ligature_set_reader.read_u16()? as usize;
+        for _ in 0..ligature_count {
+            let mut reader = Reader::new(slice_from(ligature_set_bytes, ligature_set_reader.read_u16()? as usize)?);
+            let glyph = reader.read_u16()? as usize;
+            let component_count = reader.read_u16()? as usize;
+            let mut components = Vec::with_capacity(component_count);
+            for _ in 1..component_count {
+                components.push(reader.read_u16()? as usize);
+            }
+            if glyph < glyph_count {
+                ligatures.entry(first).or_default().push(Ligature { components, glyph });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn slice_from(bytes: &[u8], offset: usize) -> Result<&[u8]> {
+    bytes.get(offset..).ok_or(Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmap_table(format: u16, groups: &[(u32, u32, u32)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0u16.to_be_bytes());
+        bytes.extend_from_slice(&1u16.to_be_bytes());
+        bytes.extend_from_slice(&3u16.to_be_bytes());
+        bytes.extend_from_slice(&10u16.to_be_bytes());
+        bytes.extend_from_slice(&12u32.to_be_bytes());
+        bytes.extend_from_slice(&format.to_be_bytes());
+        bytes.extend_from_slice(&0u16.to_be_bytes());
+        bytes.extend_from_slice(&(16 + groups.len() as u32 * 12).to_be_bytes());
+        bytes.extend_from_slice(&0u32.to_be_bytes());
+        bytes.extend_from_slice(&(groups.len() as u32).to_be_bytes());
+        for (start, end, glyph_index) in groups {
+            bytes.extend_from_slice(&start.to_be_bytes());
+            bytes.extend_from_slice(&end.to_be_bytes());
+            bytes.extend_from_slice(&glyph_index.to_be_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn cmap_format_12() {
+        let bytes = cmap_table(12, &[(0x1F600, 0x1F602, 20), (0x41, 0x43, 10), (0xFFFE, 0x10001, 30)]);
+        let (map, ranges) = parse_cmap_table(&bytes).unwrap();
+        assert_eq!(&map[0x41..=0x43], &[10, 11, 12]);
+        assert_eq!(&map[0xFFFE..], &[30, 31]);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!((ranges[0].start, ranges[0].end, ranges[0].glyph_index(0x10001)), (0x10000, 0x10001, 33));
+        assert_eq!(ranges[1].glyph_index(0x1F602), 22);
+    }
+
+    #[test]
+    fn cmap_format_13() {
+        let bytes = cmap_table(13, &[(0x20000, 0x2A6DF, 7)]);
+        let (map, ranges) = parse_cmap_table(&bytes).unwrap();
+        assert!(map.is_empty());
+        assert_eq!(ranges[0].glyph_index(0x20000), 7);
+        assert_eq!(ranges[0].glyph_index(0x2A6DF), 7);
+    }
+}
@@ -0,0 +1,360 @@
+use makepad_vector::font::TTFFont;
+use makepad_vector::geometry::Point;
+use makepad_vector::ttf_parser::parse_ttf;
+
+// The fonts in these tests are put together here, since they only need a handful of glyphs:
+// an empty .notdef, a square for 'A' and a curved shape for 'O' that goes through a subroutine.
+
+const RMOVETO: u8 = 21;
+const HLINETO: u8 = 6;
+const RLINETO: u8 = 5;
+const RRCURVETO: u8 = 8;
+const CALLSUBR: u8 = 10;
+const RETURN: u8 = 11;
+const ENDCHAR: u8 = 14;
+const HSTEMHM: u8 = 18;
+const HINTMASK: u8 = 19;
+const BLEND: u8 = 16;
+
+fn char_string(operands: &[i16], operator: u8) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for operand in operands {
+        bytes.push(28);
+        bytes.extend_from_slice(&operand.to_be_bytes());
+    }
+    bytes.push(operator);
+    bytes
+}
+
+fn index(items: &[Vec<u8>], is_cff2: bool) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    if is_cff2 {
+        bytes.extend_from_slice(&(items.len() as u32).to_be_bytes());
+    } else {
+        bytes.extend_from_slice(&(items.len() as u16).to_be_bytes());
+    }
+    if items.is_empty() {
+        return bytes;
+    }
+    bytes.push(4);
+    let mut offset = 1u32;
+    bytes.extend_from_slice(&offset.to_be_bytes());
+    for item in items {
+        offset += item.len() as u32;
+        bytes.extend_from_slice(&offset.to_be_bytes());
+    }
+    for item in items {
+        bytes.extend_from_slice(item);
+    }
+    bytes
+}
+
+// every operand is written in 5 bytes, so the size of a dict doesn't depend on its offsets
+fn dict(entries: &[(&[usize], &[u8])]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for (operands, operator) in entries {
+        for operand in *operands {
+            bytes.push(29);
+            bytes.extend_from_slice(&(*operand as u32).to_be_bytes());
+        }
+        bytes.extend_from_slice(operator);
+    }
+    bytes
+}
+
+fn local_subrs() -> Vec<u8> {
+    let mut curve = char_string(&[100, 0, 100, 100, 0, 100], RRCURVETO);
+    curve.push(RETURN);
+    index(&[curve], false)
+}
+
+fn cff_table() -> Vec<u8> {
+    let notdef = vec![ENDCHAR];
+    // a width, a hinted stem and a hint mask before the square itself
+    let mut square = char_string(&[500, 0, 700], HSTEMHM);
+    square.extend_from_slice(&[HINTMASK, 0x80]);
+    square.extend(char_string(&[100, 0], RMOVETO));
+    square.extend(char_string(&[500, 700, -500], HLINETO));
+    square.push(ENDCHAR);
+    let mut curve = char_string(&[0, 0], RMOVETO);
+    curve.extend(char_string(&[-107], CALLSUBR));
+    curve.extend(char_string(&[-200, 0], RLINETO));
+    curve.push(ENDCHAR);
+    let char_strings = index(&[notdef, square, curve], false);
+    let subrs = local_subrs();
+
+    let header = [1, 0, 4, 4];
+    let names = index(&[b"Test".to_vec()], false);
+    let strings = index(&[], false);
+    let global_subrs = index(&[], false);
+    let private_dict = dict(&[(&[6], &[19])]);
+    let top_dict = |char_strings_offset: usize, private_offset: usize| {
+        dict(&[
+            (&[char_strings_offset], &[17]),
+            (&[private_dict.len(), private_offset], &[18]),
+        ])
+    };
+    let top_dicts_length = index(&[top_dict(0, 0)], false).len();
+    let char_strings_offset = header.len() + names.len() + top_dicts_length + strings.len() + global_subrs.len();
+    let private_offset = char_strings_offset + char_strings.len();
+
+    let mut bytes = header.to_vec();
+    bytes.extend(names);
+    bytes.extend(index(&[top_dict(char_strings_offset, private_offset)], false));
+    bytes.extend(strings);
+    bytes.extend(global_subrs);
+    bytes.extend(char_strings);
+    bytes.extend(private_dict);
+    bytes.extend(subrs);
+    bytes
+}
+
+fn cff2_table() -> Vec<u8> {
+    // the square again, with its start point blended over one region
+    let mut square = char_string(&[100, 0, 50, 0, 2], BLEND);
+    square.extend(char_string(&[], RMOVETO));
+    square.extend(char_string(&[500, 700, -500], HLINETO));
+    let char_strings = index(&[vec![], square], true);
+
+    let header = [2, 0, 5];
+    let global_subrs = index(&[], true);
+    let mut variation_store = Vec::new();
+    variation_store.extend_from_slice(&1u16.to_be_bytes());
+    // the region list and the item variation data
+    variation_store.extend_from_slice(&12u32.to_be_bytes());
+    variation_store.extend_from_slice(&1u16.to_be_bytes());
+    variation_store.extend_from_slice(&22u32.to_be_bytes());
+    variation_store.extend_from_slice(&[0, 1, 0, 1, 0, 0, 0x40, 0, 0x40, 0]);
+    variation_store.extend_from_slice(&[0, 0, 0, 0, 0, 1, 0, 0]);
+    let mut vstore = (variation_store.len() as u16).to_be_bytes().to_vec();
+    vstore.extend(variation_store);
+    let private_dict: Vec<u8> = Vec::new();
+    let font_dict = |private_offset: usize| dict(&[(&[private_dict.len(), private_offset], &[18])]);
+    let top_dict = |char_strings_offset: usize, fd_array_offset: usize, vstore_offset: usize| {
+        dict(&[
+            (&[char_strings_offset], &[17]),
+            (&[fd_array_offset], &[12, 36]),
+            (&[vstore_offset], &[24]),
+        ])
+    };
+    let top_dict_length = top_dict(0, 0, 0).len();
+    let char_strings_offset = header.len() + 2 + top_dict_length + global_subrs.len();
+    let fd_array_offset = char_strings_offset + char_strings.len();
+    let vstore_offset = fd_array_offset + index(&[font_dict(0)], true).len();
+    let private_offset = vstore_offset + vstore.len();
+
+    let mut bytes = header.to_vec();
+    bytes.extend_from_slice(&(top_dict_length as u16).to_be_bytes());
+    bytes.extend(top_dict(char_strings_offset, fd_array_offset, vstore_offset));
+    bytes.extend(global_subrs);
+    bytes.extend(char_strings);
+    bytes.extend(index(&[font_dict(private_offset)], true));
+    bytes.extend(vstore);
+    bytes.extend(private_dict);
+    bytes
+}
+
+fn otf(outline_table: (&[u8; 4], Vec<u8>), glyph_count: u16) -> Vec<u8> {
+    otf_with_tables(outline_table, glyph_count, Vec::new())
+}
+
+fn otf_with_tables(outline_table: (&[u8; 4], Vec<u8>), glyph_count: u16, extra_tables: Vec<(&[u8; 4], Vec<u8>)>) -> Vec<u8> {
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+    let mut hhea = vec![0; 36];
+    hhea[4..6].copy_from_slice(&800i16.to_be_bytes());
+    hhea[6..8].copy_from_slice(&(-200i16).to_be_bytes());
+    hhea[34..36].copy_from_slice(&glyph_count.to_be_bytes());
+    // version 0.5, which is all a CFF font has
+    let mut maxp = vec![0, 0, 0x50, 0];
+    maxp.extend_from_slice(&glyph_count.to_be_bytes());
+    let mut hmtx = Vec::new();
+    for advance_width in [500u16, 700, 250].iter().take(glyph_count as usize) {
+        hmtx.extend_from_slice(&advance_width.to_be_bytes());
+        hmtx.extend_from_slice(&0i16.to_be_bytes());
+    }
+    let mut cmap = vec![0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 12];
+    // a format 4 subtable mapping 'A' and 'O' to glyphs 1 and 2
+    let segments: [(u16, u16); 3] = [(b'A' as u16, 1), (b'O' as u16, 2), (0xFFFF, 0)];
+    cmap.extend_from_slice(&[0, 4, 0, 40, 0, 0, 0, 6, 0, 4, 0, 1, 0, 2]);
+    for (code, _) in segments {
+        cmap.extend_from_slice(&code.to_be_bytes());
+    }
+    cmap.extend_from_slice(&[0, 0]);
+    for (code, _) in segments {
+        cmap.extend_from_slice(&code.to_be_bytes());
+    }
+    for (code, glyph) in segments {
+        cmap.extend_from_slice(&glyph.wrapping_sub(code).to_be_bytes());
+    }
+    cmap.extend_from_slice(&[0; 6]);
+
+    let mut tables = vec![
+        (outline_table.0, outline_table.1),
+        (b"cmap", cmap),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"maxp", maxp),
+    ];
+    tables.extend(extra_tables);
+    let mut bytes = b"OTTO".to_vec();
+    bytes.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+    bytes.extend_from_slice(&[0; 6]);
+    let mut offset = 12 + tables.len() * 16;
+    for (tag, table) in &tables {
+        bytes.extend_from_slice(*tag);
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&(offset as u32).to_be_bytes());
+        bytes.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len();
+    }
+    for (_, table) in &tables {
+        bytes.extend_from_slice(table);
+    }
+    bytes
+}
+
+fn on_curve_points(font: &TTFFont, c: char) -> Vec<Point> {
+    let glyph = font.get_glyph(c).unwrap();
+    glyph
+        .outline
+        .points()
+        .iter()
+        .filter(|point| point.is_on_curve)
+        .map(|point| point.point)
+        .collect()
+}
+
+fn square() -> Vec<Point> {
+    vec![
+        Point::new(100.0, 0.0),
+        Point::new(600.0, 0.0),
+        Point::new(600.0, 700.0),
+        Point::new(100.0, 700.0),
+    ]
+}
+
+#[test]
+fn cff_outlines() {
+    let font = parse_ttf(&otf((b"CFF ", cff_table()), 3)).unwrap();
+    assert_eq!(font.glyphs.len(), 3);
+    assert_eq!(font.glyphs[0].outline.points().len(), 0);
+
+    let glyph = font.get_glyph('A').unwrap();
+    assert_eq!(glyph.horizontal_metrics.advance_width, 700.0);
+    assert_eq!(glyph.outline.contours().count(), 1);
+    assert_eq!(on_curve_points(&font, 'A'), square());
+    assert_eq!(glyph.bounds.p_min, Point::new(100.0, 0.0));
+    assert_eq!(glyph.bounds.p_max, Point::new(600.0, 700.0));
+
+    // the cubic curve becomes quadratic ones that stay close to it
+    let glyph = font.get_glyph('O').unwrap();
+    let points = on_curve_points(&font, 'O');
+    assert!(points.len() > 3);
+    assert_eq!(points.first(), Some(&Point::new(0.0, 0.0)));
+    assert_eq!(points.last(), Some(&Point::new(0.0, 200.0)));
+    assert!(points.contains(&Point::new(200.0, 200.0)));
+    for point in &points[1..points.len() - 1] {
+        let t = cubic_parameter(*point);
+        let x = 3.0 * (1.0 - t) * (1.0 - t) * t * 100.0 + 3.0 * (1.0 - t) * t * t * 200.0 + t * t * t * 200.0;
+        assert!((point.x - x).abs() < 1.0, "{:?} is not on the curve", point);
+    }
+    assert!((glyph.bounds.p_max.x - 200.0).abs() < 1e-6);
+    assert!((glyph.bounds.p_max.y - 200.0).abs() < 1e-6);
+}
+
+// the parameter at which the curve from the 'O' glyph has the given y coordinate
+fn cubic_parameter(point: Point) -> f64 {
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..50 {
+        let t: f64 = (low + high) / 2.0;
+        let y = 3.0 * (1.0 - t) * t * t * 100.0 + t * t * t * 200.0;
+        if y < point.y {
+            low = t;
+        } else {
+            high = t;
+        }
+    }
+    low
+}
+
+#[test]
+fn cff2_outlines_use_the_default_instance() {
+    let font = parse_ttf(&otf((b"CFF2", cff2_table()), 2)).unwrap();
+    assert_eq!(font.glyphs.len(), 2);
+    assert_eq!(on_curve_points(&font, 'A'), square());
+}
+
+#[test]
+fn colr_layers_are_bounded_by_the_char_strings() {
+    // maxp claims 10 glyphs but there are only 3 char strings. 'A' is drawn with the layers
+    // 2 and 8, and 8 doesn't exist
+    let mut colr = Vec::new();
+    for value in [0u16, 1, 0, 14, 0, 20, 2, 1, 0, 2, 2, 0, 8, 0] {
+        colr.extend_from_slice(&value.to_be_bytes());
+    }
+    let mut cpal = Vec::new();
+    for value in [0u16, 1, 1, 1, 0, 14, 0] {
+        cpal.extend_from_slice(&value.to_be_bytes());
+    }
+    cpal.extend_from_slice(&[0, 0, 255, 255]);
+    let font = parse_ttf(&otf_with_tables((b"CFF ", cff_table()), 10, vec![(b"COLR", colr), (b"CPAL", cpal)])).unwrap();
+    assert_eq!(font.glyphs.len(), 3);
+    let layers: Vec<usize> = font.color_glyphs.layers[&1].iter().map(|layer| layer.glyph_id).collect();
+    assert_eq!(layers, vec![2]);
+}
+
+// A subset of IBM Plex Sans Text (.notdef, space, 'A', 'H', 'O' and 'o') with its outlines
+// converted to CFF and its names changed, as the Reserved Font Name asks. See fonts/OFL.txt.
+const SUBSET_SANS: &[u8] = include_bytes!("fonts/SubsetSans-Regular.otf");
+
+#[test]
+fn otf_subset_metrics() {
+    let font = parse_ttf(SUBSET_SANS).unwrap();
+    assert_eq!(font.units_per_em, 1000.0);
+    assert_eq!(font.ascender, 1025.0);
+    assert_eq!(font.descender, -275.0);
+    assert_eq!(font.line_gap, 0.0);
+    assert_eq!(font.bounds.p_min, Point::new(-269.0, -251.0));
+    assert_eq!(font.bounds.p_max, Point::new(1262.0, 1125.0));
+    assert_eq!(font.glyphs.len(), 6);
+    for (c, advance_width, left_side_bearing) in [(' ', 236.0, 0.0), ('A', 649.0, 22.0), ('H', 710.0, 90.0), ('O', 708.0, 56.0), ('o', 562.0, 45.0)] {
+        let metrics = &font.get_glyph(c).unwrap().horizontal_metrics;
+        assert_eq!(metrics.advance_width, advance_width, "advance width of {:?}", c);
+        assert_eq!(metrics.left_side_bearing, left_side_bearing, "left side bearing of {:?}", c);
+    }
+}
+
+#[test]
+fn otf_subset_outlines() {
+    let font = parse_ttf(SUBSET_SANS).unwrap();
+    assert_eq!(font.get_glyph(' ').unwrap().outline.points().len(), 0);
+
+    // 'H' only has straight lines, so its points are exactly those of the TrueType original
+    let glyph = font.get_glyph('H').unwrap();
+    assert_eq!(glyph.outline.contours().count(), 1);
+    let points = [
+        (523, 310), (187, 310), (187, 0), (90, 0), (90, 698), (187, 698),
+        (187, 396), (523, 396), (523, 698), (620, 698), (620, 0), (523, 0),
+    ];
+    let points: Vec<Point> = points.iter().map(|&(x, y)| Point::new(x as f64, y as f64)).collect();
+    assert_eq!(on_curve_points(&font, 'H'), points);
+    assert_eq!(glyph.bounds.p_min, Point::new(90.0, 0.0));
+    assert_eq!(glyph.bounds.p_max, Point::new(620.0, 698.0));
+
+    let glyph = font.get_glyph('A').unwrap();
+    assert_eq!(glyph.outline.contours().count(), 2);
+    assert_eq!(glyph.bounds.p_min, Point::new(22.0, 0.0));
+    assert_eq!(glyph.bounds.p_max, Point::new(627.0, 698.0));
+
+    // the curves of 'O' still pass through the on-curve points of the original, at its extremes
+    let glyph = font.get_glyph('O').unwrap();
+    assert_eq!(glyph.outline.contours().count(), 2);
+    assert_eq!(glyph.bounds.p_min, Point::new(56.0, -12.0));
+    assert_eq!(glyph.bounds.p_max, Point::new(652.0, 710.0));
+    let points = on_curve_points(&font, 'O');
+    for (x, y) in [(354, -12), (56, 349), (354, 710), (652, 349), (354, 75), (548, 292), (548, 406), (354, 623), (160, 406), (160, 292)] {
+        assert!(points.contains(&Point::new(x as f64, y as f64)), "({}, {}) is not on the outline", x, y);
+    }
+}
@@ -0,0 +1,166 @@
+use makepad_vector::geometry::{Point, Vector};
+use makepad_vector::internal_iter::InternalIterator;
+use makepad_vector::path::{
+    parse_svg_path, FillRule, LineCap, LineJoin, LinePath, LinePathCommand, LinePathIterator, Path,
+    PathCommand, PathIterator, StrokeStyle, SvgPathError,
+};
+use makepad_vector::trapezoidator::Trapezoidator;
+
+fn commands(path: &Path) -> Vec<PathCommand> {
+    Iterator::collect(path.commands())
+}
+
+fn area(path: &LinePath, fill_rule: FillRule) -> f64 {
+    let mut area = 0.0;
+    Trapezoidator::with_fill_rule(fill_rule)
+        .trapezoidate(path.commands())
+        .unwrap()
+        .for_each(&mut |trapezoid| {
+            let width = (trapezoid.xs[1] - trapezoid.xs[0]) as f64;
+            let height_0 = (trapezoid.ys[2] - trapezoid.ys[0]) as f64;
+            let height_1 = (trapezoid.ys[3] - trapezoid.ys[1]) as f64;
+            area += width * (height_0 + height_1) / 2.0;
+            true
+        });
+    area
+}
+
+fn stroke_area(d: &str, style: &StrokeStyle) -> f64 {
+    let path = parse_svg_path(d).unwrap();
+    let stroke = path.commands().linearize(0.01).stroke(style, 0.01);
+    area(&stroke, FillRule::NonZero)
+}
+
+fn assert_approx_eq(a: f64, b: f64, epsilon: f64) {
+    assert!((a - b).abs() <= epsilon, "{} != {}", a, b);
+}
+
+#[test]
+fn test_parse_svg_path() {
+    let p = Point::new;
+    let path = parse_svg_path("M10,20L30 40h5v-5zl1 1m.5.5-1-1").unwrap();
+    assert_eq!(
+        commands(&path),
+        [
+            PathCommand::MoveTo(p(10.0, 20.0)),
+            PathCommand::LineTo(p(30.0, 40.0)),
+            PathCommand::LineTo(p(35.0, 40.0)),
+            PathCommand::LineTo(p(35.0, 35.0)),
+            PathCommand::Close,
+            // a contour after a close starts where the closed one did
+            PathCommand::MoveTo(p(10.0, 20.0)),
+            PathCommand::LineTo(p(11.0, 21.0)),
+            PathCommand::MoveTo(p(11.5, 21.5)),
+            PathCommand::LineTo(p(10.5, 20.5)),
+        ]
+    );
+
+    // smooth curves reflect the last control point of the same kind of curve
+    let path = parse_svg_path("M0 0C0 10 10 10 10 0S20-10 20 0Q25 5 30 0T40 0t10 0").unwrap();
+    assert_eq!(
+        commands(&path)[1..],
+        [
+            PathCommand::CubicTo(p(0.0, 10.0), p(10.0, 10.0), p(10.0, 0.0)),
+            PathCommand::CubicTo(p(10.0, -10.0), p(20.0, -10.0), p(20.0, 0.0)),
+            PathCommand::QuadraticTo(p(25.0, 5.0), p(30.0, 0.0)),
+            PathCommand::QuadraticTo(p(35.0, -5.0), p(40.0, 0.0)),
+            PathCommand::QuadraticTo(p(45.0, 5.0), p(50.0, 0.0)),
+        ]
+    );
+
+    assert_eq!(parse_svg_path("").unwrap(), Path::new());
+    assert_eq!(parse_svg_path("L0 0"), Err(SvgPathError { position: 0 }));
+    assert_eq!(parse_svg_path("M0 0 L1 x"), Err(SvgPathError { position: 8 }));
+    assert_eq!(parse_svg_path("M0 0 X1 1"), Err(SvgPathError { position: 5 }));
+    assert_eq!(parse_svg_path("M0 0 A1 1 0 2 0 1 1"), Err(SvgPathError { position: 12 }));
+}
+
+#[test]
+fn test_arc() {
+    // compact flags, and a half circle around (10, 0)
+    let path = parse_svg_path("M0 0a10 10 0 0010 10A10 10 0 0 0 20 0").unwrap();
+    let mut points = Vec::new();
+    path.commands().linearize(0.001).for_each(&mut |command| {
+        points.push(match command {
+            LinePathCommand::MoveTo(p) | LinePathCommand::LineTo(p) => p,
+            LinePathCommand::Close => unreachable!(),
+        });
+        true
+    });
+    assert_eq!(*points.last().unwrap(), Point::new(20.0, 0.0));
+    for p in &points {
+        assert_approx_eq((*p - Point::new(10.0, 0.0)).length(), 10.0, 0.01);
+    }
+    assert!(points.iter().all(|p| p.y >= -0.01));
+
+    // radii that are too small are scaled up
+    let mut path = Path::new();
+    path.move_to(Point::new(0.0, 0.0));
+    path.arc_to(Vector::new(1.0, 1.0), 0.0, false, true, Point::new(20.0, 0.0));
+    assert_eq!(path.current_point(), Some(Point::new(20.0, 0.0)));
+}
+
+#[test]
+fn test_stroke() {
+    let style = StrokeStyle {
+        width: 2.0,
+        ..StrokeStyle::default()
+    };
+    assert_approx_eq(stroke_area("M0 0H10", &style), 20.0, 1e-3);
+    let square = StrokeStyle {
+        cap: LineCap::Square,
+        ..style.clone()
+    };
+    assert_approx_eq(stroke_area("M0 0H10", &square), 24.0, 1e-3);
+    // a point with a square cap is a square
+    assert_approx_eq(stroke_area("M5 5Z", &square), 4.0, 1e-3);
+    let round = StrokeStyle {
+        cap: LineCap::Round,
+        ..style.clone()
+    };
+    assert_approx_eq(stroke_area("M0 0H10", &round), 20.0 + std::f64::consts::PI, 0.05);
+
+    assert_approx_eq(stroke_area("M0 0H10V10", &style), 40.0, 1e-3);
+    let bevel = StrokeStyle {
+        join: LineJoin::Bevel,
+        ..style.clone()
+    };
+    assert_approx_eq(stroke_area("M0 0H10V10", &bevel), 39.5, 1e-3);
+    // the miter of a sharp corner exceeds the limit, so it is beveled
+    let limited = StrokeStyle {
+        miter_limit: 1.0,
+        ..style.clone()
+    };
+    assert_approx_eq(stroke_area("M0 0H10V10", &limited), 39.5, 1e-3);
+
+    // a closed square has joins at every corner, and no caps
+    assert_approx_eq(stroke_area("M0 0H10V10H0Z", &style), 12.0 * 12.0 - 8.0 * 8.0, 1e-3);
+
+    let dashed = StrokeStyle {
+        dashes: vec![2.0, 3.0],
+        ..style.clone()
+    };
+    assert_approx_eq(stroke_area("M0 0H10", &dashed), 8.0, 1e-3);
+    let offset = StrokeStyle {
+        dash_offset: 1.0,
+        ..dashed.clone()
+    };
+    assert_approx_eq(stroke_area("M0 0H10", &offset), 2.0 + 4.0 + 2.0, 1e-3);
+}
+
+#[test]
+fn test_fill_rule() {
+    let path = parse_svg_path("M0 0H10V10H0ZM2 2H8V8H2Z").unwrap();
+    let path: LinePath = path.commands().linearize(0.01).collect();
+    assert_approx_eq(area(&path, FillRule::NonZero), 100.0, 1e-3);
+    assert_approx_eq(area(&path, FillRule::EvenOdd), 64.0, 1e-3);
+
+    // contours that cross each other
+    let path = parse_svg_path("M0 0H10V10H0ZM5 5H15V15H5Z").unwrap();
+    let path: LinePath = path.commands().linearize(0.01).collect();
+    assert_approx_eq(area(&path, FillRule::NonZero), 175.0, 1e-3);
+    assert_approx_eq(area(&path, FillRule::EvenOdd), 150.0, 1e-3);
+    let path = parse_svg_path("M0 0L10 10V0L0 10Z").unwrap();
+    let path: LinePath = path.commands().linearize(0.01).collect();
+    assert_approx_eq(area(&path, FillRule::NonZero), 50.0, 1e-3);
+}
@@ -0,0 +1,78 @@
+use makepad_vector::font::{Ligature, TTFFont};
+use makepad_vector::ttf_parser::parse_ttf;
+use std::{fs, path::Path};
+
+fn load_font(name: &str) -> TTFFont {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../widgets/resources").join(name);
+    parse_ttf(&fs::read(path).unwrap()).unwrap()
+}
+
+fn default_advance(font: &TTFFont, c: char) -> f64 {
+    font.glyphs[font.glyph_index(c)].horizontal_metrics.advance_width
+}
+
+#[test]
+fn kerning() {
+    let font = load_font("IBMPlexSans-Text.ttf");
+    for text in ["AV", "ТА", "ΑΥ"] {
+        let shaped = font.shape(text);
+        let first = text.chars().next().unwrap();
+        assert_eq!(shaped.len(), 2);
+        assert!(shaped[0].x_advance < default_advance(&font, first), "{} is not kerned", text);
+    }
+    // kerning skips over marks
+    let shaped = font.shape("A\u{301}V");
+    assert_eq!(shaped[0].x_advance, font.shape("AV")[0].x_advance);
+}
+
+#[test]
+fn ligatures_and_marks_keep_clusters() {
+    let font = load_font("IBMPlexSans-Text.ttf");
+    let shaped = font.shape("fix");
+    assert_eq!(shaped.len(), 2);
+    assert_eq!((shaped[0].cluster, shaped[0].char_count), (0, 2));
+    assert_eq!((shaped[1].cluster, shaped[1].char_count), (2, 1));
+
+    let shaped = font.shape("e\u{301}x");
+    assert_eq!(shaped.len(), 3);
+    assert_eq!(shaped[0].char_count, 2);
+    assert_eq!(shaped[1].char_count, 0);
+    assert_eq!(shaped[1].x_advance, 0.0);
+    // the mark is pulled back over its base
+    assert!(shaped[1].x_offset < 0.0 && shaped[1].x_offset > -shaped[0].x_advance);
+    assert_eq!(shaped[2].cluster, "e\u{301}".len());
+    assert_eq!(shaped.iter().map(|glyph| glyph.char_count).sum::<usize>(), 3);
+}
+
+#[test]
+fn fonts_without_layout_tables_use_default_advances() {
+    let font = load_font("LiberationMono-Regular.ttf");
+    let shaped = font.shape("AVfi");
+    assert_eq!(shaped.len(), 4);
+    for (glyph, c) in shaped.iter().zip("AVfi".chars()) {
+        assert_eq!(glyph.x_advance, default_advance(&font, c));
+    }
+}
+
+#[test]
+fn glyph_coverage() {
+    let font = load_font("IBMPlexSans-Text.ttf");
+    assert!(font.has_glyphs_for("e\u{301}"));
+    // joiners and variation selectors don't need glyphs
+    assert!(font.has_glyphs_for("a\u{200D}b\u{FE0F}"));
+    assert!(!font.has_glyphs_for("\u{1F600}"));
+    assert_eq!(font.glyph_index('\u{1F600}'), 0);
+    assert!(font.get_glyph('\u{1F600}').is_some());
+}
+
+#[test]
+fn ligatures_to_missing_glyphs_show_the_missing_glyph() {
+    let mut font = load_font("LiberationMono-Regular.ttf");
+    let f = font.glyph_index('f');
+    let i = font.glyph_index('i');
+    font.shaping.ligatures.insert(f, vec![Ligature { components: vec![i], glyph: font.glyphs.len() + 5 }]);
+    let shaped = font.shape("fix");
+    assert_eq!(shaped.len(), 2);
+    assert_eq!((shaped[0].glyph_id, shaped[0].char_count), (0, 2));
+    assert_eq!(shaped[0].x_advance, font.glyphs[0].horizontal_metrics.advance_width);
+}
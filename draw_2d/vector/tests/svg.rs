@@ -0,0 +1,184 @@
+use makepad_vector::geometry::{Point, Transform, Vector};
+use makepad_vector::path::{FillRule, LineCap, LineJoin};
+use makepad_vector::svg::{parse_svg, SvgError, SvgGradientKind, SvgGradientUnits, SvgPaint};
+
+const RED: u32 = 0xFFFF0000;
+
+#[test]
+fn test_parse_document() {
+    let document = parse_svg(
+        r##"<?xml version="1.0" encoding="UTF-8"?>
+        <!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">
+        <svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"
+            width="48" viewBox="0 0 24 12">
+            <!-- a comment -->
+            <defs>
+                <linearGradient id="base" x2="0" y2="1"><stop offset="0" stop-color="red"/><stop offset="100%" style="stop-color:#00f;stop-opacity:.5"/></linearGradient>
+                <linearGradient id="derived" xlink:href="#base" gradientUnits="userSpaceOnUse" x1="1" y1="2" x2="3" y2="4"/>
+                <rect id="square" width="2" height="2"/>
+            </defs>
+            <title>Shapes &amp; things</title>
+            <g fill="url(#derived)" stroke="#123" stroke-width="2" transform="translate(1 2) scale(2)">
+                <path d="M0 0h4v4z" style="stroke-linecap: round; stroke-linejoin: bevel" fill-rule="evenodd"/>
+                <circle r="1" fill="none" opacity="0.5"/>
+                <line x1="0" y1="0" x2="1" y2="1" stroke-width="50%" display="none"/>
+            </g>
+            <use xlink:href="#square" x="10" fill="rgb(255, 0, 0)"/>
+            <rect width="100%" height="1" fill="url(#missing) green" stroke="none"/>
+        </svg>"##,
+    )
+    .unwrap();
+    assert_eq!(document.width, 48.0);
+    assert_eq!(document.height, 24.0);
+    assert_eq!(document.view_box.p_max, Point::new(24.0, 12.0));
+    assert_eq!(document.shapes.len(), 4);
+
+    let path = &document.shapes[0];
+    assert_eq!(
+        Point::new(1.0, 1.0).transform(&path.transform),
+        Point::new(3.0, 4.0)
+    );
+    let fill = path.fill.as_ref().unwrap();
+    assert_eq!(fill.rule, FillRule::EvenOdd);
+    match &fill.paint {
+        SvgPaint::Gradient(gradient) => {
+            assert_eq!(gradient.units, SvgGradientUnits::UserSpaceOnUse);
+            assert_eq!(
+                gradient.kind,
+                SvgGradientKind::Linear {
+                    start: Point::new(1.0, 2.0),
+                    end: Point::new(3.0, 4.0)
+                }
+            );
+            assert_eq!(gradient.stops.len(), 2);
+            assert_eq!(gradient.stops[1].offset, 1.0);
+            assert_eq!(gradient.stops[1].color, [0.0, 0.0, 1.0, 0.5]);
+        }
+        paint => panic!("expected a gradient, got {:?}", paint),
+    }
+    let stroke = path.stroke.as_ref().unwrap();
+    assert_eq!(stroke.style.width, 2.0);
+    assert_eq!(stroke.style.cap, LineCap::Round);
+    assert_eq!(stroke.style.join, LineJoin::Bevel);
+    assert_eq!(
+        stroke.paint,
+        SvgPaint::Color([0x11 as f32 / 255.0, 0x22 as f32 / 255.0, 0x33 as f32 / 255.0, 1.0])
+    );
+
+    let circle = &document.shapes[1];
+    assert!(circle.fill.is_none());
+    assert_eq!(circle.stroke.as_ref().unwrap().opacity, 0.5);
+
+    let square = &document.shapes[2];
+    assert_eq!(square.fill.as_ref().unwrap().paint, SvgPaint::Color([1.0, 0.0, 0.0, 1.0]));
+    assert_eq!(
+        Point::origin().transform(&square.transform),
+        Point::new(10.0, 0.0)
+    );
+
+    // an unresolved paint server falls back to the color after it
+    let rect = &document.shapes[3];
+    assert_eq!(
+        rect.fill.as_ref().unwrap().paint,
+        SvgPaint::Color([0.0, 128.0 / 255.0, 0.0, 1.0])
+    );
+    assert!(rect.stroke.is_none());
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse_svg("<html></html>"), Err(SvgError::NotSvg));
+    assert_eq!(parse_svg("<svg><g></svg>"), Err(SvgError::Xml { position: 10 }));
+    assert_eq!(parse_svg("<svg width=10/>"), Err(SvgError::Xml { position: 11 }));
+}
+
+#[test]
+fn test_view_box_transformation() {
+    let document = parse_svg(r#"<svg viewBox="10 10 20 10"></svg>"#).unwrap();
+    assert_eq!((document.width, document.height), (20.0, 10.0));
+    // the view box is centered in a viewport with a different aspect ratio
+    let transformation = document.view_box_transformation(40.0, 40.0);
+    assert_eq!(Point::new(10.0, 10.0).transform(&transformation), Point::new(0.0, 10.0));
+    assert_eq!(Point::new(30.0, 20.0).transform(&transformation), Point::new(40.0, 30.0));
+    let document =
+        parse_svg(r#"<svg viewBox="0 0 20 10" preserveAspectRatio="none"></svg>"#).unwrap();
+    let transformation = document.view_box_transformation(40.0, 40.0);
+    assert_eq!(Vector::new(1.0, 1.0).transform(&transformation), Vector::new(2.0, 4.0));
+}
+
+#[test]
+fn test_rasterize() {
+    let document = parse_svg(
+        r#"<svg viewBox="0 0 4 4">
+            <rect width="2" height="4" fill="red"/>
+            <rect x="2" width="2" height="4" fill="red" fill-opacity="0.5"/>
+        </svg>"#,
+    )
+    .unwrap();
+    let pixels = document.rasterize(4, 4);
+    assert_eq!(pixels.len(), 16);
+    for row in pixels.chunks(4) {
+        assert_eq!(row, [RED, RED, 0x80FF0000, 0x80FF0000]);
+    }
+
+    // the same document at twice the resolution
+    let pixels = document.rasterize(8, 8);
+    assert_eq!(pixels[0], RED);
+    assert_eq!(pixels[7], 0x80FF0000);
+
+    // half covered pixels are half transparent
+    let document = parse_svg(
+        r#"<svg viewBox="0 0 2 1"><rect width="1.5" height="1" fill="red"/></svg>"#,
+    )
+    .unwrap();
+    assert_eq!(document.rasterize(2, 1), [RED, 0x80FF0000]);
+
+    // the hole of a ring is empty under the even-odd rule only
+    let ring = |rule: &str| {
+        let document = parse_svg(&format!(
+            r#"<svg viewBox="0 0 3 3"><path d="M0 0H3V3H0ZM1 1H2V2H1Z" fill-rule="{}"/></svg>"#,
+            rule
+        ))
+        .unwrap();
+        document.rasterize(3, 3)[4]
+    };
+    assert_eq!(ring("nonzero"), 0xFF000000);
+    assert_eq!(ring("evenodd"), 0);
+
+    // a horizontal stroke through the middle row
+    let document = parse_svg(
+        r#"<svg viewBox="0 0 3 3"><line x1="0" y1="1.5" x2="3" y2="1.5" stroke="red"/></svg>"#,
+    )
+    .unwrap();
+    assert_eq!(document.rasterize(3, 3), [0, 0, 0, RED, RED, RED, 0, 0, 0]);
+}
+
+#[test]
+fn test_rasterize_gradients() {
+    let document = parse_svg(
+        r#"<svg viewBox="0 0 4 1">
+            <linearGradient id="gradient"><stop offset="0" stop-color="black"/><stop offset="1" stop-color="white"/></linearGradient>
+            <rect width="4" height="1" fill="url(#gradient)"/>
+        </svg>"#,
+    )
+    .unwrap();
+    let pixels = document.rasterize(4, 1);
+    let red = |pixel: u32| (pixel >> 16) & 0xFF;
+    // the gradient is sampled at pixel centers, across the bounding box of the shape
+    assert_eq!(red(pixels[0]), 32);
+    assert_eq!(red(pixels[3]), 223);
+    assert!(pixels.iter().all(|pixel| pixel >> 24 == 0xFF));
+
+    let document = parse_svg(
+        r#"<svg viewBox="0 0 5 5">
+            <radialGradient id="gradient" spreadMethod="pad"><stop offset="0" stop-color="white"/><stop offset="1" stop-color="black"/></radialGradient>
+            <rect width="5" height="5" fill="url(#gradient)"/>
+        </svg>"#,
+    )
+    .unwrap();
+    let pixels = document.rasterize(5, 5);
+    assert_eq!(red(pixels[12]), 255);
+    // the corners lie outside the circle, so they get the last color
+    assert_eq!(red(pixels[0]), 0);
+    assert!(red(pixels[11]) > 0 && red(pixels[11]) < 255);
+}
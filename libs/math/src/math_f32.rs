@@ -329,8 +329,33 @@ impl Vec4 {
             w: self.w
         }
     }
-    
-    
+
+    pub fn from_hsla(hsl: Vec4) -> Vec4 {
+        fn clamp(x: f32, mi: f32, ma: f32) -> f32 {if x < mi {mi} else if x > ma {ma} else {x}}
+        fn fract(x: f32) -> f32 {x - x.floor()}
+        fn abs(x: f32) -> f32 {x.abs()}
+        let c = (1.0 - abs(2.0 * hsl.z - 1.0)) * hsl.y;
+        let h = fract(hsl.x);
+        Vec4 {
+            x: hsl.z + c * (clamp(abs(fract(h + 1.0) * 6.0 - 3.0) - 1.0, 0.0, 1.0) - 0.5),
+            y: hsl.z + c * (clamp(abs(fract(h + 2.0 / 3.0) * 6.0 - 3.0) - 1.0, 0.0, 1.0) - 0.5),
+            z: hsl.z + c * (clamp(abs(fract(h + 1.0 / 3.0) * 6.0 - 3.0) - 1.0, 0.0, 1.0) - 0.5),
+            w: hsl.w
+        }
+    }
+
+    pub fn to_hsla(&self) -> Vec4 {
+        let hsv = self.to_hsva();
+        let l = hsv.z * (1.0 - 0.5 * hsv.y);
+        let s = if l <= 0.0 || l >= 1.0 {0.0} else {(hsv.z - l) / l.min(1.0 - l)};
+        Vec4 {
+            x: hsv.x,
+            y: s,
+            z: l,
+            w: self.w
+        }
+    }
+
     pub fn from_u32(val: u32) -> Vec4 {
         Vec4 {
             x: ((val >> 24) & 0xff) as f32 / 255.0,
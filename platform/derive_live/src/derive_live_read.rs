@@ -131,7 +131,7 @@ fn derive_live_read_impl_inner(parser: &mut TokenParser, tb: &mut TokenBuilder)
                     tb.add("            LiveId(").suf_u64(LiveId::from_str(&item.name).unwrap().0).add(")");
                     tb.add("        )));");
                     for field in fields {
-                        tb.ident(&format!("prefix_{}", field.name)).add(".live_read_to(LiveId(").suf_u64(LiveId::from_str(&field.name).unwrap().0).add(", out);");
+                        tb.ident(&format!("prefix_{}", field.name)).add(".live_read_to(LiveId(").suf_u64(LiveId::from_str(&field.name).unwrap().0).add("), out);");
                     }
                     tb.add("        out.push(LiveNode::from_id_value(id, LiveValue::Close));");
                     tb.add("    },");
@@ -14,6 +14,7 @@ pub mod live_ptr;
 pub mod live_eval;
 pub mod live_component;
 pub mod live_node_cbor;
+pub mod live_node_printer;
 //pub mod live_node_cbor;
 pub mod live_node_reader;
 
@@ -60,6 +61,9 @@ pub use {
        live_node_cbor::{
             LiveNodeSliceToCbor,
             LiveNodeVecFromCbor
+        },
+        live_node_printer::{
+            LiveNodeSliceToLive
         },/*
         live_node_msgpack::{
             LiveNodeSliceToMsgPack,
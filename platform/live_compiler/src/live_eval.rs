@@ -14,7 +14,7 @@ pub use {
 };
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum LiveEval {
     Float64(f64),
     Vec2(Vec2),
@@ -61,7 +61,8 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
         LiveValue::FittedString(_) |
         LiveValue::InlineString(_) |
         LiveValue::DocumentString {..} => {
-            LiveEval::String(live_registry.live_node_as_string(&nodes[*index]).unwrap())
+            *index += 1;
+            LiveEval::String(live_registry.live_node_as_string(&nodes[*index - 1]).unwrap())
         }
         LiveValue::Float32(v) => {
             *index += 1;
@@ -187,27 +188,16 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
             }
         }
         LiveValue::ExprCall {ident, args} => {
+            let call_index = *index;
             *index += 1;
-            match ident {
-                live_id!(blend) if *args == 2 => {
-                    let a = live_eval(live_registry, start, index, nodes)?;
-                    let b = live_eval(live_registry, start, index, nodes)?;
-                    if let LiveEval::Vec4(va) = a {
-                        if let LiveEval::Vec4(vb) = b {
-                            // ok so how do we blend this eh.
-                            return Ok(LiveEval::Vec4(vec4(
-                                va.x + (vb.x - va.x) * vb.w,
-                                va.y + (vb.y - va.y) * vb.w,
-                                va.z + (vb.z - va.z) * vb.w,
-                                va.w
-                            )))
-                        }
-                    }
-                }
-                _ => {}
+            let mut argv = Vec::new();
+            for _ in 0..*args {
+                argv.push(live_eval(live_registry, start, index, nodes)?);
             }
-            
-            return Err(LiveError::eval_error_expression_call_not_implemented(live_error_origin!(), *index, nodes, *ident, *args))
+            if let Some(ret) = live_eval_call(*ident, &argv) {
+                return Ok(ret)
+            }
+            return Err(LiveError::eval_error_expression_call_not_implemented(live_error_origin!(), call_index, nodes, *ident, *args))
         }
         LiveValue::ExprBinOp(op) => {
             *index += 1;
@@ -255,7 +245,10 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec4(vb) => LiveEval::Bool(va == vb),
                         _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    LiveEval::String(va) => match b {
+                        LiveEval::String(vb) => LiveEval::Bool(va == vb),
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, LiveEval::String(va), b))
+                    }
                 },
                 LiveBinOp::Ne => match a {
                     LiveEval::Bool(va) => match b {
@@ -284,7 +277,10 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
                         LiveEval::Vec4(vb) => LiveEval::Bool(va != vb),
                         _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
                     }
-                    _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, a, b))
+                    LiveEval::String(va) => match b {
+                        LiveEval::String(vb) => LiveEval::Bool(va != vb),
+                        _ => return Err(LiveError::eval_error_binop_undefined_in_expression(live_error_origin!(), *index, nodes, *op, LiveEval::String(va), b))
+                    }
                 },
                 LiveBinOp::Lt => match a {
                     LiveEval::Int64(va) => match b {
@@ -492,3 +488,148 @@ pub fn live_eval(live_registry: &LiveRegistry, start: usize, index: &mut usize,
         }
     })
 }
+
+impl LiveEval {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            LiveEval::Float64(v) => Some(*v),
+            LiveEval::Int64(v) => Some(*v as f64),
+            _ => None
+        }
+    }
+    
+    fn as_vec4(&self) -> Option<Vec4> {
+        match self {
+            LiveEval::Vec4(v) => Some(*v),
+            _ => None
+        }
+    }
+    
+    // applies f per component, integers stay integers if f keeps them whole
+    fn map(&self, f: &dyn Fn(f64) -> f64) -> Option<LiveEval> {
+        let g = | v: f32 | f(v as f64) as f32;
+        Some(match self {
+            LiveEval::Int64(v) => LiveEval::Int64(f(*v as f64) as i64),
+            LiveEval::Float64(v) => LiveEval::Float64(f(*v)),
+            LiveEval::Vec2(v) => LiveEval::Vec2(vec2(g(v.x), g(v.y))),
+            LiveEval::Vec3(v) => LiveEval::Vec3(vec3(g(v.x), g(v.y), g(v.z))),
+            LiveEval::Vec4(v) => LiveEval::Vec4(vec4(g(v.x), g(v.y), g(v.z), g(v.w))),
+            _ => return None
+        })
+    }
+    
+    // applies f per component, scalars are broadcast over vectors
+    fn zip(&self, other: &LiveEval, f: &dyn Fn(f64, f64) -> f64) -> Option<LiveEval> {
+        let g = | a: f32, b: f32 | f(a as f64, b as f64) as f32;
+        Some(match (self, other) {
+            (LiveEval::Int64(a), LiveEval::Int64(b)) => {
+                let r = f(*a as f64, *b as f64);
+                if r.fract() == 0.0 {LiveEval::Int64(r as i64)} else {LiveEval::Float64(r)}
+            }
+            (LiveEval::Vec2(a), LiveEval::Vec2(b)) => LiveEval::Vec2(vec2(g(a.x, b.x), g(a.y, b.y))),
+            (LiveEval::Vec3(a), LiveEval::Vec3(b)) => LiveEval::Vec3(vec3(g(a.x, b.x), g(a.y, b.y), g(a.z, b.z))),
+            (LiveEval::Vec4(a), LiveEval::Vec4(b)) => LiveEval::Vec4(vec4(g(a.x, b.x), g(a.y, b.y), g(a.z, b.z), g(a.w, b.w))),
+            (LiveEval::Vec2(a), b) => {
+                let b = b.as_f64()? as f32;
+                LiveEval::Vec2(vec2(g(a.x, b), g(a.y, b)))
+            }
+            (LiveEval::Vec3(a), b) => {
+                let b = b.as_f64()? as f32;
+                LiveEval::Vec3(vec3(g(a.x, b), g(a.y, b), g(a.z, b)))
+            }
+            (LiveEval::Vec4(a), b) => {
+                let b = b.as_f64()? as f32;
+                LiveEval::Vec4(vec4(g(a.x, b), g(a.y, b), g(a.z, b), g(a.w, b)))
+            }
+            (a, LiveEval::Vec2(b)) => {
+                let a = a.as_f64()? as f32;
+                LiveEval::Vec2(vec2(g(a, b.x), g(a, b.y)))
+            }
+            (a, LiveEval::Vec3(b)) => {
+                let a = a.as_f64()? as f32;
+                LiveEval::Vec3(vec3(g(a, b.x), g(a, b.y), g(a, b.z)))
+            }
+            (a, LiveEval::Vec4(b)) => {
+                let a = a.as_f64()? as f32;
+                LiveEval::Vec4(vec4(g(a, b.x), g(a, b.y), g(a, b.z), g(a, b.w)))
+            }
+            (a, b) => LiveEval::Float64(f(a.as_f64()?, b.as_f64()?))
+        })
+    }
+}
+
+// the builtin function library usable in live expressions.
+// hues in hsl/hsla are in 0..1 like Vec4::from_hsva, all other colour components are 0..1 as well
+pub fn live_eval_call(ident: LiveId, argv: &[LiveEval]) -> Option<LiveEval> {
+    match (ident, argv) {
+        (live_id!(blend), [a, b]) => {
+            let va = a.as_vec4()?;
+            let vb = b.as_vec4()?;
+            Some(LiveEval::Vec4(vec4(
+                va.x + (vb.x - va.x) * vb.w,
+                va.y + (vb.y - va.y) * vb.w,
+                va.z + (vb.z - va.z) * vb.w,
+                va.w
+            )))
+        }
+        (live_id!(cond), [c, a, b]) |
+        (live_id!(if), [c, a, b]) => match c {
+            LiveEval::Bool(true) => Some(a.clone()),
+            LiveEval::Bool(false) => Some(b.clone()),
+            _ => None
+        }
+        (live_id!(mix), [a, b, t]) => {
+            let t = t.as_f64()?;
+            match a.zip(b, &| a, b | a + (b - a) * t)? {
+                LiveEval::Int64(v) => Some(LiveEval::Float64(v as f64)),
+                v => Some(v)
+            }
+        }
+        (live_id!(min), [a, b]) => a.zip(b, &| a, b | a.min(b)),
+        (live_id!(max), [a, b]) => a.zip(b, &| a, b | a.max(b)),
+        (live_id!(clamp), [x, lo, hi]) => x.zip(lo, &| a, b | a.max(b))?.zip(hi, &| a, b | a.min(b)),
+        (live_id!(abs), [x]) => x.map(&| a | a.abs()),
+        (live_id!(floor), [x]) => x.map(&| a | a.floor()),
+        (live_id!(round), [x]) => x.map(&| a | a.round()),
+        (live_id!(rgb), [r, g, b]) => Some(LiveEval::Vec4(vec4(
+            r.as_f64()? as f32,
+            g.as_f64()? as f32,
+            b.as_f64()? as f32,
+            1.0
+        ))),
+        (live_id!(rgba), [r, g, b, a]) => Some(LiveEval::Vec4(vec4(
+            r.as_f64()? as f32,
+            g.as_f64()? as f32,
+            b.as_f64()? as f32,
+            a.as_f64()? as f32
+        ))),
+        (live_id!(hsl), [h, s, l]) => Some(LiveEval::Vec4(Vec4::from_hsla(vec4(
+            h.as_f64()? as f32,
+            s.as_f64()? as f32,
+            l.as_f64()? as f32,
+            1.0
+        )))),
+        (live_id!(hsla), [h, s, l, a]) => Some(LiveEval::Vec4(Vec4::from_hsla(vec4(
+            h.as_f64()? as f32,
+            s.as_f64()? as f32,
+            l.as_f64()? as f32,
+            a.as_f64()? as f32
+        )))),
+        (live_id!(lighten), [c, amount]) => {
+            let mut hsl = c.as_vec4()?.to_hsla();
+            hsl.z = (hsl.z + amount.as_f64()? as f32).clamp(0.0, 1.0);
+            Some(LiveEval::Vec4(Vec4::from_hsla(hsl)))
+        }
+        (live_id!(darken), [c, amount]) => {
+            let mut hsl = c.as_vec4()?.to_hsla();
+            hsl.z = (hsl.z - amount.as_f64()? as f32).clamp(0.0, 1.0);
+            Some(LiveEval::Vec4(Vec4::from_hsla(hsl)))
+        }
+        (live_id!(alpha), [c, a]) => {
+            let mut c = c.as_vec4()?;
+            c.w = a.as_f64()? as f32;
+            Some(LiveEval::Vec4(c))
+        }
+        _ => None
+    }
+}
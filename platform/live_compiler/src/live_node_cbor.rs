@@ -2,6 +2,7 @@ use {
     std::convert::TryInto,
     crate::{
         makepad_live_tokenizer::LiveId,
+        makepad_math::{Vec2, Vec3, Vec4},
         live_node::*,
     }
 };
@@ -86,6 +87,7 @@ key:{ref:{}} // template
 key:{in:[v1,v2]} // vec
 key:{as:u32} // color
 key {enum:"String"} // enum
+key:{use:"String"} // id
 */
 
 impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
@@ -295,14 +297,14 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                     encode_str("in", &mut out);
                     out.push(2 + CBOR_ARRAY_START);
                     encode_f32(v.x, &mut out);
-                    encode_f32(v.x, &mut out);
+                    encode_f32(v.y, &mut out);
                 },
                 LiveValue::Vec3(v) => {
                     out.push(1 + CBOR_MAP_START);
                     encode_str("in", &mut out);
                     out.push(3 + CBOR_ARRAY_START);
                     encode_f32(v.x, &mut out);
-                    encode_f32(v.x, &mut out);
+                    encode_f32(v.y, &mut out);
                     encode_f32(v.z, &mut out);
                 },
                 LiveValue::Vec4(v) => {
@@ -310,7 +312,7 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                     encode_str("in", &mut out);
                     out.push(4 + CBOR_ARRAY_START);
                     encode_f32(v.x, &mut out);
-                    encode_f32(v.x, &mut out);
+                    encode_f32(v.y, &mut out);
                     encode_f32(v.z, &mut out);
                     encode_f32(v.w, &mut out);
                 },
@@ -345,8 +347,10 @@ impl<T> LiveNodeSliceToCbor for T where T: AsRef<[LiveNode]> {
                 }, // subnodes including this one
                 LiveValue::Close => {},
                 // TODO ITEMS
-                LiveValue::Id(_) => {
-                    return Err("Cannot serialise LiveValue::Id".into())
+                LiveValue::Id(id) => {
+                    out.push(1 + CBOR_MAP_START);
+                    encode_str("use", &mut out);
+                    encode_id(*id, &mut out);
                 },
                 LiveValue::Clone(_) => {
                     return Err("Cannot serialise LiveValue::Clone".into())
@@ -424,7 +428,7 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
         // alright lets decode msgpack livenodes
         
         fn assert_len(o: usize, len: usize, data: &[u8]) -> Result<(), LiveNodeFromCborError> {
-            if o + len > data.len() {panic!()} //return Err(LiveNodeFromBinaryError::OutOfBounds);}
+            if o + len > data.len() {return Err(LiveNodeFromCborError::OutOfBounds);}
             Ok(())
         }
         
@@ -680,6 +684,25 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
             Ok(None)
         }
         
+        fn decode_f32(data: &[u8], o: &mut usize) -> Result<f32, LiveNodeFromCborError> {
+            // whole floats are written as integers
+            if let Some(v) = decode_i64(data, o) ? {
+                return Ok(v as f32)
+            }
+            assert_len(*o, 1, data) ?;
+            match data[*o] {
+                CBOR_FLOAT32 => {
+                    *o += 1;
+                    read_f32(data, o)
+                }
+                CBOR_FLOAT64 => {
+                    *o += 1;
+                    Ok(read_f64(data, o) ? as f32)
+                }
+                _ => Err(LiveNodeFromCborError::UnexpectedValue)
+            }
+        }
+        
         struct StackItem {len: usize, count: usize, has_keys: bool}
         
         let mut stack = vec![StackItem {count: 0, len: 1, has_keys: false}];
@@ -687,10 +710,13 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
         let mut o = 0;
         while o < data.len() {
             
-            if stack.last().unwrap().count == stack.last().unwrap().len {
+            while stack.len() > 1 && stack.last().unwrap().count == stack.last().unwrap().len {
                 self.push(LiveNode {id: LiveId(0), origin, value: LiveValue::Close});
                 stack.pop();
             }
+            if stack.last().unwrap().count == stack.last().unwrap().len {
+                return Err(LiveNodeFromCborError::UnexpectedValue)
+            }
             
             // ok lets read
             let stack_item = stack.last_mut().unwrap();
@@ -730,10 +756,35 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
                     if let Some(s) = decode_str(data, &mut o1) ? {
                         match s {
                             "in" => { // its a vec
-                                return Err(LiveNodeFromCborError::NotImpl)
+                                let value = match decode_array_len(data, &mut o1) ? {
+                                    Some(2) => LiveValue::Vec2(Vec2 {
+                                        x: decode_f32(data, &mut o1) ?,
+                                        y: decode_f32(data, &mut o1) ?
+                                    }),
+                                    Some(3) => LiveValue::Vec3(Vec3 {
+                                        x: decode_f32(data, &mut o1) ?,
+                                        y: decode_f32(data, &mut o1) ?,
+                                        z: decode_f32(data, &mut o1) ?
+                                    }),
+                                    Some(4) => LiveValue::Vec4(Vec4 {
+                                        x: decode_f32(data, &mut o1) ?,
+                                        y: decode_f32(data, &mut o1) ?,
+                                        z: decode_f32(data, &mut o1) ?,
+                                        w: decode_f32(data, &mut o1) ?
+                                    }),
+                                    _ => return Err(LiveNodeFromCborError::UnexpectedValue)
+                                };
+                                self.push(LiveNode {id, origin, value});
+                                o = o1;
+                                continue;
                             }
                             "as" => { // its a color
-                                return Err(LiveNodeFromCborError::NotImpl)
+                                if let Some(v) = decode_u64(data, &mut o1) ? {
+                                    self.push(LiveNode {id, origin, value: LiveValue::Color(v as u32)});
+                                    o = o1;
+                                    continue;
+                                }
+                                return Err(LiveNodeFromCborError::UnexpectedValue)
                             }
                             "if" => { // bare enum
                                 if let Some(variant) = decode_id(data, &mut o1) ? {
@@ -749,8 +800,37 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
                                     return Err(LiveNodeFromCborError::ExpectedBareEnumString)
                                 }
                             }
-                            "enum" => { // other enum
-                                return Err(LiveNodeFromCborError::NotImpl)
+                            "use" => { // id
+                                if let Some(v) = decode_id(data, &mut o1) ? {
+                                    self.push(LiveNode {id, origin, value: LiveValue::Id(v)});
+                                    o = o1;
+                                    continue;
+                                }
+                                return Err(LiveNodeFromCborError::ExpectedId)
+                            }
+                            "enum" => { // tuple or named enum
+                                if decode_array_len(data, &mut o1) ? != Some(2) {
+                                    return Err(LiveNodeFromCborError::UnexpectedVariant)
+                                }
+                                let variant = if let Some(variant) = decode_id(data, &mut o1) ? {
+                                    variant
+                                }
+                                else {
+                                    return Err(LiveNodeFromCborError::UnexpectedVariant)
+                                };
+                                if let Some(len) = decode_array_len(data, &mut o1) ? {
+                                    stack.push(StackItem {count: 0, len, has_keys: false});
+                                    self.push(LiveNode {id, origin, value: LiveValue::TupleEnum(variant)});
+                                }
+                                else if let Some(len) = decode_map_len(data, &mut o1) ? {
+                                    stack.push(StackItem {count: 0, len, has_keys: true});
+                                    self.push(LiveNode {id, origin, value: LiveValue::NamedEnum(variant)});
+                                }
+                                else {
+                                    return Err(LiveNodeFromCborError::UnexpectedVariant)
+                                }
+                                o = o1;
+                                continue;
                             }
                             _ => ()
                         }
@@ -794,8 +874,10 @@ impl LiveNodeVecFromCbor for Vec<LiveNode> {
             if item.count != item.len {
                 return Err(LiveNodeFromCborError::StackNotClosed)
             }
+            if stack.len() > 0 {
+                self.push(LiveNode {id: LiveId(0), origin, value: LiveValue::Close});
+            }
         }
-        self.push(LiveNode {id: LiveId(0), origin, value: LiveValue::Close});
         Ok(())
     }
 }
@@ -0,0 +1,498 @@
+use {
+    std::fmt::Write,
+    crate::{
+        makepad_live_tokenizer::{LiveId, Delim},
+        live_registry::LiveRegistry,
+        live_document::LiveOriginal,
+        live_token::{LiveToken, TokenWithSpan},
+        live_node::{LivePropType, LiveNode, LiveValue, LiveBinOp, LiveUnOp},
+    }
+};
+
+// Turns a LiveNode tree back into live_design! source. The output parses with LiveParser
+// into the same tree (minus .{..} edit info), so tools can write their edits back into Rust files.
+// Document strings, DSL blocks, node prefixes and class names are looked up via the registry.
+
+pub trait LiveNodeSliceToLive {
+    // prints the children of parent_index as the body of a live_design! block or object
+    fn to_live_source(&self, parent_index: usize, registry: &LiveRegistry) -> Result<String, String>;
+}
+
+impl<T> LiveNodeSliceToLive for T where T: AsRef<[LiveNode]> {
+    fn to_live_source(&self, parent_index: usize, registry: &LiveRegistry) -> Result<String, String> {
+        let nodes = self.as_ref();
+        if parent_index >= nodes.len() || !nodes[parent_index].value.is_open() {
+            return Err("to_live_source needs an open node as parent".into())
+        }
+        let mut printer = LivePrinter {
+            registry,
+            nodes,
+            out: String::new()
+        };
+        printer.body(parent_index + 1, 0) ?;
+        Ok(printer.out)
+    }
+}
+
+struct LivePrinter<'a> {
+    registry: &'a LiveRegistry,
+    nodes: &'a [LiveNode],
+    out: String,
+}
+
+const INDENT: &'static str = "    ";
+
+impl<'a> LivePrinter<'a> {
+
+    fn indent(&mut self, depth: usize) {
+        for _ in 0..depth {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn node(&self, index: usize) -> Result<&'a LiveNode, String> {
+        self.nodes.get(index).ok_or_else( || "Uneven stack, not enough closes".to_string())
+    }
+
+    // prints properties up to and including the close node, returns the index after it
+    fn body(&mut self, mut index: usize, depth: usize) -> Result<usize, String> {
+        loop {
+            let node = self.node(index) ?;
+            if node.value.is_close() {
+                return Ok(index + 1)
+            }
+            self.indent(depth);
+            index = self.prop(index, depth) ?;
+            self.out.push('\n');
+        }
+    }
+
+    fn prop(&mut self, index: usize, depth: usize) -> Result<usize, String> {
+        let node = self.node(index) ?;
+        match &node.value {
+            LiveValue::DSL {..} => return self.dsl(index, depth),
+            LiveValue::Import(module_id) => {
+                if node.id.is_empty() {
+                    write!(self.out, "import {}::*", module_id).unwrap();
+                }
+                else {
+                    write!(self.out, "import {}::{}", module_id, node.id).unwrap();
+                }
+                return Ok(index + 1)
+            }
+            LiveValue::Registry(crate_id) => {
+                if node.id.is_empty() {
+                    write!(self.out, "registry {}::*", crate_id).unwrap();
+                }
+                else {
+                    write!(self.out, "registry {}::{}", crate_id, node.id).unwrap();
+                }
+                return Ok(index + 1)
+            }
+            LiveValue::Clone(_) if node.id.is_unique() => {
+                // anonymous instances in a class body
+                return self.value(index, depth)
+            }
+            _ => ()
+        }
+        if let Some(prefix) = self.node_prefix(node) {
+            write!(self.out, "{} ", prefix).unwrap();
+        }
+        let pt = match node.origin.prop_type() {
+            LivePropType::Instance => " =",
+            LivePropType::Template => " =?",
+            LivePropType::Field | LivePropType::Nameless => ":",
+        };
+        write!(self.out, "{}{} ", node.id, pt).unwrap();
+        self.value(index, depth)
+    }
+
+    fn node_prefix(&self, node: &LiveNode) -> Option<LiveId> {
+        if !node.origin.node_has_prefix() {
+            return None
+        }
+        let file_id = node.origin.first_def() ?.file_id() ?;
+        if file_id.to_index() >= self.registry.live_files.len() {
+            return None
+        }
+        self.registry.get_node_prefix(node.origin)
+    }
+
+    // prints a single value (including its subtree), returns the index after it
+    fn value(&mut self, index: usize, depth: usize) -> Result<usize, String> {
+        let node = self.node(index) ?;
+        match &node.value {
+            LiveValue::None => return Err("Cannot print LiveValue::None".into()),
+            LiveValue::Str(s) => write_string(&mut self.out, s),
+            LiveValue::InlineString(s) => write_string(&mut self.out, s.as_str()),
+            LiveValue::FittedString(s) => write_string(&mut self.out, s.as_str()),
+            LiveValue::DocumentString {..} => {
+                let s = self.document_string(node) ?;
+                write_string(&mut self.out, &s);
+            }
+            LiveValue::Dependency {..} => {
+                let s = self.document_string(node) ?;
+                self.out.push('d');
+                write_string(&mut self.out, &s);
+            }
+            LiveValue::Bool(v) => write!(self.out, "{}", v).unwrap(),
+            LiveValue::Int64(v) => write!(self.out, "{}", v).unwrap(),
+            LiveValue::Float32(v) => write_float(&mut self.out, *v as f64) ?,
+            LiveValue::Float64(v) => write_float(&mut self.out, *v) ?,
+            LiveValue::Color(v) => write_color(&mut self.out, *v),
+            LiveValue::Vec2(v) => {
+                self.out.push_str("vec2(");
+                write_float(&mut self.out, v.x as f64) ?;
+                self.out.push_str(", ");
+                write_float(&mut self.out, v.y as f64) ?;
+                self.out.push(')');
+            }
+            LiveValue::Vec3(v) => {
+                self.out.push_str("vec3(");
+                write_float(&mut self.out, v.x as f64) ?;
+                self.out.push_str(", ");
+                write_float(&mut self.out, v.y as f64) ?;
+                self.out.push_str(", ");
+                write_float(&mut self.out, v.z as f64) ?;
+                self.out.push(')');
+            }
+            LiveValue::Vec4(v) => {
+                self.out.push_str("vec4(");
+                write_float(&mut self.out, v.x as f64) ?;
+                self.out.push_str(", ");
+                write_float(&mut self.out, v.y as f64) ?;
+                self.out.push_str(", ");
+                write_float(&mut self.out, v.z as f64) ?;
+                self.out.push_str(", ");
+                write_float(&mut self.out, v.w as f64) ?;
+                self.out.push(')');
+            }
+            LiveValue::Id(id) => write!(self.out, "{}", id).unwrap(),
+            LiveValue::BareEnum(variant) => write!(self.out, "{}", variant).unwrap(),
+            LiveValue::Expr {..} => {
+                self.out.push('(');
+                let index = self.expr(index + 1, 0, false) ?;
+                self.out.push(')');
+                if !self.node(index) ?.value.is_close() {
+                    return Err("Expression has more than one root".into())
+                }
+                return Ok(index + 1)
+            }
+            LiveValue::ExprBinOp(_) |
+            LiveValue::ExprUnOp(_) |
+            LiveValue::ExprMember(_) |
+            LiveValue::ExprCall {..} => return Err("Expression node outside of LiveValue::Expr".into()),
+            LiveValue::Array => {
+                return self.items(index + 1, depth, "[", "]")
+            }
+            LiveValue::TupleEnum(variant) => {
+                write!(self.out, "{}", variant).unwrap();
+                return self.items(index + 1, depth, "(", ")")
+            }
+            LiveValue::NamedEnum(variant) => {
+                write!(self.out, "{} ", variant).unwrap();
+                if self.children_are_values(index + 1) {
+                    self.out.push('{');
+                    let mut index = index + 1;
+                    let mut first = true;
+                    while !self.node(index) ?.value.is_close() {
+                        if !first {
+                            self.out.push_str(", ");
+                        }
+                        first = false;
+                        write!(self.out, "{}: ", self.nodes[index].id).unwrap();
+                        index = self.value(index, depth) ?;
+                    }
+                    self.out.push('}');
+                    return Ok(index + 1)
+                }
+                return self.object(index + 1, depth)
+            }
+            LiveValue::Object => {
+                return self.object(index + 1, depth)
+            }
+            LiveValue::Clone(clone) => {
+                write!(self.out, "<{}> ", clone).unwrap();
+                return self.object(index + 1, depth)
+            }
+            LiveValue::Class {live_type, ..} => {
+                let type_name = self.registry.live_type_infos.get(live_type)
+                    .ok_or_else( || "Cannot find type name of LiveValue::Class".to_string()) ?
+                    .type_name;
+                write!(self.out, "{{{{{}}}}} ", type_name).unwrap();
+                return self.object(index + 1, depth)
+            }
+            LiveValue::Close => return Err("Unmatched close".into()),
+            LiveValue::DSL {..} |
+            LiveValue::Import(_) |
+            LiveValue::Registry(_) => return Err("Cannot print a DSL, import or registry node as a value".into()),
+        }
+        Ok(index + 1)
+    }
+
+    fn document_string(&self, node: &LiveNode) -> Result<String, String> {
+        if let Some(token_id) = node.origin.token_id() {
+            if token_id.file_id().unwrap().to_index() < self.registry.live_files.len() {
+                return Ok(self.registry.live_node_as_string(node).unwrap())
+            }
+        }
+        Err("Cannot find document of LiveValue::DocumentString".into())
+    }
+
+    fn children_are_values(&self, mut index: usize) -> bool {
+        while let Some(node) = self.nodes.get(index) {
+            if node.value.is_close() {
+                return true
+            }
+            if node.value.is_open() && !node.value.is_expr() {
+                return false
+            }
+            index = self.skip(index);
+        }
+        false
+    }
+
+    fn skip(&self, index: usize) -> usize {
+        let mut depth = 0;
+        let mut index = index;
+        while let Some(node) = self.nodes.get(index) {
+            if node.value.is_open() {
+                depth += 1;
+            }
+            else if node.value.is_close() {
+                depth -= 1;
+            }
+            index += 1;
+            if depth == 0 {
+                break;
+            }
+        }
+        index
+    }
+
+    fn object(&mut self, index: usize, depth: usize) -> Result<usize, String> {
+        if self.node(index) ?.value.is_close() {
+            self.out.push_str("{}");
+            return Ok(index + 1)
+        }
+        self.out.push_str("{\n");
+        let index = self.body(index, depth + 1) ?;
+        self.indent(depth);
+        self.out.push('}');
+        Ok(index)
+    }
+
+    // nameless items of arrays and tuple enums
+    fn items(&mut self, mut index: usize, depth: usize, open: &str, close: &str) -> Result<usize, String> {
+        self.out.push_str(open);
+        if self.children_are_values(index) {
+            let mut first = true;
+            while !self.node(index) ?.value.is_close() {
+                if !first {
+                    self.out.push_str(", ");
+                }
+                first = false;
+                index = self.value(index, depth) ?;
+            }
+        }
+        else {
+            self.out.push('\n');
+            while !self.node(index) ?.value.is_close() {
+                self.indent(depth + 1);
+                index = self.value(index, depth + 1) ?;
+                self.out.push_str(",\n");
+            }
+            self.indent(depth);
+        }
+        self.out.push_str(close);
+        Ok(index + 1)
+    }
+
+    fn expr(&mut self, index: usize, parent_prec: usize, is_right: bool) -> Result<usize, String> {
+        let node = self.node(index) ?;
+        match &node.value {
+            LiveValue::ExprBinOp(op) => {
+                let prec = bin_op_prec(*op);
+                let parens = prec < parent_prec || (is_right && prec == parent_prec);
+                if parens {
+                    self.out.push('(');
+                }
+                let index = self.expr(index + 1, prec, false) ?;
+                write!(self.out, " {} ", bin_op_str(*op)).unwrap();
+                let index = self.expr(index, prec, true) ?;
+                if parens {
+                    self.out.push(')');
+                }
+                Ok(index)
+            }
+            LiveValue::ExprUnOp(op) => {
+                self.out.push_str(match op {
+                    LiveUnOp::Not => "!",
+                    LiveUnOp::Neg => "-"
+                });
+                self.expr(index + 1, UN_OP_PREC, false)
+            }
+            LiveValue::ExprCall {ident, args} => {
+                write!(self.out, "{}(", ident).unwrap();
+                let mut index = index + 1;
+                for i in 0..*args {
+                    if i != 0 {
+                        self.out.push_str(", ");
+                    }
+                    index = self.expr(index, 0, false) ?;
+                }
+                self.out.push(')');
+                Ok(index)
+            }
+            LiveValue::ExprMember(ident) => {
+                let index = self.expr(index + 1, UN_OP_PREC + 1, false) ?;
+                write!(self.out, ".{}", ident).unwrap();
+                Ok(index)
+            }
+            LiveValue::Float64(v) if *v < 0.0 && parent_prec > 0 => {
+                self.out.push('(');
+                write_float(&mut self.out, *v) ?;
+                self.out.push(')');
+                Ok(index + 1)
+            }
+            LiveValue::Int64(v) if *v < 0 && parent_prec > 0 => {
+                write!(self.out, "({})", v).unwrap();
+                Ok(index + 1)
+            }
+            LiveValue::Id(_) |
+            LiveValue::Bool(_) |
+            LiveValue::Int64(_) |
+            LiveValue::Float32(_) |
+            LiveValue::Float64(_) |
+            LiveValue::Color(_) => self.value(index, 0),
+            _ => Err(format!("Unexpected value in expression {:?}", node.value))
+        }
+    }
+
+    fn dsl(&mut self, index: usize, depth: usize) -> Result<usize, String> {
+        let node = self.node(index) ?;
+        if let LiveValue::DSL {token_start, token_count, ..} = &node.value {
+            let token_id = node.origin.token_id()
+                .ok_or_else( || "Cannot find tokens of LiveValue::DSL".to_string()) ?;
+            if token_id.file_id().unwrap().to_index() >= self.registry.live_files.len() {
+                return Err("Cannot find tokens of LiveValue::DSL".into())
+            }
+            let doc = self.registry.token_id_to_origin_doc(token_id);
+            let tokens = doc.get_tokens(*token_start as usize, *token_count as usize);
+            self.dsl_tokens(doc, tokens, depth) ?;
+        }
+        Ok(index + 1)
+    }
+
+    // reprints DSL tokens, keeping line breaks and indentation relative to the first token
+    fn dsl_tokens(&mut self, doc: &LiveOriginal, tokens: &[TokenWithSpan], depth: usize) -> Result<(), String> {
+        let base_column = if let Some(first) = tokens.first() {first.span.start.column} else {0};
+        let mut last: Option<&TokenWithSpan> = None;
+        for token in tokens {
+            if let Some(last) = last {
+                if token.span.start.line != last.span.start.line {
+                    self.out.push('\n');
+                    self.indent(depth);
+                    for _ in base_column..token.span.start.column {
+                        self.out.push(' ');
+                    }
+                }
+                else if token.span.start.column > last.span.end.column || needs_separator(&last.token, &token.token) {
+                    self.out.push(' ');
+                }
+            }
+            match token.token {
+                LiveToken::String {index, len} => {
+                    let mut s = String::new();
+                    doc.get_string(index as usize, len as usize, &mut s);
+                    write_string(&mut self.out, &s);
+                }
+                LiveToken::Dependency {index, len} => {
+                    let mut s = String::new();
+                    doc.get_string(index as usize, len as usize, &mut s);
+                    self.out.push('d');
+                    write_string(&mut self.out, &s);
+                }
+                LiveToken::Float(v) => write_float(&mut self.out, v) ?,
+                LiveToken::Color(v) => write_color(&mut self.out, v),
+                LiveToken::Open(Delim::Brace) => self.out.push('{'),
+                LiveToken::Close(Delim::Brace) => self.out.push('}'),
+                other => write!(self.out, "{}", other).unwrap(),
+            }
+            last = Some(token);
+        }
+        Ok(())
+    }
+}
+
+// tokens that would merge into one when printed without whitespace
+fn needs_separator(a: &LiveToken, b: &LiveToken) -> bool {
+    let is_word = | t: &LiveToken | match t {
+        LiveToken::Ident(_) | LiveToken::Bool(_) | LiveToken::Int(_) | LiveToken::Float(_) => true,
+        _ => false
+    };
+    is_word(a) && is_word(b) || matches!((a, b), (LiveToken::Punct(_), LiveToken::Punct(_)))
+}
+
+const UN_OP_PREC: usize = 7;
+
+fn bin_op_prec(op: LiveBinOp) -> usize {
+    match op {
+        LiveBinOp::Or => 1,
+        LiveBinOp::And => 2,
+        LiveBinOp::Eq | LiveBinOp::Ne => 3,
+        LiveBinOp::Lt | LiveBinOp::Le | LiveBinOp::Gt | LiveBinOp::Ge => 4,
+        LiveBinOp::Add | LiveBinOp::Sub => 5,
+        LiveBinOp::Mul | LiveBinOp::Div => 6,
+    }
+}
+
+fn bin_op_str(op: LiveBinOp) -> &'static str {
+    match op {
+        LiveBinOp::Or => "||",
+        LiveBinOp::And => "&&",
+        LiveBinOp::Eq => "==",
+        LiveBinOp::Ne => "!=",
+        LiveBinOp::Lt => "<",
+        LiveBinOp::Le => "<=",
+        LiveBinOp::Gt => ">",
+        LiveBinOp::Ge => ">=",
+        LiveBinOp::Add => "+",
+        LiveBinOp::Sub => "-",
+        LiveBinOp::Mul => "*",
+        LiveBinOp::Div => "/",
+    }
+}
+
+// always has a . or exponent so it tokenizes back into a float. live has no
+// literal for NaN or infinity, so those can't be printed
+fn write_float(out: &mut String, v: f64) -> Result<(), String> {
+    if !v.is_finite() {
+        return Err(format!("Cannot print non-finite float {}", v))
+    }
+    write!(out, "{:?}", v).unwrap();
+    Ok(())
+}
+
+// colours starting with a digit need the #x form to survive the rust tokenizer
+fn write_color(out: &mut String, v: u32) {
+    let hex = format!("{:08x}", v);
+    if hex.as_bytes()[0].is_ascii_digit() {
+        write!(out, "#x{}", hex).unwrap();
+    }
+    else {
+        write!(out, "#{}", hex).unwrap();
+    }
+}
+
+// live strings are stored with quotes and backslashes unescaped
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
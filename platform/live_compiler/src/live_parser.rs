@@ -125,6 +125,9 @@ impl<'a> LiveParser<'a> {
     }
     
     fn expect_float(&mut self) -> Result<f64, LiveError> {
+        if self.accept_token(LiveToken::Punct(live_id!(-))) {
+            return Ok(-self.expect_float() ?)
+        }
         match self.peek_token() {
             LiveToken::Float(v) => {
                 self.skip_token();
@@ -439,22 +439,22 @@ impl LiveRegistry {
                             })
                         },
                         FullToken::String => {
-                            let len = full_token.len - 2;
+                            let col = pos.column as usize + 1;
+                            let chars = unescape_string(&line_chars[col..col + full_token.len - 2]);
                             tokens.push(TokenWithSpan {span: span, token: LiveToken::String {
                                 index: strings.len() as u32,
-                                len: len as u32
+                                len: chars.len() as u32
                             }});
-                            let col = pos.column as usize + 1;
-                            strings.extend(&line_chars[col..col + len]);
+                            strings.extend(chars);
                         },
                         FullToken::Dependency => {
-                            let len = full_token.len - 3;
+                            let col = pos.column as usize + 2;
+                            let chars = unescape_string(&line_chars[col..col + full_token.len - 3]);
                             tokens.push(TokenWithSpan {span: span, token: LiveToken::Dependency {
                                 index: strings.len() as u32,
-                                len: len as u32
+                                len: chars.len() as u32
                             }});
-                            let col = pos.column as usize + 2;
-                            strings.extend(&line_chars[col..col + len]);
+                            strings.extend(chars);
                         },
                         _ => match LiveToken::from_full_token(full_token.token) {
                             Some(live_token) => {
@@ -524,9 +524,9 @@ impl LiveRegistry {
                             })
                         },
                         FullToken::String => {
-                            let new_len = full_token.len - 2;
                             let new_col = column as usize + 1;
-                            let new_chars = &line_chars[new_col..new_col + new_len];
+                            let new_chars = unescape_string(&line_chars[new_col..new_col + full_token.len - 2]);
+                            let new_len = new_chars.len();
                             let new_string = LiveToken::String {
                                 index: new_strings.len() as u32,
                                 len: new_len as u32
@@ -967,3 +967,20 @@ impl FileDepIter {
         }
     }
 }
+
+// strings are stored without the escapes for quotes and backslashes, other escapes are kept as written
+fn unescape_string(chars: &[char]) -> Vec<char> {
+    let mut out = Vec::with_capacity(chars.len());
+    let mut iter = chars.iter().cloned().peekable();
+    while let Some(c) = iter.next() {
+        if c == '\\' {
+            if let Some(next @ ('"' | '\\')) = iter.peek().cloned() {
+                iter.next();
+                out.push(next);
+                continue
+            }
+        }
+        out.push(c);
+    }
+    out
+}
@@ -1,4 +1,290 @@
+use makepad_live_compiler::{
+    *,
+    makepad_live_id::live_id,
+    makepad_math::*,
+    live_parser::LiveParser,
+    live_eval::LiveEval,
+};
+
 #[test]
 fn main() {
     // todo :)
-}
\ No newline at end of file
+}
+
+fn eval_prop(source: &str, prop: LiveId) -> Result<LiveEval, LiveError> {
+    let file_id = LiveFileId::new(0);
+    let (tokens, _) = LiveRegistry::tokenize_from_str(source, TextPos::default(), file_id).unwrap();
+    let mut parser = LiveParser::new(&tokens, &[], file_id);
+    let original = parser.parse_live_document().unwrap();
+    let nodes = &original.nodes;
+    let index = nodes.child_by_name(0, prop.as_field()).unwrap();
+    live_eval(&LiveRegistry::default(), index, &mut (index + 1), nodes)
+}
+
+fn eval_f64(source: &str, prop: LiveId) -> f64 {
+    match eval_prop(source, prop).unwrap() {
+        LiveEval::Float64(v) => v,
+        LiveEval::Int64(v) => v as f64,
+        v => panic!("expected number, got {:?}", v)
+    }
+}
+
+fn eval_vec4(source: &str, prop: LiveId) -> Vec4 {
+    match eval_prop(source, prop).unwrap() {
+        LiveEval::Vec4(v) => v,
+        v => panic!("expected vec4, got {:?}", v)
+    }
+}
+
+#[test]
+fn eval_numeric_functions() {
+    let source = "
+        base: 4.0
+        a: (mix(base, 8.0, 0.25))
+        b: (clamp(base * 10.0, 0.0, 12.5))
+        c: (min(3, base) + max(-1, abs(-2)))
+        d: (floor(2.7) + round(2.5))
+        e: (if(base > 2.0, 10, 20))
+        f: (base >= 5.0 ? 1.0 : 2.0)
+    ";
+    assert_eq!(eval_f64(source, live_id!(a)), 5.0);
+    assert_eq!(eval_f64(source, live_id!(b)), 12.5);
+    assert_eq!(eval_f64(source, live_id!(c)), 5.0);
+    assert_eq!(eval_f64(source, live_id!(d)), 5.0);
+    assert_eq!(eval_f64(source, live_id!(e)), 10.0);
+    assert_eq!(eval_f64(source, live_id!(f)), 2.0);
+}
+
+#[test]
+fn eval_color_functions() {
+    let source = "
+        accent: #ff0000
+        a: (hsl(0.0, 1.0, 0.5))
+        b: (rgb(0.0, 0.5, 1.0))
+        c: (alpha(accent, 0.5))
+        d: (darken(accent, 0.25))
+        e: (lighten(hsla(0.5, 1.0, 0.25, 1.0), 0.25))
+        f: (mix(#000000ff, #ffffffff, 0.5))
+    ";
+    assert!(eval_vec4(source, live_id!(a)).is_equal_enough(&vec4(1.0, 0.0, 0.0, 1.0), 0.001));
+    assert!(eval_vec4(source, live_id!(b)).is_equal_enough(&vec4(0.0, 0.5, 1.0, 1.0), 0.001));
+    assert!(eval_vec4(source, live_id!(c)).is_equal_enough(&vec4(1.0, 0.0, 0.0, 0.5), 0.001));
+    assert!(eval_vec4(source, live_id!(d)).is_equal_enough(&vec4(0.5, 0.0, 0.0, 1.0), 0.001));
+    assert!(eval_vec4(source, live_id!(e)).is_equal_enough(&vec4(0.0, 1.0, 1.0, 1.0), 0.001));
+    assert!(eval_vec4(source, live_id!(f)).is_equal_enough(&vec4(0.5, 0.5, 0.5, 1.0), 0.001));
+}
+
+#[test]
+fn eval_unknown_function_is_an_error() {
+    assert!(eval_prop("a: (frobnicate(1.0))", live_id!(a)).is_err());
+}
+
+fn register_source(registry: &mut LiveRegistry, file_name: &str, source: &str) -> LiveFileId {
+    let live_type_infos = vec![LiveTypeInfo {
+        live_type: std::any::TypeId::of::<u32>(),
+        type_name: LiveId::from_str("Widget").unwrap(),
+        module_id: LiveModuleId::from_str("test::module").unwrap(),
+        live_ignore: false,
+        fields: Vec::new(),
+    }];
+    let module_id = LiveModuleId::from_str(&format!("test::{}", file_name)).unwrap();
+    match registry.register_live_file(file_name, "", module_id, source.to_string(), live_type_infos, TextPos::default()) {
+        Ok(file_id) => file_id,
+        Err(err) => panic!("{}\n{}", err, source)
+    }
+}
+
+fn assert_same_tree(a: &[LiveNode], b: &[LiveNode]) {
+    assert_eq!(a.len(), b.len());
+    for (na, nb) in a.iter().zip(b.iter()) {
+        if !na.id.is_unique() || !nb.id.is_unique() {
+            assert_eq!(na.id, nb.id);
+        }
+        assert_eq!(na.origin.prop_type(), nb.origin.prop_type());
+        match (&na.value, &nb.value) {
+            (LiveValue::DocumentString {..}, LiveValue::DocumentString {..}) |
+            (LiveValue::DSL {..}, LiveValue::DSL {..}) => (),
+            (va, vb) => assert_eq!(va, vb)
+        }
+    }
+}
+
+#[test]
+fn print_round_trip() {
+    let source = r#"
+        import makepad_draw_2d::shader::std::*;
+        Button = {{Widget}} {
+            label: "hello \"world\""
+            walk: {width: Fit, height: Fixed(40.0), margin: {left: -1.5, top: 2}}
+            color: #f00
+            dark: #x0a0a0aff
+            pos: vec2(-1.0, 2.5)
+            size: (base * 2.0 + -offset.x)
+            cond: ((a + b) * c == 3 && !flag || if(x > 1, 2, 3) != 0)
+            items: [1, 2.0, Left, Pad(1, 2.5)]
+            kids: [<Child> {a: 1}, <Child> {}]
+            draw_bg: {
+                fn pixel(self) -> vec4 {
+                    let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+                    if self.hover > 0.5 {
+                        return #x00000077;
+                    }
+                    return mix(#fff, #000f, -self.pressed);
+                }
+            }
+            <Child> {
+                instance hover: 0.0
+                state = ? {
+                    default: off
+                }
+            }
+            named = <Child> {}
+        }
+    "#;
+    let mut registry = LiveRegistry::default();
+    let file_a = register_source(&mut registry, "a", source);
+    let printed_a = registry.live_files[file_a.to_index()].original.nodes.to_live_source(0, &registry).unwrap();
+    let file_b = register_source(&mut registry, "b", &printed_a);
+    let printed_b = registry.live_files[file_b.to_index()].original.nodes.to_live_source(0, &registry).unwrap();
+    assert_eq!(printed_a, printed_b);
+    assert_same_tree(
+        &registry.live_files[file_a.to_index()].original.nodes,
+        &registry.live_files[file_b.to_index()].original.nodes
+    );
+}
+
+#[test]
+fn print_constructed_nodes() {
+    // live_id! does not intern the string, the printer needs it to write the name
+    fn id(s: &str) -> LiveId {LiveId::from_str(s).unwrap()}
+    let mut nodes: Vec<LiveNode> = Vec::new();
+    nodes.open();
+    nodes.push_float64(id("width"), 100.0);
+    nodes.push_string(id("text"), "hi");
+    nodes.push_color(id("color"), 0x336699ff);
+    nodes.open_named_enum(id("walk"), id("Fixed"));
+    nodes.push_int64(id("a"), 3);
+    nodes.close();
+    nodes.open_array(id("list"));
+    nodes.push_bool(LiveId::empty(), true);
+    nodes.push_bool(LiveId::empty(), false);
+    nodes.close();
+    nodes.close();
+    let printed = nodes.to_live_source(0, &LiveRegistry::default()).unwrap();
+    assert_eq!(printed, "width: 100.0\ntext: \"hi\"\ncolor: #x336699ff\nwalk: Fixed {a: 3}\nlist: [true, false]\n");
+}
+
+fn string_values(registry: &LiveRegistry, file_id: LiveFileId) -> Vec<String> {
+    registry.live_files[file_id.to_index()].original.nodes.iter()
+        .filter( | node | matches!(node.value, LiveValue::DocumentString {..} | LiveValue::Dependency {..}))
+        .map( | node | registry.live_node_as_string(node).unwrap())
+        .collect()
+}
+
+#[test]
+fn print_escaped_strings() {
+    let source = r#"
+        Button = {{Widget}} {
+            label: "say \"hi\" to C:\\temp\\"
+            icon: d"crate://self/a\\b.svg"
+            newline: "a\nb"
+            draw_bg: {
+                fn pixel(self) -> vec4 {
+                    let s = "q\"\\";
+                    return #fff;
+                }
+            }
+        }
+    "#;
+    let mut registry = LiveRegistry::default();
+    let file_a = register_source(&mut registry, "a", source);
+    assert_eq!(string_values(&registry, file_a), ["say \"hi\" to C:\\temp\\", "crate://self/a\\b.svg", "a\\nb"]);
+    let printed_a = registry.live_files[file_a.to_index()].original.nodes.to_live_source(0, &registry).unwrap();
+    assert!(printed_a.contains(r#"let s = "q\"\\";"#), "{}", printed_a);
+    let file_b = register_source(&mut registry, "b", &printed_a);
+    assert_eq!(string_values(&registry, file_a), string_values(&registry, file_b));
+    let printed_b = registry.live_files[file_b.to_index()].original.nodes.to_live_source(0, &registry).unwrap();
+    assert_eq!(printed_a, printed_b);
+    
+    let mut nodes: Vec<LiveNode> = Vec::new();
+    nodes.open();
+    nodes.push_string(LiveId::from_str("text").unwrap(), "a\"b\\");
+    nodes.close();
+    let printed = nodes.to_live_source(0, &LiveRegistry::default()).unwrap();
+    assert_eq!(printed, "text: \"a\\\"b\\\\\"\n");
+    let file_c = register_source(&mut registry, "c", &printed);
+    assert_eq!(string_values(&registry, file_c), ["a\"b\\"]);
+}
+
+#[test]
+fn print_non_finite_floats() {
+    let print = | value: LiveValue | {
+        let nodes = vec![
+            LiveNode::from_value(LiveValue::Object),
+            LiveNode::from_id_value(LiveId::from_str("x").unwrap(), value),
+            LiveNode::from_value(LiveValue::Close),
+        ];
+        nodes.to_live_source(0, &LiveRegistry::default())
+    };
+    assert!(print(LiveValue::Float64(f64::NAN)).is_err());
+    assert!(print(LiveValue::Float32(f32::INFINITY)).is_err());
+    assert!(print(LiveValue::Vec2(vec2(1.0, f32::NEG_INFINITY))).is_err());
+    assert_eq!(print(LiveValue::Float64(1e300)).unwrap(), "x: 1e300\n");
+}
+
+#[test]
+fn cbor_round_trip() {
+    let mut nodes: Vec<LiveNode> = Vec::new();
+    nodes.open();
+    nodes.push_float64(live_id!(width), 100.5);
+    nodes.push_int64(live_id!(count), -3);
+    nodes.push_string(live_id!(text), "hi");
+    nodes.push_color(live_id!(color), 0x336699ff);
+    nodes.push_vec2(live_id!(pos), vec2(1.5, -2.0));
+    nodes.push_vec4(live_id!(rect), vec4(1.0, 2.0, 3.5, 4.0));
+    nodes.push_bare_enum(live_id!(axis), live_id!(Horizontal));
+    nodes.open_tuple_enum(live_id!(align), live_id!(FromEnd));
+    nodes.push_float64(LiveId(0), 250.25);
+    nodes.close();
+    nodes.open_object(live_id!(panels));
+    nodes.open_named_enum(live_id!(split), live_id!(Split));
+    nodes.open_array(live_id!(children));
+    nodes.push_id(LiveId(0), live_id!(left));
+    nodes.close();
+    nodes.close();
+    nodes.close();
+    nodes.push_bool(live_id!(open), true);
+    nodes.close();
+    
+    let data = nodes.to_cbor(0).unwrap();
+    let mut decoded: Vec<LiveNode> = Vec::new();
+    decoded.from_cbor(&data).unwrap();
+    
+    let expect = [
+        (LiveId(0), LiveValue::Object),
+        (live_id!(width), LiveValue::Float64(100.5)),
+        (live_id!(count), LiveValue::Int64(-3)),
+        (live_id!(text), LiveValue::InlineString(InlineString::from_str("hi").unwrap())),
+        (live_id!(color), LiveValue::Color(0x336699ff)),
+        (live_id!(pos), LiveValue::Vec2(vec2(1.5, -2.0))),
+        (live_id!(rect), LiveValue::Vec4(vec4(1.0, 2.0, 3.5, 4.0))),
+        (live_id!(axis), LiveValue::BareEnum(live_id!(Horizontal))),
+        (live_id!(align), LiveValue::TupleEnum(live_id!(FromEnd))),
+        (LiveId(0), LiveValue::Float64(250.25)),
+        (LiveId(0), LiveValue::Close),
+        (live_id!(panels), LiveValue::Object),
+        (live_id!(split), LiveValue::NamedEnum(live_id!(Split))),
+        (live_id!(children), LiveValue::Array),
+        (LiveId(0), LiveValue::Id(live_id!(left))),
+        (LiveId(0), LiveValue::Close),
+        (LiveId(0), LiveValue::Close),
+        (LiveId(0), LiveValue::Close),
+        (live_id!(open), LiveValue::Bool(true)),
+        (LiveId(0), LiveValue::Close),
+    ];
+    assert_eq!(decoded.len(), expect.len());
+    for (node, (id, value)) in decoded.iter().zip(expect.iter()) {
+        assert_eq!(node.id, *id);
+        assert_eq!(node.value, *value);
+    }
+}
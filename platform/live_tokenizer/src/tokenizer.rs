@@ -522,7 +522,7 @@ impl DoubleQuotedStringTailState {
                         FullToken::String,
                     );
                 }
-                ('\\', '"') => cursor.skip(2),
+                ('\\', '"') | ('\\', '\\') => cursor.skip(2),
                 _ => cursor.skip(1),
             }
         }
@@ -548,7 +548,7 @@ impl DoubleQuotedDependencyTailState {
                         FullToken::Dependency,
                     );
                 }
-                ('\\', '"') => cursor.skip(2),
+                ('\\', '"') | ('\\', '\\') => cursor.skip(2),
                 _ => cursor.skip(1),
             }
         }
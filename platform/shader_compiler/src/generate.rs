@@ -71,6 +71,39 @@ pub trait BackendWriter {
     fn write_ty_lit(&self, string: &mut String, ty_lit: TyLit);
     fn write_builtin_call_ident(&self, string: &mut String, ident: Ident, arg_exprs: &[Expr]);
     
+    // the defaults below emit C-style syntax, backends with a different grammar override them
+    
+    fn needs_select_for_cond_expr(&self) -> bool {
+        false
+    }
+    
+    fn inout_is_pointer(&self) -> bool {
+        false
+    }
+    
+    fn assign_is_stmt(&self) -> bool {
+        false
+    }
+    
+    fn needs_fallback_return(&self) -> bool {
+        false
+    }
+    
+    fn write_fallback_return(&self, _string: &mut String, _return_ty: &Ty) {
+    }
+    
+    fn write_fn_def_begin(&self, string: &mut String, ident: &dyn fmt::Display, return_ty: &Ty) {
+        self.write_var_decl(string, "", false, false, ident, return_ty);
+        write!(string, "(").unwrap();
+    }
+    
+    fn write_fn_def_end(&self, string: &mut String, _return_ty: &Ty) {
+        write!(string, ") ").unwrap();
+    }
+    
+    fn write_local_var_decl(&self, string: &mut String, ident: &dyn fmt::Display, ty: &Ty) {
+        self.write_var_decl(string, "", false, false, ident, ty);
+    }
 }
 
 pub struct BlockGenerator<'a> {
@@ -95,8 +128,7 @@ pub fn generate_cons_fn(backend_writer:&dyn BackendWriter, string: &mut String,
         return
     }
     
-    backend_writer.write_ty_lit(string, ty_lit);
-    write!(string, " {}(", cons_name).unwrap();
+    backend_writer.write_fn_def_begin(string, &cons_name, &ty_lit.to_ty());
     let mut sep = "";
     if param_tys.len() == 1 {
         backend_writer.write_var_decl(string, sep, false, false, &Ident(live_id!(x)), &param_tys[0]);
@@ -107,7 +139,8 @@ pub fn generate_cons_fn(backend_writer:&dyn BackendWriter, string: &mut String,
             sep = ", ";
         }
     }
-    writeln!(string, ") {{").unwrap();
+    backend_writer.write_fn_def_end(string, &ty_lit.to_ty());
+    writeln!(string, "{{").unwrap();
     write!(string, "    return ").unwrap();
     backend_writer.write_ty_lit(string, ty_lit);
     write!(string, "(").unwrap();
@@ -190,6 +223,28 @@ impl<'a> BlockGenerator<'a> {
         write!(self.string, "}}").unwrap();
     }
     
+    pub fn generate_fn_block(&mut self, block: &Block, return_ty: &Ty) {
+        // a body ending in a match or if without a return is fine in glsl, but not in every backend
+        let needs_fallback_return = self.backend_writer.needs_fallback_return()
+            && *return_ty != Ty::Void
+            && !matches!(block.stmts.last(), Some(Stmt::Return {..}));
+        if !needs_fallback_return {
+            return self.generate_block(block)
+        }
+        write!(self.string, "{{\n").unwrap();
+        self.write_indent();
+        self.indent_level += 1;
+        for stmt in &block.stmts {
+            self.generate_stmt(stmt);
+            writeln!(self.string).unwrap();
+        }
+        self.write_indent();
+        self.backend_writer.write_fallback_return(self.string, return_ty);
+        self.indent_level -= 1;
+        self.write_indent();
+        write!(self.string, "}}").unwrap();
+    }
+    
     fn generate_stmt(&mut self, stmt: &Stmt) {
         self.write_indent();
         match *stmt {
@@ -278,9 +333,11 @@ impl<'a> BlockGenerator<'a> {
         } else {
             -1
         };
+        write!(self.string, "for (").unwrap();
+        self.backend_writer.write_local_var_decl(self.string, &ident, &Ty::Int);
         write!(
             self.string,
-            "for (int {0} = {1}; {0} {2} {3}; {0} {4} {5}) ",
+            " = {1}; {0} {2} {3}; {0} {4} {5}) ",
             ident,
             if from <= to {from} else {from - 1},
             if from <= to {"<"} else {">="},
@@ -347,11 +404,8 @@ impl<'a> BlockGenerator<'a> {
         expr: &Option<Expr>,
         shadow: &Cell<Option<ScopeSymShadow >>
     ) {
-        self.backend_writer.write_var_decl(
+        self.backend_writer.write_local_var_decl(
             &mut self.string,
-            "",
-            false, 
-            false,
             &DisplayVarName(ident, shadow.get().unwrap()),
             ty.borrow().as_ref().unwrap()
         );
@@ -377,6 +431,21 @@ impl<'a> BlockGenerator<'a> {
     }
     
     fn generate_expr_stmt(&mut self, _span: TokenSpan, expr: &Expr) {
+        // a = b = c is split up into b = c; a = b; for backends where assignment is not an expression
+        if self.backend_writer.assign_is_stmt() {
+            if let ExprKind::Bin {op: BinOp::Assign, left_expr, right_expr, ..} = &expr.kind {
+                if let ExprKind::Bin {op: BinOp::Assign, left_expr: inner_left_expr, ..} = &right_expr.kind {
+                    self.generate_expr_stmt(right_expr.span, right_expr);
+                    writeln!(self.string).unwrap();
+                    self.write_indent();
+                    self.generate_expr(left_expr);
+                    write!(self.string, " = ").unwrap();
+                    self.generate_expr(inner_left_expr);
+                    writeln!(self.string, ";").unwrap();
+                    return
+                }
+            }
+        }
         self.generate_expr(expr);
         writeln!(self.string, ";").unwrap();
     }
@@ -555,6 +624,16 @@ impl<'a> ExprGenerator<'a> {
         expr_if_true: &Expr,
         expr_if_false: &Expr,
     ) {
+        if self.backend_writer.needs_select_for_cond_expr() {
+            write!(self.string, "select(").unwrap();
+            self.generate_expr(expr_if_false);
+            write!(self.string, ", ").unwrap();
+            self.generate_expr(expr_if_true);
+            write!(self.string, ", ").unwrap();
+            self.generate_expr(expr);
+            write!(self.string, ")").unwrap();
+            return
+        }
         write!(self.string, "(").unwrap();
         self.generate_expr(expr);
         write!(self.string, " ? ").unwrap();
@@ -566,6 +645,17 @@ impl<'a> ExprGenerator<'a> {
     
     fn generate_bin_expr(&mut self, _span: TokenSpan, op: BinOp, left_expr: &Expr, right_expr: &Expr) {
         
+        // assignments are statements in some backends, so they can't be wrapped in parens there
+        match op {
+            BinOp::Assign | BinOp::AddAssign | BinOp::SubAssign | BinOp::MulAssign | BinOp::DivAssign if self.backend_writer.assign_is_stmt() => {
+                self.generate_expr(left_expr);
+                write!(self.string, " {} ", op).unwrap();
+                self.generate_expr(right_expr);
+                return
+            }
+            _ => ()
+        }
+        
         // if left_expr or right_expr is a matrix, HLSL needs to use mul()
         let left_is_mat = match left_expr.ty.borrow().as_ref().unwrap() {
             Ty::Mat2 | Ty::Mat3 | Ty::Mat4 => true,
@@ -700,7 +790,7 @@ impl<'a> ExprGenerator<'a> {
             )).unwrap();
             
             let mut sep = "";
-            for arg_expr in arg_exprs {
+            for (index, arg_expr) in arg_exprs.iter().enumerate() {
                 // check if the args is a closure, ifso skip it
                 match arg_expr.ty.borrow().as_ref().unwrap(){
                     Ty::ClosureDef(_)=>{
@@ -710,7 +800,7 @@ impl<'a> ExprGenerator<'a> {
                 }
                 
                 write!(self.string, "{}", sep).unwrap();
-                self.generate_expr(arg_expr);
+                self.generate_call_arg(fn_def, arg_exprs.len(), index, arg_expr);
                 sep = ", ";
             }
             // and now the closed over values
@@ -737,9 +827,9 @@ impl<'a> ExprGenerator<'a> {
         else {
             write!(self.string, "{}_{} (", fn_def.fn_ptr, fn_def.ident).unwrap();
             let mut sep = "";
-            for arg_expr in arg_exprs {
+            for (index, arg_expr) in arg_exprs.iter().enumerate() {
                 write!(self.string, "{}", sep).unwrap();
-                self.generate_expr(arg_expr);
+                self.generate_call_arg(fn_def, arg_exprs.len(), index, arg_expr);
                 sep = ", ";
             }
 
@@ -749,6 +839,26 @@ impl<'a> ExprGenerator<'a> {
         }
     }
     
+    fn generate_call_arg(&mut self, fn_def: &FnDef, arg_count: usize, index: usize, arg_expr: &Expr) {
+        // draw shader methods are called without their self arg, so align from the end
+        if self.backend_writer.inout_is_pointer() {
+            let offset = fn_def.params.len().saturating_sub(arg_count);
+            if let Some(param) = fn_def.params.get(index + offset) {
+                if param.is_inout {
+                    write!(self.string, "&").unwrap();
+                }
+            }
+        }
+        self.generate_expr(arg_expr);
+    }
+    
+    fn is_inout_param(&self, ident: Ident, shadow: ScopeSymShadow) -> bool {
+        if let Some(fn_def) = self.fn_def {
+            return fn_def.params.iter().any( | param | param.is_inout && param.ident == ident && param.shadow.get() == Some(shadow))
+        }
+        false
+    }
+    
     fn generate_field_expr(&mut self, _span: TokenSpan, expr: &Expr, field_ident: Ident, ty:&Ty) {
         match expr.ty.borrow().as_ref() {
             Some(Ty::DrawShader(_)) => {
@@ -902,7 +1012,12 @@ impl<'a> ExprGenerator<'a> {
                 write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
             }
             VarKind::MutLocal {ident, shadow} => {
-                write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
+                if self.backend_writer.inout_is_pointer() && self.is_inout_param(ident, shadow) {
+                    write!(self.string, "(*{})", DisplayVarName(ident, shadow)).unwrap();
+                }
+                else {
+                    write!(self.string, "{}", DisplayVarName(ident, shadow)).unwrap();
+                }
             }
             VarKind::LiveValue(value_node_ptr) => {
                 // this is a live value.. also prefix needed
@@ -936,15 +1051,11 @@ pub struct FnDefGenerator<'a> {
 impl<'a> FnDefGenerator<'a> {
     pub fn generate_fn_def(&mut self) {
         
-        self.backend_writer.write_var_decl(
+        self.backend_writer.write_fn_def_begin(
             &mut self.string,
-            "",
-            false,
-            false,
             &DisplayFnName(self.fn_def.fn_ptr, self.fn_def.ident), // here we must expand IdentPath to something
             self.fn_def.return_ty.borrow().as_ref().unwrap()
         );
-        write!(self.string, "(").unwrap();
         let mut sep = "";
         for param in &self.fn_def.params {
             if !param.shadow.get().is_none() {
@@ -961,7 +1072,7 @@ impl<'a> FnDefGenerator<'a> {
             }
         }
         self.backend_writer.write_fn_def_hidden_params(self.string, self.fn_def.hidden_args.borrow().as_ref().unwrap(), sep);
-        write!(self.string, ") ").unwrap();
+        self.backend_writer.write_fn_def_end(self.string, self.fn_def.return_ty.borrow().as_ref().unwrap());
         self.generate_block(&self.fn_def.block);
         writeln!(self.string).unwrap();
         //self.visited.insert(self.decl.ident_path);
@@ -978,7 +1089,7 @@ impl<'a> FnDefGenerator<'a> {
             indent_level: 0,
             string: self.string,
         }
-        .generate_block(block)
+        .generate_fn_block(block, self.fn_def.return_ty.borrow().as_ref().unwrap())
     }
 }
 
@@ -1053,11 +1164,8 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
     
     pub fn generate_fn_def_with_closure_args(&mut self) {
         
-        self.backend_writer.write_var_decl(
+        self.backend_writer.write_fn_def_begin(
             &mut self.string,
-            "",
-            false,
-            false,
             &DisplayFnNameWithClosureArgs(
                 self.closure_site_info.site_index,
                 self.call_def.fn_ptr,
@@ -1065,7 +1173,6 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
             ), // here we must expand IdentPath to something
             self.fn_def.return_ty.borrow().as_ref().unwrap()
         );
-        write!(self.string, "(").unwrap();
         let mut sep = "";
         for param in &self.fn_def.params {
             if !param.shadow.get().is_none() {
@@ -1101,7 +1208,7 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
         merged_hidden_args.extend(self.call_def.hidden_args.borrow().as_ref().unwrap().iter().cloned());
         self.backend_writer.write_fn_def_hidden_params(self.string, &merged_hidden_args, sep);
         
-        write!(self.string, ") ").unwrap();
+        self.backend_writer.write_fn_def_end(self.string, self.fn_def.return_ty.borrow().as_ref().unwrap());
         // alright so here the block is generated.. however
         // we need to know the names and the closed-over-args passthrough
         self.generate_block(&self.fn_def.block);
@@ -1122,7 +1229,7 @@ impl<'a> FnDefWithClosureArgsGenerator<'a> {
             indent_level: 0,
             string: self.string,
         }
-        .generate_block(block)
+        .generate_fn_block(block, self.fn_def.return_ty.borrow().as_ref().unwrap())
     }
 }
 
@@ -1144,17 +1251,13 @@ impl<'a> ClosureDefGenerator<'a> {
         
         let mut sep = "";
         
-        if let TyExprKind::ClosureDecl {params, return_ty, ..} = &fn_param.ty_expr.kind {
+        let closure_return_ty = if let TyExprKind::ClosureDecl {params, return_ty, ..} = &fn_param.ty_expr.kind {
             
-            self.backend_writer.write_var_decl(
+            self.backend_writer.write_fn_def_begin(
                 &mut self.string,
-                "",
-                false,
-                false,
                 &DisplayClosureName(self.call_def.fn_ptr, self.closure_site_arg.closure_def_index), // here we must expand IdentPath to something
                 return_ty.borrow().as_ref().unwrap(),
             );
-            write!(self.string, "(").unwrap();
             
             // ok we have now params and names
             for (param_index, param) in params.iter().enumerate() {
@@ -1172,10 +1275,11 @@ impl<'a> ClosureDefGenerator<'a> {
                     sep = ", ";
                 }
             }
+            return_ty.borrow().as_ref().unwrap().clone()
         }
         else {
             panic!()
-        }
+        };
         
         for sym in self.closure_def.closed_over_syms.borrow().as_ref().unwrap() {
             if self.backend_writer.write_var_decl(
@@ -1195,7 +1299,8 @@ impl<'a> ClosureDefGenerator<'a> {
         merged_hidden_args.extend(self.call_def.hidden_args.borrow().as_ref().unwrap().iter().cloned());
         self.backend_writer.write_fn_def_hidden_params(self.string, &merged_hidden_args, sep);
         
-        writeln!(self.string, ") {{").unwrap();
+        self.backend_writer.write_fn_def_end(self.string, &closure_return_ty);
+        writeln!(self.string, "{{").unwrap();
         
         match &self.closure_def.kind {
             ClosureDefKind::Expr(expr) => {
@@ -225,6 +225,12 @@ impl<'a> DrawShaderGenerator<'a> {
                             writeln!(self.string, ": INST{};", index_to_char(index)).unwrap();
                             index += 1;
                         },
+                        Ty::Enum(_) => {
+                            write!(self.string, "    ").unwrap();
+                            self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap(),);
+                            writeln!(self.string, ": INST{};", index_to_char(index)).unwrap();
+                            index += 1;
+                        },
                         _ => panic!("unsupported type in generate_instance_struct")
                     }
                 }
@@ -279,6 +285,13 @@ impl<'a> DrawShaderGenerator<'a> {
                             writeln!(self.string, ": VARY{};", index_to_char(index)).unwrap();
                             index += 1;
                         },
+                        Ty::Enum(_) => {
+                            // integer varyings can't be interpolated
+                            write!(self.string, "    nointerpolation ").unwrap();
+                            self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap(),);
+                            writeln!(self.string, ": VARY{};", index_to_char(index)).unwrap();
+                            index += 1;
+                        },
                         _ => panic!("unsupported type in generate_varying_struct")
                     }
                 }
@@ -419,7 +432,7 @@ impl<'a> DrawShaderGenerator<'a> {
     fn write_var_init(&mut self, ty: &Ty) {
         match ty {
             Ty::Bool => write!(self.string, "false").unwrap(),
-            Ty::Int => write!(self.string, "0").unwrap(),
+            Ty::Int | Ty::Enum(_) => write!(self.string, "0").unwrap(),
             Ty::Float => write!(self.string, "0.0").unwrap(),
             Ty::Bvec2 => write!(self.string, "bool2(0,0)").unwrap(),
             Ty::Bvec3 => write!(self.string, "bool3(0,0,0)").unwrap(),
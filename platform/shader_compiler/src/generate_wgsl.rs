@@ -0,0 +1,678 @@
+use {
+    std::{
+        fmt::Write,
+        fmt,
+        collections::{BTreeMap, BTreeSet}
+    },
+    crate::{
+        makepad_live_compiler::*,
+        makepad_live_id::*,
+        shader_ast::*,
+        generate::*,
+        shader_registry::ShaderRegistry,
+    }
+};
+
+// WGSL has no hidden parameters: uniforms, textures and the const table live in
+// bind groups, and geometries, instances and varyings are module level private vars
+// that the entrypoints fill in. Group 0 holds the uniform buffers, group 1 the textures.
+
+pub const WGSL_UNIFORM_GROUP: usize = 0;
+pub const WGSL_TEXTURE_GROUP: usize = 1;
+
+pub struct WgslGeneratedShader {
+    pub wgsl: String,
+    pub fields_as_uniform_blocks: BTreeMap<Ident, Vec<(usize, Ident) >>,
+    pub bindings: Vec<WgslBinding>,
+    pub geometry_attributes: Vec<WgslVertexAttribute>,
+    pub geometry_stride: usize,
+    pub instance_attributes: Vec<WgslVertexAttribute>,
+    pub instance_stride: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WgslBindingKind {
+    LiveUniforms,
+    ConstTable,
+    Uniforms(Ident),
+    Texture(Ident),
+    Sampler,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WgslBinding {
+    pub group: usize,
+    pub binding: usize,
+    pub kind: WgslBindingKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WgslVertexFormat {
+    Float32,
+    Float32x2,
+    Float32x3,
+    Float32x4,
+    Uint32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WgslVertexAttribute {
+    pub location: usize,
+    // in bytes from the start of the geometry or instance
+    pub offset: usize,
+    pub format: WgslVertexFormat,
+}
+
+impl WgslVertexFormat {
+    fn from_slots(slots: usize) -> Self {
+        match slots {
+            1 => Self::Float32,
+            2 => Self::Float32x2,
+            3 => Self::Float32x3,
+            _ => Self::Float32x4,
+        }
+    }
+}
+
+pub fn generate_shader(draw_shader_def: &DrawShaderDef, const_table: &DrawShaderConstTable, shader_registry: &ShaderRegistry) -> WgslGeneratedShader {
+    let mut string = String::new();
+    let fields_as_uniform_blocks = draw_shader_def.fields_as_uniform_blocks();
+    let mut generator = DrawShaderGenerator {
+        draw_shader_def,
+        shader_registry,
+        const_table,
+        string: &mut string,
+        fields_as_uniform_blocks: &fields_as_uniform_blocks,
+        backend_writer: &WgslBackendWriter {shader_registry, draw_shader_def, const_table},
+        bindings: Vec::new(),
+        geometry_attributes: Vec::new(),
+        geometry_stride: 0,
+        instance_attributes: Vec::new(),
+        instance_stride: 0,
+    };
+    generator.generate_shader();
+    let bindings = generator.bindings;
+    let geometry_attributes = generator.geometry_attributes;
+    let geometry_stride = generator.geometry_stride;
+    let instance_attributes = generator.instance_attributes;
+    let instance_stride = generator.instance_stride;
+    WgslGeneratedShader {
+        wgsl: string,
+        fields_as_uniform_blocks,
+        bindings,
+        geometry_attributes,
+        geometry_stride,
+        instance_attributes,
+        instance_stride,
+    }
+}
+
+struct DrawShaderGenerator<'a> {
+    draw_shader_def: &'a DrawShaderDef,
+    shader_registry: &'a ShaderRegistry,
+    string: &'a mut String,
+    fields_as_uniform_blocks: &'a BTreeMap<Ident, Vec<(usize, Ident) >>,
+    backend_writer: &'a dyn BackendWriter,
+    const_table: &'a DrawShaderConstTable,
+    bindings: Vec<WgslBinding>,
+    geometry_attributes: Vec<WgslVertexAttribute>,
+    geometry_stride: usize,
+    instance_attributes: Vec<WgslVertexAttribute>,
+    instance_stride: usize,
+}
+
+impl<'a> DrawShaderGenerator<'a> {
+    fn generate_shader(&mut self) {
+        self.generate_builtin_helpers();
+        self.generate_struct_defs();
+        self.generate_uniform_bindings();
+        self.generate_texture_bindings();
+        self.generate_private_vars();
+        self.generate_vertex_input_struct();
+        self.generate_varying_struct();
+
+        let vertex_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, Ident(live_id!(vertex))).unwrap();
+        let pixel_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, Ident(live_id!(pixel))).unwrap();
+
+        for &(ty_lit, ref param_tys) in pixel_def
+            .constructor_fn_deps
+            .borrow_mut()
+            .as_ref()
+            .unwrap()
+            .union(vertex_def.constructor_fn_deps.borrow().as_ref().unwrap())
+        {
+            generate_cons_fn(self.backend_writer, self.string, ty_lit, param_tys);
+        }
+
+        let all_fns = self.draw_shader_def.all_fns.borrow();
+        for fn_iter in all_fns.iter().rev() {
+            let const_table_offset = self.const_table.offsets.get(fn_iter).cloned();
+            let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
+            if fn_def.has_closure_args() {
+                for call_iter in all_fns.iter().rev() {
+                    // any function that depends on us, will have the closures we need
+                    let call_def = self.shader_registry.all_fns.get(call_iter).unwrap();
+                    if call_def.callees.borrow().as_ref().unwrap().contains(fn_iter) {
+                        FnDefWithClosureArgsGenerator::generate_fn_def_with_all_closures(
+                            self.string,
+                            self.shader_registry,
+                            fn_def,
+                            call_def,
+                            self.backend_writer,
+                            const_table_offset
+                        );
+                    }
+                }
+                continue
+            }
+            FnDefGenerator {
+                fn_def,
+                const_table_offset,
+                shader_registry: self.shader_registry,
+                backend_writer: self.backend_writer,
+                string: self.string,
+            }
+            .generate_fn_def()
+        }
+        self.generate_vertex_main();
+        self.generate_pixel_main();
+    }
+
+    fn generate_builtin_helpers(&mut self) {
+        // LiveId's Ord goes through the string interner, so compare by id instead of using a set lookup
+        let mut builtins = Vec::new();
+        for fn_iter in self.draw_shader_def.all_fns.borrow().iter() {
+            let fn_def = self.shader_registry.all_fns.get(fn_iter).unwrap();
+            builtins.extend(fn_def.builtin_deps.borrow().as_ref().unwrap().iter().map( | ident | ident.0));
+        }
+        if builtins.contains(&live_id!(sample2d)) {
+            writeln!(self.string, "fn sample2d(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {{return textureSampleLevel(tex, default_sampler, pos, 0.0);}}").unwrap();
+        }
+        if builtins.contains(&live_id!(sample2d_rt)) {
+            writeln!(self.string, "fn sample2d_rt(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {{return textureSampleLevel(tex, default_sampler, pos, 0.0);}}").unwrap();
+        }
+        // WGSL has no overloading and its % truncates, so glsl mod gets a helper per signature
+        if builtins.contains(&live_id!(mod)) {
+            for (x, y) in [
+                (Ty::Float, Ty::Float),
+                (Ty::Vec2, Ty::Vec2),
+                (Ty::Vec3, Ty::Vec3),
+                (Ty::Vec4, Ty::Vec4),
+                (Ty::Vec2, Ty::Float),
+                (Ty::Vec3, Ty::Float),
+                (Ty::Vec4, Ty::Float)
+            ] {
+                write!(self.string, "fn mod_{}_{}(", x, y).unwrap();
+                self.write_var_decl(&"x", &x);
+                write!(self.string, ", ").unwrap();
+                self.write_var_decl(&"y", &y);
+                write!(self.string, ") -> ").unwrap();
+                self.write_ty(&x);
+                writeln!(self.string, " {{return x - y * floor(x / y);}}").unwrap();
+            }
+        }
+    }
+
+    fn generate_struct_defs(&mut self) {
+        // we have all the structs already from analyse
+        for struct_ptr in self.draw_shader_def.all_structs.borrow().iter().rev() {
+            let struct_def = self.shader_registry.structs.get(struct_ptr).unwrap();
+            writeln!(self.string, "struct {} {{", struct_ptr).unwrap();
+            for field in &struct_def.fields {
+                write!(self.string, "    ").unwrap();
+                self.write_var_decl(&DisplayStructField(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                writeln!(self.string, ",").unwrap();
+            }
+            writeln!(self.string, "}}").unwrap();
+        }
+    }
+
+    fn add_binding(&mut self, group: usize, kind: WgslBindingKind) -> usize {
+        let binding = self.bindings.iter().filter( | b | b.group == group).count();
+        self.bindings.push(WgslBinding {group, binding, kind});
+        binding
+    }
+
+    fn generate_uniform_bindings(&mut self) {
+        // WGSL doesn't allow empty structs, so these are only emitted when used
+        let live_refs = self.draw_shader_def.all_live_refs.borrow();
+        if !live_refs.is_empty() {
+            writeln!(self.string, "struct LiveUniforms {{").unwrap();
+            for (value_node_ptr, ty) in live_refs.iter() {
+                write!(self.string, "    ").unwrap();
+                self.write_var_decl(value_node_ptr, ty);
+                writeln!(self.string, ",").unwrap();
+            }
+            writeln!(self.string, "}}").unwrap();
+            let binding = self.add_binding(WGSL_UNIFORM_GROUP, WgslBindingKind::LiveUniforms);
+            writeln!(self.string, "@group({}) @binding({}) var<uniform> live_uniforms: LiveUniforms;", WGSL_UNIFORM_GROUP, binding).unwrap();
+        }
+
+        if !self.const_table.table.is_empty() {
+            // uniform arrays need a 16 byte stride, so the table is read as vec4s
+            let binding = self.add_binding(WGSL_UNIFORM_GROUP, WgslBindingKind::ConstTable);
+            writeln!(
+                self.string,
+                "@group({}) @binding({}) var<uniform> const_table: array<vec4<f32>, {}>;",
+                WGSL_UNIFORM_GROUP,
+                binding,
+                self.const_table.table.len().div_ceil(4)
+            ).unwrap();
+        }
+
+        for (ident, vec) in self.fields_as_uniform_blocks {
+            writeln!(self.string, "struct Uniforms_{} {{", ident).unwrap();
+            for (index, _item) in vec {
+                let field = &self.draw_shader_def.fields[*index];
+                write!(self.string, "    ").unwrap();
+                self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                writeln!(self.string, ",").unwrap();
+            }
+            writeln!(self.string, "}}").unwrap();
+            let binding = self.add_binding(WGSL_UNIFORM_GROUP, WgslBindingKind::Uniforms(*ident));
+            writeln!(self.string, "@group({0}) @binding({1}) var<uniform> uniforms_{2}: Uniforms_{2};", WGSL_UNIFORM_GROUP, binding, ident).unwrap();
+        }
+    }
+
+    fn generate_texture_bindings(&mut self) {
+        let mut has_textures = false;
+        for field in &self.draw_shader_def.fields {
+            if let DrawShaderFieldKind::Texture {..} = field.kind {
+                assert_eq!(*field.ty_expr.ty.borrow().as_ref().unwrap(), Ty::Texture2D);
+                let binding = self.add_binding(WGSL_TEXTURE_GROUP, WgslBindingKind::Texture(field.ident));
+                writeln!(self.string, "@group({}) @binding({}) var {}: texture_2d<f32>;", WGSL_TEXTURE_GROUP, binding, DisplayDsIdent(field.ident)).unwrap();
+                has_textures = true;
+            }
+        }
+        if has_textures {
+            let binding = self.add_binding(WGSL_TEXTURE_GROUP, WgslBindingKind::Sampler);
+            writeln!(self.string, "@group({}) @binding({}) var default_sampler: sampler;", WGSL_TEXTURE_GROUP, binding).unwrap();
+        }
+    }
+
+    fn generate_private_vars(&mut self) {
+        for field in &self.draw_shader_def.fields {
+            match field.kind {
+                DrawShaderFieldKind::Instance {..} if field.ident == Ident(LiveId(0)) => (),
+                DrawShaderFieldKind::Geometry {..} |
+                DrawShaderFieldKind::Instance {..} |
+                DrawShaderFieldKind::Varying {..} => {
+                    write!(self.string, "var<private> ").unwrap();
+                    self.write_var_decl(&DisplayDsIdent(field.ident), field.ty_expr.ty.borrow().as_ref().unwrap());
+                    writeln!(self.string, ";").unwrap();
+                }
+                _ => ()
+            }
+        }
+    }
+
+    fn generate_vertex_input_struct(&mut self) {
+        writeln!(self.string, "struct VertexInput {{").unwrap();
+        let mut location = 0;
+        let mut offset = 0;
+        for field in &self.draw_shader_def.fields {
+            if let DrawShaderFieldKind::Geometry {..} = field.kind {
+                let ty = field.ty_expr.ty.borrow();
+                let ty = ty.as_ref().unwrap();
+                writeln!(self.string, "    @location({}) {}: {},", location, DisplayDsIdent(field.ident), DisplayWgslTy(ty)).unwrap();
+                self.geometry_attributes.push(WgslVertexAttribute {location, offset, format: WgslVertexFormat::from_slots(ty.slots())});
+                location += 1;
+                offset += ty.slots() * 4;
+            }
+        }
+        self.geometry_stride = offset;
+
+        offset = 0;
+        for field in &self.draw_shader_def.fields {
+            if let DrawShaderFieldKind::Instance {..} = field.kind {
+                let ty = field.ty_expr.ty.borrow();
+                let ty = ty.as_ref().unwrap();
+                // padding only takes up space in the instance buffer
+                if field.ident == Ident(LiveId(0)) {
+                    offset += ty.slots() * 4;
+                    continue;
+                }
+                match ty {
+                    Ty::Float | Ty::Vec2 | Ty::Vec3 | Ty::Vec4 => {
+                        writeln!(self.string, "    @location({}) {}: {},", location, DisplayDsIdent(field.ident), DisplayWgslTy(ty)).unwrap();
+                        self.instance_attributes.push(WgslVertexAttribute {location, offset, format: WgslVertexFormat::from_slots(ty.slots())});
+                        location += 1;
+                        offset += ty.slots() * 4;
+                    }
+                    Ty::Mat4 | Ty::Mat3 => {
+                        let size = if let Ty::Mat4 = ty {4} else {3};
+                        for i in 0..size {
+                            writeln!(self.string, "    @location({}) {}{}: {},", location, DisplayDsIdent(field.ident), i, DisplayWgslTy(if size == 4 {&Ty::Vec4} else {&Ty::Vec3})).unwrap();
+                            self.instance_attributes.push(WgslVertexAttribute {location, offset, format: WgslVertexFormat::from_slots(size)});
+                            location += 1;
+                            offset += size * 4;
+                        }
+                    }
+                    Ty::Mat2 => {
+                        writeln!(self.string, "    @location({}) {}: vec4<f32>,", location, DisplayDsIdent(field.ident)).unwrap();
+                        self.instance_attributes.push(WgslVertexAttribute {location, offset, format: WgslVertexFormat::Float32x4});
+                        location += 1;
+                        offset += 16;
+                    }
+                    Ty::Enum(_) => {
+                        writeln!(self.string, "    @location({}) {}: u32,", location, DisplayDsIdent(field.ident)).unwrap();
+                        self.instance_attributes.push(WgslVertexAttribute {location, offset, format: WgslVertexFormat::Uint32});
+                        location += 1;
+                        offset += 4;
+                    }
+                    _ => panic!("unsupported type in generate_vertex_input_struct")
+                }
+            }
+        }
+        self.instance_stride = offset;
+        writeln!(self.string, "}}").unwrap();
+    }
+
+    fn generate_varying_struct(&mut self) {
+        writeln!(self.string, "struct Varyings {{").unwrap();
+        writeln!(self.string, "    @builtin(position) position: vec4<f32>,").unwrap();
+        let mut location = 0;
+        for field in &self.draw_shader_def.fields {
+            if !Self::is_varying(field) {
+                continue;
+            }
+            let ty = field.ty_expr.ty.borrow();
+            match ty.as_ref().unwrap() {
+                Ty::Mat4 | Ty::Mat3 => {
+                    let (size, col_ty) = if let Ty::Mat4 = ty.as_ref().unwrap() {(4, &Ty::Vec4)} else {(3, &Ty::Vec3)};
+                    for i in 0..size {
+                        writeln!(self.string, "    @location({}) {}{}: {},", location, DisplayDsIdent(field.ident), i, DisplayWgslTy(col_ty)).unwrap();
+                        location += 1;
+                    }
+                }
+                Ty::Enum(_) => {
+                    writeln!(self.string, "    @location({}) @interpolate(flat) {}: u32,", location, DisplayDsIdent(field.ident)).unwrap();
+                    location += 1;
+                }
+                ty => {
+                    writeln!(self.string, "    @location({}) {}: {},", location, DisplayDsIdent(field.ident), DisplayWgslTy(ty)).unwrap();
+                    location += 1;
+                }
+            }
+        }
+        writeln!(self.string, "}}").unwrap();
+    }
+
+    fn is_varying(field: &DrawShaderFieldDef) -> bool {
+        match &field.kind {
+            DrawShaderFieldKind::Geometry {is_used_in_pixel_shader, ..} |
+            DrawShaderFieldKind::Instance {is_used_in_pixel_shader, ..} => is_used_in_pixel_shader.get(),
+            DrawShaderFieldKind::Varying {..} => true,
+            _ => false
+        }
+    }
+
+    fn generate_vertex_main(&mut self) {
+        writeln!(self.string, "@vertex").unwrap();
+        writeln!(self.string, "fn vertex_main(input: VertexInput) -> Varyings {{").unwrap();
+        for field in &self.draw_shader_def.fields {
+            match field.kind {
+                DrawShaderFieldKind::Instance {..} if field.ident == Ident(LiveId(0)) => (),
+                DrawShaderFieldKind::Geometry {..} | DrawShaderFieldKind::Instance {..} => {
+                    let ident = DisplayDsIdent(field.ident);
+                    match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                        Ty::Mat4 => {
+                            writeln!(self.string, "    {0} = mat4x4<f32>(input.{0}0, input.{0}1, input.{0}2, input.{0}3);", ident).unwrap();
+                        }
+                        Ty::Mat3 => {
+                            writeln!(self.string, "    {0} = mat3x3<f32>(input.{0}0, input.{0}1, input.{0}2);", ident).unwrap();
+                        }
+                        Ty::Mat2 => {
+                            writeln!(self.string, "    {0} = mat2x2<f32>(input.{0}.xy, input.{0}.zw);", ident).unwrap();
+                        }
+                        _ => {
+                            writeln!(self.string, "    {0} = input.{0};", ident).unwrap();
+                        }
+                    }
+                }
+                _ => ()
+            }
+        }
+
+        let vertex_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, Ident(live_id!(vertex))).unwrap();
+        writeln!(self.string, "    var varyings: Varyings;").unwrap();
+        writeln!(self.string, "    varyings.position = {}();", DisplayFnName(vertex_def.fn_ptr, vertex_def.ident)).unwrap();
+
+        for field in &self.draw_shader_def.fields {
+            if !Self::is_varying(field) {
+                continue;
+            }
+            let ident = DisplayDsIdent(field.ident);
+            match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                Ty::Mat4 => for i in 0..4 {
+                    writeln!(self.string, "    varyings.{0}{1} = {0}[{1}];", ident, i).unwrap();
+                }
+                Ty::Mat3 => for i in 0..3 {
+                    writeln!(self.string, "    varyings.{0}{1} = {0}[{1}];", ident, i).unwrap();
+                }
+                _ => {
+                    writeln!(self.string, "    varyings.{0} = {0};", ident).unwrap();
+                }
+            }
+        }
+        writeln!(self.string, "    return varyings;").unwrap();
+        writeln!(self.string, "}}").unwrap();
+    }
+
+    fn generate_pixel_main(&mut self) {
+        writeln!(self.string, "@fragment").unwrap();
+        writeln!(self.string, "fn fragment_main(varyings: Varyings) -> @location(0) vec4<f32> {{").unwrap();
+        for field in &self.draw_shader_def.fields {
+            if !Self::is_varying(field) {
+                continue;
+            }
+            let ident = DisplayDsIdent(field.ident);
+            match field.ty_expr.ty.borrow().as_ref().unwrap() {
+                Ty::Mat4 => {
+                    writeln!(self.string, "    {0} = mat4x4<f32>(varyings.{0}0, varyings.{0}1, varyings.{0}2, varyings.{0}3);", ident).unwrap();
+                }
+                Ty::Mat3 => {
+                    writeln!(self.string, "    {0} = mat3x3<f32>(varyings.{0}0, varyings.{0}1, varyings.{0}2);", ident).unwrap();
+                }
+                _ => {
+                    writeln!(self.string, "    {0} = varyings.{0};", ident).unwrap();
+                }
+            }
+        }
+        let pixel_def = self.shader_registry.draw_shader_method_decl_from_ident(self.draw_shader_def, Ident(live_id!(pixel))).unwrap();
+        writeln!(self.string, "    return {}();", DisplayFnName(pixel_def.fn_ptr, pixel_def.ident)).unwrap();
+        writeln!(self.string, "}}").unwrap();
+    }
+
+    fn write_var_decl(&mut self, ident: &dyn fmt::Display, ty: &Ty) {
+        self.backend_writer.write_var_decl(self.string, "", false, false, ident, ty);
+    }
+
+    fn write_ty(&mut self, ty: &Ty) {
+        write!(self.string, "{}", DisplayWgslTy(ty)).unwrap();
+    }
+}
+
+struct DisplayWgslTy<'a>(&'a Ty);
+impl<'a> fmt::Display for DisplayWgslTy<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Ty::Void => write!(f, "void"),
+            Ty::Bool => write!(f, "bool"),
+            Ty::Int => write!(f, "i32"),
+            Ty::Float => write!(f, "f32"),
+            Ty::Bvec2 => write!(f, "vec2<bool>"),
+            Ty::Bvec3 => write!(f, "vec3<bool>"),
+            Ty::Bvec4 => write!(f, "vec4<bool>"),
+            Ty::Ivec2 => write!(f, "vec2<i32>"),
+            Ty::Ivec3 => write!(f, "vec3<i32>"),
+            Ty::Ivec4 => write!(f, "vec4<i32>"),
+            Ty::Vec2 => write!(f, "vec2<f32>"),
+            Ty::Vec3 => write!(f, "vec3<f32>"),
+            Ty::Vec4 => write!(f, "vec4<f32>"),
+            Ty::Mat2 => write!(f, "mat2x2<f32>"),
+            Ty::Mat3 => write!(f, "mat3x3<f32>"),
+            Ty::Mat4 => write!(f, "mat4x4<f32>"),
+            Ty::Texture2D => write!(f, "texture_2d<f32>"),
+            Ty::Array {elem_ty, len} => write!(f, "array<{}, {}>", DisplayWgslTy(elem_ty), len),
+            Ty::Struct(struct_ptr) => write!(f, "{}", struct_ptr),
+            Ty::Enum(_) => write!(f, "u32"),
+            Ty::DrawShader(_) | Ty::ClosureDef {..} | Ty::ClosureDecl => panic!(),
+        }
+    }
+}
+
+struct WgslBackendWriter<'a> {
+    pub shader_registry: &'a ShaderRegistry,
+    pub draw_shader_def: &'a DrawShaderDef,
+    pub const_table: &'a DrawShaderConstTable,
+}
+
+impl<'a> BackendWriter for WgslBackendWriter<'a> {
+
+    fn needs_cstyle_struct_cons(&self) -> bool {
+        true
+    }
+
+    fn needs_mul_fn_for_matrix_multiplication(&self) -> bool {
+        false
+    }
+
+    fn needs_unpack_for_matrix_multiplication(&self) -> bool {
+        false
+    }
+
+    fn enum_is_float(&self) -> bool {
+        false
+    }
+
+    fn const_table_is_vec4(&self) -> bool {
+        true
+    }
+
+    fn needs_select_for_cond_expr(&self) -> bool {
+        true
+    }
+
+    fn inout_is_pointer(&self) -> bool {
+        true
+    }
+
+    fn assign_is_stmt(&self) -> bool {
+        true
+    }
+
+    fn needs_fallback_return(&self) -> bool {
+        true
+    }
+
+    fn write_fallback_return(&self, string: &mut String, return_ty: &Ty) {
+        // WGSL wants every path to return, even after an exhaustive match
+        writeln!(string, "return {}();", DisplayWgslTy(return_ty)).unwrap();
+    }
+
+    fn use_cons_fn(&self, what: &str) -> bool {
+        // WGSL can't construct a matrix from a differently sized one
+        matches!(what, "consfn_mat3_mat4" | "consfn_mat2_mat4" | "consfn_mat2_mat3")
+    }
+
+    fn write_var_decl(
+        &self,
+        string: &mut String,
+        sep: &'static str,
+        is_inout: bool,
+        _is_packed: bool,
+        ident: &dyn fmt::Display,
+        ty: &Ty,
+    ) -> bool {
+        match ty {
+            Ty::DrawShader(_) | Ty::ClosureDef {..} | Ty::ClosureDecl => {
+                return false
+            }
+            Ty::Void => {
+                write!(string, "{}{}", sep, ident).unwrap();
+            }
+            _ => {
+                if is_inout {
+                    write!(string, "{}{}: ptr<function, {}>", sep, ident, DisplayWgslTy(ty)).unwrap();
+                }
+                else {
+                    write!(string, "{}{}: {}", sep, ident, DisplayWgslTy(ty)).unwrap();
+                }
+            }
+        }
+        true
+    }
+
+    fn write_fn_def_begin(&self, string: &mut String, ident: &dyn fmt::Display, _return_ty: &Ty) {
+        write!(string, "fn {}(", ident).unwrap();
+    }
+
+    fn write_fn_def_end(&self, string: &mut String, return_ty: &Ty) {
+        if let Ty::Void = return_ty {
+            write!(string, ") ").unwrap();
+        }
+        else {
+            write!(string, ") -> {} ", DisplayWgslTy(return_ty)).unwrap();
+        }
+    }
+
+    fn write_local_var_decl(&self, string: &mut String, ident: &dyn fmt::Display, ty: &Ty) {
+        write!(string, "var {}: {}", ident, DisplayWgslTy(ty)).unwrap();
+    }
+
+    fn write_call_expr_hidden_args(&self, _string: &mut String, _hidden_args: &BTreeSet<HiddenArgKind >, _sep: &str) {
+    }
+
+    fn write_fn_def_hidden_params(&self, _string: &mut String, _hidden_args: &BTreeSet<HiddenArgKind >, _sep: &str) {
+    }
+
+    fn generate_live_value_prefix(&self, string: &mut String) {
+        write!(string, "live_uniforms.").unwrap();
+    }
+
+    fn generate_draw_shader_field_expr(&self, string: &mut String, field_ident: Ident, _ty: &Ty) {
+        let field_def = self.draw_shader_def.find_field(field_ident).unwrap();
+        if let DrawShaderFieldKind::Uniform {block_ident, ..} = &field_def.kind {
+            write!(string, "uniforms_{}.", block_ident).unwrap();
+        }
+        write!(string, "{}", &DisplayDsIdent(field_ident)).unwrap();
+    }
+
+    fn write_ty_lit(&self, string: &mut String, ty_lit: TyLit) {
+        write!(string, "{}", DisplayWgslTy(&ty_lit.to_ty())).unwrap();
+    }
+
+    fn write_builtin_call_ident(&self, string: &mut String, ident: Ident, arg_exprs: &[Expr]) {
+        match ident {
+            Ident(live_id!(atan)) => {
+                if arg_exprs.len() == 2 {
+                    write!(string, "atan2").unwrap();
+                }
+                else {
+                    write!(string, "atan").unwrap();
+                }
+            }
+            Ident(live_id!(mod)) => {
+                write!(
+                    string,
+                    "mod_{}_{}",
+                    arg_exprs[0].ty.borrow().as_ref().unwrap(),
+                    arg_exprs[1].ty.borrow().as_ref().unwrap()
+                ).unwrap();
+            }
+            Ident(live_id!(dFdx)) => {
+                write!(string, "dpdx").unwrap();
+            }
+            Ident(live_id!(dFdy)) => {
+                write!(string, "dpdy").unwrap();
+            }
+            Ident(live_id!(inversesqrt)) => {
+                write!(string, "inverseSqrt").unwrap();
+            }
+            _ => {
+                write!(string, "{}", ident).unwrap()
+            }
+        }
+    }
+}
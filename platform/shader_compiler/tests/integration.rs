@@ -472,3 +472,275 @@ fn main() {
 #[test]
 fn main() {
 }
+
+// WGSL golden files. Compiles the draw shaders from draw_2d/src/shader the same way
+// draw_vars does at runtime and compares against tests/wgsl/<name>.wgsl.
+// Run with UPDATE_GOLDEN=1 to rewrite the golden files after an intended change.
+
+mod wgsl_golden {
+    use std::any::TypeId;
+    use makepad_shader_compiler::{
+        makepad_live_compiler::*,
+        makepad_live_id::*,
+        shader_ast::{DrawShaderPtr, DrawShaderDef, DrawShaderFieldKind, Ty, Ident},
+        generate_wgsl,
+        ShaderRegistry,
+        ShaderEnum,
+        DrawShaderQuery,
+    };
+    
+    // stand-ins for the rust types of the draw_2d structs, only their TypeIds are used
+    struct DrawQuad;
+    struct DrawColor;
+    struct DrawShape;
+    struct DrawText;
+    struct DrawVars;
+    struct Shape;
+    struct Fill;
+    struct Float;
+    struct Vec2;
+    struct Vec3;
+    struct Vec4;
+    
+    const DRAW_2D_SHADERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../draw_2d/src/shader/");
+    const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/wgsl/");
+    
+    fn read_shader_file(file: &str) -> String {
+        std::fs::read_to_string(format!("{}{}", DRAW_2D_SHADERS, file)).unwrap()
+    }
+    
+    // the body of the first `<open>{..}` in a rust file
+    fn block_body(source: &str, open: &str) -> String {
+        let start = source.find(open).unwrap_or_else( | | panic!("{} not found", open)) + open.len();
+        let mut depth = 1;
+        for (index, c) in source[start..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return source[start..start + index].to_string()
+                    }
+                }
+                _ => ()
+            }
+        }
+        panic!("unterminated {} block", open)
+    }
+    
+    fn type_info<T: 'static>(name: &str, module: &str) -> LiveTypeInfo {
+        LiveTypeInfo {
+            live_type: TypeId::of::<T>(),
+            type_name: LiveId::from_str(name).unwrap(),
+            module_id: LiveModuleId::from_str(module).unwrap(),
+            live_ignore: false,
+            fields: Vec::new()
+        }
+    }
+    
+    // value types have no live definitions here, so the expander is told to skip them
+    fn field_type_info(ty: &str, draw_super: &Option<LiveTypeInfo>) -> LiveTypeInfo {
+        if ty == "DrawQuad" {
+            return draw_super.clone().unwrap()
+        }
+        let module = "makepad_draw_2d::shader";
+        let mut live_type_info = match ty {
+            "DrawVars" => type_info::<DrawVars>(ty, module),
+            "Shape" => type_info::<Shape>(ty, module),
+            "Fill" => type_info::<Fill>(ty, module),
+            "f32" => type_info::<Float>(ty, module),
+            "Vec2" => type_info::<Vec2>(ty, module),
+            "Vec3" => type_info::<Vec3>(ty, module),
+            "Vec4" => type_info::<Vec4>(ty, module),
+            _ => panic!("field type {} is not understood by shaders", ty)
+        };
+        live_type_info.live_ignore = true;
+        live_type_info
+    }
+    
+    // the draw_super, draw_vars and instance fields of a draw shader struct, read from its rust source
+    fn struct_type_info<T: 'static>(file: &str, name: &str, draw_super: Option<LiveTypeInfo>) -> LiveTypeInfo {
+        let mut live_type_info = type_info::<T>(name, &format!("makepad_draw_2d::shader::{}", file));
+        let body = block_body(&read_shader_file(&format!("{}.rs", file)), &format!("pub struct {} {{", name));
+        // fields in front of draw_vars are not part of the instance, a struct without draw_vars extends its draw_super
+        let mut after_draw_vars = !body.contains("draw_vars:");
+        for line in body.lines().map( | line | line.trim()).filter( | line | line.starts_with("#[")) {
+            let (attr, rest) = line.split_once(']').unwrap();
+            let (id, ty) = rest.trim().trim_start_matches("pub ").trim_end_matches(',').split_once(':').unwrap();
+            let (id, ty) = (id.trim(), ty.trim());
+            if id == "draw_vars" {
+                after_draw_vars = true;
+            }
+            else if id != "draw_super" && !after_draw_vars {
+                continue
+            }
+            live_type_info.fields.push(LiveTypeField {
+                id: LiveId::from_str(id).unwrap(),
+                live_type_info: field_type_info(ty, &draw_super),
+                live_field_kind: if attr.starts_with("#[calc") {LiveFieldKind::Calc} else {LiveFieldKind::Live}
+            });
+        }
+        live_type_info
+    }
+    
+    fn shader_ty(live_type: LiveType) -> Option<Ty> {
+        if live_type == TypeId::of::<Float>() {Some(Ty::Float)}
+        else if live_type == TypeId::of::<Vec2>() {Some(Ty::Vec2)}
+        else if live_type == TypeId::of::<Vec3>() {Some(Ty::Vec3)}
+        else if live_type == TypeId::of::<Vec4>() {Some(Ty::Vec4)}
+        else {None}
+    }
+    
+    // adds the instance fields the same way draw_vars does at runtime, with padding after a draw_super
+    fn add_instance_fields(live_registry: &LiveRegistry, sr: &ShaderRegistry, level: usize, after_draw_vars: &mut bool, live_type: LiveType, draw_shader_def: &mut DrawShaderDef, span: TokenSpan) {
+        let live_type_info = live_registry.live_type_infos.get(&live_type).unwrap();
+        let mut slots = 0;
+        for field in &live_type_info.fields {
+            let field_type = field.live_type_info.live_type;
+            if field.id == live_id!(draw_super) {
+                add_instance_fields(live_registry, sr, level + 1, after_draw_vars, field_type, draw_shader_def, span);
+            }
+            else if field.id == live_id!(draw_vars) {
+                *after_draw_vars = true;
+            }
+            else if *after_draw_vars {
+                let ty = if sr.enums.contains_key(&field_type) {Ty::Enum(field_type)} else {shader_ty(field_type).unwrap()};
+                slots += ty.slots();
+                draw_shader_def.add_instance(field.id, ty, span, field.live_field_kind);
+            }
+        }
+        if level > 0 && slots % 2 == 1 {
+            draw_shader_def.add_instance(LiveId(0), Ty::Float, span, LiveFieldKind::Calc);
+        }
+    }
+    
+    fn load_draw_2d_shaders() -> (LiveRegistry, ShaderRegistry) {
+        let mut sr = ShaderRegistry::new();
+        sr.register_enum(TypeId::of::<Shape>(), ShaderEnum {
+            enum_name: live_id!(Shape),
+            variants: vec![live_id!(None), live_id!(Solid), live_id!(Rect), live_id!(Box), live_id!(BoxX), live_id!(BoxY), live_id!(BoxAll), live_id!(Circle), live_id!(Hexagon), live_id!(ShadowBox)]
+        });
+        sr.register_enum(TypeId::of::<Fill>(), ShaderEnum {
+            enum_name: live_id!(Fill),
+            variants: vec![live_id!(Color), live_id!(GradientX), live_id!(GradientY), live_id!(Image)]
+        });
+        
+        let draw_quad = struct_type_info::<DrawQuad>("draw_quad", "DrawQuad", None);
+        let files = [
+            ("std", vec![]),
+            ("draw_quad", vec![draw_quad.clone()]),
+            ("draw_color", vec![struct_type_info::<DrawColor>("draw_color", "DrawColor", Some(draw_quad.clone()))]),
+            ("draw_shape", vec![struct_type_info::<DrawShape>("draw_shape", "DrawShape", Some(draw_quad.clone()))]),
+            ("draw_text", vec![struct_type_info::<DrawText>("draw_text", "DrawText", None)]),
+        ];
+        let mut live_registry = LiveRegistry::default();
+        for (name, live_type_infos) in files {
+            let file_name = format!("draw_2d/src/shader/{}.rs", name);
+            if let Err(e) = live_registry.register_live_file(
+                &file_name,
+                "",
+                LiveModuleId::from_str(&format!("makepad_draw_2d::shader::{}", name)).unwrap(),
+                block_body(&read_shader_file(&format!("{}.rs", name)), "live_design!{"),
+                live_type_infos,
+                TextPos::default()
+            ) {
+                panic!("{}", e);
+            }
+        }
+        let mut errors = Vec::new();
+        live_registry.expand_all_documents(&mut errors);
+        for error in &errors {
+            println!("{}", live_registry.live_error_to_live_file_error(error.clone()));
+        }
+        assert!(errors.is_empty());
+        (live_registry, sr)
+    }
+    
+    fn draw_shader_ptr(live_registry: &LiveRegistry, module: &str, name: &str) -> DrawShaderPtr {
+        DrawShaderPtr(live_registry.module_id_and_name_to_ptr(
+            LiveModuleId::from_str(module).unwrap(),
+            LiveId::from_str(name).unwrap()
+        ).unwrap())
+    }
+    
+    fn generate_wgsl(live_registry: &LiveRegistry, sr: &mut ShaderRegistry, module: &str, name: &str) -> generate_wgsl::WgslGeneratedShader {
+        let shader_ptr = draw_shader_ptr(live_registry, module, name);
+        let result = sr.analyse_draw_shader(live_registry, shader_ptr, | live_registry, sr, span, query, live_type, draw_shader_def | {
+            match query {
+                DrawShaderQuery::DrawShader => {
+                    draw_shader_def.add_geometry(live_id!(geom_pos), Ty::Vec2, span);
+                    add_instance_fields(live_registry, sr, 0, &mut false, live_type, draw_shader_def, span);
+                }
+                DrawShaderQuery::Geometry => ()
+            }
+        });
+        if let Err(e) = result {
+            panic!("{}", live_registry.live_error_to_live_file_error(e));
+        }
+        let const_table = sr.compute_const_table(shader_ptr);
+        let draw_shader_def = sr.draw_shader_defs.get(&shader_ptr).unwrap();
+        generate_wgsl::generate_shader(draw_shader_def, &const_table, sr)
+    }
+    
+    fn check_golden(live_registry: &LiveRegistry, sr: &mut ShaderRegistry, module: &str, name: &str, golden: &str) {
+        let wgsl = generate_wgsl(live_registry, sr, module, name).wgsl;
+        let path = format!("{}{}", GOLDEN_DIR, golden);
+        if std::env::var("UPDATE_GOLDEN").is_ok() {
+            std::fs::create_dir_all(GOLDEN_DIR).unwrap();
+            std::fs::write(&path, &wgsl).unwrap();
+            return
+        }
+        let expected = std::fs::read_to_string(&path).unwrap_or_else( | _ | panic!("missing golden file {}", path));
+        if expected != wgsl {
+            println!("########## WGSL OUTPUT CHANGED {} ##########\n{}\n########## END ##########", golden, wgsl);
+            panic!("WGSL output for {} does not match {}", name, path);
+        }
+    }
+    
+    #[test]
+    fn wgsl_draw_2d_shaders() {
+        let (live_registry, mut sr) = load_draw_2d_shaders();
+        check_golden(&live_registry, &mut sr, "makepad_draw_2d::shader::draw_quad", "DrawQuad", "draw_quad.wgsl");
+        check_golden(&live_registry, &mut sr, "makepad_draw_2d::shader::draw_color", "DrawColor", "draw_color.wgsl");
+        check_golden(&live_registry, &mut sr, "makepad_draw_2d::shader::draw_shape", "DrawShape", "draw_shape.wgsl");
+        check_golden(&live_registry, &mut sr, "makepad_draw_2d::shader::draw_text", "DrawText", "draw_text.wgsl");
+    }
+    
+    #[test]
+    fn wgsl_bind_group_layout() {
+        let (live_registry, mut sr) = load_draw_2d_shaders();
+        let shader = generate_wgsl(&live_registry, &mut sr, "makepad_draw_2d::shader::draw_shape", "DrawShape");
+        
+        // the draw, pass and view uniform blocks are always present
+        for block in [live_id!(draw), live_id!(pass), live_id!(view)] {
+            assert!(shader.bindings.iter().any( | b | b.group == generate_wgsl::WGSL_UNIFORM_GROUP && b.kind == generate_wgsl::WgslBindingKind::Uniforms(Ident(block))));
+        }
+        let textures: Vec<_> = shader.bindings.iter().filter( | b | b.group == generate_wgsl::WGSL_TEXTURE_GROUP).collect();
+        assert_eq!(textures.len(), 2);
+        assert_eq!(textures[0].kind, generate_wgsl::WgslBindingKind::Texture(Ident(live_id!(image))));
+        assert_eq!(textures[1].kind, generate_wgsl::WgslBindingKind::Sampler);
+        
+        // bindings are numbered densely per group
+        for group in [generate_wgsl::WGSL_UNIFORM_GROUP, generate_wgsl::WGSL_TEXTURE_GROUP] {
+            for (index, binding) in shader.bindings.iter().filter( | b | b.group == group).enumerate() {
+                assert_eq!(binding.binding, index);
+            }
+        }
+        
+        // geom_pos, and the instance buffer matches the analysed instance fields including padding
+        let draw_shader_def = sr.draw_shader_defs.get(&draw_shader_ptr(&live_registry, "makepad_draw_2d::shader::draw_shape", "DrawShape")).unwrap();
+        let instances: Vec<_> = draw_shader_def.fields.iter().filter( | field | matches!(field.kind, DrawShaderFieldKind::Instance {..})).collect();
+        let slots: usize = instances.iter().map( | field | field.ty_expr.ty.borrow().as_ref().unwrap().slots()).sum();
+        assert_eq!(shader.geometry_stride, 8);
+        assert_eq!(shader.instance_stride, 4 * slots);
+        assert_eq!(shader.instance_attributes[0].location, shader.geometry_attributes.len());
+        
+        // the draw_super fields are padded to an even slot count before the shape enum
+        let shape_index = instances.iter().position( | field | field.ident == Ident(live_id!(shape))).unwrap();
+        assert_eq!(instances[shape_index - 1].ident, Ident(LiveId(0)));
+        let shape_offset: usize = instances[..shape_index].iter().map( | field | field.ty_expr.ty.borrow().as_ref().unwrap().slots()).sum();
+        assert_eq!(shape_offset % 2, 0);
+        let shape = shader.instance_attributes.iter().find( | a | a.offset == 4 * shape_offset).unwrap();
+        assert_eq!(shape.format, generate_wgsl::WgslVertexFormat::Uint32);
+    }
+}
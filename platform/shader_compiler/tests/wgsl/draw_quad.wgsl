@@ -0,0 +1,62 @@
+@group(0) @binding(0) var<uniform> const_table: array<vec4<f32>, 2>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+}
+@group(0) @binding(1) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+}
+@group(0) @binding(2) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+}
+@group(0) @binding(3) var<uniform> uniforms_view: Uniforms_view;
+var<private> ds_geom_pos: vec2<f32>;
+var<private> ds_rect_pos: vec2<f32>;
+var<private> ds_rect_size: vec2<f32>;
+var<private> ds_draw_clip: vec4<f32>;
+var<private> ds_draw_depth: f32;
+var<private> ds_pos: vec2<f32>;
+struct VertexInput {
+    @location(0) ds_geom_pos: vec2<f32>,
+    @location(1) ds_rect_pos: vec2<f32>,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_draw_clip: vec4<f32>,
+    @location(4) ds_draw_depth: f32,
+}
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_pos: vec2<f32>,
+}
+fn fn_1_6_pixel() -> vec4<f32> {
+    return vec4<f32>(const_table[0].y, const_table[0].z, const_table[0].w, const_table[1].x);
+}
+fn fn_1_3_clip_and_transform_vertex() -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((ds_geom_pos * ds_rect_size) + ds_rect_pos), ds_draw_clip.xy, ds_draw_clip.zw);
+    ds_pos = ((var_clipped_0 - ds_rect_pos) / ds_rect_size);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_1_5_vertex() -> vec4<f32> {
+    return fn_1_3_clip_and_transform_vertex ();
+}
+@vertex
+fn vertex_main(input: VertexInput) -> Varyings {
+    ds_geom_pos = input.ds_geom_pos;
+    ds_rect_pos = input.ds_rect_pos;
+    ds_rect_size = input.ds_rect_size;
+    ds_draw_clip = input.ds_draw_clip;
+    ds_draw_depth = input.ds_draw_depth;
+    var varyings: Varyings;
+    varyings.position = fn_1_5_vertex();
+    varyings.ds_pos = ds_pos;
+    return varyings;
+}
+@fragment
+fn fragment_main(varyings: Varyings) -> @location(0) vec4<f32> {
+    ds_pos = varyings.ds_pos;
+    return fn_1_6_pixel();
+}
@@ -0,0 +1,352 @@
+fn sample2d(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_sampler, pos, 0.0);}
+struct struct_0_27 {
+    f_pos: vec2<f32>,
+    f_result: vec4<f32>,
+    f_last_pos: vec2<f32>,
+    f_start_pos: vec2<f32>,
+    f_shape: f32,
+    f_clip: f32,
+    f_has_clip: f32,
+    f_old_shape: f32,
+    f_blur: f32,
+    f_aa: f32,
+    f_scale_factor: f32,
+    f_dist: f32,
+}
+struct LiveUniforms {
+    live_0_8: f32,
+}
+@group(0) @binding(0) var<uniform> live_uniforms: LiveUniforms;
+@group(0) @binding(1) var<uniform> const_table: array<vec4<f32>, 35>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+}
+@group(0) @binding(2) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+}
+@group(0) @binding(3) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+}
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+@group(1) @binding(0) var ds_image: texture_2d<f32>;
+@group(1) @binding(1) var default_sampler: sampler;
+var<private> ds_geom_pos: vec2<f32>;
+var<private> ds_rect_pos: vec2<f32>;
+var<private> ds_rect_size: vec2<f32>;
+var<private> ds_draw_clip: vec4<f32>;
+var<private> ds_draw_depth: f32;
+var<private> ds_shape: u32;
+var<private> ds_fill: u32;
+var<private> ds_color: vec4<f32>;
+var<private> ds_color2: vec4<f32>;
+var<private> ds_border_width: f32;
+var<private> ds_border_color: vec4<f32>;
+var<private> ds_inset: vec4<f32>;
+var<private> ds_radius: vec4<f32>;
+var<private> ds_image_scale: vec2<f32>;
+var<private> ds_image_pan: vec2<f32>;
+var<private> ds_pos: vec2<f32>;
+struct VertexInput {
+    @location(0) ds_geom_pos: vec2<f32>,
+    @location(1) ds_rect_pos: vec2<f32>,
+    @location(2) ds_rect_size: vec2<f32>,
+    @location(3) ds_draw_clip: vec4<f32>,
+    @location(4) ds_draw_depth: f32,
+    @location(5) ds_shape: u32,
+    @location(6) ds_fill: u32,
+    @location(7) ds_color: vec4<f32>,
+    @location(8) ds_color2: vec4<f32>,
+    @location(9) ds_border_width: f32,
+    @location(10) ds_border_color: vec4<f32>,
+    @location(11) ds_inset: vec4<f32>,
+    @location(12) ds_radius: vec4<f32>,
+    @location(13) ds_image_scale: vec2<f32>,
+    @location(14) ds_image_pan: vec2<f32>,
+}
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_rect_size: vec2<f32>,
+    @location(1) @interpolate(flat) ds_shape: u32,
+    @location(2) @interpolate(flat) ds_fill: u32,
+    @location(3) ds_color: vec4<f32>,
+    @location(4) ds_color2: vec4<f32>,
+    @location(5) ds_border_width: f32,
+    @location(6) ds_border_color: vec4<f32>,
+    @location(7) ds_inset: vec4<f32>,
+    @location(8) ds_radius: vec4<f32>,
+    @location(9) ds_image_scale: vec2<f32>,
+    @location(10) ds_image_pan: vec2<f32>,
+    @location(11) ds_pos: vec2<f32>,
+}
+fn fn_3_9_get_color() -> vec4<f32> {
+    return ds_color;
+}
+fn fn_3_10_get_fill() -> vec4<f32> {
+    if(ds_fill == 1){
+            return fn_3_9_get_color ();
+    }else  if(ds_fill == 2){
+            return mix(ds_color, ds_color2, ds_pos.x);
+    }else  if(ds_fill == 3){
+            return mix(ds_color, ds_color2, ds_pos.y);
+    }else  if(ds_fill == 4){
+            return sample2d(ds_image, ((ds_pos * ds_image_scale) + ds_image_pan)).xyzw;
+    }
+    return vec4<f32>();
+}
+fn fn_0_64_hexagon(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_r_0: f32) {
+    var var_dx_0: f32 = (abs((var_x_0 - (*var_self_0).f_pos.x)) * const_table[34].y);
+    var var_dy_0: f32 = abs((var_y_0 - (*var_self_0).f_pos.y));
+    (*var_self_0).f_dist = max(((var_dy_0 + (cos((const_table[34].z * live_uniforms.live_0_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0));
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_63_rect(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32) {
+    var var_s_0: vec2<f32> = (vec2<f32>(var_w_0, var_h_0) * const_table[33].x);
+    var var_d_0: vec2<f32> = (abs(((vec2<f32>(var_x_0, var_y_0) - (*var_self_0).f_pos) + var_s_0)) - var_s_0);
+    var var_dm_0: vec2<f32> = min(var_d_0, vec2<f32>(const_table[33].y, const_table[33].z));
+    (*var_self_0).f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2<f32>(const_table[33].w, const_table[34].x))));
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_62_box_all(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_left_top_0: f32, var_r_right_top_0: f32, var_r_right_bottom_0: f32, var_r_left_bottom_0: f32) {
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[26].w * var_w_0), (const_table[27].x * var_h_0));
+    var var_p_r_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_p_0: vec2<f32> = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    var var_bp_lt_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[27].y * var_r_left_top_0), (const_table[27].z * var_r_left_top_0)).xy), vec2<f32>(const_table[27].w, const_table[28].x));
+    var var_bp_rt_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[28].y * var_r_right_top_0), (const_table[28].z * var_r_right_top_0)).xy), vec2<f32>(const_table[28].w, const_table[29].x));
+    var var_bp_rb_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[29].y * var_r_right_bottom_0), (const_table[29].z * var_r_right_bottom_0)).xy), vec2<f32>(const_table[29].w, const_table[30].x));
+    var var_bp_lb_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[30].y * var_r_left_bottom_0), (const_table[30].z * var_r_left_bottom_0)).xy), vec2<f32>(const_table[30].w, const_table[31].x));
+    (*var_self_0).f_dist = (mix(mix((length(var_bp_lt_0) - (const_table[31].y * var_r_left_top_0)), (length(var_bp_lb_0) - (const_table[31].z * var_r_left_bottom_0)), step((const_table[31].w * var_h_0), var_p_r_0.y)), mix((length(var_bp_rt_0) - (const_table[32].x * var_r_right_top_0)), (length(var_bp_rb_0) - (const_table[32].y * var_r_right_bottom_0)), step((const_table[32].z * var_h_0), var_p_r_0.y)), step((const_table[32].w * var_w_0), var_p_r_0.x)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_61_box_x(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_left_0: f32, var_r_right_0: f32) {
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[23].z * var_w_0), (const_table[23].w * var_h_0));
+    var var_p_r_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_p_0: vec2<f32> = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    var var_bp_left_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[24].x * var_r_left_0), (const_table[24].y * var_r_left_0)).xy), vec2<f32>(const_table[24].z, const_table[24].w));
+    var var_bp_right_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[25].x * var_r_right_0), (const_table[25].y * var_r_right_0)).xy), vec2<f32>(const_table[25].z, const_table[25].w));
+    (*var_self_0).f_dist = (mix((length(var_bp_left_0) - (const_table[26].x * var_r_left_0)), (length(var_bp_right_0) - (const_table[26].y * var_r_right_0)), step((const_table[26].z * var_w_0), var_p_r_0.x)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_60_box_y(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_top_0: f32, var_r_bottom_0: f32) {
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[20].y * var_w_0), (const_table[20].z * var_h_0));
+    var var_p_r_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_p_0: vec2<f32> = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    var var_bp_top_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[20].w * var_r_top_0), (const_table[21].x * var_r_top_0)).xy), vec2<f32>(const_table[21].y, const_table[21].z));
+    var var_bp_bottom_0: vec2<f32> = max((var_p_0 + vec2<f32>((const_table[21].w * var_r_bottom_0), (const_table[22].x * var_r_bottom_0)).xy), vec2<f32>(const_table[22].y, const_table[22].z));
+    (*var_self_0).f_dist = (mix((length(var_bp_top_0) - (const_table[22].w * var_r_top_0)), (length(var_bp_bottom_0) - (const_table[23].x * var_r_bottom_0)), step((const_table[23].y * var_h_0), var_p_r_0.y)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_59_box(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_w_0: f32, var_h_0: f32, var_r_0: f32) {
+    var var_p_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_size_0: vec2<f32> = vec2<f32>((const_table[18].z * var_w_0), (const_table[18].w * var_h_0));
+    var var_bp_0: vec2<f32> = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2<f32>((const_table[19].x * var_r_0), (const_table[19].y * var_r_0)).xy)), vec2<f32>(const_table[19].z, const_table[19].w));
+    (*var_self_0).f_dist = ((length(var_bp_0) - (const_table[20].x * var_r_0)) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_58_circle(var_self_0: ptr<function, struct_0_27>, var_x_0: f32, var_y_0: f32, var_r_0: f32) {
+    var var_c_0: vec2<f32> = ((*var_self_0).f_pos - vec2<f32>(var_x_0, var_y_0));
+    var var_len_0: f32 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (*var_self_0).f_dist = ((var_len_0 - var_r_0) / (*var_self_0).f_scale_factor);
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_shape = min((*var_self_0).f_shape, (*var_self_0).f_dist);
+}
+fn fn_0_46_calc_blur(var_self_0: ptr<function, struct_0_27>, var_w_0: f32) -> f32 {
+    var var_wa_0: f32 = clamp((-var_w_0 * (*var_self_0).f_aa), const_table[17].x, const_table[17].y);
+    var var_wb_0: f32 = const_table[17].z;
+    if((*var_self_0).f_blur > const_table[17].w) {
+            var_wb_0 = clamp((-var_w_0 / (*var_self_0).f_blur), const_table[18].x, const_table[18].y);
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+fn fn_0_49_stroke_keep(var_self_0: ptr<function, struct_0_27>, var_color_0: vec4<f32>, var_width_0: f32) -> vec4<f32> {
+    var var_f_0: f32 = fn_0_46_calc_blur (&(*var_self_0), (abs((*var_self_0).f_shape) - (var_width_0 / (*var_self_0).f_scale_factor)));
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    var var_dest_0: vec4<f32> = (*var_self_0).f_result;
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[16].w - (var_source_0.a * var_f_0))));
+    return (*var_self_0).f_result;
+}
+fn fn_0_50_stroke(var_self_0: ptr<function, struct_0_27>, var_color_0: vec4<f32>, var_width_0: f32) -> vec4<f32> {
+    fn_0_49_stroke_keep (&(*var_self_0), var_color_0, var_width_0);
+    (*var_self_0).f_shape = const_table[16].x;
+    (*var_self_0).f_old_shape = (*var_self_0).f_shape;
+    (*var_self_0).f_clip = -const_table[16].y;
+    (*var_self_0).f_has_clip = const_table[16].z;
+    return (*var_self_0).f_result;
+}
+fn fn_0_47_fill_keep(var_self_0: ptr<function, struct_0_27>, var_color_0: vec4<f32>) -> vec4<f32> {
+    var var_f_0: f32 = fn_0_46_calc_blur (&(*var_self_0), (*var_self_0).f_shape);
+    var var_source_0: vec4<f32> = vec4<f32>((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (*var_self_0).f_result = ((var_source_0 * var_f_0) + ((*var_self_0).f_result * (const_table[15].x - (var_source_0.a * var_f_0))));
+    if((*var_self_0).f_has_clip > const_table[15].y) {
+            var var_f2_0: f32 = (const_table[15].z - fn_0_46_calc_blur (&(*var_self_0), -(*var_self_0).f_clip));
+        (*var_self_0).f_result = ((var_source_0 * var_f2_0) + ((*var_self_0).f_result * (const_table[15].w - (var_source_0.a * var_f2_0))));
+    }
+
+    return (*var_self_0).f_result;
+}
+fn fn_0_40_antialias(var_p_0: vec2<f32>) -> f32 {
+    return (const_table[14].w / length(vec2<f32>(length(dpdx(var_p_0)), length(dpdy(var_p_0)))));
+}
+fn fn_0_41_viewport(var_pos_0: vec2<f32>) -> struct_0_27 {
+    return struct_0_27(var_pos_0,vec4<f32>(const_table[12].y),vec2<f32>(const_table[12].z),vec2<f32>(const_table[12].w),const_table[13].x,-const_table[13].y,const_table[13].z,const_table[13].w,const_table[14].x,fn_0_40_antialias (var_pos_0),const_table[14].y,const_table[14].z);
+}
+fn fn_0_14_premul(var_v_0: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+fn fn_3_7_pixel() -> vec4<f32> {
+    var var_color_0: vec4<f32> = fn_3_10_get_fill ();
+    if(ds_shape == 1){
+            return vec4<f32>(const_table[0].y, const_table[0].z, const_table[0].w, const_table[1].x);
+    }else  if(ds_shape == 2){
+            return fn_0_14_premul (var_color_0);
+    }else  if(ds_shape == 3){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        fn_0_63_rect (&var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[1].y))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[1].z))));
+        fn_0_47_fill_keep (&var_sdf_0, ds_color);
+        if(ds_border_width > const_table[1].w) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 4){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        fn_0_59_box (&var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[2].x))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[2].y))), max(const_table[2].z, ds_radius.x));
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[2].w) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 10){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        var_sdf_0.f_blur = const_table[3].x;
+        fn_0_59_box (&var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[3].y))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[3].z))), max(const_table[3].w, ds_radius.x));
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 5){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        fn_0_61_box_x (&var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[4].x))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[4].y))), ds_radius.x, ds_radius.y);
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[4].z) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 6){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        fn_0_60_box_y (&var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[4].w))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[5].x))), ds_radius.x, ds_radius.y);
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[5].y) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 7){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        fn_0_62_box_all (&var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[5].z))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[5].w))), ds_radius.x, ds_radius.y, ds_radius.z, ds_radius.w);
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[6].x) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 8){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        if(ds_radius.x > const_table[6].y) {
+                    fn_0_58_circle (&var_sdf_0, (ds_rect_size.x * const_table[6].z), (ds_rect_size.y * const_table[6].w), ds_radius.x);
+        }else{
+                    fn_0_58_circle (&var_sdf_0, (ds_rect_size.x * const_table[7].x), (ds_rect_size.y * const_table[7].y), min(((ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (const_table[7].z * ds_border_width))) * const_table[7].w), ((ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (const_table[8].x * ds_border_width))) * const_table[8].y)));
+        }
+
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[8].z) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(ds_shape == 9){
+            var var_sdf_0: struct_0_27 = fn_0_41_viewport ((ds_pos * ds_rect_size));
+        if(ds_radius.x > const_table[8].w) {
+                    fn_0_64_hexagon (&var_sdf_0, (ds_rect_size.x * const_table[9].x), (ds_rect_size.y * const_table[9].y), ds_radius.x);
+        }else{
+                    fn_0_64_hexagon (&var_sdf_0, (ds_rect_size.x * const_table[9].z), (ds_rect_size.y * const_table[9].w), min(((ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (const_table[10].x * ds_border_width))) * const_table[10].y), ((ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (const_table[10].z * ds_border_width))) * const_table[10].w)));
+        }
+
+        fn_0_47_fill_keep (&var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[11].x) {
+                    fn_0_50_stroke (&var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }
+    return vec4<f32>(const_table[11].y, const_table[11].z, const_table[11].w, const_table[12].x);
+}
+fn fn_3_4_clip_and_transform_vertex() -> vec4<f32> {
+    var var_clipped_0: vec2<f32> = clamp(((ds_geom_pos * ds_rect_size) + ds_rect_pos), ds_draw_clip.xy, ds_draw_clip.zw);
+    ds_pos = ((var_clipped_0 - ds_rect_pos) / ds_rect_size);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0].x))));
+}
+fn fn_3_6_vertex() -> vec4<f32> {
+    return fn_3_4_clip_and_transform_vertex ();
+}
+@vertex
+fn vertex_main(input: VertexInput) -> Varyings {
+    ds_geom_pos = input.ds_geom_pos;
+    ds_rect_pos = input.ds_rect_pos;
+    ds_rect_size = input.ds_rect_size;
+    ds_draw_clip = input.ds_draw_clip;
+    ds_draw_depth = input.ds_draw_depth;
+    ds_shape = input.ds_shape;
+    ds_fill = input.ds_fill;
+    ds_color = input.ds_color;
+    ds_color2 = input.ds_color2;
+    ds_border_width = input.ds_border_width;
+    ds_border_color = input.ds_border_color;
+    ds_inset = input.ds_inset;
+    ds_radius = input.ds_radius;
+    ds_image_scale = input.ds_image_scale;
+    ds_image_pan = input.ds_image_pan;
+    var varyings: Varyings;
+    varyings.position = fn_3_6_vertex();
+    varyings.ds_rect_size = ds_rect_size;
+    varyings.ds_shape = ds_shape;
+    varyings.ds_fill = ds_fill;
+    varyings.ds_color = ds_color;
+    varyings.ds_color2 = ds_color2;
+    varyings.ds_border_width = ds_border_width;
+    varyings.ds_border_color = ds_border_color;
+    varyings.ds_inset = ds_inset;
+    varyings.ds_radius = ds_radius;
+    varyings.ds_image_scale = ds_image_scale;
+    varyings.ds_image_pan = ds_image_pan;
+    varyings.ds_pos = ds_pos;
+    return varyings;
+}
+@fragment
+fn fragment_main(varyings: Varyings) -> @location(0) vec4<f32> {
+    ds_rect_size = varyings.ds_rect_size;
+    ds_shape = varyings.ds_shape;
+    ds_fill = varyings.ds_fill;
+    ds_color = varyings.ds_color;
+    ds_color2 = varyings.ds_color2;
+    ds_border_width = varyings.ds_border_width;
+    ds_border_color = varyings.ds_border_color;
+    ds_inset = varyings.ds_inset;
+    ds_radius = varyings.ds_radius;
+    ds_image_scale = varyings.ds_image_scale;
+    ds_image_pan = varyings.ds_image_pan;
+    ds_pos = varyings.ds_pos;
+    return fn_3_7_pixel();
+}
@@ -0,0 +1,167 @@
+fn sample2d(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_sampler, pos, 0.0);}
+fn sample2d_rt(tex: texture_2d<f32>, pos: vec2<f32>) -> vec4<f32> {return textureSampleLevel(tex, default_sampler, pos, 0.0);}
+@group(0) @binding(0) var<uniform> const_table: array<vec4<f32>, 6>;
+struct Uniforms_draw {
+    ds_draw_zbias: f32,
+}
+@group(0) @binding(1) var<uniform> uniforms_draw: Uniforms_draw;
+struct Uniforms_pass {
+    ds_camera_projection: mat4x4<f32>,
+    ds_camera_view: mat4x4<f32>,
+    ds_camera_inv: mat4x4<f32>,
+    ds_dpi_factor: f32,
+    ds_dpi_dilate: f32,
+}
+@group(0) @binding(2) var<uniform> uniforms_pass: Uniforms_pass;
+struct Uniforms_user {
+    ds_brightness: f32,
+    ds_curve: f32,
+}
+@group(0) @binding(3) var<uniform> uniforms_user: Uniforms_user;
+struct Uniforms_view {
+    ds_view_transform: mat4x4<f32>,
+}
+@group(0) @binding(4) var<uniform> uniforms_view: Uniforms_view;
+@group(1) @binding(0) var ds_tex: texture_2d<f32>;
+@group(1) @binding(1) var ds_color_tex: texture_2d<f32>;
+@group(1) @binding(2) var default_sampler: sampler;
+var<private> ds_geom_pos: vec2<f32>;
+var<private> ds_color: vec4<f32>;
+var<private> ds_font_t1: vec2<f32>;
+var<private> ds_font_t2: vec2<f32>;
+var<private> ds_rect_pos: vec2<f32>;
+var<private> ds_rect_size: vec2<f32>;
+var<private> ds_draw_clip: vec4<f32>;
+var<private> ds_char_depth: f32;
+var<private> ds_delta: vec2<f32>;
+var<private> ds_font_size: f32;
+var<private> ds_advance: f32;
+var<private> ds_char_offset: f32;
+var<private> ds_char_count: f32;
+var<private> ds_is_color: f32;
+var<private> ds_is_rtl: f32;
+var<private> ds_is_underline: f32;
+var<private> ds_tex_coord1: vec2<f32>;
+var<private> ds_tex_coord2: vec2<f32>;
+var<private> ds_tex_coord3: vec2<f32>;
+var<private> ds_clipped: vec2<f32>;
+struct VertexInput {
+    @location(0) ds_geom_pos: vec2<f32>,
+    @location(1) ds_color: vec4<f32>,
+    @location(2) ds_font_t1: vec2<f32>,
+    @location(3) ds_font_t2: vec2<f32>,
+    @location(4) ds_rect_pos: vec2<f32>,
+    @location(5) ds_rect_size: vec2<f32>,
+    @location(6) ds_draw_clip: vec4<f32>,
+    @location(7) ds_char_depth: f32,
+    @location(8) ds_delta: vec2<f32>,
+    @location(9) ds_font_size: f32,
+    @location(10) ds_advance: f32,
+    @location(11) ds_char_offset: f32,
+    @location(12) ds_char_count: f32,
+    @location(13) ds_is_color: f32,
+    @location(14) ds_is_rtl: f32,
+    @location(15) ds_is_underline: f32,
+}
+struct Varyings {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ds_color: vec4<f32>,
+    @location(1) ds_is_color: f32,
+    @location(2) ds_is_underline: f32,
+    @location(3) ds_tex_coord1: vec2<f32>,
+    @location(4) ds_tex_coord2: vec2<f32>,
+    @location(5) ds_tex_coord3: vec2<f32>,
+    @location(6) ds_clipped: vec2<f32>,
+}
+fn fn_4_18_get_color() -> vec4<f32> {
+    return ds_color;
+}
+fn fn_4_19_pixel() -> vec4<f32> {
+    if(ds_is_underline > const_table[0].w) {
+            var var_col_0: vec4<f32> = fn_4_18_get_color ();
+        return vec4<f32>(((var_col_0.rgb * uniforms_user.ds_brightness) * var_col_0.a), var_col_0.a);
+    }
+
+    if(ds_is_color > const_table[1].x) {
+            var var_c_0: vec4<f32> = sample2d(ds_color_tex, ds_tex_coord1.xy);
+        return (vec4<f32>((var_c_0.rgb * var_c_0.a), var_c_0.a) * fn_4_18_get_color ().a);
+    }
+
+    var var_dx_0: f32 = dpdx(vec2<f32>((ds_tex_coord1.x * const_table[1].y), const_table[1].z)).x;
+    var var_dp_0: f32 = (const_table[1].w / const_table[2].x);
+    var var_s_0: f32 = const_table[2].y;
+    if(var_dx_0 > const_table[2].z) {
+            var_s_0 = const_table[2].w;
+    }else{
+            if(var_dx_0 > const_table[3].x) {
+                    var_s_0 = ((((sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2<f32>(const_table[3].y, const_table[3].z))).z + sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2<f32>(var_dp_0, const_table[3].w))).z) + sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2<f32>(const_table[4].x, var_dp_0))).z) + sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2<f32>(var_dp_0, var_dp_0))).z) * const_table[4].y);
+        }else{
+                    if(var_dx_0 > const_table[4].z) {
+                            var_s_0 = sample2d_rt(ds_tex, ds_tex_coord3.xy).z;
+            }else{
+                            if(var_dx_0 > const_table[4].w) {
+                                    var_s_0 = sample2d_rt(ds_tex, ds_tex_coord2.xy).y;
+                }else{
+                                    var_s_0 = sample2d_rt(ds_tex, ds_tex_coord1.xy).x;
+                }
+
+            }
+
+        }
+
+    }
+
+    var_s_0 = pow(var_s_0, uniforms_user.ds_curve);
+    var var_col_0: vec4<f32> = fn_4_18_get_color ();
+    return vec4<f32>((((var_s_0 * var_col_0.rgb) * uniforms_user.ds_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+fn fn_4_17_vertex() -> vec4<f32> {
+    var var_min_pos_0: vec2<f32> = vec2<f32>(ds_rect_pos.x, ds_rect_pos.y);
+    var var_max_pos_0: vec2<f32> = vec2<f32>((ds_rect_pos.x + ds_rect_size.x), (ds_rect_pos.y - ds_rect_size.y));
+    ds_clipped = clamp(mix(var_min_pos_0, var_max_pos_0, ds_geom_pos), ds_draw_clip.xy, ds_draw_clip.zw);
+    var var_normalized_0: vec2<f32> = ((ds_clipped - var_min_pos_0) / vec2<f32>(ds_rect_size.x, -ds_rect_size.y));
+    ds_tex_coord1 = mix(ds_font_t1.xy, ds_font_t2.xy, var_normalized_0.xy);
+    ds_tex_coord2 = mix(ds_font_t1.xy, (ds_font_t1.xy + ((ds_font_t2.xy - ds_font_t1.xy) * const_table[0].x)), var_normalized_0.xy);
+    ds_tex_coord3 = mix(ds_font_t1.xy, (ds_font_t1.xy + ((ds_font_t2.xy - ds_font_t1.xy) * const_table[0].y)), var_normalized_0.xy);
+    return (uniforms_pass.ds_camera_projection * (uniforms_pass.ds_camera_view * (uniforms_view.ds_view_transform * vec4<f32>(ds_clipped.x, ds_clipped.y, (ds_char_depth + uniforms_draw.ds_draw_zbias), const_table[0].z))));
+}
+@vertex
+fn vertex_main(input: VertexInput) -> Varyings {
+    ds_geom_pos = input.ds_geom_pos;
+    ds_color = input.ds_color;
+    ds_font_t1 = input.ds_font_t1;
+    ds_font_t2 = input.ds_font_t2;
+    ds_rect_pos = input.ds_rect_pos;
+    ds_rect_size = input.ds_rect_size;
+    ds_draw_clip = input.ds_draw_clip;
+    ds_char_depth = input.ds_char_depth;
+    ds_delta = input.ds_delta;
+    ds_font_size = input.ds_font_size;
+    ds_advance = input.ds_advance;
+    ds_char_offset = input.ds_char_offset;
+    ds_char_count = input.ds_char_count;
+    ds_is_color = input.ds_is_color;
+    ds_is_rtl = input.ds_is_rtl;
+    ds_is_underline = input.ds_is_underline;
+    var varyings: Varyings;
+    varyings.position = fn_4_17_vertex();
+    varyings.ds_color = ds_color;
+    varyings.ds_is_color = ds_is_color;
+    varyings.ds_is_underline = ds_is_underline;
+    varyings.ds_tex_coord1 = ds_tex_coord1;
+    varyings.ds_tex_coord2 = ds_tex_coord2;
+    varyings.ds_tex_coord3 = ds_tex_coord3;
+    varyings.ds_clipped = ds_clipped;
+    return varyings;
+}
+@fragment
+fn fragment_main(varyings: Varyings) -> @location(0) vec4<f32> {
+    ds_color = varyings.ds_color;
+    ds_is_color = varyings.ds_is_color;
+    ds_is_underline = varyings.ds_is_underline;
+    ds_tex_coord1 = varyings.ds_tex_coord1;
+    ds_tex_coord2 = varyings.ds_tex_coord2;
+    ds_tex_coord3 = varyings.ds_tex_coord3;
+    ds_clipped = varyings.ds_clipped;
+    return fn_4_19_pixel();
+}
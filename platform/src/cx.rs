@@ -23,6 +23,7 @@ use {
             CxOs,
         },
         debug::Debug,
+        persist::CxPersist,
         event::{
             DrawEvent,
             CxFingers,
@@ -108,8 +109,8 @@ pub struct Cx {
     pub live_registry: Rc<RefCell<LiveRegistry >>,
     pub shader_registry: ShaderRegistry,
     
-    #[allow(dead_code)]
     pub (crate) command_settings: HashMap<MenuCommand, CxCommandSetting>,
+    pub (crate) menu_commands: Vec<MenuCommand>,
     
     pub os: CxOs,
     // (cratethis cuts the compiletime of an end-user application in half
@@ -117,6 +118,8 @@ pub struct Cx {
 
     pub (crate) globals: Vec<(TypeId, Box<dyn Any>)>,
 
+    pub (crate) persist: CxPersist,
+
     pub debug:Debug,
 
 }
@@ -209,6 +212,7 @@ impl Cx {
             shader_registry: ShaderRegistry::new(),
             
             command_settings: HashMap::new(),
+            menu_commands: Vec::new(),
             
             os: CxOs {..Default::default()},
             
@@ -217,6 +221,8 @@ impl Cx {
             debug: Default::default(),
 
             globals: Vec::new(),
+            
+            persist: CxPersist::default(),
         }
     }
 }
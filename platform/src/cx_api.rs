@@ -4,6 +4,7 @@ use {
     },
     crate::{
         makepad_math::DVec2,
+        makepad_error_log::*,
         gpu_info::GpuInfo,
         cx::{Cx, OsType},
         event::{
@@ -14,6 +15,7 @@ use {
             WebSocketAutoReconnect,
             WebSocket,
             NextFrame,
+            KeyEvent,
         },
         draw_list::{
             DrawListId
@@ -30,6 +32,8 @@ use {
         },
         menu::{
             Menu,
+            MenuCommand,
+            KeyChord,
         },
         pass::{
             PassId,
@@ -98,6 +102,44 @@ impl Cx {
         self.platform_ops.push(CxOsOp::UpdateMenu(menu));
     }
     
+    // shortcuts run their command as an Event::MenuCommand on every platform.
+    // the native macos menu picks changes up on the next update_menu.
+    // a chord already taken by another command is refused, so a key always runs one command
+    pub fn set_command_shortcut(&mut self, command: MenuCommand, shortcut: Option<KeyChord>) {
+        if let Some(chord) = shortcut {
+            if let Some((other, _)) = self.command_settings.iter().find( | (other, s) | **other != command && s.shortcut == Some(chord)) {
+                error!("Shortcut {} is already used by command {:?}", chord.label(), other);
+                return
+            }
+        }
+        self.command_settings.entry(command).or_default().shortcut = shortcut;
+    }
+    
+    pub fn set_command_enabled(&mut self, command: MenuCommand, enabled: bool) {
+        self.command_settings.entry(command).or_default().enabled = enabled;
+    }
+    
+    pub fn command_shortcut(&self, command: MenuCommand) -> Option<KeyChord> {
+        self.command_settings.get(&command).and_then( | s | s.shortcut)
+    }
+    
+    pub fn command_enabled(&self, command: MenuCommand) -> bool {
+        self.command_settings.get(&command).map( | s | s.enabled).unwrap_or(true)
+    }
+    
+    // runs a command from an in-window menu, which arrives as an Event::MenuCommand after the current event
+    pub fn send_menu_command(&mut self, command: MenuCommand) {
+        if self.command_enabled(command) {
+            self.menu_commands.push(command);
+        }
+    }
+    
+    pub fn command_for_key(&self, ke: &KeyEvent) -> Option<MenuCommand> {
+        self.command_settings.iter().find( | (_, s) | {
+            s.enabled && s.shortcut.map( | chord | chord.matches(ke)).unwrap_or(false)
+        }).map( | (command, _) | *command)
+    }
+    
     pub fn push_unique_platform_op(&mut self, op: CxOsOp) {
         if self.platform_ops.iter().find( | o | **o == op).is_none() {
             self.platform_ops.push(op);
@@ -279,6 +321,10 @@ impl Cx {
         self.keyboard.has_key_focus(focus_area)
     }
     
+    pub fn key_focus(&self) -> Area {
+        self.keyboard.key_focus()
+    }
+    
     pub fn new_next_frame(&mut self) -> NextFrame {
         let res = NextFrame(self.next_frame_id);
         self.next_frame_id += 1;
@@ -32,6 +32,10 @@ impl CxKeyboard {
         self.key_focus == focus_area
     }
     
+    pub fn key_focus(&self) -> Area {
+        self.key_focus
+    }
+    
     pub (crate) fn update_area(&mut self, old_area: Area, new_area: Area) {
         if self.key_focus == old_area {
             self.key_focus = new_area
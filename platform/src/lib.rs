@@ -28,6 +28,7 @@ mod geometry;
 mod draw_list;
 mod debug;
 mod component_map;
+mod persist;
 
 #[macro_use]
 mod main_app;
@@ -120,6 +121,7 @@ pub use {
         },
         menu::{
             MenuCommand,
+            KeyChord,
         },
         event::{
             Margin,
@@ -143,6 +145,7 @@ pub use {
             HoverState,
             FingerHoverEvent,
             FingerScrollEvent,
+            WindowGeom,
             WindowGeomChangeEvent,
             WindowMovedEvent,
             NextFrameEvent,
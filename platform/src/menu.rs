@@ -1,18 +1,23 @@
 use {
     crate::{
         makepad_live_id::LiveId,
-        event::KeyCode
+        event::{KeyCode, KeyEvent}
     },
 };
 
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy)]
 pub struct CxCommandSetting {
-    pub shift: bool,
-    pub key_code: KeyCode,
+    pub shortcut: Option<KeyChord>,
     pub enabled: bool
 }
 
+impl Default for CxCommandSetting {
+    fn default() -> Self {
+        Self {shortcut: None, enabled: true}
+    }
+}
+
 // Command
 
 #[derive(Clone, Debug, Default, Eq, Hash, Copy, PartialEq)]
@@ -21,31 +26,89 @@ impl From<LiveId> for MenuCommand {
     fn from(live_id: LiveId) -> MenuCommand {MenuCommand(live_id)}
 }
 
-impl MenuCommand{
-    //pub fn from_id(id:LiveId)->Self{Self(id.0)}
-    /*
-    pub fn set_enabled(&self, cx:&mut Cx, enabled:bool)->Self{
-        let mut s = if let Some(s) = cx.command_settings.get(self){*s}else{CxCommandSetting::default()};
-        s.enabled = enabled;
-        cx.command_settings.insert(*self, s);
-        *self
-    }
+// Shortcut
 
-    pub fn set_key(&self, cx:&mut Cx, key_code:KeyCode)->Self{
-        let mut s = if let Some(s) = cx.command_settings.get(self){*s}else{CxCommandSetting::default()};
-        s.shift = false;
-        s.key_code = key_code;
-        cx.command_settings.insert(*self, s);
-        *self
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KeyChord {
+    pub key_code: KeyCode,
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    pub logo: bool,
+}
+
+impl KeyChord {
+    pub fn new(key_code: KeyCode) -> Self {
+        Self {key_code, ..Self::default()}
+    }
+    
+    // the key applications use for commands: cmd on apple, control everywhere else
+    pub fn command(key_code: KeyCode) -> Self {
+        if cfg!(any(target_os = "macos", target_os = "ios")) {
+            Self {key_code, logo: true, ..Self::default()}
+        }
+        else {
+            Self {key_code, control: true, ..Self::default()}
+        }
+    }
+    
+    pub fn with_shift(self) -> Self {Self {shift: true, ..self}}
+    pub fn with_alt(self) -> Self {Self {alt: true, ..self}}
+    
+    pub fn matches(&self, ke: &KeyEvent) -> bool {
+        ke.key_code == self.key_code
+            && ke.modifiers.shift == self.shift
+            && ke.modifiers.control == self.control
+            && ke.modifiers.alt == self.alt
+            && ke.modifiers.logo == self.logo
     }
     
-    pub fn set_key_shift(&self, cx:&mut Cx, key_code:KeyCode)->Self{
-        let mut s = if let Some(s) = cx.command_settings.get(self){*s}else{CxCommandSetting::default()};
-        s.shift = true;
-        s.key_code = key_code;
-        cx.command_settings.insert(*self, s);
-        *self
-    }*/
+    // how the chord is shown next to a menu item, like Ctrl+Shift+S
+    pub fn label(&self) -> String {
+        let mut label = String::new();
+        if self.control {label.push_str("Ctrl+")}
+        if self.alt {label.push_str("Alt+")}
+        if self.shift {label.push_str("Shift+")}
+        if self.logo {
+            label.push_str(if cfg!(any(target_os = "macos", target_os = "ios")) {"Cmd+"} else {"Super+"})
+        }
+        label.push_str(&key_code_label(self.key_code));
+        label
+    }
+}
+
+fn key_code_label(key_code: KeyCode) -> String {
+    match key_code {
+        KeyCode::Backtick => "`".into(),
+        KeyCode::Minus => "-".into(),
+        KeyCode::Equals => "=".into(),
+        KeyCode::LBracket => "[".into(),
+        KeyCode::RBracket => "]".into(),
+        KeyCode::Semicolon => ";".into(),
+        KeyCode::Quote => "'".into(),
+        KeyCode::Backslash => "\\".into(),
+        KeyCode::Comma => ",".into(),
+        KeyCode::Period => ".".into(),
+        KeyCode::Slash => "/".into(),
+        KeyCode::ReturnKey => "Enter".into(),
+        KeyCode::Escape => "Esc".into(),
+        KeyCode::Delete => "Del".into(),
+        KeyCode::ArrowUp => "Up".into(),
+        KeyCode::ArrowDown => "Down".into(),
+        KeyCode::ArrowLeft => "Left".into(),
+        KeyCode::ArrowRight => "Right".into(),
+        KeyCode::NumpadAdd => "Num+".into(),
+        KeyCode::NumpadSubtract => "Num-".into(),
+        KeyCode::NumpadEnter => "NumEnter".into(),
+        key_code => {
+            let name = format!("{:?}", key_code);
+            // Key0..Key9 and KeyA..KeyZ print as the character on the key
+            match name.strip_prefix("Key") {
+                Some(rest) if !rest.is_empty() => rest.to_string(),
+                _ => name
+            }
+        }
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -78,4 +141,11 @@ impl Menu {
             command: command
         }
     }
+    
+    pub fn items(&self) -> &[Menu] {
+        match self {
+            Menu::Main {items} | Menu::Sub {items, ..} => items,
+            _ => &[]
+        }
+    }
 }
\ No newline at end of file
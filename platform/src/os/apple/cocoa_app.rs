@@ -228,11 +228,16 @@ impl CocoaApp {
                     else {
                         CxCommandSetting::default()
                     };
+                    // cocoa only knows cmd based key equivalents, other chords are matched in cx_shared
+                    let key_equivalent = match settings.shortcut {
+                        Some(chord) if chord.logo && !chord.control && !chord.alt => keycode_to_menu_key(chord.key_code, chord.shift),
+                        _ => ""
+                    };
                     let sub_item: ObjcId = msg_send![
                         parent_menu,
                         addItemWithTitle: str_to_nsstring(name)
                         action: sel!(menuAction:)
-                        keyEquivalent: str_to_nsstring(keycode_to_menu_key(settings.key_code, settings.shift))
+                        keyEquivalent: str_to_nsstring(key_equivalent)
                     ];
                     let target: ObjcId = msg_send![menu_target_class, new];
                     let () = msg_send![sub_item, setTarget: target];
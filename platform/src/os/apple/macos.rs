@@ -77,6 +77,8 @@ impl Cx {
         cx.borrow_mut().call_event_handler(&Event::Construct);
         cx.borrow_mut().redraw_all();
         get_cocoa_app_global().event_loop();
+        
+        cx.borrow_mut().call_destruct_event();
     }
     
     pub (crate) fn handle_repaint(&mut self, metal_windows: &mut Vec<MetalWindow>, metal_cx: &mut MetalCx) {
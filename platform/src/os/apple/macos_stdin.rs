@@ -185,6 +185,8 @@ impl Cx {
             self.stdin_handle_platform_ops(metal_cx, &fb_texture);
             xpc_service_proxy_poll_run_loop();
         }
+        // the host closed our stdin
+        self.call_destruct_event();
     }
     
     
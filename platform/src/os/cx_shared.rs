@@ -158,13 +158,40 @@ impl Cx {
                 break
             }
         }
+        
+        let mut counter = 0;
+        while self.menu_commands.len() != 0 {
+            counter += 1;
+            let mut commands = Vec::new();
+            std::mem::swap(&mut self.menu_commands, &mut commands);
+            for command in commands {
+                self.inner_call_event_handler(&Event::MenuCommand(command));
+                self.inner_key_focus_change();
+            }
+            if counter > 100 {
+                error!("Menu command feedback loop detected");
+                break
+            }
+        }
     }
     
     pub (crate) fn call_event_handler(&mut self, event: &Event) {
+        // a key that is bound to a command runs the command instead, like a native menu would
+        if let Event::KeyDown(ke) = event {
+            if let Some(command) = self.command_for_key(ke) {
+                return self.call_event_handler(&Event::MenuCommand(command))
+            }
+        }
         self.inner_call_event_handler(event);
         self.inner_key_focus_change();
         self.handle_triggers_and_signals();
     }
+    
+    // the last event of an app, after which the session state is written for the next run
+    pub (crate) fn call_destruct_event(&mut self) {
+        self.call_event_handler(&Event::Destruct);
+        self.persist_save();
+    }
 
     // helpers
     
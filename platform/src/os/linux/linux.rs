@@ -259,7 +259,9 @@ impl Cx {
             } else {
                 true
             }
-        })
+        });
+        
+        self.call_destruct_event();
     }
     
     
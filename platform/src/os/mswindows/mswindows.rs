@@ -274,7 +274,9 @@ impl Cx {
             } else {
                 true
             }
-        })
+        });
+        
+        self.call_destruct_event();
     }
     
     pub fn show_text_ime(&mut self, x: f32, y: f32) { 
@@ -114,6 +114,11 @@ pub struct FromWasmWebSocketOpen {
     pub url: String
 }
 
+#[derive(FromWasm)]
+pub struct FromWasmPersistSave {
+    pub data: WasmDataU8
+}
+
 #[derive(FromWasm)]
 pub struct FromWasmWebSocketSend{
     pub web_socket_id: usize,
@@ -104,7 +104,9 @@ impl Into<WindowGeom> for WindowInfo {
 #[derive(ToWasm)]
 pub struct ToWasmInit {
     pub deps: Vec<WDepLoaded>,
-    pub window_info: WindowInfo
+    pub window_info: WindowInfo,
+    // the session state that was saved in the browser's storage, empty when there is none
+    pub persist: WasmDataU8
 }
 
 #[derive(ToWasm)]
@@ -235,6 +237,15 @@ pub struct WMouse {
 pub struct ToWasmMouseDown {
     pub mouse: WMouse,
 }
+// browsers number the buttons left, middle, right where the desktop platforms go left, right, middle
+pub fn mouse_button_to_digit(button: u32) -> usize {
+    match button {
+        1 => 2,
+        2 => 1,
+        button => button as usize
+    }
+}
+
 impl ToWasmMouseDown {
     pub fn into_finger_down_event(self, fingers: &CxFingers, digit_id: DigitId) -> FingerDownEvent {
         FingerDownEvent {
@@ -246,7 +257,7 @@ impl ToWasmMouseDown {
                 id: digit_id,
                 index: fingers.get_digit_index(digit_id),
                 count: fingers.get_digit_count(),
-                device: DigitDevice::Mouse(self.mouse.button as usize),
+                device: DigitDevice::Mouse(mouse_button_to_digit(self.mouse.button)),
             },
             modifiers: unpack_key_modifier(self.mouse.modifiers),
             time: self.mouse.time,
@@ -315,7 +326,7 @@ impl ToWasmMouseUp {
                 id: digit_id,
                 index: fingers.get_digit_index(digit_id),
                 count: fingers.get_digit_count(),
-                device: DigitDevice::Mouse(self.mouse.button as usize),
+                device: DigitDevice::Mouse(mouse_button_to_digit(self.mouse.button)),
             },
             capture_time: fingers.get_capture_time(digit_id),
             modifiers: unpack_key_modifier(self.mouse.modifiers),
@@ -650,6 +661,9 @@ pub struct ToWasmAppGotFocus {}
 #[derive(ToWasm)]
 pub struct ToWasmAppLostFocus {}
 
+#[derive(ToWasm)]
+pub struct ToWasmUnload {}
+
 #[derive(ToWasm)]
 pub struct ToWasmSignal {
     pub signals_hi: Vec<u32>,
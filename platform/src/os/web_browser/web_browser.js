@@ -8,6 +8,11 @@ export class WasmWebBrowser extends WasmBridge {
         }
 
         window.onbeforeunload = _ => {
+            // lets the app save its session state before it goes
+            if (this.to_wasm !== undefined) {
+                this.to_wasm.ToWasmUnload();
+                this.do_wasm_pump();
+            }
             this.clear_memory_refs();
             for (let worker of this.workers) {
                 worker.terminate();
@@ -64,7 +69,8 @@ export class WasmWebBrowser extends WasmBridge {
                 this.update_window_info();
                 this.to_wasm.ToWasmInit({
                     window_info: this.window_info,
-                    deps: deps
+                    deps: deps,
+                    persist: this.load_persist()
                 });
                 this.do_wasm_pump();
                 // only bind the event handlers now
@@ -236,6 +242,43 @@ export class WasmWebBrowser extends WasmBridge {
         document.body.style.cursor = web_cursor_map[args.web_cursor] || 'default'
     }
     
+    persist_key() {
+        return "makepad_state:" + location.pathname;
+    }
+    
+    load_persist() {
+        let stored = null;
+        try {
+            stored = window.localStorage.getItem(this.persist_key());
+        }
+        catch (e) {
+        }
+        if (stored === null) {
+            return new ArrayBuffer(0);
+        }
+        let bin = atob(stored);
+        let u8 = new Uint8Array(bin.length);
+        for (let i = 0; i < bin.length; i ++) {
+            u8[i] = bin.charCodeAt(i);
+        }
+        return u8.buffer;
+    }
+    
+    FromWasmPersistSave(args) {
+        let u8 = this.clone_data_u8(args.data);
+        this.free_data_u8(args.data);
+        let bin = "";
+        for (let i = 0; i < u8.length; i ++) {
+            bin += String.fromCharCode(u8[i]);
+        }
+        try {
+            window.localStorage.setItem(this.persist_key(), btoa(bin));
+        }
+        catch (e) {
+            console.error("Cannot store the session state", e);
+        }
+    }
+    
     FromWasmTextCopyResponse(args) {
         this.text_copy_response = args.response
     }
@@ -87,6 +87,10 @@ impl Cx {
                         }
                     }
                     self.os.window_geom = tw.window_info.into();
+                    let persist = tw.persist.into_vec_u8();
+                    if persist.len() != 0 {
+                        self.persist.load_cbor(&persist);
+                    }
                     
                     //self.default_inner_window_size = self.os.window_geom.inner_size;
                     
@@ -191,7 +195,7 @@ impl Cx {
                             tw.into_finger_hover_event(
                                 &self.fingers,
                                 digit_id,
-                                self.os.last_mouse_button.unwrap_or(0) as usize
+                                mouse_button_to_digit(self.os.last_mouse_button.unwrap_or(0))
                             )
                         ));
                     }
@@ -200,7 +204,7 @@ impl Cx {
                             tw.into_finger_move_event(
                                 &self.fingers,
                                 digit_id,
-                                self.os.last_mouse_button.unwrap_or(0) as usize
+                                mouse_button_to_digit(self.os.last_mouse_button.unwrap_or(0))
                             )
                         ));
                     }
@@ -274,6 +278,10 @@ impl Cx {
                     self.call_event_handler(&Event::AppLostFocus);
                 }
                 
+                live_id!(ToWasmUnload) => {
+                    self.call_destruct_event();
+                }
+                
                 live_id!(ToWasmXRUpdate) => {
                     let tw = ToWasmXRUpdate::read_to_wasm(&mut to_wasm);
                     let event = Event::XRUpdate(
@@ -380,6 +388,13 @@ impl Cx {
     where F: FnMut(&mut Cx, Event) {
     }
     
+    // the browser keeps the session state in its local storage
+    pub (crate) fn persist_store(&mut self, data: Vec<u8>) {
+        self.os.from_wasm(FromWasmPersistSave {
+            data: WasmDataU8::from_vec_u8(data)
+        });
+    }
+    
     fn handle_platform_ops(&mut self) {
         while let Some(op) = self.platform_ops.pop() {
             match op {
@@ -477,6 +492,7 @@ impl CxOsApi for Cx {
             ToWasmXRUpdate::to_string(),
             ToWasmAppGotFocus::to_string(),
             ToWasmAppLostFocus::to_string(),
+            ToWasmUnload::to_string(),
             ToWasmSignal::to_string(),
             ToWasmWebSocketOpen::to_string(),
             ToWasmWebSocketClose::to_string(),
@@ -498,6 +514,7 @@ impl CxOsApi for Cx {
             FromWasmHideTextIME::to_string(),
             FromWasmCreateThread::to_string(),
             FromWasmWebSocketOpen::to_string(),
+            FromWasmPersistSave::to_string(),
             FromWasmWebSocketSend::to_string(),
             FromWasmXrStartPresenting::to_string(),
             FromWasmXrStopPresenting::to_string(),
@@ -0,0 +1,140 @@
+use {
+    std::{
+        fs,
+        path::PathBuf,
+    },
+    crate::{
+        makepad_live_compiler::{
+            LiveNode,
+            LiveNodeSliceApi,
+            LiveNodeVecApi,
+            LiveNodeSliceToCbor,
+            LiveNodeVecFromCbor,
+        },
+        makepad_live_id::*,
+        makepad_error_log::*,
+        live_traits::{LiveRead, LiveApply},
+        cx::Cx,
+    }
+};
+
+// Session state store. Widgets snapshot themselves with LiveRead into a per-app
+// file when the app exits, and get the snapshot back via apply_over on startup.
+
+#[derive(Default)]
+pub struct CxPersist {
+    loaded: bool,
+    snapshots: LiveIdMap<LiveId, Vec<LiveNode >>,
+}
+
+impl CxPersist {
+    // <config dir>/makepad/<executable name>.state
+    pub fn file_path() -> Option<PathBuf> {
+        let exe = std::env::current_exe().ok() ?;
+        let name = exe.file_stem() ?.to_string_lossy().to_string();
+        let dir = if cfg!(target_os = "windows") {
+            PathBuf::from(std::env::var("APPDATA").ok() ?)
+        }
+        else if cfg!(target_os = "macos") {
+            PathBuf::from(std::env::var("HOME").ok() ?).join("Library/Application Support")
+        }
+        else if let Ok(dir) = std::env::var("XDG_CONFIG_HOME") {
+            PathBuf::from(dir)
+        }
+        else {
+            PathBuf::from(std::env::var("HOME").ok() ?).join(".config")
+        };
+        Some(dir.join("makepad").join(format!("{}.state", name)))
+    }
+
+    fn load(&mut self) {
+        if self.loaded {
+            return
+        }
+        self.loaded = true;
+        if let Some(data) = Self::file_path().and_then( | path | fs::read(path).ok()) {
+            self.load_cbor(&data);
+        }
+    }
+
+    // takes the snapshots from a saved session, on the web these come from the browser's storage
+    pub (crate) fn load_cbor(&mut self, data: &[u8]) {
+        self.loaded = true;
+        let mut nodes: Vec<LiveNode> = Vec::new();
+        if let Err(e) = nodes.from_cbor(data) {
+            error!("Cannot parse persisted state: {:?}", e);
+            return
+        }
+        if nodes.is_empty() || !nodes[0].value.is_open() {
+            return
+        }
+        // every child of the root object is the snapshot of one widget
+        let mut index = 1;
+        while index < nodes.len() && !nodes[index].value.is_close() {
+            let end = nodes.skip_node(index);
+            let mut snapshot = nodes[index..end].to_vec();
+            snapshot[0].id = LiveId(0);
+            self.snapshots.insert(nodes[index].id, snapshot);
+            index = end;
+        }
+    }
+
+    fn to_cbor(&self) -> Option<Vec<u8>> {
+        if self.snapshots.is_empty() {
+            return None
+        }
+        let mut nodes: Vec<LiveNode> = Vec::new();
+        nodes.open();
+        for (id, snapshot) in self.snapshots.iter() {
+            let mut root = snapshot[0].clone();
+            root.id = *id;
+            nodes.push(root);
+            nodes.extend_from_slice(&snapshot[1..]);
+        }
+        nodes.close();
+        match nodes.to_cbor(0) {
+            Ok(data) => Some(data),
+            Err(e) => {
+                error!("Cannot serialise persisted state: {}", e);
+                None
+            }
+        }
+    }
+}
+
+impl Cx {
+    // stores a snapshot of value under id, written to disk by persist_save
+    pub fn persist_write<T: LiveRead>(&mut self, id: LiveId, value: &T) {
+        self.persist.load();
+        self.persist.snapshots.insert(id, value.live_read());
+    }
+
+    // applies the snapshot stored under id, returns false if there was none
+    pub fn persist_apply<T: LiveApply>(&mut self, id: LiveId, value: &mut T) -> bool {
+        self.persist.load();
+        if let Some(snapshot) = self.persist.snapshots.get(&id).cloned() {
+            value.apply_over(self, &snapshot);
+            return true
+        }
+        false
+    }
+
+    // called by every os backend when the app exits, after Event::Destruct
+    pub fn persist_save(&mut self) {
+        self.persist.load();
+        if let Some(data) = self.persist.to_cbor() {
+            self.persist_store(data);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub (crate) fn persist_store(&mut self, data: Vec<u8>) {
+        let path = if let Some(path) = CxPersist::file_path() {path} else {return};
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Err(e) = fs::write(&path, data) {
+            error!("Cannot write persisted state {:?}: {}", path, e);
+        }
+    }
+}
@@ -141,6 +141,7 @@ impl AppInner {
         
         match event {
             Event::Construct => {
+                self.restore_session(cx, state);
                 self.collab_client.send_request(CollabRequest::LoadFileTree {with_data: false});
                 /*self.create_code_editor_tab(
                     cx,
@@ -169,6 +170,11 @@ impl AppInner {
                 );*/
                 self.build_manager.init(cx, state);
             }
+            Event::Destruct => {
+                cx.persist_write(live_id!(window), &self.window);
+                cx.persist_write(live_id!(dock), &self.dock);
+                cx.persist_write(live_id!(file_tree), &self.file_tree);
+            }
             Event::Draw(event) => {
                 return self.draw(&mut Cx2d::new(cx, event), state);
             }
@@ -326,6 +332,14 @@ impl AppInner {
     }
     
     
+    fn restore_session(&mut self, cx: &mut Cx, state: &mut AppState) {
+        cx.persist_apply(live_id!(window), &mut self.window);
+        cx.persist_apply(live_id!(file_tree), &mut self.file_tree);
+        if cx.persist_apply(live_id!(dock), &mut self.dock) {
+            state.restore_panels(self.dock.items());
+        }
+    }
+    
     fn load_file_tree(&mut self, cx: &mut Cx, state: &mut AppState, file_tree_data: FileTreeData) {
         self.file_tree.forget();
         state.load_file_tree(file_tree_data);
@@ -1,9 +1,10 @@
 use {
+    std::collections::HashMap,
     crate::{
         makepad_widgets::{
             file_tree::{FileNodeId},
             splitter::{SplitterAlign},
-            dock::{PanelId},
+            dock::{PanelId, DockItem},
             tab_bar::{TabId},
         },
         makepad_draw_2d::*,
@@ -165,6 +166,34 @@ impl AppState {
         None
     }
     
+    // adopts the panel tree a restored dock came back with, tabs that no longer exist are dropped.
+    // a tree that lost one of the built in panels is ignored and the default layout stays
+    pub fn restore_panels(&mut self, items: &HashMap<PanelId, DockItem>) {
+        let mut panels = LiveIdMap::new();
+        let mut panel_id_stack = vec![PanelId::from(live_id!(root))];
+        while let Some(panel_id) = panel_id_stack.pop() {
+            let panel = match items.get(&panel_id) {
+                Some(DockItem::Split {axis, align, children}) => {
+                    panel_id_stack.extend_from_slice(children);
+                    Panel::Split(SplitPanel {axis: *axis, align: *align, child_panel_ids: *children})
+                }
+                Some(DockItem::Tab {tabs, selected_tab}) => {
+                    let tab_ids: Vec<TabId> = tabs.iter().filter( | tab_id | self.tabs.contains_key(tab_id)).cloned().collect();
+                    let selected_tab = selected_tab.and_then( | selected | tab_ids.iter().position( | tab_id | *tab_id == selected));
+                    Panel::Tab(TabPanel {tab_ids, selected_tab})
+                }
+                None => return
+            };
+            if panels.contains_key(&panel_id) {
+                return
+            }
+            panels.insert(panel_id, panel);
+        }
+        if self.panels.keys().all( | panel_id | panels.contains_key(panel_id)) {
+            self.panels = panels;
+        }
+    }
+    
     pub fn file_node_path(&self, file_node_id: FileNodeId) -> UnixPathBuf {
         let mut components = Vec::new();
         let mut file_node = &self.file_nodes[file_node_id];
@@ -180,6 +209,12 @@ impl AppState {
     }
     
     pub fn load_file_tree(&mut self, tree_data: FileTreeData) {
+        // ids are derived from the path so the open folders in a persisted session stay valid
+        fn child_file_node_id(parent_id: FileNodeId, name: &UnixString) -> FileNodeId {
+            let name = name.to_string_lossy();
+            LiveId::from_bytes(parent_id.0.0, name.as_bytes(), 0, name.len()).into()
+        }
+        
         fn create_file_node(
             file_node_id: Option<FileNodeId>,
             file_nodes: &mut LiveIdMap<FileNodeId, FileNode>,
@@ -201,7 +236,7 @@ impl AppState {
                             .map( | entry | FileEdge {
                             name: entry.name.clone(),
                             file_node_id: create_file_node(
-                                None, 
+                                Some(child_file_node_id(file_node_id, &entry.name)),
                                 file_nodes,
                                 Some(FileEdge {
                                     name: entry.name,
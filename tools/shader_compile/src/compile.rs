@@ -0,0 +1,353 @@
+use {
+    std::{
+        fmt::Write,
+        fs,
+        path::Path,
+    },
+    crate::{
+        source_scan::ScannedCrate,
+        live_types::{LiveTypes, instance_ty_from_name},
+        makepad_shader_compiler::{
+            makepad_live_compiler::*,
+            makepad_live_id::*,
+            shader_ast::{Ty, DrawShaderDef, DrawShaderFieldKind, DrawShaderPtr, DrawShaderConstTable},
+            generate_glsl,
+            generate_hlsl,
+            generate_metal,
+            generate_wgsl,
+            ShaderRegistry,
+            DrawShaderQuery,
+        },
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backend {
+    Glsl,
+    Hlsl,
+    Metal,
+    Wgsl,
+}
+
+impl Backend {
+    pub fn all() -> [Backend; 4] {
+        [Backend::Glsl, Backend::Hlsl, Backend::Metal, Backend::Wgsl]
+    }
+
+    pub fn from_name(name: &str) -> Option<Backend> {
+        match name {
+            "glsl" => Some(Backend::Glsl),
+            "hlsl" => Some(Backend::Hlsl),
+            "metal" => Some(Backend::Metal),
+            "wgsl" => Some(Backend::Wgsl),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DrawShaderRef {
+    pub module_path: String,
+    pub name: String,
+    pub ptr: DrawShaderPtr,
+}
+
+impl DrawShaderRef {
+    // directory name for the output of this shader, module path with the :: as .
+    pub fn dir_name(&self) -> String {
+        format!("{}.{}", self.module_path.replace("::", "."), self.name)
+    }
+}
+
+pub struct CompiledShader {
+    pub shader: DrawShaderRef,
+    // (file name, source) per backend and one for the metadata
+    pub files: Vec<(String, String)>,
+}
+
+impl CompiledShader {
+    pub fn write_to(&self, out_dir: &Path) -> Result<(), String> {
+        let dir = out_dir.join(self.shader.dir_name());
+        fs::create_dir_all(&dir).map_err( | e | format!("Cannot create {}: {}", dir.display(), e)) ?;
+        for (file_name, source) in &self.files {
+            let path = dir.join(file_name);
+            fs::write(&path, source).map_err( | e | format!("Cannot write {}: {}", path.display(), e)) ?;
+        }
+        Ok(())
+    }
+}
+
+pub struct ShaderCompiler {
+    pub live_registry: LiveRegistry,
+    pub shader_registry: ShaderRegistry,
+    pub live_types: LiveTypes,
+    // what failed to expand. the shaders in the files named here are not compiled,
+    // the ones in other files still are
+    pub expand_errors: Vec<LiveFileError>,
+    // module path and file name per registered live file, in file id order
+    module_paths: Vec<String>,
+    file_names: Vec<String>,
+}
+
+impl ShaderCompiler {
+    // registers the live_design! of every scanned file, and expands them all.
+    // expand errors are kept in expand_errors instead of failing the load
+    pub fn load(crates: &[ScannedCrate]) -> Result<Self, String> {
+        let mut live_types = LiveTypes::new(crates) ?;
+        let mut shader_registry = ShaderRegistry::new();
+        live_types.register_shader_enums(&mut shader_registry) ?;
+
+        let mut live_registry = LiveRegistry::default();
+        live_types.register_components(crates, &live_registry) ?;
+        let mut module_paths = Vec::new();
+        let mut file_names = Vec::new();
+        for scanned_crate in crates {
+            for file in &scanned_crate.files {
+                let live_design = if let Some(live_design) = &file.live_design {live_design} else {continue};
+                let mut live_type_infos = Vec::new();
+                for name in &live_design.live_types {
+                    live_type_infos.push(live_types.live_type_info(&file.module_path, name) ?);
+                }
+                live_registry.register_live_file(
+                    &file.file_name,
+                    &scanned_crate.manifest_dir,
+                    LiveModuleId::from_str(&file.module_path) ?,
+                    live_design.source.clone(),
+                    live_type_infos,
+                    live_design.start_pos
+                ).map_err( | e | e.to_string()) ?;
+                module_paths.push(file.module_path.clone());
+                file_names.push(file.file_name.clone());
+            }
+        }
+
+        let mut errors = Vec::new();
+        live_registry.expand_all_documents(&mut errors);
+        let expand_errors = errors.into_iter().map( | error | live_registry.live_error_to_live_file_error(error)).collect();
+
+        Ok(Self {
+            live_registry,
+            shader_registry,
+            live_types,
+            expand_errors,
+            module_paths,
+            file_names,
+        })
+    }
+
+    // the top level definitions whose rust type has draw_vars, directly or through draw_super
+    pub fn draw_shaders(&self) -> Vec<DrawShaderRef> {
+        let mut shaders = Vec::new();
+        for (file_index, file) in self.live_registry.live_files.iter().enumerate() {
+            let nodes = &file.expanded.nodes;
+            if nodes.is_empty() {
+                continue
+            }
+            let module_path = &self.module_paths[file_index];
+            let mut child = nodes.first_child(0);
+            while let Some(index) = child {
+                if let LiveValue::Class {live_type, ..} = &nodes[index].value {
+                    if self.is_draw_type(*live_type) {
+                        shaders.push(DrawShaderRef {
+                            module_path: module_path.clone(),
+                            name: nodes[index].id.to_string(),
+                            ptr: DrawShaderPtr(self.live_registry.file_id_index_to_live_ptr(LiveFileId::new(file_index), index)),
+                        });
+                    }
+                }
+                child = nodes.next_child(index);
+            }
+        }
+        shaders
+    }
+
+    fn is_draw_type(&self, live_type: LiveType) -> bool {
+        let mut info = if let Some(info) = self.live_registry.live_type_infos.get(&live_type) {info} else {return false};
+        loop {
+            if info.fields.iter().any( | f | f.id == live_id!(draw_vars)) {
+                return true
+            }
+            if let Some(field) = info.fields.iter().find( | f | f.id == live_id!(draw_super)) {
+                info = &field.live_type_info;
+            }
+            else {
+                return false
+            }
+        }
+    }
+
+    pub fn find_draw_shader(&self, name: &str) -> Option<DrawShaderRef> {
+        self.draw_shaders().into_iter().find( | shader | shader.name == name)
+    }
+
+    pub fn compile(&mut self, shader: &DrawShaderRef, backends: &[Backend]) -> Result<CompiledShader, String> {
+        let file_name = &self.file_names[shader.ptr.0.file_id.to_index()];
+        let file_errors: Vec<_> = self.expand_errors.iter().filter( | error | error.file == *file_name).collect();
+        if !file_errors.is_empty() {
+            let mut out = format!("{} did not expand\n", file_name);
+            for error in file_errors {
+                writeln!(out, "{}", error).unwrap();
+            }
+            return Err(out)
+        }
+        let live_types = &self.live_types;
+        let mut instance_error = None;
+        let result = self.shader_registry.analyse_draw_shader(&self.live_registry, shader.ptr, | live_registry, shader_registry, span, draw_shader_query, live_type, draw_shader_def | {
+            match draw_shader_query {
+                DrawShaderQuery::DrawShader => {
+                    if let Err(err) = add_instance_fields(live_registry, shader_registry, 0, &mut false, live_type, draw_shader_def, span) {
+                        instance_error = Some(err);
+                    }
+                }
+                DrawShaderQuery::Geometry => {
+                    if let Some(fields) = live_types.geometry_fields(live_type) {
+                        for (id, ty) in fields {
+                            draw_shader_def.add_geometry(*id, ty.clone(), span);
+                        }
+                    }
+                }
+            }
+        });
+        if let Some(err) = instance_error {
+            return Err(format!("{}: {}", shader.name, err))
+        }
+        if let Err(e) = result {
+            return Err(self.live_registry.live_error_to_live_file_error(e).to_string())
+        }
+
+        let const_table = self.shader_registry.compute_const_table(shader.ptr);
+        let draw_shader_def = self.shader_registry.draw_shader_defs.get(&shader.ptr).unwrap();
+
+        let mut files = Vec::new();
+        for backend in backends {
+            match backend {
+                Backend::Glsl => {
+                    files.push(("vertex.glsl".to_string(), generate_glsl::generate_vertex_shader(draw_shader_def, &const_table, &self.shader_registry)));
+                    files.push(("pixel.glsl".to_string(), generate_glsl::generate_pixel_shader(draw_shader_def, &const_table, &self.shader_registry)));
+                }
+                Backend::Hlsl => {
+                    files.push(("shader.hlsl".to_string(), generate_hlsl::generate_shader(draw_shader_def, &const_table, &self.shader_registry)));
+                }
+                Backend::Metal => {
+                    files.push(("shader.metal".to_string(), generate_metal::generate_shader(draw_shader_def, &const_table, &self.shader_registry).mtlsl));
+                }
+                Backend::Wgsl => {
+                    files.push(("shader.wgsl".to_string(), generate_wgsl::generate_shader(draw_shader_def, &const_table, &self.shader_registry).wgsl));
+                }
+            }
+        }
+        files.push(("metadata.txt".to_string(), self.metadata(shader, draw_shader_def, &const_table)));
+
+        Ok(CompiledShader {
+            shader: shader.clone(),
+            files
+        })
+    }
+
+    fn metadata(&self, shader: &DrawShaderRef, def: &DrawShaderDef, const_table: &DrawShaderConstTable) -> String {
+        let mut out = String::new();
+        writeln!(out, "shader {}", shader.name).unwrap();
+        writeln!(out, "module {}", shader.module_path).unwrap();
+
+        let field_name = | ident: LiveId | if ident == LiveId(0) {"<padding>".to_string()} else {ident.to_string()};
+
+        for (title, filter) in [
+            ("geometries", 0),
+            ("instances", 1),
+            ("uniforms", 2),
+            ("textures", 3),
+            ("varyings", 4)
+        ] {
+            writeln!(out, "{}", title).unwrap();
+            let mut slot = 0;
+            for field in &def.fields {
+                let ty = field.ty_expr.ty.borrow().as_ref().unwrap().clone();
+                let name = field_name(field.ident.0);
+                match (&field.kind, filter) {
+                    (DrawShaderFieldKind::Geometry {..}, 0) |
+                    (DrawShaderFieldKind::Instance {..}, 1) => {
+                        writeln!(out, "    {}: {} slot {} size {}", name, ty, slot, ty.slots()).unwrap();
+                        slot += ty.slots();
+                    }
+                    (DrawShaderFieldKind::Uniform {block_ident, ..}, 2) => {
+                        writeln!(out, "    {}.{}: {}", block_ident.0, name, ty).unwrap();
+                    }
+                    (DrawShaderFieldKind::Texture {..}, 3) => {
+                        writeln!(out, "    {}: {} slot {}", name, ty, slot).unwrap();
+                        slot += 1;
+                    }
+                    (DrawShaderFieldKind::Varying {..}, 4) => {
+                        writeln!(out, "    {}: {}", name, ty).unwrap();
+                    }
+                    _ => ()
+                }
+            }
+            if filter < 2 {
+                writeln!(out, "    total slots {}", slot).unwrap();
+            }
+        }
+
+        writeln!(out, "live uniforms").unwrap();
+        for (value_ptr, ty) in def.all_live_refs.borrow().iter() {
+            let node = self.live_registry.ptr_to_node(value_ptr.0);
+            writeln!(out, "    {}: {}", node.id, ty).unwrap();
+        }
+
+        writeln!(out, "const table {}", const_table.table.len()).unwrap();
+        for fn_ptr in def.all_fns.borrow().iter() {
+            if let Some(offset) = const_table.offsets.get(fn_ptr) {
+                let fn_def = self.shader_registry.all_fns.get(fn_ptr).unwrap();
+                writeln!(out, "    {} at {}", fn_def.ident.0, offset).unwrap();
+            }
+        }
+        for chunk in const_table.table.chunks(4) {
+            let values: Vec<String> = chunk.iter().map( | v | format!("{:?}", v)).collect();
+            writeln!(out, "    {}", values.join(" ")).unwrap();
+        }
+        out
+    }
+}
+
+// mirrors the instance layout draw_vars builds at runtime: the fields after draw_vars,
+// recursing into draw_super, with a float of padding after an odd sized parent
+fn add_instance_fields(
+    live_registry: &LiveRegistry,
+    shader_registry: &ShaderRegistry,
+    level: usize,
+    after_draw_vars: &mut bool,
+    live_type: LiveType,
+    draw_shader_def: &mut DrawShaderDef,
+    span: TokenSpan
+) -> Result<(), String> {
+    let info = if let Some(info) = live_registry.live_type_infos.get(&live_type) {info} else {return Ok(())};
+    let mut slots = 0;
+    for field in &info.fields {
+        if field.id == live_id!(draw_super) {
+            add_instance_fields(live_registry, shader_registry, level + 1, after_draw_vars, field.live_type_info.live_type, draw_shader_def, span) ?;
+            continue
+        }
+        if field.id == live_id!(draw_vars) {
+            *after_draw_vars = true;
+            continue
+        }
+        if !*after_draw_vars {
+            continue
+        }
+        let field_type = field.live_type_info.live_type;
+        if shader_registry.enums.contains_key(&field_type) {
+            slots += 1;
+            draw_shader_def.add_instance(field.id, Ty::Enum(field_type), span, field.live_field_kind);
+        }
+        else if let Some(ty) = instance_ty_from_name(&field.live_type_info.type_name.to_string()) {
+            slots += ty.slots();
+            draw_shader_def.add_instance(field.id, ty, span, field.live_field_kind);
+        }
+        else {
+            return Err(format!("instance field {} has type {} which is not understood by shaders", field.id, field.live_type_info.type_name))
+        }
+    }
+    if level > 0 && slots % 2 == 1 {
+        draw_shader_def.add_instance(LiveId(0), Ty::Float, span, LiveFieldKind::Calc);
+    }
+    Ok(())
+}
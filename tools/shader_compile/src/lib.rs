@@ -0,0 +1,15 @@
+pub mod source_scan;
+pub mod live_types;
+pub mod compile;
+
+pub use makepad_shader_compiler;
+
+pub use crate::{
+    source_scan::ScannedCrate,
+    compile::{
+        Backend,
+        CompiledShader,
+        DrawShaderRef,
+        ShaderCompiler,
+    },
+};
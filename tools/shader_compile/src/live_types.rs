@@ -0,0 +1,335 @@
+// Rebuilds the LiveTypeInfos the Live derive would generate, from the scanned sources.
+// LiveType is a std::any::TypeId which can only come from a type known at compile time,
+// so every type name we come across is given one from a fixed pool of marker types.
+// Scanned types are keyed by module path and name, as different modules reuse names like
+// DrawBgQuad. A name is looked up in the module using it first, then in all scanned modules.
+
+use {
+    std::{
+        any::TypeId,
+        collections::{BTreeSet, HashMap, HashSet},
+    },
+    crate::{
+        source_scan::{ScannedCrate, ScannedFieldKind, ScannedStruct, ScannedEnum},
+        makepad_shader_compiler::{
+            makepad_live_compiler::*,
+            shader_ast::Ty,
+            ShaderEnum,
+            ShaderRegistry,
+        },
+    },
+};
+
+struct Marker<const A: usize, const B: usize>;
+
+macro_rules! marker_row {
+    ($a:literal) => {
+        [
+            TypeId::of::<Marker<$a, 0>>(), TypeId::of::<Marker<$a, 1>>(), TypeId::of::<Marker<$a, 2>>(), TypeId::of::<Marker<$a, 3>>(),
+            TypeId::of::<Marker<$a, 4>>(), TypeId::of::<Marker<$a, 5>>(), TypeId::of::<Marker<$a, 6>>(), TypeId::of::<Marker<$a, 7>>(),
+            TypeId::of::<Marker<$a, 8>>(), TypeId::of::<Marker<$a, 9>>(), TypeId::of::<Marker<$a, 10>>(), TypeId::of::<Marker<$a, 11>>(),
+            TypeId::of::<Marker<$a, 12>>(), TypeId::of::<Marker<$a, 13>>(), TypeId::of::<Marker<$a, 14>>(), TypeId::of::<Marker<$a, 15>>(),
+        ]
+    }
+}
+
+macro_rules! marker_table {
+    ($($a:literal)*) => {
+        [$(marker_row!($a)),*]
+    }
+}
+
+// module id for types that live outside the scanned crates, like f32 or Vec4
+const EXTERN_MODULE: &str = "makepad_shader_compile::extern_types";
+
+#[derive(Clone)]
+struct TypeDef {
+    module_path: String,
+    kind: TypeDefKind,
+}
+
+#[derive(Clone)]
+enum TypeDefKind {
+    Struct {live_ignore: bool, fields: Vec<(String, String, ScannedFieldKind)>},
+    Enum {live_ignore: bool},
+}
+
+// stands in for a LiveComponentRegistry derive like WidgetRegistry, without the factories
+struct ScannedRegistry {
+    type_id: LiveType,
+    component_type: LiveId,
+    components: Vec<LiveComponentInfo>,
+}
+
+impl LiveComponentRegistry for ScannedRegistry {
+    fn type_id(&self) -> LiveType {self.type_id}
+    fn component_type(&self) -> LiveId {self.component_type}
+    fn get_module_set(&self, set: &mut BTreeSet<LiveModuleId>) {
+        self.components.iter().for_each( | info | {set.insert(info.module_id);});
+    }
+    fn get_component_info(&self, name: LiveId) -> Option<LiveComponentInfo> {
+        self.components.iter().find( | info | info.name == name).cloned()
+    }
+}
+
+pub struct LiveTypes {
+    pool: Vec<LiveType>,
+    by_key: HashMap<String, LiveType>,
+    keys: HashMap<LiveType, String>,
+    defs: HashMap<String, TypeDef>,
+    // the keys of the scanned types per name, in scan order
+    keys_by_name: HashMap<String, Vec<String>>,
+    geometries: HashMap<String, Vec<(LiveId, Ty)>>,
+    infos: HashMap<String, LiveTypeInfo>,
+    shader_enums: Vec<(String, String, Vec<String>)>,
+}
+
+impl LiveTypes {
+    pub fn new(crates: &[ScannedCrate]) -> Result<Self, String> {
+        let pool = marker_table!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15).iter().flatten().cloned().collect();
+        let mut live_types = Self {
+            pool,
+            by_key: HashMap::new(),
+            keys: HashMap::new(),
+            defs: HashMap::new(),
+            keys_by_name: HashMap::new(),
+            geometries: HashMap::new(),
+            infos: HashMap::new(),
+            shader_enums: Vec::new(),
+        };
+        for scanned_crate in crates {
+            for file in &scanned_crate.files {
+                for ScannedStruct {name, live_ignore, fields} in &file.structs {
+                    live_types.insert_def(&file.module_path, name, TypeDef {
+                        module_path: file.module_path.clone(),
+                        kind: TypeDefKind::Struct {
+                            live_ignore: *live_ignore,
+                            fields: fields.iter().map( | f | (f.name.clone(), f.ty.clone(), f.kind)).collect()
+                        }
+                    });
+                }
+                for ScannedEnum {name, live_ignore, is_u32, variants} in &file.enums {
+                    live_types.insert_def(&file.module_path, name, TypeDef {
+                        module_path: file.module_path.clone(),
+                        kind: TypeDefKind::Enum {live_ignore: *live_ignore}
+                    });
+                    if *is_u32 {
+                        live_types.shader_enums.push((type_key(&file.module_path, name), name.clone(), variants.clone()));
+                    }
+                }
+                for geometry in &file.geometries {
+                    let mut fields = Vec::new();
+                    for (id, ty) in &geometry.fields {
+                        let ty = shader_ty_from_name(ty).ok_or_else( | | format!("Unsupported geometry field type {} in {}", ty, geometry.name)) ?;
+                        fields.push((LiveId::from_str(id).unwrap(), ty));
+                    }
+                    live_types.geometries.insert(type_key(&file.module_path, &geometry.name), fields);
+                }
+            }
+        }
+        Ok(live_types)
+    }
+
+    fn insert_def(&mut self, module_path: &str, name: &str, def: TypeDef) {
+        let key = type_key(module_path, name);
+        self.keys_by_name.entry(name.to_string()).or_default().push(key.clone());
+        self.defs.insert(key, def);
+    }
+
+    // the key of a type name as seen from a module, or the bare name for types that weren't scanned
+    fn resolve(&self, module_path: &str, name: &str) -> String {
+        let key = type_key(module_path, name);
+        if self.defs.contains_key(&key) {
+            return key
+        }
+        match self.keys_by_name.get(name) {
+            Some(keys) => keys[0].clone(),
+            None => name.to_string()
+        }
+    }
+
+    pub fn live_type(&mut self, key: &str) -> Result<LiveType, String> {
+        if let Some(live_type) = self.by_key.get(key) {
+            return Ok(*live_type)
+        }
+        let live_type = *self.pool.get(self.by_key.len()).ok_or_else( | | format!("Too many live types, ran out at {}", key)) ?;
+        self.by_key.insert(key.to_string(), live_type);
+        self.keys.insert(live_type, key.to_string());
+        Ok(live_type)
+    }
+
+    pub fn geometry_fields(&self, live_type: LiveType) -> Option<&Vec<(LiveId, Ty)>> {
+        self.keys.get(&live_type).and_then( | key | self.geometries.get(key))
+    }
+
+    pub fn register_shader_enums(&mut self, shader_registry: &mut ShaderRegistry) -> Result<(), String> {
+        for (key, name, variants) in self.shader_enums.clone() {
+            let live_type = self.live_type(&key) ?;
+            shader_registry.register_enum(live_type, ShaderEnum {
+                enum_name: LiveId::from_str(&name).unwrap(),
+                variants: variants.iter().map( | v | LiveId::from_str(v).unwrap()).collect()
+            });
+        }
+        Ok(())
+    }
+
+    // the components of every registry the scanned crates fill with live_design_fn factories,
+    // so registry Widget::* in a live_design! finds them
+    pub fn register_components(&mut self, crates: &[ScannedCrate], live_registry: &LiveRegistry) -> Result<(), String> {
+        let mut registries: Vec<ScannedRegistry> = Vec::new();
+        for scanned_crate in crates {
+            for file in &scanned_crate.files {
+                for component in &file.components {
+                    let component_type = LiveId::from_str(&component.registry).map_err( | e | format!("Invalid registry name {}: {}", component.registry, e)) ?;
+                    let index = match registries.iter().position( | r | r.component_type == component_type) {
+                        Some(index) => index,
+                        None => {
+                            registries.push(ScannedRegistry {
+                                type_id: self.live_type(&format!("{}Registry", component.registry)) ?,
+                                component_type,
+                                components: Vec::new(),
+                            });
+                            registries.len() - 1
+                        }
+                    };
+                    registries[index].components.push(LiveComponentInfo {
+                        name: LiveId::from_str(&component.name).map_err( | e | format!("Invalid component name {}: {}", component.name, e)) ?,
+                        module_id: LiveModuleId::from_str(&file.module_path) ?,
+                    });
+                }
+            }
+        }
+        let mut components = live_registry.components.0.borrow_mut();
+        for registry in registries {
+            components.insert(registry.type_id, Box::new(registry));
+        }
+        Ok(())
+    }
+
+    // the info of a type name used in the given module
+    pub fn live_type_info(&mut self, module_path: &str, name: &str) -> Result<LiveTypeInfo, String> {
+        let key = self.resolve(module_path, name);
+        self.live_type_info_inner(&key, name, &mut HashSet::new())
+    }
+
+    fn live_type_info_inner(&mut self, key: &str, name: &str, visiting: &mut HashSet<String>) -> Result<LiveTypeInfo, String> {
+        if let Some(info) = self.infos.get(key) {
+            return Ok(info.clone())
+        }
+        let live_type = self.live_type(key) ?;
+        let type_name = LiveId::from_str(name).map_err( | e | format!("Invalid type name {}: {}", name, e)) ?;
+        let def = self.defs.get(key).cloned();
+        let info = match def {
+            Some(TypeDef {module_path, kind: TypeDefKind::Struct {live_ignore, fields}}) => {
+                // a type that contains itself is cut off at the second level
+                if !visiting.insert(key.to_string()) {
+                    return Ok(LiveTypeInfo {
+                        live_type,
+                        type_name,
+                        module_id: LiveModuleId::from_str(&module_path) ?,
+                        live_ignore: true,
+                        fields: Vec::new()
+                    })
+                }
+                let mut live_fields = Vec::new();
+                for (field_name, ty, kind) in fields {
+                    let (inner_ty, is_option) = inner_live_type_name(&ty);
+                    let live_field_kind = match kind {
+                        ScannedFieldKind::Rust => continue,
+                        ScannedFieldKind::Live if is_option => LiveFieldKind::LiveOption,
+                        ScannedFieldKind::Live => LiveFieldKind::Live,
+                        ScannedFieldKind::Calc => LiveFieldKind::Calc,
+                    };
+                    live_fields.push(LiveTypeField {
+                        id: LiveId::from_str(&field_name).map_err( | e | format!("Invalid field name {}: {}", field_name, e)) ?,
+                        live_type_info: {
+                            let inner_key = self.resolve(&module_path, &inner_ty);
+                            self.live_type_info_inner(&inner_key, &inner_ty, visiting) ?
+                        },
+                        live_field_kind
+                    });
+                }
+                visiting.remove(key);
+                LiveTypeInfo {
+                    live_type,
+                    type_name,
+                    module_id: LiveModuleId::from_str(&module_path) ?,
+                    live_ignore,
+                    fields: live_fields
+                }
+            }
+            Some(TypeDef {module_path, kind: TypeDefKind::Enum {live_ignore}}) => LiveTypeInfo {
+                live_type,
+                type_name,
+                module_id: LiveModuleId::from_str(&module_path) ?,
+                live_ignore,
+                fields: Vec::new()
+            },
+            None => LiveTypeInfo {
+                live_type,
+                type_name,
+                module_id: LiveModuleId::from_str(EXTERN_MODULE) ?,
+                live_ignore: true,
+                fields: Vec::new()
+            }
+        };
+        self.infos.insert(key.to_string(), info.clone());
+        Ok(info)
+    }
+}
+
+fn type_key(module_path: &str, name: &str) -> String {
+    format!("{}::{}", module_path, name)
+}
+
+// Option<T>, Vec<T>, Box<T> and [T;N] take the live type info of T, like their LiveNew impls.
+// returns the last path segment of the inner type and whether it was an Option
+pub fn inner_live_type_name(ty: &str) -> (String, bool) {
+    let mut ty = ty.trim();
+    let mut is_option = false;
+    loop {
+        let segment = ty.rsplit("::").next().unwrap_or(ty);
+        if let Some(inner) = ["Option<", "Vec<", "Box<"].iter().find_map( | prefix | {
+            segment.strip_prefix(prefix).and_then( | rest | rest.strip_suffix('>'))
+        }) {
+            if segment.starts_with("Option<") {
+                is_option = true;
+            }
+            ty = inner.trim();
+            continue
+        }
+        if let Some(inner) = ty.strip_prefix('[').and_then( | rest | rest.split(';').next()) {
+            ty = inner.trim();
+            continue
+        }
+        break
+    }
+    let without_generics = ty.split('<').next().unwrap_or(ty);
+    let name = without_generics.rsplit("::").next().unwrap_or(without_generics);
+    (name.to_string(), is_option)
+}
+
+// the rust types draw_vars understands as instance fields
+pub fn instance_ty_from_name(name: &str) -> Option<Ty> {
+    match name {
+        "f32" => Some(Ty::Float),
+        "Vec2" => Some(Ty::Vec2),
+        "Vec3" => Some(Ty::Vec3),
+        "Vec4" => Some(Ty::Vec4),
+        _ => None
+    }
+}
+
+// ShaderTy variant names as written in GeometryFields impls
+fn shader_ty_from_name(name: &str) -> Option<Ty> {
+    match name {
+        "Float" => Some(Ty::Float),
+        "Vec2" => Some(Ty::Vec2),
+        "Vec3" => Some(Ty::Vec3),
+        "Vec4" => Some(Ty::Vec4),
+        "Mat2" => Some(Ty::Mat2),
+        "Mat3" => Some(Ty::Mat3),
+        "Mat4" => Some(Ty::Mat4),
+        _ => None
+    }
+}
@@ -0,0 +1,117 @@
+use {
+    std::path::{Path, PathBuf},
+    makepad_shader_compile::{Backend, ScannedCrate, ShaderCompiler},
+};
+
+const USAGE: &str = "\
+Compiles the draw shaders in the live_design! blocks of one or more crates, without running them.
+
+Usage: makepad-shader-compile [options] <crate_dir>...
+
+Pass every crate the shaders depend on, for instance the draw_2d crate when compiling widgets.
+
+Options:
+    --out <dir>         output directory, default ./shader_out
+    --backend <name>    glsl, hlsl, metal or wgsl, can be repeated. default is all of them
+    --shader <name>     only compile this draw shader, can be repeated
+    --list              print the draw shaders found and exit
+";
+
+fn main() {
+    let mut out_dir = PathBuf::from("./shader_out");
+    let mut backends = Vec::new();
+    let mut only_shaders = Vec::new();
+    let mut crate_dirs = Vec::new();
+    let mut list = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out_dir = PathBuf::from(expect_value(&arg, args.next())),
+            "--backend" => {
+                let name = expect_value(&arg, args.next());
+                match Backend::from_name(&name) {
+                    Some(backend) => backends.push(backend),
+                    None => exit_with(&format!("Unknown backend {}", name))
+                }
+            }
+            "--shader" => only_shaders.push(expect_value(&arg, args.next())),
+            "--list" => list = true,
+            "--help" | "-h" => {
+                print!("{}", USAGE);
+                return
+            }
+            _ if arg.starts_with("--") => exit_with(&format!("Unknown option {}", arg)),
+            _ => crate_dirs.push(PathBuf::from(arg))
+        }
+    }
+    if crate_dirs.is_empty() {
+        exit_with("No crate directory given");
+    }
+    if backends.is_empty() {
+        backends.extend(Backend::all());
+    }
+
+    let mut crates = Vec::new();
+    for crate_dir in &crate_dirs {
+        match ScannedCrate::scan(Path::new(crate_dir)) {
+            Ok(scanned) => crates.push(scanned),
+            Err(err) => exit_with(&err)
+        }
+    }
+    let mut compiler = match ShaderCompiler::load(&crates) {
+        Ok(compiler) => compiler,
+        Err(err) => exit_with(&err)
+    };
+
+    let shaders: Vec<_> = compiler.draw_shaders().into_iter().filter( | shader | {
+        only_shaders.is_empty() || only_shaders.contains(&shader.name)
+    }).collect();
+    for name in &only_shaders {
+        if !shaders.iter().any( | shader | shader.name == *name) {
+            exit_with(&format!("Draw shader {} not found", name));
+        }
+    }
+
+    if list {
+        for shader in &shaders {
+            println!("{}::{}", shader.module_path, shader.name);
+        }
+        return
+    }
+
+    // a file that doesn't expand only costs its own shaders
+    let mut failed = !compiler.expand_errors.is_empty();
+    for error in &compiler.expand_errors {
+        eprintln!("Error expanding {}", error);
+    }
+    for shader in &shaders {
+        match compiler.compile(shader, &backends) {
+            Ok(compiled) => {
+                if let Err(err) = compiled.write_to(&out_dir) {
+                    exit_with(&err);
+                }
+                println!("{} -> {}", shader.name, out_dir.join(shader.dir_name()).display());
+            }
+            Err(err) => {
+                eprintln!("Error compiling {}::{}\n{}", shader.module_path, shader.name, err);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn expect_value(option: &str, value: Option<String>) -> String {
+    match value {
+        Some(value) => value,
+        None => exit_with(&format!("{} needs a value", option))
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    std::process::exit(1);
+}
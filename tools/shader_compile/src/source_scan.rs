@@ -0,0 +1,623 @@
+// Finds what the Live derive and the live_design! macro would register at runtime
+// by scanning the rust sources of a crate, so the crate doesn't have to be built.
+// This is a token level scan, not a rust parser: it understands attributes, structs,
+// enums, impl blocks and live_design! bodies, and skips everything else.
+
+use {
+    std::{
+        fs,
+        path::{Path, PathBuf},
+    },
+    makepad_toml_parser::{parse_toml, Toml},
+    makepad_shader_compiler::makepad_live_compiler::TextPos,
+};
+
+pub struct LiveDesignBlock {
+    pub source: String,
+    pub start_pos: TextPos,
+    // the {{Type}} names in the order they appear, like the macro collects them
+    pub live_types: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScannedFieldKind {
+    Live,
+    Calc,
+    Rust,
+}
+
+pub struct ScannedField {
+    pub name: String,
+    pub ty: String,
+    pub kind: ScannedFieldKind,
+}
+
+pub struct ScannedStruct {
+    pub name: String,
+    pub live_ignore: bool,
+    pub fields: Vec<ScannedField>,
+}
+
+pub struct ScannedEnum {
+    pub name: String,
+    pub live_ignore: bool,
+    pub is_u32: bool,
+    pub variants: Vec<String>,
+}
+
+// an impl GeometryFields with the fields it pushes
+pub struct ScannedGeometry {
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+}
+
+// a #[live_design_fn(widget_factory!(Button))] registration, which puts Button in the
+// component registry Widget under the module of the file
+pub struct ScannedComponent {
+    pub registry: String,
+    pub name: String,
+}
+
+pub struct ScannedFile {
+    pub file_name: String,
+    pub module_path: String,
+    pub live_design: Option<LiveDesignBlock>,
+    pub structs: Vec<ScannedStruct>,
+    pub enums: Vec<ScannedEnum>,
+    pub geometries: Vec<ScannedGeometry>,
+    pub components: Vec<ScannedComponent>,
+}
+
+pub struct ScannedCrate {
+    pub crate_name: String,
+    pub manifest_dir: String,
+    pub files: Vec<ScannedFile>,
+}
+
+impl ScannedCrate {
+    pub fn scan(manifest_dir: &Path) -> Result<ScannedCrate, String> {
+        let cargo_toml = manifest_dir.join("Cargo.toml");
+        let toml_source = fs::read_to_string(&cargo_toml).map_err( | e | format!("Cannot read {}: {}", cargo_toml.display(), e)) ?;
+        let toml = parse_toml(&toml_source).map_err( | e | format!("Cannot parse {}: {:?}", cargo_toml.display(), e)) ?;
+        let crate_name = match toml.get("package.name") {
+            Some(Toml::Str(name, _)) => name.replace('-', "_"),
+            _ => return Err(format!("No package.name in {}", cargo_toml.display()))
+        };
+
+        let src_dir = manifest_dir.join("src");
+        let mut rs_files = Vec::new();
+        collect_rs_files(&src_dir, &mut rs_files) ?;
+        rs_files.sort();
+
+        // file names are relative to the parent of the crate, like file!() in a workspace
+        let base_dir = manifest_dir.parent().unwrap_or(manifest_dir);
+        let mut files = Vec::new();
+        for path in rs_files {
+            let source = fs::read_to_string(&path).map_err( | e | format!("Cannot read {}: {}", path.display(), e)) ?;
+            let file_name = path.strip_prefix(base_dir).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+            let module_path = module_path_for(&crate_name, path.strip_prefix(&src_dir).unwrap());
+            files.push(scan_file(file_name, module_path, &source));
+        }
+
+        Ok(ScannedCrate {
+            crate_name,
+            manifest_dir: manifest_dir.to_string_lossy().to_string(),
+            files
+        })
+    }
+}
+
+fn collect_rs_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let iter = fs::read_dir(dir).map_err( | e | format!("Cannot read dir {}: {}", dir.display(), e)) ?;
+    for entry in iter {
+        let path = entry.map_err( | e | e.to_string()) ?.path();
+        if path.is_dir() {
+            collect_rs_files(&path, out) ?;
+        }
+        else if path.extension().map( | ext | ext == "rs").unwrap_or(false) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+// src/lib.rs -> crate, src/a/mod.rs -> crate::a, src/a/b.rs -> crate::a::b
+fn module_path_for(crate_name: &str, rel_path: &Path) -> String {
+    let mut parts = vec![crate_name.to_string()];
+    let components: Vec<String> = rel_path.iter().map( | c | c.to_string_lossy().to_string()).collect();
+    for (index, component) in components.iter().enumerate() {
+        if index == components.len() - 1 {
+            let stem = component.trim_end_matches(".rs");
+            if !(index == 0 && (stem == "lib" || stem == "main")) && stem != "mod" {
+                parts.push(stem.to_string());
+            }
+        }
+        else {
+            parts.push(component.clone());
+        }
+    }
+    parts.join("::")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TokKind {
+    Ident,
+    Punct,
+    Literal,
+}
+
+#[derive(Clone, Debug)]
+struct Tok {
+    kind: TokKind,
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+struct Attr {
+    name: String,
+    args: Vec<String>,
+}
+
+struct FileScanner<'a> {
+    source: &'a str,
+    toks: Vec<Tok>,
+    // for every open bracket token the index of its closing token
+    matching: Vec<Option<usize >>,
+}
+
+fn scan_file(file_name: String, module_path: String, source: &str) -> ScannedFile {
+    let scanner = FileScanner::new(source);
+    let mut file = ScannedFile {
+        file_name,
+        module_path,
+        live_design: None,
+        structs: Vec::new(),
+        enums: Vec::new(),
+        geometries: Vec::new(),
+        components: Vec::new(),
+    };
+    scanner.scan_items(0, scanner.toks.len(), &mut file);
+    file
+}
+
+impl<'a> FileScanner<'a> {
+    fn new(source: &'a str) -> Self {
+        let toks = tokenize(source);
+        let mut matching = vec![None; toks.len()];
+        let mut stack = Vec::new();
+        for (index, tok) in toks.iter().enumerate() {
+            if tok.kind != TokKind::Punct {
+                continue
+            }
+            match tok.text.as_str() {
+                "(" | "[" | "{" => stack.push(index),
+                ")" | "]" | "}" => if let Some(open) = stack.pop() {
+                    matching[open] = Some(index);
+                }
+                _ => ()
+            }
+        }
+        Self {source, toks, matching}
+    }
+
+    fn is(&self, index: usize, text: &str) -> bool {
+        index < self.toks.len() && self.toks[index].text == text
+    }
+
+    fn is_ident(&self, index: usize) -> bool {
+        index < self.toks.len() && self.toks[index].kind == TokKind::Ident
+    }
+
+    fn close_of(&self, index: usize) -> usize {
+        self.matching[index].unwrap_or(self.toks.len())
+    }
+
+    // #[name(args)] starting at index, returns the attribute and the index after it
+    fn parse_attr(&self, index: usize) -> Option<(Attr, usize)> {
+        if !self.is(index, "#") || !self.is(index + 1, "[") {
+            return None
+        }
+        let close = self.close_of(index + 1);
+        let name = self.toks.get(index + 2).map( | t | t.text.clone()).unwrap_or_default();
+        let args = self.toks[(index + 3).min(close)..close].iter()
+            .filter( | t | t.kind == TokKind::Ident)
+            .map( | t | t.text.clone())
+            .collect();
+        Some((Attr {name, args}, close + 1))
+    }
+
+    fn scan_items(&self, start: usize, end: usize, file: &mut ScannedFile) {
+        let mut attrs = Vec::new();
+        let mut index = start;
+        while index < end {
+            if let Some((attr, next)) = self.parse_attr(index) {
+                attrs.push(attr);
+                index = next;
+                continue
+            }
+            let tok = &self.toks[index];
+            match tok.text.as_str() {
+                "live_design" if self.is(index + 1, "!") && (self.is(index + 2, "{") || self.is(index + 2, "(")) => {
+                    let close = self.close_of(index + 2);
+                    file.live_design = Some(self.live_design_block(index + 2, close));
+                    index = close + 1;
+                    attrs.clear();
+                    continue
+                }
+                "struct" if self.is_ident(index + 1) => {
+                    let name = self.toks[index + 1].text.clone();
+                    if let Some(open) = self.find_body(index + 2, end) {
+                        let close = self.close_of(open);
+                        if has_live_derive(&attrs) {
+                            file.components.extend(attrs.iter().filter_map(component_factory));
+                            file.structs.push(ScannedStruct {
+                                name,
+                                live_ignore: attrs.iter().any( | a | a.name == "live_ignore"),
+                                fields: self.parse_fields(open + 1, close),
+                            });
+                        }
+                        index = close + 1;
+                    }
+                    else {
+                        index += 2;
+                    }
+                    attrs.clear();
+                    continue
+                }
+                "enum" if self.is_ident(index + 1) => {
+                    let name = self.toks[index + 1].text.clone();
+                    if let Some(open) = self.find_body(index + 2, end) {
+                        let close = self.close_of(open);
+                        if has_live_derive(&attrs) {
+                            file.enums.push(ScannedEnum {
+                                name,
+                                live_ignore: attrs.iter().any( | a | a.name == "live_ignore"),
+                                is_u32: attrs.iter().any( | a | a.name == "repr" && a.args.iter().any( | arg | arg == "u32")),
+                                variants: self.parse_variants(open + 1, close),
+                            });
+                        }
+                        index = close + 1;
+                    }
+                    else {
+                        index += 2;
+                    }
+                    attrs.clear();
+                    continue
+                }
+                "impl" => {
+                    if let Some(open) = self.find_body(index + 1, end) {
+                        let close = self.close_of(open);
+                        // impl GeometryFields for Name {
+                        let header = &self.toks[index + 1..open];
+                        if header.len() >= 3 && header.iter().any( | t | t.text == "GeometryFields") && header[header.len() - 2].text == "for" {
+                            file.geometries.push(ScannedGeometry {
+                                name: header[header.len() - 1].text.clone(),
+                                fields: self.parse_geometry_fields(open + 1, close),
+                            });
+                        }
+                        index = close + 1;
+                    }
+                    else {
+                        index += 1;
+                    }
+                    attrs.clear();
+                    continue
+                }
+                "mod" if self.is_ident(index + 1) && self.is(index + 2, "{") => {
+                    let close = self.close_of(index + 2);
+                    self.scan_items(index + 3, close, file);
+                    index = close + 1;
+                    attrs.clear();
+                    continue
+                }
+                "{" | "(" | "[" => {
+                    index = self.close_of(index) + 1;
+                    attrs.clear();
+                    continue
+                }
+                "pub" | "#" | "!" => (),
+                _ => attrs.clear()
+            }
+            index += 1;
+        }
+    }
+
+    // the { of an item body, skipping generics and where clauses. None for ; items and tuple structs
+    fn find_body(&self, start: usize, end: usize) -> Option<usize> {
+        let mut index = start;
+        while index < end {
+            match self.toks[index].text.as_str() {
+                "{" => return Some(index),
+                ";" => return None,
+                "(" | "[" => index = self.close_of(index),
+                _ => ()
+            }
+            index += 1;
+        }
+        None
+    }
+
+    fn live_design_block(&self, open: usize, close: usize) -> LiveDesignBlock {
+        let start = self.toks[open].end;
+        let end = if close < self.toks.len() {self.toks[close].start} else {self.source.len()};
+        let before = &self.source[..start];
+        let line = before.matches('\n').count();
+        let column = before.len() - before.rfind('\n').map( | i | i + 1).unwrap_or(0);
+
+        let mut live_types = Vec::new();
+        let mut index = open + 1;
+        while index < close {
+            // {{Type}} or {{path::Type}}
+            if self.is(index, "{") && self.is(index + 1, "{") {
+                let inner_close = self.close_of(index + 1);
+                if self.close_of(index) == inner_close + 1 {
+                    if let Some(name) = self.toks[index + 2..inner_close].iter().rev().find( | t | t.kind == TokKind::Ident) {
+                        live_types.push(name.text.clone());
+                    }
+                    index = inner_close + 2;
+                    continue
+                }
+            }
+            index += 1;
+        }
+        LiveDesignBlock {
+            source: self.source[start..end].to_string(),
+            start_pos: TextPos {line: line as u32, column: column as u32},
+            live_types,
+        }
+    }
+
+    fn parse_fields(&self, start: usize, end: usize) -> Vec<ScannedField> {
+        let mut fields = Vec::new();
+        // like the derive, a field without a live, calc or rust attribute is live
+        let mut kind = ScannedFieldKind::Live;
+        let mut index = start;
+        while index < end {
+            if let Some((attr, next)) = self.parse_attr(index) {
+                match attr.name.as_str() {
+                    "live" => kind = ScannedFieldKind::Live,
+                    "calc" => kind = ScannedFieldKind::Calc,
+                    "rust" => kind = ScannedFieldKind::Rust,
+                    _ => ()
+                }
+                index = next;
+                continue
+            }
+            if self.is(index, "pub") {
+                index += 1;
+                if self.is(index, "(") {
+                    index = self.close_of(index) + 1;
+                }
+                continue
+            }
+            if self.is_ident(index) && self.is(index + 1, ":") && !self.is(index + 2, ":") {
+                let name = self.toks[index].text.clone();
+                let (ty, next) = self.parse_type(index + 2, end);
+                fields.push(ScannedField {name, ty, kind});
+                kind = ScannedFieldKind::Live;
+                index = next + 1;
+                continue
+            }
+            index += 1;
+        }
+        fields
+    }
+
+    // a type up to the next , at depth 0. returns the type text and the index of the ,
+    fn parse_type(&self, start: usize, end: usize) -> (String, usize) {
+        let mut ty = String::new();
+        let mut angle_depth = 0;
+        let mut index = start;
+        while index < end {
+            let tok = &self.toks[index];
+            match tok.text.as_str() {
+                "," if angle_depth == 0 => break,
+                "<" => angle_depth += 1,
+                ">" if !ty.ends_with('-') => angle_depth -= 1,
+                "(" | "[" => {
+                    let close = self.close_of(index);
+                    for tok in &self.toks[index..=close.min(end - 1)] {
+                        ty.push_str(&tok.text);
+                    }
+                    index = close + 1;
+                    continue
+                }
+                _ => ()
+            }
+            ty.push_str(&tok.text);
+            index += 1;
+        }
+        (ty, index)
+    }
+
+    fn parse_variants(&self, start: usize, end: usize) -> Vec<String> {
+        let mut variants = Vec::new();
+        let mut index = start;
+        let mut expect_name = true;
+        while index < end {
+            if let Some((_, next)) = self.parse_attr(index) {
+                index = next;
+                continue
+            }
+            let tok = &self.toks[index];
+            match tok.text.as_str() {
+                "," => expect_name = true,
+                "{" | "(" | "[" => {
+                    index = self.close_of(index) + 1;
+                    continue
+                }
+                _ => if expect_name && tok.kind == TokKind::Ident {
+                    variants.push(tok.text.clone());
+                    expect_name = false;
+                }
+            }
+            index += 1;
+        }
+        variants
+    }
+
+    // GeometryField {id: live_id!(name), ty: ShaderTy::Ty}
+    fn parse_geometry_fields(&self, start: usize, end: usize) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        for index in start..end {
+            if !self.is(index, "GeometryField") || !self.is(index + 1, "{") {
+                continue
+            }
+            let close = self.close_of(index + 1);
+            let body = &self.toks[index + 2..close];
+            let id = body.windows(4).find( | w | w[0].text == "live_id" && w[1].text == "!" && w[2].text == "(")
+                .map( | w | w[3].text.clone());
+            let ty = body.windows(4).find( | w | w[0].text == "ShaderTy" && w[1].text == ":" && w[2].text == ":")
+                .map( | w | w[3].text.clone());
+            if let (Some(id), Some(ty)) = (id, ty) {
+                fields.push((id, ty));
+            }
+        }
+        fields
+    }
+}
+
+fn has_live_derive(attrs: &[Attr]) -> bool {
+    attrs.iter().any( | a | a.name == "derive" && a.args.iter().any( | arg | arg == "Live"))
+}
+
+// live_design_fn(widget_factory!(Button)) registers Button with the Widget registry,
+// which is what the registry derive names WidgetRegistry
+fn component_factory(attr: &Attr) -> Option<ScannedComponent> {
+    if attr.name != "live_design_fn" {
+        return None
+    }
+    let (factory, name) = match attr.args.as_slice() {
+        [factory, name] => (factory, name),
+        _ => return None
+    };
+    let prefix = factory.strip_suffix("_factory")?;
+    let registry = prefix.split('_').map( | part | {
+        let mut chars = part.chars();
+        chars.next().map( | c | c.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+    }).collect();
+    Some(ScannedComponent {registry, name: name.clone()})
+}
+
+// splits rust source in idents, punctuation and literals, dropping comments.
+// string and char literals become a single token so their contents can't confuse brace matching
+fn tokenize(source: &str) -> Vec<Tok> {
+    let bytes = source.as_bytes();
+    let mut toks = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue
+        }
+        if c == b'/' && bytes.get(i + 1) == Some(&b'/') {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += 1;
+            }
+            continue
+        }
+        if c == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            let mut depth = 0;
+            while i < bytes.len() {
+                if bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'*') {
+                    depth += 1;
+                    i += 2;
+                }
+                else if bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break
+                    }
+                }
+                else {
+                    i += 1;
+                }
+            }
+            continue
+        }
+        // raw strings r"..", r#".."#
+        if c == b'r' && (bytes.get(i + 1) == Some(&b'"') || bytes.get(i + 1) == Some(&b'#')) {
+            let mut hashes = 0;
+            let mut j = i + 1;
+            while bytes.get(j) == Some(&b'#') {
+                hashes += 1;
+                j += 1;
+            }
+            if bytes.get(j) == Some(&b'"') {
+                j += 1;
+                'outer: while j < bytes.len() {
+                    if bytes[j] == b'"' {
+                        let mut k = 0;
+                        while k < hashes {
+                            if bytes.get(j + 1 + k) != Some(&b'#') {
+                                j += 1;
+                                continue 'outer;
+                            }
+                            k += 1;
+                        }
+                        j += 1 + hashes;
+                        break
+                    }
+                    j += 1;
+                }
+                i = j;
+                toks.push(Tok {kind: TokKind::Literal, text: String::from("\"\""), start, end: i});
+                continue
+            }
+        }
+        if c == b'"' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'"' {
+                if bytes[i] == b'\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            toks.push(Tok {kind: TokKind::Literal, text: String::from("\"\""), start, end: i.min(bytes.len())});
+            continue
+        }
+        if c == b'\'' {
+            // char literal or lifetime
+            if bytes.get(i + 1) == Some(&b'\\') {
+                i += 2;
+                while i < bytes.len() && bytes[i] != b'\'' {
+                    i += 1;
+                }
+                i += 1;
+                toks.push(Tok {kind: TokKind::Literal, text: String::from("''"), start, end: i.min(bytes.len())});
+                continue
+            }
+            let ch_len = source[i + 1..].chars().next().map( | ch | ch.len_utf8()).unwrap_or(0);
+            if bytes.get(i + 1 + ch_len) == Some(&b'\'') {
+                i += 2 + ch_len;
+                toks.push(Tok {kind: TokKind::Literal, text: String::from("''"), start, end: i});
+                continue
+            }
+            i += 1;
+            toks.push(Tok {kind: TokKind::Punct, text: String::from("'"), start, end: i});
+            continue
+        }
+        if c.is_ascii_alphabetic() || c == b'_' || c >= 0x80 {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] >= 0x80) {
+                i += 1;
+            }
+            toks.push(Tok {kind: TokKind::Ident, text: source[start..i].to_string(), start, end: i});
+            continue
+        }
+        if c.is_ascii_digit() {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || (bytes[i] == b'.' && bytes.get(i + 1).map( | b | b.is_ascii_digit()).unwrap_or(false))) {
+                i += 1;
+            }
+            toks.push(Tok {kind: TokKind::Literal, text: source[start..i].to_string(), start, end: i});
+            continue
+        }
+        i += 1;
+        toks.push(Tok {kind: TokKind::Punct, text: source[start..i].to_string(), start, end: i});
+    }
+    toks
+}
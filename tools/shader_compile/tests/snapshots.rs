@@ -0,0 +1,131 @@
+use {
+    std::path::Path,
+    makepad_shader_compile::{Backend, ScannedCrate, ShaderCompiler},
+};
+
+const DRAW_2D_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../draw_2d");
+const WIDGETS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../widgets");
+const SNAPSHOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+
+fn load(crate_dirs: &[&str]) -> ShaderCompiler {
+    let crates: Vec<_> = crate_dirs.iter().map( | dir | ScannedCrate::scan(Path::new(dir)).unwrap()).collect();
+    let compiler = ShaderCompiler::load(&crates).unwrap();
+    if let Some(error) = compiler.expand_errors.first() {
+        panic!("{}", error);
+    }
+    compiler
+}
+
+fn load_draw_2d() -> ShaderCompiler {
+    load(&[DRAW_2D_DIR])
+}
+
+// wgsl is left out, its golden files live in platform/shader_compiler/tests/wgsl
+const SNAPSHOT_BACKENDS: [Backend; 3] = [Backend::Glsl, Backend::Hlsl, Backend::Metal];
+
+// compiles the shader for the snapshot backends and compares each file with tests/snapshots/<dir_name>/<file>.
+// run with UPDATE_SNAPSHOTS=1 to rewrite them
+fn check_snapshot(compiler: &mut ShaderCompiler, module_path: &str, name: &str) {
+    let shader = compiler.draw_shaders().into_iter().find( | shader | shader.module_path == module_path && shader.name == name)
+        .unwrap_or_else( | | panic!("draw shader {}::{} not found", module_path, name));
+    let compiled = compiler.compile(&shader, &SNAPSHOT_BACKENDS).unwrap_or_else( | err | panic!("{} failed to compile\n{}", name, err));
+    let dir = Path::new(SNAPSHOT_DIR).join(shader.dir_name());
+    if std::env::var("UPDATE_SNAPSHOTS").is_ok() {
+        compiled.write_to(Path::new(SNAPSHOT_DIR)).unwrap();
+        return
+    }
+    for (file, output) in &compiled.files {
+        let path = dir.join(file);
+        let expected = std::fs::read_to_string(&path).unwrap_or_else( | _ | panic!("missing snapshot {}", path.display()));
+        if expected != *output {
+            println!("########## OUTPUT CHANGED {} ##########\n{}\n########## END ##########", path.display(), output);
+            panic!("{} output for {} does not match {}", file, name, path.display());
+        }
+    }
+}
+
+#[test]
+fn draw_2d_shader_list() {
+    let compiler = load_draw_2d();
+    let names: Vec<_> = compiler.draw_shaders().into_iter().map( | shader | shader.name).collect();
+    for name in ["DrawQuad", "DrawColor", "DrawText", "DrawShape", "DrawImage"] {
+        assert!(names.iter().any( | n | n == name), "{} missing from {:?}", name, names);
+    }
+}
+
+#[test]
+fn draw_2d_snapshots() {
+    let mut compiler = load_draw_2d();
+    for (module_path, name) in [
+        ("makepad_draw_2d::shader::draw_quad", "DrawQuad"),
+        ("makepad_draw_2d::shader::draw_color", "DrawColor"),
+        ("makepad_draw_2d::shader::draw_text", "DrawText"),
+        ("makepad_draw_2d::shader::draw_shape", "DrawShape"),
+        ("makepad_draw_2d::shader::draw_image", "DrawImage"),
+    ] {
+        check_snapshot(&mut compiler, module_path, name);
+    }
+}
+
+#[test]
+fn widgets_compile() {
+    let mut compiler = load(&[DRAW_2D_DIR, WIDGETS_DIR]);
+    let shaders: Vec<_> = compiler.draw_shaders().into_iter().filter( | shader | shader.module_path.starts_with("makepad_widgets::")).collect();
+    assert!(shaders.iter().any( | shader | shader.name == "DrawLabelText"));
+    for shader in &shaders {
+        if let Err(err) = compiler.compile(shader, &Backend::all()) {
+            panic!("{}::{} failed to compile\n{}", shader.module_path, shader.name, err);
+        }
+    }
+}
+
+#[test]
+fn widgets_snapshots() {
+    let mut compiler = load(&[DRAW_2D_DIR, WIDGETS_DIR]);
+    for (module_path, name) in [
+        ("makepad_widgets::button", "DrawLabelText"),
+        ("makepad_widgets::slider", "DrawSlider"),
+        // the same name in two modules, with different instance fields
+        ("makepad_widgets::file_tree", "DrawBgQuad"),
+        ("makepad_widgets::popup_menu", "DrawBgQuad"),
+    ] {
+        check_snapshot(&mut compiler, module_path, name);
+    }
+}
+
+// a crate with one live_design! that doesn't expand, next to one that does
+#[test]
+fn expand_errors_stay_in_their_file() {
+    let dir = std::env::temp_dir().join(format!("shader_compile_expand_errors_{}", std::process::id()));
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"broken\"\n").unwrap();
+    std::fs::write(src.join("lib.rs"), "pub mod bad;\npub mod good;\n").unwrap();
+    std::fs::write(src.join("bad.rs"), "live_design!{\n    Broken = <DrawMissing> {}\n}\n").unwrap();
+    std::fs::write(src.join("good.rs"), "\
+use makepad_draw_2d::*;
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    DrawGood = {{DrawGood}} {
+        fn pixel(self) -> vec4 {return vec4(self.level, 0.0, 0.0, 1.0);}
+    }
+}
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawGood {
+    draw_super: DrawQuad,
+    level: f32,
+}
+").unwrap();
+
+    let crates = [ScannedCrate::scan(Path::new(DRAW_2D_DIR)).unwrap(), ScannedCrate::scan(&dir).unwrap()];
+    let mut compiler = ShaderCompiler::load(&crates).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(compiler.expand_errors.len(), 1);
+    assert!(compiler.expand_errors[0].file.ends_with("bad.rs"), "{}", compiler.expand_errors[0]);
+
+    let shader = compiler.find_draw_shader("DrawGood").unwrap();
+    let compiled = compiler.compile(&shader, &[Backend::Glsl]).unwrap();
+    let (_, metadata) = compiled.files.iter().find( | (file, _) | file == "metadata.txt").unwrap();
+    assert!(metadata.contains("level: float"), "{}", metadata);
+}
@@ -0,0 +1,44 @@
+uniform float const_table[4];
+
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec2 packed_varying_1;
+
+vec4 ds_color=vec4(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_2_12_pixel() {
+    return vec4((ds_color.rgb * ds_color.a), ds_color.a);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_color.xyzw = packed_varying_0.xyzw;
+    ds_pos.xy = packed_varying_1.xy;
+
+    gl_FragColor = fn_2_12_pixel();
+}
@@ -0,0 +1,61 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[1];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_image: register(t0);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    float2 ds_pt1: INSTF;
+    float2 ds_pt2: INSTG;
+    float ds_alpha: INSTH;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float2 ds_pt1: VARYA;
+    float2 ds_pt2: VARYB;
+    float ds_alpha: VARYC;
+    float2 ds_pos: VARYD;
+};
+float4 consfn_vec4_vec3_float(float3 x0, , float x1) {    return float4(x0[0], x0[1], x0[2], x1);}float4 fn_3_12_pixel(inout Varyings varyings, ) {
+    float4 var_color_0 = sample2d(ds_image, lerp(varyings.ds_pt1, varyings.ds_pt2, varyings.ds_pos));
+    return consfn_vec4_vec3_float((var_color_0.rgb * varyings.ds_alpha), (var_color_0.a * varyings.ds_alpha));
+}
+float4 fn_3_9_clip_and_transform_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * instances.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / instances.ds_rect_size));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, consfn_vec4_float_float_float_float(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_3_11_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    return fn_3_9_clip_and_transform_vertex (const_table, geometries, instances, varyings, , , );
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float2(0.0,0.0), float2(0.0,0.0), 0.0, float2(0.0,0.0)};
+    varyings.ds_pt1 = instances.ds_pt1;
+    varyings.ds_pt2 = instances.ds_pt2;
+    varyings.ds_alpha = instances.ds_alpha;
+    varyings.position = fn_3_11_vertex(const_table, geometries, instances, varyings, , , );
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_3_12_pixel(const_table, varyings, );
+}
@@ -0,0 +1,48 @@
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[2];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float2 ds_pos: VARYA;
+};
+float4 fn_4_12_pixel() {
+    return float4(const_table[0].y, const_table[0].z, const_table[0].w, const_table[1].x);
+}
+float4 fn_4_9_clip_and_transform_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * instances.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / instances.ds_rect_size));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, consfn_vec4_float_float_float_float(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_4_11_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    return fn_4_9_clip_and_transform_vertex (const_table, geometries, instances, varyings, , , );
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float2(0.0,0.0)};
+    varyings.position = fn_4_11_vertex(const_table, geometries, instances, varyings, , , );
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_4_12_pixel(const_table);
+}
@@ -0,0 +1,312 @@
+uniform float const_table[140];
+
+uniform float live_table[1];
+float live_7_8;
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+uniform sampler2D ds_image;
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec4 packed_varying_2;
+varying vec4 packed_varying_3;
+varying vec4 packed_varying_4;
+varying vec4 packed_varying_5;
+varying vec4 packed_varying_6;
+varying vec3 packed_varying_7;
+
+vec2 ds_rect_size=vec2(0.0);
+float ds_shape=0.0;
+float ds_fill=0.0;
+vec4 ds_color=vec4(0.0);
+vec4 ds_color2=vec4(0.0);
+float ds_border_width=0.0;
+vec4 ds_border_color=vec4(0.0);
+vec4 ds_inset=vec4(0.0);
+vec4 ds_radius=vec4(0.0);
+vec2 ds_image_scale=vec2(0.0);
+vec2 ds_image_pan=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+struct struct_7_27 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+void fn_7_64_hexagon(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float var_dx_0 = (abs((var_x_0 - var_self_0.f_pos.x)) * const_table[137]);
+    float var_dy_0 = abs((var_y_0 - var_self_0.f_pos.y));
+    (var_self_0.f_dist = max(((var_dy_0 + (cos((const_table[138] * live_7_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0)));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_7_63_rect(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    vec2 var_s_0 = (vec2(var_w_0, var_h_0) * const_table[132]);
+    vec2 var_d_0 = (abs(((vec2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    vec2 var_dm_0 = min(var_d_0, vec2(const_table[133], const_table[134]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2(const_table[135], const_table[136])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_7_62_box_all(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_top_0, float var_r_right_top_0, float var_r_right_bottom_0, float var_r_left_bottom_0) {
+    vec2 var_size_0 = vec2((const_table[107] * var_w_0), (const_table[108] * var_h_0));
+    vec2 var_p_r_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    vec2 var_bp_lt_0 = max((var_p_0 + vec2((const_table[109] * var_r_left_top_0), (const_table[110] * var_r_left_top_0)).xy), vec2(const_table[111], const_table[112]));
+    vec2 var_bp_rt_0 = max((var_p_0 + vec2((const_table[113] * var_r_right_top_0), (const_table[114] * var_r_right_top_0)).xy), vec2(const_table[115], const_table[116]));
+    vec2 var_bp_rb_0 = max((var_p_0 + vec2((const_table[117] * var_r_right_bottom_0), (const_table[118] * var_r_right_bottom_0)).xy), vec2(const_table[119], const_table[120]));
+    vec2 var_bp_lb_0 = max((var_p_0 + vec2((const_table[121] * var_r_left_bottom_0), (const_table[122] * var_r_left_bottom_0)).xy), vec2(const_table[123], const_table[124]));
+    (var_self_0.f_dist = (mix(mix((length(var_bp_lt_0) - (const_table[125] * var_r_left_top_0)), (length(var_bp_lb_0) - (const_table[126] * var_r_left_bottom_0)), step((const_table[127] * var_h_0), var_p_r_0.y)), mix((length(var_bp_rt_0) - (const_table[128] * var_r_right_top_0)), (length(var_bp_rb_0) - (const_table[129] * var_r_right_bottom_0)), step((const_table[130] * var_h_0), var_p_r_0.y)), step((const_table[131] * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_7_61_box_x(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_0, float var_r_right_0) {
+    vec2 var_size_0 = vec2((const_table[94] * var_w_0), (const_table[95] * var_h_0));
+    vec2 var_p_r_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    vec2 var_bp_left_0 = max((var_p_0 + vec2((const_table[96] * var_r_left_0), (const_table[97] * var_r_left_0)).xy), vec2(const_table[98], const_table[99]));
+    vec2 var_bp_right_0 = max((var_p_0 + vec2((const_table[100] * var_r_right_0), (const_table[101] * var_r_right_0)).xy), vec2(const_table[102], const_table[103]));
+    (var_self_0.f_dist = (mix((length(var_bp_left_0) - (const_table[104] * var_r_left_0)), (length(var_bp_right_0) - (const_table[105] * var_r_right_0)), step((const_table[106] * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_7_60_box_y(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_top_0, float var_r_bottom_0) {
+    vec2 var_size_0 = vec2((const_table[81] * var_w_0), (const_table[82] * var_h_0));
+    vec2 var_p_r_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    vec2 var_bp_top_0 = max((var_p_0 + vec2((const_table[83] * var_r_top_0), (const_table[84] * var_r_top_0)).xy), vec2(const_table[85], const_table[86]));
+    vec2 var_bp_bottom_0 = max((var_p_0 + vec2((const_table[87] * var_r_bottom_0), (const_table[88] * var_r_bottom_0)).xy), vec2(const_table[89], const_table[90]));
+    (var_self_0.f_dist = (mix((length(var_bp_top_0) - (const_table[91] * var_r_top_0)), (length(var_bp_bottom_0) - (const_table[92] * var_r_bottom_0)), step((const_table[93] * var_h_0), var_p_r_0.y)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_7_59_box(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    vec2 var_p_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    vec2 var_size_0 = vec2((const_table[74] * var_w_0), (const_table[75] * var_h_0));
+    vec2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - vec2((const_table[76] * var_r_0), (const_table[77] * var_r_0)).xy)), vec2(const_table[78], const_table[79]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[80] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+void fn_7_58_circle(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    vec2 var_c_0 = (var_self_0.f_pos - vec2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+float fn_7_46_calc_blur(inout struct_7_27 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[68], const_table[69]);
+    float var_wb_0 = const_table[70];
+    if(var_self_0.f_blur > const_table[71]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[72], const_table[73]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_7_49_stroke_keep(inout struct_7_27 var_self_0, vec4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    vec4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[67] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+
+vec4 fn_7_50_stroke(inout struct_7_27 var_self_0, vec4 var_color_0, float var_width_0) {
+    fn_7_49_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[64]));
+    (var_self_0.f_clip = -const_table[65]);
+    (var_self_0.f_has_clip = const_table[66]);
+    return var_self_0.f_result;
+}
+
+vec4 fn_7_47_fill_keep(inout struct_7_27 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[60] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[61]) {
+            float var_f2_0 = (const_table[62] - fn_7_46_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[63] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+float fn_7_40_antialias(vec2 var_p_0) {
+    return (const_table[59] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_7_27 fn_7_41_viewport(vec2 var_pos_0) {
+    return struct_7_27(var_pos_0,vec4(const_table[49]),vec2(const_table[50]),vec2(const_table[51]),const_table[52],-const_table[53],const_table[54],const_table[55],const_table[56],fn_7_40_antialias (var_pos_0),const_table[57],const_table[58]);
+}
+
+vec4 fn_7_14_premul(vec4 var_v_0) {
+    return vec4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+
+vec4 fn_5_15_get_color() {
+    return ds_color;
+}
+
+vec4 fn_5_16_get_fill() {
+    if(abs(ds_fill - 1.0)<0.5){
+            return fn_5_15_get_color ();
+    }else  if(abs(ds_fill - 2.0)<0.5){
+            return mix(ds_color, ds_color2, ds_pos.x);
+    }else  if(abs(ds_fill - 3.0)<0.5){
+            return mix(ds_color, ds_color2, ds_pos.y);
+    }else  if(abs(ds_fill - 4.0)<0.5){
+            return sample2d(ds_image, ((ds_pos * ds_image_scale) + ds_image_pan)).xyzw;
+    }
+}
+
+vec4 fn_5_13_pixel() {
+    vec4 var_color_0 = fn_5_16_get_fill ();
+    if(abs(ds_shape - 1.0)<0.5){
+            return vec4(const_table[1], const_table[2], const_table[3], const_table[4]);
+    }else  if(abs(ds_shape - 2.0)<0.5){
+            return fn_7_14_premul (var_color_0);
+    }else  if(abs(ds_shape - 3.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        fn_7_63_rect (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[5]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[6]))));
+        fn_7_47_fill_keep (var_sdf_0, ds_color);
+        if(ds_border_width > const_table[7]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 4.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        fn_7_59_box (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[8]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[9]))), max(const_table[10], ds_radius.x));
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[11]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 10.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        (var_sdf_0.f_blur = const_table[12]);
+        fn_7_59_box (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[13]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[14]))), max(const_table[15], ds_radius.x));
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 5.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        fn_7_61_box_x (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[16]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[17]))), ds_radius.x, ds_radius.y);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[18]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 6.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        fn_7_60_box_y (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[19]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[20]))), ds_radius.x, ds_radius.y);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[21]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 7.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        fn_7_62_box_all (var_sdf_0, (ds_inset.x + ds_border_width), (ds_inset.y + ds_border_width), (ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (ds_border_width * const_table[22]))), (ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (ds_border_width * const_table[23]))), ds_radius.x, ds_radius.y, ds_radius.z, ds_radius.w);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[24]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 8.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        if(ds_radius.x > const_table[25]) {
+                    fn_7_58_circle (var_sdf_0, (ds_rect_size.x * const_table[26]), (ds_rect_size.y * const_table[27]), ds_radius.x);
+        }else{
+                    fn_7_58_circle (var_sdf_0, (ds_rect_size.x * const_table[28]), (ds_rect_size.y * const_table[29]), min(((ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (const_table[30] * ds_border_width))) * const_table[31]), ((ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (const_table[32] * ds_border_width))) * const_table[33])));
+        }
+
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[34]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(abs(ds_shape - 9.0)<0.5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+        if(ds_radius.x > const_table[35]) {
+                    fn_7_64_hexagon (var_sdf_0, (ds_rect_size.x * const_table[36]), (ds_rect_size.y * const_table[37]), ds_radius.x);
+        }else{
+                    fn_7_64_hexagon (var_sdf_0, (ds_rect_size.x * const_table[38]), (ds_rect_size.y * const_table[39]), min(((ds_rect_size.x - ((ds_inset.x + ds_inset.z) + (const_table[40] * ds_border_width))) * const_table[41]), ((ds_rect_size.y - ((ds_inset.y + ds_inset.w) + (const_table[42] * ds_border_width))) * const_table[43])));
+        }
+
+        fn_7_47_fill_keep (var_sdf_0, var_color_0);
+        if(ds_border_width > const_table[44]) {
+                    fn_7_50_stroke (var_sdf_0, ds_border_color, ds_border_width);
+        }
+
+        return var_sdf_0.f_result;
+    }
+    return vec4(const_table[45], const_table[46], const_table[47], const_table[48]);
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_7_8 = live_table[0];
+    ds_rect_size.xy = packed_varying_0.xy;
+    ds_shape = packed_varying_0.z;
+    ds_fill = packed_varying_0.w;
+    ds_color.xyzw = packed_varying_1.xyzw;
+    ds_color2.xyzw = packed_varying_2.xyzw;
+    ds_border_width = packed_varying_3.x;
+    ds_border_color.xyz = packed_varying_3.yzw;
+    ds_border_color.w = packed_varying_4.x;
+    ds_inset.xyz = packed_varying_4.yzw;
+    ds_inset.w = packed_varying_5.x;
+    ds_radius.xyz = packed_varying_5.yzw;
+    ds_radius.w = packed_varying_6.x;
+    ds_image_scale.xy = packed_varying_6.yz;
+    ds_image_pan.x = packed_varying_6.w;
+    ds_image_pan.y = packed_varying_7.x;
+    ds_pos.xy = packed_varying_7.yz;
+
+    gl_FragColor = fn_5_13_pixel();
+}
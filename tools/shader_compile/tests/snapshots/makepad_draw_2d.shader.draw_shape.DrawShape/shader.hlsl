@@ -0,0 +1,302 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+struct struct_7_27 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+cbuffer LiveUniforms : register(b0) {
+    float live_7_8;
+};
+cbuffer ConstTable : register(b1){float4 const_table[35];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_image: register(t0);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    int ds_shape: INSTF;
+    int ds_fill: INSTG;
+    float4 ds_color: INSTH;
+    float4 ds_color2: INSTI;
+    float ds_border_width: INSTJ;
+    float4 ds_border_color: INSTK;
+    float4 ds_inset: INSTL;
+    float4 ds_radius: INSTM;
+    float2 ds_image_scale: INSTN;
+    float2 ds_image_pan: INSTO;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float2 ds_rect_size: VARYA;
+    nointerpolation int ds_shape: VARYB;
+    nointerpolation int ds_fill: VARYC;
+    float4 ds_color: VARYD;
+    float4 ds_color2: VARYE;
+    float ds_border_width: VARYF;
+    float4 ds_border_color: VARYG;
+    float4 ds_inset: VARYH;
+    float4 ds_radius: VARYI;
+    float2 ds_image_scale: VARYJ;
+    float2 ds_image_pan: VARYK;
+    float2 ds_pos: VARYL;
+};
+void fn_7_64_hexagon(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_r_0, ) {
+    float var_dx_0 = (abs((var_x_0 - var_self_0.f_pos.x)) * const_table[34].y);
+    float var_dy_0 = abs((var_y_0 - var_self_0.f_pos.y));
+    (var_self_0.f_dist = max(((var_dy_0 + (cos((const_table[34].z * live_7_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0)));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_63_rect(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    float2 var_s_0 = (consfn_vec2_float_float(var_w_0, var_h_0) * const_table[33].x);
+    float2 var_d_0 = (abs(((consfn_vec2_float_float(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, consfn_vec2_float_float(const_table[33].y, const_table[33].z));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, consfn_vec2_float_float(const_table[33].w, const_table[34].x)))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_62_box_all(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_top_0, float var_r_right_top_0, float var_r_right_bottom_0, float var_r_left_bottom_0) {
+    float2 var_size_0 = consfn_vec2_float_float((const_table[26].w * var_w_0), (const_table[27].x * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - consfn_vec2_float_float(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_lt_0 = max((var_p_0 + consfn_vec2_float_float((const_table[27].y * var_r_left_top_0), (const_table[27].z * var_r_left_top_0)).xy), consfn_vec2_float_float(const_table[27].w, const_table[28].x));
+    float2 var_bp_rt_0 = max((var_p_0 + consfn_vec2_float_float((const_table[28].y * var_r_right_top_0), (const_table[28].z * var_r_right_top_0)).xy), consfn_vec2_float_float(const_table[28].w, const_table[29].x));
+    float2 var_bp_rb_0 = max((var_p_0 + consfn_vec2_float_float((const_table[29].y * var_r_right_bottom_0), (const_table[29].z * var_r_right_bottom_0)).xy), consfn_vec2_float_float(const_table[29].w, const_table[30].x));
+    float2 var_bp_lb_0 = max((var_p_0 + consfn_vec2_float_float((const_table[30].y * var_r_left_bottom_0), (const_table[30].z * var_r_left_bottom_0)).xy), consfn_vec2_float_float(const_table[30].w, const_table[31].x));
+    (var_self_0.f_dist = (lerp(lerp((length(var_bp_lt_0) - (const_table[31].y * var_r_left_top_0)), (length(var_bp_lb_0) - (const_table[31].z * var_r_left_bottom_0)), step((const_table[31].w * var_h_0), var_p_r_0.y)), lerp((length(var_bp_rt_0) - (const_table[32].x * var_r_right_top_0)), (length(var_bp_rb_0) - (const_table[32].y * var_r_right_bottom_0)), step((const_table[32].z * var_h_0), var_p_r_0.y)), step((const_table[32].w * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_61_box_x(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_0, float var_r_right_0) {
+    float2 var_size_0 = consfn_vec2_float_float((const_table[23].z * var_w_0), (const_table[23].w * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - consfn_vec2_float_float(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_left_0 = max((var_p_0 + consfn_vec2_float_float((const_table[24].x * var_r_left_0), (const_table[24].y * var_r_left_0)).xy), consfn_vec2_float_float(const_table[24].z, const_table[24].w));
+    float2 var_bp_right_0 = max((var_p_0 + consfn_vec2_float_float((const_table[25].x * var_r_right_0), (const_table[25].y * var_r_right_0)).xy), consfn_vec2_float_float(const_table[25].z, const_table[25].w));
+    (var_self_0.f_dist = (lerp((length(var_bp_left_0) - (const_table[26].x * var_r_left_0)), (length(var_bp_right_0) - (const_table[26].y * var_r_right_0)), step((const_table[26].z * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_60_box_y(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_top_0, float var_r_bottom_0) {
+    float2 var_size_0 = consfn_vec2_float_float((const_table[20].y * var_w_0), (const_table[20].z * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - consfn_vec2_float_float(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_top_0 = max((var_p_0 + consfn_vec2_float_float((const_table[20].w * var_r_top_0), (const_table[21].x * var_r_top_0)).xy), consfn_vec2_float_float(const_table[21].y, const_table[21].z));
+    float2 var_bp_bottom_0 = max((var_p_0 + consfn_vec2_float_float((const_table[21].w * var_r_bottom_0), (const_table[22].x * var_r_bottom_0)).xy), consfn_vec2_float_float(const_table[22].y, const_table[22].z));
+    (var_self_0.f_dist = (lerp((length(var_bp_top_0) - (const_table[22].w * var_r_top_0)), (length(var_bp_bottom_0) - (const_table[23].x * var_r_bottom_0)), step((const_table[23].y * var_h_0), var_p_r_0.y)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_59_box(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0) {
+    float2 var_p_0 = (var_self_0.f_pos - consfn_vec2_float_float(var_x_0, var_y_0));
+    float2 var_size_0 = consfn_vec2_float_float((const_table[18].z * var_w_0), (const_table[18].w * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - consfn_vec2_float_float((const_table[19].x * var_r_0), (const_table[19].y * var_r_0)).xy)), consfn_vec2_float_float(const_table[19].z, const_table[19].w));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[20].x * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_58_circle(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_r_0) {
+    float2 var_c_0 = (var_self_0.f_pos - consfn_vec2_float_float(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_7_46_calc_blur(inout struct_7_27 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[17].x, const_table[17].y);
+    float var_wb_0 = const_table[17].z;
+    if(var_self_0.f_blur > const_table[17].w) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[18].x, const_table[18].y));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_7_49_stroke_keep(inout struct_7_27 var_self_0, float4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[16].w - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_7_50_stroke(inout struct_7_27 var_self_0, float4 var_color_0, float var_width_0) {
+    fn_7_49_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[16].x));
+    (var_self_0.f_clip = -const_table[16].y);
+    (var_self_0.f_has_clip = const_table[16].z);
+    return var_self_0.f_result;
+}
+float4 fn_7_47_fill_keep(inout struct_7_27 var_self_0, float4 var_color_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[15].x - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[15].y) {
+            float var_f2_0 = (const_table[15].z - fn_7_46_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[15].w - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float fn_7_40_antialias(float2 var_p_0) {
+    return (const_table[14].w / length(consfn_vec2_float_float(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_7_27 fn_7_41_viewport(float2 var_pos_0) {
+    return struct_7_27{var_pos_0,consfn_vec4_float(const_table[12].y),consfn_vec2_float(const_table[12].z),consfn_vec2_float(const_table[12].w),const_table[13].x,-const_table[13].y,const_table[13].z,const_table[13].w,const_table[14].x,fn_7_40_antialias (var_pos_0, const_table),const_table[14].y,const_table[14].z};
+}
+float4 fn_7_14_premul(float4 var_v_0) {
+    return consfn_vec4_float_float_float_float((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_5_15_get_color(inout Varyings varyings) {
+    return varyings.ds_color;
+}
+float4 fn_5_16_get_fill(inout Varyings varyings, ) {
+    if(varyings.ds_fill == 1){
+            return fn_5_15_get_color (const_table, varyings);
+    }else  if(varyings.ds_fill == 2){
+            return lerp(varyings.ds_color, varyings.ds_color2, varyings.ds_pos.x);
+    }else  if(varyings.ds_fill == 3){
+            return lerp(varyings.ds_color, varyings.ds_color2, varyings.ds_pos.y);
+    }else  if(varyings.ds_fill == 4){
+            return sample2d(ds_image, ((varyings.ds_pos * varyings.ds_image_scale) + varyings.ds_image_pan)).xyzw;
+    }
+}
+float4 fn_5_13_pixel(inout Varyings varyings, , ) {
+    float4 var_color_0 = fn_5_16_get_fill (const_table, varyings, );
+    if(varyings.ds_shape == 1){
+            return float4(const_table[0].y, const_table[0].z, const_table[0].w, const_table[1].x);
+    }else  if(varyings.ds_shape == 2){
+            return fn_7_14_premul (var_color_0, const_table);
+    }else  if(varyings.ds_shape == 3){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_63_rect (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[1].y))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[1].z))), const_table);
+        fn_7_47_fill_keep (var_sdf_0, varyings.ds_color, const_table);
+        if(varyings.ds_border_width > const_table[1].w) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 4){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_59_box (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[2].x))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[2].y))), max(const_table[2].z, varyings.ds_radius.x), const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[2].w) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 10){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        (var_sdf_0.f_blur = const_table[3].x);
+        fn_7_59_box (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[3].y))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[3].z))), max(const_table[3].w, varyings.ds_radius.x), const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 5){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_61_box_x (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[4].x))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[4].y))), varyings.ds_radius.x, varyings.ds_radius.y, const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[4].z) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 6){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_60_box_y (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[4].w))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[5].x))), varyings.ds_radius.x, varyings.ds_radius.y, const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[5].y) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 7){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_62_box_all (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[5].z))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[5].w))), varyings.ds_radius.x, varyings.ds_radius.y, varyings.ds_radius.z, varyings.ds_radius.w, const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[6].x) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 8){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        if(varyings.ds_radius.x > const_table[6].y) {
+                    fn_7_58_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[6].z), (varyings.ds_rect_size.y * const_table[6].w), varyings.ds_radius.x, const_table);
+        }else{
+                    fn_7_58_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[7].x), (varyings.ds_rect_size.y * const_table[7].y), min(((varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (const_table[7].z * varyings.ds_border_width))) * const_table[7].w), ((varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (const_table[8].x * varyings.ds_border_width))) * const_table[8].y)), const_table);
+        }
+
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[8].z) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 9){
+            struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        if(varyings.ds_radius.x > const_table[8].w) {
+                    fn_7_64_hexagon (var_sdf_0, (varyings.ds_rect_size.x * const_table[9].x), (varyings.ds_rect_size.y * const_table[9].y), varyings.ds_radius.x, const_table, );
+        }else{
+                    fn_7_64_hexagon (var_sdf_0, (varyings.ds_rect_size.x * const_table[9].z), (varyings.ds_rect_size.y * const_table[9].w), min(((varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (const_table[10].x * varyings.ds_border_width))) * const_table[10].y), ((varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (const_table[10].z * varyings.ds_border_width))) * const_table[10].w)), const_table, );
+        }
+
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[11].x) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }
+    return float4(const_table[11].y, const_table[11].z, const_table[11].w, const_table[12].x);
+}
+float4 fn_5_10_clip_and_transform_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * varyings.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / varyings.ds_rect_size));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, consfn_vec4_float_float_float_float(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_5_12_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    return fn_5_10_clip_and_transform_vertex (const_table, geometries, instances, varyings, , , );
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float2(0.0,0.0), 0, 0, float4(0.0,0.0,0.0,0.0), float4(0.0,0.0,0.0,0.0), 0.0, float4(0.0,0.0,0.0,0.0), float4(0.0,0.0,0.0,0.0), float4(0.0,0.0,0.0,0.0), float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_shape = instances.ds_shape;
+    varyings.ds_fill = instances.ds_fill;
+    varyings.ds_color = instances.ds_color;
+    varyings.ds_color2 = instances.ds_color2;
+    varyings.ds_border_width = instances.ds_border_width;
+    varyings.ds_border_color = instances.ds_border_color;
+    varyings.ds_inset = instances.ds_inset;
+    varyings.ds_radius = instances.ds_radius;
+    varyings.ds_image_scale = instances.ds_image_scale;
+    varyings.ds_image_pan = instances.ds_image_pan;
+    varyings.position = fn_5_12_vertex(const_table, geometries, instances, varyings, , , );
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_5_13_pixel(const_table, varyings, , );
+}
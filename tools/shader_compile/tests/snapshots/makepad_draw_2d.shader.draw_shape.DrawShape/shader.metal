@@ -0,0 +1,323 @@
+#include <metal_stdlib>
+using namespace metal;
+float4 sample2d(texture2d<float> tex, float2 pos){return tex.sample(sampler(mag_filter::nearest,min_filter::nearest),pos);}
+struct struct_7_27 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float live_7_8;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+    texture2d<float> ds_image [[texture(0)]];};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    uint32_t  ds_shape;
+    uint32_t  ds_fill;
+    packed_float4 ds_color;
+    packed_float4 ds_color2;
+    float ds_border_width;
+    packed_float4 ds_border_color;
+    packed_float4 ds_inset;
+    packed_float4 ds_radius;
+    packed_float2 ds_image_scale;
+    packed_float2 ds_image_pan;
+};
+struct Varyings {
+    float4 position [[position]];
+    float2 ds_rect_size;
+    uint32_t  ds_shape;
+    uint32_t  ds_fill;
+    float4 ds_color;
+    float4 ds_color2;
+    float ds_border_width;
+    float4 ds_border_color;
+    float4 ds_inset;
+    float4 ds_radius;
+    float2 ds_image_scale;
+    float2 ds_image_pan;
+    float2 ds_pos;
+};
+void fn_7_64_hexagon(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table, constant LiveUniforms &live_uniforms) {
+    float var_dx_0 = (abs((var_x_0 - var_self_0.f_pos.x)) * const_table[137]);
+    float var_dy_0 = abs((var_y_0 - var_self_0.f_pos.y));
+    (var_self_0.f_dist = max(((var_dy_0 + (cos((const_table[138] * live_uniforms.live_7_8)) * var_dx_0)) - var_r_0), (var_dx_0 - var_r_0)));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_63_rect(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, constant const float *const_table) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[132]);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[133], const_table[134]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[135], const_table[136])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_62_box_all(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_top_0, float var_r_right_top_0, float var_r_right_bottom_0, float var_r_left_bottom_0, constant const float *const_table) {
+    float2 var_size_0 = float2((const_table[107] * var_w_0), (const_table[108] * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_lt_0 = max((var_p_0 + float2((const_table[109] * var_r_left_top_0), (const_table[110] * var_r_left_top_0)).xy), float2(const_table[111], const_table[112]));
+    float2 var_bp_rt_0 = max((var_p_0 + float2((const_table[113] * var_r_right_top_0), (const_table[114] * var_r_right_top_0)).xy), float2(const_table[115], const_table[116]));
+    float2 var_bp_rb_0 = max((var_p_0 + float2((const_table[117] * var_r_right_bottom_0), (const_table[118] * var_r_right_bottom_0)).xy), float2(const_table[119], const_table[120]));
+    float2 var_bp_lb_0 = max((var_p_0 + float2((const_table[121] * var_r_left_bottom_0), (const_table[122] * var_r_left_bottom_0)).xy), float2(const_table[123], const_table[124]));
+    (var_self_0.f_dist = (mix(mix((length(var_bp_lt_0) - (const_table[125] * var_r_left_top_0)), (length(var_bp_lb_0) - (const_table[126] * var_r_left_bottom_0)), step((const_table[127] * var_h_0), var_p_r_0.y)), mix((length(var_bp_rt_0) - (const_table[128] * var_r_right_top_0)), (length(var_bp_rb_0) - (const_table[129] * var_r_right_bottom_0)), step((const_table[130] * var_h_0), var_p_r_0.y)), step((const_table[131] * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_61_box_x(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_left_0, float var_r_right_0, constant const float *const_table) {
+    float2 var_size_0 = float2((const_table[94] * var_w_0), (const_table[95] * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_left_0 = max((var_p_0 + float2((const_table[96] * var_r_left_0), (const_table[97] * var_r_left_0)).xy), float2(const_table[98], const_table[99]));
+    float2 var_bp_right_0 = max((var_p_0 + float2((const_table[100] * var_r_right_0), (const_table[101] * var_r_right_0)).xy), float2(const_table[102], const_table[103]));
+    (var_self_0.f_dist = (mix((length(var_bp_left_0) - (const_table[104] * var_r_left_0)), (length(var_bp_right_0) - (const_table[105] * var_r_right_0)), step((const_table[106] * var_w_0), var_p_r_0.x)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_60_box_y(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_top_0, float var_r_bottom_0, constant const float *const_table) {
+    float2 var_size_0 = float2((const_table[81] * var_w_0), (const_table[82] * var_h_0));
+    float2 var_p_r_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_p_0 = (abs((var_p_r_0 - var_size_0.xy)) - var_size_0.xy);
+    float2 var_bp_top_0 = max((var_p_0 + float2((const_table[83] * var_r_top_0), (const_table[84] * var_r_top_0)).xy), float2(const_table[85], const_table[86]));
+    float2 var_bp_bottom_0 = max((var_p_0 + float2((const_table[87] * var_r_bottom_0), (const_table[88] * var_r_bottom_0)).xy), float2(const_table[89], const_table[90]));
+    (var_self_0.f_dist = (mix((length(var_bp_top_0) - (const_table[91] * var_r_top_0)), (length(var_bp_bottom_0) - (const_table[92] * var_r_bottom_0)), step((const_table[93] * var_h_0), var_p_r_0.y)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_59_box(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, float var_r_0, constant const float *const_table) {
+    float2 var_p_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float2 var_size_0 = float2((const_table[74] * var_w_0), (const_table[75] * var_h_0));
+    float2 var_bp_0 = max((abs((var_p_0 - var_size_0.xy)) - (var_size_0.xy - float2((const_table[76] * var_r_0), (const_table[77] * var_r_0)).xy)), float2(const_table[78], const_table[79]));
+    (var_self_0.f_dist = ((length(var_bp_0) - (const_table[80] * var_r_0)) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+void fn_7_58_circle(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_r_0, constant const float *const_table) {
+    float2 var_c_0 = (var_self_0.f_pos - float2(var_x_0, var_y_0));
+    float var_len_0 = sqrt(((var_c_0.x * var_c_0.x) + (var_c_0.y * var_c_0.y)));
+    (var_self_0.f_dist = ((var_len_0 - var_r_0) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_7_46_calc_blur(thread struct_7_27 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[68], const_table[69]);
+    float var_wb_0 = const_table[70];
+    if(var_self_0.f_blur > const_table[71]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[72], const_table[73]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_7_49_stroke_keep(thread struct_7_27 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[67] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_7_50_stroke(thread struct_7_27 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_7_49_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[64]));
+    (var_self_0.f_clip = -const_table[65]);
+    (var_self_0.f_has_clip = const_table[66]);
+    return var_self_0.f_result;
+}
+float4 fn_7_47_fill_keep(thread struct_7_27 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[60] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[61]) {
+            float var_f2_0 = (const_table[62] - fn_7_46_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[63] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float fn_7_40_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[59] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_7_27  fn_7_41_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_7_27{var_pos_0,float4(const_table[49]),float2(const_table[50]),float2(const_table[51]),const_table[52],-const_table[53],const_table[54],const_table[55],const_table[56],fn_7_40_antialias (var_pos_0, const_table),const_table[57],const_table[58]};
+}
+float4 fn_7_14_premul(float4 var_v_0, constant const float *const_table) {
+    return float4((var_v_0.x * var_v_0.w), (var_v_0.y * var_v_0.w), (var_v_0.z * var_v_0.w), var_v_0.w);
+}
+float4 fn_5_15_get_color(constant const float *const_table, thread Varyings &varyings) {
+    return varyings.ds_color;
+}
+float4 fn_5_16_get_fill(constant const float *const_table, thread Varyings &varyings, Textures textures) {
+    if(varyings.ds_fill == 1){
+            return fn_5_15_get_color (const_table, varyings);
+    }else  if(varyings.ds_fill == 2){
+            return mix(varyings.ds_color, varyings.ds_color2, varyings.ds_pos.x);
+    }else  if(varyings.ds_fill == 3){
+            return mix(varyings.ds_color, varyings.ds_color2, varyings.ds_pos.y);
+    }else  if(varyings.ds_fill == 4){
+            return sample2d(textures.ds_image, ((varyings.ds_pos * varyings.ds_image_scale) + varyings.ds_image_pan)).xyzw;
+    }
+}
+float4 fn_5_13_pixel(constant const float *const_table, thread Varyings &varyings, Textures textures, constant LiveUniforms &live_uniforms) {
+    float4 var_color_0 = fn_5_16_get_fill (const_table, varyings, textures);
+    if(varyings.ds_shape == 1){
+            return float4(const_table[1], const_table[2], const_table[3], const_table[4]);
+    }else  if(varyings.ds_shape == 2){
+            return fn_7_14_premul (var_color_0, const_table);
+    }else  if(varyings.ds_shape == 3){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_63_rect (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[5]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[6]))), const_table);
+        fn_7_47_fill_keep (var_sdf_0, varyings.ds_color, const_table);
+        if(varyings.ds_border_width > const_table[7]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 4){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_59_box (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[8]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[9]))), max(const_table[10], varyings.ds_radius.x), const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[11]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 10){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        (var_sdf_0.f_blur = const_table[12]);
+        fn_7_59_box (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[13]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[14]))), max(const_table[15], varyings.ds_radius.x), const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 5){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_61_box_x (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[16]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[17]))), varyings.ds_radius.x, varyings.ds_radius.y, const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[18]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 6){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_60_box_y (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[19]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[20]))), varyings.ds_radius.x, varyings.ds_radius.y, const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[21]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 7){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        fn_7_62_box_all (var_sdf_0, (varyings.ds_inset.x + varyings.ds_border_width), (varyings.ds_inset.y + varyings.ds_border_width), (varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (varyings.ds_border_width * const_table[22]))), (varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (varyings.ds_border_width * const_table[23]))), varyings.ds_radius.x, varyings.ds_radius.y, varyings.ds_radius.z, varyings.ds_radius.w, const_table);
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[24]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 8){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        if(varyings.ds_radius.x > const_table[25]) {
+                    fn_7_58_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[26]), (varyings.ds_rect_size.y * const_table[27]), varyings.ds_radius.x, const_table);
+        }else{
+                    fn_7_58_circle (var_sdf_0, (varyings.ds_rect_size.x * const_table[28]), (varyings.ds_rect_size.y * const_table[29]), min(((varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (const_table[30] * varyings.ds_border_width))) * const_table[31]), ((varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (const_table[32] * varyings.ds_border_width))) * const_table[33])), const_table);
+        }
+
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[34]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }else  if(varyings.ds_shape == 9){
+            struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+        if(varyings.ds_radius.x > const_table[35]) {
+                    fn_7_64_hexagon (var_sdf_0, (varyings.ds_rect_size.x * const_table[36]), (varyings.ds_rect_size.y * const_table[37]), varyings.ds_radius.x, const_table, live_uniforms);
+        }else{
+                    fn_7_64_hexagon (var_sdf_0, (varyings.ds_rect_size.x * const_table[38]), (varyings.ds_rect_size.y * const_table[39]), min(((varyings.ds_rect_size.x - ((varyings.ds_inset.x + varyings.ds_inset.z) + (const_table[40] * varyings.ds_border_width))) * const_table[41]), ((varyings.ds_rect_size.y - ((varyings.ds_inset.y + varyings.ds_inset.w) + (const_table[42] * varyings.ds_border_width))) * const_table[43])), const_table, live_uniforms);
+        }
+
+        fn_7_47_fill_keep (var_sdf_0, var_color_0, const_table);
+        if(varyings.ds_border_width > const_table[44]) {
+                    fn_7_50_stroke (var_sdf_0, varyings.ds_border_color, varyings.ds_border_width, const_table);
+        }
+
+        return var_sdf_0.f_result;
+    }
+    return float4(const_table[45], const_table[46], const_table[47], const_table[48]);
+}
+float4 fn_5_10_clip_and_transform_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * varyings.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / varyings.ds_rect_size));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_5_12_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_5_10_clip_and_transform_vertex (const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_shape = instances.ds_shape;
+    varyings.ds_fill = instances.ds_fill;
+    varyings.ds_color = instances.ds_color;
+    varyings.ds_color2 = instances.ds_color2;
+    varyings.ds_border_width = instances.ds_border_width;
+    varyings.ds_border_color = instances.ds_border_color;
+    varyings.ds_inset = instances.ds_inset;
+    varyings.ds_radius = instances.ds_radius;
+    varyings.ds_image_scale = instances.ds_image_scale;
+    varyings.ds_image_pan = instances.ds_image_pan;
+    varyings.position = fn_5_12_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_5_13_pixel(const_table, varyings, textures, live_uniforms);
+}
@@ -0,0 +1,121 @@
+uniform float const_table[140];
+
+uniform float live_table[1];
+float live_7_8;
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+uniform sampler2D ds_image;
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute vec4 packed_instance_4;
+attribute vec4 packed_instance_5;
+attribute vec4 packed_instance_6;
+attribute vec4 packed_instance_7;
+attribute vec4 packed_instance_8;
+attribute float packed_instance_9;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec4 packed_varying_2;
+varying vec4 packed_varying_3;
+varying vec4 packed_varying_4;
+varying vec4 packed_varying_5;
+varying vec4 packed_varying_6;
+varying vec3 packed_varying_7;
+
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_draw_depth=0.0;
+float ds_0=0.0;
+float ds_shape=0.0;
+float ds_fill=0.0;
+vec4 ds_color=vec4(0.0);
+vec4 ds_color2=vec4(0.0);
+float ds_border_width=0.0;
+vec4 ds_border_color=vec4(0.0);
+vec4 ds_inset=vec4(0.0);
+vec4 ds_radius=vec4(0.0);
+vec2 ds_image_scale=vec2(0.0);
+vec2 ds_image_pan=vec2(0.0);
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_pos=vec2(0.0);
+
+vec4 fn_5_10_clip_and_transform_vertex() {
+    vec2 var_clipped_0 = clamp(((ds_geom_pos * ds_rect_size) + ds_rect_pos), ds_draw_clip.xy, ds_draw_clip.zw);
+    (ds_pos = ((var_clipped_0 - ds_rect_pos) / ds_rect_size));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(var_clipped_0.x, var_clipped_0.y, (ds_draw_depth + ds_draw_zbias), const_table[0]))));
+}
+
+vec4 fn_5_12_vertex() {
+    return fn_5_10_clip_and_transform_vertex ();
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_7_8 = live_table[0];
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_rect_pos.xy = packed_instance_0.xy;
+    ds_rect_size.xy = packed_instance_0.zw;
+    ds_draw_clip.xyzw = packed_instance_1.xyzw;
+    ds_draw_depth = packed_instance_2.x;
+    ds_0 = packed_instance_2.y;
+    ds_shape = packed_instance_2.z;
+    ds_fill = packed_instance_2.w;
+    ds_color.xyzw = packed_instance_3.xyzw;
+    ds_color2.xyzw = packed_instance_4.xyzw;
+    ds_border_width = packed_instance_5.x;
+    ds_border_color.xyz = packed_instance_5.yzw;
+    ds_border_color.w = packed_instance_6.x;
+    ds_inset.xyz = packed_instance_6.yzw;
+    ds_inset.w = packed_instance_7.x;
+    ds_radius.xyz = packed_instance_7.yzw;
+    ds_radius.w = packed_instance_8.x;
+    ds_image_scale.xy = packed_instance_8.yz;
+    ds_image_pan.x = packed_instance_8.w;
+    ds_image_pan.y = packed_instance_9;
+
+    gl_Position = fn_5_12_vertex();
+
+    packed_varying_0.xy = ds_rect_size.xy;
+    packed_varying_0.z = ds_shape;
+    packed_varying_0.w = ds_fill;
+    packed_varying_1.xyzw = ds_color.xyzw;
+    packed_varying_2.xyzw = ds_color2.xyzw;
+    packed_varying_3.x = ds_border_width;
+    packed_varying_3.yzw = ds_border_color.xyz;
+    packed_varying_4.x = ds_border_color.w;
+    packed_varying_4.yzw = ds_inset.xyz;
+    packed_varying_5.x = ds_inset.w;
+    packed_varying_5.yzw = ds_radius.xyz;
+    packed_varying_6.x = ds_radius.w;
+    packed_varying_6.yz = ds_image_scale.xy;
+    packed_varying_6.w = ds_image_pan.x;
+    packed_varying_7.x = ds_image_pan.y;
+    packed_varying_7.yz = ds_pos.xy;
+}
@@ -0,0 +1,105 @@
+uniform float const_table[24];
+
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float user_table[2];
+float ds_brightness;
+float ds_curve;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+uniform sampler2D ds_tex;
+uniform sampler2D ds_color_tex;
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec4 packed_varying_2;
+varying vec2 packed_varying_3;
+
+vec4 ds_color=vec4(0.0);
+float ds_is_color=0.0;
+float ds_is_underline=0.0;
+vec2 ds_tex_coord1=vec2(0.0);
+vec2 ds_tex_coord2=vec2(0.0);
+vec2 ds_tex_coord3=vec2(0.0);
+vec2 ds_clipped=vec2(0.0);
+
+vec4 fn_6_24_get_color() {
+    return ds_color;
+}
+
+vec4 fn_6_25_pixel() {
+    if(ds_is_underline > const_table[3]) {
+            vec4 var_col_0 = fn_6_24_get_color ();
+        return vec4(((var_col_0.rgb * ds_brightness) * var_col_0.a), var_col_0.a);
+    }
+
+    if(ds_is_color > const_table[4]) {
+            vec4 var_c_0 = sample2d(ds_color_tex, ds_tex_coord1.xy);
+        return (vec4((var_c_0.rgb * var_c_0.a), var_c_0.a) * fn_6_24_get_color ().a);
+    }
+
+    float var_dx_0 = dFdx(vec2((ds_tex_coord1.x * const_table[5]), const_table[6])).x;
+    float var_dp_0 = (const_table[7] / const_table[8]);
+    float var_s_0 = const_table[9];
+    if(var_dx_0 > const_table[10]) {
+            (var_s_0 = const_table[11]);
+    }else{
+            if(var_dx_0 > const_table[12]) {
+                    (var_s_0 = ((((sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2(const_table[13], const_table[14]))).z + sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2(var_dp_0, const_table[15]))).z) + sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2(const_table[16], var_dp_0))).z) + sample2d_rt(ds_tex, (ds_tex_coord3.xy + vec2(var_dp_0, var_dp_0))).z) * const_table[17]));
+        }else{
+                    if(var_dx_0 > const_table[18]) {
+                            (var_s_0 = sample2d_rt(ds_tex, ds_tex_coord3.xy).z);
+            }else{
+                            if(var_dx_0 > const_table[19]) {
+                                    (var_s_0 = sample2d_rt(ds_tex, ds_tex_coord2.xy).y);
+                }else{
+                                    (var_s_0 = sample2d_rt(ds_tex, ds_tex_coord1.xy).x);
+                }
+
+            }
+
+        }
+
+    }
+
+    (var_s_0 = pow(var_s_0, ds_curve));
+    vec4 var_col_0 = fn_6_24_get_color ();
+    return vec4((((var_s_0 * var_col_0.rgb) * ds_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_brightness = user_table[0];
+    ds_curve = user_table[1];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_color.xyzw = packed_varying_0.xyzw;
+    ds_is_color = packed_varying_1.x;
+    ds_is_underline = packed_varying_1.y;
+    ds_tex_coord1.xy = packed_varying_1.zw;
+    ds_tex_coord2.xy = packed_varying_2.xy;
+    ds_tex_coord3.xy = packed_varying_2.zw;
+    ds_clipped.xy = packed_varying_3.xy;
+
+    gl_FragColor = fn_6_25_pixel();
+}
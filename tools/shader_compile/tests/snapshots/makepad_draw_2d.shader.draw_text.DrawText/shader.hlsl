@@ -0,0 +1,115 @@
+SamplerState default_texture_sampler{Filter=MIN_MAX_MIP_LINEAR;AddressU = Wrap;AddressV=Wrap;};
+float4 sample2d(Texture2D tex, float2 pos){return tex.Sample(default_texture_sampler,pos);}
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[6];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_user : register(b4) {
+    float ds_brightness;
+    float ds_curve;
+};
+cbuffer Uniforms_view : register(b5) {
+    float4x4 ds_view_transform;
+};
+Texture2D ds_tex: register(t0);Texture2D ds_color_tex: register(t1);struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float4 ds_color: INSTA;
+    float2 ds_font_t1: INSTB;
+    float2 ds_font_t2: INSTC;
+    float2 ds_rect_pos: INSTD;
+    float2 ds_rect_size: INSTE;
+    float4 ds_draw_clip: INSTF;
+    float ds_char_depth: INSTG;
+    float2 ds_delta: INSTH;
+    float ds_font_size: INSTI;
+    float ds_advance: INSTJ;
+    float ds_char_offset: INSTK;
+    float ds_char_count: INSTL;
+    float ds_is_color: INSTM;
+    float ds_is_rtl: INSTN;
+    float ds_is_underline: INSTO;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float4 ds_color: VARYA;
+    float ds_is_color: VARYB;
+    float ds_is_underline: VARYC;
+    float2 ds_tex_coord1: VARYD;
+    float2 ds_tex_coord2: VARYE;
+    float2 ds_tex_coord3: VARYF;
+    float2 ds_clipped: VARYG;
+};
+float2 consfn_vec2_float_float(float x0, , float x1) {    return float2(x0, x1);}float4 consfn_vec4_float_float_float_float(float x0, , float x1, , float x2, , float x3) {    return float4(x0, x1, x2, x3);}float4 consfn_vec4_vec3_float(float3 x0, , float x1) {    return float4(x0[0], x0[1], x0[2], x1);}float4 fn_6_24_get_color(inout Varyings varyings) {
+    return varyings.ds_color;
+}
+float4 fn_6_25_pixel(inout Varyings varyings, , ) {
+    if(varyings.ds_is_underline > const_table[0].w) {
+            float4 var_col_0 = fn_6_24_get_color (const_table, varyings);
+        return consfn_vec4_vec3_float(((var_col_0.rgb * ds_brightness) * var_col_0.a), var_col_0.a);
+    }
+
+    if(varyings.ds_is_color > const_table[1].x) {
+            float4 var_c_0 = sample2d(ds_color_tex, varyings.ds_tex_coord1.xy);
+        return (consfn_vec4_vec3_float((var_c_0.rgb * var_c_0.a), var_c_0.a) * fn_6_24_get_color (const_table, varyings).a);
+    }
+
+    float var_dx_0 = ddx(consfn_vec2_float_float((varyings.ds_tex_coord1.x * const_table[1].y), const_table[1].z)).x;
+    float var_dp_0 = (const_table[1].w / const_table[2].x);
+    float var_s_0 = const_table[2].y;
+    if(var_dx_0 > const_table[2].z) {
+            (var_s_0 = const_table[2].w);
+    }else{
+            if(var_dx_0 > const_table[3].x) {
+                    (var_s_0 = ((((sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + consfn_vec2_float_float(const_table[3].y, const_table[3].z))).z + sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + consfn_vec2_float_float(var_dp_0, const_table[3].w))).z) + sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + consfn_vec2_float_float(const_table[4].x, var_dp_0))).z) + sample2d_rt(ds_tex, (varyings.ds_tex_coord3.xy + consfn_vec2_float_float(var_dp_0, var_dp_0))).z) * const_table[4].y));
+        }else{
+                    if(var_dx_0 > const_table[4].z) {
+                            (var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord3.xy).z);
+            }else{
+                            if(var_dx_0 > const_table[4].w) {
+                                    (var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord2.xy).y);
+                }else{
+                                    (var_s_0 = sample2d_rt(ds_tex, varyings.ds_tex_coord1.xy).x);
+                }
+
+            }
+
+        }
+
+    }
+
+    (var_s_0 = pow(var_s_0, ds_curve));
+    float4 var_col_0 = fn_6_24_get_color (const_table, varyings);
+    return consfn_vec4_vec3_float((((var_s_0 * var_col_0.rgb) * ds_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+float4 fn_6_23_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    float2 var_min_pos_0 = consfn_vec2_float_float(instances.ds_rect_pos.x, instances.ds_rect_pos.y);
+    float2 var_max_pos_0 = consfn_vec2_float_float((instances.ds_rect_pos.x + instances.ds_rect_size.x), (instances.ds_rect_pos.y - instances.ds_rect_size.y));
+    (varyings.ds_clipped = clamp(lerp(var_min_pos_0, var_max_pos_0, geometries.ds_geom_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw));
+    float2 var_normalized_0 = ((varyings.ds_clipped - var_min_pos_0) / consfn_vec2_float_float(instances.ds_rect_size.x, -instances.ds_rect_size.y));
+    (varyings.ds_tex_coord1 = lerp(instances.ds_font_t1.xy, instances.ds_font_t2.xy, var_normalized_0.xy));
+    (varyings.ds_tex_coord2 = lerp(instances.ds_font_t1.xy, (instances.ds_font_t1.xy + ((instances.ds_font_t2.xy - instances.ds_font_t1.xy) * const_table[0].x)), var_normalized_0.xy));
+    (varyings.ds_tex_coord3 = lerp(instances.ds_font_t1.xy, (instances.ds_font_t1.xy + ((instances.ds_font_t2.xy - instances.ds_font_t1.xy) * const_table[0].y)), var_normalized_0.xy));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, consfn_vec4_float_float_float_float(varyings.ds_clipped.x, varyings.ds_clipped.y, (instances.ds_char_depth + ds_draw_zbias), const_table[0].z))));
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float4(0.0,0.0,0.0,0.0), 0.0, 0.0, float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0), float2(0.0,0.0)};
+    varyings.ds_color = instances.ds_color;
+    varyings.ds_is_color = instances.ds_is_color;
+    varyings.ds_is_underline = instances.ds_is_underline;
+    varyings.position = fn_6_23_vertex(const_table, geometries, instances, varyings, , , );
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_6_25_pixel(const_table, varyings, , );
+}
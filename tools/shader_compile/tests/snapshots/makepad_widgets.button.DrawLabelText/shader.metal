@@ -0,0 +1,143 @@
+#include <metal_stdlib>
+using namespace metal;
+float4 sample2d(texture2d<float> tex, float2 pos){return tex.sample(sampler(mag_filter::nearest,min_filter::nearest),pos);}
+float4 sample2d_rt(texture2d<float> tex, float2 pos){return tex.sample(sampler(mag_filter::nearest,min_filter::nearest),pos);}
+struct LiveUniforms {
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_user {
+    float ds_brightness;
+    float ds_curve;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+    texture2d<float> ds_tex [[texture(0)]];    texture2d<float> ds_color_tex [[texture(1)]];};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    packed_float4 ds_color;
+    packed_float2 ds_font_t1;
+    packed_float2 ds_font_t2;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_char_depth;
+    packed_float2 ds_delta;
+    float ds_font_size;
+    float ds_advance;
+    float ds_char_offset;
+    float ds_char_count;
+    float ds_is_color;
+    float ds_is_rtl;
+    float ds_is_underline;
+    float ds_hover;
+    float ds_pressed;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_is_color;
+    float ds_is_underline;
+    float ds_hover;
+    float ds_pressed;
+    float2 ds_tex_coord1;
+    float2 ds_tex_coord2;
+    float2 ds_tex_coord3;
+    float2 ds_clipped;
+};
+float4 fn_9_26_get_color(constant const float *const_table, thread Varyings &varyings) {
+    return mix(mix(float4(const_table[20], const_table[21], const_table[22], const_table[23]), float4(const_table[24], const_table[25], const_table[26], const_table[27]), varyings.ds_hover), float4(const_table[28], const_table[29], const_table[30], const_table[31]), varyings.ds_pressed);
+}
+float4 fn_9_27_pixel(constant const float *const_table, thread Varyings &varyings, Textures textures, constant Uniforms_user &uniforms_user) {
+    if(varyings.ds_is_underline > const_table[3]) {
+            float4 var_col_0 = fn_9_26_get_color (const_table, varyings);
+        return float4(((var_col_0.rgb * uniforms_user.ds_brightness) * var_col_0.a), var_col_0.a);
+    }
+
+    if(varyings.ds_is_color > const_table[4]) {
+            float4 var_c_0 = sample2d(textures.ds_color_tex, varyings.ds_tex_coord1.xy);
+        return (float4((var_c_0.rgb * var_c_0.a), var_c_0.a) * fn_9_26_get_color (const_table, varyings).a);
+    }
+
+    float var_dx_0 = dfdx(float2((varyings.ds_tex_coord1.x * const_table[5]), const_table[6])).x;
+    float var_dp_0 = (const_table[7] / const_table[8]);
+    float var_s_0 = const_table[9];
+    if(var_dx_0 > const_table[10]) {
+            (var_s_0 = const_table[11]);
+    }else{
+            if(var_dx_0 > const_table[12]) {
+                    (var_s_0 = ((((sample2d_rt(textures.ds_tex, (varyings.ds_tex_coord3.xy + float2(const_table[13], const_table[14]))).z + sample2d_rt(textures.ds_tex, (varyings.ds_tex_coord3.xy + float2(var_dp_0, const_table[15]))).z) + sample2d_rt(textures.ds_tex, (varyings.ds_tex_coord3.xy + float2(const_table[16], var_dp_0))).z) + sample2d_rt(textures.ds_tex, (varyings.ds_tex_coord3.xy + float2(var_dp_0, var_dp_0))).z) * const_table[17]));
+        }else{
+                    if(var_dx_0 > const_table[18]) {
+                            (var_s_0 = sample2d_rt(textures.ds_tex, varyings.ds_tex_coord3.xy).z);
+            }else{
+                            if(var_dx_0 > const_table[19]) {
+                                    (var_s_0 = sample2d_rt(textures.ds_tex, varyings.ds_tex_coord2.xy).y);
+                }else{
+                                    (var_s_0 = sample2d_rt(textures.ds_tex, varyings.ds_tex_coord1.xy).x);
+                }
+
+            }
+
+        }
+
+    }
+
+    (var_s_0 = pow(var_s_0, uniforms_user.ds_curve));
+    float4 var_col_0 = fn_9_26_get_color (const_table, varyings);
+    return float4((((var_s_0 * var_col_0.rgb) * uniforms_user.ds_brightness) * var_col_0.a), (var_s_0 * var_col_0.a));
+}
+float4 fn_9_25_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_min_pos_0 = float2(instances.ds_rect_pos.x, instances.ds_rect_pos.y);
+    float2 var_max_pos_0 = float2((instances.ds_rect_pos.x + instances.ds_rect_size.x), (instances.ds_rect_pos.y - instances.ds_rect_size.y));
+    (varyings.ds_clipped = clamp(mix(var_min_pos_0, var_max_pos_0, geometries.ds_geom_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw));
+    float2 var_normalized_0 = ((varyings.ds_clipped - var_min_pos_0) / float2(instances.ds_rect_size.x, -instances.ds_rect_size.y));
+    (varyings.ds_tex_coord1 = mix(instances.ds_font_t1.xy, instances.ds_font_t2.xy, var_normalized_0.xy));
+    (varyings.ds_tex_coord2 = mix(instances.ds_font_t1.xy, (instances.ds_font_t1.xy + ((instances.ds_font_t2.xy - instances.ds_font_t1.xy) * const_table[0])), var_normalized_0.xy));
+    (varyings.ds_tex_coord3 = mix(instances.ds_font_t1.xy, (instances.ds_font_t1.xy + ((instances.ds_font_t2.xy - instances.ds_font_t1.xy) * const_table[1])), var_normalized_0.xy));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(varyings.ds_clipped.x, varyings.ds_clipped.y, (instances.ds_char_depth + uniforms_draw.ds_draw_zbias), const_table[2])))))));
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_user &uniforms_user [[buffer(6)]]
+, constant Uniforms_view &uniforms_view [[buffer(7)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_is_color = instances.ds_is_color;
+    varyings.ds_is_underline = instances.ds_is_underline;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_pressed = instances.ds_pressed;
+    varyings.position = fn_9_25_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_user &uniforms_user [[buffer(6)]]
+, constant Uniforms_view &uniforms_view [[buffer(7)]]
+) {
+    return     fn_9_27_pixel(const_table, varyings, textures, uniforms_user);
+}
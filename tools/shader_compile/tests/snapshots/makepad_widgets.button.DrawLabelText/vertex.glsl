@@ -0,0 +1,114 @@
+uniform float const_table[36];
+
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float user_table[2];
+float ds_brightness;
+float ds_curve;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+uniform sampler2D ds_tex;
+uniform sampler2D ds_color_tex;
+
+attribute vec2 packed_geometry_0;
+
+attribute vec4 packed_instance_0;
+attribute vec4 packed_instance_1;
+attribute vec4 packed_instance_2;
+attribute vec4 packed_instance_3;
+attribute vec4 packed_instance_4;
+attribute vec4 packed_instance_5;
+attribute vec4 packed_instance_6;
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+varying vec4 packed_varying_2;
+
+vec4 ds_color=vec4(0.0);
+vec2 ds_font_t1=vec2(0.0);
+vec2 ds_font_t2=vec2(0.0);
+vec2 ds_rect_pos=vec2(0.0);
+vec2 ds_rect_size=vec2(0.0);
+vec4 ds_draw_clip=vec4(0.0);
+float ds_char_depth=0.0;
+vec2 ds_delta=vec2(0.0);
+float ds_font_size=0.0;
+float ds_advance=0.0;
+float ds_char_offset=0.0;
+float ds_char_count=0.0;
+float ds_is_color=0.0;
+float ds_is_rtl=0.0;
+float ds_is_underline=0.0;
+float ds_hover=0.0;
+float ds_pressed=0.0;
+vec2 ds_geom_pos=vec2(0.0);
+vec2 ds_tex_coord1=vec2(0.0);
+vec2 ds_tex_coord2=vec2(0.0);
+vec2 ds_tex_coord3=vec2(0.0);
+vec2 ds_clipped=vec2(0.0);
+
+vec4 fn_9_25_vertex() {
+    vec2 var_min_pos_0 = vec2(ds_rect_pos.x, ds_rect_pos.y);
+    vec2 var_max_pos_0 = vec2((ds_rect_pos.x + ds_rect_size.x), (ds_rect_pos.y - ds_rect_size.y));
+    (ds_clipped = clamp(mix(var_min_pos_0, var_max_pos_0, ds_geom_pos), ds_draw_clip.xy, ds_draw_clip.zw));
+    vec2 var_normalized_0 = ((ds_clipped - var_min_pos_0) / vec2(ds_rect_size.x, -ds_rect_size.y));
+    (ds_tex_coord1 = mix(ds_font_t1.xy, ds_font_t2.xy, var_normalized_0.xy));
+    (ds_tex_coord2 = mix(ds_font_t1.xy, (ds_font_t1.xy + ((ds_font_t2.xy - ds_font_t1.xy) * const_table[0])), var_normalized_0.xy));
+    (ds_tex_coord3 = mix(ds_font_t1.xy, (ds_font_t1.xy + ((ds_font_t2.xy - ds_font_t1.xy) * const_table[1])), var_normalized_0.xy));
+    return (ds_camera_projection * (ds_camera_view * (ds_view_transform * vec4(ds_clipped.x, ds_clipped.y, (ds_char_depth + ds_draw_zbias), const_table[2]))));
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_brightness = user_table[0];
+    ds_curve = user_table[1];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_geom_pos.xy = packed_geometry_0.xy;
+    ds_color.xyzw = packed_instance_0.xyzw;
+    ds_font_t1.xy = packed_instance_1.xy;
+    ds_font_t2.xy = packed_instance_1.zw;
+    ds_rect_pos.xy = packed_instance_2.xy;
+    ds_rect_size.xy = packed_instance_2.zw;
+    ds_draw_clip.xyzw = packed_instance_3.xyzw;
+    ds_char_depth = packed_instance_4.x;
+    ds_delta.xy = packed_instance_4.yz;
+    ds_font_size = packed_instance_4.w;
+    ds_advance = packed_instance_5.x;
+    ds_char_offset = packed_instance_5.y;
+    ds_char_count = packed_instance_5.z;
+    ds_is_color = packed_instance_5.w;
+    ds_is_rtl = packed_instance_6.x;
+    ds_is_underline = packed_instance_6.y;
+    ds_hover = packed_instance_6.z;
+    ds_pressed = packed_instance_6.w;
+
+    gl_Position = fn_9_25_vertex();
+
+    packed_varying_0.x = ds_is_color;
+    packed_varying_0.y = ds_is_underline;
+    packed_varying_0.z = ds_hover;
+    packed_varying_0.w = ds_pressed;
+    packed_varying_1.xy = ds_tex_coord1.xy;
+    packed_varying_1.zw = ds_tex_coord2.xy;
+    packed_varying_2.xy = ds_tex_coord3.xy;
+    packed_varying_2.zw = ds_clipped.xy;
+}
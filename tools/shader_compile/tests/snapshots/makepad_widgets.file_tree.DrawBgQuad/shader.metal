@@ -0,0 +1,88 @@
+#include <metal_stdlib>
+using namespace metal;
+struct LiveUniforms {
+    float4 live_47_60;
+    float4 live_47_65;
+    float4 live_47_70;
+    float4 live_47_73;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    float ds_is_even;
+    float ds_scale;
+    float ds_is_folder;
+    float ds_focussed;
+    float ds_selected;
+    float ds_hover;
+    float ds_opened;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_is_even;
+    float ds_focussed;
+    float ds_selected;
+    float2 ds_pos;
+};
+float4 fn_19_14_pixel(constant const float *const_table, thread Varyings &varyings, constant LiveUniforms &live_uniforms) {
+    return mix(mix(live_uniforms.live_47_60, live_uniforms.live_47_65, varyings.ds_is_even), mix(live_uniforms.live_47_73, live_uniforms.live_47_70, varyings.ds_focussed), varyings.ds_selected);
+}
+float4 fn_19_11_clip_and_transform_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * instances.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / instances.ds_rect_size));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_19_13_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_19_11_clip_and_transform_vertex (const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_is_even = instances.ds_is_even;
+    varyings.ds_focussed = instances.ds_focussed;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.position = fn_19_13_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_19_14_pixel(const_table, varyings, live_uniforms);
+}
@@ -0,0 +1,132 @@
+uniform float const_table[44];
+
+uniform float live_table[8];
+vec4 live_47_60;
+vec4 live_47_70;
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec2 packed_varying_1;
+
+vec2 ds_rect_size=vec2(0.0);
+float ds_selected=0.0;
+float ds_hover=0.0;
+vec2 ds_pos=vec2(0.0);
+struct struct_7_27 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+void fn_7_66_line_to(inout struct_7_27 var_self_0, float var_x_0, float var_y_0) {
+    vec2 var_p_0 = vec2(var_x_0, var_y_0);
+    vec2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    vec2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[37], const_table[38]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[39]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+
+void fn_7_65_move_to(inout struct_7_27 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = vec2(var_x_0, var_y_0)));
+}
+
+float fn_7_46_calc_blur(inout struct_7_27 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[31], const_table[32]);
+    float var_wb_0 = const_table[33];
+    if(var_self_0.f_blur > const_table[34]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[35], const_table[36]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_7_49_stroke_keep(inout struct_7_27 var_self_0, vec4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)));
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    vec4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[30] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+
+vec4 fn_7_50_stroke(inout struct_7_27 var_self_0, vec4 var_color_0, float var_width_0) {
+    fn_7_49_stroke_keep (var_self_0, var_color_0, var_width_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[27]));
+    (var_self_0.f_clip = -const_table[28]);
+    (var_self_0.f_has_clip = const_table[29]);
+    return var_self_0.f_result;
+}
+
+void fn_7_45_clear(inout struct_7_27 var_self_0, vec4 var_color_0) {
+    (var_self_0.f_result = vec4(((var_color_0.rgb * var_color_0.a) + (var_self_0.f_result.rgb * (const_table[26] - var_color_0.a))), var_color_0.a));
+}
+
+float fn_7_40_antialias(vec2 var_p_0) {
+    return (const_table[25] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_7_27 fn_7_41_viewport(vec2 var_pos_0) {
+    return struct_7_27(var_pos_0,vec4(const_table[15]),vec2(const_table[16]),vec2(const_table[17]),const_table[18],-const_table[19],const_table[20],const_table[21],const_table[22],fn_7_40_antialias (var_pos_0),const_table[23],const_table[24]);
+}
+
+vec4 fn_32_14_pixel() {
+    struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+    fn_7_45_clear (var_sdf_0, mix(live_47_60, live_47_70, ds_hover));
+    float var_sz_0 = const_table[1];
+    float var_dx_0 = const_table[2];
+    vec2 var_c_0 = vec2(const_table[3], (const_table[4] * ds_rect_size.y));
+    fn_7_65_move_to (var_sdf_0, ((var_c_0.x - var_sz_0) + (var_dx_0 * const_table[5])), ((var_c_0.y - var_sz_0) + var_dx_0));
+    fn_7_66_line_to (var_sdf_0, var_c_0.x, (var_c_0.y + var_sz_0));
+    fn_7_66_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0));
+    fn_7_50_stroke (var_sdf_0, mix(vec4(const_table[6], const_table[7], const_table[8], const_table[9]), vec4(const_table[10], const_table[11], const_table[12], const_table[13]), ds_selected), const_table[14]);
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    live_47_60 = vec4(live_table[0], live_table[1], live_table[2], live_table[3]);
+    live_47_70 = vec4(live_table[4], live_table[5], live_table[6], live_table[7]);
+    ds_rect_size.xy = packed_varying_0.xy;
+    ds_selected = packed_varying_0.z;
+    ds_hover = packed_varying_0.w;
+    ds_pos.xy = packed_varying_1.xy;
+
+    gl_FragColor = fn_32_14_pixel();
+}
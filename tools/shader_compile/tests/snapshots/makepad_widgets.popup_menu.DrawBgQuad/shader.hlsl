@@ -0,0 +1,130 @@
+struct struct_7_27 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+cbuffer LiveUniforms : register(b0) {
+    float4 live_47_60;
+    float4 live_47_70;
+};
+cbuffer ConstTable : register(b1){float4 const_table[11];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float2 ds_rect_pos: INSTA;
+    float2 ds_rect_size: INSTB;
+    float4 ds_draw_clip: INSTC;
+    float ds_draw_depth: INSTD;
+    float ds_0: INSTE;
+    float ds_selected: INSTF;
+    float ds_hover: INSTG;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float2 ds_rect_size: VARYA;
+    float ds_selected: VARYB;
+    float ds_hover: VARYC;
+    float2 ds_pos: VARYD;
+};
+float2 consfn_vec2_float_float(float x0, , float x1) {    return float2(x0, x1);}void fn_7_66_line_to(inout struct_7_27 var_self_0, float var_x_0, float var_y_0) {
+    float2 var_p_0 = consfn_vec2_float_float(var_x_0, var_y_0);
+    float2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    float2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[9].y, const_table[9].z);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[9].w);
+    (var_self_0.f_last_pos = var_p_0);
+}
+void fn_7_65_move_to(inout struct_7_27 var_self_0, float var_x_0, float var_y_0) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = consfn_vec2_float_float(var_x_0, var_y_0)));
+}
+float fn_7_46_calc_blur(inout struct_7_27 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[7].w, const_table[8].x);
+    float var_wb_0 = const_table[8].y;
+    if(var_self_0.f_blur > const_table[8].z) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[8].w, const_table[9].x));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_7_49_stroke_keep(inout struct_7_27 var_self_0, float4 var_color_0, float var_width_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[7].z - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_7_50_stroke(inout struct_7_27 var_self_0, float4 var_color_0, float var_width_0) {
+    fn_7_49_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[6].w));
+    (var_self_0.f_clip = -const_table[7].x);
+    (var_self_0.f_has_clip = const_table[7].y);
+    return var_self_0.f_result;
+}
+void fn_7_45_clear(inout struct_7_27 var_self_0, float4 var_color_0) {
+    (var_self_0.f_result = consfn_vec4_vec3_float(((var_color_0.rgb * var_color_0.a) + (var_self_0.f_result.rgb * (const_table[6].z - var_color_0.a))), var_color_0.a));
+}
+float fn_7_40_antialias(float2 var_p_0) {
+    return (const_table[6].y / length(consfn_vec2_float_float(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_7_27 fn_7_41_viewport(float2 var_pos_0) {
+    return struct_7_27{var_pos_0,consfn_vec4_float(const_table[3].w),consfn_vec2_float(const_table[4].x),consfn_vec2_float(const_table[4].y),const_table[4].z,-const_table[4].w,const_table[5].x,const_table[5].y,const_table[5].z,fn_7_40_antialias (var_pos_0, const_table),const_table[5].w,const_table[6].x};
+}
+float4 fn_32_14_pixel(inout Varyings varyings, ) {
+    struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    fn_7_45_clear (var_sdf_0, lerp(live_47_60, live_47_70, varyings.ds_hover), const_table);
+    float var_sz_0 = const_table[0].y;
+    float var_dx_0 = const_table[0].z;
+    float2 var_c_0 = consfn_vec2_float_float(const_table[0].w, (const_table[1].x * varyings.ds_rect_size.y));
+    fn_7_65_move_to (var_sdf_0, ((var_c_0.x - var_sz_0) + (var_dx_0 * const_table[1].y)), ((var_c_0.y - var_sz_0) + var_dx_0), const_table);
+    fn_7_66_line_to (var_sdf_0, var_c_0.x, (var_c_0.y + var_sz_0), const_table);
+    fn_7_66_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0), const_table);
+    fn_7_50_stroke (var_sdf_0, lerp(float4(const_table[1].z, const_table[1].w, const_table[2].x, const_table[2].y), float4(const_table[2].z, const_table[2].w, const_table[3].x, const_table[3].y), varyings.ds_selected), const_table[3].z, const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_32_11_clip_and_transform_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * varyings.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / varyings.ds_rect_size));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, consfn_vec4_float_float_float_float(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_32_13_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    return fn_32_11_clip_and_transform_vertex (const_table, geometries, instances, varyings, , , );
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), float2(0.0,0.0), 0.0, 0.0, float2(0.0,0.0)};
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.position = fn_32_13_vertex(const_table, geometries, instances, varyings, , , );
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_32_14_pixel(const_table, varyings, );
+}
@@ -0,0 +1,152 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_7_27 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+    float4 live_47_60;
+    float4 live_47_70;
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    float ds_selected;
+    float ds_hover;
+};
+struct Varyings {
+    float4 position [[position]];
+    float2 ds_rect_size;
+    float ds_selected;
+    float ds_hover;
+    float2 ds_pos;
+};
+void fn_7_66_line_to(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    float2 var_p_0 = float2(var_x_0, var_y_0);
+    float2 var_pa_0 = (var_self_0.f_pos - var_self_0.f_last_pos);
+    float2 var_ba_0 = (var_p_0 - var_self_0.f_last_pos);
+    float var_h_0 = clamp((dot(var_pa_0, var_ba_0) / dot(var_ba_0, var_ba_0)), const_table[37], const_table[38]);
+    float var_s_0 = sign(((var_pa_0.x * var_ba_0.y) - (var_pa_0.y * var_ba_0.x)));
+    (var_self_0.f_dist = (length((var_pa_0 - (var_ba_0 * var_h_0))) / var_self_0.f_scale_factor));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+    (var_self_0.f_clip = max(var_self_0.f_clip, (var_self_0.f_dist * var_s_0)));
+    (var_self_0.f_has_clip = const_table[39]);
+    (var_self_0.f_last_pos = var_p_0);
+}
+void fn_7_65_move_to(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, constant const float *const_table) {
+    (var_self_0.f_last_pos = (var_self_0.f_start_pos = float2(var_x_0, var_y_0)));
+}
+float fn_7_46_calc_blur(thread struct_7_27 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[31], const_table[32]);
+    float var_wb_0 = const_table[33];
+    if(var_self_0.f_blur > const_table[34]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[35], const_table[36]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_7_49_stroke_keep(thread struct_7_27 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, (abs(var_self_0.f_shape) - (var_width_0 / var_self_0.f_scale_factor)), const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    float4 var_dest_0 = var_self_0.f_result;
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_dest_0 * (const_table[30] - (var_source_0.a * var_f_0)))));
+    return var_self_0.f_result;
+}
+float4 fn_7_50_stroke(thread struct_7_27 & var_self_0, float4 var_color_0, float var_width_0, constant const float *const_table) {
+    fn_7_49_stroke_keep (var_self_0, var_color_0, var_width_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[27]));
+    (var_self_0.f_clip = -const_table[28]);
+    (var_self_0.f_has_clip = const_table[29]);
+    return var_self_0.f_result;
+}
+void fn_7_45_clear(thread struct_7_27 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    (var_self_0.f_result = float4(((var_color_0.rgb * var_color_0.a) + (var_self_0.f_result.rgb * (const_table[26] - var_color_0.a))), var_color_0.a));
+}
+float fn_7_40_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[25] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_7_27  fn_7_41_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_7_27{var_pos_0,float4(const_table[15]),float2(const_table[16]),float2(const_table[17]),const_table[18],-const_table[19],const_table[20],const_table[21],const_table[22],fn_7_40_antialias (var_pos_0, const_table),const_table[23],const_table[24]};
+}
+float4 fn_32_14_pixel(constant const float *const_table, thread Varyings &varyings, constant LiveUniforms &live_uniforms) {
+    struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    fn_7_45_clear (var_sdf_0, mix(live_uniforms.live_47_60, live_uniforms.live_47_70, varyings.ds_hover), const_table);
+    float var_sz_0 = const_table[1];
+    float var_dx_0 = const_table[2];
+    float2 var_c_0 = float2(const_table[3], (const_table[4] * varyings.ds_rect_size.y));
+    fn_7_65_move_to (var_sdf_0, ((var_c_0.x - var_sz_0) + (var_dx_0 * const_table[5])), ((var_c_0.y - var_sz_0) + var_dx_0), const_table);
+    fn_7_66_line_to (var_sdf_0, var_c_0.x, (var_c_0.y + var_sz_0), const_table);
+    fn_7_66_line_to (var_sdf_0, (var_c_0.x + var_sz_0), (var_c_0.y - var_sz_0), const_table);
+    fn_7_50_stroke (var_sdf_0, mix(float4(const_table[6], const_table[7], const_table[8], const_table[9]), float4(const_table[10], const_table[11], const_table[12], const_table[13]), varyings.ds_selected), const_table[14], const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_32_11_clip_and_transform_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * varyings.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / varyings.ds_rect_size));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_32_13_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_32_11_clip_and_transform_vertex (const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_selected = instances.ds_selected;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.position = fn_32_13_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_32_14_pixel(const_table, varyings, live_uniforms);
+}
@@ -0,0 +1,132 @@
+uniform float const_table[84];
+
+uniform float draw_table[1];
+float ds_draw_zbias;
+
+uniform float pass_table[50];
+mat4 ds_camera_projection;
+mat4 ds_camera_view;
+mat4 ds_camera_inv;
+float ds_dpi_factor;
+float ds_dpi_dilate;
+
+uniform float view_table[16];
+mat4 ds_view_transform;
+
+
+
+
+varying vec4 packed_varying_0;
+varying vec4 packed_varying_1;
+
+float ds_hover=0.0;
+float ds_focus=0.0;
+float ds_drag=0.0;
+vec2 ds_rect_size=vec2(0.0);
+float ds_slide_pos=0.0;
+vec2 ds_pos=vec2(0.0);
+struct struct_7_27 {
+    vec2 f_pos;
+    vec4 f_result;
+    vec2 f_last_pos;
+    vec2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+
+void fn_7_63_rect(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    vec2 var_s_0 = (vec2(var_w_0, var_h_0) * const_table[77]);
+    vec2 var_d_0 = (abs(((vec2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    vec2 var_dm_0 = min(var_d_0, vec2(const_table[78], const_table[79]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, vec2(const_table[80], const_table[81])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+
+float fn_7_46_calc_blur(inout struct_7_27 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[71], const_table[72]);
+    float var_wb_0 = const_table[73];
+    if(var_self_0.f_blur > const_table[74]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[75], const_table[76]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+
+vec4 fn_7_47_fill_keep(inout struct_7_27 var_self_0, vec4 var_color_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, var_self_0.f_shape);
+    vec4 var_source_0 = vec4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[67] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[68]) {
+            float var_f2_0 = (const_table[69] - fn_7_46_calc_blur (var_self_0, -var_self_0.f_clip));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[70] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+
+vec4 fn_7_48_fill(inout struct_7_27 var_self_0, vec4 var_color_0) {
+    fn_7_47_fill_keep (var_self_0, var_color_0);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[64]));
+    (var_self_0.f_clip = -const_table[65]);
+    (var_self_0.f_has_clip = const_table[66]);
+    return var_self_0.f_result;
+}
+
+float fn_7_40_antialias(vec2 var_p_0) {
+    return (const_table[63] / length(vec2(length(dFdx(var_p_0)), length(dFdy(var_p_0)))));
+}
+
+struct_7_27 fn_7_41_viewport(vec2 var_pos_0) {
+    return struct_7_27(var_pos_0,vec4(const_table[53]),vec2(const_table[54]),vec2(const_table[55]),const_table[56],-const_table[57],const_table[58],const_table[59],const_table[60],fn_7_40_antialias (var_pos_0),const_table[61],const_table[62]);
+}
+
+vec4 fn_38_13_pixel() {
+    float var_slider_height_0 = const_table[1];
+    float var_nub_size_0 = mix(const_table[2], const_table[3], ds_hover);
+    float var_nubbg_size_0 = const_table[4];
+    struct_7_27 var_sdf_0 = fn_7_41_viewport ((ds_pos * ds_rect_size));
+    vec4 var_slider_bg_color_0 = mix(vec4(const_table[5], const_table[6], const_table[7], const_table[8]), vec4(const_table[9], const_table[10], const_table[11], const_table[12]), ds_focus);
+    vec4 var_slider_color_0 = mix(mix(vec4(const_table[13], const_table[14], const_table[15], const_table[16]), vec4(const_table[17], const_table[18], const_table[19], const_table[20]), ds_hover), vec4(const_table[21], const_table[22], const_table[23], const_table[24]), ds_focus);
+    vec4 var_nub_color_0 = mix(mix(vec4(const_table[25], const_table[26], const_table[27], const_table[28]), vec4(const_table[29], const_table[30], const_table[31], const_table[32]), ds_hover), mix(vec4(const_table[33], const_table[34], const_table[35], const_table[36]), vec4(const_table[37], const_table[38], const_table[39], const_table[40]), ds_drag), ds_focus);
+    vec4 var_nubbg_color_0 = mix(vec4(const_table[41], const_table[42], const_table[43], const_table[44]), vec4(const_table[45], const_table[46], const_table[47], const_table[48]), ds_drag);
+    fn_7_63_rect (var_sdf_0, const_table[49], (ds_rect_size.y - var_slider_height_0), ds_rect_size.x, var_slider_height_0);
+    fn_7_48_fill (var_sdf_0, var_slider_bg_color_0);
+    fn_7_63_rect (var_sdf_0, const_table[50], (ds_rect_size.y - var_slider_height_0), ((ds_slide_pos * (ds_rect_size.x - var_nub_size_0)) + var_nub_size_0), var_slider_height_0);
+    fn_7_48_fill (var_sdf_0, var_slider_color_0);
+    float var_nubbg_x_0 = (((ds_slide_pos * (ds_rect_size.x - var_nub_size_0)) - (var_nubbg_size_0 * const_table[51])) + (const_table[52] * var_nub_size_0));
+    fn_7_63_rect (var_sdf_0, var_nubbg_x_0, (ds_rect_size.y - var_slider_height_0), var_nubbg_size_0, var_slider_height_0);
+    fn_7_48_fill (var_sdf_0, var_nubbg_color_0);
+    float var_nub_x_0 = (ds_slide_pos * (ds_rect_size.x - var_nub_size_0));
+    fn_7_63_rect (var_sdf_0, var_nub_x_0, (ds_rect_size.y - var_slider_height_0), var_nub_size_0, var_slider_height_0);
+    fn_7_48_fill (var_sdf_0, var_nub_color_0);
+    return var_sdf_0.f_result;
+}
+
+void main() {
+    ds_draw_zbias = draw_table[0];
+
+    ds_camera_projection = mat4(pass_table[0], pass_table[1], pass_table[2], pass_table[3], pass_table[4], pass_table[5], pass_table[6], pass_table[7], pass_table[8], pass_table[9], pass_table[10], pass_table[11], pass_table[12], pass_table[13], pass_table[14], pass_table[15]);
+    ds_camera_view = mat4(pass_table[16], pass_table[17], pass_table[18], pass_table[19], pass_table[20], pass_table[21], pass_table[22], pass_table[23], pass_table[24], pass_table[25], pass_table[26], pass_table[27], pass_table[28], pass_table[29], pass_table[30], pass_table[31]);
+    ds_camera_inv = mat4(pass_table[32], pass_table[33], pass_table[34], pass_table[35], pass_table[36], pass_table[37], pass_table[38], pass_table[39], pass_table[40], pass_table[41], pass_table[42], pass_table[43], pass_table[44], pass_table[45], pass_table[46], pass_table[47]);
+    ds_dpi_factor = pass_table[48];
+    ds_dpi_dilate = pass_table[49];
+
+    ds_view_transform = mat4(view_table[0], view_table[1], view_table[2], view_table[3], view_table[4], view_table[5], view_table[6], view_table[7], view_table[8], view_table[9], view_table[10], view_table[11], view_table[12], view_table[13], view_table[14], view_table[15]);
+
+    ds_hover = packed_varying_0.x;
+    ds_focus = packed_varying_0.y;
+    ds_drag = packed_varying_0.z;
+    ds_rect_size.x = packed_varying_0.w;
+    ds_rect_size.y = packed_varying_1.x;
+    ds_slide_pos = packed_varying_1.y;
+    ds_pos.xy = packed_varying_1.zw;
+
+    gl_FragColor = fn_38_13_pixel();
+}
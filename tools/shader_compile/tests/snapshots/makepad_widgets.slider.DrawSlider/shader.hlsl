@@ -0,0 +1,136 @@
+struct struct_7_27 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+cbuffer LiveUniforms : register(b0) {
+};
+cbuffer ConstTable : register(b1){float4 const_table[21];};
+cbuffer Uniforms_draw : register(b2) {
+    float ds_draw_zbias;
+};
+cbuffer Uniforms_pass : register(b3) {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+cbuffer Uniforms_view : register(b4) {
+    float4x4 ds_view_transform;
+};
+struct Geometries {
+    float2 ds_geom_pos: GEOMA;
+};
+struct Instances {
+    float ds_hover: INSTA;
+    float ds_focus: INSTB;
+    float ds_drag: INSTC;
+    float2 ds_rect_pos: INSTD;
+    float2 ds_rect_size: INSTE;
+    float4 ds_draw_clip: INSTF;
+    float ds_draw_depth: INSTG;
+    float ds_0: INSTH;
+    float ds_slide_pos: INSTI;
+};
+struct Varyings {
+    float4 position: SV_POSITION;
+    float ds_hover: VARYA;
+    float ds_focus: VARYB;
+    float ds_drag: VARYC;
+    float2 ds_rect_size: VARYD;
+    float ds_slide_pos: VARYE;
+    float2 ds_pos: VARYF;
+};
+void fn_7_63_rect(inout struct_7_27 var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0) {
+    float2 var_s_0 = (consfn_vec2_float_float(var_w_0, var_h_0) * const_table[19].y);
+    float2 var_d_0 = (abs(((consfn_vec2_float_float(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, consfn_vec2_float_float(const_table[19].z, const_table[19].w));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, consfn_vec2_float_float(const_table[20].x, const_table[20].y)))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_7_46_calc_blur(inout struct_7_27 var_self_0, float var_w_0) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[17].w, const_table[18].x);
+    float var_wb_0 = const_table[18].y;
+    if(var_self_0.f_blur > const_table[18].z) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[18].w, const_table[19].x));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_7_47_fill_keep(inout struct_7_27 var_self_0, float4 var_color_0) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = consfn_vec4_vec3_float((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[16].w - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[17].x) {
+            float var_f2_0 = (const_table[17].y - fn_7_46_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[17].z - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_7_48_fill(inout struct_7_27 var_self_0, float4 var_color_0) {
+    fn_7_47_fill_keep (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[16].x));
+    (var_self_0.f_clip = -const_table[16].y);
+    (var_self_0.f_has_clip = const_table[16].z);
+    return var_self_0.f_result;
+}
+float fn_7_40_antialias(float2 var_p_0) {
+    return (const_table[15].w / length(consfn_vec2_float_float(length(ddx(var_p_0)), length(ddy(var_p_0)))));
+}
+struct_7_27 fn_7_41_viewport(float2 var_pos_0) {
+    return struct_7_27{var_pos_0,consfn_vec4_float(const_table[13].y),consfn_vec2_float(const_table[13].z),consfn_vec2_float(const_table[13].w),const_table[14].x,-const_table[14].y,const_table[14].z,const_table[14].w,const_table[15].x,fn_7_40_antialias (var_pos_0, const_table),const_table[15].y,const_table[15].z};
+}
+float4 fn_38_13_pixel(inout Varyings varyings) {
+    float var_slider_height_0 = const_table[0].y;
+    float var_nub_size_0 = lerp(const_table[0].z, const_table[0].w, varyings.ds_hover);
+    float var_nubbg_size_0 = const_table[1].x;
+    struct_7_27 var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    float4 var_slider_bg_color_0 = lerp(float4(const_table[1].y, const_table[1].z, const_table[1].w, const_table[2].x), float4(const_table[2].y, const_table[2].z, const_table[2].w, const_table[3].x), varyings.ds_focus);
+    float4 var_slider_color_0 = lerp(lerp(float4(const_table[3].y, const_table[3].z, const_table[3].w, const_table[4].x), float4(const_table[4].y, const_table[4].z, const_table[4].w, const_table[5].x), varyings.ds_hover), float4(const_table[5].y, const_table[5].z, const_table[5].w, const_table[6].x), varyings.ds_focus);
+    float4 var_nub_color_0 = lerp(lerp(float4(const_table[6].y, const_table[6].z, const_table[6].w, const_table[7].x), float4(const_table[7].y, const_table[7].z, const_table[7].w, const_table[8].x), varyings.ds_hover), lerp(float4(const_table[8].y, const_table[8].z, const_table[8].w, const_table[9].x), float4(const_table[9].y, const_table[9].z, const_table[9].w, const_table[10].x), varyings.ds_drag), varyings.ds_focus);
+    float4 var_nubbg_color_0 = lerp(float4(const_table[10].y, const_table[10].z, const_table[10].w, const_table[11].x), float4(const_table[11].y, const_table[11].z, const_table[11].w, const_table[12].x), varyings.ds_drag);
+    fn_7_63_rect (var_sdf_0, const_table[12].y, (varyings.ds_rect_size.y - var_slider_height_0), varyings.ds_rect_size.x, var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_slider_bg_color_0, const_table);
+    fn_7_63_rect (var_sdf_0, const_table[12].z, (varyings.ds_rect_size.y - var_slider_height_0), ((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) + var_nub_size_0), var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_slider_color_0, const_table);
+    float var_nubbg_x_0 = (((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) - (var_nubbg_size_0 * const_table[12].w)) + (const_table[13].x * var_nub_size_0));
+    fn_7_63_rect (var_sdf_0, var_nubbg_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nubbg_size_0, var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_nubbg_color_0, const_table);
+    float var_nub_x_0 = (varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0));
+    fn_7_63_rect (var_sdf_0, var_nub_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nub_size_0, var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_nub_color_0, const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_38_10_clip_and_transform_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * varyings.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / varyings.ds_rect_size));
+    return mul(ds_camera_projection, mul(ds_camera_view, mul(ds_view_transform, consfn_vec4_float_float_float_float(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + ds_draw_zbias), const_table[0].x))));
+}
+float4 fn_38_12_vertex(in Geometries geometries, in Instances instances, inout Varyings varyings, , , ) {
+    return fn_38_10_clip_and_transform_vertex (const_table, geometries, instances, varyings, , , );
+}
+Varyings vertex_main(Geometries geometries, Instances instances, uint inst_id: SV_InstanceID) {
+    Varyings varyings = {float4(0.0,0.0,0.0,0.0), 0.0, 0.0, 0.0, float2(0.0,0.0), 0.0, float2(0.0,0.0)};
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_focus = instances.ds_focus;
+    varyings.ds_drag = instances.ds_drag;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_slide_pos = instances.ds_slide_pos;
+    varyings.position = fn_38_12_vertex(const_table, geometries, instances, varyings, , , );
+    return varyings;
+}
+float4 pixel_main(Varyings varyings) : SV_TARGET{
+    return     fn_38_13_pixel(const_table, varyings);
+}
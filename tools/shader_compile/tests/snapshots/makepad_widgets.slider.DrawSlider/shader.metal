@@ -0,0 +1,158 @@
+#include <metal_stdlib>
+using namespace metal;
+struct struct_7_27 {
+    float2 f_pos;
+    float4 f_result;
+    float2 f_last_pos;
+    float2 f_start_pos;
+    float f_shape;
+    float f_clip;
+    float f_has_clip;
+    float f_old_shape;
+    float f_blur;
+    float f_aa;
+    float f_scale_factor;
+    float f_dist;
+};
+struct LiveUniforms {
+};
+struct Uniforms_draw {
+    float ds_draw_zbias;
+};
+struct Uniforms_pass {
+    float4x4 ds_camera_projection;
+    float4x4 ds_camera_view;
+    float4x4 ds_camera_inv;
+    float ds_dpi_factor;
+    float ds_dpi_dilate;
+};
+struct Uniforms_view {
+    float4x4 ds_view_transform;
+};
+struct Textures {
+};
+struct Geometries {
+    packed_float2 ds_geom_pos;
+};
+struct Instances {
+    float ds_hover;
+    float ds_focus;
+    float ds_drag;
+    packed_float2 ds_rect_pos;
+    packed_float2 ds_rect_size;
+    packed_float4 ds_draw_clip;
+    float ds_draw_depth;
+    float pad_0;
+    float ds_slide_pos;
+};
+struct Varyings {
+    float4 position [[position]];
+    float ds_hover;
+    float ds_focus;
+    float ds_drag;
+    float2 ds_rect_size;
+    float ds_slide_pos;
+    float2 ds_pos;
+};
+void fn_7_63_rect(thread struct_7_27 & var_self_0, float var_x_0, float var_y_0, float var_w_0, float var_h_0, constant const float *const_table) {
+    float2 var_s_0 = (float2(var_w_0, var_h_0) * const_table[77]);
+    float2 var_d_0 = (abs(((float2(var_x_0, var_y_0) - var_self_0.f_pos) + var_s_0)) - var_s_0);
+    float2 var_dm_0 = min(var_d_0, float2(const_table[78], const_table[79]));
+    (var_self_0.f_dist = (max(var_dm_0.x, var_dm_0.y) + length(max(var_d_0, float2(const_table[80], const_table[81])))));
+    (var_self_0.f_old_shape = var_self_0.f_shape);
+    (var_self_0.f_shape = min(var_self_0.f_shape, var_self_0.f_dist));
+}
+float fn_7_46_calc_blur(thread struct_7_27 & var_self_0, float var_w_0, constant const float *const_table) {
+    float var_wa_0 = clamp((-var_w_0 * var_self_0.f_aa), const_table[71], const_table[72]);
+    float var_wb_0 = const_table[73];
+    if(var_self_0.f_blur > const_table[74]) {
+            (var_wb_0 = clamp((-var_w_0 / var_self_0.f_blur), const_table[75], const_table[76]));
+    }
+
+    return (var_wa_0 * var_wb_0);
+}
+float4 fn_7_47_fill_keep(thread struct_7_27 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    float var_f_0 = fn_7_46_calc_blur (var_self_0, var_self_0.f_shape, const_table);
+    float4 var_source_0 = float4((var_color_0.rgb * var_color_0.a), var_color_0.a);
+    (var_self_0.f_result = ((var_source_0 * var_f_0) + (var_self_0.f_result * (const_table[67] - (var_source_0.a * var_f_0)))));
+    if(var_self_0.f_has_clip > const_table[68]) {
+            float var_f2_0 = (const_table[69] - fn_7_46_calc_blur (var_self_0, -var_self_0.f_clip, const_table));
+        (var_self_0.f_result = ((var_source_0 * var_f2_0) + (var_self_0.f_result * (const_table[70] - (var_source_0.a * var_f2_0)))));
+    }
+
+    return var_self_0.f_result;
+}
+float4 fn_7_48_fill(thread struct_7_27 & var_self_0, float4 var_color_0, constant const float *const_table) {
+    fn_7_47_fill_keep (var_self_0, var_color_0, const_table);
+    (var_self_0.f_old_shape = (var_self_0.f_shape = const_table[64]));
+    (var_self_0.f_clip = -const_table[65]);
+    (var_self_0.f_has_clip = const_table[66]);
+    return var_self_0.f_result;
+}
+float fn_7_40_antialias(float2 var_p_0, constant const float *const_table) {
+    return (const_table[63] / length(float2(length(dfdx(var_p_0)), length(dfdy(var_p_0)))));
+}
+struct_7_27  fn_7_41_viewport(float2 var_pos_0, constant const float *const_table) {
+    return struct_7_27{var_pos_0,float4(const_table[53]),float2(const_table[54]),float2(const_table[55]),const_table[56],-const_table[57],const_table[58],const_table[59],const_table[60],fn_7_40_antialias (var_pos_0, const_table),const_table[61],const_table[62]};
+}
+float4 fn_38_13_pixel(constant const float *const_table, thread Varyings &varyings) {
+    float var_slider_height_0 = const_table[1];
+    float var_nub_size_0 = mix(const_table[2], const_table[3], varyings.ds_hover);
+    float var_nubbg_size_0 = const_table[4];
+    struct_7_27  var_sdf_0 = fn_7_41_viewport ((varyings.ds_pos * varyings.ds_rect_size), const_table);
+    float4 var_slider_bg_color_0 = mix(float4(const_table[5], const_table[6], const_table[7], const_table[8]), float4(const_table[9], const_table[10], const_table[11], const_table[12]), varyings.ds_focus);
+    float4 var_slider_color_0 = mix(mix(float4(const_table[13], const_table[14], const_table[15], const_table[16]), float4(const_table[17], const_table[18], const_table[19], const_table[20]), varyings.ds_hover), float4(const_table[21], const_table[22], const_table[23], const_table[24]), varyings.ds_focus);
+    float4 var_nub_color_0 = mix(mix(float4(const_table[25], const_table[26], const_table[27], const_table[28]), float4(const_table[29], const_table[30], const_table[31], const_table[32]), varyings.ds_hover), mix(float4(const_table[33], const_table[34], const_table[35], const_table[36]), float4(const_table[37], const_table[38], const_table[39], const_table[40]), varyings.ds_drag), varyings.ds_focus);
+    float4 var_nubbg_color_0 = mix(float4(const_table[41], const_table[42], const_table[43], const_table[44]), float4(const_table[45], const_table[46], const_table[47], const_table[48]), varyings.ds_drag);
+    fn_7_63_rect (var_sdf_0, const_table[49], (varyings.ds_rect_size.y - var_slider_height_0), varyings.ds_rect_size.x, var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_slider_bg_color_0, const_table);
+    fn_7_63_rect (var_sdf_0, const_table[50], (varyings.ds_rect_size.y - var_slider_height_0), ((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) + var_nub_size_0), var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_slider_color_0, const_table);
+    float var_nubbg_x_0 = (((varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0)) - (var_nubbg_size_0 * const_table[51])) + (const_table[52] * var_nub_size_0));
+    fn_7_63_rect (var_sdf_0, var_nubbg_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nubbg_size_0, var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_nubbg_color_0, const_table);
+    float var_nub_x_0 = (varyings.ds_slide_pos * (varyings.ds_rect_size.x - var_nub_size_0));
+    fn_7_63_rect (var_sdf_0, var_nub_x_0, (varyings.ds_rect_size.y - var_slider_height_0), var_nub_size_0, var_slider_height_0, const_table);
+    fn_7_48_fill (var_sdf_0, var_nub_color_0, const_table);
+    return var_sdf_0.f_result;
+}
+float4 fn_38_10_clip_and_transform_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    float2 var_clipped_0 = clamp(((geometries.ds_geom_pos * varyings.ds_rect_size) + instances.ds_rect_pos), instances.ds_draw_clip.xy, instances.ds_draw_clip.zw);
+    (varyings.ds_pos = ((var_clipped_0 - instances.ds_rect_pos) / varyings.ds_rect_size));
+    return (uniforms_pass.ds_camera_projection * float4((uniforms_pass.ds_camera_view * float4((uniforms_view.ds_view_transform * float4(float4(var_clipped_0.x, var_clipped_0.y, (instances.ds_draw_depth + uniforms_draw.ds_draw_zbias), const_table[0])))))));
+}
+float4 fn_38_12_vertex(constant const float *const_table, thread Geometries &geometries, thread Instances &instances, thread Varyings &varyings, constant Uniforms_draw &uniforms_draw, constant Uniforms_pass &uniforms_pass, constant Uniforms_view &uniforms_view) {
+    return fn_38_10_clip_and_transform_vertex (const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+}
+vertex Varyings vertex_main(Textures textures
+, const device Geometries *in_geometries [[buffer(0)]]
+, const device Instances *in_instances [[buffer(1)]]
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+, uint vtx_id [[vertex_id]]
+, uint inst_id [[instance_id]]
+) {
+    Geometries geometries = in_geometries[vtx_id];
+    Instances instances = in_instances[inst_id];
+    Varyings varyings;
+    varyings.ds_hover = instances.ds_hover;
+    varyings.ds_focus = instances.ds_focus;
+    varyings.ds_drag = instances.ds_drag;
+    varyings.ds_rect_size = instances.ds_rect_size;
+    varyings.ds_slide_pos = instances.ds_slide_pos;
+    varyings.position = fn_38_12_vertex(const_table, geometries, instances, varyings, uniforms_draw, uniforms_pass, uniforms_view);
+    return varyings;
+}
+fragment float4 fragment_main(Varyings varyings[[stage_in]]
+, Textures textures
+, constant LiveUniforms &live_uniforms [[buffer(2)]]
+, constant const float *const_table [[buffer(3)]]
+, constant Uniforms_draw &uniforms_draw [[buffer(4)]]
+, constant Uniforms_pass &uniforms_pass [[buffer(5)]]
+, constant Uniforms_view &uniforms_view [[buffer(6)]]
+) {
+    return     fn_38_13_pixel(const_table, varyings);
+}
@@ -33,6 +33,7 @@ live_design!{
         bg: {
             instance hover: 0.0
             instance pressed: 0.0
+            instance focus: 0.0
             
             const BORDER_RADIUS = 3.0
             
@@ -72,6 +73,16 @@ live_design!{
                     1.0
                 )
                 
+                // the focus ring
+                sdf.box(
+                    0.5,
+                    0.5,
+                    self.rect_size.x - 1.0,
+                    self.rect_size.y - 1.0,
+                    BORDER_RADIUS + 0.5
+                )
+                sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0)
+                
                 return sdf.result
             }
         }
@@ -117,6 +128,17 @@ live_design!{
                     }
                 }
             }
+            focus = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg: {focus: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 1.0}}
+                }
+            }
         }
     }
 }
@@ -146,6 +168,11 @@ pub struct Button {
     text: String
 }
 
+// the keys that activate the focused widget, like a click does
+pub fn is_activate_key(key_code: KeyCode) -> bool {
+    matches!(key_code, KeyCode::Space | KeyCode::ReturnKey | KeyCode::NumpadEnter)
+}
+
 #[derive(Live, LiveHook)]#[repr(C)]
 struct DrawLabelText {
     draw_super: DrawText,
@@ -182,6 +209,21 @@ impl Button {
                 dispatch_action(cx, ButtonAction::Release);
                 self.animate_state(cx, id!(hover.off));
             }
+            Hit::KeyFocus(_) => {
+                self.animate_state(cx, id!(focus.on));
+            }
+            Hit::KeyFocusLost(_) => {
+                self.animate_state(cx, id!(focus.off));
+            }
+            // space and return press the button when it has the key focus
+            Hit::KeyDown(ke) if !ke.is_repeat && is_activate_key(ke.key_code) => {
+                dispatch_action(cx, ButtonAction::Press);
+                self.animate_state(cx, id!(hover.pressed));
+            }
+            Hit::KeyUp(ke) if is_activate_key(ke.key_code) => {
+                dispatch_action(cx, ButtonAction::Click);
+                self.animate_state(cx, id!(hover.off));
+            }
             _ => ()
         };
     }
@@ -192,12 +234,21 @@ impl Button {
         self.bg.begin(cx, self.walk, self.layout);
         self.label.draw_walk(cx, Walk::fit(), Align::default(), label);
         self.bg.end(cx);
+        self.add_nav_stop(cx, label);
     }
     
     pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
         self.bg.begin(cx, walk, self.layout);
         self.label.draw_walk(cx, Walk::fit(), Align::default(), &self.text);
         self.bg.end(cx);
+        self.add_nav_stop(cx, &self.text);
+    }
+    
+    fn add_nav_stop(&self, cx: &mut Cx2d, label: &str) {
+        cx.add_nav_stop_with_info(self.bg.area(), NavRole::Button, Margin::default(), NavInfo {
+            name: label.to_string(),
+            ..NavInfo::default()
+        });
     }
 }
 
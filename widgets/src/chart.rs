@@ -0,0 +1,954 @@
+use {
+    std::collections::VecDeque,
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        frame::FrameRef,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+
+    DrawChartLine = {{DrawChartLine}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.move_to(self.p0.x, self.p0.y);
+            sdf.line_to(self.p1.x, self.p1.y);
+            return sdf.stroke(self.color, self.width);
+        }
+    }
+
+    DrawChartArea = {{DrawChartArea}} {
+        fn pixel(self) -> vec4 {
+            // the line goes from y0 on the left to y1 on the right, and the area is filled from
+            // the line to the base
+            let top = mix(self.y0, self.y1, self.pos.x);
+            let y = self.pos.y * self.rect_size.y;
+            let lo = min(top, self.base);
+            let hi = max(top, self.base);
+            let alpha = clamp(min(y - lo, hi - y) + 0.5, 0.0, 1.0) * self.color.a;
+            return vec4(self.color.rgb * alpha, alpha);
+        }
+    }
+
+    DrawChartBar = {{DrawChartBar}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(0.0, 0.0, self.rect_size.x, self.rect_size.y, min(2.0, 0.5 * self.rect_size.x));
+            sdf.fill(self.color);
+            return sdf.result;
+        }
+    }
+
+    DrawChartDot = {{DrawChartDot}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            let r = 0.5 * min(self.rect_size.x, self.rect_size.y);
+            sdf.circle(r, r, r - 0.5);
+            sdf.fill(self.color);
+            return sdf.result;
+        }
+    }
+
+    Chart = {{Chart}} {
+        walk: {width: Fill, height: 200}
+        bg: {color: (COLOR_BG_EDITOR)}
+        grid: {color: (COLOR_UP_4)}
+        axis: {color: (COLOR_UP_15)}
+        tick_label: {
+            text_style: <FONT_META> {}
+            color: (COLOR_TEXT_META)
+        }
+        legend_label: {
+            text_style: <FONT_META> {}
+            color: (COLOR_TEXT_DEFAULT)
+        }
+        readout_bg: {color: #000c}
+        readout_label: {
+            text_style: <FONT_META> {}
+            color: (COLOR_TEXT_HOVER)
+        }
+        plot_padding: {left: 44, top: 10, right: 14, bottom: 22}
+        show_axes: true
+        show_grid: true
+        show_legend: true
+        interactive: true
+        line_width: 1.5
+        dot_size: 6.0
+        bar_width: 0.8
+        area_opacity: 0.35
+    }
+
+    // a small chart without axes that goes in a line of text or a table cell
+    Sparkline = <Chart> {
+        walk: {width: 80, height: 20}
+        bg: {color: #0000}
+        plot_padding: {left: 1, top: 2, right: 1, bottom: 2}
+        show_axes: false
+        show_grid: false
+        show_legend: false
+        interactive: false
+        line_width: 1.0
+        dot_size: 3.0
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawChartLine {
+    draw_super: DrawQuad,
+    color: Vec4,
+    // the ends of the line, relative to the quad
+    p0: Vec2,
+    p1: Vec2,
+    width: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawChartArea {
+    draw_super: DrawQuad,
+    color: Vec4,
+    y0: f32,
+    y1: f32,
+    base: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawChartBar {
+    draw_super: DrawQuad,
+    color: Vec4,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawChartDot {
+    draw_super: DrawQuad,
+    color: Vec4,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ChartKind {
+    Line,
+    Area,
+    Bar,
+    Scatter,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, Copy, PartialEq)]
+pub struct ChartSeriesId(pub usize);
+
+// the colors the series get in the order they are added
+const SERIES_COLORS: [u32; 6] = [0x4d9de0ff, 0xe15554ff, 0x3bb273ff, 0xe1bc29ff, 0x7768aeff, 0x46c2c2ff];
+
+pub struct ChartSeries {
+    pub name: String,
+    pub kind: ChartKind,
+    pub color: Vec4,
+    // the oldest points are dropped when there are more than this, 0 keeps all of them
+    pub max_points: usize,
+    points: VecDeque<DVec2>,
+    // while the x values only go up the visible points are found with a binary search
+    sorted: bool,
+    bounds: Option<(DVec2, DVec2)>,
+    bounds_dirty: bool,
+}
+
+impl ChartSeries {
+    fn new(name: &str, kind: ChartKind, color: Vec4) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            color,
+            max_points: 0,
+            points: VecDeque::new(),
+            sorted: true,
+            bounds: None,
+            bounds_dirty: false,
+        }
+    }
+
+    pub fn points(&self) -> &VecDeque<DVec2> {
+        &self.points
+    }
+
+    fn push(&mut self, point: DVec2) {
+        if let Some(last) = self.points.back() {
+            if point.x < last.x {
+                self.sorted = false;
+            }
+        }
+        self.points.push_back(point);
+        if !self.bounds_dirty {
+            self.bounds = Some(match self.bounds {
+                Some((min, max)) => (
+                    dvec2(min.x.min(point.x), min.y.min(point.y)),
+                    dvec2(max.x.max(point.x), max.y.max(point.y))
+                ),
+                None => (point, point)
+            });
+        }
+        if self.max_points > 0 {
+            while self.points.len() > self.max_points {
+                let old = self.points.pop_front().unwrap();
+                // the bounds only have to be found again when the point was on them. while the
+                // x values only go up the smallest one is the new front, so only y is checked
+                if let Some((min, max)) = &mut self.bounds {
+                    if old.y <= min.y || old.y >= max.y || !self.sorted && (old.x <= min.x || old.x >= max.x) {
+                        self.bounds_dirty = true;
+                    }
+                    else if self.sorted {
+                        min.x = self.points.front().unwrap().x;
+                    }
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.points.clear();
+        self.sorted = true;
+        self.bounds = None;
+        self.bounds_dirty = false;
+    }
+
+    fn bounds(&mut self) -> Option<(DVec2, DVec2)> {
+        if self.bounds_dirty {
+            self.bounds_dirty = false;
+            self.bounds = None;
+            for point in &self.points {
+                self.bounds = Some(match self.bounds {
+                    Some((min, max)) => (
+                        dvec2(min.x.min(point.x), min.y.min(point.y)),
+                        dvec2(max.x.max(point.x), max.y.max(point.y))
+                    ),
+                    None => (*point, *point)
+                });
+            }
+        }
+        self.bounds
+    }
+
+    // the range of points within x_min..x_max, with one more on each side so lines run to the edges
+    fn visible_range(&self, x_min: f64, x_max: f64) -> std::ops::Range<usize> {
+        if !self.sorted {
+            return 0..self.points.len()
+        }
+        let start = self.points.partition_point( | p | p.x < x_min);
+        let end = self.points.partition_point( | p | p.x <= x_max);
+        start.saturating_sub(1)..(end + 1).min(self.points.len())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ChartView {
+    pub x_min: f64,
+    pub x_max: f64,
+    pub y_min: f64,
+    pub y_max: f64,
+}
+
+// Evenly spaced round values between min and max, at most about count of them. The step is 1, 2
+// or 5 times a power of ten.
+pub fn chart_ticks(min: f64, max: f64, count: usize) -> (Vec<f64>, f64) {
+    let range = max - min;
+    if range.is_nan() || range <= 0.0 || count == 0 {
+        return (vec![min], 1.0)
+    }
+    let rough = range / count as f64;
+    let power = 10f64.powf(rough.log10().floor());
+    let step = match rough / power {
+        f if f <= 1.0 => 1.0,
+        f if f <= 2.0 => 2.0,
+        f if f <= 5.0 => 5.0,
+        _ => 10.0,
+    } * power;
+    let mut ticks = Vec::new();
+    let mut tick = (min / step).ceil() * step;
+    while tick <= max + step * 1e-9 {
+        // avoid printing -0
+        ticks.push(if tick.abs() < step * 1e-9 {0.0} else {tick});
+        tick += step;
+    }
+    (ticks, step)
+}
+
+// formats a value with as many decimals as the step between the ticks needs
+pub fn chart_format(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    format!("{:.*}", decimals, value)
+}
+
+// A chart of one or more series of points, drawn as lines, filled areas, bars or dots:
+//
+// let chart = self.ui.get_chart(id!(cpu));
+// let load = chart.add_series(cx, "Load", ChartKind::Area).unwrap();
+// chart.append(cx, load, dvec2(time, value));
+//
+// The axes fit the data, or the last x_window of it when points are streamed in. Dragging pans,
+// the scroll wheel zooms around the mouse and a double click goes back to fitting the data.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(Chart))]
+pub struct Chart {
+    bg: DrawColor,
+    grid: DrawColor,
+    axis: DrawColor,
+    line: DrawChartLine,
+    fill: DrawChartArea,
+    bar: DrawChartBar,
+    dot: DrawChartDot,
+    tick_label: DrawText,
+    legend_label: DrawText,
+    swatch: DrawColor,
+    readout_bg: DrawColor,
+    readout_label: DrawText,
+
+    walk: Walk,
+    // the space around the plot, where the tick labels go
+    plot_padding: Padding,
+    show_axes: bool,
+    show_grid: bool,
+    show_legend: bool,
+    // pan and zoom with the mouse, and show the value under it
+    interactive: bool,
+    line_width: f64,
+    dot_size: f64,
+    // the part of the space between two x values that the bars take up
+    bar_width: f64,
+    area_opacity: f64,
+    // the length of the end of the data that shows, in x units, 0 shows all of it
+    x_window: f64,
+    // a fixed y range from y_range.x to y_range.y, used when it isn't empty
+    y_range: DVec2,
+    #[live(6usize)] x_ticks: usize,
+    #[live(5usize)] y_ticks: usize,
+
+    #[rust] series: Vec<ChartSeries>,
+    // the x range set by panning and zooming, which stops the chart from following the data
+    #[rust] x_view: Option<(f64, f64)>,
+    #[rust] view: ChartView,
+    #[rust] plot: Rect,
+    #[rust] drag_start: Option<(DVec2, (f64, f64))>,
+    #[rust] hover: Option<(usize, usize)>,
+}
+
+impl LiveHook for Chart {
+    fn after_apply(&mut self, cx: &mut Cx, _from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        self.bg.redraw(cx);
+    }
+}
+
+#[derive(Clone, WidgetAction)]
+pub enum ChartAction {
+    Hover(ChartSeriesId, DVec2),
+    HoverOut,
+    ViewChanged,
+    None
+}
+
+impl Widget for Chart {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.bg.redraw(cx);
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        self.draw_walk(cx, walk);
+        WidgetDraw::done()
+    }
+}
+
+impl Chart {
+    pub fn add_series(&mut self, cx: &mut Cx, name: &str, kind: ChartKind) -> ChartSeriesId {
+        let color = Vec4::from_u32(SERIES_COLORS[self.series.len() % SERIES_COLORS.len()]);
+        self.series.push(ChartSeries::new(name, kind, color));
+        self.bg.redraw(cx);
+        ChartSeriesId(self.series.len() - 1)
+    }
+
+    pub fn series(&self, id: ChartSeriesId) -> &ChartSeries {
+        &self.series[id.0]
+    }
+
+    pub fn series_mut(&mut self, cx: &mut Cx, id: ChartSeriesId) -> &mut ChartSeries {
+        self.bg.redraw(cx);
+        &mut self.series[id.0]
+    }
+
+    pub fn append(&mut self, cx: &mut Cx, id: ChartSeriesId, point: DVec2) {
+        self.series[id.0].push(point);
+        self.bg.redraw(cx);
+    }
+
+    pub fn extend(&mut self, cx: &mut Cx, id: ChartSeriesId, points: &[DVec2]) {
+        for point in points {
+            self.series[id.0].push(*point);
+        }
+        self.bg.redraw(cx);
+    }
+
+    pub fn set_points(&mut self, cx: &mut Cx, id: ChartSeriesId, points: &[DVec2]) {
+        self.series[id.0].clear();
+        self.extend(cx, id, points);
+    }
+
+    // removes the points of all the series
+    pub fn clear(&mut self, cx: &mut Cx) {
+        for series in &mut self.series {
+            series.clear();
+        }
+        self.hover = None;
+        self.bg.redraw(cx);
+    }
+
+    // goes back to fitting the data after panning or zooming
+    pub fn reset_view(&mut self, cx: &mut Cx) {
+        self.x_view = None;
+        self.bg.redraw(cx);
+    }
+
+    pub fn view(&self) -> ChartView {
+        self.view
+    }
+
+    fn to_screen(&self, point: DVec2) -> DVec2 {
+        let view = &self.view;
+        dvec2(
+            self.plot.pos.x + (point.x - view.x_min) / (view.x_max - view.x_min) * self.plot.size.x,
+            self.plot.pos.y + self.plot.size.y - (point.y - view.y_min) / (view.y_max - view.y_min) * self.plot.size.y
+        )
+    }
+
+    fn to_data_x(&self, x: f64) -> f64 {
+        self.view.x_min + (x - self.plot.pos.x) / self.plot.size.x * (self.view.x_max - self.view.x_min)
+    }
+
+    // fits the view to the data, or to what is visible of it when the x range is set
+    fn compute_view(&mut self) {
+        let (x_min, x_max) = if let Some(x_view) = self.x_view {
+            x_view
+        }
+        else {
+            let mut range: Option<(f64, f64)> = None;
+            for series in &mut self.series {
+                if let Some((min, max)) = series.bounds() {
+                    range = Some(match range {
+                        Some((lo, hi)) => (lo.min(min.x), hi.max(max.x)),
+                        None => (min.x, max.x)
+                    });
+                }
+            }
+            match range {
+                Some((_, hi)) if self.x_window > 0.0 => (hi - self.x_window, hi),
+                Some((lo, hi)) if hi > lo => (lo, hi),
+                Some((lo, _)) => (lo - 0.5, lo + 0.5),
+                None => (0.0, 1.0)
+            }
+        };
+
+        let (y_min, y_max) = if self.y_range.y > self.y_range.x {
+            (self.y_range.x, self.y_range.y)
+        }
+        else {
+            let mut range: Option<(f64, f64)> = None;
+            for series in &self.series {
+                let points = &series.points;
+                for index in series.visible_range(x_min, x_max) {
+                    let y = points[index].y;
+                    range = Some(match range {
+                        Some((lo, hi)) => (lo.min(y), hi.max(y)),
+                        None => (y, y)
+                    });
+                }
+                // bars and areas stand on zero
+                if series.kind == ChartKind::Bar || series.kind == ChartKind::Area {
+                    if let Some((lo, hi)) = range {
+                        range = Some((lo.min(0.0), hi.max(0.0)));
+                    }
+                }
+            }
+            match range {
+                Some((lo, hi)) if hi > lo => {
+                    // round the range out to the ticks
+                    let (_, step) = chart_ticks(lo, hi, self.y_ticks.max(1));
+                    ((lo / step).floor() * step, (hi / step).ceil() * step)
+                }
+                Some((lo, _)) => (lo - 0.5, lo + 0.5),
+                None => (0.0, 1.0)
+            }
+        };
+        self.view = ChartView {x_min, x_max, y_min, y_max};
+    }
+
+    // the points of a series on the screen, with many points per pixel column reduced to the
+    // lowest and highest of them
+    fn screen_points(&self, series: &ChartSeries) -> Vec<DVec2> {
+        let range = series.visible_range(self.view.x_min, self.view.x_max);
+        let mut out = Vec::with_capacity(range.len());
+        let decimate = series.sorted && range.len() as f64 > 2.0 * self.plot.size.x;
+        let mut column: Option<(i64, DVec2, DVec2)> = None;
+        for index in range {
+            let point = self.to_screen(series.points[index]);
+            if !decimate {
+                out.push(point);
+                continue
+            }
+            let x = point.x.floor() as i64;
+            column = match column {
+                Some((cx, lo, hi)) if cx == x => Some((
+                    cx,
+                    if point.y < lo.y {point} else {lo},
+                    if point.y > hi.y {point} else {hi}
+                )),
+                Some((_, lo, hi)) => {
+                    out.push(lo);
+                    out.push(hi);
+                    Some((x, point, point))
+                }
+                None => Some((x, point, point))
+            };
+        }
+        if let Some((_, lo, hi)) = column {
+            out.push(lo);
+            out.push(hi);
+        }
+        out
+    }
+
+    pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        let rect = cx.walk_turtle(walk);
+        self.bg.draw_abs(cx, rect);
+        self.plot = Rect {
+            pos: rect.pos + self.plot_padding.left_top(),
+            size: dvec2(
+                (rect.size.x - self.plot_padding.width()).max(1.0),
+                (rect.size.y - self.plot_padding.height()).max(1.0)
+            )
+        };
+        self.compute_view();
+        let plot = self.plot;
+
+        let (x_ticks, x_step) = chart_ticks(self.view.x_min, self.view.x_max, self.x_ticks);
+        let (y_ticks, y_step) = chart_ticks(self.view.y_min, self.view.y_max, self.y_ticks);
+        if self.show_grid {
+            for x in &x_ticks {
+                let pos = self.to_screen(dvec2(*x, 0.0));
+                self.grid.draw_abs(cx, Rect {pos: dvec2(pos.x.floor(), plot.pos.y), size: dvec2(1.0, plot.size.y)});
+            }
+            for y in &y_ticks {
+                let pos = self.to_screen(dvec2(0.0, *y));
+                self.grid.draw_abs(cx, Rect {pos: dvec2(plot.pos.x, pos.y.floor()), size: dvec2(plot.size.x, 1.0)});
+            }
+        }
+        if self.show_axes {
+            self.axis.draw_abs(cx, Rect {pos: plot.pos, size: dvec2(1.0, plot.size.y)});
+            self.axis.draw_abs(cx, Rect {pos: dvec2(plot.pos.x, plot.pos.y + plot.size.y), size: dvec2(plot.size.x, 1.0)});
+            for x in &x_ticks {
+                let text = chart_format(*x, x_step);
+                let width = self.text_width(cx, &text);
+                let pos = self.to_screen(dvec2(*x, 0.0));
+                self.tick_label.draw_abs(cx, dvec2(pos.x - 0.5 * width, plot.pos.y + plot.size.y + 4.0), &text);
+            }
+            for y in &y_ticks {
+                let text = chart_format(*y, y_step);
+                let width = self.text_width(cx, &text);
+                let pos = self.to_screen(dvec2(0.0, *y));
+                self.tick_label.draw_abs(cx, dvec2(plot.pos.x - width - 6.0, pos.y - 6.0), &text);
+            }
+        }
+
+        // the series are clipped to the plot
+        cx.begin_turtle(Walk::fixed_size(plot.size).with_abs_pos(plot.pos), Layout::default());
+        self.draw_series(cx);
+        self.draw_readout(cx, x_step, y_step);
+        cx.end_turtle();
+
+        if self.show_legend && self.series.len() > 1 {
+            self.draw_legend(cx);
+        }
+    }
+
+    fn text_width(&self, cx: &Cx2d, text: &str) -> f64 {
+        self.tick_label.compute_geom(cx, Walk::fit(), text).map( | geom | geom.measured_width).unwrap_or(0.0)
+    }
+
+    fn draw_series(&mut self, cx: &mut Cx2d) {
+        let plot = self.plot;
+        let base = self.to_screen(dvec2(0.0, 0.0)).y.max(plot.pos.y).min(plot.pos.y + plot.size.y);
+        let bar_count = self.series.iter().filter( | series | series.kind == ChartKind::Bar).count();
+        let mut bar_slot = 0;
+
+        for index in 0..self.series.len() {
+            let points = self.screen_points(&self.series[index]);
+            let series = &self.series[index];
+            match series.kind {
+                ChartKind::Line | ChartKind::Area => {
+                    if series.kind == ChartKind::Area {
+                        self.fill.color = vec4(series.color.x, series.color.y, series.color.z, self.area_opacity as f32);
+                        self.fill.begin_many_instances(cx);
+                        for pair in points.windows(2) {
+                            let (a, b) = (pair[0], pair[1]);
+                            if b.x - a.x <= 0.0 {
+                                continue
+                            }
+                            let top = a.y.min(b.y).min(base);
+                            let bottom = a.y.max(b.y).max(base);
+                            self.fill.y0 = (a.y - top) as f32;
+                            self.fill.y1 = (b.y - top) as f32;
+                            self.fill.base = (base - top) as f32;
+                            self.fill.draw_abs(cx, Rect {pos: dvec2(a.x, top), size: dvec2(b.x - a.x, bottom - top)});
+                        }
+                        self.fill.end_many_instances(cx);
+                    }
+                    let pad = self.line_width + 1.0;
+                    self.line.color = series.color;
+                    self.line.width = self.line_width as f32;
+                    self.line.begin_many_instances(cx);
+                    for pair in points.windows(2) {
+                        let (a, b) = (pair[0], pair[1]);
+                        let pos = dvec2(a.x.min(b.x) - pad, a.y.min(b.y) - pad);
+                        let size = dvec2((b.x - a.x).abs() + 2.0 * pad, (b.y - a.y).abs() + 2.0 * pad);
+                        self.line.p0 = (a - pos).into();
+                        self.line.p1 = (b - pos).into();
+                        self.line.draw_abs(cx, Rect {pos, size});
+                    }
+                    self.line.end_many_instances(cx);
+                }
+                ChartKind::Bar => {
+                    // the bars of all bar series share the space between two x values
+                    let spacing = points.windows(2)
+                        .map( | pair | pair[1].x - pair[0].x)
+                        .filter( | dx | *dx > 0.0)
+                        .fold(f64::INFINITY, f64::min);
+                    let spacing = if spacing.is_finite() {spacing} else {plot.size.x * 0.1};
+                    let group = spacing * self.bar_width;
+                    let width = (group / bar_count as f64).max(1.0);
+                    self.bar.color = series.color;
+                    self.bar.begin_many_instances(cx);
+                    for point in &points {
+                        let x = point.x - 0.5 * group + bar_slot as f64 * width;
+                        let top = point.y.min(base);
+                        self.bar.draw_abs(cx, Rect {
+                            pos: dvec2(x, top),
+                            size: dvec2(width, (point.y.max(base) - top).max(1.0))
+                        });
+                    }
+                    self.bar.end_many_instances(cx);
+                    bar_slot += 1;
+                }
+                ChartKind::Scatter => {
+                    let size = dvec2(self.dot_size, self.dot_size);
+                    self.dot.color = series.color;
+                    self.dot.begin_many_instances(cx);
+                    for point in &points {
+                        if plot.add_margin(size).contains(*point) {
+                            self.dot.draw_abs(cx, Rect {pos: *point - 0.5 * size, size});
+                        }
+                    }
+                    self.dot.end_many_instances(cx);
+                }
+            }
+        }
+    }
+
+    fn draw_readout(&mut self, cx: &mut Cx2d, x_step: f64, y_step: f64) {
+        let (series_index, point_index) = match self.hover {
+            Some(hover) => hover,
+            None => return
+        };
+        let series = match self.series.get(series_index) {
+            Some(series) => series,
+            None => return
+        };
+        let point = match series.points.get(point_index) {
+            Some(point) => *point,
+            None => return
+        };
+        let pos = self.to_screen(point);
+        let size = dvec2(self.dot_size + 4.0, self.dot_size + 4.0);
+        self.dot.color = series.color;
+        self.dot.draw_abs(cx, Rect {pos: pos - 0.5 * size, size});
+
+        let text = format!("{}: {}, {}", series.name, chart_format(point.x, x_step / 10.0), chart_format(point.y, y_step / 10.0));
+        let text_size = dvec2(self.text_width(cx, &text), 12.0);
+        let pad = dvec2(6.0, 4.0);
+        let box_size = text_size + 2.0 * pad;
+        // next to the point, on the side where there is room for it
+        let plot = self.plot;
+        let mut box_pos = pos + dvec2(10.0, -box_size.y - 10.0);
+        if box_pos.x + box_size.x > plot.pos.x + plot.size.x {
+            box_pos.x = pos.x - box_size.x - 10.0;
+        }
+        if box_pos.y < plot.pos.y {
+            box_pos.y = pos.y + 10.0;
+        }
+        self.readout_bg.draw_abs(cx, Rect {pos: box_pos, size: box_size});
+        self.readout_label.draw_abs(cx, box_pos + pad, &text);
+    }
+
+    fn draw_legend(&mut self, cx: &mut Cx2d) {
+        let swatch = 8.0;
+        let spacing = 12.0;
+        let widths: Vec<f64> = self.series.iter().map( | series | {
+            self.legend_label.compute_geom(cx, Walk::fit(), &series.name).map( | geom | geom.measured_width).unwrap_or(0.0)
+        }).collect();
+        let total: f64 = widths.iter().map( | width | swatch + 4.0 + width + spacing).sum::<f64>() - spacing;
+        let mut x = self.plot.pos.x + self.plot.size.x - total - 4.0;
+        let y = self.plot.pos.y + 4.0;
+        for (series, width) in self.series.iter().zip(widths) {
+            self.swatch.color = series.color;
+            self.swatch.draw_abs(cx, Rect {pos: dvec2(x, y + 2.0), size: dvec2(swatch, swatch)});
+            self.legend_label.draw_abs(cx, dvec2(x + swatch + 4.0, y), &series.name);
+            x += swatch + 4.0 + width + spacing;
+        }
+    }
+
+    // the point closest to a position on the screen: the one closest in x for series drawn along
+    // x, and the closest dot within a few pixels for scatter series
+    fn point_at(&self, abs: DVec2) -> Option<(usize, usize)> {
+        let x = self.to_data_x(abs.x);
+        let mut best: Option<(f64, usize, usize)> = None;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let candidates = if series.kind == ChartKind::Scatter || !series.sorted {
+                series.visible_range(self.view.x_min, self.view.x_max)
+            }
+            else {
+                let index = series.points.partition_point( | p | p.x < x);
+                index.saturating_sub(1)..(index + 1).min(series.points.len())
+            };
+            for index in candidates {
+                let pos = self.to_screen(series.points[index]);
+                let distance = if series.kind == ChartKind::Scatter {
+                    let distance = (pos - abs).length();
+                    if distance > self.dot_size + 6.0 {
+                        continue
+                    }
+                    distance
+                }
+                else {
+                    // the x distance counts most, the y distance picks between the series
+                    (pos.x - abs.x).abs() * 1000.0 + (pos.y - abs.y).abs()
+                };
+                if best.map( | (best, _, _) | distance < best).unwrap_or(true) {
+                    best = Some((distance, series_index, index));
+                }
+            }
+        }
+        best.map( | (_, series, index) | (series, index))
+    }
+
+    fn set_hover(&mut self, cx: &mut Cx, hover: Option<(usize, usize)>, dispatch_action: &mut dyn FnMut(&mut Cx, ChartAction)) {
+        if hover == self.hover {
+            return
+        }
+        self.hover = hover;
+        self.bg.redraw(cx);
+        match hover {
+            Some((series, index)) => {
+                let point = self.series[series].points[index];
+                dispatch_action(cx, ChartAction::Hover(ChartSeriesId(series), point));
+            }
+            None => dispatch_action(cx, ChartAction::HoverOut)
+        }
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, ChartAction)) {
+        if !self.interactive {
+            return
+        }
+
+        // zoom the x axis around the mouse
+        if let Event::FingerScroll(fe) = event {
+            if !fe.handled_y.get() && fe.sweep_lock.get().is_empty() && self.plot.contains(fe.abs) && fe.scroll.y != 0.0 {
+                fe.handled_y.set(true);
+                let center = self.to_data_x(fe.abs.x);
+                let scale = (1.0 + fe.scroll.y * 0.002).clamp(0.1, 10.0);
+                self.x_view = Some((
+                    center - (center - self.view.x_min) * scale,
+                    center + (self.view.x_max - center) * scale
+                ));
+                self.hover = None;
+                self.bg.redraw(cx);
+                dispatch_action(cx, ChartAction::ViewChanged);
+            }
+        }
+
+        match event.hits(cx, self.bg.area()) {
+            Hit::FingerHoverIn(fe) | Hit::FingerHoverOver(fe) => {
+                let hover = if self.plot.contains(fe.abs) {self.point_at(fe.abs)} else {None};
+                self.set_hover(cx, hover, dispatch_action);
+            }
+            Hit::FingerHoverOut(_) => {
+                self.set_hover(cx, None, dispatch_action);
+            }
+            Hit::FingerDown(fe) => {
+                if fe.tap_count == 2 {
+                    self.reset_view(cx);
+                    dispatch_action(cx, ChartAction::ViewChanged);
+                }
+                else if self.plot.contains(fe.abs) {
+                    self.drag_start = Some((fe.abs, (self.view.x_min, self.view.x_max)));
+                }
+            }
+            Hit::FingerMove(fe) => {
+                if let Some((start, (x_min, x_max))) = self.drag_start {
+                    let shift = (fe.abs.x - start.x) / self.plot.size.x * (x_max - x_min);
+                    self.x_view = Some((x_min - shift, x_max - shift));
+                    self.hover = None;
+                    self.bg.redraw(cx);
+                    dispatch_action(cx, ChartAction::ViewChanged);
+                }
+            }
+            Hit::FingerUp(_) => {
+                self.drag_start = None;
+            }
+            _ => ()
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct ChartRef(WidgetRef);
+
+impl ChartRef {
+    pub fn add_series(&self, cx: &mut Cx, name: &str, kind: ChartKind) -> Option<ChartSeriesId> {
+        if let Some(mut inner) = self.inner_mut() {
+            return Some(inner.add_series(cx, name, kind))
+        }
+        None
+    }
+
+    pub fn append(&self, cx: &mut Cx, id: ChartSeriesId, point: DVec2) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.append(cx, id, point);
+        }
+    }
+
+    pub fn extend(&self, cx: &mut Cx, id: ChartSeriesId, points: &[DVec2]) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.extend(cx, id, points);
+        }
+    }
+
+    pub fn set_points(&self, cx: &mut Cx, id: ChartSeriesId, points: &[DVec2]) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_points(cx, id, points);
+        }
+    }
+
+    pub fn set_max_points(&self, cx: &mut Cx, id: ChartSeriesId, max_points: usize) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.series_mut(cx, id).max_points = max_points;
+        }
+    }
+
+    pub fn clear(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.clear(cx);
+        }
+    }
+
+    pub fn reset_view(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.reset_view(cx);
+        }
+    }
+
+    pub fn hovered(&self, actions: &WidgetActions) -> Option<(ChartSeriesId, DVec2)> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let ChartAction::Hover(id, point) = item.action() {
+                return Some((id, point))
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ticks(min: f64, max: f64, count: usize, expected_ticks: &[f64], expected_step: f64) {
+        let (ticks, step) = chart_ticks(min, max, count);
+        assert!((step - expected_step).abs() < 1e-12, "step {} for {}..{}", step, min, max);
+        assert_eq!(ticks.len(), expected_ticks.len(), "{:?} for {}..{}", ticks, min, max);
+        for (tick, expected) in ticks.iter().zip(expected_ticks) {
+            assert!((tick - expected).abs() < 1e-9, "{:?} for {}..{}", ticks, min, max);
+        }
+    }
+
+    #[test]
+    fn ticks() {
+        assert_ticks(0.0, 10.0, 5, &[0.0, 2.0, 4.0, 6.0, 8.0, 10.0], 2.0);
+        assert_ticks(0.0, 1.0, 4, &[0.0, 0.5, 1.0], 0.5);
+        assert_ticks(-3.0, 7.0, 5, &[-2.0, 0.0, 2.0, 4.0, 6.0], 2.0);
+        assert_ticks(1000.0, 1500.0, 3, &[1000.0, 1200.0, 1400.0], 200.0);
+        assert_ticks(0.0, 95.0, 10, &[0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0], 10.0);
+        // the last tick survives rounding errors in the sum of the steps
+        assert_ticks(0.0, 0.3, 3, &[0.0, 0.1, 0.2, 0.3], 0.1);
+
+        // no -0 in the middle of the ticks
+        let (ticks, _) = chart_ticks(-0.3, 0.3, 3);
+        assert!(ticks[1] == 0.0 && ticks[1].is_sign_positive());
+
+        // an empty range has one tick
+        assert_eq!(chart_ticks(5.0, 5.0, 4), (vec![5.0], 1.0));
+        assert_eq!(chart_ticks(5.0, 1.0, 4), (vec![5.0], 1.0));
+        assert_eq!(chart_ticks(0.0, 1.0, 0), (vec![0.0], 1.0));
+        assert_eq!(chart_ticks(0.0, f64::NAN, 4).0.len(), 1);
+    }
+
+    #[test]
+    fn tick_labels() {
+        assert_eq!(chart_format(1234.0, 200.0), "1234");
+        assert_eq!(chart_format(3.0, 1.0), "3");
+        assert_eq!(chart_format(2.0, 0.2), "2.0");
+        assert_eq!(chart_format(0.5, 0.5), "0.5");
+        assert_eq!(chart_format(0.25, 0.05), "0.25");
+        assert_eq!(chart_format(-1.5, 0.5), "-1.5");
+        assert_eq!(chart_format(0.30000000000000004, 0.1), "0.3");
+    }
+
+    #[test]
+    fn series_bounds() {
+        let mut series = ChartSeries::new("a", ChartKind::Line, Vec4::default());
+        series.max_points = 3;
+        for (x, y) in [(0.0, 0.0), (1.0, 5.0), (2.0, 2.0), (3.0, 3.0)] {
+            series.push(dvec2(x, y));
+        }
+        // dropping a point on the y bounds means looking at all of them again
+        assert!(series.bounds_dirty);
+        assert_eq!(series.bounds(), Some((dvec2(1.0, 2.0), dvec2(3.0, 5.0))));
+
+        // a streaming series keeps its bounds while only the x minimum moves
+        series.push(dvec2(4.0, 1.0));
+        assert_eq!(series.bounds(), Some((dvec2(2.0, 1.0), dvec2(4.0, 3.0))));
+        series.push(dvec2(5.0, 2.5));
+        assert!(!series.bounds_dirty);
+        assert_eq!(series.bounds(), Some((dvec2(3.0, 1.0), dvec2(5.0, 3.0))));
+
+        // without the order a point on the x bounds counts too
+        let mut series = ChartSeries::new("b", ChartKind::Line, Vec4::default());
+        series.max_points = 3;
+        for (x, y) in [(5.0, 2.0), (0.0, 3.0), (2.0, 1.0), (1.0, 2.5)] {
+            series.push(dvec2(x, y));
+        }
+        assert!(!series.sorted && series.bounds_dirty);
+        assert_eq!(series.bounds(), Some((dvec2(0.0, 1.0), dvec2(2.0, 3.0))));
+    }
+}
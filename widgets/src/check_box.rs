@@ -4,6 +4,7 @@ use {
         makepad_draw_2d::*,
         widget::*,
         data_binding::DataBinding,
+        button::is_activate_key,
         frame::*,
     }
 };
@@ -28,6 +29,8 @@ live_design!{
                     sdf.line_to(c.x, c.y + szs);
                     sdf.line_to(c.x + szs, c.y - szs);
                     sdf.stroke(mix(#fff0, #f, self.selected), 1.25);
+                    sdf.box(left - 2.0, c.y - sz - 2.0, sz * 2.0 + 4.0, sz * 2.0 + 4.0, 4.0);
+                    sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0);
                 }
                 CheckType::Radio => {
                     let sz = self.size;
@@ -38,6 +41,8 @@ live_design!{
                     let isz = sz * 0.5;
                     sdf.circle(left, c.y, isz);
                     sdf.fill(mix(#fff0, #f, self.selected));
+                    sdf.circle(left, c.y, sz + 2.0);
+                    sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0);
                 }
                 CheckType::Toggle => {
                     let sz = self.size;
@@ -52,6 +57,8 @@ live_design!{
                     sdf.circle(left + sz + self.selected * sz, c.y, isz);
                     sdf.blend(self.selected)
                     sdf.fill(#f);
+                    sdf.box(left - 2.0, c.y - sz - 2.0, sz * 3.0 + 4.0, sz * 2.0 + 4.0, 0.5 * sz + 2.0);
+                    sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0);
                 }
             }
             return sdf.result
@@ -183,15 +190,17 @@ impl CheckBox {
                 self.animate_state(cx, id!(hover.off));
             },
             Hit::FingerDown(_fe) => {
-                if self.state.is_in_state(cx, id!(selected.on)) {
-                    self.animate_state(cx, id!(selected.off));
-                    dispatch_action(cx, CheckBoxAction::Change(false));
-                }
-                else {
-                    self.animate_state(cx, id!(selected.on));
-                    dispatch_action(cx, CheckBoxAction::Change(true));
-                }
+                self.toggle(cx, dispatch_action);
             },
+            Hit::KeyFocus(_) => {
+                self.animate_state(cx, id!(focus.on));
+            }
+            Hit::KeyFocusLost(_) => {
+                self.animate_state(cx, id!(focus.off));
+            }
+            Hit::KeyDown(ke) if !ke.is_repeat && is_activate_key(ke.key_code) => {
+                self.toggle(cx, dispatch_action);
+            }
             Hit::FingerUp(_fe) => {
                 
             }
@@ -202,10 +211,28 @@ impl CheckBox {
         }
     }
     
+    fn toggle(&mut self, cx: &mut Cx, dispatch_action: &mut dyn FnMut(&mut Cx, CheckBoxAction)) {
+        if self.state.is_in_state(cx, id!(selected.on)) {
+            self.animate_state(cx, id!(selected.off));
+            dispatch_action(cx, CheckBoxAction::Change(false));
+        }
+        else {
+            self.animate_state(cx, id!(selected.on));
+            dispatch_action(cx, CheckBoxAction::Change(true));
+        }
+    }
+    
     pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
         self.check_box.begin(cx, walk, self.layout);
         self.label_text.draw_walk(cx, self.label_walk, self.label_align, &self.label);
         self.check_box.end(cx);
+        
+        let checked = self.state.is_in_state(cx, id!(selected.on));
+        cx.add_nav_stop_with_info(self.check_box.area(), NavRole::CheckBox, Margin::default(), NavInfo {
+            name: self.label.clone(),
+            value: None,
+            state: NavState {checked: Some(checked), ..NavState::default()}
+        });
     }
 }
 
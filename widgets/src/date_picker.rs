@@ -0,0 +1,1103 @@
+use {
+    std::fmt,
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        data_binding::DataBinding,
+        widget::*,
+        frame::FrameRef,
+        text_input::{TextInput, TextInputAction},
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+    import makepad_widgets::text_input::TextInput;
+
+    DrawPickerField = {{DrawPickerField}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+            sdf.fill_keep(mix(COLOR_BG_EDITOR, COLOR_BG_UNFOCUSSED, self.hover));
+            sdf.stroke(mix(mix(COLOR_UP_10, COLOR_UP_25, self.focus), COLOR_HIGH, self.invalid), 1.0);
+            return sdf.result;
+        }
+    }
+
+    DrawCalendarButton = {{DrawCalendarButton}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+            sdf.fill(mix(#fff0, COLOR_UP_10, self.hover));
+            let color = mix(COLOR_TEXT_DEFAULT, COLOR_TEXT_HOVER, self.hover);
+            let c = floor(self.rect_size * 0.5);
+            sdf.box(c.x - 5.0, c.y - 4.5, 10.0, 9.0, 1.0);
+            sdf.stroke(color, 1.0);
+            sdf.rect(c.x - 5.0, c.y - 2.5, 10.0, 1.0);
+            sdf.fill(color);
+            return sdf.result;
+        }
+    }
+
+    DrawCalendarPanel = {{DrawCalendarPanel}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 3.0);
+            sdf.fill_keep(COLOR_BG_HEADER);
+            sdf.stroke(COLOR_UP_10, 1.0);
+            return sdf.result;
+        }
+    }
+
+    DrawCalendarDay = {{DrawCalendarDay}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+            sdf.fill_keep(mix(mix(#fff0, COLOR_UP_10, self.hover), COLOR_BG_SELECTED, self.selected));
+            sdf.stroke(mix(#fff0, COLOR_UP_25, self.today), 1.0);
+            return sdf.result;
+        }
+    }
+
+    DrawCalendarArrow = {{DrawCalendarArrow}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+            sdf.fill(mix(#fff0, COLOR_UP_10, self.hover));
+            // points left for the month before and right for the month after
+            let d = mix(-1.0, 1.0, self.next);
+            let c = self.rect_size * 0.5;
+            sdf.move_to(c.x - 2.0 * d, c.y - 4.0);
+            sdf.line_to(c.x + 2.0 * d, c.y);
+            sdf.line_to(c.x - 2.0 * d, c.y + 4.0);
+            sdf.stroke(mix(COLOR_TEXT_DEFAULT, COLOR_TEXT_HOVER, self.hover), 1.0);
+            return sdf.result;
+        }
+    }
+
+    DrawCalendarText = {{DrawCalendarText}} {
+        text_style: <FONT_LABEL> {}
+        fn get_color(self) -> vec4 {
+            return mix(COLOR_TEXT_DEFAULT, COLOR_TEXT_META, self.dim)
+        }
+    }
+
+    PickerTextInput = <TextInput> {
+        cursor_margin_bottom: 3.0,
+        cursor_margin_top: 4.0,
+        select_pad_edges: 3.0
+        cursor_size: 2.0,
+        bg: {
+            shape: None
+            color: #5
+            radius: 2
+        },
+        layout: {
+            padding: {left: 6, top: 5, right: 2, bottom: 5},
+            align: {y: 0.}
+        },
+        walk: {
+            margin: 0
+        }
+    }
+
+    DatePicker = {{DatePicker}} {
+        walk: {width: 130, height: Fit, margin: {top: 3, right: 5}}
+        layout: {flow: Right, align: {y: 0.5}, padding: {right: 2}}
+        button_walk: {width: 20, height: 18}
+        text_input: <PickerTextInput> {
+            empty_message: "YYYY-MM-DD"
+        }
+        state: {
+            hover = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg: {hover: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {hover: 1.0}}
+                }
+            }
+            focus = {
+                default: off
+                off = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 1.0}}
+                }
+            }
+        }
+
+        catcher: {color: #0000}
+        prev: {next: 0.0}
+        next: {next: 1.0}
+        padding: {left: 6, top: 6, right: 6, bottom: 6}
+        cell_size: 26.0
+        header_height: 26.0
+        sunday_first: false
+        value: ""
+        min: ""
+        max: ""
+    }
+
+    TimePicker = {{TimePicker}} {
+        walk: {width: 90, height: Fit, margin: {top: 3, right: 5}}
+        layout: {flow: Right, align: {y: 0.5}}
+        text_input: <PickerTextInput> {
+            empty_message: "HH:MM"
+        }
+        state: {
+            hover = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg: {hover: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {hover: 1.0}}
+                }
+            }
+            focus = {
+                default: off
+                off = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 1.0}}
+                }
+            }
+        }
+        value: ""
+        minute_step: 1
+        show_seconds: false
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawPickerField {
+    draw_super: DrawQuad,
+    hover: f32,
+    focus: f32,
+    invalid: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawCalendarButton {
+    draw_super: DrawQuad,
+    hover: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawCalendarPanel {
+    draw_super: DrawQuad,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawCalendarDay {
+    draw_super: DrawQuad,
+    hover: f32,
+    selected: f32,
+    today: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawCalendarArrow {
+    draw_super: DrawQuad,
+    hover: f32,
+    next: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawCalendarText {
+    draw_super: DrawText,
+    // the days of the months before and after, and the ones out of range
+    dim: f32,
+}
+
+// A day in the proleptic gregorian calendar, written as YYYY-MM-DD.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > Self::days_in_month(year, month) {
+            return None
+        }
+        Some(Self {year, month, day})
+    }
+
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None
+        }
+        Self::new(year, month, day)
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31
+        }
+    }
+
+    // the days since 1970-01-01, from Howard Hinnant's days_from_civil
+    pub fn to_days(self) -> i64 {
+        let year = self.year as i64 - if self.month <= 2 {1} else {0};
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year = (153 * (month + if month > 2 {-3} else {9}) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    pub fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 {mp + 3} else {mp - 9} as u32;
+        let year = year_of_era + era * 400 + if month <= 2 {1} else {0};
+        Self {year: year as i32, month, day}
+    }
+
+    // 0 is monday and 6 is sunday
+    pub fn weekday(self) -> u32 {
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    // the day is kept, or is the last one of a shorter month
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        Self {year, month, day: self.day.min(Self::days_in_month(year, month))}
+    }
+
+    fn first_of_month(self) -> Self {
+        Self {day: 1, ..self}
+    }
+
+    // by the clock of the system in utc, the web build has no clock to ask
+    pub fn today() -> Option<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Ok(time) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            return Some(Self::from_days((time.as_secs() / 86400) as i64))
+        }
+        None
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// A time of the day on a 24 hour clock, written as HH:MM or HH:MM:SS.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl TimeOfDay {
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return None
+        }
+        Some(Self {hour, minute, second})
+    }
+
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split(':');
+        let hour = parts.next()?.parse().ok()?;
+        let minute = parts.next()?.parse().ok()?;
+        let second = match parts.next() {
+            Some(second) => second.parse().ok()?,
+            None => 0
+        };
+        if parts.next().is_some() {
+            return None
+        }
+        Self::new(hour, minute, second)
+    }
+
+    pub fn to_seconds(self) -> i64 {
+        (self.hour * 3600 + self.minute * 60 + self.second) as i64
+    }
+
+    // wraps around midnight
+    pub fn from_seconds(seconds: i64) -> Self {
+        let seconds = seconds.rem_euclid(86400) as u32;
+        Self {hour: seconds / 3600, minute: seconds / 60 % 60, second: seconds % 60}
+    }
+
+    pub fn add_minutes(self, minutes: i64) -> Self {
+        Self::from_seconds(self.to_seconds() + minutes * 60)
+    }
+
+    pub fn format(self, show_seconds: bool) -> String {
+        if show_seconds {
+            format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        }
+        else {
+            format!("{:02}:{:02}", self.hour, self.minute)
+        }
+    }
+}
+
+fn live_value_str(value: &LiveValue) -> Option<&str> {
+    match value {
+        LiveValue::Str(s) => Some(s),
+        LiveValue::FittedString(s) => Some(s.as_str()),
+        LiveValue::InlineString(s) => Some(s.as_str()),
+        _ => None
+    }
+}
+
+// A field for a date that can be typed in as YYYY-MM-DD or picked from a calendar, which opens
+// with the button in the field or alt+down. The arrow keys step the date by days in the field and
+// move through the calendar while it is open, page up and down go a month, with shift a year.
+//
+// <DatePicker> {value: "2024-02-29", min: "2000-01-01"}
+#[derive(Live)]
+#[live_design_fn(widget_factory!(DatePicker))]
+pub struct DatePicker {
+    bg: DrawPickerField,
+    button: DrawCalendarButton,
+    text_input: TextInput,
+
+    walk: Walk,
+    layout: Layout,
+    button_walk: Walk,
+    state: State,
+
+    view: View,
+    // covers the window while the calendar is open, so a click anywhere else closes it
+    catcher: DrawColor,
+    panel: DrawCalendarPanel,
+    day: DrawCalendarDay,
+    prev: DrawCalendarArrow,
+    next: DrawCalendarArrow,
+    label: DrawCalendarText,
+    padding: Padding,
+    cell_size: f64,
+    header_height: f64,
+    sunday_first: bool,
+
+    // the dates are written as YYYY-MM-DD, an empty min or max leaves that side open
+    value: String,
+    min: String,
+    max: String,
+
+    #[rust] date: Option<CalendarDate>,
+    #[rust] min_date: Option<CalendarDate>,
+    #[rust] max_date: Option<CalendarDate>,
+    #[rust] invalid: bool,
+
+    #[rust] is_open: bool,
+    #[rust] take_focus: bool,
+    // the day the keyboard is on in the calendar, its month is the one shown
+    #[rust] cursor: Option<CalendarDate>,
+    #[rust] hover: Option<CalendarDate>,
+    #[rust] days: Vec<(CalendarDate, Rect)>,
+    #[rust] panel_rect: Rect,
+    #[rust] prev_rect: Rect,
+    #[rust] next_rect: Rect,
+}
+
+impl LiveHook for DatePicker {
+    fn after_apply(&mut self, cx: &mut Cx, from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        if from.is_from_doc() {
+            self.min_date = CalendarDate::parse(&self.min);
+            self.max_date = CalendarDate::parse(&self.max);
+            self.date = CalendarDate::parse(&self.value).map( | date | self.clamp(date));
+            self.update_text_input(cx);
+        }
+    }
+}
+
+#[derive(Clone, WidgetAction)]
+pub enum DatePickerAction {
+    Changed(CalendarDate),
+    None
+}
+
+impl DatePicker {
+    fn in_range(&self, date: CalendarDate) -> bool {
+        self.min_date.is_none_or( | min | date >= min) && self.max_date.is_none_or( | max | date <= max)
+    }
+
+    fn clamp(&self, date: CalendarDate) -> CalendarDate {
+        let date = self.min_date.map_or(date, | min | date.max(min));
+        self.max_date.map_or(date, | max | date.min(max))
+    }
+
+    pub fn date(&self) -> Option<CalendarDate> {
+        self.date
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.invalid
+    }
+
+    // sets the date from code, which does not send a Changed action
+    pub fn set_date(&mut self, cx: &mut Cx, date: Option<CalendarDate>) {
+        self.date = date.map( | date | self.clamp(date));
+        self.set_invalid(cx, false);
+        self.update_text_input(cx);
+        self.view.redraw(cx);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    fn set_invalid(&mut self, cx: &mut Cx, invalid: bool) {
+        if self.invalid != invalid {
+            self.invalid = invalid;
+            self.bg.redraw(cx);
+        }
+    }
+
+    fn update_text_input(&mut self, cx: &mut Cx) {
+        self.text_input.text = self.date.map(| date | date.to_string()).unwrap_or_default();
+        self.text_input.select_all();
+        self.text_input.redraw(cx);
+    }
+
+    fn change_date(&mut self, cx: &mut Cx, date: CalendarDate, dispatch_action: &mut dyn FnMut(&mut Cx, DatePickerAction)) {
+        let date = self.clamp(date);
+        self.set_invalid(cx, false);
+        if self.date != Some(date) {
+            self.date = Some(date);
+            dispatch_action(cx, DatePickerAction::Changed(date));
+        }
+        self.update_text_input(cx);
+    }
+
+    // what is typed in, or the date when that is not a date
+    fn typed_date(&self) -> Option<CalendarDate> {
+        CalendarDate::parse(&self.text_input.text).or(self.date)
+    }
+
+    pub fn open(&mut self, cx: &mut Cx) {
+        let start = self.typed_date()
+            .or(CalendarDate::today())
+            .unwrap_or(CalendarDate {year: 2000, month: 1, day: 1});
+        self.cursor = Some(self.clamp(start));
+        self.hover = None;
+        self.is_open = true;
+        self.take_focus = true;
+        self.view.redraw(cx);
+    }
+
+    // the key focus goes back to the text, unless it went somewhere else already
+    pub fn close(&mut self, cx: &mut Cx) {
+        self.close_calendar(cx, true);
+    }
+
+    fn close_calendar(&mut self, cx: &mut Cx, restore_focus: bool) {
+        if !self.is_open {
+            return
+        }
+        self.is_open = false;
+        self.days.clear();
+        if restore_focus {
+            self.text_input.set_key_focus(cx);
+        }
+        self.view.redraw(cx);
+    }
+
+    fn move_cursor(&mut self, cx: &mut Cx, cursor: CalendarDate) {
+        self.cursor = Some(self.clamp(cursor));
+        self.view.redraw(cx);
+    }
+
+    fn pick(&mut self, cx: &mut Cx, date: CalendarDate, dispatch_action: &mut dyn FnMut(&mut Cx, DatePickerAction)) {
+        if self.in_range(date) {
+            self.change_date(cx, date, dispatch_action);
+            self.close(cx);
+        }
+    }
+
+    fn handle_calendar(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, DatePickerAction)) {
+        let Some(cursor) = self.cursor else {return};
+        match event.hits(cx, self.catcher.area()) {
+            Hit::KeyDown(ke) => {
+                let months = if ke.modifiers.shift {12} else {1};
+                match ke.key_code {
+                    KeyCode::ArrowLeft => self.move_cursor(cx, cursor.add_days(-1)),
+                    KeyCode::ArrowRight => self.move_cursor(cx, cursor.add_days(1)),
+                    KeyCode::ArrowUp => self.move_cursor(cx, cursor.add_days(-7)),
+                    KeyCode::ArrowDown => self.move_cursor(cx, cursor.add_days(7)),
+                    KeyCode::PageUp => self.move_cursor(cx, cursor.add_months(-months)),
+                    KeyCode::PageDown => self.move_cursor(cx, cursor.add_months(months)),
+                    KeyCode::ReturnKey | KeyCode::NumpadEnter | KeyCode::Space => {
+                        self.pick(cx, cursor, dispatch_action);
+                    }
+                    KeyCode::Escape => self.close(cx),
+                    _ => ()
+                }
+            }
+            Hit::KeyFocusLost(_) => self.close_calendar(cx, false),
+            _ => ()
+        }
+        let day_at = | days: &[(CalendarDate, Rect)], pos: DVec2 | {
+            days.iter().find( | (_, rect) | rect.contains(pos)).map( | (date, _) | *date)
+        };
+        match event {
+            Event::FingerHover(fe) => {
+                let hover = day_at(&self.days, fe.abs);
+                if hover != self.hover {
+                    self.hover = hover;
+                    self.view.redraw(cx);
+                }
+            }
+            Event::FingerDown(fe) if !self.panel_rect.contains(fe.abs) => self.close(cx),
+            Event::FingerDown(fe) => {
+                if self.prev_rect.contains(fe.abs) {
+                    self.move_cursor(cx, cursor.first_of_month().add_months(-1));
+                }
+                else if self.next_rect.contains(fe.abs) {
+                    self.move_cursor(cx, cursor.first_of_month().add_months(1));
+                }
+                else if let Some(date) = day_at(&self.days, fe.abs) {
+                    self.pick(cx, date, dispatch_action);
+                }
+            }
+            _ => ()
+        }
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, DatePickerAction)) {
+        self.state_handle_event(cx, event);
+        if self.is_open {
+            self.handle_calendar(cx, event, dispatch_action);
+        }
+
+        match event.hits(cx, self.button.area()) {
+            Hit::FingerHoverIn(_) => {
+                cx.set_cursor(MouseCursor::Hand);
+                self.button.hover = 1.0;
+                self.button.redraw(cx);
+            }
+            Hit::FingerHoverOut(_) => {
+                self.button.hover = 0.0;
+                self.button.redraw(cx);
+            }
+            Hit::FingerDown(_) => self.open(cx),
+            _ => ()
+        }
+
+        for action in self.text_input.handle_event(cx, event) {
+            match action {
+                TextInputAction::KeyFocus => {
+                    self.animate_state(cx, id!(focus.on));
+                }
+                TextInputAction::KeyFocusLost => {
+                    self.animate_state(cx, id!(focus.off));
+                }
+                TextInputAction::Change(text) => {
+                    let valid = text.trim().is_empty() || CalendarDate::parse(&text).is_some_and( | date | self.in_range(date));
+                    self.set_invalid(cx, !valid);
+                }
+                TextInputAction::Return(text) => match CalendarDate::parse(&text) {
+                    Some(date) => self.change_date(cx, date, dispatch_action),
+                    // text that is not a date puts the date back
+                    None => {
+                        self.set_invalid(cx, false);
+                        self.update_text_input(cx);
+                    }
+                }
+                TextInputAction::Escape => {
+                    self.set_invalid(cx, false);
+                    self.update_text_input(cx);
+                }
+                _ => ()
+            }
+        }
+
+        match event.hits(cx, self.bg.area()) {
+            Hit::FingerHoverIn(_) => self.animate_state(cx, id!(hover.on)),
+            Hit::FingerHoverOut(_) => self.animate_state(cx, id!(hover.off)),
+            _ => ()
+        }
+
+        if let Event::KeyDown(ke) = event {
+            if self.text_input.has_key_focus(cx) {
+                let months = if ke.modifiers.shift {12} else {1};
+                let typed = self.typed_date();
+                match (ke.key_code, typed) {
+                    (KeyCode::ArrowDown, _) if ke.modifiers.alt => self.open(cx),
+                    (KeyCode::ArrowUp, Some(date)) => self.change_date(cx, date.add_days(1), dispatch_action),
+                    (KeyCode::ArrowDown, Some(date)) => self.change_date(cx, date.add_days(-1), dispatch_action),
+                    (KeyCode::PageUp, Some(date)) => self.change_date(cx, date.add_months(months), dispatch_action),
+                    (KeyCode::PageDown, Some(date)) => self.change_date(cx, date.add_months(-months), dispatch_action),
+                    _ => ()
+                }
+            }
+        }
+    }
+
+    fn text_size(&self, cx: &Cx2d, text: &str) -> DVec2 {
+        self.label.compute_geom(cx, Walk::fit(), text)
+            .map( | geom | dvec2(geom.measured_width, geom.measured_height))
+            .unwrap_or_default()
+    }
+
+    fn draw_centered(&mut self, cx: &mut Cx2d, rect: Rect, text: &str) {
+        let size = self.text_size(cx, text);
+        self.label.draw_abs(cx, rect.pos + (rect.size - size) * 0.5, text);
+    }
+
+    fn draw_calendar(&mut self, cx: &mut Cx2d, field: Rect) {
+        let Some(cursor) = self.cursor else {return};
+        self.view.begin_overlay(cx);
+        cx.begin_overlay_turtle(Layout::flow_down());
+        let pass_size = cx.current_pass_size();
+        self.catcher.draw_abs(cx, Rect {pos: DVec2::default(), size: pass_size});
+        cx.set_sweep_lock(self.catcher.area());
+
+        // below the field, or above it when there is no room below
+        let cell = self.cell_size;
+        let size = dvec2(
+            self.padding.left + 7.0 * cell + self.padding.right,
+            self.padding.top + self.header_height + 7.0 * cell + self.padding.bottom
+        );
+        let mut pos = dvec2(field.pos.x, field.pos.y + field.size.y);
+        if pos.y + size.y > pass_size.y {
+            pos.y = field.pos.y - size.y;
+        }
+        if pos.x + size.x > pass_size.x {
+            pos.x = pass_size.x - size.x;
+        }
+        pos = dvec2(pos.x.max(0.0), pos.y.max(0.0));
+        self.panel_rect = Rect {pos, size};
+        self.panel.draw_abs(cx, self.panel_rect);
+
+        // the month and the arrows to the ones around it
+        let left = pos.x + self.padding.left;
+        let mut y = pos.y + self.padding.top;
+        self.prev_rect = Rect {pos: dvec2(left, y), size: dvec2(cell, self.header_height)};
+        self.next_rect = Rect {pos: dvec2(left + 6.0 * cell, y), size: dvec2(cell, self.header_height)};
+        self.prev.draw_abs(cx, self.prev_rect);
+        self.next.draw_abs(cx, self.next_rect);
+        let month = cursor.first_of_month();
+        const MONTHS: [&str; 12] = [
+            "January", "February", "March", "April", "May", "June",
+            "July", "August", "September", "October", "November", "December"
+        ];
+        let title = format!("{} {}", MONTHS[month.month as usize - 1], month.year);
+        self.label.dim = 0.0;
+        self.draw_centered(cx, Rect {pos: dvec2(left + cell, y), size: dvec2(5.0 * cell, self.header_height)}, &title);
+        y += self.header_height;
+
+        const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+        let first_weekday = if self.sunday_first {6} else {0};
+        self.label.dim = 1.0;
+        for column in 0..7 {
+            let name = WEEKDAYS[(first_weekday + column) % 7];
+            self.draw_centered(cx, Rect {pos: dvec2(left + column as f64 * cell, y), size: dvec2(cell, cell)}, name);
+        }
+        y += cell;
+
+        // six weeks from the one the month starts in, which fits every month
+        let today = CalendarDate::today();
+        let offset = (month.weekday() as usize + 7 - first_weekday) % 7;
+        let start = month.add_days(-(offset as i64));
+        self.days.clear();
+        for index in 0..42 {
+            let date = start.add_days(index as i64);
+            let rect = Rect {
+                pos: dvec2(left + (index % 7) as f64 * cell, y + (index / 7) as f64 * cell),
+                size: dvec2(cell, cell)
+            };
+            let pickable = self.in_range(date);
+            self.day.hover = if pickable && (self.hover == Some(date) || cursor == date) {1.0} else {0.0};
+            self.day.selected = if self.date == Some(date) {1.0} else {0.0};
+            self.day.today = if today == Some(date) {1.0} else {0.0};
+            self.day.draw_abs(cx, rect);
+            self.label.dim = if pickable && date.month == month.month {0.0} else {1.0};
+            self.draw_centered(cx, rect, &date.day.to_string());
+            if pickable {
+                self.days.push((date, rect));
+            }
+        }
+
+        cx.end_overlay_turtle();
+        self.view.end(cx);
+
+        if self.take_focus {
+            self.take_focus = false;
+            cx.set_key_focus(self.catcher.area());
+        }
+    }
+
+    pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        self.bg.invalid = if self.invalid {1.0} else {0.0};
+        self.bg.begin(cx, walk, self.layout);
+        self.text_input.draw_walk(cx, self.text_input.get_walk());
+        cx.walk_turtle(Walk::size(Size::fill(), Size::Fixed(0.0)));
+        self.button.draw_walk(cx, self.button_walk);
+        self.bg.end(cx);
+
+        if self.is_open {
+            let field = self.bg.area().get_rect(cx);
+            self.draw_calendar(cx, field);
+        }
+        else {
+            cx.clear_sweep_lock(self.catcher.area());
+        }
+    }
+}
+
+impl Widget for DatePicker {
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.bg.redraw(cx);
+        self.view.redraw(cx);
+    }
+
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        self.draw_walk(cx, walk);
+        WidgetDraw::done()
+    }
+
+    fn bind_to(&mut self, cx: &mut Cx, db: &mut DataBinding, act: &WidgetActions, path: &[LiveId]) {
+        match db {
+            DataBinding::FromWidgets {nodes, ..} => if let Some(item) = act.find_single_action(self.widget_uid()) {
+                if let DatePickerAction::Changed(date) = item.action() {
+                    nodes.write_by_field_path(path, &[LiveNode::from_value(date.to_string().as_str().to_live_value())]);
+                }
+            }
+            DataBinding::ToWidgets {nodes, ..} => {
+                if let Some(text) = nodes.read_by_field_path(path).and_then(live_value_str) {
+                    let date = CalendarDate::parse(text);
+                    self.set_date(cx, date);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct DatePickerRef(WidgetRef);
+
+impl DatePickerRef {
+    pub fn set_date(&self, cx: &mut Cx, date: Option<CalendarDate>) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_date(cx, date);
+        }
+    }
+
+    pub fn date(&self) -> Option<CalendarDate> {
+        if let Some(inner) = self.inner() {
+            return inner.date()
+        }
+        None
+    }
+
+    pub fn changed(&self, actions: &WidgetActions) -> Option<CalendarDate> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let DatePickerAction::Changed(date) = item.action() {
+                return Some(date)
+            }
+        }
+        None
+    }
+}
+
+// A field for a time of the day, typed in as HH:MM or HH:MM:SS. The arrow keys step it by
+// minute_step minutes, or by hours with shift, and page up and down go an hour.
+//
+// <TimePicker> {value: "09:30", minute_step: 15}
+#[derive(Live)]
+#[live_design_fn(widget_factory!(TimePicker))]
+pub struct TimePicker {
+    bg: DrawPickerField,
+    text_input: TextInput,
+
+    walk: Walk,
+    layout: Layout,
+    state: State,
+
+    value: String,
+    minute_step: u32,
+    show_seconds: bool,
+
+    #[rust] time: Option<TimeOfDay>,
+    #[rust] invalid: bool,
+}
+
+impl LiveHook for TimePicker {
+    fn after_apply(&mut self, cx: &mut Cx, from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        if from.is_from_doc() {
+            self.time = TimeOfDay::parse(&self.value);
+            self.update_text_input(cx);
+        }
+    }
+}
+
+#[derive(Clone, WidgetAction)]
+pub enum TimePickerAction {
+    Changed(TimeOfDay),
+    None
+}
+
+impl TimePicker {
+    pub fn time(&self) -> Option<TimeOfDay> {
+        self.time
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.invalid
+    }
+
+    // sets the time from code, which does not send a Changed action
+    pub fn set_time(&mut self, cx: &mut Cx, time: Option<TimeOfDay>) {
+        self.time = time;
+        self.set_invalid(cx, false);
+        self.update_text_input(cx);
+    }
+
+    fn set_invalid(&mut self, cx: &mut Cx, invalid: bool) {
+        if self.invalid != invalid {
+            self.invalid = invalid;
+            self.bg.redraw(cx);
+        }
+    }
+
+    fn update_text_input(&mut self, cx: &mut Cx) {
+        self.text_input.text = self.time.map( | time | time.format(self.show_seconds)).unwrap_or_default();
+        self.text_input.select_all();
+        self.text_input.redraw(cx);
+    }
+
+    fn change_time(&mut self, cx: &mut Cx, time: TimeOfDay, dispatch_action: &mut dyn FnMut(&mut Cx, TimePickerAction)) {
+        // the seconds that are not shown can not be changed either
+        let time = if self.show_seconds {time} else {TimeOfDay {second: 0, ..time}};
+        self.set_invalid(cx, false);
+        if self.time != Some(time) {
+            self.time = Some(time);
+            dispatch_action(cx, TimePickerAction::Changed(time));
+        }
+        self.update_text_input(cx);
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, TimePickerAction)) {
+        self.state_handle_event(cx, event);
+        for action in self.text_input.handle_event(cx, event) {
+            match action {
+                TextInputAction::KeyFocus => {
+                    self.animate_state(cx, id!(focus.on));
+                }
+                TextInputAction::KeyFocusLost => {
+                    self.animate_state(cx, id!(focus.off));
+                }
+                TextInputAction::Change(text) => {
+                    let valid = text.trim().is_empty() || TimeOfDay::parse(&text).is_some();
+                    self.set_invalid(cx, !valid);
+                }
+                TextInputAction::Return(text) => match TimeOfDay::parse(&text) {
+                    Some(time) => self.change_time(cx, time, dispatch_action),
+                    // text that is not a time puts the time back
+                    None => {
+                        self.set_invalid(cx, false);
+                        self.update_text_input(cx);
+                    }
+                }
+                TextInputAction::Escape => {
+                    self.set_invalid(cx, false);
+                    self.update_text_input(cx);
+                }
+                _ => ()
+            }
+        }
+
+        match event.hits(cx, self.bg.area()) {
+            Hit::FingerHoverIn(_) => self.animate_state(cx, id!(hover.on)),
+            Hit::FingerHoverOut(_) => self.animate_state(cx, id!(hover.off)),
+            _ => ()
+        }
+
+        if let Event::KeyDown(ke) = event {
+            if self.text_input.has_key_focus(cx) {
+                let step = if ke.modifiers.shift {60} else {self.minute_step.max(1) as i64};
+                let typed = TimeOfDay::parse(&self.text_input.text).or(self.time);
+                match (ke.key_code, typed) {
+                    (KeyCode::ArrowUp, Some(time)) => self.change_time(cx, time.add_minutes(step), dispatch_action),
+                    (KeyCode::ArrowDown, Some(time)) => self.change_time(cx, time.add_minutes(-step), dispatch_action),
+                    (KeyCode::PageUp, Some(time)) => self.change_time(cx, time.add_minutes(60), dispatch_action),
+                    (KeyCode::PageDown, Some(time)) => self.change_time(cx, time.add_minutes(-60), dispatch_action),
+                    _ => ()
+                }
+            }
+        }
+    }
+
+    pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        self.bg.invalid = if self.invalid {1.0} else {0.0};
+        self.bg.begin(cx, walk, self.layout);
+        self.text_input.draw_walk(cx, self.text_input.get_walk());
+        self.bg.end(cx);
+    }
+}
+
+impl Widget for TimePicker {
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.bg.redraw(cx);
+    }
+
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        self.draw_walk(cx, walk);
+        WidgetDraw::done()
+    }
+
+    fn bind_to(&mut self, cx: &mut Cx, db: &mut DataBinding, act: &WidgetActions, path: &[LiveId]) {
+        match db {
+            DataBinding::FromWidgets {nodes, ..} => if let Some(item) = act.find_single_action(self.widget_uid()) {
+                if let TimePickerAction::Changed(time) = item.action() {
+                    let text = time.format(self.show_seconds);
+                    nodes.write_by_field_path(path, &[LiveNode::from_value(text.as_str().to_live_value())]);
+                }
+            }
+            DataBinding::ToWidgets {nodes, ..} => {
+                if let Some(text) = nodes.read_by_field_path(path).and_then(live_value_str) {
+                    let time = TimeOfDay::parse(text);
+                    self.set_time(cx, time);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct TimePickerRef(WidgetRef);
+
+impl TimePickerRef {
+    pub fn set_time(&self, cx: &mut Cx, time: Option<TimeOfDay>) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_time(cx, time);
+        }
+    }
+
+    pub fn time(&self) -> Option<TimeOfDay> {
+        if let Some(inner) = self.inner() {
+            return inner.time()
+        }
+        None
+    }
+
+    pub fn changed(&self, actions: &WidgetActions) -> Option<TimeOfDay> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let TimePickerAction::Changed(time) = item.action() {
+                return Some(time)
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn calendar_days() {
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(date(1969, 12, 31).to_days(), -1);
+        assert_eq!(date(2000, 3, 1).to_days(), 11017);
+        assert_eq!(CalendarDate::from_days(11016), date(2000, 2, 29));
+        // round trips across leap days, century years and dates before the epoch
+        for days in (-800_000..800_000).step_by(997) {
+            assert_eq!(CalendarDate::from_days(days).to_days(), days);
+        }
+        assert_eq!(date(1970, 1, 1).weekday(), 3);
+        assert_eq!(date(1969, 12, 31).weekday(), 2);
+        assert_eq!(date(2000, 1, 1).weekday(), 5);
+        assert_eq!(date(2024, 1, 1).weekday(), 0);
+    }
+
+    #[test]
+    fn calendar_months() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 11, 15).add_months(3), date(2025, 2, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 3, 31).add_months(-25), date(2022, 2, 28));
+        assert_eq!(CalendarDate::parse(" 2024-02-29 "), Some(date(2024, 2, 29)));
+        assert_eq!(CalendarDate::parse("2023-02-29"), None);
+        assert_eq!(CalendarDate::parse("2024-02"), None);
+        assert_eq!(CalendarDate::parse("2024-02-01-01"), None);
+    }
+
+    #[test]
+    fn time_of_day_parse() {
+        assert_eq!(TimeOfDay::parse("09:30"), TimeOfDay::new(9, 30, 0));
+        assert_eq!(TimeOfDay::parse(" 23:59:59 "), TimeOfDay::new(23, 59, 59));
+        assert_eq!(TimeOfDay::parse("24:00"), None);
+        assert_eq!(TimeOfDay::parse("12:60"), None);
+        assert_eq!(TimeOfDay::parse("12"), None);
+        assert_eq!(TimeOfDay::parse("12:00:00:00"), None);
+        assert_eq!(TimeOfDay::parse("ab:cd"), None);
+        assert_eq!(TimeOfDay::new(23, 45, 0).unwrap().add_minutes(30), TimeOfDay::new(0, 15, 0).unwrap());
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     debug_view::DebugView,
     makepad_draw_2d::*,
     nav_control::NavControl,
-    window_menu::*,
+    menu_bar::MenuBar,
     button::*,
     widget::*,
     frame::*,
@@ -12,9 +12,11 @@ live_design!{
     import crate::theme::*;
     registry Widget::*;
     import makepad_widgets::frame::*;
+    import makepad_widgets::menu_bar::MenuBar;
     
     DesktopWindow= {{DesktopWindow}} {
         pass: {clear_color: (COLOR_CLEAR)}
+        menu_bar: <MenuBar> {}
         var caption =  "Makepad"
         frame: {
             layout: {
@@ -55,7 +57,8 @@ pub struct DesktopWindow {
     
     frame: FrameRef,
     
-    #[rust(WindowMenu::new(cx))] pub window_menu: WindowMenu,
+    // the menu passed to begin, where macos has no native menu bar
+    menu_bar: MenuBar,
     #[rust(Menu::main(vec![
         Menu::sub("App", vec![
             //Menu::item("Quit App", Cx::command_quit()),
@@ -68,6 +71,8 @@ pub struct DesktopWindow {
     
     // testing
     #[rust] pub inner_over_chrome: bool,
+    
+    #[rust] window_geom: Option<WindowGeom>,
 }
 
 impl LiveHook for DesktopWindow {
@@ -80,6 +85,20 @@ impl LiveHook for DesktopWindow {
     }
 }
 
+// the window geometry applies to the window before it gets created
+impl LiveRead for DesktopWindow {
+    fn live_read_to(&self, id: LiveId, out: &mut Vec<LiveNode>) {
+        out.open_object(id);
+        if let Some(geom) = &self.window_geom {
+            out.open_object(live_id!(window));
+            geom.inner_size.live_read_to(live_id!(inner_size), out);
+            geom.position.live_read_to(live_id!(position), out);
+            out.close();
+        }
+        out.close();
+    }
+}
+
 #[derive(Clone)]
 pub enum DesktopWindowEvent {
     EventForOtherWindow,
@@ -95,6 +114,7 @@ impl DesktopWindow {
         self.debug_view.handle_event(cx,event);
         self.nav_control.handle_event(cx, event, self.main_view.draw_list_id());
         self.overlay.handle_event(cx, event);
+        self.menu_bar.handle_event_fn(cx, event, &mut | _, _ | ());
         let actions = self.frame.handle_event(cx, event);
         if actions.not_empty(){
             if self.frame.get_button(id!(min_btn)).clicked(&actions){
@@ -142,6 +162,7 @@ impl DesktopWindow {
             }
             Event::WindowGeomChange(ev) => {
                 if ev.window_id == self.window.window_id() {
+                    self.window_geom = Some(ev.new_geom.clone());
                     return dispatch_action(cx, DesktopWindowEvent::WindowGeomChange(ev.clone()))
                 }
                 true
@@ -173,7 +194,7 @@ impl DesktopWindow {
         }
     }
     
-    pub fn begin(&mut self, cx: &mut Cx2d, _menu: Option<&Menu>) -> ViewRedrawing {
+    pub fn begin(&mut self, cx: &mut Cx2d, menu: Option<&Menu>) -> ViewRedrawing {
         if !cx.view_will_redraw(&self.main_view) {
             return ViewRedrawing::no()
         }
@@ -187,6 +208,20 @@ impl DesktopWindow {
         cx.begin_turtle(Walk::fixed_size(pass_size), Layout::flow_down());
         
         self.overlay.begin(cx);
+        
+        if let Some(menu) = menu {
+            if let OsType::OSX = cx.platform_type() {
+                if self.last_menu.as_ref() != Some(menu) {
+                    self.last_menu = Some(menu.clone());
+                    cx.update_menu(menu.clone());
+                }
+            }
+            else {
+                self.menu_bar.set_menu(cx, menu.clone());
+                let walk = self.menu_bar.get_walk();
+                self.menu_bar.draw_walk(cx, walk);
+            }
+        }
 
         //while self.frame.draw(cx).is_ok(){}
         if self.frame.draw(cx).is_done() {
@@ -1,4 +1,5 @@
 use {
+    std::collections::HashMap,
     crate::{
         makepad_draw_2d::*,
         splitter::{SplitterAction, Splitter, SplitterAlign},
@@ -80,6 +81,14 @@ pub struct Dock {
     #[rust] panels: ComponentMap<PanelId, Panel>,
     #[rust] panel_id_stack: Vec<PanelId>,
     #[rust] drag: Option<Drag>,
+    #[rust] items: HashMap<PanelId, DockItem>,
+}
+
+// The panel tree as last drawn by the app, or as restored from a persisted snapshot
+#[derive(Clone, Debug)]
+pub enum DockItem {
+    Split {axis: Axis, align: SplitterAlign, children: [PanelId; 2]},
+    Tab {tabs: Vec<TabId>, selected_tab: Option<TabId>},
 }
 
 impl LiveHook for Dock {
@@ -96,11 +105,164 @@ impl LiveHook for Dock {
         }
         self.area.redraw(cx);
     }
+    
+    fn apply_value_unknown(&mut self, cx: &mut Cx, from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        match nodes[index].id {
+            // restored panel tree, the splitters and tab bars are rebuilt from it right away
+            live_id!(panels) if nodes[index].is_open() => {
+                self.items.clear();
+                let mut node_index = index + 1;
+                while !nodes[node_index].is_close() {
+                    let panel_id = PanelId(nodes[node_index].id);
+                    if let Some(item) = DockItem::from_nodes(cx, from, node_index, nodes) {
+                        self.restore_panel(cx, panel_id, &item);
+                        self.items.insert(panel_id, item);
+                    }
+                    node_index = nodes.skip_node(node_index);
+                }
+                node_index + 1
+            }
+            _ => {
+                if !nodes[index].origin.node_has_prefix() {
+                    cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+                }
+                nodes.skip_node(index)
+            }
+        }
+    }
+}
+
+impl LiveRead for Dock {
+    fn live_read_to(&self, id: LiveId, out: &mut Vec<LiveNode>) {
+        out.open_object(id);
+        out.open_object(live_id!(panels));
+        for (panel_id, item) in &self.items {
+            item.live_read_to(panel_id.0, out);
+        }
+        out.close();
+        out.close();
+    }
+}
+
+impl LiveRead for DockItem {
+    fn live_read_to(&self, id: LiveId, out: &mut Vec<LiveNode>) {
+        out.open_object(id);
+        match self {
+            Self::Split {axis, align, children} => {
+                axis.live_read_to(live_id!(axis), out);
+                align.live_read_to(live_id!(align), out);
+                out.open_array(live_id!(children));
+                for child_id in children {
+                    out.push_id(LiveId(0), child_id.0);
+                }
+                out.close();
+            }
+            Self::Tab {tabs, selected_tab} => {
+                out.open_array(live_id!(tabs));
+                for tab_id in tabs {
+                    out.push_id(LiveId(0), tab_id.0);
+                }
+                out.close();
+                if let Some(tab_id) = selected_tab {
+                    out.push_id(live_id!(selected_tab), tab_id.0);
+                }
+            }
+        }
+        out.close();
+    }
+}
+
+impl DockItem {
+    // a split has a children array, a tab panel a tabs array, anything else is skipped
+    fn from_nodes(cx: &mut Cx, from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> Option<Self> {
+        if !nodes[index].is_object() {
+            return None
+        }
+        let mut axis = Axis::Horizontal;
+        let mut align = SplitterAlign::Weighted(0.5);
+        let mut children = None;
+        let mut tabs = None;
+        let mut selected_tab = None;
+        let mut node_index = index + 1;
+        while !nodes[node_index].is_close() {
+            match nodes[node_index].id {
+                live_id!(axis) => axis = Axis::new_apply_mut_index(cx, from, &mut node_index, nodes),
+                live_id!(align) => align = SplitterAlign::new_apply_mut_index(cx, from, &mut node_index, nodes),
+                live_id!(children) => {
+                    let ids = read_id_array(node_index, nodes);
+                    if ids.len() == 2 {
+                        children = Some([PanelId(ids[0]), PanelId(ids[1])]);
+                    }
+                    node_index = nodes.skip_node(node_index);
+                }
+                live_id!(tabs) => {
+                    tabs = Some(read_id_array(node_index, nodes).into_iter().map(TabId).collect());
+                    node_index = nodes.skip_node(node_index);
+                }
+                live_id!(selected_tab) => {
+                    if let LiveValue::Id(id) = nodes[node_index].value {
+                        selected_tab = Some(TabId(id));
+                    }
+                    node_index = nodes.skip_node(node_index);
+                }
+                _ => node_index = nodes.skip_node(node_index)
+            }
+        }
+        match (children, tabs) {
+            (Some(children), _) => Some(Self::Split {axis, align, children}),
+            (None, Some(tabs)) => Some(Self::Tab {tabs, selected_tab}),
+            (None, None) => None
+        }
+    }
+}
+
+fn read_id_array(index: usize, nodes: &[LiveNode]) -> Vec<LiveId> {
+    let mut ids = Vec::new();
+    if nodes[index].is_array() {
+        let mut node_index = index + 1;
+        while !nodes[node_index].is_close() {
+            if let LiveValue::Id(id) = nodes[node_index].value {
+                ids.push(id);
+            }
+            node_index = nodes.skip_node(node_index);
+        }
+    }
+    ids
 }
 
 impl Dock {
     
+    // the panel tree as last drawn or restored, for the app to rebuild its own panel state from
+    pub fn items(&self) -> &HashMap<PanelId, DockItem> {
+        &self.items
+    }
+    
+    fn restore_panel(&mut self, cx: &mut Cx, panel_id: PanelId, item: &DockItem) {
+        match item {
+            DockItem::Split {axis, align, ..} => {
+                let panel = self.get_or_create_split_panel(cx, panel_id);
+                panel.splitter.set_axis(*axis);
+                panel.splitter.set_align(*align);
+            }
+            DockItem::Tab {selected_tab, ..} => {
+                let panel = self.get_or_create_tab_panel(cx, panel_id);
+                panel.tab_bar.set_selected_tab_id(cx, *selected_tab, Animate::No);
+            }
+        }
+    }
+    
+    // records a newly begun panel in the split that contains it
+    fn push_item_child(&mut self, panel_id: PanelId) {
+        if let Some(parent_id) = self.panel_id_stack.last() {
+            if let Some(DockItem::Split {children, ..}) = self.items.get_mut(parent_id) {
+                let slot = if children[0].0.is_empty() {0} else {1};
+                children[slot] = panel_id;
+            }
+        }
+    }
+    
     pub fn begin(&mut self, cx: &mut Cx2d) {
+        self.items.clear();
         cx.begin_turtle(Walk::default(), self.layout);
     }
     
@@ -159,6 +321,8 @@ impl Dock {
         panel.splitter.set_axis(axis);
         panel.splitter.set_align(align);
         panel.splitter.begin(cx, Walk::default());
+        self.push_item_child(panel_id);
+        self.items.insert(panel_id, DockItem::Split {axis, align, children: Default::default()});
         self.panel_id_stack.push(panel_id);
     }
     
@@ -176,6 +340,8 @@ impl Dock {
     
     pub fn begin_tab_panel(&mut self, cx: &mut Cx2d, panel_id: PanelId) {
         self.get_or_create_tab_panel(cx, panel_id);
+        self.push_item_child(panel_id);
+        self.items.insert(panel_id, DockItem::Tab {tabs: Vec::new(), selected_tab: None});
         self.panel_id_stack.push(panel_id);
     }
     
@@ -194,6 +360,10 @@ impl Dock {
         let panel_id = *self.panel_id_stack.last().unwrap();
         let panel = self.panels[panel_id].as_tab_panel_mut();
         panel.tab_bar.end(cx);
+        let selected = panel.tab_bar.selected_tab_id();
+        if let Some(DockItem::Tab {selected_tab, ..}) = self.items.get_mut(&panel_id) {
+            *selected_tab = selected;
+        }
         //self.contents(cx);
     }
     
@@ -201,6 +371,9 @@ impl Dock {
         let panel_id = *self.panel_id_stack.last().unwrap();
         let panel = self.panels[panel_id].as_tab_panel_mut();
         panel.tab_bar.draw_tab(cx, tab_id, name);
+        if let Some(DockItem::Tab {tabs, ..}) = self.items.get_mut(&panel_id) {
+            tabs.push(tab_id);
+        }
     }
     
     pub fn set_split_panel_axis(&mut self, cx: &mut Cx, panel_id: PanelId, axis: Axis) {
@@ -332,7 +332,11 @@ impl DropDown {
         }
         self.bg.end(cx);
         
-        cx.add_nav_stop(self.bg.area(), NavRole::DropDown, Margin::default());
+        cx.add_nav_stop_with_info(self.bg.area(), NavRole::DropDown, Margin::default(), NavInfo {
+            name: String::new(),
+            value: self.labels.get(self.selected_item).cloned(),
+            state: NavState {expanded: Some(self.is_open), ..NavState::default()}
+        });
         
         if self.is_open && self.popup_menu.is_some() {
             let last_rect = self.last_rect.unwrap_or(Rect::default());
@@ -283,6 +283,42 @@ impl LiveHook for FileTree {
         }
         self.scroll_bars.redraw(cx);
     }
+    
+    fn apply_value_unknown(&mut self, cx: &mut Cx, _from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        match nodes[index].id {
+            // restored set of open folders
+            live_id!(open_nodes) if nodes[index].is_array() => {
+                self.open_nodes.clear();
+                let mut node_index = index + 1;
+                while !nodes[node_index].is_close() {
+                    if let LiveValue::Id(id) = nodes[node_index].value {
+                        self.open_nodes.insert(id.into());
+                    }
+                    node_index = nodes.skip_node(node_index);
+                }
+                nodes.skip_node(index)
+            }
+            _ => {
+                if !nodes[index].origin.node_has_prefix() {
+                    cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+                }
+                nodes.skip_node(index)
+            }
+        }
+    }
+}
+
+impl LiveRead for FileTree {
+    fn live_read_to(&self, id: LiveId, out: &mut Vec<LiveNode>) {
+        out.open_object(id);
+        self.scroll_bars.live_read_to(live_id!(scroll_bars), out);
+        out.open_array(live_id!(open_nodes));
+        for node_id in &self.open_nodes {
+            out.push_id(LiveId(0), node_id.0);
+        }
+        out.close();
+        out.close();
+    }
 }
 
 pub enum FileTreeAction {
@@ -312,7 +348,7 @@ impl FileTreeNode {
     pub fn draw_folder(&mut self, cx: &mut Cx2d, name: &str, is_even: f32, node_height: f64, depth: usize, scale: f64) {
         self.set_draw_state(is_even, scale);
         
-        self.bg.begin(cx, Walk::size(Size::Fill, Size::Fixed(scale * node_height)), self.layout);
+        self.bg.begin(cx, Walk::size(Size::fill(), Size::Fixed(scale * node_height)), self.layout);
         
         cx.walk_turtle(self.indent_walk(depth));
         
@@ -325,7 +361,7 @@ impl FileTreeNode {
     pub fn draw_file(&mut self, cx: &mut Cx2d, name: &str, is_even: f32, node_height: f64, depth: usize, scale: f64) {
         self.set_draw_state(is_even, scale);
         
-        self.bg.begin(cx, Walk::size(Size::Fill, Size::Fixed(scale * node_height)), self.layout);
+        self.bg.begin(cx, Walk::size(Size::fill(), Size::Fixed(scale * node_height)), self.layout);
         
         cx.walk_turtle(self.indent_walk(depth));
         
@@ -344,6 +380,7 @@ impl FileTreeNode {
                 right: depth as f64 * 4.0,
                 bottom: 0.0,
             },
+            ..Walk::default()
         }
     }
     
@@ -415,7 +452,7 @@ impl FileTree {
         while walk < height_left {
             self.count += 1;
             self.filler.is_even = Self::is_even(self.count);
-            self.filler.draw_walk(cx, Walk::size(Size::Fill, Size::Fixed(self.node_height.min(height_left - walk))));
+            self.filler.draw_walk(cx, Walk::size(Size::fill(), Size::Fixed(self.node_height.min(height_left - walk))));
             walk += self.node_height.max(1.0);
         }
         
@@ -433,7 +470,7 @@ impl FileTree {
     pub fn should_node_draw(&mut self, cx: &mut Cx2d) -> bool {
         let scale = self.stack.last().cloned().unwrap_or(1.0);
         let height = self.node_height * scale;
-        let walk = Walk::size(Size::Fill, Size::Fixed(height));
+        let walk = Walk::size(Size::fill(), Size::Fixed(height));
         if scale > 0.01 && cx.walk_turtle_would_be_visible(walk) {
             return true
         }
@@ -4,9 +4,15 @@ use {
         makepad_derive_widget::*,
         makepad_image_formats::jpeg,
         makepad_image_formats::png,
+        makepad_image_formats::gif,
+        makepad_image_formats::webp,
+        makepad_image_formats::qoi,
+        makepad_image_formats::AnimatedImage,
         makepad_draw_2d::*,
+        makepad_draw_2d::makepad_vector::svg::SvgDocument,
         widget::*,
         scroll_bars::ScrollBars,
+        svg::{parse_svg_dependency, update_svg_texture},
     },
 };
 
@@ -39,12 +45,22 @@ pub struct Frame { // draw info per UI element
     
     pub layout: Layout,
     
+    pub grid: GridLayout,
+    
     pub walk: Walk,
     
     image: LiveDependency,
     
     image_texture: Texture,
     
+    #[rust] svg_document: Option<SvgDocument>,
+    #[rust] svg_texture_size: (usize, usize),
+    
+    #[rust] animation: Option<AnimatedImage>,
+    #[rust] animation_start: Option<f64>,
+    #[rust] animation_frame: usize,
+    #[rust] next_frame: NextFrame,
+    
     has_view: bool,
     #[live(true)] visible: bool,
     user_draw: bool,
@@ -82,6 +98,8 @@ impl LiveHook for Frame {
         let image_path = self.image.as_ref();
         if image_path.len()>0 {
             let mut image_buffer = None;
+            self.svg_document = None;
+            self.animation = None;
             match cx.get_dependency(image_path) {
                 Ok(data) => {
                     if image_path.ends_with(".jpg") {
@@ -104,6 +122,50 @@ impl LiveHook for Frame {
                             }
                         }
                     }
+                    else if image_path.ends_with(".gif") || image_path.ends_with(".webp") {
+                        let animation = if image_path.ends_with(".gif") {
+                            gif::decode_animation(data)
+                        }
+                        else {
+                            webp::decode_animation(data)
+                        };
+                        match animation {
+                            Ok(animation) => {
+                                image_buffer = Some(animation.frames[0].image.clone());
+                                if animation.frames.len() > 1 {
+                                    self.animation = Some(animation);
+                                    self.animation_start = None;
+                                    self.animation_frame = 0;
+                                    self.next_frame = cx.new_next_frame();
+                                }
+                            }
+                            Err(err) => {
+                                cx.apply_image_decoding_failed(live_error_origin!(), index, nodes, image_path, &err);
+                            }
+                        }
+                    }
+                    else if image_path.ends_with(".qoi") {
+                        match qoi::decode(data) {
+                            Ok(image) => {
+                                image_buffer = Some(image);
+                            }
+                            Err(err) => {
+                                cx.apply_image_decoding_failed(live_error_origin!(), index, nodes, image_path, &err);
+                            }
+                        }
+                    }
+                    else if image_path.ends_with(".svg") {
+                        match parse_svg_dependency(data) {
+                            Ok(document) => {
+                                // the document is rasterized once the frame knows its size
+                                self.svg_document = Some(document);
+                                self.svg_texture_size = (0, 0);
+                            }
+                            Err(err) => {
+                                cx.apply_image_decoding_failed(live_error_origin!(), index, nodes, image_path, &err);
+                            }
+                        }
+                    }
                     else {
                         cx.apply_image_type_not_supported(live_error_origin!(), index, nodes, image_path);
                     }
@@ -215,6 +277,10 @@ impl Widget for Frame {
             }
         }
         
+        if let Some(ne) = self.next_frame.is_event(event) {
+            self.play_animation(cx, ne.time);
+        }
+        
         if let Some(cursor) = &self.cursor {
             match event.hits(cx, self.area()) {
                 Hit::FingerDown(_) => {
@@ -396,6 +462,24 @@ impl Frame {
         self.area
     }
     
+    // shows the frame of an animated image that is due at `time`, and keeps asking for
+    // frames until the last loop is over
+    fn play_animation(&mut self, cx: &mut Cx, time: f64) {
+        let animation = if let Some(animation) = &self.animation {animation} else {return};
+        let start = *self.animation_start.get_or_insert(time);
+        let elapsed = time - start;
+        let frame = animation.frame_at_time(elapsed);
+        if frame != self.animation_frame {
+            self.animation_frame = frame;
+            let mut data = animation.frames[frame].image.data.clone();
+            self.image_texture.swap_image_u32(cx, &mut data);
+            self.area.redraw(cx);
+        }
+        if animation.loop_count == 0 || elapsed < animation.duration() * animation.loop_count as f64 {
+            self.next_frame = cx.new_next_frame();
+        }
+    }
+    
     pub fn draw(&mut self, cx: &mut Cx2d,) -> WidgetDraw {
         self.draw_walk(cx, self.get_walk())
     }
@@ -433,6 +517,7 @@ impl Frame {
             else {
                 cx.begin_turtle(walk, self.layout.with_scroll(scroll));
             }
+            cx.turtle_mut().set_grid(&self.grid);
             
             if self.user_draw {
                 return WidgetDraw::not_done(WidgetRef::empty())
@@ -475,6 +560,10 @@ impl Frame {
                 if self.bg.shape != Shape::None {
                     self.bg.end(cx);
                     self.area = self.bg.area();
+                    if let Some(document) = &self.svg_document {
+                        let size = self.area.get_rect(cx).size;
+                        update_svg_texture(cx, document, &self.image_texture, size, &mut self.svg_texture_size);
+                    }
                 }
                 else {
                     cx.end_turtle_with_area(&mut self.area);
@@ -37,12 +37,20 @@ pub mod log_list;
 pub mod log_icon;
 pub mod color_picker;
 
-#[macro_use]
-pub mod window_menu;
 pub mod debug_view;
 pub mod nav_control;
 
 pub mod frame;
+pub mod portal_list;
+pub mod table;
+pub mod modal;
+pub mod tooltip;
+pub mod toast;
+pub mod chart;
+pub mod menu_bar;
+pub mod number_input;
+pub mod date_picker;
+pub mod svg;
 pub mod widget;
 
 #[macro_use]
@@ -55,6 +63,16 @@ pub use crate::{
     bare_window::BareWindow,
     button::*,
     frame::*,
+    portal_list::*,
+    table::*,
+    modal::*,
+    tooltip::*,
+    toast::*,
+    chart::*,
+    menu_bar::*,
+    number_input::*,
+    date_picker::*,
+    svg::*,
     label::*,
     slider::*,
     check_box::*,
@@ -95,6 +113,16 @@ pub fn live_design(cx: &mut Cx) {
     crate::label::live_design(cx);
     crate::nav_control::live_design(cx);
     crate::frame::live_design(cx);
+    crate::portal_list::live_design(cx);
+    crate::table::live_design(cx);
+    crate::modal::live_design(cx);
+    crate::tooltip::live_design(cx);
+    crate::toast::live_design(cx);
+    crate::chart::live_design(cx);
+    crate::menu_bar::live_design(cx);
+    crate::number_input::live_design(cx);
+    crate::date_picker::live_design(cx);
+    crate::svg::live_design(cx);
     crate::fold_button::live_design(cx);
     crate::text_input::live_design(cx);
     crate::link_label::live_design(cx);
@@ -103,7 +131,6 @@ pub fn live_design(cx: &mut Cx) {
     crate::desktop_button::live_design(cx);
     crate::desktop_window::live_design(cx);
     crate::bare_window::live_design(cx);
-    crate::window_menu::live_design(cx);
     crate::scroll_bar::live_design(cx);
     crate::scroll_bars::live_design(cx);
     crate::check_box::live_design(cx);
@@ -119,3 +146,14 @@ pub fn live_design(cx: &mut Cx) {
     crate::popup_menu::live_design(cx);
     crate::drop_down::live_design(cx);
 }
+
+// a Cx with the widgets registered and expanded, and the widget `id` from makepad_widgets::<module>
+#[cfg(test)]
+pub(crate) fn test_widget<T: LiveNew>(module: &str, id: LiveId) -> (Cx, T) {
+    let mut cx = Cx::new(Box::new( | _, _ | {}));
+    live_design(&mut cx);
+    cx.live_expand();
+    let module_id = LiveModuleId::from_str(&format!("makepad_widgets::{}", module)).unwrap();
+    let widget = T::new_from_module(&mut cx, module_id, id).unwrap();
+    (cx, widget)
+}
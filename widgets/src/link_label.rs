@@ -24,7 +24,7 @@ live_design!{
                         COLOR_TEXT_DEFAULT,
                         COLOR_TEXT_META,
                         self.pressed
-                    ), mix(0.0, THICKNESS, self.hover));
+                    ), mix(0.0, THICKNESS, max(self.hover, self.focus)));
                 }
             }
             label: {
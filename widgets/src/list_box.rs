@@ -5,6 +5,7 @@ use {
     crate::{
         makepad_derive_widget::*,
         scroll_bars::ScrollBars,
+        button::is_activate_key,
         makepad_draw_2d::*,
         widget::*,
     },
@@ -16,7 +17,8 @@ live_design!{
     
     DrawBgQuad = {{DrawBgQuad}} {
         fn pixel(self) -> vec4 {
-            return mix(
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.clear(mix(
                 mix(
                     COLOR_BG_EDITOR,
                     COLOR_BG_ODD,
@@ -24,7 +26,11 @@ live_design!{
                 ),
                 COLOR_BG_SELECTED,
                 self.selected
-            );
+            ));
+            // the focus ring
+            sdf.box(0.5, 0.5, self.rect_size.x - 1.0, self.rect_size.y - 1.0, 1.0);
+            sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0);
+            return sdf.result;
         }
     }
     
@@ -81,6 +87,18 @@ live_design!{
                     apply: {selected: 1.0}
                 }
             }
+            
+            focus = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg_quad: {focus: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg_quad: {focus: 1.0}}
+                }
+            }
         }
         
         indent_width: 10.0
@@ -103,6 +121,7 @@ struct DrawBgQuad {
     is_even: f32,
     selected: f32,
     hover: f32,
+    focus: f32,
 }
 
 #[derive(Live, LiveHook)]#[repr(C)]
@@ -193,9 +212,16 @@ impl ListBoxItem {
         node_height: f64,
     ) {
         self.set_draw_state(is_even);
-        self.bg_quad.begin(cx, Walk::size(Size::Fill, Size::Fixed(node_height)), self.layout);
+        self.bg_quad.begin(cx, Walk::size(Size::fill(), Size::Fixed(node_height)), self.layout);
         self.name_text.draw_walk(cx, Walk::fit(), Align::default(), label);
         self.bg_quad.end(cx);
+        
+        let selected = self.state.is_in_state(cx, id!(select.on));
+        cx.add_nav_stop_with_info(self.bg_quad.area(), NavRole::ListItem, Margin::default(), NavInfo {
+            name: label.to_string(),
+            value: None,
+            state: NavState {selected: Some(selected), ..NavState::default()}
+        });
     }
     
     pub fn set_is_selected(&mut self, cx: &mut Cx, is_selected: bool, animate: Animate) {
@@ -228,6 +254,16 @@ impl ListBoxItem {
                 self.animate_state(cx, id!(select.on));
                 dispatch_action(cx, ListBoxNodeAction::WasClicked);
             }
+            Hit::KeyFocus(_) => {
+                self.animate_state(cx, id!(focus.on));
+            }
+            Hit::KeyFocusLost(_) => {
+                self.animate_state(cx, id!(focus.off));
+            }
+            Hit::KeyDown(ke) if !ke.is_repeat && is_activate_key(ke.key_code) => {
+                self.animate_state(cx, id!(select.on));
+                dispatch_action(cx, ListBoxNodeAction::WasClicked);
+            }
             _ => {}
         }
     }
@@ -247,7 +283,7 @@ impl ListBox {
         while walk < height_left {
             self.count += 1;
             self.filler_quad.is_even = Self::is_even(self.count);
-            self.filler_quad.draw_walk(cx, Walk::size(Size::Fill, Size::Fixed(self.node_height.min(height_left - walk))));
+            self.filler_quad.draw_walk(cx, Walk::size(Size::fill(), Size::Fixed(self.node_height.min(height_left - walk))));
             walk += self.node_height.max(1.0);
         }
         
@@ -283,7 +319,7 @@ impl ListBox {
     
     pub fn should_node_draw(&mut self, cx: &mut Cx2d) -> bool {
         let height = self.node_height;
-        let walk = Walk::size(Size::Fill, Size::Fixed(height));
+        let walk = Walk::size(Size::fill(), Size::Fixed(height));
         if cx.walk_turtle_would_be_visible(walk) {
             return true
         }
@@ -219,7 +219,7 @@ impl LogListNode {
     ) {
         self.set_draw_state(is_even);
         
-        self.bg.begin(cx, Walk::size(Size::Fill, Size::Fixed(node_height)), self.layout);
+        self.bg.begin(cx, Walk::size(Size::fill(), Size::Fixed(node_height)), self.layout);
         
         // lets draw a fold button
         //self.fold_button.draw_walk(cx, self.fold_button.get_walk());
@@ -302,7 +302,7 @@ impl LogList {
         while walk < height_left {
             self.count += 1;
             self.filler_quad.is_even = Self::is_even(self.count);
-            self.filler_quad.draw_walk(cx, Walk::size(Size::Fill, Size::Fixed(self.node_height.min(height_left - walk))));
+            self.filler_quad.draw_walk(cx, Walk::size(Size::fill(), Size::Fixed(self.node_height.min(height_left - walk))));
             walk += self.node_height.max(1.0);
         }
         self.scroll_shadow.draw(cx, dvec2(0., 0.));
@@ -356,7 +356,7 @@ impl LogList {
     
     pub fn should_node_draw(&mut self, cx: &mut Cx2d) -> bool {
         let height = self.node_height;
-        let walk = Walk::size(Size::Fill, Size::Fixed(height));
+        let walk = Walk::size(Size::fill(), Size::Fixed(height));
         if cx.walk_turtle_would_be_visible(walk) {
             return true
         }
@@ -0,0 +1,981 @@
+use {
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        frame::FrameRef,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+    import makepad_widgets::frame::Frame;
+
+    DrawMenuPanel = {{DrawMenuPanel}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, self.radius);
+            sdf.fill_keep(self.color);
+            sdf.stroke(COLOR_UP_10, 1.0);
+            return sdf.result;
+        }
+    }
+
+    DrawMenuItem = {{DrawMenuItem}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 0.0, self.rect_size.x - 2.0, self.rect_size.y, 2.0);
+            sdf.fill(mix(#fff0, COLOR_BG_SELECTED, self.hover));
+            // the arrow of an item that opens a submenu
+            let c = vec2(self.rect_size.x - 10.0, 0.5 * self.rect_size.y);
+            sdf.move_to(c.x - 2.0, c.y - 4.0);
+            sdf.line_to(c.x + 2.0, c.y);
+            sdf.line_to(c.x - 2.0, c.y + 4.0);
+            sdf.stroke(mix(#fff0, COLOR_TEXT_DEFAULT, self.sub), 1.0);
+            return sdf.result;
+        }
+    }
+
+    DrawMenuText = {{DrawMenuText}} {
+        text_style: <FONT_LABEL> {}
+        fn get_color(self) -> vec4 {
+            return mix(
+                mix(COLOR_TEXT_DEFAULT, COLOR_TEXT_HOVER, self.hover),
+                COLOR_TEXT_META,
+                self.dim
+            )
+        }
+    }
+
+    MenuPopup = {{MenuPopup}} {
+        catcher: {color: #0000}
+        panel: {color: (COLOR_BG_HEADER), radius: 3.0}
+        separator: {color: (COLOR_UP_10)}
+        padding: {left: 4, top: 4, right: 4, bottom: 4}
+        item_height: 24.0
+        separator_height: 9.0
+        item_padding: 12.0
+        arrow_width: 14.0
+        shortcut_gap: 24.0
+        min_width: 140.0
+    }
+
+    MenuBar = {{MenuBar}} {
+        walk: {width: Fill, height: Fit}
+        layout: {padding: {left: 4, top: 2, right: 4, bottom: 2}}
+        item_layout: {padding: {left: 8, top: 4, right: 8, bottom: 4}}
+        bg: {color: (COLOR_BG_HEADER)}
+        popup: <MenuPopup> {}
+    }
+
+    ContextMenu = {{ContextMenu}} {
+        walk: {width: Fit, height: Fit}
+        content: <Frame> {
+            walk: {width: Fit, height: Fit}
+        }
+        popup: <MenuPopup> {}
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawMenuPanel {
+    draw_super: DrawQuad,
+    color: Vec4,
+    radius: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawMenuItem {
+    draw_super: DrawQuad,
+    hover: f32,
+    sub: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawMenuText {
+    draw_super: DrawText,
+    hover: f32,
+    // shortcuts and disabled items
+    dim: f32,
+}
+
+#[derive(Clone, Default)]
+struct MenuPanelLayout {
+    rect: Rect,
+    items: Vec<Rect>,
+}
+
+pub enum MenuPopupAction {
+    Closed,
+    // the arrow keys going past the first level of the menu, which a menu bar uses to step to
+    // the menu next to it
+    Left,
+    Right,
+    None
+}
+
+// The panels of an open menu, each submenu cascading off the item that opened it. It is the
+// part shared by MenuBar and ContextMenu, and draws the menu it is given as an overlay. Picking an
+// item sends its command, which reaches the app as an Event::MenuCommand like a native menu does.
+#[derive(Live, LiveHook)]
+pub struct MenuPopup {
+    view: View,
+    // covers the window while open, so a click anywhere else closes the menu
+    catcher: DrawColor,
+    panel: DrawMenuPanel,
+    item: DrawMenuItem,
+    label: DrawMenuText,
+    separator: DrawColor,
+
+    padding: Padding,
+    item_height: f64,
+    separator_height: f64,
+    // the room left and right of the texts in an item
+    item_padding: f64,
+    // the extra room at the right of items that open a submenu
+    arrow_width: f64,
+    // the least room between a label and its shortcut
+    shortcut_gap: f64,
+    min_width: f64,
+
+    #[rust] is_open: bool,
+    #[rust] anchor: DVec2,
+    // the highlighted item of every open panel, the last panel comes from a submenu that is
+    // highlighted in the one before it
+    #[rust] selection: Vec<Option<usize >>,
+    #[rust] panels: Vec<MenuPanelLayout>,
+    #[rust] take_focus: bool,
+    #[rust] restore_focus: Area,
+}
+
+fn is_selectable(item: &Menu) -> bool {
+    !matches!(item, Menu::Line | Menu::Main {..})
+}
+
+fn next_selectable(items: &[Menu], from: Option<usize>, backwards: bool) -> Option<usize> {
+    let len = items.len();
+    if len == 0 {
+        return None
+    }
+    let mut index = match (from, backwards) {
+        (Some(index), _) => index,
+        (None, false) => len - 1,
+        (None, true) => 0,
+    };
+    for _ in 0..len {
+        index = if backwards {(index + len - 1) % len} else {(index + 1) % len};
+        if is_selectable(&items[index]) {
+            return Some(index)
+        }
+    }
+    None
+}
+
+fn item_enabled(cx: &Cx, item: &Menu) -> bool {
+    match item {
+        Menu::Item {command, ..} => cx.command_enabled(*command),
+        Menu::Sub {..} => true,
+        _ => false
+    }
+}
+
+impl MenuPopup {
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    // opens the menu at a position in the window, from the keyboard the first item is highlighted
+    pub fn open(&mut self, cx: &mut Cx, items: &[Menu], anchor: DVec2, from_keyboard: bool) {
+        if !self.is_open {
+            self.restore_focus = cx.key_focus();
+        }
+        self.is_open = true;
+        self.anchor = anchor;
+        self.selection = vec![if from_keyboard {next_selectable(items, None, false)} else {None}];
+        self.take_focus = true;
+        self.view.redraw(cx);
+    }
+
+    // starts over with other items, at the same place
+    pub fn reset(&mut self, cx: &mut Cx, items: &[Menu]) {
+        let from_keyboard = self.selection.first().map( | selected | selected.is_some()).unwrap_or(false);
+        self.open(cx, items, self.anchor, from_keyboard);
+    }
+
+    pub fn close(&mut self, cx: &mut Cx) {
+        if !self.is_open {
+            return
+        }
+        self.is_open = false;
+        self.selection.clear();
+        self.panels.clear();
+        cx.set_key_focus(self.restore_focus);
+        self.view.redraw(cx);
+    }
+
+    fn panel_items<'a>(&self, items: &'a [Menu], level: usize) -> &'a [Menu] {
+        let mut items = items;
+        for selected in self.selection.iter().take(level) {
+            match selected.and_then( | index | items.get(index)) {
+                Some(sub @ Menu::Sub {..}) => items = sub.items(),
+                _ => return &[]
+            }
+        }
+        items
+    }
+
+    // the deepest panel with a highlighted item, which the arrow keys work on
+    fn key_level(&self) -> usize {
+        self.selection.iter().rposition( | selected | selected.is_some()).unwrap_or(0)
+    }
+
+    fn item_at(&self, pos: DVec2) -> Option<(usize, Option<usize>)> {
+        for (level, panel) in self.panels.iter().enumerate().rev() {
+            if panel.rect.contains(pos) {
+                return Some((level, panel.items.iter().position( | rect | rect.contains(pos))))
+            }
+        }
+        None
+    }
+
+    fn select(&mut self, cx: &mut Cx, items: &[Menu], level: usize, index: Option<usize>, open_sub: bool) {
+        self.selection.truncate(level + 1);
+        self.selection[level] = index;
+        if open_sub {
+            if let Some(Menu::Sub {..}) = index.and_then( | index | self.panel_items(items, level).get(index)) {
+                self.selection.push(None);
+            }
+        }
+        self.view.redraw(cx);
+    }
+
+    // the mouse highlights what it is over and opens submenus. lines and the edges of panels
+    // change nothing, so the mouse can cross them on the way into a submenu
+    fn hover_at(&mut self, cx: &mut Cx, items: &[Menu], pos: DVec2) {
+        if let Some((level, Some(index))) = self.item_at(pos) {
+            if self.selection.get(level) != Some(&Some(index)) && is_selectable(&self.panel_items(items, level)[index]) {
+                self.select(cx, items, level, Some(index), true);
+            }
+        }
+    }
+
+    fn activate(&mut self, cx: &mut Cx, items: &[Menu], level: usize, index: usize, dispatch_action: &mut dyn FnMut(&mut Cx, MenuPopupAction)) {
+        match self.panel_items(items, level).get(index) {
+            Some(Menu::Item {command, ..}) if cx.command_enabled(*command) => {
+                cx.send_menu_command(*command);
+                self.close(cx);
+                dispatch_action(cx, MenuPopupAction::Closed);
+            }
+            Some(Menu::Sub {items: sub_items, ..}) => {
+                let first = next_selectable(sub_items, None, false);
+                self.select(cx, items, level, Some(index), false);
+                self.selection.push(first);
+            }
+            _ => ()
+        }
+    }
+
+    pub fn handle_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        items: &[Menu],
+        dispatch_action: &mut dyn FnMut(&mut Cx, MenuPopupAction)
+    ) {
+        if !self.is_open {
+            return
+        }
+        match event.hits(cx, self.catcher.area()) {
+            Hit::KeyDown(ke) => {
+                let level = self.key_level();
+                let selected = self.selection.get(level).cloned().flatten();
+                match ke.key_code {
+                    KeyCode::ArrowDown | KeyCode::ArrowUp => {
+                        let backwards = ke.key_code == KeyCode::ArrowUp;
+                        let next = next_selectable(self.panel_items(items, level), selected, backwards);
+                        self.select(cx, items, level, next, false);
+                    }
+                    KeyCode::ArrowRight => match selected {
+                        Some(index) if matches!(self.panel_items(items, level).get(index), Some(Menu::Sub {..})) => {
+                            self.activate(cx, items, level, index, dispatch_action);
+                        }
+                        _ => dispatch_action(cx, MenuPopupAction::Right)
+                    }
+                    KeyCode::ArrowLeft => if level > 0 {
+                        self.selection.truncate(level);
+                        self.view.redraw(cx);
+                    }
+                    else {
+                        dispatch_action(cx, MenuPopupAction::Left)
+                    }
+                    KeyCode::ReturnKey | KeyCode::NumpadEnter | KeyCode::Space => if let Some(index) = selected {
+                        self.activate(cx, items, level, index, dispatch_action);
+                    }
+                    KeyCode::Escape => if self.selection.len() > 1 {
+                        self.selection.pop();
+                        self.view.redraw(cx);
+                    }
+                    else {
+                        self.close(cx);
+                        dispatch_action(cx, MenuPopupAction::Closed);
+                    }
+                    _ => ()
+                }
+            }
+            Hit::KeyFocusLost(_) => {
+                self.close(cx);
+                dispatch_action(cx, MenuPopupAction::Closed);
+            }
+            _ => ()
+        }
+        match event {
+            Event::FingerHover(fe) => self.hover_at(cx, items, fe.abs),
+            Event::FingerMove(fe) => self.hover_at(cx, items, fe.abs),
+            Event::FingerDown(fe) if self.item_at(fe.abs).is_none() => {
+                self.close(cx);
+                dispatch_action(cx, MenuPopupAction::Closed);
+            }
+            // a press can be dragged onto an item and released there
+            Event::FingerUp(fe) => if let Some((level, Some(index))) = self.item_at(fe.abs) {
+                if let Some(Menu::Item {..}) = self.panel_items(items, level).get(index) {
+                    self.activate(cx, items, level, index, dispatch_action);
+                }
+            }
+            _ => ()
+        }
+    }
+
+    fn text_width(&self, cx: &Cx2d, text: &str) -> f64 {
+        self.label.compute_geom(cx, Walk::fit(), text).map( | geom | geom.measured_width).unwrap_or(0.0)
+    }
+
+    fn draw_panel(&mut self, cx: &mut Cx2d, level: usize, items: &[Menu], parent: Option<(Rect, Rect)>) {
+        // measure the panel first, the items all get its width
+        let mut label_width = 0.0f64;
+        let mut shortcut_width = 0.0f64;
+        let mut has_sub = false;
+        let mut height = self.padding.top + self.padding.bottom;
+        let mut shortcuts = Vec::with_capacity(items.len());
+        for item in items {
+            let shortcut = match item {
+                Menu::Item {command, ..} => cx.command_shortcut(*command).map( | chord | chord.label()),
+                _ => None
+            };
+            match item {
+                Menu::Item {name, ..} | Menu::Sub {name, ..} => {
+                    label_width = label_width.max(self.text_width(cx, name));
+                    height += self.item_height;
+                }
+                _ => height += self.separator_height
+            }
+            if let Some(shortcut) = &shortcut {
+                shortcut_width = shortcut_width.max(self.text_width(cx, shortcut));
+            }
+            has_sub |= matches!(item, Menu::Sub {..});
+            shortcuts.push(shortcut);
+        }
+        let mut item_width = label_width + 2.0 * self.item_padding;
+        if shortcut_width > 0.0 {
+            item_width += self.shortcut_gap + shortcut_width;
+        }
+        if has_sub {
+            item_width += self.arrow_width;
+        }
+        let size = dvec2(
+            (item_width + self.padding.left + self.padding.right).max(self.min_width),
+            height
+        );
+
+        // the first panel goes at the anchor, submenus next to the item that opened them, and
+        // either flips or moves to stay in the window
+        let pass_size = cx.current_pass_size();
+        let mut pos = match parent {
+            None => {
+                let x = if self.anchor.x + size.x > pass_size.x {pass_size.x - size.x} else {self.anchor.x};
+                dvec2(x, self.anchor.y)
+            }
+            Some((parent_panel, parent_item)) => {
+                let right = parent_panel.pos.x + parent_panel.size.x - 2.0;
+                let x = if right + size.x > pass_size.x {parent_panel.pos.x - size.x + 2.0} else {right};
+                dvec2(x, parent_item.pos.y - self.padding.top)
+            }
+        };
+        if pos.y + size.y > pass_size.y {
+            pos.y = pass_size.y - size.y;
+        }
+        pos = dvec2(pos.x.max(0.0), pos.y.max(0.0));
+
+        // a submenu goes over the panel it comes from
+        if level > 0 {
+            self.panel.new_draw_call(cx);
+            self.separator.new_draw_call(cx);
+            self.item.new_draw_call(cx);
+            self.label.new_draw_call(cx);
+        }
+        let rect = Rect {pos, size};
+        self.panel.draw_abs(cx, rect);
+
+        let selected = self.selection.get(level).cloned().flatten();
+        let text_height = self.label.compute_geom(cx, Walk::fit(), "Mg").map( | geom | geom.measured_height).unwrap_or(0.0);
+        let width = size.x - self.padding.left - self.padding.right;
+        let mut y = pos.y + self.padding.top;
+        let mut item_rects = Vec::with_capacity(items.len());
+        for (index, (item, shortcut)) in items.iter().zip(shortcuts.iter()).enumerate() {
+            let (name, is_sub) = match item {
+                Menu::Item {name, ..} => (name, false),
+                Menu::Sub {name, ..} => (name, true),
+                _ => {
+                    let item_rect = Rect {pos: dvec2(pos.x + self.padding.left, y), size: dvec2(width, self.separator_height)};
+                    self.separator.draw_abs(cx, Rect {
+                        pos: dvec2(item_rect.pos.x + 4.0, y + (0.5 * self.separator_height).floor()),
+                        size: dvec2(width - 8.0, 1.0)
+                    });
+                    item_rects.push(item_rect);
+                    y += self.separator_height;
+                    continue
+                }
+            };
+            let item_rect = Rect {pos: dvec2(pos.x + self.padding.left, y), size: dvec2(width, self.item_height)};
+            let hover = if selected == Some(index) {1.0} else {0.0};
+            self.item.hover = hover;
+            self.item.sub = if is_sub {1.0} else {0.0};
+            self.item.draw_abs(cx, item_rect);
+
+            let text_y = y + 0.5 * (self.item_height - text_height);
+            self.label.hover = hover;
+            self.label.dim = if item_enabled(cx, item) {0.0} else {1.0};
+            self.label.draw_abs(cx, dvec2(item_rect.pos.x + self.item_padding, text_y), name);
+            if let Some(shortcut) = shortcut {
+                let mut right = item_rect.pos.x + item_rect.size.x - self.item_padding;
+                if has_sub {
+                    right -= self.arrow_width;
+                }
+                self.label.hover = 0.0;
+                self.label.dim = 1.0;
+                let shortcut_x = right - self.text_width(cx, shortcut);
+                self.label.draw_abs(cx, dvec2(shortcut_x, text_y), shortcut);
+            }
+            item_rects.push(item_rect);
+            y += self.item_height;
+        }
+        self.panels.push(MenuPanelLayout {rect, items: item_rects});
+    }
+
+    pub fn draw(&mut self, cx: &mut Cx2d, items: &[Menu]) {
+        if !self.is_open {
+            cx.clear_sweep_lock(self.catcher.area());
+            return
+        }
+        self.view.begin_overlay(cx);
+        cx.begin_overlay_turtle(Layout::flow_down());
+        let pass_size = cx.current_pass_size();
+        self.catcher.draw_abs(cx, Rect {pos: DVec2::default(), size: pass_size});
+        cx.set_sweep_lock(self.catcher.area());
+
+        self.panels.clear();
+        let mut parent = None;
+        for level in 0..self.selection.len() {
+            let panel_items = self.panel_items(items, level);
+            if level > 0 && parent.is_none() {
+                break
+            }
+            self.draw_panel(cx, level, panel_items, parent);
+            let panel = &self.panels[level];
+            parent = match self.selection[level] {
+                Some(index) if matches!(panel_items.get(index), Some(Menu::Sub {..})) => {
+                    Some((panel.rect, panel.items[index]))
+                }
+                _ => None
+            };
+        }
+        cx.end_overlay_turtle();
+        self.view.end(cx);
+
+        if self.take_focus {
+            self.take_focus = false;
+            cx.set_key_focus(self.catcher.area());
+        }
+    }
+}
+
+// A row of menus at the top of a window, drawn from the same Menu a macos app gets in its menu
+// bar. The menus open on a click, the mouse moves between them while one is open, and F10 opens the
+// first one from the keyboard:
+//
+// self.ui.get_menu_bar(id!(menu_bar)).set_menu(cx, Menu::main(vec![
+//     Menu::sub("File", vec![Menu::item("Save", live_id!(save).into())]),
+// ]));
+#[derive(Live)]
+#[live_design_fn(widget_factory!(MenuBar))]
+pub struct MenuBar {
+    bg: DrawColor,
+    item: DrawMenuItem,
+    label: DrawMenuText,
+
+    walk: Walk,
+    layout: Layout,
+    item_layout: Layout,
+
+    popup: MenuPopup,
+
+    #[rust(Menu::main(vec![]))] menu: Menu,
+    #[rust] titles: Vec<Rect>,
+    #[rust] hover: Option<usize>,
+    #[rust] open: Option<usize>,
+}
+
+impl LiveHook for MenuBar {}
+
+#[derive(Clone, WidgetAction)]
+pub enum MenuBarAction {
+    Opened,
+    Closed,
+    None
+}
+
+impl Widget for MenuBar {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.bg.redraw(cx);
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        self.draw_walk(cx, walk);
+        WidgetDraw::done()
+    }
+}
+
+impl MenuBar {
+    pub fn set_menu(&mut self, cx: &mut Cx, menu: Menu) {
+        if self.menu == menu {
+            return
+        }
+        self.menu = menu;
+        self.popup.close(cx);
+        self.open = None;
+        self.hover = None;
+        self.bg.redraw(cx);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    fn title_at(&self, pos: DVec2) -> Option<usize> {
+        self.titles.iter().position( | rect | rect.contains(pos))
+    }
+
+    // the menus on the bar that can be opened, going round at the ends
+    fn step_title(&self, from: usize, backwards: bool) -> Option<usize> {
+        let items = self.menu.items();
+        let len = items.len();
+        let mut index = from;
+        for _ in 0..len {
+            index = if backwards {(index + len - 1) % len} else {(index + 1) % len};
+            if let Menu::Sub {..} = items[index] {
+                return Some(index)
+            }
+        }
+        None
+    }
+
+    fn open_title(&mut self, cx: &mut Cx, index: usize, from_keyboard: bool) {
+        let rect = match self.titles.get(index) {
+            Some(rect) => *rect,
+            None => return
+        };
+        match &self.menu.items()[index] {
+            Menu::Sub {items, ..} => {
+                self.popup.open(cx, items, rect.pos + dvec2(0.0, rect.size.y), from_keyboard);
+                self.open = Some(index);
+            }
+            // a command straight on the bar
+            Menu::Item {command, ..} => {
+                cx.send_menu_command(*command);
+                self.close(cx);
+            }
+            _ => ()
+        }
+        self.bg.redraw(cx);
+    }
+
+    pub fn close(&mut self, cx: &mut Cx) {
+        self.popup.close(cx);
+        self.open = None;
+        self.bg.redraw(cx);
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, MenuBarAction)) {
+        let open = match self.open {
+            Some(open) => open,
+            None => {
+                match event {
+                    Event::FingerHover(fe) => {
+                        let hover = if fe.sweep_lock.get().is_empty() {self.title_at(fe.abs)} else {None};
+                        if hover != self.hover {
+                            self.hover = hover;
+                            self.bg.redraw(cx);
+                        }
+                    }
+                    Event::FingerDown(fe) if fe.sweep_lock.get().is_empty() => if let Some(index) = self.title_at(fe.abs) {
+                        self.open_title(cx, index, false);
+                        if self.open.is_some() {
+                            dispatch_action(cx, MenuBarAction::Opened);
+                        }
+                    }
+                    Event::KeyDown(ke) if ke.key_code == KeyCode::F10 && !ke.modifiers.shift => {
+                        if let Some(index) = self.step_title(self.menu.items().len().max(1) - 1, false) {
+                            self.open_title(cx, index, true);
+                            dispatch_action(cx, MenuBarAction::Opened);
+                        }
+                    }
+                    _ => ()
+                }
+                return
+            }
+        };
+
+        // while a menu is open the other titles are still live
+        match event {
+            Event::FingerHover(fe) => if let Some(index) = self.title_at(fe.abs) {
+                if index != open && matches!(self.menu.items()[index], Menu::Sub {..}) {
+                    return self.open_title(cx, index, false)
+                }
+            }
+            Event::FingerMove(fe) => if let Some(index) = self.title_at(fe.abs) {
+                if index != open && matches!(self.menu.items()[index], Menu::Sub {..}) {
+                    return self.open_title(cx, index, false)
+                }
+            }
+            Event::FingerDown(fe) => if let Some(index) = self.title_at(fe.abs) {
+                if index == open {
+                    self.close(cx);
+                    dispatch_action(cx, MenuBarAction::Closed);
+                }
+                else {
+                    self.open_title(cx, index, false);
+                }
+                return
+            }
+            _ => ()
+        }
+
+        let mut actions = Vec::new();
+        self.popup.handle_event_fn(cx, event, self.menu.items()[open].items(), &mut | _, action | actions.push(action));
+        for action in actions {
+            match action {
+                MenuPopupAction::Closed => {
+                    self.open = None;
+                    self.bg.redraw(cx);
+                    dispatch_action(cx, MenuBarAction::Closed);
+                }
+                MenuPopupAction::Left | MenuPopupAction::Right => {
+                    let backwards = matches!(action, MenuPopupAction::Left);
+                    if let Some(index) = self.step_title(open, backwards) {
+                        self.open_title(cx, index, true);
+                    }
+                }
+                MenuPopupAction::None => ()
+            }
+        }
+    }
+
+    pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        self.bg.begin(cx, walk, self.layout);
+        self.titles.clear();
+        for (index, item) in self.menu.items().iter().enumerate() {
+            let name = match item {
+                Menu::Item {name, ..} | Menu::Sub {name, ..} => name,
+                _ => {
+                    self.titles.push(Rect::default());
+                    continue
+                }
+            };
+            let hover = if self.open == Some(index) {1.0} else if self.hover == Some(index) {0.5} else {0.0};
+            self.item.hover = hover;
+            self.item.sub = 0.0;
+            self.item.begin(cx, Walk::fit(), self.item_layout);
+            self.label.hover = hover;
+            self.label.dim = 0.0;
+            self.label.draw_walk(cx, Walk::fit(), Align::default(), name);
+            self.item.end(cx);
+            self.titles.push(self.item.area().get_rect(cx));
+        }
+        self.bg.end(cx);
+
+        let items = match self.open {
+            Some(open) => self.menu.items()[open].items(),
+            None => &[]
+        };
+        self.popup.draw(cx, items);
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct MenuBarRef(WidgetRef);
+
+impl MenuBarRef {
+    pub fn set_menu(&self, cx: &mut Cx, menu: Menu) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_menu(cx, menu);
+        }
+    }
+
+    pub fn close(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.close(cx);
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        if let Some(inner) = self.inner() {
+            return inner.is_open()
+        }
+        false
+    }
+}
+
+// Opens a menu where its content is right clicked, or with shift+F10 when the key focus is in the
+// content:
+//
+// <ContextMenu> {content: {file_tree = <FileTree> {}}}
+//
+// The menu is set from the app, and can be changed when the Opened action comes in, which has the
+// position it opens at.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(ContextMenu))]
+pub struct ContextMenu {
+    content: WidgetRef,
+    walk: Walk,
+    popup: MenuPopup,
+
+    #[rust(Menu::main(vec![]))] menu: Menu,
+    #[rust] area: Area,
+    #[rust] draw_state: DrawStateWrap<()>,
+}
+
+impl LiveHook for ContextMenu {}
+
+#[derive(Clone, WidgetAction)]
+pub enum ContextMenuAction {
+    Opened(DVec2),
+    Closed,
+    None
+}
+
+impl Widget for ContextMenu {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        let uid = self.widget_uid();
+        self.content.handle_widget_event_fn(cx, event, dispatch_action);
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.area.redraw(cx);
+        self.content.redraw(cx);
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn find_widget(&mut self, path: &[LiveId], cached: WidgetCache) -> WidgetResult {
+        self.content.find_widget(path, cached)
+    }
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        if self.draw_state.begin(cx, ()) {
+            cx.begin_turtle(walk, Layout::default());
+        }
+        self.content.draw_walk_widget(cx) ?;
+        cx.end_turtle_with_area(&mut self.area);
+        self.draw_state.end();
+        self.popup.draw(cx, self.menu.items());
+        WidgetDraw::done()
+    }
+}
+
+impl ContextMenu {
+    pub fn set_menu(&mut self, cx: &mut Cx, menu: Menu) {
+        if self.menu == menu {
+            return
+        }
+        self.menu = menu;
+        if self.popup.is_open() {
+            self.popup.reset(cx, self.menu.items());
+        }
+    }
+
+    pub fn open_at(&mut self, cx: &mut Cx, pos: DVec2, from_keyboard: bool) {
+        self.popup.open(cx, self.menu.items(), pos, from_keyboard);
+        self.area.redraw(cx);
+    }
+
+    pub fn close(&mut self, cx: &mut Cx) {
+        self.popup.close(cx);
+        self.area.redraw(cx);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, ContextMenuAction)) {
+        if self.popup.is_open() {
+            let mut closed = false;
+            self.popup.handle_event_fn(cx, event, self.menu.items(), &mut | _, action | {
+                if let MenuPopupAction::Closed = action {
+                    closed = true;
+                }
+            });
+            if closed {
+                self.area.redraw(cx);
+                dispatch_action(cx, ContextMenuAction::Closed);
+            }
+            return
+        }
+        if !self.area.is_valid(cx) {
+            return
+        }
+        let rect = self.area.get_clipped_rect(cx);
+        match event {
+            Event::FingerDown(fe) if fe.digit.mouse_button() == Some(1)
+                && fe.sweep_lock.get().is_empty()
+                && rect.contains(fe.abs) => {
+                dispatch_action(cx, ContextMenuAction::Opened(fe.abs));
+                self.open_at(cx, fe.abs, false);
+            }
+            Event::KeyDown(ke) if ke.key_code == KeyCode::F10 && ke.modifiers.shift => {
+                let focus = cx.key_focus();
+                if focus.is_valid(cx) {
+                    let focus_rect = focus.get_clipped_rect(cx);
+                    let pos = focus_rect.pos + focus_rect.size * 0.5;
+                    if rect.contains(pos) {
+                        dispatch_action(cx, ContextMenuAction::Opened(pos));
+                        self.open_at(cx, pos, true);
+                    }
+                }
+            }
+            _ => ()
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct ContextMenuRef(WidgetRef);
+
+impl ContextMenuRef {
+    pub fn set_menu(&self, cx: &mut Cx, menu: Menu) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_menu(cx, menu);
+        }
+    }
+
+    pub fn open_at(&self, cx: &mut Cx, pos: DVec2) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.open_at(cx, pos, false);
+        }
+    }
+
+    pub fn close(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.close(cx);
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        if let Some(inner) = self.inner() {
+            return inner.is_open()
+        }
+        false
+    }
+
+    pub fn opened(&self, actions: &WidgetActions) -> Option<DVec2> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let ContextMenuAction::Opened(pos) = item.action() {
+                return Some(pos)
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn key(key_code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {key_code, is_repeat: false, modifiers, time: 0.0}
+    }
+    
+    fn control_shift() -> KeyModifiers {
+        KeyModifiers {control: true, shift: true, ..KeyModifiers::default()}
+    }
+    
+    fn save_chord() -> KeyChord {
+        KeyChord {control: true, ..KeyChord::new(KeyCode::KeyS)}.with_shift()
+    }
+    
+    #[test]
+    fn chord_matches() {
+        let chord = save_chord();
+        assert!(chord.matches(&key(KeyCode::KeyS, control_shift())));
+        // every modifier has to agree, extra ones included
+        assert!(!chord.matches(&key(KeyCode::KeyS, KeyModifiers {control: true, ..KeyModifiers::default()})));
+        assert!(!chord.matches(&key(KeyCode::KeyS, KeyModifiers {alt: true, ..control_shift()})));
+        assert!(!chord.matches(&key(KeyCode::KeyA, control_shift())));
+        assert!(KeyChord::new(KeyCode::KeyA).matches(&key(KeyCode::KeyA, KeyModifiers::default())));
+    }
+    
+    #[test]
+    fn command_for_key() {
+        let mut cx = Cx::new(Box::new( | _, _ | {}));
+        let save = MenuCommand(live_id!(save));
+        let ke = key(KeyCode::KeyS, control_shift());
+        assert_eq!(cx.command_for_key(&ke), None);
+        
+        cx.set_command_shortcut(save, Some(save_chord()));
+        assert_eq!(cx.command_for_key(&ke), Some(save));
+        assert_eq!(cx.command_for_key(&key(KeyCode::KeyA, control_shift())), None);
+        
+        // a disabled command doesn't take its key, and neither does its menu item
+        cx.set_command_enabled(save, false);
+        assert_eq!(cx.command_for_key(&ke), None);
+        assert!(!item_enabled(&cx, &Menu::item("Save", save)));
+        cx.set_command_enabled(save, true);
+        
+        // a chord belongs to one command at a time
+        let save_as = MenuCommand(live_id!(save_as));
+        cx.set_command_shortcut(save_as, Some(save_chord()));
+        assert_eq!(cx.command_shortcut(save_as), None);
+        assert_eq!(cx.command_for_key(&ke), Some(save));
+        
+        cx.set_command_shortcut(save, None);
+        assert_eq!(cx.command_for_key(&ke), None);
+        cx.set_command_shortcut(save_as, Some(save_chord()));
+        assert_eq!(cx.command_for_key(&ke), Some(save_as));
+    }
+}
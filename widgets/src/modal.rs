@@ -0,0 +1,391 @@
+use {
+    std::cell::Cell,
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        frame::FrameRef,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+    import makepad_widgets::frame::Frame;
+
+    DrawModalBackdrop = {{DrawModalBackdrop}} {
+        fn pixel(self) -> vec4 {
+            return vec4(self.color.rgb * self.color.a, self.color.a) * self.opened;
+        }
+    }
+
+    DrawModalBg = {{DrawModalBg}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, self.radius);
+            sdf.fill_keep(self.color);
+            sdf.stroke(COLOR_UP_10, 1.0);
+            return sdf.result * self.opened;
+        }
+    }
+
+    Modal = {{Modal}} {
+        walk: {width: 400, height: Fit}
+        layout: {flow: Down, padding: 20, spacing: 10}
+        backdrop: {color: #0009}
+        bg: {color: (COLOR_BG_HEADER), radius: 4.0}
+        content: <Frame> {
+            walk: {width: Fill, height: Fit}
+            layout: {flow: Down, spacing: 10}
+        }
+        close_on_escape: true
+        close_on_backdrop: true
+        state: {
+            open = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.15}}
+                    redraw: true
+                    apply: {
+                        backdrop: {opened: 0.0}
+                        bg: {opened: 0.0}
+                    }
+                }
+                on = {
+                    from: {all: Forward {duration: 0.15}}
+                    redraw: true
+                    apply: {
+                        backdrop: {opened: 1.0}
+                        bg: {opened: 1.0}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawModalBackdrop {
+    draw_super: DrawQuad,
+    color: Vec4,
+    opened: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawModalBg {
+    draw_super: DrawQuad,
+    color: Vec4,
+    radius: f32,
+    opened: f32,
+}
+
+// A dialog drawn over the window, which blocks the input to the rest of the window while it is
+// open. The modal itself takes no space where it sits in the tree, and is opened from the app:
+//
+// self.ui.get_modal(id!(confirm)).open(cx);
+//
+// While open the key focus stays within the modal, tab cycles through its nav stops and escape
+// or a click on the backdrop dismiss it.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(Modal))]
+pub struct Modal {
+    view: View,
+    backdrop: DrawModalBackdrop,
+    bg: DrawModalBg,
+    content: WidgetRef,
+
+    walk: Walk,
+    layout: Layout,
+    state: State,
+
+    close_on_escape: bool,
+    close_on_backdrop: bool,
+
+    #[rust] is_open: bool,
+    // where the modal sits in the tree, which is redrawn to open and close it
+    #[rust] area: Area,
+    #[rust] draw_state: DrawStateWrap<()>,
+    #[rust] focus_content: bool,
+    #[rust] restore_focus: Area,
+}
+
+impl LiveHook for Modal {}
+
+#[derive(Clone, WidgetAction)]
+pub enum ModalAction {
+    Dismissed,
+    None
+}
+
+// the area finger input is locked to, which the window's overlay sets on the event before the
+// widgets get it
+fn event_sweep_lock(event: &Event) -> Option<&Cell<Area>> {
+    match event {
+        Event::FingerDown(fe) => Some(&fe.sweep_lock),
+        Event::FingerMove(fe) => Some(&fe.sweep_lock),
+        Event::FingerHover(fe) => Some(&fe.sweep_lock),
+        Event::FingerScroll(fe) => Some(&fe.sweep_lock),
+        _ => None
+    }
+}
+
+impl Widget for Modal {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        let uid = self.widget_uid();
+        let mut actions = Vec::new();
+        self.handle_event_fn(cx, event, dispatch_action, &mut | _, action | actions.push(action));
+        for action in actions {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid));
+        }
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.view.redraw(cx);
+        self.content.redraw(cx);
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn find_widget(&mut self, path: &[LiveId], cached: WidgetCache) -> WidgetResult {
+        self.content.find_widget(path, cached)
+    }
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        if self.draw_state.begin(cx, ()) {
+            cx.walk_turtle_with_area(&mut self.area, Walk::fixed_size(DVec2::default()));
+            if !self.is_open && !self.state.is_track_animating(cx, id!(open)) {
+                cx.clear_sweep_lock(self.backdrop.area());
+                self.draw_state.end();
+                return WidgetDraw::done()
+            }
+            self.view.begin_overlay(cx);
+            cx.begin_overlay_turtle(Layout::flow_down());
+            let pass_size = cx.current_pass_size();
+            self.backdrop.draw_abs(cx, Rect {pos: DVec2::default(), size: pass_size});
+            if self.is_open {
+                cx.set_sweep_lock(self.backdrop.area());
+            }
+            else {
+                cx.clear_sweep_lock(self.backdrop.area());
+            }
+            self.bg.begin(cx, walk, self.layout);
+        }
+        self.content.draw_walk_widget(cx) ?;
+
+        // center the dialog in the window
+        let turtle = cx.turtle().rect();
+        let used = cx.turtle().used() + self.layout.padding.right_bottom();
+        let size = dvec2(
+            if turtle.size.x.is_nan() {used.x} else {turtle.size.x},
+            if turtle.size.y.is_nan() {used.y} else {turtle.size.y}
+        );
+        let space = cx.current_pass_size() - size;
+        let pos = dvec2(space.x.max(0.0), space.y.max(0.0)) * 0.5;
+        cx.turtle_mut().set_shift(pos - turtle.pos);
+        self.bg.end(cx);
+        cx.end_overlay_turtle();
+        self.view.end(cx);
+
+        if self.focus_content {
+            self.focus_content = false;
+            self.cycle_focus(cx, false);
+        }
+        self.draw_state.end();
+        WidgetDraw::done()
+    }
+}
+
+impl Modal {
+    pub fn open(&mut self, cx: &mut Cx) {
+        if self.is_open {
+            return
+        }
+        self.is_open = true;
+        self.restore_focus = cx.key_focus();
+        self.focus_content = true;
+        self.animate_state(cx, id!(open.on));
+        self.area.redraw(cx);
+    }
+
+    pub fn close(&mut self, cx: &mut Cx) {
+        if !self.is_open {
+            return
+        }
+        self.is_open = false;
+        self.animate_state(cx, id!(open.off));
+        cx.set_key_focus(self.restore_focus);
+        self.area.redraw(cx);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    // whether an area is drawn within the modal
+    fn contains_area(&self, cx: &Cx, area: Area) -> bool {
+        if area == self.bg.area() {
+            return true
+        }
+        let mut draw_list_id = area.draw_list_id();
+        while let Some(id) = draw_list_id {
+            if id == self.view.draw_list_id() {
+                return true
+            }
+            draw_list_id = cx.draw_lists[id].codeflow_parent_id;
+        }
+        false
+    }
+
+    // moves the key focus to the next nav stop in the modal, going round at the end
+    fn cycle_focus(&mut self, cx: &mut Cx, backwards: bool) {
+        let mut stops = Vec::new();
+        Cx2d::iterate_nav_stops(cx, self.view.draw_list_id(), | _, stop | {
+            stops.push(stop.area);
+            None
+        });
+        if stops.is_empty() {
+            cx.set_key_focus(self.bg.area());
+            return
+        }
+        let current = stops.iter().position( | area | cx.has_key_focus(*area));
+        let next = match (current, backwards) {
+            (Some(index), false) => (index + 1) % stops.len(),
+            (Some(index), true) => (index + stops.len() - 1) % stops.len(),
+            (None, false) => 0,
+            (None, true) => stops.len() - 1,
+        };
+        cx.set_key_focus(stops[next]);
+    }
+
+    pub fn handle_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_item: &mut dyn FnMut(&mut Cx, WidgetActionItem),
+        dispatch_action: &mut dyn FnMut(&mut Cx, ModalAction)
+    ) {
+        if self.state_handle_event(cx, event).must_redraw() {
+            self.view.redraw(cx);
+            // once it has faded out the modal is no longer drawn
+            if !self.is_open {
+                self.area.redraw(cx);
+            }
+        }
+        if !self.is_open || !self.bg.area().is_valid(cx) {
+            return
+        }
+
+        // the content gets the finger input the rest of the window is locked out of
+        let lock = event_sweep_lock(event).filter( | lock | lock.get() == self.backdrop.area());
+        if let Some(lock) = lock {
+            lock.set(Area::Empty);
+        }
+        self.content.handle_widget_event_fn(cx, event, dispatch_item);
+        if let Some(lock) = lock {
+            lock.set(self.backdrop.area());
+        }
+
+        match event {
+            Event::FingerDown(fe) if lock.is_some() && self.close_on_backdrop
+                && !self.bg.area().get_clipped_rect(cx).contains(fe.abs) => {
+                self.close(cx);
+                dispatch_action(cx, ModalAction::Dismissed);
+            }
+            Event::KeyDown(ke) => match ke.key_code {
+                KeyCode::Escape if self.close_on_escape => {
+                    self.close(cx);
+                    dispatch_action(cx, ModalAction::Dismissed);
+                }
+                KeyCode::Tab => {
+                    self.cycle_focus(cx, ke.modifiers.shift);
+                }
+                _ => ()
+            }
+            // keep the key focus from going to the rest of the window
+            Event::KeyFocus(kf) if !self.contains_area(cx, kf.focus) => {
+                if self.contains_area(cx, kf.prev) {
+                    cx.set_key_focus(kf.prev);
+                }
+                else {
+                    cx.set_key_focus(self.bg.area());
+                }
+            }
+            _ => ()
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct ModalRef(WidgetRef);
+
+impl ModalRef {
+    pub fn open(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.open(cx);
+        }
+    }
+
+    pub fn close(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.close(cx);
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        if let Some(inner) = self.inner() {
+            return inner.is_open()
+        }
+        false
+    }
+
+    pub fn dismissed(&self, actions: &WidgetActions) -> bool {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let ModalAction::Dismissed = item.action() {
+                return true
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_and_close() {
+        let (mut cx, mut modal): (_, Modal) = crate::test_widget("modal", live_id!(Modal));
+        assert!(!modal.is_open());
+        assert!(modal.state.is_in_state(&cx, id!(open.off)));
+
+        modal.open(&mut cx);
+        assert!(modal.is_open() && modal.focus_content);
+        assert!(modal.state.is_in_state(&cx, id!(open.on)));
+        // opening again leaves the focus it goes back to alone
+        modal.focus_content = false;
+        modal.open(&mut cx);
+        assert!(!modal.focus_content);
+
+        modal.close(&mut cx);
+        assert!(!modal.is_open());
+        assert!(modal.state.is_in_state(&cx, id!(open.off)));
+
+        // a closed modal doesn't take the keys
+        let mut dismissed = false;
+        let key_down = Event::KeyDown(KeyEvent {
+            key_code: KeyCode::Escape,
+            is_repeat: false,
+            modifiers: KeyModifiers::default(),
+            time: 0.0,
+        });
+        modal.handle_event_fn(&mut cx, &key_down, &mut | _, _ | {}, &mut | _, _ | dismissed = true);
+        assert!(!dismissed);
+    }
+}
@@ -54,6 +54,10 @@ impl NavControl {
         match event {
             Event::KeyDown(ke) => match ke.key_code {
                 KeyCode::Tab => {
+                    // without a focused stop tab starts at the first one, and shift tab at the last
+                    let has_focus = Cx2d::iterate_nav_stops(cx, root, | cx, stop | {
+                        if cx.has_key_focus(stop.area) {Some(stop.area)} else {None}
+                    }).is_some();
                     if ke.modifiers.shift {
                         let mut prev_area = Area::Empty;
                         if let Some((prev_area, scroll_stack)) = Cx2d::iterate_nav_stops(cx, root, | cx, stop | {
@@ -68,9 +72,12 @@ impl NavControl {
                                 cx.set_key_focus(prev_area);
                             }
                         }
+                        else if !has_focus && !prev_area.is_empty() {
+                            cx.set_key_focus(prev_area);
+                        }
                     }
                     else {
-                        let mut next_stop = false;
+                        let mut next_stop = !has_focus;
                         if let Some((next_area, scroll_stack)) = Cx2d::iterate_nav_stops(cx, root, | cx, stop | {
                             if next_stop {
                                 return Some(stop.area)
@@ -0,0 +1,511 @@
+use {
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        data_binding::DataBinding,
+        widget::*,
+        frame::FrameRef,
+        text_input::{TextInput, TextInputAction},
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+
+    DrawNumberBg = {{DrawNumberBg}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+            sdf.fill_keep(mix(COLOR_BG_EDITOR, COLOR_BG_UNFOCUSSED, self.hover));
+            sdf.stroke(mix(mix(COLOR_UP_10, COLOR_UP_25, self.focus), COLOR_HIGH, self.invalid), 1.0);
+            // how far the value is between min and max
+            sdf.rect(2.0, self.rect_size.y - 3.0, (self.rect_size.x - 4.0) * self.fill, 1.0);
+            sdf.fill(mix(COLOR_UP_15, COLOR_UP_50, self.focus));
+            return sdf.result;
+        }
+    }
+
+    DrawStepButton = {{DrawStepButton}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+            sdf.fill(mix(mix(#fff0, COLOR_UP_10, self.hover), COLOR_UP_25, self.pressed));
+            let color = mix(COLOR_TEXT_DEFAULT, COLOR_TEXT_HOVER, self.hover);
+            let c = floor(self.rect_size * 0.5);
+            sdf.rect(c.x - 3.5, c.y - 0.5, 7.0, 1.0);
+            sdf.fill(color);
+            // the button that steps up gets a plus
+            sdf.rect(c.x - 0.5, c.y - 3.5, 1.0, 7.0);
+            sdf.fill(mix(#fff0, color, self.up));
+            return sdf.result;
+        }
+    }
+
+    NumberInput = {{NumberInput}} {
+        walk: {width: 100, height: Fit, margin: {top: 3, right: 5}}
+        layout: {flow: Right, align: {y: 0.5}, padding: {right: 2}}
+
+        value: 0.0
+        min: 0.0
+        max: 0.0
+        step: 1.0
+        precision: 2
+        scrub_distance: 4.0
+        show_buttons: false
+
+        unit_label: {
+            color: (COLOR_TEXT_META)
+            text_style: <FONT_LABEL> {}
+        }
+        unit_walk: {width: Fit, height: Fit, margin: {right: 4}}
+        button_walk: {width: 18, height: 18}
+        dec: {up: 0.0}
+        inc: {up: 1.0}
+
+        text_input: {
+            cursor_margin_bottom: 3.0,
+            cursor_margin_top: 4.0,
+            select_pad_edges: 3.0
+            cursor_size: 2.0,
+            empty_message: "0",
+            numeric_only: true,
+            bg: {
+                shape: None
+                color: #5
+                radius: 2
+            },
+            layout: {
+                padding: {left: 6, top: 5, right: 2, bottom: 5},
+                align: {y: 0.}
+            },
+            walk: {
+                margin: 0
+            }
+        }
+
+        state: {
+            hover = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg: {hover: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {hover: 1.0}}
+                }
+            }
+            focus = {
+                default: off
+                off = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 1.0}}
+                }
+            }
+        }
+    }
+
+    // a number input with buttons that step it down and up, for counts and other whole numbers
+    Spinner = <NumberInput> {
+        precision: 0
+        show_buttons: true
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawNumberBg {
+    draw_super: DrawQuad,
+    hover: f32,
+    focus: f32,
+    invalid: f32,
+    fill: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawStepButton {
+    draw_super: DrawQuad,
+    hover: f32,
+    pressed: f32,
+    up: f32,
+}
+
+#[derive(Clone, Copy)]
+struct Scrub {
+    start: f64,
+    moved: bool,
+}
+
+// A field for a number which can be typed in, stepped with the arrow keys and dragged left and
+// right to scrub it. The value is rounded to the precision and kept between min and max, and text
+// that is not a number in that range gets a red outline until it is entered or the key focus goes.
+//
+// <NumberInput> {min: 0.0, max: 100.0, step: 0.5, precision: 1, unit: "%"}
+#[derive(Live)]
+#[live_design_fn(widget_factory!(NumberInput))]
+pub struct NumberInput {
+    bg: DrawNumberBg,
+    dec: DrawStepButton,
+    inc: DrawStepButton,
+    unit_label: DrawText,
+    text_input: TextInput,
+
+    walk: Walk,
+    layout: Layout,
+    unit_walk: Walk,
+    button_walk: Walk,
+    state: State,
+
+    value: f64,
+    // the value stays between min and max, unless max is not above min
+    min: f64,
+    max: f64,
+    // what the arrow keys and the buttons add, with shift it is ten times as much
+    step: f64,
+    // the number of decimals the value is rounded to
+    precision: usize,
+    // shown after the number, like px or %
+    unit: String,
+    // the pixels the mouse moves for every step when scrubbing
+    scrub_distance: f64,
+    show_buttons: bool,
+
+    #[rust] scrub: Option<Scrub>,
+    #[rust] invalid: bool,
+    // a step button that is held down repeats its step
+    #[rust] repeat_timer: Timer,
+    #[rust] repeat_steps: f64,
+}
+
+impl LiveHook for NumberInput {
+    fn after_apply(&mut self, cx: &mut Cx, from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        if from.is_from_doc() {
+            self.value = self.snap(self.value);
+            self.update_text_input(cx);
+        }
+    }
+}
+
+#[derive(Clone, WidgetAction)]
+pub enum NumberInputAction {
+    Changed(f64),
+    None
+}
+
+fn read_number(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter( | value | value.is_finite())
+}
+
+impl NumberInput {
+    fn has_range(&self) -> bool {
+        self.max > self.min
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(self.precision as i32);
+        let value = (value * scale).round() / scale;
+        if self.has_range() {value.max(self.min).min(self.max)} else {value}
+    }
+
+    fn is_valid_text(&self, text: &str) -> bool {
+        match read_number(text) {
+            Some(value) => !self.has_range() || value >= self.min && value <= self.max,
+            None => false
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.invalid
+    }
+
+    // sets the value from code, which does not send a Changed action
+    pub fn set_value(&mut self, cx: &mut Cx, value: f64) {
+        self.value = self.snap(value);
+        self.set_invalid(cx, false);
+        self.update_text_input(cx);
+        self.bg.redraw(cx);
+    }
+
+    fn set_invalid(&mut self, cx: &mut Cx, invalid: bool) {
+        if self.invalid != invalid {
+            self.invalid = invalid;
+            self.bg.redraw(cx);
+        }
+    }
+
+    fn update_text_input(&mut self, cx: &mut Cx) {
+        self.text_input.text = format!("{:.*}", self.precision, self.value);
+        self.text_input.select_all();
+        self.text_input.redraw(cx);
+    }
+
+    fn change_value(&mut self, cx: &mut Cx, value: f64, dispatch_action: &mut dyn FnMut(&mut Cx, NumberInputAction)) {
+        let value = self.snap(value);
+        self.set_invalid(cx, false);
+        if value != self.value {
+            self.value = value;
+            self.bg.redraw(cx);
+            dispatch_action(cx, NumberInputAction::Changed(value));
+        }
+        self.update_text_input(cx);
+    }
+
+    // steps from what is typed in, when that is a number
+    fn step_by(&mut self, cx: &mut Cx, steps: f64, dispatch_action: &mut dyn FnMut(&mut Cx, NumberInputAction)) {
+        let from = read_number(&self.text_input.text).unwrap_or(self.value);
+        self.change_value(cx, from + steps * self.step, dispatch_action);
+    }
+
+    fn handle_button(&mut self, cx: &mut Cx, event: &Event, up: bool, dispatch_action: &mut dyn FnMut(&mut Cx, NumberInputAction)) {
+        let button = if up {&mut self.inc} else {&mut self.dec};
+        match event.hits(cx, button.area()) {
+            Hit::FingerHoverIn(_) => {
+                cx.set_cursor(MouseCursor::Hand);
+                button.hover = 1.0;
+                button.redraw(cx);
+            }
+            Hit::FingerHoverOut(_) => {
+                button.hover = 0.0;
+                button.redraw(cx);
+            }
+            Hit::FingerDown(fe) => {
+                button.pressed = 1.0;
+                button.redraw(cx);
+                let steps = if fe.mod_shift() {10.0} else {1.0};
+                self.repeat_steps = if up {steps} else {-steps};
+                self.repeat_timer = cx.start_timeout(0.4);
+                self.step_by(cx, self.repeat_steps, dispatch_action);
+            }
+            Hit::FingerUp(fe) => {
+                button.pressed = 0.0;
+                button.hover = if fe.is_over && fe.digit.has_hovers() {1.0} else {0.0};
+                button.redraw(cx);
+                cx.stop_timer(self.repeat_timer);
+                self.repeat_timer = Timer::empty();
+            }
+            _ => ()
+        }
+    }
+
+    fn handle_scrub(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, NumberInputAction)) {
+        match event.hits(cx, self.bg.area()) {
+            Hit::FingerHoverIn(_) => {
+                cx.set_cursor(MouseCursor::EwResize);
+                self.animate_state(cx, id!(hover.on));
+            }
+            Hit::FingerHoverOut(_) => {
+                self.animate_state(cx, id!(hover.off));
+            }
+            Hit::FingerDown(_) => {
+                cx.set_cursor(MouseCursor::EwResize);
+                self.scrub = Some(Scrub {start: self.value, moved: false});
+            }
+            Hit::FingerMove(fe) => {
+                let dx = fe.abs.x - fe.abs_start.x;
+                // the wobble of a click does not scrub yet
+                if let Some(scrub) = self.scrub.as_mut().filter( | scrub | scrub.moved || dx.abs() > 3.0) {
+                    scrub.moved = true;
+                    let start = scrub.start;
+                    let mut steps = (dx / self.scrub_distance).round();
+                    if fe.modifiers.shift {
+                        steps *= 0.1;
+                    }
+                    self.change_value(cx, start + steps * self.step, dispatch_action);
+                }
+            }
+            Hit::FingerUp(fe) => {
+                // a click without a drag is for typing a value in
+                if let Some(scrub) = self.scrub.take() {
+                    if !scrub.moved && fe.is_over {
+                        self.text_input.set_key_focus(cx);
+                    }
+                }
+                if fe.is_over && fe.digit.has_hovers() {
+                    self.animate_state(cx, id!(hover.on));
+                }
+                else {
+                    self.animate_state(cx, id!(hover.off));
+                }
+            }
+            _ => ()
+        }
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, NumberInputAction)) {
+        self.state_handle_event(cx, event);
+
+        if self.repeat_timer.is_event(event) {
+            self.repeat_timer = cx.start_timeout(0.05);
+            self.step_by(cx, self.repeat_steps, dispatch_action);
+        }
+        if self.show_buttons {
+            self.handle_button(cx, event, false, dispatch_action);
+            self.handle_button(cx, event, true, dispatch_action);
+        }
+
+        // the text only gets the finger before scrubbing does while it is being typed in
+        let editing = self.text_input.has_key_focus(cx);
+        if !editing {
+            self.handle_scrub(cx, event, dispatch_action);
+        }
+        for action in self.text_input.handle_event(cx, event) {
+            match action {
+                TextInputAction::KeyFocus => {
+                    self.animate_state(cx, id!(focus.on));
+                }
+                TextInputAction::KeyFocusLost => {
+                    self.animate_state(cx, id!(focus.off));
+                }
+                TextInputAction::Change(text) => {
+                    let invalid = !self.is_valid_text(&text);
+                    self.set_invalid(cx, invalid);
+                }
+                TextInputAction::Return(text) => {
+                    // text that is not a number puts the value back
+                    let value = read_number(&text).unwrap_or(self.value);
+                    self.change_value(cx, value, dispatch_action);
+                }
+                TextInputAction::Escape => {
+                    self.set_invalid(cx, false);
+                    self.update_text_input(cx);
+                }
+                _ => ()
+            }
+        }
+        if editing {
+            self.handle_scrub(cx, event, dispatch_action);
+        }
+
+        if let Event::KeyDown(ke) = event {
+            if self.text_input.has_key_focus(cx) {
+                let steps = if ke.modifiers.shift {10.0} else {1.0};
+                match ke.key_code {
+                    KeyCode::ArrowUp => self.step_by(cx, steps, dispatch_action),
+                    KeyCode::ArrowDown => self.step_by(cx, -steps, dispatch_action),
+                    KeyCode::PageUp => self.step_by(cx, 10.0 * steps, dispatch_action),
+                    KeyCode::PageDown => self.step_by(cx, -10.0 * steps, dispatch_action),
+                    _ => ()
+                }
+            }
+        }
+    }
+
+    pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        self.bg.fill = if self.has_range() {
+            ((self.value - self.min) / (self.max - self.min)) as f32
+        }
+        else {
+            0.0
+        };
+        self.bg.invalid = if self.invalid {1.0} else {0.0};
+        self.bg.begin(cx, walk, self.layout);
+
+        self.text_input.draw_walk(cx, self.text_input.get_walk());
+        if !self.unit.is_empty() {
+            self.unit_label.draw_walk(cx, self.unit_walk, Align::default(), &self.unit);
+        }
+        if self.show_buttons {
+            cx.walk_turtle(Walk::size(Size::fill(), Size::Fixed(0.0)));
+            self.dec.draw_walk(cx, self.button_walk);
+            self.inc.draw_walk(cx, self.button_walk);
+        }
+
+        self.bg.end(cx);
+    }
+}
+
+impl Widget for NumberInput {
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.bg.redraw(cx);
+    }
+
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        self.draw_walk(cx, walk);
+        WidgetDraw::done()
+    }
+
+    // whole numbers are written as ints, so a count in the data stays one
+    fn bind_to(&mut self, cx: &mut Cx, db: &mut DataBinding, act: &WidgetActions, path: &[LiveId]) {
+        match db {
+            DataBinding::FromWidgets {nodes, ..} => if let Some(item) = act.find_single_action(self.widget_uid()) {
+                if let NumberInputAction::Changed(v) = item.action() {
+                    let value = if self.precision == 0 {LiveValue::Int64(v as i64)} else {LiveValue::Float64(v)};
+                    nodes.write_by_field_path(path, &[LiveNode::from_value(value)]);
+                }
+            }
+            DataBinding::ToWidgets {nodes, ..} => {
+                if let Some(value) = nodes.read_by_field_path(path).and_then( | value | value.as_float()) {
+                    self.set_value(cx, value);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct NumberInputRef(WidgetRef);
+
+impl NumberInputRef {
+    pub fn set_value(&self, cx: &mut Cx, value: f64) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_value(cx, value);
+        }
+    }
+
+    pub fn value(&self) -> Option<f64> {
+        if let Some(inner) = self.inner() {
+            return Some(inner.value())
+        }
+        None
+    }
+
+    pub fn changed(&self, actions: &WidgetActions) -> Option<f64> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let NumberInputAction::Changed(value) = item.action() {
+                return Some(value)
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap() {
+        let (mut cx, mut input): (_, NumberInput) = crate::test_widget("number_input", live_id!(NumberInput));
+        input.apply_over(&mut cx, live!{precision: 1});
+        // without a range only the precision applies
+        assert_eq!(input.snap(1.26), 1.3);
+        assert_eq!(input.snap(-1000.04), -1000.0);
+
+        input.apply_over(&mut cx, live!{min: 0.0, max: 10.0, precision: 0});
+        assert_eq!(input.snap(3.5), 4.0);
+        assert_eq!(input.snap(-2.0), 0.0);
+        assert_eq!(input.snap(12.0), 10.0);
+        assert!(input.is_valid_text(" 7 "));
+        assert!(!input.is_valid_text("11"));
+        assert!(!input.is_valid_text("inf"));
+    }
+}
@@ -0,0 +1,507 @@
+use {
+    std::collections::{HashMap, HashSet},
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        frame::FrameRef,
+        scroll_bars::ScrollBars,
+    },
+};
+
+live_design!{
+    import crate::scroll_bars::ScrollBars;
+    import makepad_widgets::theme::*;
+
+    PortalList = {{PortalList}} {
+        walk: {width: Fill, height: Fill}
+        layout: {flow: Down, clip_x: true, clip_y: true}
+        scroll_bars: <ScrollBars> {
+            show_scroll_x: false,
+            show_scroll_y: true,
+            scroll_bar_y: {smoothing: 0.15}
+        }
+        estimated_item_height: (DIM_DATA_ITEM_HEIGHT)
+    }
+}
+
+// A list that only draws the items in its viewport, for lists of any length. The app sets the
+// item count and draws the items while the list asks for them:
+//
+// while let Some(next) = self.ui.draw(cx).into_not_done() {
+//     if let Some(mut list) = next.into_portal_list().inner_mut() {
+//         list.set_item_count(lines.len() as u64);
+//         while let Some(item_id) = list.next_visible_item(cx) {
+//             let item = list.get_item(cx, item_id, live_id!(Line)).unwrap();
+//             item.get_label(id!(text)).set_text(&lines[item_id as usize]);
+//             item.draw_walk_widget(cx);
+//         }
+//     }
+// }
+//
+// Items are instanced from the templates of the list, and reused for other items once they
+// scroll out of view. Rows can have any height, the scroll bar uses the measured rows and an
+// estimate for the rest. With more than one column the items are laid out in a grid.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(PortalList))]
+pub struct PortalList {
+    walk: Walk,
+    layout: Layout,
+    scroll_bars: ScrollBars,
+
+    // the height of a row that was never drawn, until the list has measured a few
+    estimated_item_height: f64,
+    #[live(1usize)] columns: usize,
+
+    #[rust] area: Area,
+    #[rust] draw_state: DrawStateWrap<ListDrawState>,
+    #[rust] templates: ComponentMap<LiveId, LivePtr>,
+    #[rust] items: HashMap<u64, (LiveId, WidgetRef)>,
+    #[rust] free_items: HashMap<LiveId, Vec<WidgetRef>>,
+    #[rust] drawn_items: HashSet<u64>,
+
+    #[rust] item_count: u64,
+
+    // the row at the top of the view, and how far it is scrolled out of view
+    #[rust] first_row: u64,
+    #[rust] first_scroll: f64,
+    // the scroll bar position that the first row and scroll were derived from
+    #[rust] scroll_pos: f64,
+
+    #[rust] row_heights: HashMap<u64, f64>,
+    #[rust] measured_height: f64,
+
+    #[rust] view_height: f64,
+    #[rust] next_row: u64,
+    #[rust] next_column: usize,
+    #[rust] drawn_height: f64,
+}
+
+#[derive(Clone)]
+enum ListDrawState {
+    Begin,
+    Row,
+    Item,
+    End
+}
+
+impl LiveHook for PortalList {
+    fn apply_value_instance(&mut self, cx: &mut Cx, from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        let id = nodes[index].id;
+        match from {
+            ApplyFrom::NewFromDoc {file_id} | ApplyFrom::UpdateFromDoc {file_id} => {
+                // the list has no children of its own, so instances are templates as well
+                if nodes[index].origin.has_prop_type(LivePropType::Template)
+                    || nodes[index].origin.has_prop_type(LivePropType::Instance) {
+                    let live_ptr = cx.live_registry.borrow().file_id_index_to_live_ptr(file_id, index);
+                    self.templates.insert(id, live_ptr);
+                    // the items of a template that changed follow it
+                    for (template, item) in self.items.values_mut() {
+                        if *template == id {
+                            item.apply(cx, from, index, nodes);
+                        }
+                    }
+                    self.free_items.remove(&id);
+                }
+                else {
+                    cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+                }
+            }
+            _ => ()
+        }
+        nodes.skip_node(index)
+    }
+}
+
+impl Widget for PortalList {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        self.scroll_bars.handle_main_event(cx, event, &mut | _, _ | {});
+
+        for (_, item) in self.items.values() {
+            item.handle_widget_event_fn(cx, event, dispatch_action);
+        }
+
+        match event.hits(cx, self.area) {
+            Hit::FingerDown(_) => {
+                cx.set_key_focus(self.area);
+            }
+            Hit::KeyDown(ke) => match ke.key_code {
+                KeyCode::ArrowDown => {
+                    let row_height = self.row_height(self.first_row);
+                    self.scroll_by(cx, row_height);
+                }
+                KeyCode::ArrowUp => {
+                    let row_height = self.row_height(self.first_row.saturating_sub(1));
+                    self.scroll_by(cx, -row_height);
+                }
+                KeyCode::PageDown => {
+                    self.scroll_by(cx, self.view_height);
+                }
+                KeyCode::PageUp => {
+                    self.scroll_by(cx, -self.view_height);
+                }
+                KeyCode::Home => {
+                    self.scroll_to_item(cx, 0);
+                }
+                KeyCode::End => {
+                    self.scroll_to_item(cx, self.item_count.saturating_sub(1));
+                }
+                _ => ()
+            }
+            _ => ()
+        }
+
+        self.scroll_bars.handle_scroll_event(cx, event, &mut | _, _ | {});
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.area.redraw(cx);
+    }
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        if self.draw_state.begin(cx, ListDrawState::Begin) {
+            self.begin(cx, walk);
+            return WidgetDraw::not_done(WidgetRef::empty())
+        }
+        if let ListDrawState::End = self.draw_state.get() {
+            return WidgetDraw::done()
+        }
+        self.end(cx);
+        WidgetDraw::done()
+    }
+
+    fn find_widget(&mut self, path: &[LiveId], _cached: WidgetCache) -> WidgetResult {
+        for (_, item) in self.items.values_mut() {
+            if let Some(result) = item.find_widget(path, WidgetCache::No).into_found() {
+                return WidgetResult::found(result)
+            }
+        }
+        WidgetResult::not_found()
+    }
+
+    fn find_template(&self, id: &[LiveId; 1]) -> Option<LivePtr> {
+        self.templates.get(&id[0]).copied()
+    }
+}
+
+impl PortalList {
+    pub fn set_item_count(&mut self, item_count: u64) {
+        self.item_count = item_count;
+    }
+
+    pub fn item_count(&self) -> u64 {
+        self.item_count
+    }
+
+    // the first item in view
+    pub fn first_item(&self) -> u64 {
+        self.first_row * self.columns() as u64
+    }
+
+    pub fn area(&self) -> Area {
+        self.area
+    }
+
+    pub fn scroll_pos(&self) -> DVec2 {
+        self.scroll_bars.get_scroll_pos()
+    }
+
+    fn columns(&self) -> usize {
+        self.columns.max(1)
+    }
+
+    fn row_count(&self) -> u64 {
+        let columns = self.columns() as u64;
+        self.item_count.div_ceil(columns)
+    }
+
+    fn average_row_height(&self) -> f64 {
+        if self.row_heights.is_empty() {
+            self.estimated_item_height + self.layout.spacing
+        }
+        else {
+            self.measured_height / self.row_heights.len() as f64
+        }
+    }
+
+    fn row_height(&self, row: u64) -> f64 {
+        match self.row_heights.get(&row) {
+            Some(height) => *height,
+            None => self.average_row_height()
+        }
+    }
+
+    // where the first row would be if every row before it had the average height. the scroll
+    // bar uses this, so it stays still while rows are measured
+    fn estimated_scroll_pos(&self) -> f64 {
+        self.first_row as f64 * self.average_row_height() + self.first_scroll
+    }
+
+    // keeps the first scroll within the first row, and the first row within the list
+    fn normalize_first_row(&mut self) {
+        let row_count = self.row_count();
+        if self.first_row >= row_count {
+            self.first_row = row_count.saturating_sub(1);
+            self.first_scroll = 0.0;
+        }
+        while self.first_scroll < 0.0 && self.first_row > 0 {
+            self.first_row -= 1;
+            self.first_scroll += self.row_height(self.first_row);
+        }
+        if self.first_scroll < 0.0 {
+            self.first_scroll = 0.0;
+        }
+        while self.first_row + 1 < row_count && self.first_scroll >= self.row_height(self.first_row) {
+            self.first_scroll -= self.row_height(self.first_row);
+            self.first_row += 1;
+        }
+    }
+
+    // follows the scroll bar. small moves, like the steps of a smooth scroll, move the rows by
+    // their real height, a larger jump goes to the row at the estimated position
+    fn apply_scroll_pos(&mut self) {
+        let scroll_pos = self.scroll_bars.get_scroll_pos().y;
+        let delta = scroll_pos - self.scroll_pos;
+        if delta == 0.0 {
+            return
+        }
+        if delta.abs() > self.view_height {
+            let row_height = self.average_row_height();
+            self.first_row = (scroll_pos / row_height).max(0.0) as u64;
+            self.first_scroll = scroll_pos - self.first_row as f64 * row_height;
+        }
+        else {
+            self.first_scroll += delta;
+        }
+        self.normalize_first_row();
+        self.scroll_pos = scroll_pos;
+    }
+
+    pub fn scroll_by(&mut self, cx: &mut Cx, delta: f64) {
+        let scroll_pos = self.scroll_bars.get_scroll_pos();
+        self.scroll_bars.set_scroll_pos(cx, scroll_pos + dvec2(0.0, delta));
+        self.area.redraw(cx);
+    }
+
+    pub fn scroll_to_item(&mut self, cx: &mut Cx, item_id: u64) {
+        self.first_row = item_id / self.columns() as u64;
+        self.first_scroll = 0.0;
+        self.normalize_first_row();
+        self.scroll_pos = self.estimated_scroll_pos();
+        let scroll_pos = self.scroll_bars.get_scroll_pos();
+        self.scroll_bars.set_scroll_pos_no_clip(cx, dvec2(scroll_pos.x, self.scroll_pos));
+        self.area.redraw(cx);
+    }
+
+    // scrolls as little as it takes to show the whole item, going by the rows of the last draw
+    pub fn scroll_into_view(&mut self, cx: &mut Cx, item_id: u64) {
+        let row = item_id / self.columns() as u64;
+        if row < self.first_row || (row == self.first_row && self.first_scroll > 0.0) || row > self.next_row {
+            self.scroll_to_item(cx, item_id);
+            return
+        }
+        let bottom = (self.first_row..=row).map( | row | self.row_height(row)).sum::<f64>() - self.first_scroll;
+        if bottom > self.view_height {
+            self.scroll_by(cx, bottom - self.view_height);
+        }
+    }
+
+    fn begin(&mut self, cx: &mut Cx2d, walk: Walk) {
+        self.apply_scroll_pos();
+
+        self.scroll_bars.begin_nav_area(cx);
+        let scroll = dvec2(self.scroll_bars.get_scroll_pos().x, self.first_scroll);
+        cx.begin_turtle(walk, self.layout.with_scroll(scroll));
+
+        let view_height = cx.turtle().padded_rect().size.y;
+        self.view_height = if view_height.is_nan() {f64::INFINITY} else {view_height};
+        self.next_row = self.first_row;
+        self.next_column = 0;
+        self.drawn_height = 0.0;
+        self.drawn_items.clear();
+    }
+
+    // the next item that is in view, the app draws it before asking for the next one
+    pub fn next_visible_item(&mut self, cx: &mut Cx2d) -> Option<u64> {
+        loop {
+            match self.draw_state.get() {
+                ListDrawState::Begin => {
+                    if self.next_row >= self.row_count() || self.drawn_height >= self.view_height + self.first_scroll {
+                        return None
+                    }
+                    cx.begin_turtle(Walk::size(Size::fill(), Size::Fit), Layout::flow_right());
+                    self.next_column = 0;
+                    self.draw_state.set(ListDrawState::Row);
+                }
+                ListDrawState::Row => {
+                    let item_id = self.next_row * self.columns() as u64 + self.next_column as u64;
+                    if self.next_column < self.columns() && item_id < self.item_count {
+                        let width = cx.turtle().padded_rect().size.x / self.columns() as f64;
+                        cx.begin_turtle(Walk::size(Size::Fixed(width), Size::Fit), Layout::flow_down());
+                        self.draw_state.set(ListDrawState::Item);
+                        return Some(item_id)
+                    }
+                    self.end_row(cx);
+                }
+                ListDrawState::Item => {
+                    cx.end_turtle();
+                    self.next_column += 1;
+                    self.draw_state.set(ListDrawState::Row);
+                }
+                ListDrawState::End => {
+                    return None
+                }
+            }
+        }
+    }
+
+    fn end_row(&mut self, cx: &mut Cx2d) {
+        let height = cx.end_turtle().size.y + self.layout.spacing;
+        if let Some(old_height) = self.row_heights.insert(self.next_row, height) {
+            self.measured_height -= old_height;
+        }
+        self.measured_height += height;
+        self.drawn_height += height;
+        self.next_row += 1;
+        self.draw_state.set(ListDrawState::Begin);
+    }
+
+    // the item widget for an item in view, instanced from a template
+    pub fn get_item(&mut self, cx: &mut Cx, item_id: u64, template: LiveId) -> Option<WidgetRef> {
+        let live_ptr = *self.templates.get(&template)?;
+        self.drawn_items.insert(item_id);
+        if let Some((item_template, item)) = self.items.get(&item_id) {
+            if *item_template == template {
+                return Some(item.clone())
+            }
+        }
+        let item = match self.free_items.get_mut(&template).and_then( | free | free.pop()) {
+            Some(item) => item,
+            None => WidgetRef::new_from_ptr(cx, Some(live_ptr))
+        };
+        if let Some((old_template, old_item)) = self.items.insert(item_id, (template, item.clone())) {
+            self.free_items.entry(old_template).or_default().push(old_item);
+        }
+        Some(item)
+    }
+
+    fn end(&mut self, cx: &mut Cx2d) {
+        match self.draw_state.get() {
+            ListDrawState::Item => {
+                cx.end_turtle();
+                self.end_row(cx);
+            }
+            ListDrawState::Row => {
+                self.end_row(cx);
+            }
+            _ => ()
+        }
+
+        let row_count = self.row_count();
+        let space_left = self.view_height + self.first_scroll - self.drawn_height;
+        if self.next_row >= row_count && space_left > 0.5 && (self.first_row > 0 || self.first_scroll > 0.0) {
+            // scrolled past the end, show the last row at the bottom from the next frame on
+            self.first_scroll -= space_left;
+            self.normalize_first_row();
+            self.area.redraw(cx);
+        }
+
+        // the rows in view have their real height, the others the average
+        let row_height = self.average_row_height();
+        let total = self.first_row as f64 * row_height + self.drawn_height
+            + row_count.saturating_sub(self.next_row) as f64 * row_height;
+        let scroll_pos = self.estimated_scroll_pos();
+        let delta = scroll_pos - self.scroll_bars.get_scroll_pos().y;
+        if delta != 0.0 {
+            self.scroll_bars.shift_scroll_pos(cx, dvec2(0.0, delta));
+        }
+        let total = if self.view_height.is_finite() {total.max(scroll_pos + self.view_height)} else {total};
+        let used_width = cx.turtle().used().x;
+        self.scroll_bars.draw_scroll_bars_with_total(cx, dvec2(used_width, total));
+        self.scroll_pos = self.scroll_bars.get_scroll_pos().y;
+
+        cx.end_turtle_with_area(&mut self.area);
+        self.scroll_bars.set_area(self.area);
+        self.scroll_bars.end_nav_area(cx);
+
+        // items that scrolled out of view are kept for reuse
+        let drawn_items = &self.drawn_items;
+        let free_items = &mut self.free_items;
+        self.items.retain( | item_id, (template, item) | {
+            if drawn_items.contains(item_id) {
+                return true
+            }
+            free_items.entry(*template).or_default().push(item.clone());
+            false
+        });
+        self.draw_state.set(ListDrawState::End);
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct PortalListRef(WidgetRef);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn portal_list(item_count: u64, row_heights: &[f64]) -> PortalList {
+        let (_, mut list): (_, PortalList) = crate::test_widget("portal_list", live_id!(PortalList));
+        list.set_item_count(item_count);
+        for (row, height) in row_heights.iter().enumerate() {
+            list.row_heights.insert(row as u64, *height);
+            list.measured_height += height;
+        }
+        list
+    }
+
+    fn normalized(list: &mut PortalList, first_row: u64, first_scroll: f64) -> (u64, f64) {
+        list.first_row = first_row;
+        list.first_scroll = first_scroll;
+        list.normalize_first_row();
+        (list.first_row, list.first_scroll)
+    }
+
+    #[test]
+    fn normalize_first_row() {
+        // rows that were never drawn have the average height of 20
+        let mut list = portal_list(10, &[10.0, 20.0, 30.0]);
+        assert_eq!(normalized(&mut list, 0, 35.0), (2, 5.0));
+        assert_eq!(normalized(&mut list, 2, -15.0), (1, 5.0));
+        assert_eq!(normalized(&mut list, 4, 45.0), (6, 5.0));
+        assert_eq!(normalized(&mut list, 0, -5.0), (0, 0.0));
+        assert_eq!(normalized(&mut list, 20, 15.0), (9, 0.0));
+        // the last row can scroll further than its height
+        assert_eq!(normalized(&mut list, 9, 100.0), (9, 100.0));
+
+        let mut list = portal_list(10, &[]);
+        list.columns = 3;
+        assert_eq!(list.row_count(), 4);
+        assert_eq!(normalized(&mut list, 7, 0.0), (3, 0.0));
+        assert_eq!(list.first_item(), 9);
+    }
+
+    #[test]
+    fn estimated_scroll_pos() {
+        let mut list = portal_list(100, &[]);
+        let estimated_row_height = list.estimated_item_height + list.layout.spacing;
+        list.first_row = 5;
+        list.first_scroll = 3.0;
+        assert_eq!(list.estimated_scroll_pos(), 5.0 * estimated_row_height + 3.0);
+
+        // measured rows move the estimate of every row to their average
+        let mut list = portal_list(100, &[10.0, 20.0, 30.0]);
+        list.first_row = 5;
+        list.first_scroll = 3.0;
+        assert_eq!(list.estimated_scroll_pos(), 103.0);
+    }
+}
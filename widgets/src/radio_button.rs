@@ -4,6 +4,7 @@ use {
         makepad_draw_2d::*,
         widget::*,
         data_binding::DataBinding,
+        button::is_activate_key,
         frame::*,
     }
 };
@@ -24,6 +25,8 @@ live_design!{
                     let isz = sz * 0.5;
                     sdf.circle(left, c.y, isz);
                     sdf.fill(mix(#fff0, #f, self.selected));
+                    sdf.circle(left, c.y, sz + 2.0);
+                    sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0);
                 }
                 RadioType::Tab => {
                 }
@@ -158,11 +161,17 @@ impl RadioButton {
                 self.animate_state(cx, id!(hover.off));
             },
             Hit::FingerDown(_fe) => {
-                if self.state.is_in_state(cx, id!(selected.off)) {
-                    self.animate_state(cx, id!(selected.on));
-                    dispatch_action(cx, RadioButtonAction::Clicked);
-                }
+                self.select(cx, dispatch_action);
             },
+            Hit::KeyFocus(_) => {
+                self.animate_state(cx, id!(focus.on));
+            }
+            Hit::KeyFocusLost(_) => {
+                self.animate_state(cx, id!(focus.off));
+            }
+            Hit::KeyDown(ke) if !ke.is_repeat && is_activate_key(ke.key_code) => {
+                self.select(cx, dispatch_action);
+            }
             Hit::FingerUp(_fe) => {
                 
             }
@@ -173,10 +182,24 @@ impl RadioButton {
         }
     }
     
+    fn select(&mut self, cx: &mut Cx, dispatch_action: &mut dyn FnMut(&mut Cx, RadioButtonAction)) {
+        if self.state.is_in_state(cx, id!(selected.off)) {
+            self.animate_state(cx, id!(selected.on));
+            dispatch_action(cx, RadioButtonAction::Clicked);
+        }
+    }
+    
     pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
         self.radio_button.begin(cx, walk, self.layout);
         self.label_text.draw_walk(cx, self.label_walk, self.label_align, &self.label);
         self.radio_button.end(cx);
+        
+        let checked = self.state.is_in_state(cx, id!(selected.on));
+        cx.add_nav_stop_with_info(self.radio_button.area(), NavRole::RadioButton, Margin::default(), NavInfo {
+            name: self.label.clone(),
+            value: None,
+            state: NavState {checked: Some(checked), ..NavState::default()}
+        });
     }
 }
 
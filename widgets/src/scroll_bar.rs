@@ -242,6 +242,14 @@ impl ScrollBar {
         return false
     }
     
+    // moves the position and the target of a smooth scroll together, so a view that corrects
+    // its scroll position does not stop the animation
+    pub fn shift_scroll_pos(&mut self, cx: &mut Cx, delta: f64) {
+        self.scroll_pos += delta;
+        self.scroll_target += delta;
+        self.update_shader_scroll_pos(cx);
+    }
+    
     pub fn get_scroll_target(&mut self) -> f64 {
         return self.scroll_target
     }
@@ -10,7 +10,7 @@ live_design!{
     }
 }
 
-#[derive(Live, LiveHook)]
+#[derive(Live)]
 pub struct ScrollBars {
     show_scroll_x: bool,
     show_scroll_y: bool,
@@ -21,6 +21,34 @@ pub struct ScrollBars {
     #[rust] area: Area,
 }
 
+impl LiveHook for ScrollBars {
+    fn apply_value_unknown(&mut self, cx: &mut Cx, from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        match nodes[index].id {
+            // restored scroll position, the view size isnt known yet so dont clip
+            live_id!(scroll) => {
+                let mut scroll = self.scroll;
+                let index = scroll.apply(cx, from, index, nodes);
+                self.set_scroll_pos_no_clip(cx, scroll);
+                index
+            }
+            _ => {
+                if !nodes[index].origin.node_has_prefix() {
+                    cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+                }
+                nodes.skip_node(index)
+            }
+        }
+    }
+}
+
+impl LiveRead for ScrollBars {
+    fn live_read_to(&self, id: LiveId, out: &mut Vec<LiveNode>) {
+        out.open_object(id);
+        self.scroll.live_read_to(live_id!(scroll), out);
+        out.close();
+    }
+}
+
 pub enum ScrollBarsAction {
     ScrollX(f64),
     ScrollY(f64),
@@ -250,9 +278,25 @@ impl ScrollBars {
         cx.add_end_scroll(self.nav_scroll_index.take().unwrap(), self.area);
     }
     
+    pub fn shift_scroll_pos(&mut self, cx: &mut Cx, delta: DVec2) {
+        if self.show_scroll_x {
+            self.scroll_bar_x.shift_scroll_pos(cx, delta.x);
+            self.scroll.x = self.scroll_bar_x.get_scroll_pos();
+        }
+        if self.show_scroll_y {
+            self.scroll_bar_y.shift_scroll_pos(cx, delta.y);
+            self.scroll.y = self.scroll_bar_y.get_scroll_pos();
+        }
+    }
+    
     pub fn draw_scroll_bars(&mut self, cx: &mut Cx2d) {
         // lets ask the turtle our actual bounds
         let view_total = cx.turtle().used();
+        self.draw_scroll_bars_with_total(cx, view_total);
+    }
+    
+    // for views that only draw what is visible, and estimate their total size themselves
+    pub fn draw_scroll_bars_with_total(&mut self, cx: &mut Cx2d, view_total: DVec2) {
         let mut rect_now = cx.turtle().rect();
         
         if rect_now.size.y.is_nan() {
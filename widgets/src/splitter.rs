@@ -134,6 +134,16 @@ enum DrawState {
     DrawB
 }
 
+// only the user adjustable state is persisted, the rest comes from the DSL
+impl LiveRead for Splitter {
+    fn live_read_to(&self, id: LiveId, out: &mut Vec<LiveNode>) {
+        out.open_object(id);
+        self.axis.live_read_to(live_id!(axis), out);
+        self.align.live_read_to(live_id!(align), out);
+        out.close();
+    }
+}
+
 impl Widget for Splitter {
     fn widget_uid(&self) -> WidgetUid {return WidgetUid(self as *const _ as u64)}
     
@@ -207,8 +217,8 @@ impl Splitter {
         self.position = self.align.to_position(self.axis, self.rect);
         
         let walk = match self.axis {
-            Axis::Horizontal => Walk::size(Size::Fixed(self.position), Size::Fill),
-            Axis::Vertical => Walk::size(Size::Fill, Size::Fixed(self.position)),
+            Axis::Horizontal => Walk::size(Size::Fixed(self.position), Size::fill()),
+            Axis::Vertical => Walk::size(Size::fill(), Size::Fixed(self.position)),
         };
         cx.begin_turtle(walk, Layout::flow_down());
     }
@@ -218,11 +228,11 @@ impl Splitter {
         match self.axis {
             Axis::Horizontal => {
                 self.bar.is_vertical = 1.0;
-                self.bar.draw_walk(cx, Walk::size(Size::Fixed(self.split_bar_size), Size::Fill));
+                self.bar.draw_walk(cx, Walk::size(Size::Fixed(self.split_bar_size), Size::fill()));
             }
             Axis::Vertical => {
                 self.bar.is_vertical = 0.0;
-                self.bar.draw_walk(cx, Walk::size(Size::Fill, Size::Fixed(self.split_bar_size)));
+                self.bar.draw_walk(cx, Walk::size(Size::fill(), Size::Fixed(self.split_bar_size)));
             }
         }
         cx.begin_turtle(Walk::default(), Layout::flow_down());
@@ -340,7 +350,7 @@ fn margin(&self) -> Margin {
 }
 }
 
-#[derive(Clone, Copy, Debug, Live, LiveHook)]
+#[derive(Clone, Copy, Debug, Live, LiveHook, LiveRead)]
 #[live_ignore]
 pub enum SplitterAlign {
     #[live(50.0)] FromStart(f64),
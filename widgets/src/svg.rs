@@ -0,0 +1,158 @@
+use {
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        makepad_draw_2d::makepad_vector::svg::{parse_svg, SvgDocument},
+        frame::*,
+        widget::*,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+
+    Svg = {{Svg}} {
+        walk: {width: Fit, height: Fit}
+        draw_svg: {
+            fn pixel(self) -> vec4 {
+                // the rasterized texture is not premultiplied
+                let color = sample2d(self.image, mix(self.pt1, self.pt2, self.pos));
+                return vec4(color.rgb * color.a, color.a) * self.alpha;
+            }
+        }
+    }
+
+    // an icon only uses the coverage of the document, and is tinted with a single color
+    Icon = <Svg> {
+        walk: {width: 16, height: 16}
+        draw_svg: {
+            instance color: #fff
+            fn pixel(self) -> vec4 {
+                let color = sample2d(self.image, mix(self.pt1, self.pt2, self.pos));
+                return vec4(self.color.rgb * self.color.a, self.color.a) * color.a * self.alpha;
+            }
+        }
+    }
+}
+
+#[derive(Live)]
+#[live_design_fn(widget_factory!(Svg))]
+pub struct Svg {
+    draw_svg: DrawImage,
+    walk: Walk,
+    source: LiveDependency,
+    texture: Texture,
+
+    #[rust] document: Option<SvgDocument>,
+    #[rust] texture_size: (usize, usize),
+}
+
+impl LiveHook for Svg {
+    fn after_apply(&mut self, cx: &mut Cx, _from: ApplyFrom, index: usize, nodes: &[LiveNode]) {
+        let path = self.source.as_ref();
+        if path.is_empty() {
+            self.document = None;
+            return
+        }
+        match cx.get_dependency(path) {
+            Ok(data) => match parse_svg_dependency(data) {
+                Ok(document) => {
+                    self.document = Some(document);
+                    self.texture_size = (0, 0);
+                }
+                Err(err) => {
+                    cx.apply_image_decoding_failed(live_error_origin!(), index, nodes, path, &err);
+                }
+            }
+            Err(err) => {
+                cx.apply_resource_not_loaded(live_error_origin!(), index, nodes, path, &err);
+            }
+        }
+    }
+}
+
+impl Widget for Svg {
+    fn widget_uid(&self) -> WidgetUid {return WidgetUid(self as *const _ as u64)}
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.draw_svg.redraw(cx)
+    }
+
+    fn get_walk(&self) -> Walk {
+        self.walk
+    }
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        self.draw_walk(cx, walk);
+        WidgetDraw::done()
+    }
+}
+
+impl Svg {
+    pub fn area(&self) -> Area {
+        self.draw_svg.area()
+    }
+
+    pub fn set_document(&mut self, cx: &mut Cx, document: Option<SvgDocument>) {
+        self.document = document;
+        self.texture_size = (0, 0);
+        self.redraw(cx);
+    }
+
+    pub fn draw_walk(&mut self, cx: &mut Cx2d, mut walk: Walk) {
+        let document = if let Some(document) = &self.document {document} else {
+            cx.walk_turtle(walk);
+            return
+        };
+        // a fitting axis takes the intrinsic size of the document
+        if let Size::Fit = walk.width {
+            walk.width = Size::Fixed(document.width);
+        }
+        if let Size::Fit = walk.height {
+            walk.height = Size::Fixed(document.height);
+        }
+        let rect = cx.walk_turtle(walk);
+        update_svg_texture(cx, document, &self.texture, rect.size, &mut self.texture_size);
+        self.draw_svg.set_texture(&self.texture);
+        self.draw_svg.draw_abs(cx, rect);
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct SvgRef(WidgetRef);
+
+impl SvgRef {
+    pub fn set_document(&self, cx: &mut Cx, document: Option<SvgDocument>) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_document(cx, document);
+        }
+    }
+}
+
+/// Parses the contents of an SVG dependency, with the error as a message for the live
+/// error log.
+pub fn parse_svg_dependency(data: &[u8]) -> Result<SvgDocument, String> {
+    let source = std::str::from_utf8(data).map_err( | err | err.to_string()) ?;
+    parse_svg(source).map_err( | err | err.to_string())
+}
+
+/// Rasterizes `document` into `texture` at the physical pixel size of a rect of `size`, unless
+/// the texture already has that size. Redrawing at a different size or dpi factor rasterizes
+/// the document again instead of scaling the old texture.
+pub fn update_svg_texture(cx: &mut Cx2d, document: &SvgDocument, texture: &Texture, size: DVec2, texture_size: &mut (usize, usize)) {
+    let dpi_factor = cx.current_dpi_factor();
+    let width = (size.x * dpi_factor).ceil().max(1.0) as usize;
+    let height = (size.y * dpi_factor).ceil().max(1.0) as usize;
+    if *texture_size == (width, height) {
+        return
+    }
+    *texture_size = (width, height);
+    let mut pixels = document.rasterize(width, height);
+    texture.set_desc(cx, TextureDesc {
+        format: TextureFormat::ImageBGRA,
+        width: Some(width),
+        height: Some(height),
+        multisample: None
+    });
+    texture.swap_image_u32(cx, &mut pixels);
+}
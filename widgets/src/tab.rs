@@ -1,6 +1,7 @@
 use {
     crate::{
         tab_close_button::{TabCloseButtonAction, TabCloseButton},
+        button::is_activate_key,
         makepad_draw_2d::*,
     }
 };
@@ -30,10 +31,11 @@ live_design!{
         bg: {
             instance hover: float
             instance selected: float
+            instance focus: float
             
             fn pixel(self) -> vec4 {
                 let sdf = Sdf2d::viewport(self.pos * self.rect_size)
-                return mix(
+                sdf.clear(mix(
                     mix(
                         COLOR_BG_HEADER,
                         COLOR_BG_EDITOR,
@@ -41,7 +43,11 @@ live_design!{
                     ),
                     #f,
                     0.0 //mix(self.hover * 0.05, self.hover * -0.025, self.selected)
-                );
+                ));
+                // the focus ring
+                sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 2.0);
+                sdf.stroke(mix(#fff0, #fffa, self.focus), 1.0);
+                return sdf.result;
                 /*sdf.clear(color)
                 sdf.move_to(0.0, 0.0)
                 sdf.line_to(0.0, self.rect_size.y)
@@ -105,6 +111,18 @@ live_design!{
                     }
                 }
             }
+            
+            focus = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg: {focus: 0.0}}
+                }
+                on = {
+                    from: {all: Snap}
+                    apply: {bg: {focus: 1.0}}
+                }
+            }
         }
     }
 }
@@ -158,6 +176,12 @@ impl Tab {
         //cx.turtle_align_y();
         self.bg.end(cx);
         
+        cx.add_nav_stop_with_info(self.bg.area(), NavRole::Tab, Margin::default(), NavInfo {
+            name: name.to_string(),
+            value: None,
+            state: NavState {selected: Some(self.is_selected), ..NavState::default()}
+        });
+        
         if self.is_dragged {
             self.drag.draw_abs(cx, self.bg.area().get_clipped_rect(cx));
         }
@@ -190,6 +214,15 @@ impl Tab {
             Hit::FingerDown(_) => {
                 dispatch_action(cx, TabAction::WasPressed);
             }
+            Hit::KeyFocus(_) => {
+                self.animate_state(cx, id!(focus.on));
+            }
+            Hit::KeyFocusLost(_) => {
+                self.animate_state(cx, id!(focus.off));
+            }
+            Hit::KeyDown(ke) if !ke.is_repeat && is_activate_key(ke.key_code) => {
+                dispatch_action(cx, TabAction::WasPressed);
+            }
             _ => {}
         }
         match event.drag_hits(cx, self.bg.area()) {
@@ -86,14 +86,14 @@ impl TabBar {
             self.drag.draw_walk(
                 cx,
                 Walk {
-                    width: Size::Fill,
-                    height: Size::Fill,
+                    width: Size::fill(),
+                    height: Size::fill(),
                     ..Walk::default()
                 },
             );
         }
         self.tabs.retain_visible();
-        self.bar_fill.draw_walk(cx, Walk::size(Size::Fill, Size::Fill));
+        self.bar_fill.draw_walk(cx, Walk::size(Size::fill(), Size::fill()));
         self.scroll_bars.end(cx);
     }
     
@@ -0,0 +1,907 @@
+use {
+    std::{
+        cmp::Ordering,
+        collections::{HashMap, HashSet},
+    },
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        data_binding::DataBinding,
+        frame::FrameRef,
+        portal_list::PortalList,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+    import makepad_widgets::label::Label;
+    import makepad_widgets::portal_list::PortalList;
+    import makepad_widgets::scroll_bars::ScrollBars;
+
+    DrawHeaderCell = {{DrawHeaderCell}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.clear(mix(COLOR_BG_HEADER, COLOR_BG_EDITOR, self.hover));
+            // the divider that resizes the column
+            sdf.rect(self.rect_size.x - 1.0, 3.0, 1.0, self.rect_size.y - 6.0);
+            sdf.fill(COLOR_UP_10);
+            if self.sort != 0.0 {
+                let sz = 3.0;
+                let c = vec2(self.rect_size.x - 10.0, self.rect_size.y * 0.5);
+                sdf.move_to(c.x - sz, c.y + sz * self.sort);
+                sdf.line_to(c.x, c.y - sz * self.sort);
+                sdf.line_to(c.x + sz, c.y + sz * self.sort);
+                sdf.close_path();
+                sdf.fill(COLOR_TEXT_DEFAULT);
+            }
+            return sdf.result
+        }
+    }
+
+    DrawTableRow = {{DrawTableRow}} {
+        fn pixel(self) -> vec4 {
+            return mix(
+                mix(COLOR_BG_EDITOR, COLOR_BG_ODD, self.odd),
+                COLOR_BG_SELECTED,
+                self.selected
+            );
+        }
+    }
+
+    Table = {{Table}} {
+        walk: {width: Fill, height: Fill}
+        layout: {flow: Down}
+        header_height: (DIM_TAB_HEIGHT)
+        header_layout: {
+            align: {y: 0.5}
+            padding: {left: 6.0, right: 18.0}
+        }
+        header_label: {
+            text_style: <FONT_LABEL> {}
+            color: (COLOR_TEXT_DEFAULT)
+        }
+        cell_layout: {
+            padding: {left: 6.0, top: 4.0, right: 6.0, bottom: 4.0}
+        }
+        draw_cursor: {
+            color: (COLOR_BG_SELECTED)
+        }
+        body: <PortalList> {
+            scroll_bars: <ScrollBars> {
+                show_scroll_x: true,
+                show_scroll_y: true,
+                scroll_bar_y: {smoothing: 0.15}
+            }
+        }
+        Cell = <Label> {
+            label: {
+                text_style: <FONT_DATA> {}
+                color: (COLOR_TEXT_DEFAULT)
+            }
+        }
+    }
+}
+
+// how close to the right edge of a header cell a finger resizes the column
+const RESIZE_MARGIN: f64 = 4.0;
+// how far a finger moves on a header cell before it drags the column
+const DRAG_DISTANCE: f64 = 4.0;
+
+// A column of a table, declared in the DSL as
+//
+// columns: [
+//     {id: name, label: "Name", width: 150}
+//     {id: size, label: "Size", cell: NumberCell}
+// ]
+//
+// The cells of a column are instanced from the `cell` template of the table, which gets the
+// value of the cell as its `text`.
+#[derive(Live, Clone)]
+#[live_ignore]
+pub struct TableColumn {
+    // the field of the bound rows that the column shows
+    id: LiveId,
+    label: String,
+    #[live(100.0)] width: f64,
+    #[live(30.0)] min_width: f64,
+    #[live(true)] sortable: bool,
+    #[rust(live_id!(Cell))] cell: LiveId,
+}
+
+impl LiveHook for TableColumn {
+    // template names are capitalized, which makes them enum values rather than ids
+    fn apply_value_unknown(&mut self, cx: &mut Cx, _from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        match (nodes[index].id, &nodes[index].value) {
+            (live_id!(cell), LiveValue::BareEnum(id) | LiveValue::Id(id)) => {
+                self.cell = *id;
+            }
+            _ => {
+                cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+            }
+        }
+        nodes.skip_node(index)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Live, LiveHook)]
+#[live_ignore]
+pub enum TableSelection {
+    // whole rows are selected, with shift and control to select more than one
+    #[pick] Row,
+    // a single cell is selected
+    Cell
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawHeaderCell {
+    draw_super: DrawQuad,
+    hover: f32,
+    // 1.0 when the table is sorted ascending on the column, -1.0 when descending
+    sort: f32,
+}
+
+#[derive(Live, LiveHook)]
+#[repr(C)]
+pub struct DrawTableRow {
+    draw_super: DrawQuad,
+    selected: f32,
+    odd: f32,
+}
+
+// A table of rows of text, with a header to sort and rearrange the columns. Only the rows in
+// view are drawn, so tables can have any number of rows. Rows are set with `set_rows` or bound
+// to an array of objects with a `DataBinding`, where the fields of an object are the values of
+// the columns with the same id.
+//
+// Clicking a header cell sorts on its column, dragging its edge resizes the column and
+// dragging the cell moves the column. The selection follows the arrow keys, and copies as tab
+// separated values.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(Table))]
+pub struct Table {
+    walk: Walk,
+    layout: Layout,
+    body: PortalList,
+
+    header_height: f64,
+    header_layout: Layout,
+    draw_header: DrawHeaderCell,
+    header_label: DrawText,
+    draw_row: DrawTableRow,
+    draw_cursor: DrawColor,
+    cell_layout: Layout,
+
+    columns: Vec<TableColumn>,
+    selection: TableSelection,
+
+    #[rust] area: Area,
+    #[rust] header_area: Area,
+    #[rust] templates: ComponentMap<LiveId, LivePtr>,
+    #[rust] cells: HashMap<(usize, usize), TableCell>,
+    #[rust] free_cells: HashMap<LiveId, Vec<WidgetRef>>,
+    #[rust] drawn_cells: HashSet<(usize, usize)>,
+
+    // the values of the rows, in the order the columns are declared in
+    #[rust] rows: Vec<Vec<String>>,
+    // the columns in the order they are shown
+    #[rust] column_order: Vec<usize>,
+    #[rust] sort: Option<(usize, bool)>,
+    // the rows in the order they are shown, and the other way around
+    #[rust] sorted_rows: Vec<usize>,
+    #[rust] row_positions: Vec<usize>,
+
+    #[rust] selected_rows: HashSet<usize>,
+    // the row and column of the selected cell, which is where the keys move from
+    #[rust] cursor: Option<(usize, usize)>,
+    // the other end of a range of selected rows
+    #[rust] anchor: Option<usize>,
+
+    #[rust] hover_column: Option<usize>,
+    #[rust] header_drag: HeaderDrag,
+    // where the first column starts, on screen
+    #[rust] header_left: f64,
+    // the rows that were drawn, and where
+    #[rust] row_rects: Vec<(usize, Rect)>,
+}
+
+struct TableCell {
+    template: LiveId,
+    widget: WidgetRef,
+    text: String,
+}
+
+#[derive(Clone, Copy, Default)]
+enum HeaderDrag {
+    #[default]
+    None,
+    // a finger is down on a column, which sorts when it comes up without moving
+    Press {column: usize},
+    Resize {column: usize, start_width: f64},
+    Move {column: usize},
+}
+
+#[derive(Clone, WidgetAction)]
+pub enum TableAction {
+    Sorted {column: LiveId, descending: bool},
+    ColumnsChanged,
+    SelectionChanged,
+    CellClicked {row: usize, column: LiveId},
+    None
+}
+
+impl LiveHook for Table {
+    fn apply_value_instance(&mut self, cx: &mut Cx, from: ApplyFrom, index: usize, nodes: &[LiveNode]) -> usize {
+        let id = nodes[index].id;
+        match from {
+            ApplyFrom::NewFromDoc {file_id} | ApplyFrom::UpdateFromDoc {file_id} => {
+                if nodes[index].origin.has_prop_type(LivePropType::Template)
+                    || nodes[index].origin.has_prop_type(LivePropType::Instance) {
+                    let live_ptr = cx.live_registry.borrow().file_id_index_to_live_ptr(file_id, index);
+                    self.templates.insert(id, live_ptr);
+                    for cell in self.cells.values_mut() {
+                        if cell.template == id {
+                            cell.widget.apply(cx, from, index, nodes);
+                        }
+                    }
+                    self.free_cells.remove(&id);
+                }
+                else {
+                    cx.apply_error_no_matching_field(live_error_origin!(), index, nodes);
+                }
+            }
+            _ => ()
+        }
+        nodes.skip_node(index)
+    }
+
+    fn after_apply(&mut self, _cx: &mut Cx, _from: ApplyFrom, _index: usize, _nodes: &[LiveNode]) {
+        if self.column_order.len() != self.columns.len() {
+            self.column_order = (0..self.columns.len()).collect();
+            if let Some((column, _)) = self.sort {
+                if column >= self.columns.len() {
+                    self.sort = None;
+                }
+            }
+            self.sort_rows();
+        }
+    }
+}
+
+impl Widget for Table {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+        for cell in self.cells.values() {
+            cell.widget.handle_widget_event_fn(cx, event, dispatch_action);
+        }
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.area.redraw(cx);
+    }
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        cx.begin_turtle(walk, self.layout);
+        self.draw_header(cx);
+        let body_walk = self.body.get_walk();
+        if self.body.draw_widget(cx, body_walk).is_not_done() {
+            self.draw_rows(cx);
+            let _ = self.body.draw_widget(cx, body_walk);
+        }
+        cx.end_turtle_with_area(&mut self.area);
+        WidgetDraw::done()
+    }
+
+    fn find_widget(&mut self, path: &[LiveId], _cached: WidgetCache) -> WidgetResult {
+        for cell in self.cells.values_mut() {
+            if let Some(result) = cell.widget.find_widget(path, WidgetCache::No).into_found() {
+                return WidgetResult::found(result)
+            }
+        }
+        WidgetResult::not_found()
+    }
+
+    fn find_template(&self, id: &[LiveId; 1]) -> Option<LivePtr> {
+        self.templates.get(&id[0]).copied()
+    }
+
+    fn bind_to(&mut self, cx: &mut Cx, db: &mut DataBinding, _act: &WidgetActions, path: &[LiveId]) {
+        match db {
+            DataBinding::ToWidgets {nodes} => {
+                let index = match nodes.child_by_field_path(0, path) {
+                    Some(index) if nodes[index].is_array() => index,
+                    _ => return
+                };
+                let mut rows = Vec::new();
+                let mut row = nodes.first_child(index);
+                while let Some(row_index) = row {
+                    rows.push(self.columns.iter().map( | column | {
+                        match nodes.child_by_name(row_index, LiveProp::field(column.id)) {
+                            Some(value_index) => live_value_to_string(&nodes[value_index].value),
+                            None => String::new()
+                        }
+                    }).collect());
+                    row = nodes.next_child(row_index);
+                }
+                if rows != self.rows {
+                    self.set_rows(cx, rows);
+                }
+            }
+            // the table only shows the data
+            DataBinding::FromWidgets {..} => ()
+        }
+    }
+}
+
+fn live_value_to_string(value: &LiveValue) -> String {
+    match value {
+        LiveValue::Str(s) => s.to_string(),
+        LiveValue::FittedString(s) => s.as_str().to_string(),
+        LiveValue::InlineString(s) => s.as_str().to_string(),
+        LiveValue::Bool(v) => v.to_string(),
+        LiveValue::Int64(v) => v.to_string(),
+        LiveValue::Float32(v) => v.to_string(),
+        LiveValue::Float64(v) => v.to_string(),
+        LiveValue::Id(id) | LiveValue::BareEnum(id) => id.to_string(),
+        _ => String::new()
+    }
+}
+
+// a value with a tab, newline or quote is quoted, with its quotes doubled, like spreadsheets paste it
+fn push_tsv_field(tsv: &mut String, value: &str) {
+    if value.contains(['\t', '\n', '\r', '"']) {
+        tsv.push('"');
+        tsv.push_str(&value.replace('"', "\"\""));
+        tsv.push('"');
+    }
+    else {
+        tsv.push_str(value);
+    }
+}
+
+// numbers sort by their value, everything else as text
+fn compare_values(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b)
+    }
+}
+
+impl Table {
+    pub fn set_rows(&mut self, cx: &mut Cx, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        let row_count = self.rows.len();
+        self.selected_rows.retain( | row | *row < row_count);
+        if let Some((row, _)) = self.cursor {
+            if row >= row_count {
+                self.cursor = None;
+            }
+        }
+        if let Some(anchor) = self.anchor {
+            if anchor >= row_count {
+                self.anchor = None;
+            }
+        }
+        // the cells show new values from the next draw on
+        for cell in self.cells.values_mut() {
+            cell.text.clear();
+        }
+        self.sort_rows();
+        self.area.redraw(cx);
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    // the column id, and whether it is descending
+    pub fn sort_column(&self) -> Option<(LiveId, bool)> {
+        self.sort.map( | (column, descending) | (self.columns[column].id, descending))
+    }
+
+    pub fn sort_by(&mut self, cx: &mut Cx, column: LiveId, descending: bool) {
+        self.sort = self.columns.iter().position( | c | c.id == column).map( | column | (column, descending));
+        self.sort_rows();
+        self.area.redraw(cx);
+    }
+
+    // the selected rows, in the order they are shown
+    pub fn selected_rows(&self) -> Vec<usize> {
+        let mut rows: Vec<usize> = self.selected_rows.iter().copied().collect();
+        rows.sort_by_key( | row | self.row_positions[*row]);
+        rows
+    }
+
+    // the row and column id of the selected cell
+    pub fn selected_cell(&self) -> Option<(usize, LiveId)> {
+        self.cursor.map( | (row, column) | (row, self.columns[column].id))
+    }
+
+    pub fn select_row(&mut self, cx: &mut Cx, row: usize) {
+        if row >= self.rows.len() {
+            return
+        }
+        self.selected_rows.clear();
+        self.selected_rows.insert(row);
+        let column = self.cursor.map( | (_, column) | column).unwrap_or(0);
+        self.cursor = Some((row, column));
+        self.anchor = Some(row);
+        self.body.scroll_into_view(cx, self.row_positions[row] as u64);
+        self.area.redraw(cx);
+    }
+
+    fn sort_rows(&mut self) {
+        let mut sorted_rows: Vec<usize> = (0..self.rows.len()).collect();
+        if let Some((column, descending)) = self.sort {
+            let rows = &self.rows;
+            let empty = String::new();
+            sorted_rows.sort_by( | a, b | {
+                let a = rows[*a].get(column).unwrap_or(&empty);
+                let b = rows[*b].get(column).unwrap_or(&empty);
+                let ordering = compare_values(a, b);
+                if descending {ordering.reverse()} else {ordering}
+            });
+        }
+        self.row_positions = vec![0; sorted_rows.len()];
+        for (position, row) in sorted_rows.iter().enumerate() {
+            self.row_positions[*row] = position;
+        }
+        self.sorted_rows = sorted_rows;
+    }
+
+    fn total_width(&self) -> f64 {
+        self.columns.iter().map( | column | column.width).sum()
+    }
+
+    // the column at a distance from the left of the first column, and whether it is on the
+    // edge that resizes the column
+    fn column_at(&self, x: f64) -> Option<(usize, bool)> {
+        let mut left = 0.0;
+        for column in &self.column_order {
+            let right = left + self.columns[*column].width;
+            if (x - right).abs() <= RESIZE_MARGIN {
+                return Some((*column, true))
+            }
+            if x >= left && x < right {
+                return Some((*column, false))
+            }
+            left = right;
+        }
+        None
+    }
+
+    fn row_at(&self, abs: DVec2) -> Option<(usize, Rect)> {
+        self.row_rects.iter().find( | (_, rect) | rect.contains(abs)).copied()
+    }
+
+    fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, TableAction)) {
+        // the table moves its selection with the keys, rather than the body scrolling with them
+        if !matches!(event, Event::KeyDown(_)) {
+            self.body.handle_widget_event_fn(cx, event, &mut | _, _ | {});
+        }
+
+        self.handle_header_event(cx, event, dispatch_action);
+
+        match event.hits(cx, self.body.area()) {
+            Hit::FingerDown(fe) => {
+                cx.set_key_focus(self.body.area());
+                if let Some((position, rect)) = self.row_at(fe.abs) {
+                    let row = self.sorted_rows[position];
+                    if let Some((column, _)) = self.column_at(fe.abs.x - rect.pos.x) {
+                        self.select_at(cx, row, column, fe.modifiers.shift, fe.modifiers.control || fe.modifiers.logo);
+                        dispatch_action(cx, TableAction::SelectionChanged);
+                        dispatch_action(cx, TableAction::CellClicked {row, column: self.columns[column].id});
+                    }
+                }
+            }
+            Hit::KeyDown(ke) => {
+                let page = self.row_rects.len().saturating_sub(1).max(1) as isize;
+                let (rows, columns) = match ke.key_code {
+                    KeyCode::ArrowUp => (-1, 0),
+                    KeyCode::ArrowDown => (1, 0),
+                    KeyCode::ArrowLeft => (0, -1),
+                    KeyCode::ArrowRight => (0, 1),
+                    KeyCode::PageUp => (-page, 0),
+                    KeyCode::PageDown => (page, 0),
+                    KeyCode::Home => (isize::MIN / 2, 0),
+                    KeyCode::End => (isize::MAX / 2, 0),
+                    KeyCode::KeyA if ke.modifiers.control || ke.modifiers.logo => {
+                        if self.selection == TableSelection::Row {
+                            self.selected_rows = (0..self.rows.len()).collect();
+                            self.area.redraw(cx);
+                            dispatch_action(cx, TableAction::SelectionChanged);
+                        }
+                        return
+                    }
+                    _ => return
+                };
+                if self.move_cursor(cx, rows, columns, ke.modifiers.shift) {
+                    dispatch_action(cx, TableAction::SelectionChanged);
+                }
+            }
+            Hit::TextCopy(ce) => {
+                *ce.response.borrow_mut() = self.copy_selection();
+            }
+            _ => ()
+        }
+    }
+
+    fn handle_header_event(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, TableAction)) {
+        match event.hits(cx, self.header_area) {
+            Hit::FingerHoverIn(fe) | Hit::FingerHoverOver(fe) => {
+                let hit = self.column_at(fe.abs.x - self.header_left);
+                if let Some((_, true)) = hit {
+                    cx.set_cursor(MouseCursor::ColResize);
+                }
+                else if let Some((column, false)) = hit {
+                    if self.columns[column].sortable {
+                        cx.set_cursor(MouseCursor::Hand);
+                    }
+                }
+                let hover_column = hit.map( | (column, _) | column);
+                if self.hover_column != hover_column {
+                    self.hover_column = hover_column;
+                    self.area.redraw(cx);
+                }
+            }
+            Hit::FingerHoverOut(_) if self.hover_column.take().is_some() => {
+                self.area.redraw(cx);
+            }
+            Hit::FingerDown(fe) => {
+                cx.set_key_focus(self.body.area());
+                self.header_drag = match self.column_at(fe.abs.x - self.header_left) {
+                    Some((column, true)) => {
+                        cx.set_cursor(MouseCursor::ColResize);
+                        HeaderDrag::Resize {column, start_width: self.columns[column].width}
+                    }
+                    Some((column, false)) => HeaderDrag::Press {column},
+                    None => HeaderDrag::None
+                };
+            }
+            Hit::FingerMove(fe) => {
+                let dx = fe.abs.x - fe.abs_start.x;
+                match self.header_drag {
+                    HeaderDrag::Resize {column, start_width} => {
+                        let width = (start_width + dx).max(self.columns[column].min_width);
+                        if width != self.columns[column].width {
+                            self.columns[column].width = width;
+                            self.area.redraw(cx);
+                        }
+                    }
+                    HeaderDrag::Press {column} if dx.abs() > DRAG_DISTANCE => {
+                        self.header_drag = HeaderDrag::Move {column};
+                    }
+                    HeaderDrag::Move {column} => {
+                        if let Some((over, _)) = self.column_at(fe.abs.x - self.header_left) {
+                            if over != column {
+                                let from = self.column_order.iter().position( | c | *c == column).unwrap();
+                                let to = self.column_order.iter().position( | c | *c == over).unwrap();
+                                self.column_order.remove(from);
+                                self.column_order.insert(to, column);
+                                self.area.redraw(cx);
+                            }
+                        }
+                    }
+                    _ => ()
+                }
+            }
+            Hit::FingerUp(fe) => {
+                match self.header_drag {
+                    HeaderDrag::Press {column} if fe.is_over && self.columns[column].sortable => {
+                        let descending = matches!(self.sort, Some((sorted, false)) if sorted == column);
+                        self.sort = Some((column, descending));
+                        self.sort_rows();
+                        self.area.redraw(cx);
+                        dispatch_action(cx, TableAction::Sorted {column: self.columns[column].id, descending});
+                    }
+                    HeaderDrag::Resize {..} | HeaderDrag::Move {..} => {
+                        dispatch_action(cx, TableAction::ColumnsChanged);
+                    }
+                    _ => ()
+                }
+                self.header_drag = HeaderDrag::None;
+            }
+            _ => ()
+        }
+    }
+
+    fn select_at(&mut self, cx: &mut Cx, row: usize, column: usize, extend: bool, toggle: bool) {
+        self.cursor = Some((row, column));
+        if self.selection == TableSelection::Row {
+            if let (true, Some(anchor)) = (extend, self.anchor) {
+                let anchor = self.row_positions[anchor];
+                let position = self.row_positions[row];
+                self.selected_rows.clear();
+                for position in anchor.min(position)..=anchor.max(position) {
+                    self.selected_rows.insert(self.sorted_rows[position]);
+                }
+            }
+            else if toggle {
+                if !self.selected_rows.remove(&row) {
+                    self.selected_rows.insert(row);
+                }
+                self.anchor = Some(row);
+            }
+            else {
+                self.selected_rows.clear();
+                self.selected_rows.insert(row);
+                self.anchor = Some(row);
+            }
+        }
+        self.area.redraw(cx);
+    }
+
+    // moves the selected cell by a number of rows and columns, in the order they are shown
+    fn move_cursor(&mut self, cx: &mut Cx, rows: isize, columns: isize, extend: bool) -> bool {
+        if self.rows.is_empty() || self.columns.is_empty() {
+            return false
+        }
+        let (position, column) = match self.cursor {
+            Some((row, column)) => {
+                let column = self.column_order.iter().position( | c | *c == column).unwrap_or(0);
+                (self.row_positions[row] as isize + rows, column as isize + columns)
+            }
+            None => (0, 0)
+        };
+        let position = position.clamp(0, self.rows.len() as isize - 1) as usize;
+        let column = self.column_order[column.clamp(0, self.column_order.len() as isize - 1) as usize];
+        let row = self.sorted_rows[position];
+        if self.cursor == Some((row, column)) {
+            return false
+        }
+        self.select_at(cx, row, column, extend, false);
+        self.body.scroll_into_view(cx, position as u64);
+        true
+    }
+
+    // the selection as tab separated values
+    fn copy_selection(&self) -> Option<String> {
+        match self.selection {
+            TableSelection::Row => {
+                if self.selected_rows.is_empty() {
+                    return None
+                }
+                let mut tsv = String::new();
+                for row in self.selected_rows() {
+                    for (index, column) in self.column_order.iter().enumerate() {
+                        if index > 0 {
+                            tsv.push('\t');
+                        }
+                        push_tsv_field(&mut tsv, self.rows[row].get(*column).map(String::as_str).unwrap_or(""));
+                    }
+                    tsv.push('\n');
+                }
+                Some(tsv)
+            }
+            TableSelection::Cell => {
+                let (row, column) = self.cursor?;
+                self.rows[row].get(column).cloned()
+            }
+        }
+    }
+
+    fn draw_header(&mut self, cx: &mut Cx2d) {
+        let scroll_x = self.body.scroll_pos().x;
+        cx.begin_turtle(
+            Walk::size(Size::fill(), Size::Fixed(self.header_height)),
+            Layout::flow_right().with_scroll(dvec2(scroll_x, 0.0))
+        );
+        self.header_left = cx.turtle().pos().x;
+        for column in self.column_order.clone() {
+            self.draw_header.hover = if self.hover_column == Some(column) {1.0} else {0.0};
+            self.draw_header.sort = match self.sort {
+                Some((sorted, descending)) if sorted == column => if descending {-1.0} else {1.0},
+                _ => 0.0
+            };
+            let width = self.columns[column].width;
+            self.draw_header.begin(cx, Walk::size(Size::Fixed(width), Size::fill()), self.header_layout);
+            self.header_label.draw_walk(cx, Walk::fill(), Align {x: 0.0, y: 0.5}, &self.columns[column].label);
+            self.draw_header.end(cx);
+        }
+        cx.end_turtle_with_area(&mut self.header_area);
+    }
+
+    fn draw_rows(&mut self, cx: &mut Cx2d) {
+        self.body.set_item_count(self.rows.len() as u64);
+        self.row_rects.clear();
+        self.drawn_cells.clear();
+        let total_width = self.total_width();
+        while let Some(position) = self.body.next_visible_item(cx) {
+            let position = position as usize;
+            let row = self.sorted_rows[position];
+            let selected = match self.selection {
+                TableSelection::Row => self.selected_rows.contains(&row),
+                TableSelection::Cell => false
+            };
+            self.draw_row.selected = if selected {1.0} else {0.0};
+            self.draw_row.odd = (position & 1) as f32;
+            self.draw_row.begin(cx, Walk::size(Size::Fixed(total_width), Size::Fit), Layout::flow_right());
+            for column in self.column_order.clone() {
+                let walk = Walk::size(Size::Fixed(self.columns[column].width), Size::Fit);
+                let is_cursor = self.selection == TableSelection::Cell && self.cursor == Some((row, column));
+                if is_cursor {
+                    self.draw_cursor.begin(cx, walk, self.cell_layout);
+                }
+                else {
+                    cx.begin_turtle(walk, self.cell_layout);
+                }
+                if let Some(cell) = self.get_cell(cx, row, column) {
+                    // cells draw in one go
+                    let _ = cell.draw_walk_widget(cx);
+                }
+                if is_cursor {
+                    self.draw_cursor.end(cx);
+                }
+                else {
+                    cx.end_turtle();
+                }
+            }
+            let rect = cx.end_turtle();
+            self.draw_row.draw_vars.area.set_rect(cx, &rect);
+            self.row_rects.push((position, rect));
+        }
+        // rows are as wide as the view, this makes the body scroll over all the columns
+        cx.walk_turtle(Walk::size(Size::Fixed(total_width), Size::Fixed(0.0)));
+
+        // cells that scrolled out of view are kept for reuse
+        let drawn_cells = &self.drawn_cells;
+        let free_cells = &mut self.free_cells;
+        self.cells.retain( | key, cell | {
+            if drawn_cells.contains(key) {
+                return true
+            }
+            free_cells.entry(cell.template).or_default().push(cell.widget.clone());
+            false
+        });
+    }
+
+    fn get_cell(&mut self, cx: &mut Cx, row: usize, column: usize) -> Option<WidgetRef> {
+        let template = self.columns[column].cell;
+        let live_ptr = *self.templates.get(&template)?;
+        let key = (row, column);
+        self.drawn_cells.insert(key);
+        let value = self.rows[row].get(column).map(String::as_str).unwrap_or("");
+        if let Some(cell) = self.cells.get_mut(&key) {
+            if cell.template == template {
+                if cell.text != value {
+                    cell.text = value.to_string();
+                    cell.widget.apply_over(cx, live!{text: (value)});
+                }
+                return Some(cell.widget.clone())
+            }
+        }
+        let mut widget = match self.free_cells.get_mut(&template).and_then( | free | free.pop()) {
+            Some(widget) => widget,
+            None => WidgetRef::new_from_ptr(cx, Some(live_ptr))
+        };
+        widget.apply_over(cx, live!{text: (value)});
+        let cell = TableCell {template, widget: widget.clone(), text: value.to_string()};
+        if let Some(old_cell) = self.cells.insert(key, cell) {
+            self.free_cells.entry(old_cell.template).or_default().push(old_cell.widget);
+        }
+        Some(widget)
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct TableRef(WidgetRef);
+
+impl TableRef {
+    pub fn set_rows(&self, cx: &mut Cx, rows: Vec<Vec<String>>) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_rows(cx, rows);
+        }
+    }
+
+    pub fn selected_rows(&self) -> Vec<usize> {
+        if let Some(inner) = self.inner() {
+            return inner.selected_rows()
+        }
+        Vec::new()
+    }
+
+    pub fn sorted(&self, actions: &WidgetActions) -> Option<(LiveId, bool)> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let TableAction::Sorted {column, descending} = item.action() {
+                return Some((column, descending))
+            }
+        }
+        None
+    }
+
+    pub fn cell_clicked(&self, actions: &WidgetActions) -> Option<(usize, LiveId)> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let TableAction::CellClicked {row, column} = item.action() {
+                return Some((row, column))
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: &[[&str; 2]]) -> (Cx, Table) {
+        let (mut cx, mut table): (_, Table) = crate::test_widget("table", live_id!(Table));
+        table.apply_over(&mut cx, live!{
+            columns: [{id: name}, {id: size}]
+        });
+        table.set_rows(&mut cx, rows.iter().map( | row | row.iter().map( | value | value.to_string()).collect()).collect());
+        (cx, table)
+    }
+
+    #[test]
+    fn compare_values() {
+        assert_eq!(super::compare_values("9", "10"), Ordering::Less);
+        assert_eq!(super::compare_values(" 2.5", "1e1"), Ordering::Less);
+        assert_eq!(super::compare_values("-3", "-3.0"), Ordering::Equal);
+        // numbers come before text
+        assert_eq!(super::compare_values("100", "a"), Ordering::Less);
+        assert_eq!(super::compare_values("", "0"), Ordering::Greater);
+        assert_eq!(super::compare_values("b", "a"), Ordering::Greater);
+        assert_eq!(super::compare_values("B", "a"), Ordering::Less);
+    }
+
+    #[test]
+    fn sort_rows() {
+        let (mut cx, mut table) = table(&[["b", "10"], ["a", "9"], ["c", "x"], ["a", "1"]]);
+        assert_eq!(table.sorted_rows, [0, 1, 2, 3]);
+
+        table.sort_by(&mut cx, live_id!(size), false);
+        assert_eq!(table.sort_column(), Some((live_id!(size), false)));
+        assert_eq!(table.sorted_rows, [3, 1, 0, 2]);
+        assert_eq!(table.row_positions, [2, 1, 3, 0]);
+
+        // rows with the same value keep their order
+        table.sort_by(&mut cx, live_id!(name), true);
+        assert_eq!(table.sorted_rows, [2, 0, 1, 3]);
+
+        table.sort_by(&mut cx, live_id!(unknown), false);
+        assert_eq!(table.sort_column(), None);
+        assert_eq!(table.sorted_rows, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn copy_selection() {
+        let (mut cx, mut table) = table(&[["b", "10"], ["a", "9"], ["c", "x"]]);
+        assert_eq!(table.copy_selection(), None);
+
+        // rows copy in the order they are shown, with the columns in the order they are shown
+        table.sort_by(&mut cx, live_id!(name), false);
+        table.column_order = vec![1, 0];
+        table.selected_rows = HashSet::from([0, 1]);
+        assert_eq!(table.copy_selection().unwrap(), "9\ta\n10\tb\n");
+
+        // values that would break the rows and columns apart are quoted
+        table.set_rows(&mut cx, vec![
+            vec!["a\tb".to_string(), "say \"hi\"".to_string()],
+            vec!["two\nlines".to_string(), "plain".to_string()],
+        ]);
+        table.selected_rows = HashSet::from([0, 1]);
+        assert_eq!(table.copy_selection().unwrap(), "\"say \"\"hi\"\"\"\t\"a\tb\"\nplain\t\"two\nlines\"\n");
+
+        table.selection = TableSelection::Cell;
+        assert_eq!(table.copy_selection(), None);
+        table.cursor = Some((1, 0));
+        // a single cell is copied as it is
+        assert_eq!(table.copy_selection().unwrap(), "two\nlines");
+    }
+}
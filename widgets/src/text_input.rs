@@ -3,6 +3,11 @@ use {
     crate::{
         makepad_derive_widget::*,
         makepad_draw_2d::*,
+        makepad_draw_2d::makepad_segment::{
+            bidi::{Direction, Paragraph},
+            cursor::char::Cursor,
+            str::StrExt,
+        },
         widget::*,
     }
 };
@@ -77,6 +82,7 @@ live_design!{
         select_pad_edges: 3.0
         cursor_size: 2.0,
         numeric_only: false,
+        multiline: false,
         empty_message: "0",
         bg: {
             shape: Box
@@ -141,6 +147,17 @@ live_design!{
             }
         }
     }
+    
+    // a text input for notes and descriptions, which wraps its lines to its width
+    TextArea = <TextInput> {
+        multiline: true
+        empty_message: ""
+        walk: {width: Fill, height: 100}
+        label_walk: {width: Fill}
+        layout: {
+            padding: {left: 10, top: 8, right: 10, bottom: 8}
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -158,6 +175,7 @@ pub enum UndoGroup {
     Delete(u64),
     External(u64),
     Cut(u64),
+    Paste(u64),
 }
 
 
@@ -189,6 +207,8 @@ pub struct TextInput {
     select_pad_edges: f64,
     empty_message: String,
     numeric_only: bool,
+    // return inserts a newline rather than submitting, and the lines wrap to the label width
+    multiline: bool,
     
     pub read_only: bool,
     
@@ -201,7 +221,17 @@ pub struct TextInput {
     #[rust] undo_stack: Vec<UndoItem>,
     #[rust] redo_stack: Vec<UndoItem>,
     #[rust] cursor_tail: usize,
-    #[rust] cursor_head: usize
+    #[rust] cursor_head: usize,
+    
+    // the wrapped lines of a multiline input as they were last drawn, and where the text was
+    // relative to the background
+    #[rust] lines: Vec<TextLayoutLine>,
+    #[rust] text_offset: DVec2,
+    #[rust] scroll_y: f64,
+    #[rust] view_height: f64,
+    // the x the cursor keeps while it goes up and down, relative to the left of the text
+    #[rust] cursor_x: Option<f64>,
+    #[rust] scroll_to_cursor: bool,
 }
 
 impl LiveHook for TextInput {
@@ -390,24 +420,185 @@ impl TextInput {
         }
     }
     
+    fn byte_offset(&self, offset: usize) -> usize {
+        self.text.char_indices().nth(offset).map( | (index, _) | index).unwrap_or(self.text.len())
+    }
+    
+    // the char offset of the start of the word before the given one, or the end of the word
+    // after it. the whitespace between words is skipped
+    pub fn word_boundary(&self, offset: usize, forward: bool) -> usize {
+        let mut cursor = self.text.cursor_at(self.byte_offset(offset)).into_word_cursor();
+        loop {
+            let start = cursor.position();
+            if forward {
+                if cursor.is_at_end() {
+                    break
+                }
+                cursor.move_next();
+            }
+            else {
+                if cursor.is_at_start() {
+                    break
+                }
+                cursor.move_prev();
+            }
+            let end = cursor.position();
+            if !self.text[start.min(end)..start.max(end)].trim().is_empty() {
+                break
+            }
+        }
+        self.text[..cursor.position()].chars().count()
+    }
+    
+    fn move_cursor(&mut self, cx: &mut Cx, head: usize, extend: bool) {
+        self.undo_id += 1;
+        self.cursor_head = head.min(self.text.chars().count());
+        if !extend {
+            self.cursor_tail = self.cursor_head;
+        }
+        self.cursor_x = None;
+        self.scroll_to_cursor = true;
+        self.bg.redraw(cx);
+    }
+    
+    // the line of a multiline input that a cursor is on. a cursor where a line wraps goes at
+    // the start of the next line
+    fn line_of(&self, offset: usize) -> usize {
+        self.lines.iter().rposition( | line | line.start <= offset).unwrap_or(0)
+    }
+    
+    // the last place a cursor can go on a line, which is before the space a line wraps at
+    fn line_end(&self, line: usize) -> usize {
+        let line = &self.lines[line];
+        if !line.ends_paragraph && line.end > line.start {line.end - 1} else {line.end}
+    }
+    
+    fn text_origin(&self, cx: &Cx) -> DVec2 {
+        self.bg.area().get_rect(cx).pos + self.text_offset
+    }
+    
+    // where a cursor goes on screen, as the x and the top of its line
+    fn cursor_pos(&self, cx: &Cx, offset: usize, origin: DVec2) -> DVec2 {
+        let line = &self.lines[self.line_of(offset)];
+        let pos = if offset < line.end {
+            self.label.get_cursor_pos(cx, 0.0, offset)
+        }
+        else if offset > line.start {
+            self.label.get_cursor_pos(cx, 1.0, offset - 1)
+        }
+        else {
+            None
+        };
+        dvec2(pos.map( | pos | pos.x).unwrap_or(origin.x), origin.y + line.y)
+    }
+    
+    // the start of the line the cursor is on, or of the text when its a single line
+    fn line_home(&self) -> usize {
+        if !self.multiline || self.lines.is_empty() {
+            return 0
+        }
+        self.lines[self.line_of(self.cursor_head)].start
+    }
+    
+    fn line_end_of_cursor(&self) -> usize {
+        if !self.multiline || self.lines.is_empty() {
+            return self.text.chars().count()
+        }
+        self.line_end(self.line_of(self.cursor_head))
+    }
+    
+    // the char offset on a line that is closest to an x relative to the left of the text
+    fn offset_on_line(&self, cx: &Cx, line: usize, x: f64) -> usize {
+        let start = self.lines[line].start;
+        if self.lines[line].end == start {
+            return start
+        }
+        let origin = self.text_origin(cx);
+        let y = origin.y + self.lines[line].y + 0.5 * self.lines[line].height;
+        match self.label.closest_offset(cx, dvec2(origin.x + x, y)) {
+            Some(offset) => offset.clamp(start, self.line_end(line)),
+            None => start
+        }
+    }
+    
+    // the char offset closest to a position on screen
+    fn offset_at(&self, cx: &Cx, abs: DVec2) -> Option<usize> {
+        if !self.multiline {
+            return self.label.closest_offset(cx, abs)
+        }
+        if self.lines.is_empty() {
+            return None
+        }
+        let origin = self.text_origin(cx);
+        let line = self.lines.iter().rposition( | line | origin.y + line.y <= abs.y).unwrap_or(0);
+        Some(self.offset_on_line(cx, line, abs.x - origin.x))
+    }
+    
+    // moves the cursor up or down a number of lines, keeping it at the same x
+    fn move_lines(&mut self, cx: &mut Cx, lines: isize, extend: bool) {
+        if !self.multiline || self.lines.is_empty() {
+            let head = if lines < 0 {0} else {self.text.chars().count()};
+            self.move_cursor(cx, head, extend);
+            return
+        }
+        let origin = self.text_origin(cx);
+        let x = self.cursor_x.unwrap_or_else( || self.cursor_pos(cx, self.cursor_head, origin).x - origin.x);
+        let line = self.line_of(self.cursor_head) as isize + lines;
+        let head = if line < 0 {
+            0
+        }
+        else if line as usize >= self.lines.len() {
+            self.text.chars().count()
+        }
+        else {
+            self.offset_on_line(cx, line as usize, x)
+        };
+        self.move_cursor(cx, head, extend);
+        self.cursor_x = Some(x);
+    }
+    
+    // the number of lines that fit in view
+    fn page_lines(&self) -> isize {
+        let line_height = match self.lines.first() {
+            Some(line) if line.height > 0.0 => line.height,
+            _ => return 1
+        };
+        ((self.view_height / line_height) as isize - 1).max(1)
+    }
+    
     pub fn change(&mut self, cx: &mut Cx, s: &str, dispatch_action: &mut dyn FnMut(&mut Cx, TextInputAction)) {
         if self.read_only {
             return
         }
         self.replace_text(s);
+        self.cursor_x = None;
+        self.scroll_to_cursor = true;
         dispatch_action(cx, TextInputAction::Change(self.text.clone()));
         self.bg.redraw(cx);
     }
     
+    // the empty message names the input for the accessibility tree
+    fn nav_info(&self) -> NavInfo {
+        NavInfo {
+            name: self.empty_message.clone(),
+            value: Some(self.text.clone()),
+            state: NavState::default()
+        }
+    }
+
     pub fn set_key_focus(&self, cx: &mut Cx) {
         cx.set_key_focus(self.bg.area());
     }
     
+    pub fn has_key_focus(&self, cx: &Cx) -> bool {
+        cx.has_key_focus(self.bg.area())
+    }
+    
     pub fn filter_numeric(&self, input:String)->String{
         if self.numeric_only{
             let mut output = String::new();
             for c in input.chars(){
-                if c.is_ascii_digit() ||c == '.'{
+                if c.is_ascii_digit() || c == '.' || c == '-'{
                     output.push(c);
                 }
                 else if c == ','{  
@@ -431,6 +622,17 @@ impl TextInput {
     
     pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, TextInputAction)) {
         self.state_handle_event(cx, event);
+        if let Event::FingerScroll(fe) = event {
+            if self.multiline && !fe.handled_y.get() && self.bg.area().get_rect(cx).contains(fe.abs) {
+                let max_scroll = self.lines.last().map( | line | line.y + line.height).unwrap_or(0.0) - self.view_height;
+                let scroll_y = (self.scroll_y + fe.scroll.y).min(max_scroll).max(0.0);
+                if scroll_y != self.scroll_y {
+                    self.scroll_y = scroll_y;
+                    fe.handled_y.set(true);
+                    self.bg.redraw(cx);
+                }
+            }
+        }
         match event.hits(cx, self.bg.area()) {
             Hit::KeyFocusLost(_) => {
                 self.animate_state(cx, id!(focus.off));
@@ -440,18 +642,34 @@ impl TextInput {
             Hit::KeyFocus(_) => {
                 self.undo_id += 1;
                 self.animate_state(cx, id!(focus.on));
-                // select all
-                self.select_all();
+                // select all, unless its a multiline input where you're likely to add to the text
+                if !self.multiline {
+                    self.select_all();
+                }
                 self.bg.redraw(cx);
                 dispatch_action(cx, TextInputAction::KeyFocus);
             }
             Hit::TextInput(te) => {
-                let input = self.filter_numeric(te.input);
+                let mut input = self.filter_numeric(te.input);
+                if te.was_paste {
+                    input = if self.multiline {
+                        input.replace("\r\n", "\n").replace('\r', "\n")
+                    }
+                    else {
+                        input.replace("\r\n", " ").replace(['\r', '\n'], " ")
+                    };
+                }
                 if input.len() == 0{
                     return
                 }
                 let last_undo = self.last_undo.take();
-                if te.replace_last {
+                if te.was_paste {
+                    // a paste is undone on its own
+                    self.undo_id += 1;
+                    self.create_undo(UndoGroup::Paste(self.undo_id));
+                    self.undo_id += 1;
+                }
+                else if te.replace_last {
                     self.undo_id += 1;
                     self.create_undo(UndoGroup::TextInput(self.undo_id));
                     if let Some(item) = last_undo {
@@ -475,6 +693,11 @@ impl TextInput {
                 KeyCode::Tab => {
                     // dispatch_action(cx, self, TextInputAction::Tab(key.mod_shift));
                 }
+                KeyCode::ReturnKey if self.multiline && !ke.modifiers.control && !ke.modifiers.logo => {
+                    self.undo_id += 1;
+                    self.create_undo(UndoGroup::TextInput(self.undo_id));
+                    self.change(cx, "\n", dispatch_action);
+                }
                 KeyCode::ReturnKey => {
                     dispatch_action(cx, TextInputAction::Return(self.text.clone()));
                 },
@@ -508,10 +731,54 @@ impl TextInput {
                         self.change(cx, "", dispatch_action);
                     }
                 }
+                KeyCode::ArrowLeft if ke.modifiers.logo => {
+                    let head = self.line_home();
+                    self.move_cursor(cx, head, ke.modifiers.shift);
+                }
+                KeyCode::ArrowRight if ke.modifiers.logo => {
+                    let head = self.line_end_of_cursor();
+                    self.move_cursor(cx, head, ke.modifiers.shift);
+                }
+                KeyCode::ArrowLeft if ke.modifiers.control || ke.modifiers.alt => {
+                    let head = self.word_boundary(self.cursor_head, false);
+                    self.move_cursor(cx, head, ke.modifiers.shift);
+                }
+                KeyCode::ArrowRight if ke.modifiers.control || ke.modifiers.alt => {
+                    let head = self.word_boundary(self.cursor_head, true);
+                    self.move_cursor(cx, head, ke.modifiers.shift);
+                }
+                KeyCode::ArrowUp => {
+                    self.move_lines(cx, -1, ke.modifiers.shift);
+                }
+                KeyCode::ArrowDown => {
+                    self.move_lines(cx, 1, ke.modifiers.shift);
+                }
+                KeyCode::PageUp => {
+                    self.move_lines(cx, -self.page_lines(), ke.modifiers.shift);
+                }
+                KeyCode::PageDown => {
+                    self.move_lines(cx, self.page_lines(), ke.modifiers.shift);
+                }
+                KeyCode::Home => {
+                    let head = if ke.modifiers.control || ke.modifiers.logo {0} else {self.line_home()};
+                    self.move_cursor(cx, head, ke.modifiers.shift);
+                }
+                KeyCode::End => {
+                    let head = if ke.modifiers.control || ke.modifiers.logo {
+                        self.text.chars().count()
+                    }
+                    else {
+                        self.line_end_of_cursor()
+                    };
+                    self.move_cursor(cx, head, ke.modifiers.shift);
+                }
                 KeyCode::ArrowLeft => {
                     self.undo_id += 1;
-                    if self.cursor_head>0 {
-                        self.cursor_head -= 1;
+                    self.cursor_x = None;
+                    self.scroll_to_cursor = true;
+                    // the arrows move over the screen, which goes backwards through right-to-left text
+                    if let Some(head) = Paragraph::new(&self.text, None).move_visually(self.cursor_head, Direction::RightToLeft) {
+                        self.cursor_head = head;
                     }
                     if !ke.modifiers.shift {
                         self.cursor_tail = self.cursor_head;
@@ -520,8 +787,10 @@ impl TextInput {
                 },
                 KeyCode::ArrowRight => {
                     self.undo_id += 1;
-                    if self.cursor_head < self.text.chars().count() {
-                        self.cursor_head += 1;
+                    self.cursor_x = None;
+                    self.scroll_to_cursor = true;
+                    if let Some(head) = Paragraph::new(&self.text, None).move_visually(self.cursor_head, Direction::LeftToRight) {
+                        self.cursor_head = head;
                     }
                     if !ke.modifiers.shift {
                         self.cursor_tail = self.cursor_head;
@@ -531,7 +800,10 @@ impl TextInput {
                 KeyCode::Backspace => {
                     self.create_undo(UndoGroup::Backspace(self.undo_id));
                     if self.cursor_head == self.cursor_tail {
-                        if self.cursor_tail > 0 {
+                        if ke.modifiers.control || ke.modifiers.alt {
+                            self.cursor_tail = self.word_boundary(self.cursor_head, false);
+                        }
+                        else if self.cursor_tail > 0 {
                             self.cursor_tail -= 1;
                         }
                     }
@@ -540,7 +812,10 @@ impl TextInput {
                 KeyCode::Delete => {
                     self.create_undo(UndoGroup::Delete(self.undo_id));
                     if self.cursor_head == self.cursor_tail {
-                        if self.cursor_head < self.text.chars().count() {
+                        if ke.modifiers.control || ke.modifiers.alt {
+                            self.cursor_head = self.word_boundary(self.cursor_head, true);
+                        }
+                        else if self.cursor_head < self.text.chars().count() {
                             self.cursor_head += 1;
                         }
                     }
@@ -560,8 +835,9 @@ impl TextInput {
                 self.set_key_focus(cx);
                 // ok so we need to calculate where we put the cursor down.
                 //elf.
-                if let Some(pos) = self.label.closest_offset(cx, fe.abs) {
+                if let Some(pos) = self.offset_at(cx, fe.abs) {
                     //log!("{} {}", pos, fe.abs);
+                    self.cursor_x = None;
                     let pos = pos.min(self.text.chars().count());
                     self.cursor_head = pos;
                     if !fe.mod_shift() {
@@ -588,7 +864,7 @@ impl TextInput {
                 }
             }
             Hit::FingerMove(fe) => {
-                if let Some(pos) = self.label.closest_offset(cx, fe.abs) {
+                if let Some(pos) = self.offset_at(cx, fe.abs) {
                     let pos = pos.min(self.text.chars().count());
                     if fe.tap_count == 2 {
                         let (head, tail) = self.double_tap_start.unwrap();
@@ -604,6 +880,8 @@ impl TextInput {
                     }
                     else if fe.tap_count == 1 && pos != self.cursor_head {
                         self.cursor_head = pos;
+                        self.cursor_x = None;
+                        self.scroll_to_cursor = true;
                         self.bg.redraw(cx);
                     }
                 }
@@ -613,9 +891,13 @@ impl TextInput {
     }
     
     pub fn draw_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        if self.multiline {
+            return self.draw_multiline_walk(cx, walk)
+        }
         
         self.bg.begin(cx, walk, self.layout);
         let turtle_rect = cx.turtle().rect();
+        self.text_offset = cx.turtle().pos() - turtle_rect.pos;
         
         // this makes sure selection goes behind the text
         self.select.append_to_draw_call(cx);
@@ -644,24 +926,21 @@ impl TextInput {
             });
         }
         
-        // draw selection rect
+        // draw selection rects, more than one if the selection goes over a change in direction
         if self.cursor_head != self.cursor_tail {
-            let tail_x = self.label.get_cursor_pos(cx, 0.0, self.cursor_tail)
-                .unwrap_or(dvec2(turtle.pos.x, 0.0)).x;
-            
-            let (left_x, right_x, left, right) = if self.cursor_head < self.cursor_tail {
-                (head_x, tail_x, self.cursor_head, self.cursor_tail)
-            }
-            else {
-                (tail_x, head_x, self.cursor_tail, self.cursor_head)
-            };
+            let (left, right) = self.sorted_cursor();
             let char_count = self.label.get_char_count(cx);
             let pad = if left == 0 && right == char_count {self.select_pad_edges}else {0.0};
             
-            self.select.draw_abs(cx, Rect {
-                pos: dvec2(left_x - 0.5 * self.cursor_size - pad, turtle.pos.y),
-                size: dvec2(right_x - left_x + self.cursor_size + 2.0 * pad, turtle.size.y)
-            });
+            let spans = self.label.get_selection_spans(cx, left, right);
+            for (index, (left_x, right_x)) in spans.iter().cloned().enumerate() {
+                let left_pad = if index == 0 {pad} else {0.0};
+                let right_pad = if index + 1 == spans.len() {pad} else {0.0};
+                self.select.draw_abs(cx, Rect {
+                    pos: dvec2(left_x - 0.5 * self.cursor_size - left_pad, turtle.pos.y),
+                    size: dvec2(right_x - left_x + self.cursor_size + left_pad + right_pad, turtle.size.y)
+                });
+            }
         }
         self.bg.end(cx);
         
@@ -679,7 +958,138 @@ impl TextInput {
             }
         }
         
-        cx.add_nav_stop(self.bg.area(), NavRole::TextInput, Margin::default())
+        cx.add_nav_stop_with_info(self.bg.area(), NavRole::TextInput, Margin::default(), self.nav_info())
+    }
+    
+    fn draw_multiline_walk(&mut self, cx: &mut Cx2d, walk: Walk) {
+        self.bg.begin(cx, walk, self.layout.with_scroll(dvec2(0.0, self.scroll_y)));
+        let turtle_rect = cx.turtle().rect();
+        self.text_offset = cx.turtle().pos() - turtle_rect.pos;
+        let origin = turtle_rect.pos + self.text_offset;
+        
+        // this makes sure selection goes behind the text
+        self.select.append_to_draw_call(cx);
+        
+        self.lines = self.label.layout_lines_walk(cx, self.label_walk, &[TextSpan::new(&self.text)]);
+        if self.text.is_empty() {
+            self.label.is_empty = 1.0;
+            self.label.draw_spans_walk(cx, self.label_walk, self.align, &[TextSpan::new(&self.empty_message)]);
+        }
+        else {
+            self.label.is_empty = 0.0;
+            self.label.draw_spans_walk(cx, self.label_walk, self.align, &[TextSpan::new(&self.text)]);
+        }
+        
+        if self.lines.is_empty() {
+            self.bg.end(cx);
+            cx.add_nav_stop_with_info(self.bg.area(), NavRole::TextInput, Margin::default(), self.nav_info());
+            return
+        }
+        
+        let head = self.cursor_pos(cx, self.cursor_head, origin);
+        let head_line = self.lines[self.line_of(self.cursor_head)].clone();
+        if !self.read_only && self.cursor_head == self.cursor_tail {
+            self.cursor.draw_abs(cx, Rect {
+                pos: dvec2(head.x - 0.5 * self.cursor_size, head.y),
+                size: dvec2(self.cursor_size, head_line.height)
+            });
+        }
+        
+        // draw the selection a line at a time, with a bit extra where it takes in a newline
+        if self.cursor_head != self.cursor_tail {
+            let (left, right) = self.sorted_cursor();
+            let mut rects = Vec::new();
+            for line in &self.lines {
+                let y = origin.y + line.y;
+                for (left_x, right_x) in self.label.get_selection_spans(cx, left.max(line.start), right.min(line.end)) {
+                    rects.push(Rect {
+                        pos: dvec2(left_x - 0.5 * self.cursor_size, y),
+                        size: dvec2(right_x - left_x + self.cursor_size, line.height)
+                    });
+                }
+                if line.ends_paragraph && left <= line.end && right > line.end {
+                    let x = self.cursor_pos(cx, line.end, origin).x;
+                    rects.push(Rect {
+                        pos: dvec2(x - 0.5 * self.cursor_size, y),
+                        size: dvec2(self.select_pad_edges + self.cursor_size, line.height)
+                    });
+                }
+            }
+            for rect in rects {
+                self.select.draw_abs(cx, rect);
+            }
+        }
+        
+        // keep the scroll within the text, and the cursor in view when it has moved
+        let view_height = turtle_rect.size.y - self.layout.padding.top - self.layout.padding.bottom;
+        let text_height = self.lines.last().map( | line | line.y + line.height).unwrap_or(0.0);
+        self.view_height = if view_height.is_finite() {view_height} else {text_height};
+        let mut scroll_y = self.scroll_y;
+        if self.scroll_to_cursor {
+            self.scroll_to_cursor = false;
+            if head_line.y < scroll_y {
+                scroll_y = head_line.y;
+            }
+            else if head_line.y + head_line.height > scroll_y + self.view_height {
+                scroll_y = head_line.y + head_line.height - self.view_height;
+            }
+        }
+        scroll_y = scroll_y.min(text_height - self.view_height).max(0.0);
+        self.bg.end(cx);
+        if scroll_y != self.scroll_y {
+            self.scroll_y = scroll_y;
+            self.bg.redraw(cx);
+        }
+        
+        if cx.has_key_focus(self.bg.area()) {
+            if self.numeric_only {
+                cx.hide_text_ime();
+            }
+            else {
+                let ime_x = self.label.get_cursor_pos(cx, 0.5, self.cursor_head).map( | pos | pos.x).unwrap_or(head.x);
+                cx.show_text_ime(self.bg.area(), dvec2(ime_x, head.y) - turtle_rect.pos);
+            }
+        }
+        
+        cx.add_nav_stop_with_info(self.bg.area(), NavRole::TextInput, Margin::default(), self.nav_info())
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_boundary() {
+        let (mut cx, mut text_input): (_, TextInput) = crate::test_widget("text_input", live_id!(TextInput));
+
+        text_input.text = "hello world  foo.bar".to_string();
+        assert_eq!(text_input.word_boundary(0, true), 5);
+        assert_eq!(text_input.word_boundary(2, true), 5);
+        // the whitespace before a word is skipped
+        assert_eq!(text_input.word_boundary(5, true), 11);
+        assert_eq!(text_input.word_boundary(11, false), 6);
+        assert_eq!(text_input.word_boundary(6, false), 0);
+        assert_eq!(text_input.word_boundary(13, false), 6);
+        // a full stop between letters doesn't break the word
+        assert_eq!(text_input.word_boundary(11, true), 20);
+        assert_eq!(text_input.word_boundary(20, true), 20);
+        assert_eq!(text_input.word_boundary(0, false), 0);
+
+        // offsets are in chars
+        text_input.text = "héllo wörld".to_string();
+        assert_eq!(text_input.word_boundary(0, true), 5);
+        assert_eq!(text_input.word_boundary(11, false), 6);
+
+        // punctuation is a word of its own
+        text_input.text = "a, b".to_string();
+        assert_eq!(text_input.word_boundary(1, true), 2);
+        assert_eq!(text_input.word_boundary(4, false), 3);
+        assert_eq!(text_input.word_boundary(3, false), 1);
+
+        text_input.text = String::new();
+        assert_eq!(text_input.word_boundary(0, true), 0);
+        assert_eq!(text_input.word_boundary(0, false), 0);
+    }
+}
@@ -0,0 +1,390 @@
+use {
+    std::collections::VecDeque,
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        frame::FrameRef,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+
+    DrawToastBg = {{DrawToastBg}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(0.0, 0.0, self.rect_size.x, self.rect_size.y, 4.0);
+            sdf.fill_keep(mix(COLOR_BG_HEADER, COLOR_BG_EDITOR, self.hover));
+            sdf.stroke(COLOR_UP_10, 1.0);
+            // a stripe on the left in the colour of the kind of message
+            sdf.box(0.0, 0.0, 4.0, self.rect_size.y, 2.0);
+            sdf.fill(mix(
+                mix(COLOR_TEXT_META, COLOR_LOW, clamp(self.kind, 0.0, 1.0)),
+                mix(COLOR_WARNING, COLOR_ERROR, clamp(self.kind - 2.0, 0.0, 1.0)),
+                clamp(self.kind - 1.0, 0.0, 1.0)
+            ));
+            return sdf.result * self.shown;
+        }
+    }
+
+    DrawToastText = {{DrawToastText}} {
+        fn get_color(self) -> vec4 {
+            return vec4(self.color.rgb, self.color.a * self.shown);
+        }
+    }
+
+    ToastItem = {{ToastItem}} {
+        walk: {width: 300, height: Fit}
+        layout: {padding: {left: 16, top: 12, right: 12, bottom: 12}}
+        label: {
+            color: (COLOR_TEXT_HOVER)
+            wrap: Word
+        }
+        label_walk: {width: Fill, height: Fit}
+        state: {
+            hover = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.1}}
+                    apply: {bg: {hover: 0.0}}
+                }
+                on = {
+                    cursor: Hand
+                    from: {all: Snap}
+                    apply: {bg: {hover: 1.0}}
+                }
+            }
+            show = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: 0.3}}
+                    redraw: true
+                    apply: {
+                        bg: {shown: 0.0}
+                        label: {shown: 0.0}
+                    }
+                }
+                on = {
+                    from: {all: Forward {duration: 0.2}}
+                    redraw: true
+                    apply: {
+                        bg: {shown: 1.0}
+                        label: {shown: 1.0}
+                    }
+                }
+            }
+        }
+    }
+
+    Toast = {{Toast}} {
+        item: <ToastItem> {}
+        layout: {flow: Down, spacing: 8}
+        align: {x: 1.0, y: 1.0}
+        margin: 20
+        duration: 4.0
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawToastBg {
+    draw_super: DrawQuad,
+    kind: f32,
+    hover: f32,
+    shown: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawToastText {
+    draw_super: DrawText,
+    shown: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, Copy, PartialEq)]
+pub struct ToastId(pub u64);
+
+#[derive(Live, LiveHook)]
+pub struct ToastItem {
+    bg: DrawToastBg,
+    label: DrawToastText,
+    walk: Walk,
+    layout: Layout,
+    label_walk: Walk,
+    state: State,
+
+    #[rust] text: String,
+    #[rust] timer: Timer,
+    #[rust] closing: bool,
+}
+
+impl ToastItem {
+    fn draw(&mut self, cx: &mut Cx2d) {
+        self.bg.begin(cx, self.walk, self.layout);
+        self.label.draw_walk(cx, self.label_walk, Align::default(), &self.text);
+        self.bg.end(cx);
+    }
+
+    fn dismiss(&mut self, cx: &mut Cx) {
+        if !self.timer.is_empty() {
+            cx.stop_timer(self.timer);
+            self.timer = Timer::empty();
+        }
+        if !self.closing {
+            self.closing = true;
+            self.animate_state(cx, id!(show.off));
+        }
+    }
+}
+
+// Short messages that show in a corner of the window and go away by themselves after a while,
+// or when they are clicked:
+//
+// self.ui.get_toast(id!(toast)).push(cx, ToastKind::Success, "Saved");
+//
+// Only a few messages show at once, the rest wait in a queue until there is room for them. A
+// message stays while the mouse is on it.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(Toast))]
+pub struct Toast {
+    view: View,
+    item: Option<LivePtr>,
+    layout: Layout,
+    // where the messages go in the window
+    align: Align,
+    // the space between the messages and the edges of the window
+    margin: Margin,
+    // how long a message shows, in seconds
+    duration: f64,
+    #[live(3usize)] max_visible: usize,
+
+    #[rust] area: Area,
+    #[rust] items: Vec<(ToastId, ToastItem)>,
+    #[rust] queue: VecDeque<(ToastId, ToastKind, String)>,
+    #[rust] next_id: u64,
+}
+
+impl LiveHook for Toast {
+    fn after_apply(&mut self, cx: &mut Cx, from: ApplyFrom, index: usize, nodes: &[LiveNode]) {
+        if let Some(index) = nodes.child_by_name(index, live_id!(item).as_field()) {
+            for (_, item) in &mut self.items {
+                item.apply(cx, from, index, nodes);
+            }
+        }
+        self.view.redraw(cx);
+    }
+}
+
+#[derive(Clone, WidgetAction)]
+pub enum ToastAction {
+    Dismissed(ToastId),
+    None
+}
+
+impl Widget for Toast {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        let uid = self.widget_uid();
+        self.handle_event_fn(cx, event, &mut | cx, action | {
+            dispatch_action(cx, WidgetActionItem::new(action.into(), uid))
+        });
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.view.redraw(cx);
+    }
+
+    fn get_walk(&self) -> Walk {Walk::fixed_size(DVec2::default())}
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        cx.walk_turtle_with_area(&mut self.area, walk);
+        if self.items.is_empty() {
+            return WidgetDraw::done()
+        }
+        self.view.begin_overlay(cx);
+        cx.begin_overlay_turtle(Layout::flow_down());
+        cx.begin_turtle(Walk::fit(), self.layout);
+        for (_, item) in &mut self.items {
+            item.draw(cx);
+        }
+
+        // move the stack of messages to its place in the window
+        let size = cx.turtle().used() + self.layout.padding.right_bottom();
+        let space = cx.current_pass_size() - size - self.margin.left_top() - self.margin.right_bottom();
+        let pos = self.margin.left_top() + dvec2(self.align.x * space.x.max(0.0), self.align.y * space.y.max(0.0));
+        let origin = cx.turtle().rect().pos;
+        cx.turtle_mut().set_shift(pos - origin);
+        cx.end_turtle();
+
+        cx.end_overlay_turtle();
+        self.view.end(cx);
+        WidgetDraw::done()
+    }
+}
+
+impl Toast {
+    // shows a message, or queues it until there is room for it
+    pub fn push(&mut self, cx: &mut Cx, kind: ToastKind, text: &str) -> ToastId {
+        self.next_id += 1;
+        let id = ToastId(self.next_id);
+        self.queue.push_back((id, kind, text.to_string()));
+        self.show_queued(cx);
+        id
+    }
+
+    pub fn dismiss(&mut self, cx: &mut Cx, id: ToastId) {
+        if let Some((_, item)) = self.items.iter_mut().find( | (item_id, _) | *item_id == id) {
+            item.dismiss(cx);
+        }
+        self.queue.retain( | (item_id, _, _) | *item_id != id);
+    }
+
+    pub fn dismiss_all(&mut self, cx: &mut Cx) {
+        self.queue.clear();
+        for (_, item) in &mut self.items {
+            item.dismiss(cx);
+        }
+    }
+
+    fn show_queued(&mut self, cx: &mut Cx) {
+        while self.items.len() < self.max_visible {
+            let (id, kind, text) = match self.queue.pop_front() {
+                Some(queued) => queued,
+                None => break
+            };
+            let mut item = ToastItem::new_from_ptr(cx, self.item);
+            item.text = text;
+            item.bg.kind = match kind {
+                ToastKind::Info => 0.0,
+                ToastKind::Success => 1.0,
+                ToastKind::Warning => 2.0,
+                ToastKind::Error => 3.0,
+            };
+            item.timer = cx.start_timeout(self.duration);
+            item.animate_state(cx, id!(show.on));
+            self.items.push((id, item));
+        }
+        // the first message needs the toast to draw its overlay
+        self.area.redraw(cx);
+        self.view.redraw(cx);
+    }
+
+    pub fn handle_event_fn(&mut self, cx: &mut Cx, event: &Event, dispatch_action: &mut dyn FnMut(&mut Cx, ToastAction)) {
+        for (_, item) in &mut self.items {
+            if item.state_handle_event(cx, event).must_redraw() {
+                self.view.redraw(cx);
+            }
+            if item.timer.is_event(event) {
+                item.timer = Timer::empty();
+                item.dismiss(cx);
+            }
+            match event.hits(cx, item.bg.area()) {
+                Hit::FingerHoverIn(_) => {
+                    item.animate_state(cx, id!(hover.on));
+                    // the message stays while it is being read
+                    if !item.timer.is_empty() {
+                        cx.stop_timer(item.timer);
+                        item.timer = Timer::empty();
+                    }
+                }
+                Hit::FingerHoverOut(_) => {
+                    item.animate_state(cx, id!(hover.off));
+                    if !item.closing {
+                        item.timer = cx.start_timeout(self.duration);
+                    }
+                }
+                Hit::FingerUp(fe) if fe.is_over => {
+                    item.dismiss(cx);
+                }
+                _ => ()
+            }
+        }
+
+        // the messages that have faded out make room for the queued ones
+        let mut dismissed = Vec::new();
+        self.items.retain( | (id, item) | {
+            if item.closing && item.bg.shown <= 0.0 {
+                dismissed.push(*id);
+                return false
+            }
+            true
+        });
+        if !dismissed.is_empty() {
+            self.show_queued(cx);
+            for id in dismissed {
+                dispatch_action(cx, ToastAction::Dismissed(id));
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct ToastRef(WidgetRef);
+
+impl ToastRef {
+    pub fn push(&self, cx: &mut Cx, kind: ToastKind, text: &str) -> Option<ToastId> {
+        if let Some(mut inner) = self.inner_mut() {
+            return Some(inner.push(cx, kind, text))
+        }
+        None
+    }
+
+    pub fn dismiss(&self, cx: &mut Cx, id: ToastId) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.dismiss(cx, id);
+        }
+    }
+
+    pub fn dismissed(&self, actions: &WidgetActions) -> Option<ToastId> {
+        if let Some(item) = actions.find_single_action(self.widget_uid()) {
+            if let ToastAction::Dismissed(id) = item.action() {
+                return Some(id)
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue() {
+        let (mut cx, mut toast): (_, Toast) = crate::test_widget("toast", live_id!(Toast));
+        toast.max_visible = 2;
+
+        let ids: Vec<ToastId> = (0..4).map( | i | toast.push(&mut cx, ToastKind::Info, &format!("message {}", i))).collect();
+        assert_eq!(ids, [ToastId(1), ToastId(2), ToastId(3), ToastId(4)]);
+        let shown: Vec<ToastId> = toast.items.iter().map( | (id, _) | *id).collect();
+        assert_eq!(shown, [ToastId(1), ToastId(2)]);
+        assert_eq!(toast.items[1].1.text, "message 1");
+        assert!(toast.items.iter().all( | (_, item) | !item.timer.is_empty() && !item.closing));
+
+        // a queued message goes away without showing, a shown one fades out
+        toast.dismiss(&mut cx, ToastId(3));
+        let queued: Vec<ToastId> = toast.queue.iter().map( | (id, _, _) | *id).collect();
+        assert_eq!(queued, [ToastId(4)]);
+        toast.dismiss(&mut cx, ToastId(1));
+        assert!(toast.items[0].1.closing && toast.items[0].1.timer.is_empty());
+        assert_eq!(toast.items.len(), 2);
+
+        toast.dismiss_all(&mut cx);
+        assert!(toast.queue.is_empty());
+        assert!(toast.items.iter().all( | (_, item) | item.closing));
+    }
+}
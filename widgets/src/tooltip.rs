@@ -0,0 +1,313 @@
+use {
+    crate::{
+        makepad_derive_widget::*,
+        makepad_draw_2d::*,
+        widget::*,
+        frame::FrameRef,
+    },
+};
+
+live_design!{
+    import makepad_draw_2d::shader::std::*;
+    import makepad_widgets::theme::*;
+    import makepad_widgets::frame::Frame;
+
+    DrawTooltipBg = {{DrawTooltipBg}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            sdf.box(0.0, 0.0, self.rect_size.x, self.rect_size.y, 3.0);
+            sdf.fill(self.color);
+            return sdf.result * self.opened;
+        }
+    }
+
+    DrawTooltipText = {{DrawTooltipText}} {
+        fn get_color(self) -> vec4 {
+            return vec4(self.color.rgb, self.color.a * self.opened);
+        }
+    }
+
+    Tooltip = {{Tooltip}} {
+        walk: {width: Fit, height: Fit}
+        content: <Frame> {
+            walk: {width: Fit, height: Fit}
+        }
+        bg: {color: #000d}
+        label: {
+            color: (COLOR_TEXT_HOVER)
+            wrap: Word
+        }
+        tip_layout: {padding: {left: 8, top: 5, right: 8, bottom: 5}}
+        max_width: 300.0
+        delay: 0.6
+        offset: 4.0
+        state: {
+            open = {
+                default: off
+                off = {
+                    from: {all: Snap}
+                    redraw: true
+                    apply: {
+                        bg: {opened: 0.0}
+                        label: {opened: 0.0}
+                    }
+                }
+                on = {
+                    from: {all: Forward {duration: 0.1}}
+                    redraw: true
+                    apply: {
+                        bg: {opened: 1.0}
+                        label: {opened: 1.0}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawTooltipBg {
+    draw_super: DrawQuad,
+    color: Vec4,
+    opened: f32,
+}
+
+#[derive(Live, LiveHook)]#[repr(C)]
+struct DrawTooltipText {
+    draw_super: DrawText,
+    opened: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Live, LiveHook)]
+#[live_ignore]
+pub enum TooltipPosition {
+    #[pick] Bottom,
+    Top,
+}
+
+// Shows a line of text next to its content when the mouse rests on it:
+//
+// <Tooltip> {text: "Saves the file", content: {save = <Button> {text: "Save"}}}
+//
+// The tip goes on the preferred side of the content, flips to the other side when it doesn't fit
+// in the window there, and moves sideways to stay in the window.
+#[derive(Live)]
+#[live_design_fn(widget_factory!(Tooltip))]
+pub struct Tooltip {
+    view: View,
+    content: WidgetRef,
+    bg: DrawTooltipBg,
+    label: DrawTooltipText,
+
+    walk: Walk,
+    tip_layout: Layout,
+    // the width the text wraps at
+    max_width: f64,
+    state: State,
+
+    text: String,
+    position: TooltipPosition,
+    // how long the mouse rests on the content before the tip shows, in seconds
+    delay: f64,
+    // the distance between the content and the tip
+    offset: f64,
+
+    #[rust] area: Area,
+    #[rust] draw_state: DrawStateWrap<()>,
+    #[rust] hovered: bool,
+    #[rust] is_open: bool,
+    #[rust] timer: Timer,
+}
+
+impl LiveHook for Tooltip {}
+
+impl Widget for Tooltip {
+    fn widget_uid(&self) -> WidgetUid {WidgetUid(self as *const _ as u64)}
+
+    fn handle_widget_event_fn(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        dispatch_action: &mut dyn FnMut(&mut Cx, WidgetActionItem)
+    ) {
+        self.handle_event(cx, event);
+        self.content.handle_widget_event_fn(cx, event, dispatch_action);
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.area.redraw(cx);
+        self.content.redraw(cx);
+    }
+
+    fn get_walk(&self) -> Walk {self.walk}
+
+    fn find_widget(&mut self, path: &[LiveId], cached: WidgetCache) -> WidgetResult {
+        self.content.find_widget(path, cached)
+    }
+
+    fn draw_widget(&mut self, cx: &mut Cx2d, walk: Walk) -> WidgetDraw {
+        if self.draw_state.begin(cx, ()) {
+            cx.begin_turtle(walk, Layout::default());
+        }
+        self.content.draw_walk_widget(cx) ?;
+        cx.end_turtle_with_area(&mut self.area);
+        self.draw_state.end();
+
+        if self.is_open || self.state.is_track_animating(cx, id!(open)) {
+            self.draw_tip(cx);
+        }
+        WidgetDraw::done()
+    }
+}
+
+impl Tooltip {
+    pub fn set_text(&mut self, cx: &mut Cx, text: &str) {
+        self.text = text.to_string();
+        if self.is_open {
+            self.view.redraw(cx);
+        }
+    }
+
+    pub fn show(&mut self, cx: &mut Cx) {
+        if self.is_open || self.text.is_empty() {
+            return
+        }
+        self.is_open = true;
+        self.animate_state(cx, id!(open.on));
+        self.area.redraw(cx);
+    }
+
+    pub fn hide(&mut self, cx: &mut Cx) {
+        if !self.timer.is_empty() {
+            cx.stop_timer(self.timer);
+            self.timer = Timer::empty();
+        }
+        if !self.is_open {
+            return
+        }
+        self.is_open = false;
+        self.animate_state(cx, id!(open.off));
+        self.area.redraw(cx);
+    }
+
+    pub fn handle_event(&mut self, cx: &mut Cx, event: &Event) {
+        if self.state_handle_event(cx, event).must_redraw() {
+            self.view.redraw(cx);
+        }
+        if self.timer.is_event(event) {
+            self.timer = Timer::empty();
+            self.show(cx);
+        }
+        match event {
+            Event::FingerHover(fe) => {
+                // the content doesn't get hovers while the input is locked to something else
+                let over = fe.sweep_lock.get().is_empty()
+                    && self.area.is_valid(cx)
+                    && self.area.get_clipped_rect(cx).contains(fe.abs);
+                if over && !self.hovered {
+                    self.hovered = true;
+                    self.timer = cx.start_timeout(self.delay);
+                }
+                else if !over && self.hovered {
+                    self.hovered = false;
+                    self.hide(cx);
+                }
+            }
+            Event::FingerDown(_) | Event::FingerScroll(_) | Event::KeyDown(_) => {
+                self.hide(cx);
+            }
+            _ => ()
+        }
+    }
+
+    // puts the tip on its side of the content, or the other side if there is no room for it,
+    // and keeps it within the window sideways
+    fn tip_pos(&self, anchor: Rect, size: DVec2, pass_size: DVec2) -> DVec2 {
+        let below = anchor.pos.y + anchor.size.y + self.offset;
+        let above = anchor.pos.y - self.offset - size.y;
+        let fits_below = below + size.y <= pass_size.y;
+        let fits_above = above >= 0.0;
+        let y = match self.position {
+            TooltipPosition::Bottom => if fits_below || !fits_above {below} else {above},
+            TooltipPosition::Top => if fits_above || !fits_below {above} else {below},
+        };
+        let x = (anchor.pos.x + 0.5 * (anchor.size.x - size.x)).min(pass_size.x - size.x).max(0.0);
+        dvec2(x, y)
+    }
+
+    fn draw_tip(&mut self, cx: &mut Cx2d) {
+        let anchor = self.area.get_clipped_rect(cx);
+        self.view.begin_overlay(cx);
+        cx.begin_overlay_turtle(Layout::flow_down());
+        self.bg.begin(cx, Walk::fit(), self.tip_layout);
+
+        // short text goes on one line, longer text wraps at the max width
+        let text_width = self.label.compute_geom(cx, Walk::fit(), &self.text)
+            .map( | geom | geom.measured_width.ceil() + 1.0)
+            .unwrap_or(0.0)
+            .min(self.max_width);
+        self.label.draw_walk(cx, Walk::size(Size::Fixed(text_width), Size::Fit), Align::default(), &self.text);
+
+        let size = cx.turtle().used() + self.tip_layout.padding.right_bottom();
+        let pos = self.tip_pos(anchor, size, cx.current_pass_size());
+        let origin = cx.turtle().rect().pos;
+        cx.turtle_mut().set_shift(pos - origin);
+
+        self.bg.end(cx);
+        cx.end_overlay_turtle();
+        self.view.end(cx);
+    }
+}
+
+#[derive(Clone, PartialEq, WidgetRef)]
+pub struct TooltipRef(WidgetRef);
+
+impl TooltipRef {
+    pub fn set_text(&self, cx: &mut Cx, text: &str) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.set_text(cx, text);
+        }
+    }
+
+    pub fn show(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.show(cx);
+        }
+    }
+
+    pub fn hide(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.inner_mut() {
+            inner.hide(cx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tip_pos() {
+        let (_cx, mut tooltip): (_, Tooltip) = crate::test_widget("tooltip", live_id!(Tooltip));
+        tooltip.offset = 4.0;
+        let pass_size = dvec2(400.0, 300.0);
+        let size = dvec2(60.0, 20.0);
+        let anchor = | x, y | Rect {pos: dvec2(x, y), size: dvec2(40.0, 30.0)};
+
+        // centered below the content
+        tooltip.position = TooltipPosition::Bottom;
+        assert_eq!(tooltip.tip_pos(anchor(100.0, 100.0), size, pass_size), dvec2(90.0, 134.0));
+        // flips above when there is no room below
+        assert_eq!(tooltip.tip_pos(anchor(100.0, 260.0), size, pass_size), dvec2(90.0, 236.0));
+
+        tooltip.position = TooltipPosition::Top;
+        assert_eq!(tooltip.tip_pos(anchor(100.0, 100.0), size, pass_size), dvec2(90.0, 76.0));
+        assert_eq!(tooltip.tip_pos(anchor(100.0, 10.0), size, pass_size), dvec2(90.0, 44.0));
+        // stays on its side when it fits on neither
+        assert_eq!(tooltip.tip_pos(anchor(100.0, 10.0), dvec2(60.0, 290.0), pass_size), dvec2(90.0, -284.0));
+
+        // moves sideways to stay in the window
+        assert_eq!(tooltip.tip_pos(anchor(0.0, 100.0), size, pass_size).x, 0.0);
+        assert_eq!(tooltip.tip_pos(anchor(370.0, 100.0), size, pass_size).x, 340.0);
+    }
+}